@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The minimum number of scalars for an MSM to run on the GPU.
+// TODO SNP: where to set the threshold
+const MIN_CUDA_MSM_SIZE: usize = 1025;
+/// The minimum domain size for an NTT to run on the GPU.
+// SNP TODO: how to set threshold
+const MIN_CUDA_NTT_SIZE: usize = 32;
+
+/// The CUDA provider, which runs the operations on the GPU, and falls back to the CPU if the device fails.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct CudaProvider;
+
+impl AcceleratorProvider for CudaProvider {
+    fn name(&self) -> &'static str {
+        "cuda"
+    }
+
+    fn msm(&self, bases: &[G1Affine], scalars: &[<Fr as PrimeField>::BigInteger]) -> Option<G1Projective> {
+        if scalars.len() < MIN_CUDA_MSM_SIZE {
+            return None;
+        }
+        snarkvm_algorithms_cuda::msm::<G1Affine, G1Projective, <Fr as PrimeField>::BigInteger>(bases, scalars).ok()
+    }
+
+    fn ntt(&self, values: &mut [Fr], direction: NTTDirection, ntt_type: NTTType) -> bool {
+        if values.len() < MIN_CUDA_NTT_SIZE {
+            return false;
+        }
+        let direction = match direction {
+            NTTDirection::Forward => snarkvm_algorithms_cuda::NTTDirection::Forward,
+            NTTDirection::Inverse => snarkvm_algorithms_cuda::NTTDirection::Inverse,
+        };
+        let ntt_type = match ntt_type {
+            NTTType::Standard => snarkvm_algorithms_cuda::NTTType::Standard,
+            NTTType::Coset => snarkvm_algorithms_cuda::NTTType::Coset,
+        };
+        snarkvm_algorithms_cuda::NTT(
+            values.len(),
+            values,
+            snarkvm_algorithms_cuda::NTTInputOutputOrder::NN,
+            direction,
+            ntt_type,
+        )
+        .is_ok()
+    }
+}
@@ -0,0 +1,255 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+mod cuda;
+#[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+pub use cuda::*;
+
+use snarkvm_curves::bls12_377::{Fr, G1Affine, G1Projective};
+use snarkvm_fields::PrimeField;
+
+use std::sync::{Arc, RwLock};
+
+/// The direction of an NTT.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NTTDirection {
+    /// The forward transform, from coefficients to evaluations.
+    Forward,
+    /// The inverse transform, from evaluations to coefficients.
+    Inverse,
+}
+
+/// The domain of an NTT.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NTTType {
+    /// The transform over the subgroup of the domain.
+    Standard,
+    /// The transform over the coset of the domain, shifted by its generator.
+    Coset,
+}
+
+/// A provider of hardware acceleration for the MSMs and FFTs over BLS12-377, which dominate proving time.
+///
+/// A provider may decline any operation (e.g. if the input is too small, or the device fails),
+/// in which case the operation falls back to the CPU implementation.
+pub trait AcceleratorProvider: Send + Sync {
+    /// Returns the name of the provider.
+    fn name(&self) -> &'static str;
+
+    /// Returns the variable-base MSM of the given bases and scalars, or `None` to fall back to the CPU.
+    fn msm(&self, bases: &[G1Affine], scalars: &[<Fr as PrimeField>::BigInteger]) -> Option<G1Projective>;
+
+    /// Computes the in-order NTT of the given values in place, and returns `true`, or `false` to fall back to the CPU.
+    /// The number of values is the size of the domain, which is a power of two.
+    fn ntt(&self, values: &mut [Fr], direction: NTTDirection, ntt_type: NTTType) -> bool;
+}
+
+/// The CPU provider, which declines every operation.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct CpuProvider;
+
+impl AcceleratorProvider for CpuProvider {
+    fn name(&self) -> &'static str {
+        "cpu"
+    }
+
+    fn msm(&self, _bases: &[G1Affine], _scalars: &[<Fr as PrimeField>::BigInteger]) -> Option<G1Projective> {
+        None
+    }
+
+    fn ntt(&self, _values: &mut [Fr], _direction: NTTDirection, _ntt_type: NTTType) -> bool {
+        false
+    }
+}
+
+/// The provider selected at runtime, if any.
+static PROVIDER: RwLock<Option<Arc<dyn AcceleratorProvider>>> = RwLock::new(None);
+
+/// Returns the selected provider. By default, this is the CUDA provider if the `cuda` feature is enabled,
+/// and the CPU provider otherwise.
+pub fn provider() -> Arc<dyn AcceleratorProvider> {
+    match PROVIDER.read().unwrap_or_else(|error| error.into_inner()).as_ref() {
+        Some(provider) => provider.clone(),
+        None => default_provider(),
+    }
+}
+
+/// Selects the given provider for all subsequent MSMs and FFTs in this process.
+pub fn set_provider(provider: Arc<dyn AcceleratorProvider>) {
+    *PROVIDER.write().unwrap_or_else(|error| error.into_inner()) = Some(provider);
+}
+
+/// Restores the default provider.
+pub fn reset_provider() {
+    *PROVIDER.write().unwrap_or_else(|error| error.into_inner()) = None;
+}
+
+/// Returns the default provider.
+fn default_provider() -> Arc<dyn AcceleratorProvider> {
+    #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
+    {
+        Arc::new(CudaProvider)
+    }
+    #[cfg(not(all(feature = "cuda", target_arch = "x86_64")))]
+    {
+        Arc::new(CpuProvider)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fft::EvaluationDomain, msm::VariableBase};
+    use snarkvm_curves::ProjectiveCurve;
+    use snarkvm_fields::{Field, One, Zero};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    };
+
+    /// A lock to serialize the tests that select a provider, as the provider is global.
+    static PROVIDER_LOCK: Mutex<()> = Mutex::new(());
+
+    /// A provider that counts its calls, and declines every operation.
+    struct CountingProvider {
+        calls: AtomicUsize,
+    }
+
+    impl AcceleratorProvider for CountingProvider {
+        fn name(&self) -> &'static str {
+            "counting"
+        }
+
+        fn msm(&self, _bases: &[G1Affine], _scalars: &[<Fr as PrimeField>::BigInteger]) -> Option<G1Projective> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            None
+        }
+
+        fn ntt(&self, _values: &mut [Fr], _direction: NTTDirection, _ntt_type: NTTType) -> bool {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            false
+        }
+    }
+
+    /// A provider that counts its calls, and computes small NTTs by direct evaluation.
+    struct NaiveProvider {
+        calls: AtomicUsize,
+    }
+
+    impl AcceleratorProvider for NaiveProvider {
+        fn name(&self) -> &'static str {
+            "naive"
+        }
+
+        fn msm(&self, _bases: &[G1Affine], _scalars: &[<Fr as PrimeField>::BigInteger]) -> Option<G1Projective> {
+            None
+        }
+
+        fn ntt(&self, values: &mut [Fr], direction: NTTDirection, ntt_type: NTTType) -> bool {
+            // Decline large NTTs, and the forward NTT over the coset.
+            if values.len() > 64 || (direction, ntt_type) == (NTTDirection::Forward, NTTType::Coset) {
+                return false;
+            }
+            self.calls.fetch_add(1, Ordering::SeqCst);
+
+            let domain = EvaluationDomain::<Fr>::new(values.len()).unwrap();
+            let (root, scale) = match direction {
+                NTTDirection::Forward => (domain.group_gen, Fr::one()),
+                NTTDirection::Inverse => (domain.group_gen_inv, domain.size_inv),
+            };
+            // Evaluate the values as a polynomial at each power of the root.
+            let input = values.to_vec();
+            for (i, value) in values.iter_mut().enumerate() {
+                let point = root.pow([i as u64]);
+                *value = input.iter().rev().fold(Fr::zero(), |acc, c| acc * point + c) * scale;
+            }
+            // Shift the coefficients back from the coset.
+            if ntt_type == NTTType::Coset {
+                for (i, value) in values.iter_mut().enumerate() {
+                    *value *= domain.generator_inv.pow([i as u64]);
+                }
+            }
+            true
+        }
+    }
+
+    #[test]
+    fn test_provider() {
+        let _lock = PROVIDER_LOCK.lock().unwrap_or_else(|error| error.into_inner());
+        let rng = &mut TestRng::default();
+
+        let bases = (0..64).map(|_| G1Affine::rand(rng)).collect::<Vec<_>>();
+        let scalars = (0..64).map(|_| Fr::rand(rng).to_bigint()).collect::<Vec<_>>();
+        let expected = VariableBase::msm(&bases, &scalars);
+
+        // Select the counting provider.
+        let counting = Arc::new(CountingProvider { calls: AtomicUsize::new(0) });
+        set_provider(counting.clone());
+        assert_eq!(provider().name(), "counting");
+
+        // Ensure the MSM and FFT fall back to the CPU when the provider declines.
+        assert_eq!(VariableBase::msm(&bases, &scalars).to_affine(), expected.to_affine());
+        let domain = EvaluationDomain::<Fr>::new(64).unwrap();
+        let coeffs = (0..64).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let mut evaluations = coeffs.clone();
+        domain.fft_in_place(&mut evaluations);
+        domain.ifft_in_place(&mut evaluations);
+        assert_eq!(evaluations, coeffs);
+        assert!(counting.calls.load(Ordering::SeqCst) >= 3);
+
+        // Restore the default provider.
+        reset_provider();
+    }
+
+    #[test]
+    fn test_accelerated_ntt() {
+        let _lock = PROVIDER_LOCK.lock().unwrap_or_else(|error| error.into_inner());
+        let rng = &mut TestRng::default();
+
+        let domain = EvaluationDomain::<Fr>::new(64).unwrap();
+        let coeffs = (0..64).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+
+        // Compute the NTTs on the CPU.
+        reset_provider();
+        let mut expected_fft = coeffs.clone();
+        domain.fft_in_place(&mut expected_fft);
+        let mut expected_ifft = coeffs.clone();
+        domain.ifft_in_place(&mut expected_ifft);
+        let mut expected_coset_ifft = coeffs.clone();
+        domain.coset_ifft_in_place(&mut expected_coset_ifft);
+
+        // Select the naive provider.
+        let naive = Arc::new(NaiveProvider { calls: AtomicUsize::new(0) });
+        set_provider(naive.clone());
+
+        // Ensure the accelerated NTTs match the CPU.
+        let mut fft = coeffs.clone();
+        domain.fft_in_place(&mut fft);
+        assert_eq!(fft, expected_fft);
+        let mut ifft = coeffs.clone();
+        domain.ifft_in_place(&mut ifft);
+        assert_eq!(ifft, expected_ifft);
+        let mut coset_ifft = coeffs;
+        domain.coset_ifft_in_place(&mut coset_ifft);
+        assert_eq!(coset_ifft, expected_coset_ifft);
+        // Ensure each NTT ran on the provider.
+        assert_eq!(naive.calls.load(Ordering::SeqCst), 3);
+
+        // Restore the default provider.
+        reset_provider();
+    }
+}
@@ -25,17 +25,20 @@
 //! by performing an O(n log n) FFT over such a domain.
 
 use crate::{
+    accelerator::{NTTDirection, NTTType},
     cfg_chunks_mut,
     cfg_into_iter,
     cfg_iter,
     cfg_iter_mut,
     fft::{DomainCoeff, SparsePolynomial},
 };
+use snarkvm_curves::bls12_377::Fr;
 use snarkvm_fields::{batch_inversion, FftField, FftParameters, Field};
 #[cfg(not(feature = "serial"))]
 use snarkvm_utilities::max_available_threads;
 use snarkvm_utilities::{execute_with_max_available_threads, serialize::*};
 
+use core::any::TypeId;
 use rand::Rng;
 use std::{borrow::Cow, fmt};
 
@@ -166,6 +169,7 @@ impl<F: FftField> EvaluationDomain<F> {
 
     /// Compute an FFT, modifying the vector in place.
     pub fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
+        let _span = crate::instrument!("fft", size = self.size());
         execute_with_max_available_threads(|| {
             coeffs.resize(self.size(), T::zero());
             self.in_order_fft_in_place(&mut *coeffs);
@@ -182,6 +186,7 @@ impl<F: FftField> EvaluationDomain<F> {
     /// Compute an IFFT, modifying the vector in place.
     #[inline]
     pub fn ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        let _span = crate::instrument!("ifft", size = self.size());
         execute_with_max_available_threads(|| {
             evals.resize(self.size(), T::zero());
             self.in_order_ifft_in_place(&mut *evals);
@@ -213,6 +218,7 @@ impl<F: FftField> EvaluationDomain<F> {
 
     /// Compute an IFFT over a coset of the domain, modifying the input vector in place.
     pub fn coset_ifft_in_place<T: DomainCoeff<F>>(&self, evals: &mut Vec<T>) {
+        let _span = crate::instrument!("ifft", size = self.size(), coset = true);
         execute_with_max_available_threads(|| {
             evals.resize(self.size(), T::zero());
             self.in_order_coset_ifft_in_place(&mut *evals);
@@ -371,19 +377,8 @@ impl<F: FftField> EvaluationDomain<F> {
     }
 
     pub(crate) fn in_order_fft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
-        #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-        // SNP TODO: how to set threshold and check that the type is Fr
-        if self.size >= 32 && std::mem::size_of::<T>() == 32 {
-            let result = snarkvm_algorithms_cuda::NTT(
-                self.size as usize,
-                x_s,
-                snarkvm_algorithms_cuda::NTTInputOutputOrder::NN,
-                snarkvm_algorithms_cuda::NTTDirection::Forward,
-                snarkvm_algorithms_cuda::NTTType::Standard,
-            );
-            if result.is_ok() {
-                return;
-            }
+        if self.accelerated_ntt(x_s, NTTDirection::Forward, NTTType::Standard) {
+            return;
         }
 
         let pc = self.precompute_fft();
@@ -400,19 +395,8 @@ impl<F: FftField> EvaluationDomain<F> {
     }
 
     pub(crate) fn in_order_ifft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
-        #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-        // SNP TODO: how to set threshold
-        if self.size >= 32 && std::mem::size_of::<T>() == 32 {
-            let result = snarkvm_algorithms_cuda::NTT(
-                self.size as usize,
-                x_s,
-                snarkvm_algorithms_cuda::NTTInputOutputOrder::NN,
-                snarkvm_algorithms_cuda::NTTDirection::Inverse,
-                snarkvm_algorithms_cuda::NTTType::Standard,
-            );
-            if result.is_ok() {
-                return;
-            }
+        if self.accelerated_ntt(x_s, NTTDirection::Inverse, NTTType::Standard) {
+            return;
         }
 
         let pc = self.precompute_ifft();
@@ -421,19 +405,8 @@ impl<F: FftField> EvaluationDomain<F> {
     }
 
     pub(crate) fn in_order_coset_ifft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
-        #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-        // SNP TODO: how to set threshold
-        if self.size >= 32 && std::mem::size_of::<T>() == 32 {
-            let result = snarkvm_algorithms_cuda::NTT(
-                self.size as usize,
-                x_s,
-                snarkvm_algorithms_cuda::NTTInputOutputOrder::NN,
-                snarkvm_algorithms_cuda::NTTDirection::Inverse,
-                snarkvm_algorithms_cuda::NTTType::Coset,
-            );
-            if result.is_ok() {
-                return;
-            }
+        if self.accelerated_ntt(x_s, NTTDirection::Inverse, NTTType::Coset) {
+            return;
         }
 
         let pc = self.precompute_ifft();
@@ -448,19 +421,8 @@ impl<F: FftField> EvaluationDomain<F> {
         x_s: &mut [T],
         pre_comp: &FFTPrecomputation<F>,
     ) {
-        #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-        // SNP TODO: how to set threshold
-        if self.size >= 32 && std::mem::size_of::<T>() == 32 {
-            let result = snarkvm_algorithms_cuda::NTT(
-                self.size as usize,
-                x_s,
-                snarkvm_algorithms_cuda::NTTInputOutputOrder::NN,
-                snarkvm_algorithms_cuda::NTTDirection::Forward,
-                snarkvm_algorithms_cuda::NTTType::Standard,
-            );
-            if result.is_ok() {
-                return;
-            }
+        if self.accelerated_ntt(x_s, NTTDirection::Forward, NTTType::Standard) {
+            return;
         }
 
         self.fft_helper_in_place_with_pc(x_s, FFTOrder::II, pre_comp)
@@ -479,19 +441,8 @@ impl<F: FftField> EvaluationDomain<F> {
         x_s: &mut [T],
         pre_comp: &IFFTPrecomputation<F>,
     ) {
-        #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-        // SNP TODO: how to set threshold
-        if self.size >= 32 && std::mem::size_of::<T>() == 32 {
-            let result = snarkvm_algorithms_cuda::NTT(
-                self.size as usize,
-                x_s,
-                snarkvm_algorithms_cuda::NTTInputOutputOrder::NN,
-                snarkvm_algorithms_cuda::NTTDirection::Inverse,
-                snarkvm_algorithms_cuda::NTTType::Standard,
-            );
-            if result.is_ok() {
-                return;
-            }
+        if self.accelerated_ntt(x_s, NTTDirection::Inverse, NTTType::Standard) {
+            return;
         }
 
         self.ifft_helper_in_place_with_pc(x_s, FFTOrder::II, pre_comp);
@@ -513,19 +464,8 @@ impl<F: FftField> EvaluationDomain<F> {
         x_s: &mut [T],
         pre_comp: &IFFTPrecomputation<F>,
     ) {
-        #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-        // SNP TODO: how to set threshold
-        if self.size >= 32 && std::mem::size_of::<T>() == 32 {
-            let result = snarkvm_algorithms_cuda::NTT(
-                self.size as usize,
-                x_s,
-                snarkvm_algorithms_cuda::NTTInputOutputOrder::NN,
-                snarkvm_algorithms_cuda::NTTDirection::Inverse,
-                snarkvm_algorithms_cuda::NTTType::Coset,
-            );
-            if result.is_ok() {
-                return;
-            }
+        if self.accelerated_ntt(x_s, NTTDirection::Inverse, NTTType::Coset) {
+            return;
         }
 
         self.ifft_helper_in_place_with_pc(x_s, FFTOrder::II, pre_comp);
@@ -533,6 +473,21 @@ impl<F: FftField> EvaluationDomain<F> {
         Self::distribute_powers_and_mul_by_const(x_s, coset_shift, self.size_inv);
     }
 
+    /// Computes the in-order NTT of the given values in place on the selected accelerator provider,
+    /// and returns `true`, or `false` if the provider declines.
+    fn accelerated_ntt<T: DomainCoeff<F>>(&self, x_s: &mut [T], direction: NTTDirection, ntt_type: NTTType) -> bool {
+        // Note: The providers operate on the BLS12-377 scalar field, where the only coefficients of its size are field elements.
+        if TypeId::of::<F>() != TypeId::of::<Fr>()
+            || std::mem::size_of::<T>() != std::mem::size_of::<Fr>()
+            || x_s.len() != self.size()
+        {
+            return false;
+        }
+        // SAFETY: The coefficients are elements of `Fr`, and the slice covers the entire domain.
+        let x_s = unsafe { &mut *(x_s as *mut [T] as *mut [Fr]) };
+        crate::accelerator::provider().ntt(x_s, direction, ntt_type)
+    }
+
     fn fft_helper_in_place_with_pc<T: DomainCoeff<F>>(
         &self,
         x_s: &mut [T],
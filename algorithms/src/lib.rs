@@ -29,6 +29,8 @@ extern crate thiserror;
 
 pub use snarkvm_utilities::{cfg_chunks, cfg_chunks_mut, cfg_into_iter, cfg_iter, cfg_iter_mut, cfg_reduce};
 
+#[cfg(any(feature = "fft", feature = "msm"))]
+pub mod accelerator;
 #[cfg(feature = "crypto_hash")]
 pub mod crypto_hash;
 #[cfg(feature = "fft")]
@@ -44,6 +46,8 @@ pub mod snark;
 
 pub mod srs;
 
+pub mod telemetry;
+
 pub mod errors;
 pub use errors::*;
 
@@ -15,6 +15,9 @@
 pub mod fixed_base;
 pub use fixed_base::*;
 
+pub mod precomputed;
+pub use precomputed::*;
+
 #[cfg(test)]
 pub mod tests;
 
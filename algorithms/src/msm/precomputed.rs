@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{cfg_chunks_mut, cfg_into_iter, BigInteger};
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// A set of fixed bases with precomputed window tables, for repeated Pippenger MSMs over the same bases.
+///
+/// For each base `G_i` and window `j`, the table stores `2^(j * window) * G_i`. An MSM then accumulates
+/// every window of every scalar into a single set of buckets, which removes the per-window bucket reduction
+/// and the doublings of the standard algorithm, at the cost of `num_windows` times the memory of the bases.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct PrecomputedBases<G: AffineCurve> {
+    /// The window size, in bits.
+    window: usize,
+    /// The number of windows per scalar.
+    num_windows: usize,
+    /// The precomputed multiples, where the multiples of base `i` are at `i * num_windows..(i + 1) * num_windows`.
+    table: Vec<G>,
+}
+
+impl<G: AffineCurve> PrecomputedBases<G> {
+    /// Returns the window size for precomputing the given number of bases (chosen empirically).
+    pub fn default_window(num_bases: usize) -> usize {
+        match num_bases < 32 {
+            true => 3,
+            false => super::ln_without_floats(num_bases) + 2,
+        }
+    }
+
+    /// Precomputes the window tables for the given bases, with the given window size.
+    pub fn new(bases: &[G], window: usize) -> Self {
+        assert!(window > 0 && window < 32, "Invalid window size for precomputed bases");
+
+        let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
+        let num_windows = (num_bits + window - 1) / window;
+
+        // Compute the multiples `2^(j * window) * G_i` of each base.
+        let mut table = vec![G::Projective::zero(); bases.len() * num_windows];
+        cfg_chunks_mut!(table, num_windows).zip(bases).for_each(|(multiples, base)| {
+            let mut multiple = base.to_projective();
+            for entry in multiples.iter_mut() {
+                *entry = multiple;
+                (0..window).for_each(|_| multiple.double_in_place());
+            }
+        });
+
+        Self { window, num_windows, table: G::Projective::batch_normalization_into_affine(table) }
+    }
+
+    /// Returns the window size, in bits.
+    pub const fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Returns the number of bases.
+    pub fn num_bases(&self) -> usize {
+        self.table.len() / self.num_windows
+    }
+
+    /// Returns the MSM of the given scalars with the bases starting at the given offset,
+    /// i.e. `sum_i scalars[i] * G_{offset + i}`.
+    pub fn msm(&self, offset: usize, scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
+        assert!(offset + scalars.len() <= self.num_bases(), "Insufficient precomputed bases for the MSM");
+
+        // Split the scalars into one chunk per thread, each with their own buckets.
+        #[cfg(not(feature = "serial"))]
+        let num_chunks = snarkvm_utilities::max_available_threads().max(1);
+        #[cfg(feature = "serial")]
+        let num_chunks = 1;
+        let chunk_size = ((scalars.len() + num_chunks - 1) / num_chunks).max(1);
+
+        cfg_into_iter!(0..num_chunks)
+            .map(|chunk| {
+                let start = (chunk * chunk_size).min(scalars.len());
+                let end = (start + chunk_size).min(scalars.len());
+                self.msm_chunk(offset + start, &scalars[start..end])
+            })
+            .sum()
+    }
+
+    /// Returns the MSM of the given scalars with the bases starting at the given offset, on a single thread.
+    fn msm_chunk(&self, offset: usize, scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
+        // We don't need the "zero" bucket, so we only have 2^window - 1 buckets.
+        let mut buckets = vec![G::Projective::zero(); (1 << self.window) - 1];
+
+        for (index, scalar) in scalars.iter().enumerate() {
+            let multiples = &self.table[(offset + index) * self.num_windows..(offset + index + 1) * self.num_windows];
+            let mut scalar = *scalar;
+            for multiple in multiples {
+                // We mod the remaining bits by the window size.
+                let digit = scalar.as_ref()[0] % (1u64 << self.window);
+                // If the digit is non-zero, we update the corresponding bucket.
+                if digit != 0 {
+                    buckets[(digit - 1) as usize].add_assign_mixed(multiple);
+                }
+                // We right-shift by the window size, thus getting rid of the lower bits.
+                scalar.divn(self.window as u32);
+            }
+        }
+
+        // Compute `sum_d d * bucket_d` with a running sum.
+        let mut result = G::Projective::zero();
+        let mut running_sum = G::Projective::zero();
+        for bucket in buckets.into_iter().rev() {
+            running_sum += bucket;
+            result += running_sum;
+        }
+        result
+    }
+}
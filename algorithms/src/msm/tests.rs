@@ -14,7 +14,7 @@
 
 use crate::msm::*;
 use snarkvm_curves::{
-    bls12_377::{Fr, G1Projective},
+    bls12_377::{Fr, G1Affine, G1Projective},
     traits::{AffineCurve, ProjectiveCurve},
 };
 use snarkvm_fields::{PrimeField, Zero};
@@ -64,3 +64,22 @@ fn variable_base_test_with_bls12_unequal_numbers() {
 
     assert_eq!(naive.to_affine(), fast.to_affine());
 }
+
+#[test]
+fn precomputed_bases_test_with_bls12() {
+    const SAMPLES: usize = 1 << 10;
+    const OFFSET: usize = 100;
+
+    let mut rng = TestRng::default();
+
+    let v = (0..SAMPLES - OFFSET).map(|_| Fr::rand(&mut rng).to_bigint()).collect::<Vec<_>>();
+    let g = (0..SAMPLES).map(|_| G1Projective::rand(&mut rng).to_affine()).collect::<Vec<_>>();
+
+    let precomputed = PrecomputedBases::new(g.as_slice(), PrecomputedBases::<G1Affine>::default_window(SAMPLES));
+    assert_eq!(precomputed.num_bases(), SAMPLES);
+
+    let naive = naive_variable_base_msm(&g[OFFSET..], v.as_slice());
+    let fast = precomputed.msm(OFFSET, v.as_slice());
+
+    assert_eq!(naive.to_affine(), fast.to_affine());
+}
@@ -18,7 +18,10 @@ pub mod standard;
 #[cfg(target_arch = "x86_64")]
 pub mod prefetch;
 
-use snarkvm_curves::{bls12_377::G1Affine, traits::AffineCurve};
+use snarkvm_curves::{
+    bls12_377::{Fr, G1Affine, G1Projective},
+    traits::AffineCurve,
+};
 use snarkvm_fields::PrimeField;
 
 use core::any::TypeId;
@@ -27,17 +30,14 @@ pub struct VariableBase;
 
 impl VariableBase {
     pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
+        let _span = crate::instrument!("msm", size = bases.len());
         // For BLS12-377, we perform variable base MSM using a batched addition technique.
         if TypeId::of::<G>() == TypeId::of::<G1Affine>() {
-            #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
-            // TODO SNP: where to set the threshold
-            if scalars.len() > 1024 {
-                let result = snarkvm_algorithms_cuda::msm::<G, G::Projective, <G::ScalarField as PrimeField>::BigInteger>(
-                    bases, scalars,
-                );
-                if let Ok(result) = result {
-                    return result;
-                }
+            // SAFETY: `G` is `G1Affine`, so the bases, scalars, and result are of the same types as the provider's.
+            let g1_bases = unsafe { &*(bases as *const [G] as *const [G1Affine]) };
+            let g1_scalars = unsafe { &*(scalars as *const [_] as *const [<Fr as PrimeField>::BigInteger]) };
+            if let Some(result) = crate::accelerator::provider().msm(g1_bases, g1_scalars) {
+                return unsafe { std::mem::transmute_copy::<G1Projective, G::Projective>(&result) };
             }
             batched::msm(bases, scalars)
         }
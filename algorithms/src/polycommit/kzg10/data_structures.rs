@@ -14,6 +14,7 @@
 
 use crate::{
     fft::{DensePolynomial, EvaluationDomain},
+    msm::PrecomputedBases,
     AlgebraicSponge,
 };
 use snarkvm_curves::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
@@ -65,6 +66,11 @@ impl<E: PairingEngine> UniversalParams<E> {
         self.powers.write().download_powers_for(range)
     }
 
+    /// Returns the number of contiguous powers of beta G that are currently loaded.
+    pub fn num_loaded_powers(&self) -> usize {
+        self.powers.read().num_powers()
+    }
+
     pub fn lagrange_basis(&self, domain: EvaluationDomain<E::Fr>) -> Result<Vec<E::G1Affine>> {
         let basis = domain
             .ifft(&self.powers_of_beta_g(0, domain.size())?.iter().map(|e| (*e).to_projective()).collect::<Vec<_>>());
@@ -153,6 +159,8 @@ pub struct Powers<'a, E: PairingEngine> {
     pub powers_of_beta_g: Cow<'a, [E::G1Affine]>,
     /// Group elements of the form `β^i γG`, for different values of `i`.
     pub powers_of_beta_times_gamma_g: Cow<'a, [E::G1Affine]>,
+    /// The precomputed window tables for a prefix of `powers_of_beta_g`, if any.
+    pub precomputed_powers_of_beta_g: Option<&'a PrecomputedBases<E::G1Affine>>,
 }
 
 impl<E: PairingEngine> Powers<'_, E> {
@@ -112,10 +112,8 @@ impl<E: PairingEngine> KZG10<E> {
             Polynomial::Dense(polynomial) => {
                 let (num_leading_zeros, plain_coeffs) = skip_leading_zeros_and_convert_to_bigints(polynomial);
 
-                let bases = &powers.powers_of_beta_g[num_leading_zeros..(num_leading_zeros + plain_coeffs.len())];
-
                 let msm_time = start_timer!(|| "MSM to compute commitment to plaintext poly");
-                let commitment = VariableBase::msm(bases, &plain_coeffs);
+                let commitment = Self::msm_powers_of_beta_g(powers, num_leading_zeros, &plain_coeffs);
                 end_timer!(msm_time);
 
                 commitment
@@ -245,10 +243,8 @@ impl<E: PairingEngine> KZG10<E> {
         Self::check_degree_is_too_large(witness_polynomial.degree(), powers.size())?;
         let (num_leading_zeros, witness_coeffs) = skip_leading_zeros_and_convert_to_bigints(witness_polynomial);
 
-        let bases = &powers.powers_of_beta_g[num_leading_zeros..(num_leading_zeros + witness_coeffs.len())];
-
         let witness_comm_time = start_timer!(|| "Computing commitment to witness polynomial");
-        let mut w = VariableBase::msm(bases, &witness_coeffs);
+        let mut w = Self::msm_powers_of_beta_g(powers, num_leading_zeros, &witness_coeffs);
         end_timer!(witness_comm_time);
 
         let random_v = if let Some(hiding_witness_polynomial) = hiding_witness_polynomial {
@@ -400,6 +396,19 @@ impl<E: PairingEngine> KZG10<E> {
         Ok(result)
     }
 
+    /// Returns the MSM of the given scalars with `powers_of_beta_g`, starting at the given offset.
+    /// This uses the precomputed window tables of the powers, if they cover the required powers.
+    fn msm_powers_of_beta_g(
+        powers: &Powers<E>,
+        offset: usize,
+        scalars: &[<E::Fr as PrimeField>::BigInteger],
+    ) -> E::G1Projective {
+        match powers.precomputed_powers_of_beta_g {
+            Some(precomputed) if offset + scalars.len() <= precomputed.num_bases() => precomputed.msm(offset, scalars),
+            _ => VariableBase::msm(&powers.powers_of_beta_g[offset..(offset + scalars.len())], scalars),
+        }
+    }
+
     pub(crate) fn check_degree_is_too_large(degree: usize, num_powers: usize) -> Result<(), PCError> {
         let num_coefficients = degree + 1;
         if num_coefficients > num_powers {
@@ -502,6 +511,7 @@ mod tests {
             let powers = Powers {
                 powers_of_beta_g: Cow::Owned(powers_of_beta_g),
                 powers_of_beta_times_gamma_g: Cow::Owned(powers_of_beta_times_gamma_g),
+                precomputed_powers_of_beta_g: None,
             };
             let vk = VerifierKey {
                 g: pp.power_of_beta_g(0).unwrap(),
@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{
+    BatchProof,
+    Commitment,
+    CommitterKey,
+    CommitterUnionKey,
+    Evaluations,
+    LabeledCommitment,
+    LabeledPolynomial,
+    QuerySet,
+    Randomness,
+    SonicKZG10,
+};
+use crate::{
+    fft::DensePolynomial,
+    polycommit::PCError,
+    srs::{UniversalProver, UniversalVerifier},
+    AlgebraicSponge,
+};
+use snarkvm_curves::PairingEngine;
+
+use anyhow::anyhow;
+
+/// The domain separator for standalone batch openings.
+const BATCH_OPENING_PROTOCOL_NAME: &[u8] = b"SonicKZG10-BatchOpening-2023";
+
+impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
+    /// Commits to the given polynomials, without hiding or degree bounds.
+    ///
+    /// Together with `open_at_points` and `check_at_points`, this provides a deterministic interface
+    /// for protocols other than Varuna (e.g. vector commitments) to use the universal SRS.
+    pub fn commit_deterministic(
+        universal_prover: &UniversalProver<E>,
+        ck: &CommitterKey<E>,
+        polynomials: &[DensePolynomial<E::Fr>],
+    ) -> Result<Vec<Commitment<E>>, PCError> {
+        let ck = CommitterUnionKey::union(std::iter::once(ck));
+        let polynomials = Self::label_polynomials(polynomials);
+        let (commitments, _) = Self::commit(universal_prover, &ck, polynomials.iter().map(Into::into), None)?;
+        Ok(commitments.into_iter().map(|commitment| *commitment.commitment()).collect())
+    }
+
+    /// Returns the evaluations of each polynomial at each point, and a proof of these evaluations.
+    /// In detail, `evaluations[i][j]` is the evaluation of `polynomials[i]` at `points[j]`.
+    ///
+    /// The opening challenges are derived from the commitments, points, and evaluations,
+    /// so the proof is deterministic, and may be checked with `check_at_points`.
+    pub fn open_at_points(
+        universal_prover: &UniversalProver<E>,
+        ck: &CommitterKey<E>,
+        fs_parameters: &S::Parameters,
+        polynomials: &[DensePolynomial<E::Fr>],
+        commitments: &[Commitment<E>],
+        points: &[E::Fr],
+    ) -> Result<(Vec<Vec<E::Fr>>, BatchProof<E>), PCError> {
+        if polynomials.len() != commitments.len() {
+            return Err(PCError::AnyhowError(anyhow!("Mismatched number of polynomials and commitments")));
+        }
+        if points.is_empty() {
+            return Err(PCError::AnyhowError(anyhow!("Cannot open polynomials at an empty set of points")));
+        }
+
+        // Evaluate the polynomials at the points.
+        let evaluations: Vec<Vec<_>> = polynomials
+            .iter()
+            .map(|polynomial| points.iter().map(|point| polynomial.evaluate(*point)).collect())
+            .collect();
+
+        // Construct the query set, and the sponge.
+        let ck = CommitterUnionKey::union(std::iter::once(ck));
+        let polynomials = Self::label_polynomials(polynomials);
+        let commitments = Self::label_commitments(commitments);
+        let query_set = Self::query_set_for(polynomials.len(), points);
+        let randomness = vec![Randomness::<E>::empty(); polynomials.len()];
+        let mut sponge = Self::init_sponge_for_opening(fs_parameters, &commitments, points, &evaluations);
+
+        // Open the polynomials.
+        let proof =
+            Self::batch_open(universal_prover, &ck, &polynomials, &commitments, &query_set, &randomness, &mut sponge)?;
+        Ok((evaluations, proof))
+    }
+
+    /// Returns `true` if `evaluations[i][j]` is the evaluation of the polynomial committed to in
+    /// `commitments[i]` at `points[j]`, for the proof produced by `open_at_points`.
+    pub fn check_at_points(
+        vk: &UniversalVerifier<E>,
+        fs_parameters: &S::Parameters,
+        commitments: &[Commitment<E>],
+        points: &[E::Fr],
+        evaluations: &[Vec<E::Fr>],
+        proof: &BatchProof<E>,
+    ) -> Result<bool, PCError> {
+        if commitments.len() != evaluations.len() || evaluations.iter().any(|evals| evals.len() != points.len()) {
+            return Err(PCError::AnyhowError(anyhow!("Mismatched number of commitments, points, and evaluations")));
+        }
+        if points.is_empty() || proof.0.len() != points.len() {
+            return Ok(false);
+        }
+
+        // Construct the query set, the claimed evaluations, and the sponge.
+        let commitments = Self::label_commitments(commitments);
+        let query_set = Self::query_set_for(commitments.len(), points);
+        let mut values = Evaluations::new();
+        for (i, evals) in evaluations.iter().enumerate() {
+            for (point, value) in points.iter().zip(evals) {
+                values.insert((Self::polynomial_label(i), *point), *value);
+            }
+        }
+        let mut sponge = Self::init_sponge_for_opening(fs_parameters, &commitments, points, evaluations);
+
+        // Check the proof.
+        Self::batch_check(vk, &commitments, &query_set, &values, proof, &mut sponge)
+    }
+
+    /// Returns the label of the `index`-th polynomial in a standalone batch opening.
+    fn polynomial_label(index: usize) -> String {
+        format!("polynomial_{index}")
+    }
+
+    fn label_polynomials(polynomials: &[DensePolynomial<E::Fr>]) -> Vec<LabeledPolynomial<E::Fr>> {
+        polynomials
+            .iter()
+            .enumerate()
+            .map(|(i, polynomial)| LabeledPolynomial::new(Self::polynomial_label(i), polynomial.clone(), None, None))
+            .collect()
+    }
+
+    fn label_commitments(commitments: &[Commitment<E>]) -> Vec<LabeledCommitment<Commitment<E>>> {
+        commitments
+            .iter()
+            .enumerate()
+            .map(|(i, commitment)| LabeledCommitment::new(Self::polynomial_label(i), *commitment, None))
+            .collect()
+    }
+
+    /// Returns the query set that opens each of the `num_polynomials` polynomials at every point.
+    fn query_set_for(num_polynomials: usize, points: &[E::Fr]) -> QuerySet<E::Fr> {
+        let mut query_set = QuerySet::new();
+        for i in 0..num_polynomials {
+            for (j, point) in points.iter().enumerate() {
+                query_set.insert((Self::polynomial_label(i), (format!("point_{j}"), *point)));
+            }
+        }
+        query_set
+    }
+
+    /// Initializes a sponge that has absorbed the statement of a standalone batch opening.
+    fn init_sponge_for_opening(
+        fs_parameters: &S::Parameters,
+        commitments: &[LabeledCommitment<Commitment<E>>],
+        points: &[E::Fr],
+        evaluations: &[Vec<E::Fr>],
+    ) -> S {
+        let mut sponge = S::new_with_parameters(fs_parameters);
+        sponge.absorb_bytes(BATCH_OPENING_PROTOCOL_NAME);
+        sponge.absorb_bytes(&(commitments.len() as u64).to_le_bytes());
+        sponge.absorb_bytes(&(points.len() as u64).to_le_bytes());
+        let commitments = commitments.iter().map(|commitment| *commitment.commitment()).collect::<Vec<_>>();
+        sponge.absorb_native_field_elements(&commitments);
+        sponge.absorb_nonnative_field_elements(points.iter().copied());
+        sponge.absorb_nonnative_field_elements(evaluations.iter().flatten().copied());
+        sponge
+    }
+}
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use super::{LabeledPolynomial, PolynomialInfo};
-use crate::{crypto_hash::sha256::sha256, fft::EvaluationDomain, polycommit::kzg10};
+use crate::{crypto_hash::sha256::sha256, fft::EvaluationDomain, msm::PrecomputedBases, polycommit::kzg10};
 use snarkvm_curves::PairingEngine;
 use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
 use snarkvm_utilities::{error, serialize::*, FromBytes, ToBytes};
@@ -294,6 +294,9 @@ pub struct CommitterUnionKey<'a, E: PairingEngine> {
     /// Sorted in ascending order from smallest bound to largest bound.
     /// This is `None` if `self` does not support enforcing any degree bounds.
     pub enforced_degree_bounds: Option<Vec<usize>>,
+
+    /// The precomputed window tables for a prefix of `powers_of_beta_g`, if any.
+    pub precomputed_powers_of_beta_g: Option<&'a PrecomputedBases<E::G1Affine>>,
 }
 
 impl<'a, E: PairingEngine> CommitterUnionKey<'a, E> {
@@ -302,6 +305,7 @@ impl<'a, E: PairingEngine> CommitterUnionKey<'a, E> {
         kzg10::Powers {
             powers_of_beta_g: self.powers_of_beta_g.unwrap().as_slice().into(),
             powers_of_beta_times_gamma_g: self.powers_of_beta_times_gamma_g.unwrap().as_slice().into(),
+            precomputed_powers_of_beta_g: self.precomputed_powers_of_beta_g,
         }
     }
 
@@ -320,6 +324,7 @@ impl<'a, E: PairingEngine> CommitterUnionKey<'a, E> {
                 let ck = kzg10::Powers {
                     powers_of_beta_g: shifted_powers_of_beta_g[powers_range].into(),
                     powers_of_beta_times_gamma_g: shifted_powers_of_beta_times_gamma_g[&bound].clone().into(),
+                    precomputed_powers_of_beta_g: None,
                 };
 
                 Some(ck)
@@ -347,6 +352,7 @@ impl<'a, E: PairingEngine> CommitterUnionKey<'a, E> {
             shifted_powers_of_beta_g: None,
             shifted_powers_of_beta_times_gamma_g: None,
             enforced_degree_bounds: None,
+            precomputed_powers_of_beta_g: None,
         };
         let mut enforced_degree_bounds = vec![];
         let mut biggest_ck: Option<&CommitterKey<E>> = None;
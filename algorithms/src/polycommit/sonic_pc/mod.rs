@@ -32,6 +32,8 @@ use std::{
     collections::{BTreeMap, BTreeSet},
 };
 
+mod batch_opening;
+
 mod data_structures;
 pub use data_structures::*;
 
@@ -179,6 +181,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         polynomials: impl IntoIterator<Item = LabeledPolynomialWithBasis<'b, E::Fr>>,
         rng: Option<&mut dyn RngCore>,
     ) -> Result<(Vec<LabeledCommitment<Commitment<E>>>, Vec<Randomness<E>>), PCError> {
+        let _span = crate::instrument!("commit");
         let rng = &mut OptionalRng(rng);
         let commit_time = start_timer!(|| "Committing to polynomials");
         let mut labeled_comms: Vec<LabeledCommitment<Commitment<E>>> = Vec::new();
@@ -359,6 +362,22 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool, PCError>
+    where
+        Commitment<E>: 'a,
+    {
+        Self::prepare_batch_check(vk, commitments, query_set, values, proof, fs_rng)?.verify(vk)
+    }
+
+    /// Returns the pairing check for the given batch opening, without performing the pairings,
+    /// so that it can be combined with the pairing checks of other proofs.
+    pub fn prepare_batch_check<'a>(
+        vk: &UniversalVerifier<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        proof: &BatchProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<PairingCheck<E>, PCError>
     where
         Commitment<E>: 'a,
     {
@@ -414,9 +433,9 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
         }
 
-        let result = Self::check_elems(vk, combined_comms, combined_witness, combined_adjusted_witness);
+        let check = PairingCheck::new(combined_comms, combined_witness, combined_adjusted_witness);
         end_timer!(batch_check_time);
-        result
+        Ok(check)
     }
 
     pub fn open_combinations<'a>(
@@ -433,6 +452,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         Randomness<E>: 'a,
         Commitment<E>: 'a,
     {
+        let _span = crate::instrument!("open");
         let label_map = polynomials
             .into_iter()
             .zip_eq(rands)
@@ -513,6 +533,25 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
     where
         Commitment<E>: 'a,
     {
+        Self::prepare_check_combinations(vk, linear_combinations, commitments, query_set, evaluations, proof, fs_rng)?
+            .verify(vk)
+    }
+
+    /// Returns the pairing check for the given linear combinations, without performing the pairings,
+    /// so that it can be combined with the pairing checks of other proofs.
+    pub fn prepare_check_combinations<'a>(
+        vk: &UniversalVerifier<E>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        evaluations: &Evaluations<E::Fr>,
+        proof: &BatchLCProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<PairingCheck<E>, PCError>
+    where
+        Commitment<E>: 'a,
+    {
+        let _span = crate::instrument!("check_opening");
         let BatchLCProof { proof, .. } = proof;
         let label_comm_map = commitments.into_iter().map(|c| (c.label(), c)).collect::<BTreeMap<_, _>>();
 
@@ -566,7 +605,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             .collect::<Vec<_>>();
         end_timer!(combined_comms_norm_time);
 
-        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+        Self::prepare_batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
     }
 }
 
@@ -657,37 +696,69 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         *combined_adjusted_witness += VariableBase::msm(&bases, &coeffs);
         end_timer!(acc_time);
     }
+}
 
-    #[allow(clippy::type_complexity)]
-    fn check_elems(
-        vk: &UniversalVerifier<E>,
-        combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
+/// The final pairing check of one or more batch openings, as the G1 elements to pair with each prepared G2 element
+/// of the universal verifier. The check holds if the product of the pairings is one.
+///
+/// The G2 elements are shared by every proof, so the checks of many proofs can be combined with random coefficients,
+/// and performed with a single multi-pairing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PairingCheck<E: PairingEngine> {
+    /// The G1 elements to pair with `h`, or with the negative power of `beta_h` for the degree bound, if any.
+    h_elems: BTreeMap<Option<usize>, E::G1Projective>,
+    /// The G1 element to pair with `beta_h`.
+    beta_h_elem: E::G1Projective,
+}
+
+impl<E: PairingEngine> PairingCheck<E> {
+    /// Initializes the pairing check from the accumulated elements of a batch opening.
+    fn new(
+        mut combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
         combined_witness: E::G1Projective,
         combined_adjusted_witness: E::G1Projective,
-    ) -> Result<bool, PCError> {
+    ) -> Self {
+        // Note: The commitments without a degree bound and the adjusted witness are both paired with `h`.
+        *combined_comms.entry(None).or_insert_with(E::G1Projective::zero) -= &combined_adjusted_witness;
+        Self { h_elems: combined_comms, beta_h_elem: -combined_witness }
+    }
+
+    /// Combines the given pairing checks with the given coefficients into one pairing check,
+    /// which holds (with overwhelming probability, for random coefficients) if and only if every given check holds.
+    pub fn combine(checks: impl IntoIterator<Item = (E::Fr, Self)>) -> Self {
+        let mut h_elems = BTreeMap::new();
+        let mut beta_h_elem = E::G1Projective::zero();
+        for (coefficient, check) in checks {
+            for (degree_bound, elem) in check.h_elems {
+                *h_elems.entry(degree_bound).or_insert_with(E::G1Projective::zero) += &elem.mul(coefficient);
+            }
+            beta_h_elem += &check.beta_h_elem.mul(coefficient);
+        }
+        Self { h_elems, beta_h_elem }
+    }
+
+    /// Returns `true` if the pairing check holds for the given universal verifier.
+    pub fn verify(&self, vk: &UniversalVerifier<E>) -> Result<bool, PCError> {
         let check_time = start_timer!(|| "Checking elems");
-        let mut g1_projective_elems = Vec::with_capacity(combined_comms.len() + 2);
-        let mut g2_prepared_elems = Vec::with_capacity(combined_comms.len() + 2);
+        let mut g1_projective_elems = Vec::with_capacity(self.h_elems.len() + 1);
+        let mut g2_prepared_elems = Vec::with_capacity(self.h_elems.len() + 1);
 
-        for (degree_bound, comm) in combined_comms.into_iter() {
+        for (degree_bound, elem) in &self.h_elems {
             let shift_power = if let Some(degree_bound) = degree_bound {
                 // Find the appropriate prepared shift for the degree bound.
                 vk.prepared_negative_powers_of_beta_h
-                    .get(&degree_bound)
+                    .get(degree_bound)
                     .cloned()
-                    .ok_or(PCError::UnsupportedDegreeBound(degree_bound))?
+                    .ok_or(PCError::UnsupportedDegreeBound(*degree_bound))?
             } else {
                 vk.vk.prepared_h.clone()
             };
 
-            g1_projective_elems.push(comm);
+            g1_projective_elems.push(*elem);
             g2_prepared_elems.push(shift_power);
         }
 
-        g1_projective_elems.push(-combined_adjusted_witness);
-        g2_prepared_elems.push(vk.vk.prepared_h.clone());
-
-        g1_projective_elems.push(-combined_witness);
+        g1_projective_elems.push(self.beta_h_elem);
         g2_prepared_elems.push(vk.vk.prepared_beta_h.clone());
 
         let g1_prepared_elems_iter = E::G1Projective::batch_normalization_into_affine(g1_projective_elems)
@@ -808,4 +879,53 @@ mod tests {
             .expect("test failed for bls12-377");
         println!("Finished bls12-377");
     }
+
+    #[test]
+    fn test_open_at_points() {
+        use crate::{fft::DensePolynomial, AlgebraicSponge};
+        use snarkvm_curves::bls12_377::Fr;
+        use snarkvm_fields::One;
+        use snarkvm_utilities::Uniform;
+
+        let rng = &mut TestRng::default();
+        let max_degree = 64;
+        let pp = PC_Bls12_377::load_srs(max_degree).unwrap();
+        let universal_prover = &pp.to_universal_prover().unwrap();
+        let (ck, vk) = PC_Bls12_377::trim(&pp, max_degree, None, 0, None).unwrap();
+        let fs_parameters = Sponge::sample_parameters();
+
+        let polynomials = (0..5).map(|i| DensePolynomial::<Fr>::rand(10 * i + 1, rng)).collect::<Vec<_>>();
+        let points = (0..3).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+
+        // Commit to the polynomials, and open them at the points.
+        let commitments = PC_Bls12_377::commit_deterministic(universal_prover, &ck, &polynomials).unwrap();
+        let (evaluations, proof) =
+            PC_Bls12_377::open_at_points(universal_prover, &ck, &fs_parameters, &polynomials, &commitments, &points)
+                .unwrap();
+        assert_eq!(evaluations[2][1], polynomials[2].evaluate(points[1]));
+
+        // Ensure the commitments and the proof are deterministic.
+        assert_eq!(commitments, PC_Bls12_377::commit_deterministic(universal_prover, &ck, &polynomials).unwrap());
+        let (_, expected_proof) =
+            PC_Bls12_377::open_at_points(universal_prover, &ck, &fs_parameters, &polynomials, &commitments, &points)
+                .unwrap();
+        assert_eq!(proof, expected_proof);
+
+        // Ensure the proof verifies.
+        assert!(
+            PC_Bls12_377::check_at_points(&vk, &fs_parameters, &commitments, &points, &evaluations, &proof).unwrap()
+        );
+
+        // Ensure an incorrect evaluation is rejected.
+        let mut bad_evaluations = evaluations.clone();
+        bad_evaluations[0][0] += Fr::one();
+        assert!(!PC_Bls12_377::check_at_points(&vk, &fs_parameters, &commitments, &points, &bad_evaluations, &proof)
+            .unwrap());
+
+        // Ensure the proof does not verify at other points.
+        let mut bad_points = points.clone();
+        bad_points[2] = Fr::rand(rng);
+        assert!(!PC_Bls12_377::check_at_points(&vk, &fs_parameters, &commitments, &bad_points, &evaluations, &proof)
+            .unwrap());
+    }
 }
@@ -27,3 +27,6 @@ mod round_functions;
 
 mod state;
 pub(self) use state::*;
+
+mod witness_buffer;
+pub(self) use witness_buffer::*;
@@ -77,11 +77,17 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
 
             for (j, (private_vars, x_poly)) in itertools::izip!(private_variables, x_polys).enumerate() {
                 let w_label = witness_label(circuit.id, "w", j);
-                job_pool.add_job(move || Self::calculate_w(w_label, private_vars, x_poly, v_domain, i_domain, circuit));
+                job_pool.add_job(move || {
+                    let w = Self::calculate_w(w_label, private_vars.into_vec()?, x_poly, v_domain, i_domain, circuit);
+                    Ok::<_, anyhow::Error>(w)
+                });
             }
         }
-        let mut batches =
-            job_pool.execute_all().into_iter().map(|w_poly| prover::WitnessPoly(w_poly)).collect::<Vec<_>>();
+        let mut batches = job_pool
+            .execute_in_chunks(state.mode.max_concurrent_jobs())
+            .into_iter()
+            .map(|w_poly| w_poly.map(prover::WitnessPoly))
+            .collect::<Result<Vec<_>, _>>()?;
         assert_eq!(batches.len(), state.total_instances);
 
         let mut circuit_specific_batches = BTreeMap::new();
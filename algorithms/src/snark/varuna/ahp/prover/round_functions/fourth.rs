@@ -118,7 +118,7 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
         let mut sums = Vec::with_capacity(state.circuit_specific_states.len());
         let mut gs = BTreeMap::new();
         for ((circuit_a, results_a), (circuit_b, results_b), (circuit_c, results_c)) in
-            pool.execute_all().into_iter().tuples()
+            pool.execute_in_chunks(state.mode.max_concurrent_jobs()).into_iter().tuples()
         {
             assert_eq!(circuit_a, circuit_b);
             assert_eq!(circuit_a, circuit_c);
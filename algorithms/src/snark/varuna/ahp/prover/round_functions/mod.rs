@@ -18,6 +18,7 @@ use crate::{
     snark::varuna::{
         ahp::{indexer::Circuit, AHPError, AHPForR1CS},
         prover,
+        ProvingMode,
         SNARKMode,
     },
 };
@@ -46,6 +47,7 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
     /// Initialize the AHP prover.
     pub fn init_prover<'a, C: ConstraintSynthesizer<F>, R: Rng + CryptoRng>(
         circuits_to_constraints: &BTreeMap<&'a Circuit<F, MM>, &[C]>,
+        mode: ProvingMode,
         rng: &mut R,
     ) -> Result<prover::State<'a, F, MM>, AHPError> {
         let init_time = start_timer!(|| "AHP::Prover::Init");
@@ -130,7 +132,7 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
                         Self::formatted_public_input_is_admissible(&padded_public_variables)?;
 
                         let eval_z_a_time = start_timer!(|| format!("For {:?}, evaluating z_A_{_i}", circuit.id));
-                        let z_a = cfg_iter!(circuit.a)
+                        let z_a: Vec<F> = cfg_iter!(circuit.a)
                             .map(|row| {
                                 inner_product(&padded_public_variables, &private_variables, row, num_public_variables)
                             })
@@ -138,7 +140,7 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
                         end_timer!(eval_z_a_time);
 
                         let eval_z_b_time = start_timer!(|| format!("For {:?}, evaluating z_B_{_i}", circuit.id));
-                        let z_b = cfg_iter!(circuit.b)
+                        let z_b: Vec<F> = cfg_iter!(circuit.b)
                             .map(|row| {
                                 inner_product(&padded_public_variables, &private_variables, row, num_public_variables)
                             })
@@ -146,13 +148,19 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
                         end_timer!(eval_z_b_time);
 
                         let eval_z_c_time = start_timer!(|| format!("For {:?}, evaluating z_C_{_i}", circuit.id));
-                        let z_c = cfg_iter!(circuit.c)
+                        let z_c: Vec<F> = cfg_iter!(circuit.c)
                             .map(|row| {
                                 inner_product(&padded_public_variables, &private_variables, row, num_public_variables)
                             })
                             .collect();
                         end_timer!(eval_z_c_time);
 
+                        // In low-memory mode, the witness evaluations are stored on disk until they are needed.
+                        let private_variables = prover::WitnessBuffer::new(private_variables, mode)?;
+                        let z_a = prover::WitnessBuffer::new(z_a, mode)?;
+                        let z_b = prover::WitnessBuffer::new(z_b, mode)?;
+                        let z_c = prover::WitnessBuffer::new(z_c, mode)?;
+
                         end_timer!(init_time);
                         Ok(prover::Assignments::<F>(padded_public_variables, private_variables, z_a, z_b, z_c))
                     })
@@ -161,7 +169,7 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
             })
             .collect::<Result<BTreeMap<&'a Circuit<F, MM>, Vec<prover::Assignments<F>>>, AHPError>>()?;
 
-        let state = prover::State::initialize(indices_and_assignments)?;
+        let state = prover::State::initialize(indices_and_assignments, mode)?;
 
         Ok(state)
     }
@@ -77,6 +77,7 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
     ) -> Result<DensePolynomial<F>> {
         let mut job_pool = ExecutionPool::with_capacity(state.circuit_specific_states.len());
         let max_constraint_domain = state.max_constraint_domain;
+        let max_concurrent_jobs = state.mode.max_concurrent_jobs();
 
         for (circuit, circuit_specific_state) in state.circuit_specific_states.iter_mut() {
             let z_a = circuit_specific_state.z_a.take().unwrap();
@@ -99,9 +100,9 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
                     let za_label = witness_label(circuit.id, "z_a", j);
                     let zb_label = witness_label(circuit.id, "z_b", j);
                     let zc_label = witness_label(circuit.id, "z_c", j);
-                    let z_a = Self::calculate_z_m(za_label, z_a, constraint_domain, circuit);
-                    let z_b = Self::calculate_z_m(zb_label, z_b, constraint_domain, circuit);
-                    let z_c = Self::calculate_z_m(zc_label, z_c, constraint_domain, circuit);
+                    let z_a = Self::calculate_z_m(za_label, z_a.into_vec()?, constraint_domain, circuit);
+                    let z_b = Self::calculate_z_m(zb_label, z_b.into_vec()?, constraint_domain, circuit);
+                    let z_c = Self::calculate_z_m(zc_label, z_c.into_vec()?, constraint_domain, circuit);
                     let mut multiplier_2 = PolyMultiplier::new();
                     multiplier_2.add_precomputation(fft_precomputation, ifft_precomputation);
                     multiplier_2.add_polynomial(z_a, "z_a");
@@ -125,15 +126,18 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
         }
 
         let h_sum_time = start_timer!(|| "AHP::Prover::SecondRound h_sum");
-        let h_sum: DensePolynomial<F> =
-            cfg_reduce!(cfg_into_iter!(job_pool.execute_all()), || Ok(DensePolynomial::zero()), |a, b| {
+        let h_sum: DensePolynomial<F> = cfg_reduce!(
+            cfg_into_iter!(job_pool.execute_in_chunks(max_concurrent_jobs)),
+            || Ok(DensePolynomial::zero()),
+            |a, b| {
                 a.and_then(|a| {
                     b.map(|mut b| {
                         b += &a;
                         b
                     })
                 })
-            })?;
+            }
+        )?;
         end_timer!(h_sum_time);
 
         Ok(h_sum)
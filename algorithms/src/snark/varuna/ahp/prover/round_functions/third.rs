@@ -133,6 +133,7 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
         let num_instances = batch_combiners.values().map(|c| c.instance_combiners.len()).collect_vec();
         let total_instances = num_instances.iter().sum::<usize>();
         let max_variable_domain = &state.max_variable_domain;
+        let max_concurrent_jobs = state.mode.max_concurrent_jobs();
         let matrix_labels = ["a", "b", "c"];
         let matrix_combiners = [F::one(), *eta_b, *eta_c];
 
@@ -181,7 +182,7 @@ impl<F: PrimeField, MM: SNARKMode> AHPForR1CS<F, MM> {
         let mut xg_1_sum = DensePolynomial::zero();
         let mut circuit_index = 0;
         let mut instances_seen = 0;
-        for (i, linevals) in job_pool.execute_all().chunks_exact_mut(3).enumerate() {
+        for (i, linevals) in job_pool.execute_in_chunks(max_concurrent_jobs).chunks_exact_mut(3).enumerate() {
             if linevals[0].is_ok() && linevals[1].is_ok() && linevals[2].is_ok() {
                 let lineval_a = linevals[0].as_ref().unwrap();
                 let lineval_b = linevals[1].as_ref().unwrap();
@@ -17,7 +17,7 @@ use std::{collections::BTreeMap, sync::Arc};
 use crate::{
     fft::{DensePolynomial, EvaluationDomain, Evaluations as EvaluationsOnDomain},
     r1cs::{SynthesisError, SynthesisResult},
-    snark::varuna::{AHPError, AHPForR1CS, Circuit, SNARKMode},
+    snark::varuna::{AHPError, AHPForR1CS, Circuit, ProvingMode, SNARKMode},
 };
 use snarkvm_fields::PrimeField;
 
@@ -39,19 +39,19 @@ pub struct CircuitSpecificState<F: PrimeField> {
 
     /// The list of private variables for each instance in the batch.
     /// The length of this list must be equal to the batch size.
-    pub(super) private_variables: Vec<Vec<F>>,
+    pub(super) private_variables: Vec<super::WitnessBuffer<F>>,
 
     /// The list of Az vectors for each instance in the batch.
     /// The length of this list must be equal to the batch size.
-    pub(super) z_a: Option<Vec<Vec<F>>>,
+    pub(super) z_a: Option<Vec<super::WitnessBuffer<F>>>,
 
     /// The list of Bz vectors for each instance in the batch.
     /// The length of this list must be equal to the batch size.
-    pub(super) z_b: Option<Vec<Vec<F>>>,
+    pub(super) z_b: Option<Vec<super::WitnessBuffer<F>>>,
 
     /// The list of Cz vectors for each instance in the batch.
     /// The length of this list must be equal to the batch size.
-    pub(super) z_c: Option<Vec<Vec<F>>>,
+    pub(super) z_c: Option<Vec<super::WitnessBuffer<F>>>,
 
     /// A list of polynomials corresponding to the interpolation of the public input.
     /// The length of this list must be equal to the batch size.
@@ -76,20 +76,22 @@ pub struct State<'a, F: PrimeField, MM: SNARKMode> {
     pub(in crate::snark) max_variable_domain: EvaluationDomain<F>,
     /// The total number of instances we're proving in the batch.
     pub(in crate::snark) total_instances: usize,
+    /// The memory usage of the prover.
+    pub(in crate::snark) mode: ProvingMode,
 }
 
 /// The public inputs for a single instance.
 type PaddedPubInputs<F> = Vec<F>;
 /// The private inputs for a single instance.
-type PrivateInputs<F> = Vec<F>;
+type PrivateInputs<F> = super::WitnessBuffer<F>;
 /// The z_i_j*A_i vector for a single instance.
-type Za<F> = Vec<F>;
+type Za<F> = super::WitnessBuffer<F>;
 /// The z_i_j*B_i vector for a single instance.
-type Zb<F> = Vec<F>;
+type Zb<F> = super::WitnessBuffer<F>;
 /// The z_i_j*C_i vector for a single instance.
-type Zc<F> = Vec<F>;
+type Zc<F> = super::WitnessBuffer<F>;
 /// Assignments for a single instance.
-pub(super) struct Assignments<F>(
+pub(super) struct Assignments<F: PrimeField>(
     pub(super) PaddedPubInputs<F>,
     pub(super) PrivateInputs<F>,
     pub(super) Za<F>,
@@ -100,6 +102,7 @@ pub(super) struct Assignments<F>(
 impl<'a, F: PrimeField, MM: SNARKMode> State<'a, F, MM> {
     pub(super) fn initialize(
         indices_and_assignments: BTreeMap<&'a Circuit<F, MM>, Vec<Assignments<F>>>,
+        mode: ProvingMode,
     ) -> Result<Self, AHPError> {
         let mut max_non_zero_domain: Option<EvaluationDomain<F>> = None;
         let mut max_num_constraints = 0;
@@ -176,6 +179,7 @@ impl<'a, F: PrimeField, MM: SNARKMode> State<'a, F, MM> {
             circuit_specific_states,
             total_instances,
             first_round_oracles: None,
+            mode,
         })
     }
 
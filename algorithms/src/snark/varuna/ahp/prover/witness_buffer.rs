@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::snark::varuna::ProvingMode;
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::serialize::*;
+
+use anyhow::{anyhow, Result};
+use std::io::{BufReader, BufWriter};
+use tempfile::NamedTempFile;
+
+/// The evaluations of a witness vector, which are held in memory or in a disk-backed buffer.
+pub(super) enum WitnessBuffer<F: PrimeField> {
+    /// The evaluations are held in memory.
+    Memory(Vec<F>),
+    /// The evaluations are stored in a temporary file, which is removed when the buffer is dropped.
+    Disk(NamedTempFile),
+}
+
+impl<F: PrimeField> WitnessBuffer<F> {
+    /// Initializes a new witness buffer for the given evaluations.
+    /// In low-memory mode, the evaluations are written to disk, and released from memory.
+    /// Note: There is no file system on `wasm32`, so the evaluations are always held in memory there.
+    pub(super) fn new(evaluations: Vec<F>, mode: ProvingMode) -> Result<Self> {
+        if !mode.is_low_memory() || cfg!(target_family = "wasm") {
+            return Ok(Self::Memory(evaluations));
+        }
+        // Create a new temporary file, which is only accessible to the current user.
+        let file = tempfile::Builder::new().prefix("snarkvm-varuna-").suffix(".witness").tempfile()?;
+        // Write the evaluations to disk.
+        let mut writer = BufWriter::new(file.as_file());
+        evaluations
+            .serialize_uncompressed(&mut writer)
+            .map_err(|e| anyhow!("Failed to write the witness buffer to '{}' - {e}", file.path().display()))?;
+        std::io::Write::flush(&mut writer)?;
+        drop(writer);
+        Ok(Self::Disk(file))
+    }
+
+    /// Returns the evaluations, reading them from disk if necessary.
+    pub(super) fn into_vec(self) -> Result<Vec<F>> {
+        match self {
+            Self::Memory(evaluations) => Ok(evaluations),
+            Self::Disk(file) => {
+                let reader = BufReader::new(file.reopen()?);
+                // Note: The file was written by this process, so the elements do not need to be checked.
+                Vec::deserialize_uncompressed_unchecked(reader)
+                    .map_err(|e| anyhow!("Failed to read the witness buffer from '{}' - {e}", file.path().display()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    #[test]
+    fn test_witness_buffer_round_trip() {
+        let rng = &mut TestRng::default();
+        let evaluations = (0..1000).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+
+        for mode in [ProvingMode::Standard, ProvingMode::LowMemory] {
+            let buffer = WitnessBuffer::new(evaluations.clone(), mode).unwrap();
+            // Ensure the evaluations are only written to disk in low-memory mode.
+            let path = match &buffer {
+                WitnessBuffer::Memory(_) => None,
+                WitnessBuffer::Disk(file) => Some(file.path().to_path_buf()),
+            };
+            assert_eq!(path.is_some(), mode.is_low_memory());
+            assert_eq!(buffer.into_vec().unwrap(), evaluations);
+            // Ensure the temporary file is removed.
+            assert!(path.map_or(true, |path| !path.exists()));
+        }
+    }
+}
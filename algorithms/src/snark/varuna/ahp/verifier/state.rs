@@ -18,13 +18,83 @@ use crate::{
     fft::EvaluationDomain,
     snark::varuna::{
         ahp::verifier::{FirstMessage, FourthMessage, SecondMessage, ThirdMessage},
+        AHPError,
         CircuitId,
+        CircuitInfo,
         SNARKMode,
     },
 };
 use snarkvm_fields::PrimeField;
 use std::collections::BTreeMap;
 
+/// The evaluation domains of a circuit, which depend only on its verifying key.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CircuitDomains<F: PrimeField> {
+    pub(crate) input_domain: EvaluationDomain<F>,
+    pub(crate) variable_domain: EvaluationDomain<F>,
+    pub(crate) constraint_domain: EvaluationDomain<F>,
+    pub(crate) non_zero_a_domain: EvaluationDomain<F>,
+    pub(crate) non_zero_b_domain: EvaluationDomain<F>,
+    pub(crate) non_zero_c_domain: EvaluationDomain<F>,
+}
+
+impl<F: PrimeField> CircuitDomains<F> {
+    /// Constructs the evaluation domains for the given circuit.
+    pub fn new(circuit_info: &CircuitInfo) -> Result<Self, AHPError> {
+        let constraint_domain_time = start_timer!(|| "Constructing constraint domain");
+        let constraint_domain =
+            EvaluationDomain::new(circuit_info.num_constraints).ok_or(AHPError::PolynomialDegreeTooLarge)?;
+        end_timer!(constraint_domain_time);
+
+        let variable_domain_time = start_timer!(|| "Constructing variable domain");
+        let variable_domain =
+            EvaluationDomain::new(circuit_info.num_variables).ok_or(AHPError::PolynomialDegreeTooLarge)?;
+        end_timer!(variable_domain_time);
+
+        let non_zero_a_time = start_timer!(|| "Constructing non-zero-a domain");
+        let non_zero_a_domain =
+            EvaluationDomain::new(circuit_info.num_non_zero_a).ok_or(AHPError::PolynomialDegreeTooLarge)?;
+        end_timer!(non_zero_a_time);
+
+        let non_zero_b_time = start_timer!(|| "Constructing non-zero-b domain");
+        let non_zero_b_domain =
+            EvaluationDomain::new(circuit_info.num_non_zero_b).ok_or(AHPError::PolynomialDegreeTooLarge)?;
+        end_timer!(non_zero_b_time);
+
+        let non_zero_c_time = start_timer!(|| "Constructing non-zero-c domain");
+        let non_zero_c_domain =
+            EvaluationDomain::new(circuit_info.num_non_zero_c).ok_or(AHPError::PolynomialDegreeTooLarge)?;
+        end_timer!(non_zero_c_time);
+
+        let input_domain_time = start_timer!(|| "Constructing input domain");
+        let input_domain =
+            EvaluationDomain::new(circuit_info.num_public_inputs).ok_or(AHPError::PolynomialDegreeTooLarge)?;
+        end_timer!(input_domain_time);
+
+        Ok(Self {
+            input_domain,
+            variable_domain,
+            constraint_domain,
+            non_zero_a_domain,
+            non_zero_b_domain,
+            non_zero_c_domain,
+        })
+    }
+
+    /// Returns the input domain of the circuit.
+    pub const fn input_domain(&self) -> EvaluationDomain<F> {
+        self.input_domain
+    }
+
+    /// Returns the largest of the non-zero domains of the circuit.
+    pub fn max_non_zero_domain(&self) -> EvaluationDomain<F> {
+        [self.non_zero_a_domain, self.non_zero_b_domain, self.non_zero_c_domain]
+            .into_iter()
+            .max_by_key(|domain| domain.size())
+            .unwrap()
+    }
+}
+
 #[derive(Debug)]
 /// Circuit Specific State of the Verifier
 pub struct CircuitSpecificState<F: PrimeField> {
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::{
+    msm::PrecomputedBases,
     polycommit::sonic_pc,
     snark::varuna::{ahp::indexer::*, CircuitVerifyingKey, SNARKMode},
 };
@@ -27,7 +28,7 @@ use snarkvm_utilities::{
 use std::{cmp::Ordering, sync::Arc};
 
 /// Proving key for a specific circuit (i.e., R1CS matrices).
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug)]
 pub struct CircuitProvingKey<E: PairingEngine, MM: SNARKMode> {
     /// The circuit verifying key.
     pub circuit_verifying_key: CircuitVerifyingKey<E>,
@@ -38,6 +39,19 @@ pub struct CircuitProvingKey<E: PairingEngine, MM: SNARKMode> {
     pub circuit: Arc<Circuit<E::Fr, MM>>,
     /// The committer key for this index, trimmed from the universal SRS.
     pub committer_key: Arc<sonic_pc::CommitterKey<E>>,
+    /// The precomputed window tables for `committer_key.powers_of_beta_g`, if any.
+    /// Note: These are derived from the committer key, and are therefore not serialized.
+    pub precomputed_powers_of_beta_g: Option<Arc<PrecomputedBases<E::G1Affine>>>,
+}
+
+impl<E: PairingEngine, MM: SNARKMode> CircuitProvingKey<E, MM> {
+    /// Precomputes the window tables for the powers of beta in the committer key,
+    /// which speeds up the commitments in subsequent proofs at the cost of additional memory.
+    pub fn precompute_powers_of_beta_g(&mut self) {
+        let bases = &self.committer_key.powers_of_beta_g;
+        let window = PrecomputedBases::<E::G1Affine>::default_window(bases.len());
+        self.precomputed_powers_of_beta_g = Some(Arc::new(PrecomputedBases::new(bases, window)));
+    }
 }
 
 impl<E: PairingEngine, MM: SNARKMode> ToBytes for CircuitProvingKey<E, MM> {
@@ -58,10 +72,26 @@ impl<E: PairingEngine, MM: SNARKMode> FromBytes for CircuitProvingKey<E, MM> {
         let circuit = CanonicalDeserialize::deserialize_compressed(&mut reader)?;
         let committer_key = Arc::new(FromBytes::read_le(&mut reader)?);
 
-        Ok(Self { circuit_verifying_key, circuit_commitment_randomness, circuit, committer_key })
+        Ok(Self {
+            circuit_verifying_key,
+            circuit_commitment_randomness,
+            circuit,
+            committer_key,
+            precomputed_powers_of_beta_g: None,
+        })
     }
 }
 
+/// Proving keys are identified by their circuit ID, consistent with their ordering.
+/// Note: The precomputed window tables are derived from the committer key, and do not affect equality.
+impl<E: PairingEngine, MM: SNARKMode> PartialEq for CircuitProvingKey<E, MM> {
+    fn eq(&self, other: &Self) -> bool {
+        self.circuit.id == other.circuit.id
+    }
+}
+
+impl<E: PairingEngine, MM: SNARKMode> Eq for CircuitProvingKey<E, MM> {}
+
 impl<E: PairingEngine, MM: SNARKMode> Ord for CircuitProvingKey<E, MM> {
     fn cmp(&self, other: &Self) -> Ordering {
         self.circuit.id.cmp(&other.circuit.id)
@@ -24,6 +24,10 @@ pub use circuit_proving_key::*;
 pub(super) mod circuit_verifying_key;
 pub use circuit_verifying_key::*;
 
+/// The Varuna circuit verifying key, prepared for verification.
+pub(super) mod prepared_circuit_verifying_key;
+pub use prepared_circuit_verifying_key::*;
+
 /// The Varuna zkSNARK proof.
 pub(super) mod proof;
 pub use proof::*;
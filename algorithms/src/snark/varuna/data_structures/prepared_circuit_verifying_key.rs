@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    polycommit::sonic_pc::{Commitment, LabeledCommitment},
+    snark::varuna::{ahp::verifier::CircuitDomains, AHPForR1CS, CircuitVerifyingKey, VarunaHidingMode},
+    SNARKError,
+};
+use snarkvm_curves::PairingEngine;
+
+use core::{cmp::Ordering, ops::Deref};
+
+/// A circuit verifying key with the verifier precomputations for its circuit cached.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreparedCircuitVerifyingKey<E: PairingEngine> {
+    /// The original verifying key.
+    pub orig_vk: CircuitVerifyingKey<E>,
+    /// The evaluation domains of the circuit.
+    pub(crate) domains: CircuitDomains<E::Fr>,
+    /// The labeled commitments to the indexed polynomials, as checked in the final pairing.
+    pub(crate) index_commitments: Vec<LabeledCommitment<Commitment<E>>>,
+}
+
+impl<E: PairingEngine> PreparedCircuitVerifyingKey<E> {
+    /// Prepares the given verifying key for verification.
+    pub fn new(orig_vk: CircuitVerifyingKey<E>) -> Result<Self, SNARKError> {
+        // Construct the evaluation domains of the circuit.
+        let domains = CircuitDomains::new(&orig_vk.circuit_info)?;
+        // Label the commitments to the indexed polynomials (the labels do not depend on the SNARK mode).
+        let index_info = AHPForR1CS::<E::Fr, VarunaHidingMode>::index_polynomial_info(core::iter::once(&orig_vk.id));
+        if index_info.len() != orig_vk.circuit_commitments.len() {
+            return Err(SNARKError::Message("Mismatched number of circuit commitments".to_string()));
+        }
+        let index_commitments = orig_vk
+            .circuit_commitments
+            .iter()
+            .zip(index_info.values())
+            .map(|(commitment, info)| LabeledCommitment::new_with_info(info, *commitment))
+            .collect();
+        Ok(Self { orig_vk, domains, index_commitments })
+    }
+}
+
+impl<E: PairingEngine> Deref for PreparedCircuitVerifyingKey<E> {
+    type Target = CircuitVerifyingKey<E>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.orig_vk
+    }
+}
+
+impl<E: PairingEngine> Ord for PreparedCircuitVerifyingKey<E> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.orig_vk.cmp(&other.orig_vk)
+    }
+}
+
+impl<E: PairingEngine> PartialOrd for PreparedCircuitVerifyingKey<E> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -13,14 +13,17 @@
 // limitations under the License.
 
 use crate::{
-    polycommit::sonic_pc,
+    polycommit::{
+        kzg10::{KZGCommitment, KZGProof},
+        sonic_pc,
+    },
     snark::varuna::{ahp, CircuitId},
     SNARKError,
 };
 
-use ahp::prover::{FourthMessage, ThirdMessage};
+use ahp::prover::{FourthMessage, MatrixSums, ThirdMessage};
 use snarkvm_curves::PairingEngine;
-use snarkvm_fields::PrimeField;
+use snarkvm_fields::{PrimeField, Zero};
 use snarkvm_utilities::{
     error,
     io::{self, Read, Write},
@@ -269,6 +272,50 @@ impl<E: PairingEngine> Proof<E> {
         }
         Ok(&self.batch_sizes)
     }
+
+    /// Returns the compressed size in bytes of a proof for the given batch sizes, without computing the proof.
+    /// The size of a proof only depends on the number of circuits and instances, and on the hiding mode.
+    pub fn compressed_size_for(batch_sizes: &[usize], is_hiding: bool) -> usize {
+        let num_circuits = batch_sizes.len();
+        let total_instances = batch_sizes.iter().sum::<usize>();
+        let commitment = KZGCommitment::<E>(E::G1Affine::zero());
+        let proof = Self {
+            batch_sizes: batch_sizes.to_vec(),
+            commitments: Commitments {
+                witness_commitments: vec![WitnessCommitments { w: commitment }; total_instances],
+                mask_poly: is_hiding.then_some(commitment),
+                h_0: commitment,
+                g_1: commitment,
+                h_1: commitment,
+                g_a_commitments: vec![commitment; num_circuits],
+                g_b_commitments: vec![commitment; num_circuits],
+                g_c_commitments: vec![commitment; num_circuits],
+                h_2: commitment,
+            },
+            evaluations: Evaluations {
+                g_1_eval: E::Fr::zero(),
+                g_a_evals: vec![E::Fr::zero(); num_circuits],
+                g_b_evals: vec![E::Fr::zero(); num_circuits],
+                g_c_evals: vec![E::Fr::zero(); num_circuits],
+            },
+            third_msg: ThirdMessage {
+                sums: batch_sizes.iter().map(|batch_size| vec![MatrixSums::default(); *batch_size]).collect(),
+            },
+            fourth_msg: FourthMessage { sums: vec![MatrixSums::default(); num_circuits] },
+            // The polynomial commitment opens one proof for each query point (`alpha`, `beta`, and `gamma`),
+            // where only the opening at `beta` includes hiding polynomials.
+            pc_proof: sonic_pc::BatchLCProof {
+                proof: sonic_pc::BatchProof(
+                    [false, is_hiding, false]
+                        .into_iter()
+                        .map(|is_hiding| KZGProof { w: E::G1Affine::zero(), random_v: is_hiding.then(E::Fr::zero) })
+                        .collect(),
+                ),
+                evaluations: None,
+            },
+        };
+        proof.serialized_size(Compress::Yes)
+    }
 }
 
 impl<E: PairingEngine> CanonicalSerialize for Proof<E> {
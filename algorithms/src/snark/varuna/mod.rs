@@ -27,5 +27,9 @@ pub use varuna::*;
 mod mode;
 pub use mode::*;
 
+/// Specifies the memory usage of the prover.
+mod proving_mode;
+pub use proving_mode::*;
+
 #[cfg(test)]
 pub mod tests;
@@ -0,0 +1,40 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Specifies how the Varuna prover trades memory for time.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ProvingMode {
+    /// Holds all witness evaluations in memory, and computes the FFTs for all instances concurrently.
+    #[default]
+    Standard,
+    /// Stores the witness evaluations in disk-backed buffers until they are needed,
+    /// and computes the FFTs for one instance at a time, which bounds the peak memory
+    /// usage to that of a single instance, at the cost of additional proving time.
+    LowMemory,
+}
+
+impl ProvingMode {
+    /// Returns `true` if the witness evaluations are stored on disk.
+    pub const fn is_low_memory(&self) -> bool {
+        matches!(self, Self::LowMemory)
+    }
+
+    /// Returns the maximum number of prover jobs (each with their own FFTs) to run concurrently.
+    pub const fn max_concurrent_jobs(&self) -> usize {
+        match self {
+            Self::Standard => usize::MAX,
+            Self::LowMemory => 1,
+        }
+    }
+}
@@ -24,12 +24,17 @@ mod varuna {
         mode::SNARKMode,
         AHPForR1CS,
         CircuitVerifyingKey,
+        Proof,
         VarunaHidingMode,
         VarunaNonHidingMode,
         VarunaSNARK,
     };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
-    use snarkvm_utilities::rand::{TestRng, Uniform};
+    use snarkvm_utilities::{
+        rand::{TestRng, Uniform},
+        CanonicalSerialize,
+        Compress,
+    };
 
     type FS = crate::crypto_hash::PoseidonSponge<Fq, 2, 1>;
 
@@ -110,6 +115,10 @@ mod varuna {
                             let proof =
                                 $snark_inst::prove_batch(universal_prover, &fs_parameters, &pks_to_constraints, rng).unwrap();
                             println!("Called prover");
+                            assert_eq!(
+                                proof.serialized_size(Compress::Yes),
+                                Proof::<Bls12_377>::compressed_size_for(proof.batch_sizes().unwrap(), $snark_mode::ZK)
+                            );
 
                             assert!(
                                 $snark_inst::verify_batch(universal_verifier, &fs_parameters, &vks_to_inputs, &proof).unwrap(),
@@ -275,6 +284,86 @@ mod varuna {
         SonicPCTest::test_bincode(num_constraints, num_variables);
         SonicPCPoswTest::test_bincode(num_constraints, num_variables);
     }
+
+    #[test]
+    fn prove_and_verify_with_low_memory_mode() {
+        use crate::snark::varuna::ProvingMode;
+
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaSonicInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuits, inputs): (Vec<_>, Vec<_>) = (0..4).map(|_| TestCircuit::gen_rand(2, 50, 25, rng)).unzip();
+        let (index_pk, index_vk) = VarunaSonicInst::circuit_setup(&universal_srs, &circuits[0]).unwrap();
+        let pks_to_constraints = BTreeMap::from([(&index_pk, circuits.as_slice())]);
+        let vks_to_inputs = BTreeMap::from([(&index_vk, inputs.as_slice())]);
+
+        // Ensure a proof in low-memory mode verifies.
+        let proof = VarunaSonicInst::prove_batch_with_mode(
+            universal_prover,
+            &fs_parameters,
+            &pks_to_constraints,
+            ProvingMode::LowMemory,
+            rng,
+        )
+        .unwrap();
+        assert!(VarunaSonicInst::verify_batch(universal_verifier, &fs_parameters, &vks_to_inputs, &proof).unwrap());
+    }
+
+    #[test]
+    fn prove_and_verify_with_prepared_verifying_keys() {
+        use crate::snark::varuna::PreparedCircuitVerifyingKey;
+        use snarkvm_fields::One;
+
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaSonicInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        let (circuit_a, inputs_a) = TestCircuit::gen_rand(2, 50, 25, rng);
+        let (circuit_b, inputs_b) = TestCircuit::gen_rand(2, 80, 40, rng);
+        let (pk_a, vk_a) = VarunaSonicInst::circuit_setup(&universal_srs, &circuit_a).unwrap();
+        let (pk_b, vk_b) = VarunaSonicInst::circuit_setup(&universal_srs, &circuit_b).unwrap();
+
+        // Prepare the verifying keys once.
+        let prepared_a = PreparedCircuitVerifyingKey::new(vk_a.clone()).unwrap();
+        let prepared_b = PreparedCircuitVerifyingKey::new(vk_b.clone()).unwrap();
+        assert_eq!(*prepared_a, vk_a);
+
+        let (circuits_a, circuits_b) = ([circuit_a], [circuit_b]);
+        let (inputs_a, inputs_b) = ([inputs_a], [inputs_b]);
+        let pks_to_constraints = BTreeMap::from([(&pk_a, &circuits_a[..]), (&pk_b, &circuits_b[..])]);
+        let vks_to_inputs = BTreeMap::from([(&vk_a, &inputs_a[..]), (&vk_b, &inputs_b[..])]);
+        let prepared_to_inputs = BTreeMap::from([(&prepared_a, &inputs_a[..]), (&prepared_b, &inputs_b[..])]);
+
+        // Ensure the prepared keys verify the same proofs as the original keys.
+        for _ in 0..2 {
+            let proof =
+                VarunaSonicInst::prove_batch(universal_prover, &fs_parameters, &pks_to_constraints, rng).unwrap();
+            assert!(VarunaSonicInst::verify_batch(universal_verifier, &fs_parameters, &vks_to_inputs, &proof).unwrap());
+            assert!(VarunaSonicInst::verify_batch_prepared(
+                universal_verifier,
+                &fs_parameters,
+                &prepared_to_inputs,
+                &proof
+            )
+            .unwrap());
+        }
+
+        // Ensure the prepared keys reject invalid public inputs.
+        let proof = VarunaSonicInst::prove_batch(universal_prover, &fs_parameters, &pks_to_constraints, rng).unwrap();
+        let bad_inputs_a = [inputs_a[0].iter().map(|input| *input + Fr::one()).collect::<Vec<_>>()];
+        let bad_to_inputs = BTreeMap::from([(&prepared_a, &bad_inputs_a[..]), (&prepared_b, &inputs_b[..])]);
+        assert!(!VarunaSonicInst::verify_batch_prepared(universal_verifier, &fs_parameters, &bad_to_inputs, &proof)
+            .unwrap());
+    }
 }
 
 mod varuna_hiding {
@@ -20,19 +20,22 @@ use crate::{
         CommitterUnionKey,
         Evaluations,
         LabeledCommitment,
+        PairingCheck,
         QuerySet,
         Randomness,
         SonicKZG10,
     },
     r1cs::{ConstraintSynthesizer, SynthesisError},
     snark::varuna::{
-        ahp::{AHPError, AHPForR1CS, CircuitId, EvaluationsProvider},
+        ahp::{verifier::CircuitDomains, AHPError, AHPForR1CS, CircuitId, EvaluationsProvider},
         proof,
         prover,
         witness_label,
         CircuitProvingKey,
         CircuitVerifyingKey,
+        PreparedCircuitVerifyingKey,
         Proof,
+        ProvingMode,
         SNARKMode,
         UniversalSRS,
     },
@@ -44,7 +47,7 @@ use crate::{
 use rand::RngCore;
 use snarkvm_curves::PairingEngine;
 use snarkvm_fields::{One, PrimeField, ToConstraintField, Zero};
-use snarkvm_utilities::{to_bytes_le, ToBytes};
+use snarkvm_utilities::{to_bytes_le, ToBytes, Uniform};
 
 use anyhow::{anyhow, Result};
 use core::marker::PhantomData;
@@ -73,13 +76,17 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, MM: SNARKMode> VarunaSNARK
         universal_srs: &UniversalSRS<E>,
         circuits: &[&C],
     ) -> Result<Vec<(CircuitProvingKey<E, MM>, CircuitVerifyingKey<E>)>> {
+        let _span = crate::instrument!("circuit_setup", num_circuits = circuits.len());
         let index_time = start_timer!(|| "Varuna::CircuitSetup");
 
         let universal_prover = &universal_srs.to_universal_prover()?;
 
         let mut circuit_keys = Vec::with_capacity(circuits.len());
         for circuit in circuits {
-            let mut indexed_circuit = AHPForR1CS::<_, MM>::index(*circuit)?;
+            let mut indexed_circuit = {
+                let _span = crate::instrument!("index");
+                AHPForR1CS::<_, MM>::index(*circuit)?
+            };
             // TODO: Add check that c is in the correct mode.
             // Ensure the universal SRS supports the circuit size.
             universal_srs
@@ -119,6 +126,7 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, MM: SNARKMode> VarunaSNARK
                 circuit_commitment_randomness,
                 circuit: Arc::new(indexed_circuit),
                 committer_key: Arc::new(committer_key),
+                precomputed_powers_of_beta_g: None,
             };
             circuit_keys.push((circuit_proving_key, circuit_verifying_key));
         }
@@ -184,165 +192,20 @@ impl<E: PairingEngine, FS: AlgebraicSponge<E::Fq, 2>, MM: SNARKMode> VarunaSNARK
         }
         end_timer!(sponge_time);
     }
-}
-
-impl<E: PairingEngine, FS, MM> SNARK for VarunaSNARK<E, FS, MM>
-where
-    E::Fr: PrimeField,
-    E::Fq: PrimeField,
-    FS: AlgebraicSponge<E::Fq, 2>,
-    MM: SNARKMode,
-{
-    type BaseField = E::Fq;
-    type Certificate = Certificate<E>;
-    type FSParameters = FS::Parameters;
-    type FiatShamirRng = FS;
-    type Proof = Proof<E>;
-    type ProvingKey = CircuitProvingKey<E, MM>;
-    type ScalarField = E::Fr;
-    type UniversalProver = UniversalProver<E>;
-    type UniversalSRS = UniversalSRS<E>;
-    type UniversalVerifier = UniversalVerifier<E>;
-    type VerifierInput = [E::Fr];
-    type VerifyingKey = CircuitVerifyingKey<E>;
-
-    fn universal_setup(max_degree: usize) -> Result<Self::UniversalSRS, SNARKError> {
-        let setup_time = start_timer!(|| { format!("Varuna::UniversalSetup with max_degree {max_degree}",) });
-        let srs = SonicKZG10::<E, FS>::load_srs(max_degree).map_err(Into::into);
-        end_timer!(setup_time);
-        srs
-    }
-
-    /// Generates the circuit proving and verifying keys.
-    /// This is a deterministic algorithm that anyone can rerun.
-    fn circuit_setup<C: ConstraintSynthesizer<E::Fr>>(
-        universal_srs: &Self::UniversalSRS,
-        circuit: &C,
-    ) -> Result<(Self::ProvingKey, Self::VerifyingKey)> {
-        let mut circuit_keys = Self::batch_circuit_setup::<C>(universal_srs, &[circuit])?;
-        assert_eq!(circuit_keys.len(), 1);
-        Ok(circuit_keys.pop().unwrap())
-    }
-
-    /// Prove that the verifying key commitments commit to the indexed circuit's polynomials
-    fn prove_vk(
-        universal_prover: &Self::UniversalProver,
-        fs_parameters: &Self::FSParameters,
-        verifying_key: &Self::VerifyingKey,
-        proving_key: &Self::ProvingKey,
-    ) -> Result<Self::Certificate, SNARKError> {
-        // Initialize sponge
-        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, &verifying_key.circuit_commitments);
-        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
-        // The linear combination requires `num_polynomials - 1` coefficients
-        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
-        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
-        let point = challenges.pop().unwrap();
-        let one = E::Fr::one();
-        let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
-
-        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
-        let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
-        for (poly, &c) in proving_key.circuit.iter().zip(linear_combination_challenges) {
-            lc.add(c, poly.label());
-        }
-
-        let circuit_id = std::iter::once(&verifying_key.id);
-        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
-        let commitments = verifying_key
-            .iter()
-            .cloned()
-            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info(circuit_id).values())
-            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
-            .collect::<Vec<_>>();
-
-        let committer_key = CommitterUnionKey::union(std::iter::once(proving_key.committer_key.as_ref()));
-
-        let certificate = SonicKZG10::<E, FS>::open_combinations(
-            universal_prover,
-            &committer_key,
-            &[lc],
-            proving_key.circuit.iter(),
-            &commitments,
-            &query_set,
-            &proving_key.circuit_commitment_randomness.clone(),
-            &mut sponge,
-        )?;
-
-        Ok(Self::Certificate::new(certificate))
-    }
-
-    /// Verify that the verifying key commitments commit to the indexed circuit's polynomials
-    /// Verify that the verifying key's circuit_info is correct
-    fn verify_vk<C: ConstraintSynthesizer<Self::ScalarField>>(
-        universal_verifier: &Self::UniversalVerifier,
-        fs_parameters: &Self::FSParameters,
-        circuit: &C,
-        verifying_key: &Self::VerifyingKey,
-        certificate: &Self::Certificate,
-    ) -> Result<bool, SNARKError> {
-        // Ensure the VerifyingKey encodes the expected circuit.
-        let circuit_id = &verifying_key.id;
-        let state = AHPForR1CS::<E::Fr, MM>::index_helper(circuit)?;
-        if state.index_info != verifying_key.circuit_info {
-            return Err(SNARKError::CircuitNotFound);
-        }
-        if state.id != *circuit_id {
-            return Err(SNARKError::CircuitNotFound);
-        }
-
-        // Initialize sponge.
-        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, &verifying_key.circuit_commitments);
-
-        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
-        // The linear combination requires `num_polynomials - 1` coefficients
-        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
-        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
-        let point = challenges.pop().unwrap();
-        let one = E::Fr::one();
-        let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
-
-        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
-        let poly_info = AHPForR1CS::<E::Fr, MM>::index_polynomial_info(std::iter::once(circuit_id));
-        let evaluations_at_point = AHPForR1CS::<E::Fr, MM>::evaluate_index_polynomials(state, circuit_id, point)?;
-        let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
-        let mut evaluation = E::Fr::zero();
-        for ((label, &c), eval) in poly_info.keys().zip_eq(linear_combination_challenges).zip_eq(evaluations_at_point) {
-            lc.add(c, label.as_str());
-            evaluation += c * eval;
-        }
-
-        let commitments = verifying_key
-            .iter()
-            .cloned()
-            .zip_eq(poly_info.values())
-            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
-            .collect::<Vec<_>>();
-        let evaluations = Evaluations::from_iter([(("circuit_check".into(), point), evaluation)]);
-        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
-
-        SonicKZG10::<E, FS>::check_combinations(
-            universal_verifier,
-            &[lc],
-            &commitments,
-            &query_set,
-            &evaluations,
-            &certificate.pc_proof,
-            &mut sponge,
-        )
-        .map_err(Into::into)
-    }
 
-    #[allow(clippy::only_used_in_recursion)]
-    /// This is the main entrypoint for creating proofs.
+    /// Returns a proof for the given batch of proving keys and constraints, with the given proving mode.
+    /// In low-memory mode, the prover stores its witness evaluations on disk, and processes the FFTs
+    /// for one instance at a time, which makes it possible to prove large circuits on smaller machines.
     /// You can find a specification of the prover algorithm in:
     /// https://github.com/AleoHQ/protocol-docs/tree/main/snark/varuna
-    fn prove_batch<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
-        universal_prover: &Self::UniversalProver,
-        fs_parameters: &Self::FSParameters,
+    pub fn prove_batch_with_mode<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        universal_prover: &UniversalProver<E>,
+        fs_parameters: &FS::Parameters,
         keys_to_constraints: &BTreeMap<&CircuitProvingKey<E, MM>, &[C]>,
+        mode: ProvingMode,
         zk_rng: &mut R,
-    ) -> Result<Self::Proof, SNARKError> {
+    ) -> Result<Proof<E>, SNARKError> {
+        let _span = crate::instrument!("prove", num_circuits = keys_to_constraints.len());
         let prover_time = start_timer!(|| "Varuna::Prover");
         if keys_to_constraints.is_empty() {
             return Err(SNARKError::EmptyBatch);
@@ -352,11 +215,14 @@ where
         for (pk, constraints) in keys_to_constraints {
             circuits_to_constraints.insert(pk.circuit.deref(), *constraints);
         }
-        let prover_state = AHPForR1CS::<_, MM>::init_prover(&circuits_to_constraints, zk_rng)?;
+        let prover_state = {
+            let _span = crate::instrument!("synthesize");
+            AHPForR1CS::<_, MM>::init_prover(&circuits_to_constraints, mode, zk_rng)?
+        };
 
         // extract information from the prover key and state to consume in further calculations
         let mut batch_sizes = BTreeMap::new();
-        let mut circuit_infos = BTreeMap::new();
+        let mut circuit_domains = BTreeMap::new();
         let mut inputs_and_batch_sizes = BTreeMap::new();
         let mut total_instances = 0;
         let mut public_inputs = BTreeMap::new(); // inputs need to live longer than the rest of prover_state
@@ -369,7 +235,7 @@ where
                 prover_state.padded_public_inputs(&pk.circuit).ok_or(SNARKError::CircuitNotFound)?;
             let circuit_id = pk.circuit.id;
             batch_sizes.insert(circuit_id, batch_size);
-            circuit_infos.insert(circuit_id, &pk.circuit_verifying_key.circuit_info);
+            circuit_domains.insert(circuit_id, CircuitDomains::new(&pk.circuit_verifying_key.circuit_info)?);
             inputs_and_batch_sizes.insert(circuit_id, (batch_size, padded_public_input));
             total_instances += batch_size;
             public_inputs.insert(circuit_id, public_input);
@@ -377,7 +243,12 @@ where
         }
         assert_eq!(prover_state.total_instances, total_instances);
 
-        let committer_key = CommitterUnionKey::union(keys_to_constraints.keys().map(|pk| pk.committer_key.deref()));
+        let mut committer_key = CommitterUnionKey::union(keys_to_constraints.keys().map(|pk| pk.committer_key.deref()));
+        // The committer keys are all trimmed from the same SRS, so the largest precomputed table is valid for the union.
+        committer_key.precomputed_powers_of_beta_g = keys_to_constraints
+            .keys()
+            .filter_map(|pk| pk.precomputed_powers_of_beta_g.as_deref())
+            .max_by_key(|precomputed| precomputed.num_bases());
 
         let circuit_commitments =
             keys_to_constraints.keys().map(|pk| pk.circuit_verifying_key.circuit_commitments.as_slice());
@@ -405,7 +276,7 @@ where
 
         let (verifier_first_message, verifier_state) = AHPForR1CS::<_, MM>::verifier_first_round(
             &batch_sizes,
-            &circuit_infos,
+            &circuit_domains,
             prover_state.max_constraint_domain,
             prover_state.max_variable_domain,
             prover_state.max_non_zero_domain,
@@ -629,15 +500,67 @@ where
         Ok(proof)
     }
 
-    /// This is the main entrypoint for verifying proofs.
+    /// Returns `true` if the batch proof is valid for the given prepared verifying keys and public inputs.
+    /// Preparing a verifying key caches the verifier precomputations for its circuit,
+    /// so that callers verifying many proofs for the same circuits only perform them once.
     /// You can find a specification of the verifier algorithm in:
     /// https://github.com/AleoHQ/protocol-docs/tree/main/marlin
-    fn verify_batch<B: Borrow<Self::VerifierInput>>(
-        universal_verifier: &Self::UniversalVerifier,
-        fs_parameters: &Self::FSParameters,
-        keys_to_inputs: &BTreeMap<&Self::VerifyingKey, &[B]>,
-        proof: &Self::Proof,
+    pub fn verify_batch_prepared<B: Borrow<[E::Fr]>>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_inputs: &BTreeMap<&PreparedCircuitVerifyingKey<E>, &[B]>,
+        proof: &Proof<E>,
+    ) -> Result<bool, SNARKError> {
+        let _span = crate::instrument!("verify", num_circuits = keys_to_inputs.len());
+        let Some(check) = Self::prepare_batch_check(universal_verifier, fs_parameters, keys_to_inputs, proof)? else {
+            return Ok(false);
+        };
+        let evaluations_are_correct = check.verify(universal_verifier)?;
+        if !evaluations_are_correct {
+            #[cfg(debug_assertions)]
+            eprintln!("SonicKZG10::Check failed for the AHP verifier linear equations");
+        }
+        Ok(evaluations_are_correct)
+    }
+
+    /// Returns `true` if every batch proof is valid for its prepared verifying keys and public inputs.
+    /// The final pairing checks of the proofs are combined with random coefficients from the given RNG,
+    /// so that all of the proofs are checked with a single multi-pairing.
+    #[allow(clippy::type_complexity)]
+    pub fn verify_batches_prepared<B: Borrow<[E::Fr]>, R: Rng + CryptoRng>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        batches: &[(BTreeMap<&PreparedCircuitVerifyingKey<E>, &[B]>, &Proof<E>)],
+        rng: &mut R,
     ) -> Result<bool, SNARKError> {
+        let _span = crate::instrument!("verify_batches", num_proofs = batches.len());
+        if batches.is_empty() {
+            return Err(SNARKError::EmptyBatch);
+        }
+        // Prepare the pairing check of each proof.
+        let checks = batches
+            .iter()
+            .map(|(keys_to_inputs, proof)| {
+                Self::prepare_batch_check(universal_verifier, fs_parameters, keys_to_inputs, proof)
+            })
+            .collect::<Result<Option<Vec<_>>, _>>()?;
+        // If a proof has an incorrect hiding mode, the batch is invalid.
+        let Some(checks) = checks else {
+            return Ok(false);
+        };
+        // Combine the pairing checks with random coefficients, and check them at once.
+        let check = PairingCheck::combine(checks.into_iter().map(|check| (E::Fr::rand(rng), check)));
+        Ok(check.verify(universal_verifier)?)
+    }
+
+    /// Returns the final pairing check of the batch proof for the given prepared verifying keys and public inputs,
+    /// or `None` if the proof has an incorrect hiding mode.
+    fn prepare_batch_check<B: Borrow<[E::Fr]>>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_inputs: &BTreeMap<&PreparedCircuitVerifyingKey<E>, &[B]>,
+        proof: &Proof<E>,
+    ) -> Result<Option<PairingCheck<E>>, SNARKError> {
         if keys_to_inputs.is_empty() {
             return Err(SNARKError::EmptyBatch);
         }
@@ -663,18 +586,19 @@ where
         let mut public_inputs = BTreeMap::new();
         let mut padded_public_vec = Vec::with_capacity(keys_to_inputs.len());
         let mut inputs_and_batch_sizes = BTreeMap::new();
-        let mut input_domains = BTreeMap::new();
         let mut circuit_infos = BTreeMap::new();
+        let mut circuit_domains = BTreeMap::new();
         let mut circuit_ids = Vec::with_capacity(keys_to_inputs.len());
         for (vk, public_inputs_i) in keys_to_inputs.iter() {
             max_num_constraints = max_num_constraints.max(vk.circuit_info.num_constraints);
             max_num_variables = max_num_variables.max(vk.circuit_info.num_variables);
 
-            let non_zero_domains = AHPForR1CS::<_, MM>::cmp_non_zero_domains(&vk.circuit_info, max_non_zero_domain)?;
-            max_non_zero_domain = non_zero_domains.max_non_zero_domain;
+            let non_zero_domain = vk.domains.max_non_zero_domain();
+            if max_non_zero_domain.map_or(true, |max: EvaluationDomain<E::Fr>| max.size() <= non_zero_domain.size()) {
+                max_non_zero_domain = Some(non_zero_domain);
+            }
 
-            let input_domain = EvaluationDomain::<E::Fr>::new(vk.circuit_info.num_public_inputs).unwrap();
-            input_domains.insert(vk.id, input_domain);
+            let input_domain = vk.domains.input_domain();
 
             let (padded_public_inputs_i, parsed_public_inputs_i): (Vec<_>, Vec<_>) = {
                 public_inputs_i
@@ -696,6 +620,7 @@ where
             public_inputs.insert(circuit_id, parsed_public_inputs_i);
             padded_public_vec.push(padded_public_inputs_i);
             circuit_infos.insert(circuit_id, &vk.circuit_info);
+            circuit_domains.insert(circuit_id, vk.domains);
             circuit_ids.push(circuit_id);
         }
         for (i, (vk, &batch_size)) in keys_to_inputs.keys().zip(batch_sizes.values()).enumerate() {
@@ -718,7 +643,7 @@ where
                 "Found `mask_poly` in the first round when not expected, or proof has incorrect hiding mode ({})",
                 proof.pc_proof.is_hiding()
             );
-            return Ok(false);
+            return Ok(None);
         }
 
         let verifier_time = start_timer!(|| format!("Varuna::Verify with batch sizes: {:?}", batch_sizes));
@@ -788,7 +713,7 @@ where
         Self::absorb_labeled(&first_commitments, &mut sponge);
         let (_, verifier_state) = AHPForR1CS::<_, MM>::verifier_first_round(
             &batch_sizes,
-            &circuit_infos,
+            &circuit_domains,
             max_constraint_domain,
             max_variable_domain,
             max_non_zero_domain,
@@ -840,11 +765,9 @@ where
         // correct degree.
 
         // Gather commitments in one vector.
-        let commitments: Vec<_> = circuit_commitments
-            .into_iter()
-            .flatten()
-            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info(circuit_ids.iter()).values())
-            .map(|(c, info)| LabeledCommitment::new_with_info(info, *c))
+        let commitments: Vec<_> = keys_to_inputs
+            .keys()
+            .flat_map(|vk| vk.index_commitments.iter().cloned())
             .chain(first_commitments)
             .chain(second_commitments)
             .chain(third_commitments)
@@ -892,8 +815,8 @@ where
         )?;
         end_timer!(lc_time);
 
-        let pc_time = start_timer!(|| "Checking linear combinations with PC");
-        let evaluations_are_correct = SonicKZG10::<E, FS>::check_combinations(
+        let pc_time = start_timer!(|| "Preparing the linear combination check with PC");
+        let check = SonicKZG10::<E, FS>::prepare_check_combinations(
             universal_verifier,
             lc_s.values(),
             &commitments,
@@ -904,15 +827,190 @@ where
         )?;
         end_timer!(pc_time);
 
-        if !evaluations_are_correct {
-            #[cfg(debug_assertions)]
-            eprintln!("SonicKZG10::Check failed using final challenge: {:?}", verifier_state.gamma);
+        end_timer!(verifier_time);
+        Ok(Some(check))
+    }
+}
+
+impl<E: PairingEngine, FS, MM> SNARK for VarunaSNARK<E, FS, MM>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+    FS: AlgebraicSponge<E::Fq, 2>,
+    MM: SNARKMode,
+{
+    type BaseField = E::Fq;
+    type Certificate = Certificate<E>;
+    type FSParameters = FS::Parameters;
+    type FiatShamirRng = FS;
+    type Proof = Proof<E>;
+    type ProvingKey = CircuitProvingKey<E, MM>;
+    type ScalarField = E::Fr;
+    type UniversalProver = UniversalProver<E>;
+    type UniversalSRS = UniversalSRS<E>;
+    type UniversalVerifier = UniversalVerifier<E>;
+    type VerifierInput = [E::Fr];
+    type VerifyingKey = CircuitVerifyingKey<E>;
+
+    fn universal_setup(max_degree: usize) -> Result<Self::UniversalSRS, SNARKError> {
+        let setup_time = start_timer!(|| { format!("Varuna::UniversalSetup with max_degree {max_degree}",) });
+        let srs = SonicKZG10::<E, FS>::load_srs(max_degree).map_err(Into::into);
+        end_timer!(setup_time);
+        srs
+    }
+
+    /// Generates the circuit proving and verifying keys.
+    /// This is a deterministic algorithm that anyone can rerun.
+    fn circuit_setup<C: ConstraintSynthesizer<E::Fr>>(
+        universal_srs: &Self::UniversalSRS,
+        circuit: &C,
+    ) -> Result<(Self::ProvingKey, Self::VerifyingKey)> {
+        let mut circuit_keys = Self::batch_circuit_setup::<C>(universal_srs, &[circuit])?;
+        assert_eq!(circuit_keys.len(), 1);
+        Ok(circuit_keys.pop().unwrap())
+    }
+
+    /// Prove that the verifying key commitments commit to the indexed circuit's polynomials
+    fn prove_vk(
+        universal_prover: &Self::UniversalProver,
+        fs_parameters: &Self::FSParameters,
+        verifying_key: &Self::VerifyingKey,
+        proving_key: &Self::ProvingKey,
+    ) -> Result<Self::Certificate, SNARKError> {
+        // Initialize sponge
+        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, &verifying_key.circuit_commitments);
+        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
+        // The linear combination requires `num_polynomials - 1` coefficients
+        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
+        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
+        let point = challenges.pop().unwrap();
+        let one = E::Fr::one();
+        let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
+
+        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
+        let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
+        for (poly, &c) in proving_key.circuit.iter().zip(linear_combination_challenges) {
+            lc.add(c, poly.label());
+        }
+
+        let circuit_id = std::iter::once(&verifying_key.id);
+        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
+        let commitments = verifying_key
+            .iter()
+            .cloned()
+            .zip_eq(AHPForR1CS::<E::Fr, MM>::index_polynomial_info(circuit_id).values())
+            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
+            .collect::<Vec<_>>();
+
+        let committer_key = CommitterUnionKey::union(std::iter::once(proving_key.committer_key.as_ref()));
+
+        let certificate = SonicKZG10::<E, FS>::open_combinations(
+            universal_prover,
+            &committer_key,
+            &[lc],
+            proving_key.circuit.iter(),
+            &commitments,
+            &query_set,
+            &proving_key.circuit_commitment_randomness.clone(),
+            &mut sponge,
+        )?;
+
+        Ok(Self::Certificate::new(certificate))
+    }
+
+    /// Verify that the verifying key commitments commit to the indexed circuit's polynomials
+    /// Verify that the verifying key's circuit_info is correct
+    fn verify_vk<C: ConstraintSynthesizer<Self::ScalarField>>(
+        universal_verifier: &Self::UniversalVerifier,
+        fs_parameters: &Self::FSParameters,
+        circuit: &C,
+        verifying_key: &Self::VerifyingKey,
+        certificate: &Self::Certificate,
+    ) -> Result<bool, SNARKError> {
+        // Ensure the VerifyingKey encodes the expected circuit.
+        let circuit_id = &verifying_key.id;
+        let state = AHPForR1CS::<E::Fr, MM>::index_helper(circuit)?;
+        if state.index_info != verifying_key.circuit_info {
+            return Err(SNARKError::CircuitNotFound);
         }
+        if state.id != *circuit_id {
+            return Err(SNARKError::CircuitNotFound);
+        }
+
+        // Initialize sponge.
+        let mut sponge = Self::init_sponge_for_certificate(fs_parameters, &verifying_key.circuit_commitments);
 
-        end_timer!(verifier_time, || format!(
-            " SonicKZG10::Check for AHP Verifier linear equations: {}",
-            evaluations_are_correct & proof_has_correct_zk_mode
-        ));
-        Ok(evaluations_are_correct & proof_has_correct_zk_mode)
+        // Compute challenges for linear combination, and the point to evaluate the polynomials at.
+        // The linear combination requires `num_polynomials - 1` coefficients
+        // (since the first coeff is 1), and so we squeeze out `num_polynomials` points.
+        let mut challenges = sponge.squeeze_nonnative_field_elements(verifying_key.circuit_commitments.len());
+        let point = challenges.pop().unwrap();
+        let one = E::Fr::one();
+        let linear_combination_challenges = core::iter::once(&one).chain(challenges.iter());
+
+        // We will construct a linear combination and provide a proof of evaluation of the lc at `point`.
+        let poly_info = AHPForR1CS::<E::Fr, MM>::index_polynomial_info(std::iter::once(circuit_id));
+        let evaluations_at_point = AHPForR1CS::<E::Fr, MM>::evaluate_index_polynomials(state, circuit_id, point)?;
+        let mut lc = crate::polycommit::sonic_pc::LinearCombination::empty("circuit_check");
+        let mut evaluation = E::Fr::zero();
+        for ((label, &c), eval) in poly_info.keys().zip_eq(linear_combination_challenges).zip_eq(evaluations_at_point) {
+            lc.add(c, label.as_str());
+            evaluation += c * eval;
+        }
+
+        let commitments = verifying_key
+            .iter()
+            .cloned()
+            .zip_eq(poly_info.values())
+            .map(|(c, info)| LabeledCommitment::new_with_info(info, c))
+            .collect::<Vec<_>>();
+        let evaluations = Evaluations::from_iter([(("circuit_check".into(), point), evaluation)]);
+        let query_set = QuerySet::from_iter([("circuit_check".into(), ("challenge".into(), point))]);
+
+        SonicKZG10::<E, FS>::check_combinations(
+            universal_verifier,
+            &[lc],
+            &commitments,
+            &query_set,
+            &evaluations,
+            &certificate.pc_proof,
+            &mut sponge,
+        )
+        .map_err(Into::into)
+    }
+
+    #[allow(clippy::only_used_in_recursion)]
+    /// This is the main entrypoint for creating proofs.
+    /// You can find a specification of the prover algorithm in:
+    /// https://github.com/AleoHQ/protocol-docs/tree/main/snark/varuna
+    fn prove_batch<C: ConstraintSynthesizer<E::Fr>, R: Rng + CryptoRng>(
+        universal_prover: &Self::UniversalProver,
+        fs_parameters: &Self::FSParameters,
+        keys_to_constraints: &BTreeMap<&CircuitProvingKey<E, MM>, &[C]>,
+        zk_rng: &mut R,
+    ) -> Result<Self::Proof, SNARKError> {
+        Self::prove_batch_with_mode(universal_prover, fs_parameters, keys_to_constraints, ProvingMode::Standard, zk_rng)
+    }
+
+    /// This is the main entrypoint for verifying proofs.
+    /// You can find a specification of the verifier algorithm in:
+    /// https://github.com/AleoHQ/protocol-docs/tree/main/marlin
+    fn verify_batch<B: Borrow<Self::VerifierInput>>(
+        universal_verifier: &Self::UniversalVerifier,
+        fs_parameters: &Self::FSParameters,
+        keys_to_inputs: &BTreeMap<&Self::VerifyingKey, &[B]>,
+        proof: &Self::Proof,
+    ) -> Result<bool, SNARKError> {
+        // Prepare the verifying keys.
+        let prepared_keys = keys_to_inputs
+            .keys()
+            .map(|vk| PreparedCircuitVerifyingKey::new((*vk).clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let prepared_keys_to_inputs = prepared_keys
+            .iter()
+            .zip_eq(keys_to_inputs.values())
+            .map(|(vk, inputs)| (vk, *inputs))
+            .collect::<BTreeMap<_, _>>();
+        Self::verify_batch_prepared(universal_verifier, fs_parameters, &prepared_keys_to_inputs, proof)
     }
 }
@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Instrumentation for the proving pipeline.
+//!
+//! Hot paths (synthesis, FFT, MSM, commit, open, prove, verify) enter a `debug`-level `tracing` span
+//! with the `snarkvm` target, which costs nothing unless a subscriber enables it. With the `metrics`
+//! feature, each span also increments the `snarkvm_<name>_total` counter and records its duration
+//! in seconds to the `snarkvm_<name>_seconds` histogram.
+
+#[doc(hidden)]
+pub use tracing;
+
+/// Enters a span for the given operation, which is recorded until it is dropped.
+///
+/// ```ignore
+/// let _span = instrument!("msm", size = bases.len());
+/// ```
+#[macro_export]
+macro_rules! instrument {
+    ($name:literal $(, $($field:tt)+)?) => {
+        $crate::telemetry::Instrumented::new(
+            $crate::telemetry::tracing::debug_span!(target: "snarkvm", $name $(, $($field)+)?),
+            concat!("snarkvm_", $name, "_total"),
+            concat!("snarkvm_", $name, "_seconds"),
+        )
+    };
+}
+
+/// An entered span of an instrumented operation.
+#[must_use = "the operation is only instrumented until this is dropped"]
+pub struct Instrumented {
+    /// The entered span.
+    _span: tracing::span::EnteredSpan,
+    /// The name of the duration histogram.
+    #[cfg(feature = "metrics")]
+    histogram: &'static str,
+    /// The start time of the operation.
+    #[cfg(feature = "metrics")]
+    start: std::time::Instant,
+}
+
+impl Instrumented {
+    /// Enters the given span. Use the `instrument!` macro instead of calling this directly.
+    #[doc(hidden)]
+    #[allow(unused_variables)]
+    pub fn new(span: tracing::Span, counter: &'static str, histogram: &'static str) -> Self {
+        #[cfg(feature = "metrics")]
+        metrics::increment_counter!(counter);
+        Self {
+            _span: span.entered(),
+            #[cfg(feature = "metrics")]
+            histogram,
+            #[cfg(feature = "metrics")]
+            start: std::time::Instant::now(),
+        }
+    }
+}
+
+#[cfg(feature = "metrics")]
+impl Drop for Instrumented {
+    fn drop(&mut self) {
+        metrics::histogram!(self.histogram, self.start.elapsed().as_secs_f64());
+    }
+}
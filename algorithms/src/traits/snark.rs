@@ -80,6 +80,8 @@ pub trait SNARK {
         Self::prove_batch(universal_prover, fs_parameters, &keys_to_constraints, rng)
     }
 
+    /// Returns a single proof for a batch of instances, which may span multiple distinct circuits.
+    /// Each proving key is mapped to the instances of its circuit.
     fn prove_batch<C: ConstraintSynthesizer<Self::ScalarField>, R: Rng + CryptoRng>(
         universal_prover: &Self::UniversalProver,
         fs_parameters: &Self::FSParameters,
@@ -108,6 +110,8 @@ pub trait SNARK {
         Self::verify_batch(universal_verifier, fs_parameters, &keys_to_inputs, proof)
     }
 
+    /// Verifies a single proof for a batch of instances, which may span multiple distinct circuits.
+    /// Each verifying key is mapped to the public inputs of its instances, in the order they were proven.
     fn verify_batch<B: Borrow<Self::VerifierInput>>(
         universal_verifier: &Self::UniversalVerifier,
         fs_parameters: &Self::FSParameters,
@@ -22,6 +22,10 @@ type Field = <console::Testnet3 as console::Environment>::Field;
 thread_local! {
     pub(super) static CIRCUIT: Rc<RefCell<R1CS<Field>>> = Rc::new(RefCell::new(R1CS::new()));
     pub(super) static IN_WITNESS: Rc<RefCell<bool>> = Rc::new(RefCell::new(false));
+    pub(super) static IN_CONSTRAINT_GRAPH_MODE: Rc<RefCell<bool>> = Rc::new(RefCell::new(false));
+    pub(super) static CONSTRAINT_GRAPH: Rc<RefCell<Option<ConstraintGraph>>> = Rc::new(RefCell::new(None));
+    pub(super) static MEMORY_LIMIT: Rc<RefCell<Option<u64>>> = Rc::new(RefCell::new(None));
+    pub(super) static PEAK_MEMORY_USAGE: Rc<RefCell<u64>> = Rc::new(RefCell::new(0));
     pub(super) static ZERO: LinearCombination<Field> = LinearCombination::zero();
     pub(super) static ONE: LinearCombination<Field> = LinearCombination::one();
 }
@@ -50,10 +54,15 @@ impl Environment for Circuit {
         IN_WITNESS.with(|in_witness| {
             // Ensure we are not in witness mode.
             if !(*(**in_witness).borrow()) {
-                CIRCUIT.with(|circuit| match mode {
-                    Mode::Constant => (**circuit).borrow_mut().new_constant(value),
-                    Mode::Public => (**circuit).borrow_mut().new_public(value),
-                    Mode::Private => (**circuit).borrow_mut().new_private(value),
+                CIRCUIT.with(|circuit| {
+                    let variable = match mode {
+                        Mode::Constant => (**circuit).borrow_mut().new_constant(value),
+                        Mode::Public => (**circuit).borrow_mut().new_public(value),
+                        Mode::Private => (**circuit).borrow_mut().new_private(value),
+                    };
+                    // Ensure the circuit does not exceed the memory limit.
+                    check_memory_limit(&(**circuit).borrow());
+                    variable
                 })
             } else {
                 Self::halt("Tried to initialize a new variable in witness mode")
@@ -106,25 +115,28 @@ impl Environment for Circuit {
     where
         Fn: FnOnce() -> Output,
     {
+        // Set the entire environment to the new scope.
+        let name = name.into();
+        Self::push_scope(name.clone());
+
+        // Run the logic.
+        let output = logic();
+
+        // Return the entire environment to the previous scope.
+        Self::pop_scope(name);
+
+        output
+    }
+
+    /// Enters a new scope with the given label.
+    fn push_scope<S: Into<String>>(label: S) {
         IN_WITNESS.with(|in_witness| {
             // Ensure we are not in witness mode.
             if !(*(**in_witness).borrow()) {
                 CIRCUIT.with(|circuit| {
-                    // Set the entire environment to the new scope.
-                    let name = name.into();
-                    if let Err(error) = (**circuit).borrow_mut().push_scope(&name) {
+                    if let Err(error) = (**circuit).borrow_mut().push_scope(label) {
                         Self::halt(error)
                     }
-
-                    // Run the logic.
-                    let output = logic();
-
-                    // Return the entire environment to the previous scope.
-                    if let Err(error) = (**circuit).borrow_mut().pop_scope(name) {
-                        Self::halt(error)
-                    }
-
-                    output
                 })
             } else {
                 Self::halt("Tried to initialize a new scope in witness mode")
@@ -132,6 +144,15 @@ impl Environment for Circuit {
         })
     }
 
+    /// Exits the scope with the given label.
+    fn pop_scope<S: Into<String>>(label: S) {
+        CIRCUIT.with(|circuit| {
+            if let Err(error) = (**circuit).borrow_mut().pop_scope(label) {
+                Self::halt(error)
+            }
+        })
+    }
+
     /// Adds one constraint enforcing that `(A * B) == C`.
     fn enforce<Fn, A, B, C>(constraint: Fn)
     where
@@ -169,7 +190,9 @@ impl Environment for Circuit {
                             // Construct the constraint object.
                             let constraint = Constraint((**circuit).borrow().scope(), a, b, c);
                             // Append the constraint.
-                            (**circuit).borrow_mut().enforce(constraint)
+                            (**circuit).borrow_mut().enforce(constraint);
+                            // Ensure the circuit does not exceed the memory limit.
+                            check_memory_limit(&(**circuit).borrow());
                         }
                     }
                 });
@@ -184,6 +207,11 @@ impl Environment for Circuit {
         CIRCUIT.with(|circuit| (**circuit).borrow().is_satisfied())
     }
 
+    /// Returns the first unsatisfied constraint in the environment, with the assignments of its variables, if any.
+    fn first_unsatisfied_constraint() -> Option<UnsatisfiedConstraint> {
+        CIRCUIT.with(|circuit| (**circuit).borrow().first_unsatisfied_constraint())
+    }
+
     /// Returns `true` if all constraints in the current scope are satisfied.
     fn is_satisfied_in_scope() -> bool {
         CIRCUIT.with(|circuit| (**circuit).borrow().is_satisfied_in_scope())
@@ -275,6 +303,7 @@ impl Environment for Circuit {
             IN_WITNESS.with(|in_witness| *(**in_witness).borrow_mut() = false);
             // Eject the R1CS instance.
             let r1cs = circuit.replace(R1CS::<<Self as Environment>::BaseField>::new());
+            record_peak_memory_usage(&r1cs);
             // Ensure the circuit is now empty.
             assert_eq!(0, (**circuit).borrow().num_constants());
             assert_eq!(1, (**circuit).borrow().num_public());
@@ -294,10 +323,15 @@ impl Environment for Circuit {
             IN_WITNESS.with(|in_witness| *(**in_witness).borrow_mut() = false);
             // Eject the R1CS instance.
             let r1cs = circuit.replace(R1CS::<<Self as Environment>::BaseField>::new());
+            record_peak_memory_usage(&r1cs);
             assert_eq!(0, (**circuit).borrow().num_constants());
             assert_eq!(1, (**circuit).borrow().num_public());
             assert_eq!(0, (**circuit).borrow().num_private());
             assert_eq!(0, (**circuit).borrow().num_constraints());
+            // If constraint graph mode is enabled, retain the constraint graph.
+            if Self::is_constraint_graph_mode() {
+                CONSTRAINT_GRAPH.with(|graph| *(**graph).borrow_mut() = Some(ConstraintGraph::from(&r1cs)));
+            }
             // Convert the R1CS instance to an assignment.
             Assignment::from(r1cs)
         })
@@ -308,6 +342,7 @@ impl Environment for Circuit {
         CIRCUIT.with(|circuit| {
             // Reset the witness mode.
             IN_WITNESS.with(|in_witness| *(**in_witness).borrow_mut() = false);
+            record_peak_memory_usage(&(**circuit).borrow());
             *(**circuit).borrow_mut() = R1CS::<<Self as Environment>::BaseField>::new();
             assert_eq!(0, (**circuit).borrow().num_constants());
             assert_eq!(1, (**circuit).borrow().num_public());
@@ -315,6 +350,69 @@ impl Environment for Circuit {
             assert_eq!(0, (**circuit).borrow().num_constraints());
         });
     }
+
+    /// Enables or disables constraint graph mode.
+    fn set_constraint_graph_mode(is_enabled: bool) {
+        IN_CONSTRAINT_GRAPH_MODE.with(|mode| *(**mode).borrow_mut() = is_enabled);
+    }
+
+    /// Returns `true` if constraint graph mode is enabled.
+    fn is_constraint_graph_mode() -> bool {
+        IN_CONSTRAINT_GRAPH_MODE.with(|mode| *(**mode).borrow())
+    }
+
+    /// Returns the constraint graph of the current circuit.
+    fn to_constraint_graph() -> ConstraintGraph {
+        CIRCUIT.with(|circuit| ConstraintGraph::from(&*(**circuit).borrow()))
+    }
+
+    /// Returns the constraint graph of the last ejected circuit, if it was ejected in constraint graph mode.
+    fn take_constraint_graph() -> Option<ConstraintGraph> {
+        CONSTRAINT_GRAPH.with(|graph| (**graph).borrow_mut().take())
+    }
+
+    /// Sets the memory limit (in bytes) of the circuit, or removes it if `None`.
+    fn set_memory_limit(limit: Option<u64>) {
+        MEMORY_LIMIT.with(|memory_limit| *(**memory_limit).borrow_mut() = limit);
+    }
+
+    /// Returns the memory limit (in bytes) of the circuit, if any.
+    fn memory_limit() -> Option<u64> {
+        MEMORY_LIMIT.with(|memory_limit| *(**memory_limit).borrow())
+    }
+
+    /// Returns the estimated memory usage (in bytes) of the current circuit.
+    fn memory_usage() -> u64 {
+        CIRCUIT.with(|circuit| (**circuit).borrow().memory_usage())
+    }
+
+    /// Returns the peak estimated memory usage (in bytes) of the circuits since the last `reset_peak_memory_usage`.
+    fn peak_memory_usage() -> u64 {
+        PEAK_MEMORY_USAGE.with(|peak| *(**peak).borrow()).max(Self::memory_usage())
+    }
+
+    /// Resets the peak estimated memory usage, so that it only reflects the circuits from this point on.
+    fn reset_peak_memory_usage() {
+        PEAK_MEMORY_USAGE.with(|peak| *(**peak).borrow_mut() = 0);
+    }
+}
+
+/// Halts if the estimated memory usage of the given circuit exceeds the memory limit, if any.
+fn check_memory_limit(r1cs: &R1CS<Field>) {
+    if let Some(memory_limit) = MEMORY_LIMIT.with(|memory_limit| *(**memory_limit).borrow()) {
+        let memory_usage = r1cs.memory_usage();
+        if memory_usage > memory_limit {
+            Circuit::halt(format!("Synthesis exceeded the memory limit ({memory_usage} of {memory_limit} bytes)"))
+        }
+    }
+}
+
+/// Records the estimated memory usage of the given circuit, before it is ejected or cleared, in the peak memory usage.
+fn record_peak_memory_usage(r1cs: &R1CS<Field>) {
+    PEAK_MEMORY_USAGE.with(|peak| {
+        let mut peak = (**peak).borrow_mut();
+        *peak = (*peak).max(r1cs.memory_usage());
+    });
 }
 
 impl fmt::Display for Circuit {
@@ -351,6 +449,43 @@ mod tests {
         candidate
     }
 
+    #[test]
+    fn test_memory_usage() {
+        Circuit::reset();
+        Circuit::reset_peak_memory_usage();
+        let empty_usage = Circuit::memory_usage();
+        assert!(empty_usage > 0);
+
+        // Ensure the memory usage grows with the circuit.
+        let _candidate = create_example_circuit::<Circuit>();
+        let usage = Circuit::memory_usage();
+        assert!(usage > empty_usage);
+        assert_eq!(Circuit::peak_memory_usage(), usage);
+
+        // Ensure the memory limit is checked against the memory usage.
+        assert!(!Circuit::is_memory_limit_exceeded());
+        Circuit::set_memory_limit(Some(usage - 1));
+        assert!(Circuit::is_memory_limit_exceeded());
+        Circuit::set_memory_limit(Some(usage));
+        assert!(!Circuit::is_memory_limit_exceeded());
+
+        // Ensure the circuit halts as soon as it exceeds the memory limit.
+        let result =
+            std::panic::catch_unwind(|| Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::one()));
+        assert!(result.is_err());
+        Circuit::set_memory_limit(None);
+        Circuit::reset();
+        Circuit::reset_peak_memory_usage();
+        let _candidate = create_example_circuit::<Circuit>();
+
+        // Ensure the peak memory usage is retained after the circuit is reset.
+        Circuit::reset();
+        assert_eq!(Circuit::memory_usage(), empty_usage);
+        assert_eq!(Circuit::peak_memory_usage(), usage);
+        Circuit::reset_peak_memory_usage();
+        assert_eq!(Circuit::peak_memory_usage(), empty_usage);
+    }
+
     #[test]
     fn test_print_circuit() {
         let _candidate = create_example_circuit::<Circuit>();
@@ -358,6 +493,62 @@ mod tests {
         println!("{output}");
     }
 
+    #[test]
+    fn test_first_unsatisfied_constraint() {
+        use snarkvm_circuit::environment::GraphVariable;
+
+        Circuit::reset();
+
+        let three = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::from_u64(3);
+        let five = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::from_u64(5);
+        let a = Field::<Circuit>::new(Mode::Private, three);
+        let b = Field::<Circuit>::new(Mode::Public, five);
+        let _ = &a * &b;
+        assert!(Circuit::first_unsatisfied_constraint().is_none());
+
+        // Enforce an incorrect product.
+        let c = Field::<Circuit>::new(Mode::Private, three);
+        Circuit::scope("bad_product", || Circuit::enforce(|| (&a, &b, &c)));
+        assert!(!Circuit::is_satisfied());
+
+        let constraint = Circuit::first_unsatisfied_constraint().unwrap();
+        assert_eq!(constraint.index, 1);
+        assert_eq!(constraint.scope, "bad_product");
+        assert_eq!(constraint.values, ("3".to_string(), "5".to_string(), "3".to_string()));
+        assert_eq!(constraint.variables, vec![
+            (GraphVariable::Private(0), "3".to_string()),
+            (GraphVariable::Public(1), "5".to_string()),
+            (GraphVariable::Private(2), "3".to_string()),
+        ]);
+        assert_eq!(
+            constraint.to_string(),
+            "Constraint #1 in 'bad_product' is unsatisfied: 3 * 5 != 3 (where private_0 = 3, public_1 = 5, private_2 = 3)"
+        );
+
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_push_and_pop_scope() {
+        Circuit::reset();
+
+        let one = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one();
+        let a = Field::<Circuit>::new(Mode::Private, one);
+
+        Circuit::push_scope("first");
+        let _ = &a * &a;
+        Circuit::push_scope("second");
+        let _ = &a * &a;
+        Circuit::pop_scope("second");
+        Circuit::pop_scope("first");
+        let _ = &a * &a;
+
+        let scopes = Circuit::to_constraint_graph().constraints().iter().map(|c| c.scope.clone()).collect::<Vec<_>>();
+        assert_eq!(scopes, vec!["first".to_string(), "first.second".to_string(), "".to_string()]);
+
+        Circuit::reset();
+    }
+
     #[test]
     fn test_circuit_scope() {
         Circuit::scope("test_circuit_scope", || {
@@ -12,7 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{witness_mode, Assignment, Inject, LinearCombination, Mode, Variable, R1CS};
+use crate::{
+    witness_mode,
+    Assignment,
+    ConstraintGraph,
+    Inject,
+    LinearCombination,
+    Mode,
+    UnsatisfiedConstraint,
+    Variable,
+    R1CS,
+};
 use snarkvm_curves::AffineCurve;
 use snarkvm_fields::traits::*;
 
@@ -59,6 +69,13 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
     where
         Fn: FnOnce() -> Output;
 
+    /// Enters a new scope with the given label, which tags every constraint enforced until the matching `pop_scope`.
+    /// Note: Labels cannot contain periods, as the labels of nested scopes are joined with periods.
+    fn push_scope<S: Into<String>>(label: S);
+
+    /// Exits the scope with the given label, which must be the most recently pushed scope.
+    fn pop_scope<S: Into<String>>(label: S);
+
     /// Adds one constraint enforcing that `(A * B) == C`.
     fn enforce<Fn, A, B, C>(constraint: Fn)
     where
@@ -106,6 +123,9 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
     /// Returns `true` if all constraints in the environment are satisfied.
     fn is_satisfied() -> bool;
 
+    /// Returns the first unsatisfied constraint in the environment, with the assignments of its variables, if any.
+    fn first_unsatisfied_constraint() -> Option<UnsatisfiedConstraint>;
+
     /// Returns `true` if all constraints in the current scope are satisfied.
     fn is_satisfied_in_scope() -> bool;
 
@@ -171,4 +191,37 @@ pub trait Environment: 'static + Copy + Clone + fmt::Debug + fmt::Display + Eq +
 
     /// Clears and initializes an empty environment.
     fn reset();
+
+    /// Enables or disables constraint graph mode. In this mode, `eject_assignment_and_reset` retains the constraint graph.
+    fn set_constraint_graph_mode(is_enabled: bool);
+
+    /// Returns `true` if constraint graph mode is enabled.
+    fn is_constraint_graph_mode() -> bool;
+
+    /// Returns the constraint graph of the current circuit.
+    fn to_constraint_graph() -> ConstraintGraph;
+
+    /// Returns the constraint graph of the last ejected circuit, if it was ejected in constraint graph mode.
+    fn take_constraint_graph() -> Option<ConstraintGraph>;
+
+    /// Sets the memory limit (in bytes) of the circuit, or removes it if `None`.
+    /// Note: The circuit halts as soon as a new variable or constraint exceeds the limit.
+    fn set_memory_limit(limit: Option<u64>);
+
+    /// Returns the memory limit (in bytes) of the circuit, if any.
+    fn memory_limit() -> Option<u64>;
+
+    /// Returns the estimated memory usage (in bytes) of the current circuit.
+    fn memory_usage() -> u64;
+
+    /// Returns the peak estimated memory usage (in bytes) of the circuits since the last `reset_peak_memory_usage`.
+    fn peak_memory_usage() -> u64;
+
+    /// Resets the peak estimated memory usage, so that it only reflects the circuits from this point on.
+    fn reset_peak_memory_usage();
+
+    /// Returns `true` if the estimated memory usage of the current circuit exceeds the memory limit.
+    fn is_memory_limit_exceeded() -> bool {
+        Self::memory_limit().map_or(false, |limit| Self::memory_usage() > limit)
+    }
 }
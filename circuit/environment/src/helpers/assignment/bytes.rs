@@ -0,0 +1,296 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The interchange format for a synthesized constraint system and its witness assignment,
+//! which allows a circuit to be proven by an external proving stack.
+//!
+//! All integers are little-endian, and all field elements are in their canonical little-endian
+//! encoding (as in `ToBytes`). An assignment is encoded as follows:
+//!
+//! | Field             | Type                  | Description                                                  |
+//! |-------------------|-----------------------|--------------------------------------------------------------|
+//! | `magic`           | `[u8; 4]`             | The bytes `b"R1CS"`.                                         |
+//! | `version`         | `u8`                  | The version of the format, which is currently `1`.           |
+//! | `modulus`         | `BigInteger`          | The modulus of the field, which identifies the field.        |
+//! | `num_public`      | `u64`                 | The number of public variables, including the constant `1`.  |
+//! | `public`          | `[F; num_public]`     | The public variables, where `public[0] == 1`.                |
+//! | `num_private`     | `u64`                 | The number of private variables.                             |
+//! | `private`         | `[F; num_private]`    | The private variables.                                       |
+//! | `num_constraints` | `u64`                 | The number of constraints.                                   |
+//! | `constraints`     | `[(LC, LC, LC); ..]`  | The constraints `A * B == C`.                                |
+//!
+//! A linear combination (`LC`) is encoded as its constant term (`F`), followed by its number of terms (`u64`),
+//! followed by each term, as a variable and its coefficient (`F`). A variable is encoded as a tag (`u8`)
+//! followed by its payload, where `0` is a constant (with its value `F`), `1` is a public variable (with its
+//! index `u64`), and `2` is a private variable (with its index `u64`).
+//!
+//! The integrity of an exported assignment is checked with its digest, which is the SHA-256 hash
+//! of its encoding, and which is sent alongside the encoding to the prover.
+
+use super::*;
+use snarkvm_utilities::{error, FromBytes, ToBytes};
+
+use sha2::{Digest, Sha256};
+use std::io::{Read, Result as IoResult, Write};
+
+/// The magic bytes of the interchange format.
+const MAGIC: [u8; 4] = *b"R1CS";
+/// The version of the interchange format.
+const VERSION: u8 = 1;
+
+impl<F: PrimeField> FromBytes for AssignmentVariable<F> {
+    /// Reads the assignment variable from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        match u8::read_le(&mut reader)? {
+            0 => Ok(Self::Constant(F::read_le(&mut reader)?)),
+            1 => Ok(Self::Public(Index::read_le(&mut reader)?)),
+            2 => Ok(Self::Private(Index::read_le(&mut reader)?)),
+            _ => Err(error("Invalid assignment variable tag")),
+        }
+    }
+}
+
+impl<F: PrimeField> ToBytes for AssignmentVariable<F> {
+    /// Writes the assignment variable to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::Constant(value) => {
+                0u8.write_le(&mut writer)?;
+                value.write_le(&mut writer)
+            }
+            Self::Public(index) => {
+                1u8.write_le(&mut writer)?;
+                index.write_le(&mut writer)
+            }
+            Self::Private(index) => {
+                2u8.write_le(&mut writer)?;
+                index.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+impl<F: PrimeField> FromBytes for AssignmentLC<F> {
+    /// Reads the assignment linear combination from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the constant term.
+        let constant = F::read_le(&mut reader)?;
+        // Read the terms.
+        let num_terms = u64::read_le(&mut reader)?;
+        let mut terms = IndexMap::new();
+        for _ in 0..num_terms {
+            let variable = AssignmentVariable::read_le(&mut reader)?;
+            let coefficient = F::read_le(&mut reader)?;
+            // Ensure the variable is not duplicated.
+            if terms.insert(variable, coefficient).is_some() {
+                return Err(error("Duplicate term in assignment linear combination"));
+            }
+        }
+        Ok(Self { constant, terms })
+    }
+}
+
+impl<F: PrimeField> ToBytes for AssignmentLC<F> {
+    /// Writes the assignment linear combination to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the constant term.
+        self.constant.write_le(&mut writer)?;
+        // Write the terms.
+        (self.terms.len() as u64).write_le(&mut writer)?;
+        for (variable, coefficient) in &self.terms {
+            variable.write_le(&mut writer)?;
+            coefficient.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> FromBytes for Assignment<F> {
+    /// Reads the assignment from a buffer, in the interchange format.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the magic bytes.
+        let magic = <[u8; 4]>::read_le(&mut reader)?;
+        if magic != MAGIC {
+            return Err(error("Invalid magic bytes for an assignment"));
+        }
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        if version != VERSION {
+            return Err(error("Invalid assignment version"));
+        }
+        // Read the modulus, and ensure it matches the field.
+        let modulus = F::BigInteger::read_le(&mut reader)?;
+        if modulus != F::modulus() {
+            return Err(error("The assignment is for a different field"));
+        }
+
+        // Read the public variables.
+        let num_public = u64::read_le(&mut reader)?;
+        let public = (0..num_public).map(|index| Ok((index, F::read_le(&mut reader)?))).collect::<IoResult<_>>()?;
+        // Read the private variables.
+        let num_private = u64::read_le(&mut reader)?;
+        let private = (0..num_private).map(|index| Ok((index, F::read_le(&mut reader)?))).collect::<IoResult<_>>()?;
+        // Read the constraints.
+        let num_constraints = u64::read_le(&mut reader)?;
+        let constraints = (0..num_constraints)
+            .map(|_| {
+                let a = AssignmentLC::read_le(&mut reader)?;
+                let b = AssignmentLC::read_le(&mut reader)?;
+                let c = AssignmentLC::read_le(&mut reader)?;
+                Ok((a, b, c))
+            })
+            .collect::<IoResult<Vec<_>>>()?;
+
+        let assignment = Self { public, private, constraints };
+        // Ensure the first public variable is the constant `1`.
+        if assignment.public.get(&0) != Some(&F::one()) {
+            return Err(error("The first public variable of an assignment must be 1"));
+        }
+        // Ensure every variable in the constraints is allocated.
+        let is_allocated = |variable: &AssignmentVariable<F>| match variable {
+            AssignmentVariable::Constant(..) => true,
+            AssignmentVariable::Public(index) => *index < num_public,
+            AssignmentVariable::Private(index) => *index < num_private,
+        };
+        if !assignment.constraints.iter().all(|(a, b, c)| [a, b, c].iter().all(|lc| lc.terms.keys().all(is_allocated)))
+        {
+            return Err(error("The assignment references an unallocated variable"));
+        }
+        Ok(assignment)
+    }
+}
+
+impl<F: PrimeField> ToBytes for Assignment<F> {
+    /// Writes the assignment to a buffer, in the interchange format.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the magic bytes.
+        MAGIC.write_le(&mut writer)?;
+        // Write the version.
+        VERSION.write_le(&mut writer)?;
+        // Write the modulus.
+        F::modulus().write_le(&mut writer)?;
+
+        // Write the public variables.
+        (self.public.len() as u64).write_le(&mut writer)?;
+        for (i, (index, value)) in self.public.iter().enumerate() {
+            if *index != i as u64 {
+                return Err(error("Public variables must be in order"));
+            }
+            value.write_le(&mut writer)?;
+        }
+        // Write the private variables.
+        (self.private.len() as u64).write_le(&mut writer)?;
+        for (i, (index, value)) in self.private.iter().enumerate() {
+            if *index != i as u64 {
+                return Err(error("Private variables must be in order"));
+            }
+            value.write_le(&mut writer)?;
+        }
+        // Write the constraints.
+        (self.constraints.len() as u64).write_le(&mut writer)?;
+        for (a, b, c) in &self.constraints {
+            a.write_le(&mut writer)?;
+            b.write_le(&mut writer)?;
+            c.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> Assignment<F> {
+    /// Returns the digest of the assignment, which is the SHA-256 hash of its interchange format.
+    pub fn to_digest(&self) -> IoResult<[u8; 32]> {
+        let mut bytes = Vec::new();
+        self.write_le(&mut bytes)?;
+        Ok(Sha256::digest(bytes).into())
+    }
+
+    /// Returns `true` if the given bytes of an assignment match the given digest.
+    pub fn verify_assignment_digest(bytes: &[u8], digest: &[u8; 32]) -> bool {
+        Sha256::digest(bytes).as_slice() == digest
+    }
+
+    /// Reads the assignment from the given bytes, after ensuring they match the given digest.
+    pub fn from_bytes_with_digest(bytes: &[u8], digest: &[u8; 32]) -> IoResult<Self> {
+        // Ensure the bytes match the digest.
+        if !Self::verify_assignment_digest(bytes, digest) {
+            return Err(error("The assignment does not match its digest"));
+        }
+        // Read the assignment, and ensure there are no trailing bytes.
+        let mut reader = bytes;
+        let assignment = Self::read_le(&mut reader)?;
+        match reader.is_empty() {
+            true => Ok(assignment),
+            false => Err(error("The assignment has trailing bytes")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use snarkvm_circuit::prelude::*;
+    use snarkvm_utilities::{FromBytes, ToBytes};
+
+    use sha2::{Digest, Sha256};
+
+    #[test]
+    fn test_bytes() {
+        // Synthesize a circuit.
+        let two = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one().double();
+        let mut accumulator = Field::<Circuit>::new(Mode::Public, two);
+        for _ in 0..16 {
+            accumulator *= Field::new(Mode::Private, two);
+        }
+        assert!(Circuit::is_satisfied());
+        let expected = Circuit::eject_assignment_and_reset();
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        let candidate = Assignment::<<Circuit as Environment>::BaseField>::read_le(&expected_bytes[..]).unwrap();
+        assert_eq!(format!("{expected:?}"), format!("{candidate:?}"));
+        assert_eq!(expected_bytes, candidate.to_bytes_le().unwrap());
+        assert!(Assignment::<<Circuit as Environment>::BaseField>::read_le(&expected_bytes[1..]).is_err());
+    }
+
+    #[test]
+    fn test_digest() {
+        type F = <Circuit as Environment>::BaseField;
+
+        // Synthesize a circuit.
+        let two = snarkvm_console_types::Field::<<Circuit as Environment>::Network>::one().double();
+        let mut accumulator = Field::<Circuit>::new(Mode::Public, two);
+        for _ in 0..16 {
+            accumulator *= Field::new(Mode::Private, two);
+        }
+        let expected = Circuit::eject_assignment_and_reset();
+
+        // Check the digest.
+        let bytes = expected.to_bytes_le().unwrap();
+        let digest = expected.to_digest().unwrap();
+        assert!(Assignment::<F>::verify_assignment_digest(&bytes, &digest));
+        let candidate = Assignment::<F>::from_bytes_with_digest(&bytes, &digest).unwrap();
+        assert_eq!(bytes, candidate.to_bytes_le().unwrap());
+
+        // Ensure tampered bytes are rejected.
+        let mut tampered = bytes.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(!Assignment::<F>::verify_assignment_digest(&tampered, &digest));
+        assert!(Assignment::<F>::from_bytes_with_digest(&tampered, &digest).is_err());
+        // Ensure trailing bytes are rejected.
+        let mut trailing = bytes;
+        trailing.push(0);
+        let trailing_digest: [u8; 32] = Sha256::digest(&trailing).into();
+        assert!(Assignment::<F>::from_bytes_with_digest(&trailing, &trailing_digest).is_err());
+    }
+}
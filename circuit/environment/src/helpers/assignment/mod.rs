@@ -12,10 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bytes;
+
 use crate::Index;
 use snarkvm_fields::PrimeField;
 
 use indexmap::IndexMap;
+use std::mem::size_of;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum AssignmentVariable<F: PrimeField> {
@@ -139,6 +142,19 @@ impl<F: PrimeField> Assignment<F> {
             .map(|(a, b, c)| (a.num_nonzeros(), b.num_nonzeros(), c.num_nonzeros()))
             .fold((0, 0, 0), |(a, b, c), (x, y, z)| (a.saturating_add(x), b.saturating_add(y), c.saturating_add(z)))
     }
+
+    /// Returns the estimated memory usage (in bytes) of the assignment.
+    pub fn memory_usage(&self) -> u64 {
+        // Note: Each variable and term is an entry in an index map, with a key, a value, and a hash.
+        let variable_size = (size_of::<Index>() + size_of::<F>() + size_of::<usize>()) as u64;
+        let term_size = (size_of::<AssignmentVariable<F>>() + size_of::<F>() + size_of::<usize>()) as u64;
+        let constraint_size = size_of::<(AssignmentLC<F>, AssignmentLC<F>, AssignmentLC<F>)>() as u64;
+        let (a_nonzeros, b_nonzeros, c_nonzeros) = self.num_nonzeros();
+        (self.num_public() + self.num_private())
+            .saturating_mul(variable_size)
+            .saturating_add(self.num_constraints().saturating_mul(constraint_size))
+            .saturating_add((a_nonzeros + b_nonzeros + c_nonzeros).saturating_mul(term_size))
+    }
 }
 
 impl<F: PrimeField> snarkvm_algorithms::r1cs::ConstraintSynthesizer<F> for Assignment<F> {
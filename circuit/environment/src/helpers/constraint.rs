@@ -65,3 +65,59 @@ impl<F: PrimeField> Display for Constraint<F> {
         }
     }
 }
+
+/// An unsatisfied constraint, with the assignments of the variables it involves.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnsatisfiedConstraint {
+    /// The index of the constraint in the circuit.
+    pub index: usize,
+    /// The scope of the constraint.
+    pub scope: Scope,
+    /// The values of the linear combinations `(A, B, C)`, where `A * B != C`.
+    pub values: (String, String, String),
+    /// The variables of the constraint, in order of first appearance, with their assignments.
+    pub variables: Vec<(GraphVariable, String)>,
+}
+
+impl UnsatisfiedConstraint {
+    /// Returns the report for the given constraint, or `None` if the constraint is satisfied.
+    pub(crate) fn new<F: PrimeField>(index: usize, constraint: &Constraint<F>) -> Option<Self> {
+        let (a, b, c) = constraint.to_terms();
+        if a.value() * b.value() == c.value() {
+            return None;
+        }
+        let mut variables: Vec<(GraphVariable, String)> = Vec::new();
+        for variable in a.to_terms().keys().chain(b.to_terms().keys()).chain(c.to_terms().keys()) {
+            let graph_variable = match variable {
+                Variable::Constant(..) => continue,
+                Variable::Public(index, _) => GraphVariable::Public(*index),
+                Variable::Private(index, _) => GraphVariable::Private(*index),
+            };
+            if !variables.iter().any(|(existing, _)| *existing == graph_variable) {
+                variables.push((graph_variable, variable.value().to_string()));
+            }
+        }
+        Some(Self {
+            index,
+            scope: constraint.0.clone(),
+            values: (a.value().to_string(), b.value().to_string(), c.value().to_string()),
+            variables,
+        })
+    }
+}
+
+impl Display for UnsatisfiedConstraint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let scope = match self.scope.is_empty() {
+            true => "(unscoped)",
+            false => &self.scope,
+        };
+        let (a, b, c) = &self.values;
+        write!(f, "Constraint #{} in '{scope}' is unsatisfied: {a} * {b} != {c}", self.index)?;
+        if !self.variables.is_empty() {
+            let variables = self.variables.iter().map(|(variable, value)| format!("{variable} = {value}"));
+            write!(f, " (where {})", variables.collect::<Vec<_>>().join(", "))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,277 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    helpers::{Constraint, LinearCombination},
+    Index,
+    Variable,
+    R1CS,
+};
+use snarkvm_fields::PrimeField;
+
+use core::fmt::{self, Write};
+use serde_json::{json, Value as Json};
+use std::collections::HashMap;
+
+/// A variable of the constraint graph. Constants are folded into the constant term of each linear combination.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum GraphVariable {
+    Public(Index),
+    Private(Index),
+}
+
+impl fmt::Display for GraphVariable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Public(index) => write!(f, "public_{index}"),
+            Self::Private(index) => write!(f, "private_{index}"),
+        }
+    }
+}
+
+/// A linear combination of the constraint graph, as a constant and `(variable, coefficient)` terms.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GraphLinearCombination {
+    /// The constant term.
+    pub constant: String,
+    /// The variable terms, with their coefficients.
+    pub terms: Vec<(GraphVariable, String)>,
+}
+
+impl GraphLinearCombination {
+    /// Returns the graph representation of the given linear combination.
+    fn new<F: PrimeField>(lc: &LinearCombination<F>) -> Self {
+        let mut constant = lc.to_constant();
+        let mut terms = Vec::with_capacity(lc.to_terms().len());
+        for (variable, coefficient) in lc.to_terms() {
+            match variable {
+                Variable::Constant(value) => constant += **value * coefficient,
+                Variable::Public(index, _) => terms.push((GraphVariable::Public(*index), coefficient.to_string())),
+                Variable::Private(index, _) => terms.push((GraphVariable::Private(*index), coefficient.to_string())),
+            }
+        }
+        Self { constant: constant.to_string(), terms }
+    }
+
+    /// Returns the JSON representation of the linear combination.
+    fn to_json(&self) -> Json {
+        json!({
+            "constant": self.constant,
+            "terms": self.terms.iter().map(|(variable, coefficient)| json!([variable.to_string(), coefficient])).collect::<Vec<_>>(),
+        })
+    }
+}
+
+/// A constraint `A * B == C` of the constraint graph, with the scope it was enforced in.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GraphConstraint {
+    /// The scope of the constraint, which the synthesizer sets to the instruction that produced it.
+    pub scope: String,
+    /// The linear combination `A`.
+    pub a: GraphLinearCombination,
+    /// The linear combination `B`.
+    pub b: GraphLinearCombination,
+    /// The linear combination `C`.
+    pub c: GraphLinearCombination,
+}
+
+impl GraphConstraint {
+    /// Returns the graph representation of the given constraint.
+    fn new<F: PrimeField>(constraint: &Constraint<F>) -> Self {
+        let (a, b, c) = constraint.to_terms();
+        Self {
+            scope: constraint.0.clone(),
+            a: GraphLinearCombination::new(a),
+            b: GraphLinearCombination::new(b),
+            c: GraphLinearCombination::new(c),
+        }
+    }
+
+    /// Returns the variables of the constraint, with the side (`a`, `b`, or `c`) they appear in.
+    fn variables(&self) -> impl Iterator<Item = (&'static str, &GraphVariable)> {
+        [("a", &self.a), ("b", &self.b), ("c", &self.c)]
+            .into_iter()
+            .flat_map(|(label, lc)| lc.terms.iter().map(move |(variable, _)| (label, variable)))
+    }
+}
+
+/// The structure of a synthesized circuit: its variables, and its constraints labeled by scope.
+/// Variable values are omitted, so the graph is safe to share for any assignment.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConstraintGraph {
+    /// The number of constants.
+    num_constants: u64,
+    /// The public variables, including the constant `1` at index 0.
+    public: Vec<GraphVariable>,
+    /// The private variables.
+    private: Vec<GraphVariable>,
+    /// The constraints.
+    constraints: Vec<GraphConstraint>,
+}
+
+impl<F: PrimeField> From<&R1CS<F>> for ConstraintGraph {
+    /// Returns the constraint graph of the given R1CS.
+    fn from(r1cs: &R1CS<F>) -> Self {
+        Self {
+            num_constants: r1cs.num_constants(),
+            public: r1cs.to_public_variables().iter().map(|variable| GraphVariable::Public(variable.index())).collect(),
+            private: r1cs
+                .to_private_variables()
+                .iter()
+                .map(|variable| GraphVariable::Private(variable.index()))
+                .collect(),
+            constraints: r1cs.to_constraints().iter().map(GraphConstraint::new).collect(),
+        }
+    }
+}
+
+impl ConstraintGraph {
+    /// Returns the number of constants.
+    pub const fn num_constants(&self) -> u64 {
+        self.num_constants
+    }
+
+    /// Returns the public variables, including the constant `1` at index 0.
+    pub fn public(&self) -> &[GraphVariable] {
+        &self.public
+    }
+
+    /// Returns the private variables.
+    pub fn private(&self) -> &[GraphVariable] {
+        &self.private
+    }
+
+    /// Returns the constraints.
+    pub fn constraints(&self) -> &[GraphConstraint] {
+        &self.constraints
+    }
+
+    /// Returns the number of constraints in each scope, in order of first appearance.
+    pub fn constraints_per_scope(&self) -> Vec<(&str, usize)> {
+        let mut counts: Vec<(&str, usize)> = Vec::new();
+        let mut positions: HashMap<&str, usize> = HashMap::new();
+        for constraint in &self.constraints {
+            match positions.get(constraint.scope.as_str()) {
+                Some(position) => counts[*position].1 += 1,
+                None => {
+                    positions.insert(constraint.scope.as_str(), counts.len());
+                    counts.push((constraint.scope.as_str(), 1));
+                }
+            }
+        }
+        counts
+    }
+
+    /// Returns the pairs `(first, duplicate)` of constraint indices, where the duplicate constraint enforces the
+    /// same product `A * B` as an earlier one (up to swapping `A` and `B`), and is therefore redundant.
+    pub fn redundant_constraints(&self) -> Vec<(usize, usize)> {
+        let mut seen = HashMap::new();
+        let mut redundant = Vec::new();
+        for (index, constraint) in self.constraints.iter().enumerate() {
+            // Order `A` and `B`, as multiplication is commutative.
+            let (a, b) =
+                match (&constraint.a.constant, &constraint.a.terms) <= (&constraint.b.constant, &constraint.b.terms) {
+                    true => (&constraint.a, &constraint.b),
+                    false => (&constraint.b, &constraint.a),
+                };
+            match seen.get(&(a, b)) {
+                Some(first) => redundant.push((*first, index)),
+                None => {
+                    seen.insert((a, b), index);
+                }
+            }
+        }
+        redundant
+    }
+
+    /// Returns the graph in the Graphviz DOT format, with a node per variable and per constraint,
+    /// and an edge labeled `a`, `b`, or `c` from each variable to the constraints it appears in.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        // Note: Writing to a `String` is infallible.
+        let _ = writeln!(dot, "digraph circuit {{");
+        let _ = writeln!(dot, "    rankdir=LR;");
+        for variable in self.public.iter().chain(&self.private) {
+            let shape = match variable {
+                GraphVariable::Public(..) => "doublecircle",
+                GraphVariable::Private(..) => "circle",
+            };
+            let _ = writeln!(dot, "    \"{variable}\" [shape={shape}];");
+        }
+        for (index, constraint) in self.constraints.iter().enumerate() {
+            let label = format!("#{index} {}", constraint.scope).replace('\\', "\\\\").replace('"', "\\\"");
+            let _ = writeln!(dot, "    \"constraint_{index}\" [shape=box, label=\"{label}\"];");
+            for (side, variable) in constraint.variables() {
+                let _ = writeln!(dot, "    \"{variable}\" -> \"constraint_{index}\" [label=\"{side}\"];");
+            }
+        }
+        let _ = writeln!(dot, "}}");
+        dot
+    }
+
+    /// Returns the JSON representation of the graph.
+    pub fn to_json(&self) -> Json {
+        json!({
+            "num_constants": self.num_constants,
+            "public": self.public.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            "private": self.private.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            "constraints": self.constraints.iter().map(|constraint| json!({
+                "scope": constraint.scope,
+                "a": constraint.a.to_json(),
+                "b": constraint.b.to_json(),
+                "c": constraint.c.to_json(),
+            })).collect::<Vec<_>>(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use snarkvm_circuit::prelude::*;
+
+    #[test]
+    fn test_constraint_graph() {
+        Circuit::reset();
+
+        let a = Field::<Circuit>::new(Mode::Private, snarkvm_console_types::Field::from_u64(3));
+        let b = Field::<Circuit>::new(Mode::Public, snarkvm_console_types::Field::from_u64(5));
+        Circuit::scope("mul", || {
+            let _ = &a * &b;
+        });
+        Circuit::scope("square", || {
+            let _ = a.square();
+            let _ = a.square();
+        });
+
+        let graph = Circuit::to_constraint_graph();
+        // The public variables include the constant `1`.
+        assert_eq!(graph.public().len(), 2);
+        assert_eq!(graph.private().len(), 4);
+        assert_eq!(graph.constraints_per_scope(), vec![("mul", 1), ("square", 2)]);
+        // The second square recomputes the first.
+        assert_eq!(graph.redundant_constraints(), vec![(1, 2)]);
+
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph circuit {"));
+        assert!(dot.contains("\"constraint_0\" [shape=box, label=\"#0 mul\"];"));
+        assert!(dot.contains("\"private_0\" -> \"constraint_0\" [label=\"a\"];"));
+        assert!(dot.contains("\"public_1\" -> \"constraint_0\" [label=\"b\"];"));
+
+        let json = graph.to_json();
+        assert_eq!(json["constraints"].as_array().unwrap().len(), 3);
+        assert_eq!(json["constraints"][1]["scope"], "square");
+
+        Circuit::reset();
+    }
+}
@@ -40,9 +40,10 @@ impl<F: PrimeField> Counter<F> {
                 };
 
                 // Save the current scope members.
+                // Note: The constraints are moved rather than cloned, as the new scope starts without any.
                 self.parents.push((
                     self.scope.clone(),
-                    self.constraints.clone(),
+                    core::mem::take(&mut self.constraints),
                     self.constants,
                     self.public,
                     self.private,
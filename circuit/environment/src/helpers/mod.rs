@@ -21,6 +21,9 @@ pub use circuit_type::*;
 pub mod constraint;
 pub use constraint::*;
 
+pub mod constraint_graph;
+pub use constraint_graph::*;
+
 pub(super) mod converter;
 
 pub mod count;
@@ -13,12 +13,12 @@
 // limitations under the License.
 
 use crate::{
-    helpers::{Constraint, Counter},
+    helpers::{Constraint, Counter, UnsatisfiedConstraint},
     prelude::*,
 };
 use snarkvm_fields::PrimeField;
 
-use std::rc::Rc;
+use std::{mem::size_of, rc::Rc};
 
 pub type Scope = String;
 
@@ -30,6 +30,7 @@ pub struct R1CS<F: PrimeField> {
     constraints: Vec<Constraint<F>>,
     counter: Counter<F>,
     nonzeros: (u64, u64, u64),
+    memory_usage: u64,
 }
 
 impl<F: PrimeField> R1CS<F> {
@@ -42,9 +43,25 @@ impl<F: PrimeField> R1CS<F> {
             constraints: Default::default(),
             counter: Default::default(),
             nonzeros: (0, 0, 0),
+            memory_usage: Self::variable_size(),
         }
     }
 
+    /// Returns the estimated size (in bytes) of a variable, including its reference-counted value.
+    fn variable_size() -> u64 {
+        (size_of::<Variable<F>>() + size_of::<F>() + 2 * size_of::<usize>()) as u64
+    }
+
+    /// Returns the estimated size (in bytes) of the given constraint, including the terms of its linear combinations.
+    fn constraint_size(constraint: &Constraint<F>) -> u64 {
+        let (a_nonzeros, b_nonzeros, c_nonzeros) = constraint.num_nonzeros();
+        // Note: Each term is an entry in an index map, with a variable (sharing its value), a coefficient, and a hash.
+        let term_size = (size_of::<Variable<F>>() + size_of::<F>() + 2 * size_of::<usize>()) as u64;
+        size_of::<Constraint<F>>() as u64
+            + constraint.0.len() as u64
+            + (a_nonzeros + b_nonzeros + c_nonzeros) * term_size
+    }
+
     /// Appends the given scope to the current environment.
     pub(crate) fn push_scope<S: Into<String>>(&mut self, name: S) -> Result<(), String> {
         self.counter.push(name)
@@ -60,6 +77,7 @@ impl<F: PrimeField> R1CS<F> {
         let variable = Variable::Constant(Rc::new(value));
         self.constants.push(variable.clone());
         self.counter.increment_constant();
+        self.memory_usage += Self::variable_size();
         variable
     }
 
@@ -68,6 +86,7 @@ impl<F: PrimeField> R1CS<F> {
         let variable = Variable::Public(self.public.len() as u64, Rc::new(value));
         self.public.push(variable.clone());
         self.counter.increment_public();
+        self.memory_usage += Self::variable_size();
         variable
     }
 
@@ -76,6 +95,7 @@ impl<F: PrimeField> R1CS<F> {
         let variable = Variable::Private(self.private.len() as u64, Rc::new(value));
         self.private.push(variable.clone());
         self.counter.increment_private();
+        self.memory_usage += Self::variable_size();
         variable
     }
 
@@ -85,6 +105,7 @@ impl<F: PrimeField> R1CS<F> {
         self.nonzeros.0 += a_nonzeros;
         self.nonzeros.1 += b_nonzeros;
         self.nonzeros.2 += c_nonzeros;
+        self.memory_usage += Self::constraint_size(&constraint);
 
         self.constraints.push(constraint.clone());
         self.counter.add_constraint(constraint);
@@ -95,6 +116,14 @@ impl<F: PrimeField> R1CS<F> {
         self.constraints.iter().all(|constraint| constraint.is_satisfied())
     }
 
+    /// Returns the first unsatisfied constraint in the environment, if any.
+    pub(crate) fn first_unsatisfied_constraint(&self) -> Option<UnsatisfiedConstraint> {
+        self.constraints
+            .iter()
+            .enumerate()
+            .find_map(|(index, constraint)| UnsatisfiedConstraint::new(index, constraint))
+    }
+
     /// Returns `true` if all constraints in the current scope are satisfied.
     pub(crate) fn is_satisfied_in_scope(&self) -> bool {
         self.counter.is_satisfied_in_scope()
@@ -130,6 +159,11 @@ impl<F: PrimeField> R1CS<F> {
         self.nonzeros
     }
 
+    /// Returns the estimated memory usage (in bytes) of the constraint system.
+    pub(crate) fn memory_usage(&self) -> u64 {
+        self.memory_usage
+    }
+
     /// Returns the number of constants for the current scope.
     pub(crate) fn num_constants_in_scope(&self) -> u64 {
         self.counter.num_constants_in_scope()
@@ -19,7 +19,13 @@ pub mod v0;
 pub use v0::*;
 
 use snarkvm_circuit_collections::merkle_tree::MerklePath;
-use snarkvm_circuit_types::{environment::Environment, Boolean, Field, Group, Scalar};
+use snarkvm_circuit_types::{
+    environment::{Environment, ToBits},
+    Boolean,
+    Field,
+    Group,
+    Scalar,
+};
 
 pub trait Aleo: Environment {
     /// The maximum number of field elements in data (must not exceed u16::MAX).
@@ -178,4 +184,26 @@ pub trait Aleo: Environment {
         root: &Field<Self>,
         leaf: &Vec<Field<Self>>,
     ) -> Boolean<Self>;
+
+    /// Returns the record commitment, for the given input bits of `(program_id || record_name || record)`.
+    /// Note: This must match `console::Network::commit_record`.
+    fn commit_record(input: &[Boolean<Self>]) -> Field<Self> {
+        Self::hash_bhp1024(input)
+    }
+
+    /// Returns the generator `H` of the serial number, for the given record commitment.
+    /// Note: This must match `console::Network::serial_number_generator`.
+    fn serial_number_generator(commitment: Field<Self>) -> Group<Self> {
+        Self::hash_to_group_psd2(&[Self::serial_number_domain(), commitment])
+    }
+
+    /// Returns the serial number, for the given `gamma` (as `sk_sig * H`) and record commitment.
+    /// Note: This must match `console::Network::serial_number_from_gamma`.
+    fn serial_number_from_gamma(gamma: &Group<Self>, commitment: Field<Self>) -> Field<Self> {
+        // Compute `sn_nonce` as `Hash(COFACTOR * gamma)`.
+        let sn_nonce =
+            Self::hash_to_scalar_psd2(&[Self::serial_number_domain(), gamma.mul_by_cofactor().to_x_coordinate()]);
+        // Compute `serial_number` as `Commit(commitment, sn_nonce)`.
+        Self::commit_bhp512(&(Self::serial_number_domain(), commitment).to_bits_le(), &sn_nonce)
+    }
 }
@@ -39,7 +39,7 @@ use snarkvm_circuit_algorithms::{
 };
 use snarkvm_circuit_collections::merkle_tree::MerklePath;
 use snarkvm_circuit_types::{
-    environment::{prelude::*, Assignment, Circuit, R1CS},
+    environment::{prelude::*, Assignment, Circuit, ConstraintGraph, UnsatisfiedConstraint, R1CS},
     Boolean,
     Field,
     Group,
@@ -390,6 +390,16 @@ impl Environment for AleoV0 {
         E::scope(name, logic)
     }
 
+    /// Enters a new scope with the given label.
+    fn push_scope<S: Into<String>>(label: S) {
+        E::push_scope(label)
+    }
+
+    /// Exits the scope with the given label.
+    fn pop_scope<S: Into<String>>(label: S) {
+        E::pop_scope(label)
+    }
+
     /// Adds one constraint enforcing that `(A * B) == C`.
     fn enforce<Fn, A, B, C>(constraint: Fn)
     where
@@ -406,6 +416,11 @@ impl Environment for AleoV0 {
         E::is_satisfied()
     }
 
+    /// Returns the first unsatisfied constraint in the environment, with the assignments of its variables, if any.
+    fn first_unsatisfied_constraint() -> Option<UnsatisfiedConstraint> {
+        E::first_unsatisfied_constraint()
+    }
+
     /// Returns `true` if all constraints in the current scope are satisfied.
     fn is_satisfied_in_scope() -> bool {
         E::is_satisfied_in_scope()
@@ -485,6 +500,51 @@ impl Environment for AleoV0 {
     fn reset() {
         E::reset()
     }
+
+    /// Enables or disables constraint graph mode.
+    fn set_constraint_graph_mode(is_enabled: bool) {
+        E::set_constraint_graph_mode(is_enabled)
+    }
+
+    /// Returns `true` if constraint graph mode is enabled.
+    fn is_constraint_graph_mode() -> bool {
+        E::is_constraint_graph_mode()
+    }
+
+    /// Returns the constraint graph of the current circuit.
+    fn to_constraint_graph() -> ConstraintGraph {
+        E::to_constraint_graph()
+    }
+
+    /// Returns the constraint graph of the last ejected circuit, if it was ejected in constraint graph mode.
+    fn take_constraint_graph() -> Option<ConstraintGraph> {
+        E::take_constraint_graph()
+    }
+
+    /// Sets the memory limit (in bytes) of the circuit, or removes it if `None`.
+    fn set_memory_limit(limit: Option<u64>) {
+        E::set_memory_limit(limit)
+    }
+
+    /// Returns the memory limit (in bytes) of the circuit, if any.
+    fn memory_limit() -> Option<u64> {
+        E::memory_limit()
+    }
+
+    /// Returns the estimated memory usage (in bytes) of the current circuit.
+    fn memory_usage() -> u64 {
+        E::memory_usage()
+    }
+
+    /// Returns the peak estimated memory usage (in bytes) of the circuits since the last `reset_peak_memory_usage`.
+    fn peak_memory_usage() -> u64 {
+        E::peak_memory_usage()
+    }
+
+    /// Resets the peak estimated memory usage, so that it only reflects the circuits from this point on.
+    fn reset_peak_memory_usage() {
+        E::reset_peak_memory_usage()
+    }
 }
 
 impl Display for AleoV0 {
@@ -26,6 +26,7 @@ mod encrypt;
 mod equal;
 mod find;
 mod num_randomizers;
+mod reencrypt;
 mod serial_number;
 mod tag;
 mod to_bits;
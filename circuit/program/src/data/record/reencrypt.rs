@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+use crate::Literal;
+use snarkvm_circuit_types::Address;
+
+impl<A: Aleo> Record<A, Ciphertext<A>> {
+    /// Re-encrypts `self` to the given new owner under the given randomizer, using the view key of the current owner.
+    pub fn reencrypt(&self, view_key: &ViewKey<A>, new_owner: Address<A>, randomizer: &Scalar<A>) -> Self {
+        // Decrypt the record, which ensures the view key belongs to the current owner.
+        let record = self.decrypt(view_key);
+        // Replace the owner, preserving its visibility.
+        let owner = match record.owner.is_public().eject_value() {
+            true => Owner::Public(new_owner),
+            false => Owner::Private(Plaintext::from(Literal::Address(new_owner))),
+        };
+        // Encrypt the record for the new owner.
+        Record { owner, data: record.data, nonce: A::g_scalar_multiply(randomizer) }.encrypt(randomizer)
+    }
+
+    /// Returns `true` if `reencrypted` is the re-encryption of `self` to the given new owner under the given randomizer.
+    pub fn is_reencryption(
+        &self,
+        reencrypted: &Self,
+        view_key: &ViewKey<A>,
+        new_owner: Address<A>,
+        randomizer: &Scalar<A>,
+    ) -> Boolean<A> {
+        self.reencrypt(view_key, new_owner, randomizer).is_equal(reencrypted)
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use anyhow::Result;
+
+    const ITERATIONS: u64 = 10;
+
+    fn check_reencrypt(visibility: &str, rng: &mut TestRng) -> Result<()> {
+        type Network = <Circuit as Environment>::Network;
+
+        // Sample the current and new owners.
+        let private_key = snarkvm_console_account::PrivateKey::<Network>::new(rng)?;
+        let view_key = snarkvm_console_account::ViewKey::try_from(private_key)?;
+        let address = snarkvm_console_account::Address::try_from(private_key)?;
+        let new_owner = snarkvm_console_account::Address::try_from(snarkvm_console_account::PrivateKey::new(rng)?)?;
+
+        // Prepare the record.
+        let randomizer = Uniform::rand(rng);
+        let record = console::Record::<Network, console::Plaintext<Network>>::from_str(&format!(
+            "{{ owner: {address}.{visibility}, amount: {}u64.private, _nonce: {}.public }}",
+            u64::rand(rng),
+            <Network as console::Network>::g_scalar_multiply(&randomizer)
+        ))?;
+        let ciphertext = record.encrypt(randomizer)?;
+
+        // Compute the expected re-encryption.
+        let new_randomizer = Uniform::rand(rng);
+        let expected = ciphertext.reencrypt(&view_key, new_owner, new_randomizer)?;
+
+        // Re-encrypt the record in the circuit.
+        let ciphertext = Record::<Circuit, Ciphertext<Circuit>>::new(Mode::Private, ciphertext);
+        let view_key = ViewKey::<Circuit>::new(Mode::Private, view_key);
+        let new_owner = Address::<Circuit>::new(Mode::Private, new_owner);
+        let new_randomizer = Scalar::<Circuit>::new(Mode::Private, new_randomizer);
+        let candidate = ciphertext.reencrypt(&view_key, new_owner.clone(), &new_randomizer);
+        assert_eq!(expected, candidate.eject_value());
+
+        // Ensure the verification gadget accepts the re-encryption.
+        let reencrypted = Record::<Circuit, Ciphertext<Circuit>>::new(Mode::Private, expected);
+        assert!(ciphertext.is_reencryption(&reencrypted, &view_key, new_owner, &new_randomizer).eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset();
+        Ok(())
+    }
+
+    #[test]
+    fn test_reencrypt() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            check_reencrypt("private", &mut rng)?;
+            check_reencrypt("public", &mut rng)?;
+        }
+        Ok(())
+    }
+}
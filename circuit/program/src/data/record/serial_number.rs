@@ -18,7 +18,7 @@ impl<A: Aleo, Private: Visibility<A>> Record<A, Private> {
     /// A helper method to derive the serial number from the private key and commitment.
     pub fn serial_number(private_key: PrivateKey<A>, commitment: Field<A>) -> Field<A> {
         // Compute the generator `H` as `HashToGroup(commitment)`.
-        let h = A::hash_to_group_psd2(&[A::serial_number_domain(), commitment.clone()]);
+        let h = A::serial_number_generator(commitment.clone());
         // Compute `gamma` as `sk_sig * H`.
         let gamma = h * private_key.sk_sig();
         // Compute the serial number from `gamma`.
@@ -27,9 +27,6 @@ impl<A: Aleo, Private: Visibility<A>> Record<A, Private> {
 
     /// A helper method to derive the serial number from the gamma and commitment.
     pub fn serial_number_from_gamma(gamma: &Group<A>, commitment: Field<A>) -> Field<A> {
-        // Compute `sn_nonce` as `Hash(COFACTOR * gamma)`.
-        let sn_nonce = A::hash_to_scalar_psd2(&[A::serial_number_domain(), gamma.mul_by_cofactor().to_x_coordinate()]);
-        // Compute `serial_number` as `Commit(commitment, sn_nonce)`.
-        A::commit_bhp512(&(A::serial_number_domain(), commitment).to_bits_le(), &sn_nonce)
+        A::serial_number_from_gamma(gamma, commitment)
     }
 }
@@ -21,8 +21,8 @@ impl<A: Aleo> Record<A, Plaintext<A>> {
         let mut input = program_id.to_bits_le();
         record_name.write_bits_le(&mut input);
         self.write_bits_le(&mut input);
-        // Compute the BHP hash of the program record.
-        A::hash_bhp1024(&input)
+        // Compute the commitment of the program record.
+        A::commit_record(&input)
     }
 }
 
@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod bytes;
+mod serialize;
+mod string;
+mod try_from;
+
+#[cfg(feature = "view_key")]
+use crate::ViewKey;
+
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Address, Field, Group, Scalar};
+
+/// The domain separator of the detection key derivation.
+const DETECTION_KEY_DOMAIN: &str = "AleoDetectionKey0";
+/// The domain separator of the detection tags.
+const DETECTION_TAG_DOMAIN: &str = "AleoDetectionTag0";
+
+/// The account detection key, scoped to a single program.
+///
+/// A detection key lets a third party find the records of a single program that carry a detection tag
+/// for this account, without being able to decrypt those records or find records of any other program.
+///
+/// The detection key is derived from the view key, and the account shares its detection public key with senders.
+/// It cannot be derived from the address, as any key a sender could compute from the address alone
+/// would reveal the view key to the detector.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DetectionKey<N: Network> {
+    /// The detection key `sk_detect` := HashToScalar(view_key || program_address).
+    sk_detect: Scalar<N>,
+}
+
+impl<N: Network> DetectionKey<N> {
+    /// Returns the detection key.
+    pub const fn sk_detect(&self) -> Scalar<N> {
+        self.sk_detect
+    }
+
+    /// Returns the detection public key `G^sk_detect`, which senders use to compute detection tags.
+    pub fn to_public_key(&self) -> Group<N> {
+        N::g_scalar_multiply(&self.sk_detect)
+    }
+
+    /// Returns the detection tag of a record with the given nonce, as `Hash(sk_detect * nonce)`.
+    pub fn to_tag(&self, nonce: &Group<N>) -> Result<Field<N>> {
+        Self::hash_tag(*nonce * self.sk_detect)
+    }
+
+    /// Returns `true` if the given detection tag belongs to the record with the given nonce.
+    pub fn is_detected(&self, nonce: &Group<N>, tag: &Field<N>) -> bool {
+        matches!(self.to_tag(nonce), Ok(candidate) if candidate == *tag)
+    }
+
+    /// Returns the detection tag for the recipient with the given detection public key, where the record nonce is
+    /// `G^randomizer`. This is computed by the sender, and equals the tag derived by the detection key.
+    pub fn tag_from_randomizer(public_key: &Group<N>, randomizer: &Scalar<N>) -> Result<Field<N>> {
+        Self::hash_tag(*public_key * randomizer)
+    }
+
+    /// Returns the detection tag for the given shared point.
+    fn hash_tag(shared: Group<N>) -> Result<Field<N>> {
+        N::hash_psd2(&[Field::new_domain_separator(DETECTION_TAG_DOMAIN), shared.to_x_coordinate()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_detection() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new detection key for a program.
+            let view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+            let program = Address::new(Group::rand(&mut rng));
+            let detection_key = DetectionKey::try_from((&view_key, &program))?;
+
+            // Compute the tag of a record as the sender.
+            let randomizer = Scalar::rand(&mut rng);
+            let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+            let tag = DetectionKey::tag_from_randomizer(&detection_key.to_public_key(), &randomizer)?;
+
+            // Ensure the detection key finds the record.
+            assert!(detection_key.is_detected(&nonce, &tag));
+            // Ensure the detection key does not find a record with another nonce.
+            assert!(!detection_key.is_detected(&Group::rand(&mut rng), &tag));
+            // Ensure the detection key of another program does not find the record.
+            let other = DetectionKey::try_from((&view_key, &Address::new(Group::rand(&mut rng))))?;
+            assert!(!other.is_detected(&nonce, &tag));
+            // Ensure the detection key differs from the record view key.
+            assert_ne!(nonce * detection_key.sk_detect(), nonce * *view_key);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+static DETECTION_KEY_PREFIX: [u8; 8] = [185, 133, 213, 29, 1, 96, 227, 96]; // ADetectKey1
+
+impl<N: Network> FromStr for DetectionKey<N> {
+    type Err = Error;
+
+    /// Reads in an account detection key from a base58 string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Encode the string into base58.
+        let data = bs58::decode(s).into_vec().map_err(|err| anyhow!("{:?}", err))?;
+        if data.len() != 40 {
+            bail!("Invalid account detection key length: found {}, expected 40", data.len())
+        } else if data[0..8] != DETECTION_KEY_PREFIX {
+            bail!("Invalid account detection key prefix: found {:?}, expected {:?}", &data[0..8], DETECTION_KEY_PREFIX)
+        }
+        // Output the detection key.
+        Self::try_from(Scalar::new(FromBytes::read_le(&data[8..40])?))
+    }
+}
+
+impl<N: Network> fmt::Display for DetectionKey<N> {
+    /// Writes the account detection key as a base58 string.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Write the detection key bytes.
+        let mut detection_key = [0u8; 40];
+        detection_key[0..8].copy_from_slice(&DETECTION_KEY_PREFIX);
+        self.sk_detect.write_le(&mut detection_key[8..40]).map_err(|_| fmt::Error)?;
+        // Encode the detection key into base58.
+        write!(f, "{}", bs58::encode(detection_key).into_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_string() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new detection key.
+            let expected = DetectionKey::<CurrentNetwork>::try_from(Scalar::rand(&mut rng))?;
+
+            // Check the string representation.
+            let candidate = format!("{expected}");
+            assert!(candidate.starts_with("ADetectKey1"));
+            assert_eq!(expected, DetectionKey::from_str(&candidate)?);
+        }
+        Ok(())
+    }
+}
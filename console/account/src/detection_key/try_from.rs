@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+#[cfg(feature = "view_key")]
+impl<N: Network> TryFrom<(ViewKey<N>, Address<N>)> for DetectionKey<N> {
+    type Error = Error;
+
+    /// Derives the account detection key for the given program address from an account view key.
+    fn try_from((view_key, program_address): (ViewKey<N>, Address<N>)) -> Result<Self, Self::Error> {
+        Self::try_from((&view_key, &program_address))
+    }
+}
+
+#[cfg(feature = "view_key")]
+impl<N: Network> TryFrom<(&ViewKey<N>, &Address<N>)> for DetectionKey<N> {
+    type Error = Error;
+
+    /// Derives the account detection key for the given program address from an account view key.
+    fn try_from((view_key, program_address): (&ViewKey<N>, &Address<N>)) -> Result<Self, Self::Error> {
+        // Compute sk_detect := HashToScalar(view_key || program_address).
+        let sk_detect = N::hash_to_scalar_psd4(&[
+            Field::new_domain_separator(DETECTION_KEY_DOMAIN),
+            view_key.to_field()?,
+            program_address.to_x_coordinate(),
+        ])?;
+        // Output the detection key.
+        Self::try_from(sk_detect)
+    }
+}
+
+impl<N: Network> TryFrom<Scalar<N>> for DetectionKey<N> {
+    type Error = Error;
+
+    /// Initializes the account detection key from a scalar.
+    fn try_from(sk_detect: Scalar<N>) -> Result<Self, Self::Error> {
+        // Ensure the detection key is nonzero.
+        ensure!(!sk_detect.is_zero(), "The detection key must be nonzero");
+        // Output the detection key.
+        Ok(Self { sk_detect })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 1000;
+
+    #[test]
+    fn test_try_from() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new view key and program address.
+            let view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+            let program = Address::new(Group::rand(&mut rng));
+
+            // Check the derivation is deterministic.
+            let candidate = DetectionKey::try_from((view_key, program))?;
+            assert_eq!(candidate, DetectionKey::try_from((&view_key, &program))?);
+            assert_eq!(candidate, DetectionKey::try_from(candidate.sk_detect())?);
+        }
+        // Ensure a zero detection key is rejected.
+        assert!(DetectionKey::<CurrentNetwork>::try_from(Scalar::zero()).is_err());
+        Ok(())
+    }
+}
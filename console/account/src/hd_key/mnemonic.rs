@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use snarkvm_console_network::prelude::*;
+
+use alloc::vec::Vec;
+use hmac::Hmac;
+use sha2::{Digest, Sha256, Sha512};
+
+/// The BIP-39 English wordlist, in sorted order.
+const WORDLIST: &str = include_str!("resources/english.txt");
+/// The number of PBKDF2 rounds in BIP-39.
+const PBKDF2_ROUNDS: u32 = 2048;
+
+/// Returns the 64-byte BIP-39 seed for the given mnemonic phrase and passphrase, as
+/// `PBKDF2-HMAC-SHA512(mnemonic, "mnemonic" || passphrase, 2048)`.
+/// The words of the mnemonic are normalized to be separated by single spaces.
+/// Note: Unicode NFKD normalization is not applied, so non-ASCII passphrases must be normalized by the caller.
+pub(super) fn to_seed(mnemonic: &str, passphrase: &str) -> Result<[u8; 64]> {
+    let words = mnemonic.split_whitespace().collect::<Vec<_>>();
+    ensure_valid(&words)?;
+
+    let mnemonic = words.join(" ");
+    let salt = format!("mnemonic{passphrase}");
+
+    let mut seed = [0u8; 64];
+    pbkdf2::pbkdf2::<Hmac<Sha512>>(mnemonic.as_bytes(), salt.as_bytes(), PBKDF2_ROUNDS, &mut seed)
+        .expect("HMAC accepts keys of any size");
+    Ok(seed)
+}
+
+/// Ensures the mnemonic has 12, 15, 18, 21, or 24 words from the BIP-39 English wordlist, and a valid checksum.
+fn ensure_valid(words: &[&str]) -> Result<()> {
+    ensure!(
+        matches!(words.len(), 12 | 15 | 18 | 21 | 24),
+        "The mnemonic must have 12, 15, 18, 21, or 24 words, found {} words",
+        words.len()
+    );
+
+    // Concatenate the 11-bit index of each word, most significant bit first.
+    let wordlist = WORDLIST.lines().collect::<Vec<_>>();
+    let mut bits = Vec::with_capacity(words.len() * 11);
+    for word in words {
+        let index = wordlist.binary_search(word).map_err(|_| anyhow!("The word '{word}' is not in the wordlist"))?;
+        bits.extend((0..11).rev().map(|i| (index >> i) & 1 == 1));
+    }
+
+    // Split the bits into the entropy and the checksum, which has 1 bit for every 32 bits of entropy.
+    let (entropy, checksum) = bits.split_at(bits.len() * 32 / 33);
+    let entropy =
+        entropy.chunks(8).map(|byte| byte.iter().fold(0u8, |acc, bit| (acc << 1) | *bit as u8)).collect::<Vec<_>>();
+
+    // Ensure the checksum is the leading bits of `SHA-256(entropy)`.
+    let hash = Sha256::digest(&entropy);
+    let expected = (0..checksum.len()).map(|i| (hash[i / 8] >> (7 - i % 8)) & 1 == 1);
+    ensure!(checksum.iter().copied().eq(expected), "The mnemonic checksum is invalid");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the bytes of the given hex string.
+    fn hex(string: &str) -> Vec<u8> {
+        (0..string.len()).step_by(2).map(|i| u8::from_str_radix(&string[i..i + 2], 16).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_wordlist() {
+        let wordlist = WORDLIST.lines().collect::<Vec<_>>();
+        assert_eq!(wordlist.len(), 2048);
+        // Ensure the wordlist is sorted, so words can be found by binary search.
+        assert!(wordlist.windows(2).all(|pair| pair[0] < pair[1]));
+        // Ensure the wordlist is the BIP-39 English wordlist.
+        assert_eq!(
+            Sha256::digest(WORDLIST.as_bytes()).to_vec(),
+            hex("2f5eed53a4727b4bf8880d8f3f199efc90e58503646d9ff8eff3a2ed3b24dbda")
+        );
+    }
+
+    #[test]
+    fn test_seed_vector() {
+        // The BIP-39 reference vector for the entropy `00000000000000000000000000000000`, with the passphrase `TREZOR`.
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let expected = "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04";
+        assert_eq!(to_seed(mnemonic, "TREZOR").unwrap().to_vec(), hex(expected));
+    }
+
+    #[test]
+    fn test_checksum() {
+        // The BIP-39 reference mnemonics for the entropies `00..`, `7f..`, `80..`, and `ff..`.
+        for mnemonic in [
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            "legal winner thank year wave sausage worth useful legal winner thank yellow",
+            "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+            "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong",
+            "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote",
+        ] {
+            assert!(ensure_valid(&mnemonic.split(' ').collect::<Vec<_>>()).is_ok(), "'{mnemonic}' should be valid");
+        }
+        // Ensure a wrong checksum, an unknown word, or an invalid number of words is rejected.
+        for mnemonic in [
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon",
+            "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo",
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon aleo",
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        ] {
+            assert!(to_seed(mnemonic, "").is_err(), "'{mnemonic}' should be invalid");
+        }
+    }
+}
@@ -0,0 +1,219 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod mnemonic;
+mod path;
+pub use path::DerivationPath;
+
+use crate::PrivateKey;
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::Field;
+
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+
+/// The HMAC key used to derive the master key from a seed.
+const MASTER_KEY_DOMAIN: &[u8] = b"Aleo seed";
+/// The offset of hardened child indices.
+pub const HARDENED_OFFSET: u32 = 0x8000_0000;
+
+/// A hierarchical deterministic key, from which Aleo accounts are derived along a path.
+///
+/// The derivation follows SLIP-0010 with hardened indices only, as Aleo keys are not secp256k1 keys:
+///     (key, chain code) := HMAC-SHA512("Aleo seed", seed)
+///     (child key, child chain code) := HMAC-SHA512(chain code, 0x00 || key || index_be32), for index >= 2^31
+/// The account seed of the derived private key is the key, read as a little-endian integer modulo the field order.
+#[derive(Clone, PartialEq, Eq)]
+pub struct HDKey {
+    /// The key.
+    key: [u8; 32],
+    /// The chain code.
+    chain_code: [u8; 32],
+}
+
+impl HDKey {
+    /// Returns the master key for the given seed, which must be 16 to 64 bytes (e.g. a BIP-39 seed).
+    pub fn from_seed(seed: &[u8]) -> Result<Self> {
+        ensure!((16..=64).contains(&seed.len()), "The seed must be 16 to 64 bytes, found {} bytes", seed.len());
+        Ok(Self::from_hmac(MASTER_KEY_DOMAIN, &[seed]))
+    }
+
+    /// Returns the master key for the given BIP-39 mnemonic phrase and passphrase.
+    /// The mnemonic must use the BIP-39 English wordlist, and have a valid checksum.
+    pub fn from_mnemonic(mnemonic: &str, passphrase: &str) -> Result<Self> {
+        Self::from_seed(&mnemonic::to_seed(mnemonic, passphrase)?)
+    }
+
+    /// Returns the hardened child key at the given index, which must be at least `HARDENED_OFFSET`.
+    pub fn derive_child(&self, index: u32) -> Result<Self> {
+        ensure!(index >= HARDENED_OFFSET, "Only hardened child indices are supported, found index {index}");
+        Ok(Self::from_hmac(&self.chain_code, &[&[0u8], &self.key, &index.to_be_bytes()]))
+    }
+
+    /// Returns the key at the given path, relative to this key.
+    pub fn derive_path(&self, path: &DerivationPath) -> Result<Self> {
+        path.indices().iter().try_fold(self.clone(), |key, index| key.derive_child(*index))
+    }
+
+    /// Returns the private key for this key.
+    pub fn to_private_key<N: Network>(&self) -> Result<PrivateKey<N>> {
+        PrivateKey::try_from(Field::new(N::Field::from_bytes_le_mod_order(&self.key)))
+    }
+
+    /// Returns the key.
+    pub const fn key(&self) -> &[u8; 32] {
+        &self.key
+    }
+
+    /// Returns the chain code.
+    pub const fn chain_code(&self) -> &[u8; 32] {
+        &self.chain_code
+    }
+
+    /// Returns the key and chain code from `HMAC-SHA512(key, data)`.
+    fn from_hmac(key: &[u8], data: &[&[u8]]) -> Self {
+        let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts keys of any size");
+        data.iter().for_each(|data| mac.update(data));
+        let output = mac.finalize().into_bytes();
+
+        let (mut key, mut chain_code) = ([0u8; 32], [0u8; 32]);
+        key.copy_from_slice(&output[..32]);
+        chain_code.copy_from_slice(&output[32..]);
+        Self { key, chain_code }
+    }
+}
+
+impl Debug for HDKey {
+    /// Prints the key without its secret material.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("HDKey(..)")
+    }
+}
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the private key at the given path from the master key of the given seed.
+    pub fn from_seed_and_path(seed: &[u8], path: &DerivationPath) -> Result<Self> {
+        HDKey::from_seed(seed)?.derive_path(path)?.to_private_key()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Address, ViewKey};
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the bytes of the given hex string.
+    fn hex(string: &str) -> Vec<u8> {
+        (0..string.len()).step_by(2).map(|i| u8::from_str_radix(&string[i..i + 2], 16).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_derivation_vectors() {
+        // The vectors, as (path, key, chain code) for the seed `000102030405060708090a0b0c0d0e0f`.
+        let vectors = [
+            (
+                "m",
+                "f17f00aacc384e27747eda10eda2216d48921f01bc2dcbd022f7e13e91627c7a",
+                "176119d872cde0f463915cfb0db3380a9c099c8d8faae8a4107a43c40b53797d",
+            ),
+            (
+                "m/44'",
+                "ce1cf1d0717fd16a5cdff66243509b63368614a1e9fe6be3a05d0c18e1512f66",
+                "72c79b87a71f4c28915c91c71d13f0ba701466c0dd5a851f9a8f2673231386a9",
+            ),
+            (
+                "m/44'/683'",
+                "4bf781196a505676192257e63e9e11ec91bbc9f2ead3ce3b32691feeb8e3d174",
+                "3b2a91516f28cc0c347f5c4d90d914c0ae74abb3a0bb9cea4956aaf2b90f33fd",
+            ),
+            (
+                "m/44'/683'/0'",
+                "8c11dddcd2f0dfc84c58736e0fbbc8f4c9e4e478949a5aa3d3d2768ab67d0bcd",
+                "34dc68c0924c5e1b13442f55676ee195ae356b28dd3e51c21d3812171de43816",
+            ),
+            (
+                "m/44'/683'/0'/0'",
+                "c31733f1c78b228eb843c581ba71358eb8eeff0b48ac99b5d27b3cbac35253d9",
+                "a8655cc393a76ba14bf6fbd07d211a0f3b23c9b5fe3f1f8b533eba096ec95b16",
+            ),
+        ];
+        let master = HDKey::from_seed(&hex("000102030405060708090a0b0c0d0e0f")).unwrap();
+        for (path, key, chain_code) in vectors {
+            let candidate = master.derive_path(&DerivationPath::from_str(path).unwrap()).unwrap();
+            assert_eq!(candidate.key().to_vec(), hex(key), "Mismatched key at {path}");
+            assert_eq!(candidate.chain_code().to_vec(), hex(chain_code), "Mismatched chain code at {path}");
+        }
+    }
+
+    #[test]
+    fn test_mnemonic_vector() {
+        // The BIP-39 reference vector for the all-`abandon` mnemonic, with the passphrase `TREZOR`.
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let master = HDKey::from_mnemonic(mnemonic, "TREZOR").unwrap();
+        let path = DerivationPath::aleo_account(0).unwrap();
+        assert_eq!(path.to_string(), "m/44'/683'/0'/0'");
+        let candidate = master.derive_path(&path).unwrap();
+        assert_eq!(candidate.key().to_vec(), hex("d5cdb5461394d1598ba6e189a61b7b93fb676fa5f3e9fe1196de7c5505659388"));
+        assert_eq!(
+            candidate.chain_code().to_vec(),
+            hex("191875fc6bc50c551365d45e53ca4e9154086d5b56986cc5d12db2ce25dc04d0")
+        );
+    }
+
+    #[test]
+    fn test_account_vector() {
+        // The Aleo account at `m/44'/683'/0'/0'` for the all-`abandon` mnemonic, with no passphrase.
+        let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let master = HDKey::from_mnemonic(mnemonic, "").unwrap();
+        let private_key =
+            master.derive_path(&DerivationPath::aleo_account(0).unwrap()).unwrap().to_private_key().unwrap();
+        let view_key = ViewKey::<CurrentNetwork>::try_from(&private_key).unwrap();
+        assert_eq!(private_key.to_string(), "APrivateKey1zkpC4gBKhpTomsVLH6ZU8SA7xS4R4MvhTBVeRPqHRkzbzUb");
+        assert_eq!(view_key.to_string(), "AViewKey1gUxYrDsyvL8MntxKBK8MtCMjFBabNKaQmzGbZJbbgZHh");
+        assert_eq!(
+            Address::try_from(&view_key).unwrap().to_string(),
+            "aleo1zzer46nc9pdaq52t4vuv59at0367wz7fwf2h9heq34p4jdkdtuyshwazs7"
+        );
+    }
+
+    #[test]
+    fn test_account_derivation() {
+        let seed = hex("000102030405060708090a0b0c0d0e0f");
+        let first =
+            PrivateKey::<CurrentNetwork>::from_seed_and_path(&seed, &DerivationPath::aleo_account(0).unwrap()).unwrap();
+        let second =
+            PrivateKey::<CurrentNetwork>::from_seed_and_path(&seed, &DerivationPath::aleo_account(1).unwrap()).unwrap();
+
+        // Ensure the derivation is deterministic, and distinct across accounts.
+        assert_eq!(first, PrivateKey::from_seed_and_path(&seed, &DerivationPath::aleo_account(0).unwrap()).unwrap());
+        assert_ne!(first, second);
+        assert_ne!(
+            Address::try_from(ViewKey::try_from(&first).unwrap()).unwrap(),
+            Address::try_from(ViewKey::try_from(&second).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid_derivation() {
+        // Ensure seeds of invalid length are rejected.
+        assert!(HDKey::from_seed(&[0u8; 15]).is_err());
+        assert!(HDKey::from_seed(&[0u8; 65]).is_err());
+        // Ensure non-hardened indices are rejected.
+        let master = HDKey::from_seed(&[0u8; 32]).unwrap();
+        assert!(master.derive_child(0).is_err());
+        assert!(master.derive_child(HARDENED_OFFSET).is_ok());
+    }
+}
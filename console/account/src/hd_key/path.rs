@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::HARDENED_OFFSET;
+use snarkvm_console_network::prelude::*;
+
+/// The BIP-44 purpose.
+const BIP44_PURPOSE: u32 = 44;
+/// The SLIP-0044 coin type of Aleo.
+const ALEO_COIN_TYPE: u32 = 683;
+
+/// A derivation path of hardened indices, e.g. `m/44'/683'/0'/0'`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DerivationPath {
+    /// The child indices, with the hardened offset applied.
+    indices: Vec<u32>,
+}
+
+impl DerivationPath {
+    /// Returns the BIP-44 path of the Aleo account at the given index, as `m/44'/683'/{account}'/0'`.
+    /// The account index must be less than `HARDENED_OFFSET`.
+    pub fn aleo_account(account: u32) -> Result<Self> {
+        ensure!(account < HARDENED_OFFSET, "The account index {account} is out of range");
+        let indices = [BIP44_PURPOSE, ALEO_COIN_TYPE, account, 0];
+        Ok(Self { indices: indices.iter().map(|index| index + HARDENED_OFFSET).collect() })
+    }
+
+    /// Returns the child indices, with the hardened offset applied.
+    pub fn indices(&self) -> &[u32] {
+        &self.indices
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = Error;
+
+    /// Parses a derivation path of hardened indices, e.g. `m/44'/683'/0'/0'`.
+    fn from_str(path: &str) -> Result<Self> {
+        let mut components = path.split('/');
+        ensure!(components.next() == Some("m"), "The derivation path '{path}' must start with 'm'");
+        let indices = components
+            .map(|component| {
+                let index = component
+                    .strip_suffix('\'')
+                    .ok_or_else(|| anyhow!("The index '{component}' in '{path}' must be hardened"))?;
+                let index = index.parse::<u32>().map_err(|_| anyhow!("Invalid index '{component}' in '{path}'"))?;
+                ensure!(index < HARDENED_OFFSET, "The index '{component}' in '{path}' is out of range");
+                Ok(index + HARDENED_OFFSET)
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { indices })
+    }
+}
+
+impl Display for DerivationPath {
+    /// Prints the derivation path, e.g. `m/44'/683'/0'/0'`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "m")?;
+        self.indices.iter().try_for_each(|index| write!(f, "/{}'", index - HARDENED_OFFSET))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        for path in ["m", "m/0'", "m/44'/683'/0'/0'", "m/2147483647'"] {
+            assert_eq!(DerivationPath::from_str(path).unwrap().to_string(), path);
+        }
+        assert_eq!(DerivationPath::from_str("m/44'/683'/7'/0'").unwrap(), DerivationPath::aleo_account(7).unwrap());
+        assert_eq!(
+            DerivationPath::from_str("m/44'/683'/2147483647'/0'").unwrap(),
+            DerivationPath::aleo_account(HARDENED_OFFSET - 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for path in ["", "44'", "m/", "m/0", "m/a'", "m/2147483648'", "m/44'/683'/0'/0"] {
+            assert!(DerivationPath::from_str(path).is_err(), "Path '{path}' should be invalid");
+        }
+        // Ensure an account index that would wrap around is rejected.
+        assert!(DerivationPath::aleo_account(HARDENED_OFFSET).is_err());
+        assert!(DerivationPath::aleo_account(u32::MAX).is_err());
+    }
+}
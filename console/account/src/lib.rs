@@ -12,11 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_code)]
 #![allow(clippy::too_many_arguments)]
 #![warn(clippy::cast_possible_truncation)]
 #![cfg_attr(test, allow(clippy::assertions_on_result_states))]
 
+#[macro_use]
+extern crate alloc;
+
 pub use snarkvm_console_types::{environment::prelude::*, Address, Field, Group, Scalar};
 
 mod address;
@@ -26,11 +30,21 @@ pub mod compute_key;
 #[cfg(feature = "compute_key")]
 pub use compute_key::*;
 
+#[cfg(feature = "detection_key")]
+pub mod detection_key;
+#[cfg(feature = "detection_key")]
+pub use detection_key::*;
+
 #[cfg(feature = "graph_key")]
 pub mod graph_key;
 #[cfg(feature = "graph_key")]
 pub use graph_key::*;
 
+#[cfg(feature = "hd_key")]
+pub mod hd_key;
+#[cfg(feature = "hd_key")]
+pub use hd_key::*;
+
 #[cfg(feature = "private_key")]
 pub mod private_key;
 #[cfg(feature = "private_key")]
@@ -41,6 +55,11 @@ pub mod signature;
 #[cfg(feature = "signature")]
 pub use signature::*;
 
+#[cfg(feature = "threshold")]
+pub mod threshold;
+#[cfg(feature = "threshold")]
+pub use threshold::*;
+
 #[cfg(feature = "view_key")]
 pub mod view_key;
 #[cfg(feature = "view_key")]
@@ -0,0 +1,344 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+use crate::Address;
+
+use serde::{Deserialize, Serialize};
+
+use chacha20poly1305::{
+    aead::{Aead, KeyInit, Payload},
+    ChaCha20Poly1305,
+    Key,
+    Nonce,
+};
+use zeroize::Zeroizing;
+
+/// The version of the keystore format.
+const KEYSTORE_VERSION: u8 = 1;
+/// The name of the key derivation function.
+const KDF_NAME: &str = "argon2id";
+/// The name of the authenticated cipher.
+const CIPHER_NAME: &str = "chacha20poly1305";
+/// The number of bytes in the salt.
+const SALT_SIZE: usize = 32;
+/// The number of bytes in the nonce.
+const NONCE_SIZE: usize = 12;
+/// The minimum memory cost, in KiB.
+const MIN_MEMORY_COST: u32 = 19 * 1024;
+/// The maximum memory cost, in KiB.
+const MAX_MEMORY_COST: u32 = 4 * 1024 * 1024;
+/// The minimum number of iterations.
+const MIN_TIME_COST: u32 = 2;
+/// The maximum number of iterations.
+const MAX_TIME_COST: u32 = 64;
+/// The maximum degree of parallelism.
+const MAX_PARALLELISM: u32 = 16;
+
+/// The parameters of the Argon2id key derivation of a keystore.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct KeystoreParameters {
+    /// The memory cost, in KiB.
+    pub memory_cost: u32,
+    /// The number of iterations.
+    pub time_cost: u32,
+    /// The degree of parallelism.
+    pub parallelism: u32,
+}
+
+impl Default for KeystoreParameters {
+    /// Returns the recommended Argon2id parameters (64 MiB, 3 iterations, 1 lane).
+    fn default() -> Self {
+        Self { memory_cost: 64 * 1024, time_cost: 3, parallelism: 1 }
+    }
+}
+
+impl KeystoreParameters {
+    /// Ensures the parameters are within the supported bounds.
+    ///
+    /// The lower bounds prevent keystores that are cheap to brute force, and the upper bounds prevent
+    /// a crafted keystore from exhausting the memory or time of the machine that decrypts it.
+    pub fn check(&self) -> Result<()> {
+        ensure!(
+            (MIN_MEMORY_COST..=MAX_MEMORY_COST).contains(&self.memory_cost),
+            "The keystore memory cost must be between {MIN_MEMORY_COST} and {MAX_MEMORY_COST} KiB, found {}",
+            self.memory_cost
+        );
+        ensure!(
+            (MIN_TIME_COST..=MAX_TIME_COST).contains(&self.time_cost),
+            "The keystore time cost must be between {MIN_TIME_COST} and {MAX_TIME_COST}, found {}",
+            self.time_cost
+        );
+        ensure!(
+            (1..=MAX_PARALLELISM).contains(&self.parallelism),
+            "The keystore parallelism must be between 1 and {MAX_PARALLELISM}, found {}",
+            self.parallelism
+        );
+        Ok(())
+    }
+}
+
+/// The key derivation section of a keystore.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct KeystoreKdf {
+    /// The name of the key derivation function.
+    name: String,
+    /// The salt, as hex.
+    salt: String,
+    /// The parameters of the key derivation.
+    #[serde(flatten)]
+    parameters: KeystoreParameters,
+}
+
+/// The cipher section of a keystore.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct KeystoreCipher {
+    /// The name of the authenticated cipher.
+    name: String,
+    /// The nonce, as hex.
+    nonce: String,
+    /// The encrypted private key seed, with its authentication tag, as hex.
+    ciphertext: String,
+}
+
+/// A password-encrypted private key, serialized as JSON.
+///
+/// The private key seed is encrypted with ChaCha20-Poly1305 under a key derived from the password with
+/// Argon2id. The address and key derivation parameters are authenticated as associated data.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct Keystore<N: Network> {
+    /// The version of the keystore format.
+    version: u8,
+    /// The address of the private key.
+    address: Address<N>,
+    /// The key derivation section.
+    kdf: KeystoreKdf,
+    /// The cipher section.
+    cipher: KeystoreCipher,
+}
+
+impl<N: Network> Keystore<N> {
+    /// Returns the address of the encrypted private key.
+    pub const fn address(&self) -> &Address<N> {
+        &self.address
+    }
+
+    /// Returns the associated data of the keystore, which binds the address and key derivation to the ciphertext.
+    fn associated_data(address: &Address<N>, salt: &[u8], parameters: &KeystoreParameters) -> Result<Vec<u8>> {
+        let mut associated_data = vec![KEYSTORE_VERSION];
+        address.write_le(&mut associated_data)?;
+        associated_data.extend_from_slice(salt);
+        associated_data.extend_from_slice(&parameters.memory_cost.to_le_bytes());
+        associated_data.extend_from_slice(&parameters.time_cost.to_le_bytes());
+        associated_data.extend_from_slice(&parameters.parallelism.to_le_bytes());
+        Ok(associated_data)
+    }
+
+    /// Derives the encryption key from the given password, salt, and parameters.
+    fn derive_key(password: &[u8], salt: &[u8], parameters: &KeystoreParameters) -> Result<Zeroizing<[u8; 32]>> {
+        // Ensure the parameters are within bounds, before allocating the memory for the key derivation.
+        parameters.check()?;
+        let params =
+            argon2::Params::new(parameters.memory_cost, parameters.time_cost, parameters.parallelism, Some(32))
+                .map_err(|e| anyhow!("Invalid keystore parameters: {e}"))?;
+        let argon2 = argon2::Argon2::new(argon2::Algorithm::Argon2id, argon2::Version::V0x13, params);
+        let mut key = Zeroizing::new([0u8; 32]);
+        argon2
+            .hash_password_into(password, salt, key.as_mut())
+            .map_err(|e| anyhow!("Failed to derive the key: {e}"))?;
+        Ok(key)
+    }
+}
+
+impl<N: Network> FromStr for Keystore<N> {
+    type Err = Error;
+
+    /// Reads in a keystore from a JSON string.
+    fn from_str(keystore: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(keystore)?)
+    }
+}
+
+impl<N: Network> Display for Keystore<N> {
+    /// Writes the keystore as a JSON string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string_pretty(self).map_err(|_| fmt::Error)?)
+    }
+}
+
+impl<N: Network> PrivateKey<N> {
+    /// Encrypts the private key into a keystore under the given password, with the recommended parameters.
+    pub fn to_keystore<R: Rng + CryptoRng>(&self, password: &str, rng: &mut R) -> Result<Keystore<N>> {
+        self.to_keystore_with_parameters(password, KeystoreParameters::default(), rng)
+    }
+
+    /// Encrypts the private key into a keystore under the given password, with the given parameters.
+    pub fn to_keystore_with_parameters<R: Rng + CryptoRng>(
+        &self,
+        password: &str,
+        parameters: KeystoreParameters,
+        rng: &mut R,
+    ) -> Result<Keystore<N>> {
+        // Sample the salt and nonce.
+        let salt: [u8; SALT_SIZE] = rng.gen();
+        let nonce: [u8; NONCE_SIZE] = rng.gen();
+
+        // Derive the encryption key, and encrypt the private key seed.
+        let address = Address::try_from(self)?;
+        let key = Keystore::<N>::derive_key(password.as_bytes(), &salt, &parameters)?;
+        let aad = Keystore::associated_data(&address, &salt, &parameters)?;
+        let seed = Zeroizing::new(self.seed.to_bytes_le()?);
+        let ciphertext = ChaCha20Poly1305::new(Key::from_slice(key.as_ref()))
+            .encrypt(Nonce::from_slice(&nonce), Payload { msg: &seed, aad: &aad })
+            .map_err(|_| anyhow!("Failed to encrypt the private key"))?;
+
+        Ok(Keystore {
+            version: KEYSTORE_VERSION,
+            address,
+            kdf: KeystoreKdf { name: KDF_NAME.to_string(), salt: hex::encode(salt), parameters },
+            cipher: KeystoreCipher {
+                name: CIPHER_NAME.to_string(),
+                nonce: hex::encode(nonce),
+                ciphertext: hex::encode(ciphertext),
+            },
+        })
+    }
+
+    /// Decrypts the private key from the given keystore with the given password.
+    pub fn from_keystore(keystore: &Keystore<N>, password: &str) -> Result<Self> {
+        // Ensure the keystore format is supported.
+        ensure!(keystore.version == KEYSTORE_VERSION, "Unsupported keystore version {}", keystore.version);
+        ensure!(keystore.kdf.name == KDF_NAME, "Unsupported keystore key derivation '{}'", keystore.kdf.name);
+        ensure!(keystore.cipher.name == CIPHER_NAME, "Unsupported keystore cipher '{}'", keystore.cipher.name);
+
+        // Decode the salt and nonce.
+        let salt = hex::decode(&keystore.kdf.salt)?;
+        let nonce = hex::decode(&keystore.cipher.nonce)?;
+        ensure!(salt.len() == SALT_SIZE, "Invalid keystore salt length {}", salt.len());
+        ensure!(nonce.len() == NONCE_SIZE, "Invalid keystore nonce length {}", nonce.len());
+
+        // Derive the encryption key, and decrypt the private key seed.
+        let key = Keystore::<N>::derive_key(password.as_bytes(), &salt, &keystore.kdf.parameters)?;
+        let aad = Keystore::associated_data(&keystore.address, &salt, &keystore.kdf.parameters)?;
+        let seed = ChaCha20Poly1305::new(Key::from_slice(key.as_ref()))
+            .decrypt(Nonce::from_slice(&nonce), Payload { msg: &hex::decode(&keystore.cipher.ciphertext)?, aad: &aad })
+            .map(Zeroizing::new)
+            .map_err(|_| anyhow!("Failed to decrypt the keystore: invalid password or corrupted keystore"))?;
+
+        // Recover the private key, and ensure it matches the address.
+        let private_key = Self::try_from(Field::read_le(&seed[..])?)?;
+        ensure!(Address::try_from(&private_key)? == keystore.address, "The keystore address does not match its key");
+        Ok(private_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 5;
+
+    /// The minimum parameters, used to keep the tests fast.
+    const TEST_PARAMETERS: KeystoreParameters =
+        KeystoreParameters { memory_cost: MIN_MEMORY_COST, time_cost: MIN_TIME_COST, parallelism: 1 };
+
+    #[test]
+    fn test_keystore() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+
+            // Encrypt and decrypt the private key.
+            let keystore = private_key.to_keystore_with_parameters("password", TEST_PARAMETERS, &mut rng)?;
+            assert_eq!(*keystore.address(), Address::try_from(&private_key)?);
+            assert_eq!(private_key, PrivateKey::from_keystore(&keystore, "password")?);
+
+            // Ensure the keystore round trips through its string representation.
+            let candidate = Keystore::<CurrentNetwork>::from_str(&keystore.to_string())?;
+            assert_eq!(keystore, candidate);
+            assert_eq!(private_key, PrivateKey::from_keystore(&candidate, "password")?);
+
+            // Ensure the wrong password is rejected.
+            assert!(PrivateKey::from_keystore(&keystore, "passw0rd").is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_keystore_tampered() -> Result<()> {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let keystore = private_key.to_keystore_with_parameters("password", TEST_PARAMETERS, &mut rng)?;
+
+        // Ensure a different address is rejected.
+        let mut candidate = keystore.clone();
+        candidate.address = Address::try_from(&PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+        assert!(PrivateKey::from_keystore(&candidate, "password").is_err());
+
+        // Ensure weaker parameters are rejected.
+        let mut candidate = keystore.clone();
+        candidate.kdf.parameters.time_cost += 1;
+        assert!(PrivateKey::from_keystore(&candidate, "password").is_err());
+
+        // Ensure an unsupported version is rejected.
+        let mut candidate = keystore;
+        candidate.version = 2;
+        assert!(PrivateKey::from_keystore(&candidate, "password").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_keystore_parameter_bounds() -> Result<()> {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+
+        // Ensure parameters below the minimum are rejected on encryption.
+        for parameters in [
+            KeystoreParameters { memory_cost: MIN_MEMORY_COST - 1, ..TEST_PARAMETERS },
+            KeystoreParameters { time_cost: MIN_TIME_COST - 1, ..TEST_PARAMETERS },
+            KeystoreParameters { parallelism: 0, ..TEST_PARAMETERS },
+        ] {
+            assert!(private_key.to_keystore_with_parameters("password", parameters, &mut rng).is_err());
+        }
+
+        // Ensure a keystore with parameters above the maximum is rejected before deriving the key.
+        let keystore = private_key.to_keystore_with_parameters("password", TEST_PARAMETERS, &mut rng)?;
+        for parameters in [
+            KeystoreParameters { memory_cost: MAX_MEMORY_COST + 1, ..TEST_PARAMETERS },
+            KeystoreParameters { time_cost: u32::MAX, ..TEST_PARAMETERS },
+            KeystoreParameters { parallelism: MAX_PARALLELISM + 1, ..TEST_PARAMETERS },
+        ] {
+            let mut candidate = keystore.clone();
+            candidate.kdf.parameters = parameters;
+            assert!(PrivateKey::from_keystore(&candidate, "password").is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_keystore_default_parameters() -> Result<()> {
+        let mut rng = TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+
+        // Ensure the recommended parameters round trip.
+        let keystore = private_key.to_keystore("password", &mut rng)?;
+        assert_eq!(private_key, PrivateKey::from_keystore(&keystore, "password")?);
+        Ok(())
+    }
+}
@@ -30,6 +30,11 @@ impl<N: Network> PrivateKey<N> {
     pub fn sign_bits<R: Rng + CryptoRng>(&self, message: &[bool], rng: &mut R) -> Result<Signature<N>> {
         Signature::sign_bits(self, message, rng)
     }
+
+    /// Returns a signature for the given off-chain message (as bytes) in the given domain using the private key.
+    pub fn sign_message<R: Rng + CryptoRng>(&self, domain: &str, message: &[u8], rng: &mut R) -> Result<Signature<N>> {
+        Signature::sign_message(self, domain, message, rng)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+/// The domain separator of off-chain messages.
+const MESSAGE_DOMAIN: &str = "AleoSignedMessage0";
+
+impl<N: Network> Signature<N> {
+    /// Returns a signature for the given off-chain message (as bytes) in the given domain, such as the origin of a dApp.
+    /// The signed preimage is domain-separated from transaction signatures, so the signature cannot be replayed as one.
+    #[cfg(feature = "private_key")]
+    pub fn sign_message<R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        domain: &str,
+        message: &[u8],
+        rng: &mut R,
+    ) -> Result<Self> {
+        Self::sign(private_key, &Self::message_preimage(domain, message)?, rng)
+    }
+
+    /// Verifies a signature for the given off-chain message (as bytes) in the given domain.
+    pub fn verify_message(&self, address: &Address<N>, domain: &str, message: &[u8]) -> bool {
+        match Self::message_preimage(domain, message) {
+            Ok(preimage) => self.verify(address, &preimage),
+            Err(_) => false,
+        }
+    }
+
+    /// Returns the signed preimage of an off-chain message, as:
+    ///     (MESSAGE_DOMAIN, len(domain), Hash(domain), len(message), Hash(message))
+    fn message_preimage(domain: &str, message: &[u8]) -> Result<Vec<Field<N>>> {
+        // Ensure the domain is not empty.
+        ensure!(!domain.is_empty(), "The message domain must not be empty");
+
+        // Pack the bytes into field elements, and hash them.
+        let hash = |bytes: &[u8]| -> Result<Field<N>> {
+            let fields = bytes
+                .to_bits_le()
+                .chunks(Field::<N>::size_in_data_bits())
+                .map(Field::from_bits_le)
+                .collect::<Result<Vec<_>>>()?;
+            N::hash_psd8(&fields)
+        };
+
+        Ok(vec![
+            Field::new_domain_separator(MESSAGE_DOMAIN),
+            Field::from_u64(domain.len() as u64),
+            hash(domain.as_bytes())?,
+            Field::from_u64(message.len() as u64),
+            hash(message)?,
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_sign_and_verify_message() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        for i in 0..ITERATIONS {
+            // Sample an address and a private key.
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Check that the signature is valid for the message.
+            let message: Vec<u8> = (0..i).map(|_| Uniform::rand(rng)).collect();
+            let signature = private_key.sign_message("example.com", &message, rng)?;
+            assert!(signature.verify_message(&address, "example.com", &message));
+
+            // Check that the signature is invalid for another domain.
+            assert!(!signature.verify_message(&address, "example.org", &message));
+            // Check that the signature is invalid for an extended message.
+            let mut extended = message.clone();
+            extended.push(0);
+            assert!(!signature.verify_message(&address, "example.com", &extended));
+            // Check that the signature is invalid for another address.
+            let other = Address::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+            assert!(!signature.verify_message(&other, "example.com", &message));
+            // Check that the signature is invalid as a signature over the raw message.
+            assert!(!signature.verify_bytes(&address, &message));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_message_empty_domain() -> Result<()> {
+        let rng = &mut TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        assert!(private_key.sign_message("", b"hello", rng).is_err());
+        Ok(())
+    }
+}
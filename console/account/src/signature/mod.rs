@@ -15,6 +15,7 @@
 mod bitwise;
 mod bytes;
 mod from_bits;
+mod message;
 mod parse;
 mod serialize;
 mod size_in_bits;
@@ -32,7 +33,7 @@ use crate::PrivateKey;
 
 use crate::address::Address;
 use snarkvm_console_network::prelude::*;
-use snarkvm_console_types::{Boolean, Field, Scalar};
+use snarkvm_console_types::{Boolean, Field, Group, Scalar};
 
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Signature<N: Network> {
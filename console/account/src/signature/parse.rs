@@ -39,7 +39,7 @@ impl<N: Network> FromStr for Signature<N> {
     /// Reads in the signature string.
     fn from_str(signature: &str) -> Result<Self, Self::Err> {
         // Decode the signature string from bech32m.
-        let (hrp, data, variant) = bech32::decode(signature)?;
+        let (hrp, data, variant) = bech32::decode(signature).map_err(|e| anyhow!("{e}"))?;
         if hrp != SIGNATURE_PREFIX {
             bail!("Failed to decode signature: '{hrp}' is an invalid prefix")
         } else if data.is_empty() {
@@ -48,7 +48,7 @@ impl<N: Network> FromStr for Signature<N> {
             bail!("Found an signature that is not bech32m encoded: {signature}");
         }
         // Decode the signature data from u5 to u8, and into the signature.
-        Ok(Self::read_le(&Vec::from_base32(&data)?[..])?)
+        Ok(Self::read_le(&Vec::from_base32(&data).map_err(|e| anyhow!("{e}"))?[..])?)
     }
 }
 
@@ -14,10 +14,105 @@
 
 use super::*;
 
+/// The domain separator of the randomizers in a batch verification.
+const BATCH_DOMAIN: &str = "AleoSignatureBatch0";
+/// The number of bits in each randomizer of a batch verification.
+const BATCH_RANDOMIZER_BITS: usize = 128;
+
 impl<N: Network> Signature<N> {
     /// Verifies (challenge == challenge') && (address == address') where:
     ///     challenge' := HashToScalar(G^response pk_sig^challenge, pk_sig, pr_sig, address, message)
     pub fn verify(&self, address: &Address<N>, message: &[Field<N>]) -> bool {
+        // Ensure the challenge is correct.
+        if !self.verify_challenge(address, message) {
+            return false;
+        }
+
+        // Derive the address from the compute key, and return `false` if this operation fails.
+        let candidate_address = match Address::try_from(self.compute_key) {
+            // Output the computed candidate address.
+            Ok(candidate_address) => candidate_address,
+            // Return `false` if the address errored.
+            Err(_) => return false,
+        };
+
+        // Return `true` if the address is correct.
+        *address == candidate_address
+    }
+
+    /// Verifies each of the given signatures for its address and message.
+    /// Returns `true` if every signature is valid.
+    ///
+    /// The challenge is a hash of the nonce commitment `g_r`, so it is checked for each signature.
+    /// The addresses are checked together, with one random linear combination of `address == pk_sig + pr_sig + G^sk_prf`:
+    ///     sum_i rho_i * (pk_sig_i + pr_sig_i - address_i) + G^(sum_i rho_i * sk_prf_i) == 0
+    /// where the 128-bit randomizers `rho_i` are derived from a hash of the batch.
+    ///
+    /// Note: As a signature stores its challenge rather than `g_r`, the challenge checks cannot be combined,
+    /// and each one still recomputes `g_r := G^response pk_sig^challenge`. Only the fixed-base multiplication
+    /// `G^sk_prf` of each address check is saved, so this is only modestly faster than `verify` on each signature.
+    #[allow(clippy::type_complexity)]
+    pub fn verify_batch(batch: &[(Self, Address<N>, Vec<Field<N>>)]) -> bool {
+        // Ensure the challenge of each signature is correct.
+        if !batch.iter().all(|(signature, address, message)| signature.verify_challenge(address, message)) {
+            return false;
+        }
+        // A single signature is checked directly.
+        if let [(signature, address, message)] = batch {
+            return signature.verify(address, message);
+        }
+
+        // Compute the term `pk_sig + pr_sig - address` for each signature.
+        let terms = batch
+            .iter()
+            .map(|(signature, address, _)| signature.compute_key.pk_sig() + signature.compute_key.pr_sig() - **address)
+            .collect::<Vec<_>>();
+
+        // Hash the terms and compute keys to derive the seed of the randomizers.
+        let mut preimage = Vec::with_capacity(1 + 6 * batch.len());
+        preimage.push(Field::new_domain_separator(BATCH_DOMAIN));
+        for ((signature, _, _), term) in batch.iter().zip(&terms) {
+            for point in [*term, signature.compute_key.pk_sig(), signature.compute_key.pr_sig()] {
+                preimage.extend([point.to_x_coordinate(), point.to_y_coordinate()]);
+            }
+        }
+        let seed = match N::hash_psd8(&preimage) {
+            Ok(seed) => seed,
+            Err(_) => return false,
+        };
+
+        // Derive the randomizer for each signature, and accumulate `sum_i rho_i * sk_prf_i`.
+        let mut randomizers = Vec::with_capacity(batch.len());
+        let mut sk_prf = Scalar::<N>::zero();
+        for (index, (signature, _, _)) in batch.iter().enumerate() {
+            let randomizer = match N::hash_psd2(&[seed, Field::from_u64(index as u64)]) {
+                Ok(randomizer) => randomizer.to_bits_le()[..BATCH_RANDOMIZER_BITS].to_vec(),
+                Err(_) => return false,
+            };
+            match Scalar::<N>::from_bits_le(&randomizer) {
+                Ok(rho) => sk_prf += rho * signature.compute_key.sk_prf(),
+                Err(_) => return false,
+            }
+            randomizers.push(randomizer);
+        }
+
+        // Compute `sum_i rho_i * term_i`, with one chain of doublings for all of the terms.
+        let mut sum = Group::<N>::zero();
+        for bit in (0..BATCH_RANDOMIZER_BITS).rev() {
+            sum = sum.double();
+            for (term, randomizer) in terms.iter().zip(&randomizers) {
+                if randomizer[bit] {
+                    sum += term;
+                }
+            }
+        }
+
+        // Return `true` if the combination of the address checks is zero.
+        (sum + N::g_scalar_multiply(&sk_prf)).is_zero()
+    }
+
+    /// Returns `true` if challenge == HashToScalar(G^response pk_sig^challenge, pk_sig, pr_sig, address, message).
+    fn verify_challenge(&self, address: &Address<N>, message: &[Field<N>]) -> bool {
         // Ensure the number of field elements does not exceed the maximum allowed size.
         if message.len() > N::MAX_DATA_SIZE_IN_FIELDS as usize {
             eprintln!("Cannot sign the signature: the signed message exceeds maximum allowed size");
@@ -38,23 +133,12 @@ impl<N: Network> Signature<N> {
         preimage.extend(message);
 
         // Hash to derive the verifier challenge, and return `false` if this operation fails.
-        let candidate_challenge = match N::hash_to_scalar_psd8(&preimage) {
-            // Output the computed candidate challenge.
-            Ok(candidate_challenge) => candidate_challenge,
+        match N::hash_to_scalar_psd8(&preimage) {
+            // Return `true` if the candidate challenge is correct.
+            Ok(candidate_challenge) => self.challenge == candidate_challenge,
             // Return `false` if the challenge errored.
-            Err(_) => return false,
-        };
-
-        // Derive the address from the compute key, and return `false` if this operation fails.
-        let candidate_address = match Address::try_from(self.compute_key) {
-            // Output the computed candidate address.
-            Ok(candidate_address) => candidate_address,
-            // Return `false` if the address errored.
-            Err(_) => return false,
-        };
-
-        // Return `true` if the candidate challenge and address are correct.
-        self.challenge == candidate_challenge && *address == candidate_address
+            Err(_) => false,
+        }
     }
 
     /// Verifies a signature for the given address and message (as bytes).
@@ -109,6 +193,51 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_verify_batch() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a batch of valid signatures.
+        let mut batch = (0..ITERATIONS)
+            .map(|i| {
+                let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+                let address = Address::try_from(&private_key)?;
+                let message: Vec<_> = (0..i % 10).map(|_| Uniform::rand(rng)).collect();
+                let signature = Signature::sign(&private_key, &message, rng)?;
+                Ok((signature, address, message))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        assert!(Signature::<CurrentNetwork>::verify_batch(&[]));
+        assert!(Signature::verify_batch(&batch[..1]));
+        assert!(Signature::verify_batch(&batch));
+
+        // Ensure the batch is invalid if any signature is for a different message.
+        let mut invalid_batch = batch.clone();
+        invalid_batch[ITERATIONS as usize / 2].2.push(Uniform::rand(rng));
+        assert!(!Signature::verify_batch(&invalid_batch));
+
+        // Sample a signature with a valid challenge for an address that its compute key does not derive.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let compute_key = ComputeKey::try_from(&private_key)?;
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        let message = vec![Uniform::rand(rng)];
+        let nonce = Scalar::rand(rng);
+        let g_r = CurrentNetwork::g_scalar_multiply(&nonce);
+        let mut preimage =
+            [g_r, compute_key.pk_sig(), compute_key.pr_sig(), *address].map(|point| point.to_x_coordinate()).to_vec();
+        preimage.extend(&message);
+        let challenge = CurrentNetwork::hash_to_scalar_psd8(&preimage)?;
+        let signature = Signature::from((challenge, nonce - challenge * private_key.sk_sig(), compute_key));
+        assert!(signature.verify_challenge(&address, &message));
+        assert!(!signature.verify(&address, &message));
+
+        // Ensure the batch is invalid if any address does not match its compute key.
+        batch[ITERATIONS as usize / 2] = (signature, address, message);
+        assert!(!Signature::verify_batch(&batch));
+        assert!(!Signature::verify_batch(&batch[ITERATIONS as usize / 2..][..1]));
+        Ok(())
+    }
+
     #[test]
     fn test_sign_and_verify_bytes() -> Result<()> {
         let rng = &mut TestRng::default();
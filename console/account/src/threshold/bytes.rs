@@ -0,0 +1,246 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+/// The version of the serialized threshold messages.
+const VERSION: u8 = 1;
+
+/// Reads and checks the version of a threshold message.
+fn read_version<R: Read>(mut reader: R) -> IoResult<()> {
+    let version = u8::read_le(&mut reader)?;
+    match version == VERSION {
+        true => Ok(()),
+        false => Err(error(format!("Invalid threshold message version ({version})"))),
+    }
+}
+
+/// Reads a length-prefixed list of elements.
+fn read_vec<T: FromBytes, R: Read>(mut reader: R) -> IoResult<Vec<T>> {
+    let length = u16::read_le(&mut reader)?;
+    (0..length).map(|_| T::read_le(&mut reader)).collect()
+}
+
+/// Writes a length-prefixed list of elements.
+fn write_vec<T: ToBytes, W: Write>(elements: &[T], mut writer: W) -> IoResult<()> {
+    u16::try_from(elements.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+    elements.iter().try_for_each(|element| element.write_le(&mut writer))
+}
+
+impl FromBytes for ThresholdParameters {
+    /// Reads the threshold parameters from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let threshold = u16::read_le(&mut reader)?;
+        let num_participants = u16::read_le(&mut reader)?;
+        Self::new(threshold, num_participants).map_err(|e| error(e.to_string()))
+    }
+}
+
+impl ToBytes for ThresholdParameters {
+    /// Writes the threshold parameters to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.threshold.write_le(&mut writer)?;
+        self.num_participants.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromBytes for Round1Package<N> {
+    /// Reads a round 1 package from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        read_version(&mut reader)?;
+        let session_id = Field::read_le(&mut reader)?;
+        let sender = u16::read_le(&mut reader)?;
+        let commitments = read_vec(&mut reader)?;
+        let commitment_proof = (Scalar::read_le(&mut reader)?, Scalar::read_le(&mut reader)?);
+        let pr_sig_share = Group::read_le(&mut reader)?;
+        let pr_sig_proof = (Scalar::read_le(&mut reader)?, Scalar::read_le(&mut reader)?);
+        Ok(Self { session_id, sender, commitments, commitment_proof, pr_sig_share, pr_sig_proof })
+    }
+}
+
+impl<N: Network> ToBytes for Round1Package<N> {
+    /// Writes a round 1 package to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        VERSION.write_le(&mut writer)?;
+        self.session_id.write_le(&mut writer)?;
+        self.sender.write_le(&mut writer)?;
+        write_vec(&self.commitments, &mut writer)?;
+        self.commitment_proof.0.write_le(&mut writer)?;
+        self.commitment_proof.1.write_le(&mut writer)?;
+        self.pr_sig_share.write_le(&mut writer)?;
+        self.pr_sig_proof.0.write_le(&mut writer)?;
+        self.pr_sig_proof.1.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromBytes for Round2Package<N> {
+    /// Reads a round 2 package from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        read_version(&mut reader)?;
+        let sender = u16::read_le(&mut reader)?;
+        let receiver = u16::read_le(&mut reader)?;
+        let share = Scalar::read_le(&mut reader)?;
+        Ok(Self { sender, receiver, share })
+    }
+}
+
+impl<N: Network> ToBytes for Round2Package<N> {
+    /// Writes a round 2 package to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        VERSION.write_le(&mut writer)?;
+        self.sender.write_le(&mut writer)?;
+        self.receiver.write_le(&mut writer)?;
+        self.share.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromBytes for SigningCommitments<N> {
+    /// Reads the signing commitments from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        read_version(&mut reader)?;
+        let identifier = u16::read_le(&mut reader)?;
+        let hiding = Group::read_le(&mut reader)?;
+        let binding = Group::read_le(&mut reader)?;
+        Ok(Self { identifier, hiding, binding })
+    }
+}
+
+impl<N: Network> ToBytes for SigningCommitments<N> {
+    /// Writes the signing commitments to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        VERSION.write_le(&mut writer)?;
+        self.identifier.write_le(&mut writer)?;
+        self.hiding.write_le(&mut writer)?;
+        self.binding.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromBytes for SignatureShare<N> {
+    /// Reads a signature share from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        read_version(&mut reader)?;
+        let identifier = u16::read_le(&mut reader)?;
+        let response = Scalar::read_le(&mut reader)?;
+        Ok(Self { identifier, response })
+    }
+}
+
+impl<N: Network> ToBytes for SignatureShare<N> {
+    /// Writes a signature share to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        VERSION.write_le(&mut writer)?;
+        self.identifier.write_le(&mut writer)?;
+        self.response.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> FromBytes for ThresholdPublicKey<N> {
+    /// Reads a threshold public key from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        read_version(&mut reader)?;
+        let parameters = ThresholdParameters::read_le(&mut reader)?;
+        let compute_key = ComputeKey::read_le(&mut reader)?;
+        let shares: Vec<Group<N>> = read_vec(&mut reader)?;
+        // Ensure there is a verification share for each participant.
+        if shares.len() != parameters.num_participants() as usize {
+            return Err(error(format!("Expected {} verification shares", parameters.num_participants())));
+        }
+        let verification_shares = (1..=parameters.num_participants()).zip_eq(shares).collect();
+        Ok(Self { parameters, compute_key, verification_shares })
+    }
+}
+
+impl<N: Network> ToBytes for ThresholdPublicKey<N> {
+    /// Writes a threshold public key to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        VERSION.write_le(&mut writer)?;
+        self.parameters.write_le(&mut writer)?;
+        self.compute_key.write_le(&mut writer)?;
+        write_vec(&self.verification_shares.values().copied().collect::<Vec<_>>(), &mut writer)
+    }
+}
+
+impl<N: Network> FromBytes for KeyShare<N> {
+    /// Reads a key share from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        read_version(&mut reader)?;
+        let identifier = u16::read_le(&mut reader)?;
+        let sk_sig_share = Scalar::read_le(&mut reader)?;
+        let public_key = ThresholdPublicKey::read_le(&mut reader)?;
+        // Ensure the key share matches its verification share.
+        match public_key.verification_share(identifier) {
+            Ok(share) if share == N::g_scalar_multiply(&sk_sig_share) => {
+                Ok(Self { identifier, sk_sig_share, public_key })
+            }
+            _ => Err(error(format!("Invalid key share for participant {identifier}"))),
+        }
+    }
+}
+
+impl<N: Network> ToBytes for KeyShare<N> {
+    /// Writes a key share to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        VERSION.write_le(&mut writer)?;
+        self.identifier.write_le(&mut writer)?;
+        self.sk_sig_share.write_le(&mut writer)?;
+        self.public_key.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Checks the given value round trips through its byte representation.
+    fn check_bytes<T: ToBytes + FromBytes + PartialEq + Debug>(expected: &T) {
+        let bytes = expected.to_bytes_le().unwrap();
+        assert_eq!(*expected, T::read_le(&bytes[..]).unwrap());
+        assert!(T::read_le(&bytes[1..]).is_err());
+    }
+
+    #[test]
+    fn test_bytes() {
+        let rng = &mut TestRng::default();
+        let parameters = ThresholdParameters::new(2, 2).unwrap();
+        check_bytes(&parameters);
+
+        // Check the key generation messages.
+        let session_id = Field::rand(rng);
+        let (mut first, first_round1) = DkgParticipant::<CurrentNetwork>::new(session_id, 1, parameters, rng).unwrap();
+        let (mut second, second_round1) =
+            DkgParticipant::<CurrentNetwork>::new(session_id, 2, parameters, rng).unwrap();
+        check_bytes(&first_round1);
+        let round1 = [first_round1, second_round1];
+        let to_second = first.round2(&round1).unwrap();
+        let to_first = second.round2(&round1).unwrap();
+        let bytes = to_second[0].to_bytes_le().unwrap();
+        assert!(to_second[0] == Round2Package::read_le(&bytes[..]).unwrap());
+
+        // Check the key shares.
+        let first = first.finish(&to_first).unwrap();
+        let second = second.finish(&to_second).unwrap();
+        check_bytes(&first);
+        check_bytes(first.public_key());
+
+        // Check the signing messages.
+        let message = [Field::rand(rng)];
+        let (first_nonces, first_commitments) = first.commit(rng);
+        let (_, second_commitments) = second.commit(rng);
+        check_bytes(&first_commitments);
+        let share = first.sign(first_nonces, &[first_commitments, second_commitments], &message).unwrap();
+        check_bytes(&share);
+    }
+}
@@ -0,0 +1,400 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+/// A Schnorr proof of knowledge of the discrete logarithm of a commitment, as `(challenge, response)`.
+pub type ProofOfKnowledge<N> = (Scalar<N>, Scalar<N>);
+
+/// The round 1 package of the key generation, broadcast by a participant to all participants.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Round1Package<N: Network> {
+    /// The identifier of the key generation session.
+    pub(super) session_id: Field<N>,
+    /// The identifier of the sender.
+    pub(super) sender: u16,
+    /// The commitments `G^a_k` to the coefficients of the secret polynomial of the sender.
+    pub(super) commitments: Vec<Group<N>>,
+    /// The proof of knowledge of the constant term of the secret polynomial.
+    pub(super) commitment_proof: ProofOfKnowledge<N>,
+    /// The contribution `G^r_i` of the sender to `pr_sig`.
+    pub(super) pr_sig_share: Group<N>,
+    /// The proof of knowledge of the discrete logarithm of the contribution to `pr_sig`.
+    pub(super) pr_sig_proof: ProofOfKnowledge<N>,
+}
+
+impl<N: Network> Round1Package<N> {
+    /// Returns the identifier of the key generation session.
+    pub const fn session_id(&self) -> Field<N> {
+        self.session_id
+    }
+
+    /// Returns the identifier of the sender.
+    pub const fn sender(&self) -> u16 {
+        self.sender
+    }
+}
+
+/// The round 2 package of the key generation, sent privately by a participant to a single participant.
+///
+/// The package contains a secret share, and must be sent over an authenticated and encrypted channel.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Round2Package<N: Network> {
+    /// The identifier of the sender.
+    pub(super) sender: u16,
+    /// The identifier of the receiver.
+    pub(super) receiver: u16,
+    /// The evaluation of the secret polynomial of the sender at the identifier of the receiver.
+    pub(super) share: Scalar<N>,
+}
+
+impl<N: Network> Round2Package<N> {
+    /// Returns the identifier of the sender.
+    pub const fn sender(&self) -> u16 {
+        self.sender
+    }
+
+    /// Returns the identifier of the receiver.
+    pub const fn receiver(&self) -> u16 {
+        self.receiver
+    }
+}
+
+impl<N: Network> Drop for Round2Package<N> {
+    /// Zeroizes the secret share.
+    fn drop(&mut self) {
+        zeroize(&mut self.share);
+    }
+}
+
+impl<N: Network> Debug for Round2Package<N> {
+    /// Prints the package without its secret share.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Round2Package").field("sender", &self.sender).field("receiver", &self.receiver).finish()
+    }
+}
+
+/// A participant in the distributed key generation of a threshold account.
+///
+/// Each participant samples a secret polynomial of degree `threshold - 1`, and the signature secret key
+/// `sk_sig` of the account is the sum of their constant terms. Likewise, `pr_sig` is the sum of the
+/// contributions of each participant, so neither `sk_sig` nor `r_sig` ever exist in a single place.
+///
+/// All participants must agree on a fresh `session_id` beforehand. It is bound into the proofs of knowledge,
+/// so round 1 packages cannot be replayed from another key generation.
+pub struct DkgParticipant<N: Network> {
+    /// The identifier of the key generation session.
+    session_id: Field<N>,
+    /// The participant identifier.
+    identifier: u16,
+    /// The threshold parameters.
+    parameters: ThresholdParameters,
+    /// The coefficients of the secret polynomial.
+    coefficients: Vec<Scalar<N>>,
+    /// The round 1 packages of all participants, once received.
+    round1: Option<BTreeMap<u16, Round1Package<N>>>,
+}
+
+impl<N: Network> DkgParticipant<N> {
+    /// Initializes a participant with the given session and participant identifiers, and returns its round 1 package.
+    pub fn new<R: Rng + CryptoRng>(
+        session_id: Field<N>,
+        identifier: u16,
+        parameters: ThresholdParameters,
+        rng: &mut R,
+    ) -> Result<(Self, Round1Package<N>)> {
+        // Ensure the identifier is valid.
+        parameters.ensure_participant(identifier)?;
+
+        // Sample the secret polynomial, and commit to its coefficients.
+        let coefficients = (0..parameters.threshold()).map(|_| Scalar::rand(rng)).collect::<Vec<_>>();
+        let commitments = coefficients.iter().map(N::g_scalar_multiply).collect::<Vec<_>>();
+        // Sample the contribution to `pr_sig`.
+        let r_sig_share = Scalar::rand(rng);
+        let pr_sig_share = N::g_scalar_multiply(&r_sig_share);
+
+        // Prove knowledge of the constant term and the contribution to `pr_sig`.
+        let commitment_proof = prove_knowledge(session_id, identifier, &coefficients[0], &commitments[0], rng)?;
+        let pr_sig_proof = prove_knowledge(session_id, identifier, &r_sig_share, &pr_sig_share, rng)?;
+
+        let package =
+            Round1Package { session_id, sender: identifier, commitments, commitment_proof, pr_sig_share, pr_sig_proof };
+        Ok((Self { session_id, identifier, parameters, coefficients, round1: None }, package))
+    }
+
+    /// Returns the identifier of the key generation session.
+    pub const fn session_id(&self) -> Field<N> {
+        self.session_id
+    }
+
+    /// Returns the participant identifier.
+    pub const fn identifier(&self) -> u16 {
+        self.identifier
+    }
+
+    /// Verifies the round 1 packages of all participants (including this participant),
+    /// and returns the round 2 packages to send to each other participant.
+    pub fn round2(&mut self, packages: &[Round1Package<N>]) -> Result<Vec<Round2Package<N>>> {
+        // Ensure round 2 has not already been performed.
+        ensure!(self.round1.is_none(), "Participant {} already performed round 2", self.identifier);
+        // Ensure there is exactly one package from each participant.
+        ensure!(
+            packages.len() == self.parameters.num_participants() as usize,
+            "Expected {} round 1 packages, found {}",
+            self.parameters.num_participants(),
+            packages.len()
+        );
+
+        let mut round1 = BTreeMap::new();
+        for package in packages {
+            // Ensure the package belongs to this session.
+            ensure!(
+                package.session_id == self.session_id,
+                "Participant {} sent a package from another session",
+                package.sender
+            );
+            // Ensure the sender is valid and unique.
+            self.parameters.ensure_participant(package.sender)?;
+            ensure!(
+                !round1.contains_key(&package.sender),
+                "Found duplicate packages from participant {}",
+                package.sender
+            );
+            // Ensure the number of commitments matches the threshold.
+            ensure!(
+                package.commitments.len() == self.parameters.threshold() as usize,
+                "Participant {} committed to {} coefficients, expected {}",
+                package.sender,
+                package.commitments.len(),
+                self.parameters.threshold()
+            );
+            // Verify the proofs of knowledge.
+            verify_knowledge(self.session_id, package.sender, &package.commitments[0], &package.commitment_proof)?;
+            verify_knowledge(self.session_id, package.sender, &package.pr_sig_share, &package.pr_sig_proof)?;
+            round1.insert(package.sender, package.clone());
+        }
+
+        // Ensure the package of this participant is unaltered.
+        let own_commitments = self.coefficients.iter().map(N::g_scalar_multiply).collect::<Vec<_>>();
+        ensure!(
+            round1[&self.identifier].commitments == own_commitments,
+            "The round 1 package of this participant differs"
+        );
+
+        // Evaluate the secret polynomial at each other participant.
+        let packages = round1
+            .keys()
+            .filter(|receiver| **receiver != self.identifier)
+            .map(|receiver| Round2Package {
+                sender: self.identifier,
+                receiver: *receiver,
+                share: evaluate_polynomial(&self.coefficients, *receiver),
+            })
+            .collect();
+        self.round1 = Some(round1);
+        Ok(packages)
+    }
+
+    /// Verifies the round 2 packages sent to this participant, and returns its key share.
+    pub fn finish(self, packages: &[Round2Package<N>]) -> Result<KeyShare<N>> {
+        let round1 = match &self.round1 {
+            Some(round1) => round1,
+            None => bail!("Participant {} must perform round 2 before finishing", self.identifier),
+        };
+        // Ensure there is exactly one package from each other participant.
+        ensure!(
+            packages.len() + 1 == round1.len(),
+            "Expected {} round 2 packages, found {}",
+            round1.len() - 1,
+            packages.len()
+        );
+
+        // Start from the evaluation of the secret polynomial of this participant.
+        let mut sk_sig_share = evaluate_polynomial(&self.coefficients, self.identifier);
+        let mut senders = BTreeSet::new();
+        for package in packages {
+            // Ensure the package is addressed to this participant, from a unique sender.
+            ensure!(package.receiver == self.identifier, "Received a package for participant {}", package.receiver);
+            ensure!(package.sender != self.identifier, "Received a package from this participant");
+            let commitments = match round1.get(&package.sender) {
+                Some(sender) => &sender.commitments,
+                None => bail!("Received a package from unknown participant {}", package.sender),
+            };
+            ensure!(senders.insert(package.sender), "Found duplicate packages from {}", package.sender);
+            // Verify the share against the commitments of the sender.
+            ensure!(
+                N::g_scalar_multiply(&package.share) == evaluate_commitments(commitments, self.identifier),
+                "Participant {} sent an invalid share",
+                package.sender
+            );
+            sk_sig_share += package.share;
+        }
+
+        // Compute the public key of the account.
+        let pk_sig = round1.values().map(|package| package.commitments[0]).sum::<Group<N>>();
+        let pr_sig = round1.values().map(|package| package.pr_sig_share).sum::<Group<N>>();
+        let compute_key = ComputeKey::try_from((pk_sig, pr_sig))?;
+        // Compute the verification share of each participant.
+        let verification_shares = round1
+            .keys()
+            .map(|identifier| {
+                let share =
+                    round1.values().map(|package| evaluate_commitments(&package.commitments, *identifier)).sum();
+                (*identifier, share)
+            })
+            .collect();
+
+        let public_key = ThresholdPublicKey { parameters: self.parameters, compute_key, verification_shares };
+        Ok(KeyShare { identifier: self.identifier, sk_sig_share, public_key })
+    }
+}
+
+impl<N: Network> Drop for DkgParticipant<N> {
+    /// Zeroizes the coefficients of the secret polynomial.
+    fn drop(&mut self) {
+        self.coefficients.iter_mut().for_each(zeroize);
+    }
+}
+
+/// Returns a Schnorr proof of knowledge of `secret`, where `commitment := G^secret`.
+fn prove_knowledge<N: Network, R: Rng + CryptoRng>(
+    session_id: Field<N>,
+    identifier: u16,
+    secret: &Scalar<N>,
+    commitment: &Group<N>,
+    rng: &mut R,
+) -> Result<ProofOfKnowledge<N>> {
+    let nonce = Scalar::rand(rng);
+    let challenge = knowledge_challenge(session_id, identifier, commitment, &N::g_scalar_multiply(&nonce))?;
+    Ok((challenge, nonce - challenge * secret))
+}
+
+/// Verifies the Schnorr proof of knowledge of the discrete logarithm of `commitment`.
+fn verify_knowledge<N: Network>(
+    session_id: Field<N>,
+    identifier: u16,
+    commitment: &Group<N>,
+    proof: &ProofOfKnowledge<N>,
+) -> Result<()> {
+    let (challenge, response) = proof;
+    let g_r = N::g_scalar_multiply(response) + (*commitment * challenge);
+    ensure!(
+        *challenge == knowledge_challenge(session_id, identifier, commitment, &g_r)?,
+        "Participant {identifier} sent an invalid proof of knowledge"
+    );
+    Ok(())
+}
+
+/// Returns the challenge of a proof of knowledge, as:
+///     HashToScalar(domain, session_id, identifier, commitment, nonce * G)
+fn knowledge_challenge<N: Network>(
+    session_id: Field<N>,
+    identifier: u16,
+    commitment: &Group<N>,
+    g_r: &Group<N>,
+) -> Result<Scalar<N>> {
+    N::hash_to_scalar_psd4(&[
+        Field::new_domain_separator(DKG_PROOF_DOMAIN),
+        session_id,
+        Field::from_u16(identifier),
+        commitment.to_x_coordinate(),
+        g_r.to_x_coordinate(),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_dkg() {
+        let rng = &mut TestRng::default();
+        let key_shares = super::super::tests::run_dkg(3, 5, rng);
+
+        // Ensure every key share matches its verification share.
+        for share in &key_shares {
+            let expected = share.public_key().verification_share(share.identifier()).unwrap();
+            assert_eq!(CurrentNetwork::g_scalar_multiply(&share.sk_sig_share), expected);
+        }
+        // Ensure any 3 key shares interpolate `pk_sig`.
+        let signers = [2u16, 3, 5];
+        let sk_sig = signers
+            .iter()
+            .map(|signer| {
+                lagrange_coefficient::<CurrentNetwork>(*signer, &signers).unwrap()
+                    * key_shares[*signer as usize - 1].sk_sig_share
+            })
+            .sum::<Scalar<CurrentNetwork>>();
+        assert_eq!(CurrentNetwork::g_scalar_multiply(&sk_sig), key_shares[0].public_key().compute_key().pk_sig());
+    }
+
+    #[test]
+    fn test_dkg_rejects_invalid_share() {
+        let rng = &mut TestRng::default();
+        let parameters = ThresholdParameters::new(2, 2).unwrap();
+        let session_id = Field::rand(rng);
+
+        let (mut first, first_round1) = DkgParticipant::<CurrentNetwork>::new(session_id, 1, parameters, rng).unwrap();
+        let (mut second, second_round1) =
+            DkgParticipant::<CurrentNetwork>::new(session_id, 2, parameters, rng).unwrap();
+        let round1 = [first_round1, second_round1];
+        let mut to_second = first.round2(&round1).unwrap();
+        let _ = second.round2(&round1).unwrap();
+
+        // Ensure a tampered share is rejected.
+        to_second[0].share += Scalar::one();
+        assert!(second.finish(&to_second).is_err());
+    }
+
+    #[test]
+    fn test_dkg_rejects_invalid_proof() {
+        let rng = &mut TestRng::default();
+        let parameters = ThresholdParameters::new(2, 2).unwrap();
+        let session_id = Field::rand(rng);
+
+        let (mut first, first_round1) = DkgParticipant::<CurrentNetwork>::new(session_id, 1, parameters, rng).unwrap();
+        let (_, mut second_round1) = DkgParticipant::<CurrentNetwork>::new(session_id, 2, parameters, rng).unwrap();
+        // Ensure a commitment without a proof of knowledge is rejected.
+        second_round1.commitments[0] = Group::rand(rng);
+        assert!(first.round2(&[first_round1, second_round1]).is_err());
+    }
+
+    #[test]
+    fn test_dkg_rejects_other_session() {
+        let rng = &mut TestRng::default();
+        let parameters = ThresholdParameters::new(2, 2).unwrap();
+
+        let (mut first, first_round1) =
+            DkgParticipant::<CurrentNetwork>::new(Field::rand(rng), 1, parameters, rng).unwrap();
+        let (_, mut second_round1) =
+            DkgParticipant::<CurrentNetwork>::new(Field::rand(rng), 2, parameters, rng).unwrap();
+        // Ensure a package from another session is rejected.
+        assert!(first.round2(&[first_round1.clone(), second_round1.clone()]).is_err());
+        // Ensure the proofs of knowledge do not verify under another session, even with the session identifier replaced.
+        second_round1.session_id = first.session_id();
+        assert!(first.round2(&[first_round1, second_round1]).is_err());
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(ThresholdParameters::new(0, 3).is_err());
+        assert!(ThresholdParameters::new(4, 3).is_err());
+        let parameters = ThresholdParameters::new(2, 3).unwrap();
+        let rng = &mut TestRng::default();
+        assert!(DkgParticipant::<CurrentNetwork>::new(Field::zero(), 0, parameters, rng).is_err());
+        assert!(DkgParticipant::<CurrentNetwork>::new(Field::zero(), 4, parameters, rng).is_err());
+    }
+}
@@ -0,0 +1,304 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod bytes;
+mod dkg;
+pub use dkg::*;
+mod sign;
+pub use sign::*;
+
+use crate::{ComputeKey, Signature};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Address, Field, Group, Scalar};
+
+use alloc::collections::{BTreeMap, BTreeSet};
+
+/// The domain separator of the proofs of knowledge in the key generation.
+const DKG_PROOF_DOMAIN: &str = "AleoThresholdKeyGenProof0";
+/// The domain separator of the binding factors in the signing.
+const BINDING_FACTOR_DOMAIN: &str = "AleoThresholdBindingFactor0";
+
+/// The parameters of a `t`-of-`n` threshold account, where any `threshold` of the `num_participants`
+/// participants, identified as `1..=num_participants`, may sign together.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ThresholdParameters {
+    /// The minimum number of participants required to sign.
+    threshold: u16,
+    /// The total number of participants.
+    num_participants: u16,
+}
+
+impl ThresholdParameters {
+    /// Initializes the parameters of a `threshold`-of-`num_participants` account.
+    pub fn new(threshold: u16, num_participants: u16) -> Result<Self> {
+        ensure!(threshold >= 1, "The threshold must be at least 1");
+        ensure!(
+            threshold <= num_participants,
+            "The threshold ({threshold}) exceeds the participants ({num_participants})"
+        );
+        Ok(Self { threshold, num_participants })
+    }
+
+    /// Returns the minimum number of participants required to sign.
+    pub const fn threshold(&self) -> u16 {
+        self.threshold
+    }
+
+    /// Returns the total number of participants.
+    pub const fn num_participants(&self) -> u16 {
+        self.num_participants
+    }
+
+    /// Ensures the given participant identifier is in `1..=num_participants`.
+    fn ensure_participant(&self, identifier: u16) -> Result<()> {
+        ensure!(
+            (1..=self.num_participants).contains(&identifier),
+            "Participant {identifier} is not in 1..={}",
+            self.num_participants
+        );
+        Ok(())
+    }
+}
+
+/// The share of a participant in a threshold account, produced by the distributed key generation.
+/// The signature secret key `sk_sig` and the randomizer `r_sig` of the account are never reconstructed.
+///
+/// As a result, a threshold account has no view key (`sk_sig + r_sig + sk_prf`). It cannot decrypt its records,
+/// nor compute their serial numbers, which require `gamma := H^sk_sig`, so it cannot spend records.
+/// A threshold account may only sign messages, e.g. to authorize an action on public state.
+#[derive(Clone, PartialEq, Eq)]
+pub struct KeyShare<N: Network> {
+    /// The participant identifier.
+    identifier: u16,
+    /// The share of the signature secret key.
+    sk_sig_share: Scalar<N>,
+    /// The public key of the threshold account.
+    public_key: ThresholdPublicKey<N>,
+}
+
+impl<N: Network> KeyShare<N> {
+    /// Returns the participant identifier.
+    pub const fn identifier(&self) -> u16 {
+        self.identifier
+    }
+
+    /// Returns the public key of the threshold account.
+    pub const fn public_key(&self) -> &ThresholdPublicKey<N> {
+        &self.public_key
+    }
+}
+
+impl<N: Network> Drop for KeyShare<N> {
+    /// Zeroizes the secret share.
+    fn drop(&mut self) {
+        zeroize(&mut self.sk_sig_share);
+    }
+}
+
+impl<N: Network> Debug for KeyShare<N> {
+    /// Prints the key share without its secret share.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("KeyShare").field("identifier", &self.identifier).field("public_key", &self.public_key).finish()
+    }
+}
+
+/// The public key of a threshold account, with the verification share of each participant.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThresholdPublicKey<N: Network> {
+    /// The threshold parameters.
+    parameters: ThresholdParameters,
+    /// The compute key of the account.
+    compute_key: ComputeKey<N>,
+    /// The verification share `G^sk_sig_share` of each participant.
+    verification_shares: BTreeMap<u16, Group<N>>,
+}
+
+impl<N: Network> ThresholdPublicKey<N> {
+    /// Returns the threshold parameters.
+    pub const fn parameters(&self) -> ThresholdParameters {
+        self.parameters
+    }
+
+    /// Returns the compute key of the account.
+    pub const fn compute_key(&self) -> &ComputeKey<N> {
+        &self.compute_key
+    }
+
+    /// Returns the address of the account.
+    pub fn to_address(&self) -> Address<N> {
+        self.compute_key.to_address()
+    }
+
+    /// Returns the verification share of the given participant.
+    pub fn verification_share(&self, identifier: u16) -> Result<Group<N>> {
+        self.verification_shares.get(&identifier).copied().ok_or_else(|| anyhow!("Participant {identifier} is unknown"))
+    }
+}
+
+/// Returns the participant identifier as a scalar.
+fn to_scalar<N: Network>(identifier: u16) -> Scalar<N> {
+    Scalar::new(N::Scalar::from(identifier as u64))
+}
+
+/// Overwrites the given secret with zero.
+/// Note: The secret is passed through `black_box`, so the compiler does not elide the write before a drop.
+fn zeroize<N: Network>(secret: &mut Scalar<N>) {
+    *secret = Scalar::zero();
+    core::hint::black_box(secret);
+}
+
+/// Returns the evaluation of the polynomial with the given coefficients at the given participant identifier.
+fn evaluate_polynomial<N: Network>(coefficients: &[Scalar<N>], identifier: u16) -> Scalar<N> {
+    let x = to_scalar::<N>(identifier);
+    coefficients.iter().rev().fold(Scalar::zero(), |result, coefficient| result * x + coefficient)
+}
+
+/// Returns the evaluation of the committed polynomial (in the exponent) at the given participant identifier.
+fn evaluate_commitments<N: Network>(commitments: &[Group<N>], identifier: u16) -> Group<N> {
+    let x = to_scalar::<N>(identifier);
+    commitments.iter().rev().fold(Group::zero(), |result, commitment| result * x + commitment)
+}
+
+/// Returns the Lagrange coefficient at zero of the given participant, for the given set of signers.
+fn lagrange_coefficient<N: Network>(identifier: u16, signers: &[u16]) -> Result<Scalar<N>> {
+    // Ensure the signers are unique.
+    let mut unique = BTreeSet::new();
+    ensure!(signers.iter().all(|signer| unique.insert(*signer)), "The signers contain duplicate identifiers");
+
+    let x_i = to_scalar::<N>(identifier);
+    let (mut numerator, mut denominator) = (Scalar::<N>::one(), Scalar::<N>::one());
+    for signer in signers.iter().filter(|signer| **signer != identifier) {
+        let x_j = to_scalar::<N>(*signer);
+        numerator *= x_j;
+        denominator *= x_j - x_i;
+    }
+    Ok(numerator / denominator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Runs the distributed key generation among the given number of participants.
+    pub(super) fn run_dkg(threshold: u16, num_participants: u16, rng: &mut TestRng) -> Vec<KeyShare<CurrentNetwork>> {
+        let parameters = ThresholdParameters::new(threshold, num_participants).unwrap();
+        let session_id = Field::rand(rng);
+
+        // Round 1: Each participant broadcasts its commitments.
+        let (mut participants, round1): (Vec<_>, Vec<_>) = (1..=num_participants)
+            .map(|identifier| DkgParticipant::new(session_id, identifier, parameters, rng).unwrap())
+            .unzip();
+        // Round 2: Each participant sends a secret share to every other participant.
+        let round2 =
+            participants.iter_mut().flat_map(|participant| participant.round2(&round1).unwrap()).collect::<Vec<_>>();
+        // Each participant derives its key share from the shares sent to it.
+        participants
+            .into_iter()
+            .map(|participant| {
+                let identifier = participant.identifier();
+                let received =
+                    round2.iter().filter(|package| package.receiver() == identifier).cloned().collect::<Vec<_>>();
+                participant.finish(&received).unwrap()
+            })
+            .collect()
+    }
+
+    /// Signs the given message with the given signers.
+    fn threshold_sign(
+        key_shares: &[&KeyShare<CurrentNetwork>],
+        message: &[Field<CurrentNetwork>],
+        rng: &mut TestRng,
+    ) -> Result<Signature<CurrentNetwork>> {
+        // Round 1: Each signer commits to its nonces.
+        let (nonces, commitments): (Vec<_>, Vec<_>) = key_shares.iter().map(|share| share.commit(rng)).unzip();
+        // Round 2: Each signer signs the message.
+        let shares = key_shares
+            .iter()
+            .zip_eq(nonces)
+            .map(|(share, nonces)| share.sign(nonces, &commitments, message))
+            .collect::<Result<Vec<_>>>()?;
+        // Aggregate the signature shares.
+        key_shares[0].public_key().aggregate(&commitments, message, &shares)
+    }
+
+    #[test]
+    fn test_threshold_sign() {
+        let rng = &mut TestRng::default();
+        let key_shares = run_dkg(2, 3, rng);
+
+        // Ensure every participant agrees on the public key.
+        let public_key = key_shares[0].public_key().clone();
+        assert!(key_shares.iter().all(|share| *share.public_key() == public_key));
+        let address = public_key.to_address();
+
+        // Ensure every pair of participants can sign.
+        let message = (0..5).map(|_| Field::rand(rng)).collect::<Vec<_>>();
+        for (i, j) in [(0, 1), (0, 2), (1, 2)] {
+            let signature = threshold_sign(&[&key_shares[i], &key_shares[j]], &message, rng).unwrap();
+            assert!(signature.verify(&address, &message));
+            assert_eq!(signature.to_address(), address);
+        }
+
+        // Ensure all participants together can sign.
+        let signature = threshold_sign(&key_shares.iter().collect::<Vec<_>>(), &message, rng).unwrap();
+        assert!(signature.verify(&address, &message));
+    }
+
+    #[test]
+    fn test_threshold_sign_below_threshold() {
+        let rng = &mut TestRng::default();
+        let key_shares = run_dkg(3, 4, rng);
+
+        // Ensure too few signers are rejected.
+        let message = [Field::rand(rng)];
+        assert!(threshold_sign(&[&key_shares[0], &key_shares[1]], &message, rng).is_err());
+    }
+
+    #[test]
+    fn test_invalid_signature_share() {
+        let rng = &mut TestRng::default();
+        let key_shares = run_dkg(2, 2, rng);
+        let message = [Field::rand(rng)];
+
+        let (nonces, commitments): (Vec<_>, Vec<_>) = key_shares.iter().map(|share| share.commit(rng)).unzip();
+        let mut shares = key_shares
+            .iter()
+            .zip_eq(nonces)
+            .map(|(share, nonces)| share.sign(nonces, &commitments, &message).unwrap())
+            .collect::<Vec<_>>();
+        // Ensure a tampered share is rejected.
+        shares[1] = SignatureShare::new(shares[1].identifier(), shares[1].response() + Scalar::one());
+        assert!(key_shares[0].public_key().aggregate(&commitments, &message, &shares).is_err());
+    }
+
+    #[test]
+    fn test_lagrange_interpolation() {
+        let rng = &mut TestRng::default();
+        let coefficients = (0..3).map(|_| Scalar::<CurrentNetwork>::rand(rng)).collect::<Vec<_>>();
+        // Ensure any 3 evaluations interpolate the constant term.
+        let signers = [1u16, 4, 5];
+        let candidate = signers
+            .iter()
+            .map(|signer| {
+                lagrange_coefficient::<CurrentNetwork>(*signer, &signers).unwrap()
+                    * evaluate_polynomial(&coefficients, *signer)
+            })
+            .sum::<Scalar<CurrentNetwork>>();
+        assert_eq!(candidate, coefficients[0]);
+        // Ensure duplicate signers are rejected.
+        assert!(lagrange_coefficient::<CurrentNetwork>(1, &[1, 2, 2]).is_err());
+    }
+}
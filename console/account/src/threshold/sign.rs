@@ -0,0 +1,257 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+/// The secret nonces of a signer for a single signing session.
+///
+/// The nonces are consumed by [`KeyShare::sign`], and must never be reused across sessions.
+pub struct SigningNonces<N: Network> {
+    /// The identifier of the signer.
+    identifier: u16,
+    /// The hiding nonce.
+    hiding: Scalar<N>,
+    /// The binding nonce.
+    binding: Scalar<N>,
+}
+
+impl<N: Network> Drop for SigningNonces<N> {
+    /// Zeroizes the nonces.
+    fn drop(&mut self) {
+        zeroize(&mut self.hiding);
+        zeroize(&mut self.binding);
+    }
+}
+
+impl<N: Network> Debug for SigningNonces<N> {
+    /// Prints the nonces without their secrets.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("SigningNonces").field("identifier", &self.identifier).finish()
+    }
+}
+
+/// The public commitments to the nonces of a signer, broadcast to all signers in round 1 of signing.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SigningCommitments<N: Network> {
+    /// The identifier of the signer.
+    pub(super) identifier: u16,
+    /// The commitment to the hiding nonce.
+    pub(super) hiding: Group<N>,
+    /// The commitment to the binding nonce.
+    pub(super) binding: Group<N>,
+}
+
+impl<N: Network> SigningCommitments<N> {
+    /// Returns the identifier of the signer.
+    pub const fn identifier(&self) -> u16 {
+        self.identifier
+    }
+}
+
+/// The signature share of a signer, sent to the aggregator in round 2 of signing.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SignatureShare<N: Network> {
+    /// The identifier of the signer.
+    pub(super) identifier: u16,
+    /// The response share of the signer.
+    pub(super) response: Scalar<N>,
+}
+
+impl<N: Network> SignatureShare<N> {
+    /// Initializes a new signature share.
+    pub const fn new(identifier: u16, response: Scalar<N>) -> Self {
+        Self { identifier, response }
+    }
+
+    /// Returns the identifier of the signer.
+    pub const fn identifier(&self) -> u16 {
+        self.identifier
+    }
+
+    /// Returns the response share of the signer.
+    pub const fn response(&self) -> Scalar<N> {
+        self.response
+    }
+}
+
+/// The state of a signing session, derived by every signer and the aggregator from the commitments.
+struct SigningContext<N: Network> {
+    /// The identifiers of the signers, in ascending order.
+    signers: Vec<u16>,
+    /// The binding factor of each signer.
+    binding_factors: BTreeMap<u16, Scalar<N>>,
+    /// The verifier challenge.
+    challenge: Scalar<N>,
+}
+
+impl<N: Network> KeyShare<N> {
+    /// Samples the nonces for a new signing session, and returns them with their commitments.
+    pub fn commit<R: Rng + CryptoRng>(&self, rng: &mut R) -> (SigningNonces<N>, SigningCommitments<N>) {
+        let (hiding, binding) = (Scalar::rand(rng), Scalar::rand(rng));
+        let commitments = SigningCommitments {
+            identifier: self.identifier,
+            hiding: N::g_scalar_multiply(&hiding),
+            binding: N::g_scalar_multiply(&binding),
+        };
+        (SigningNonces { identifier: self.identifier, hiding, binding }, commitments)
+    }
+
+    /// Returns the signature share of this signer for the given message, where:
+    ///     response_i := hiding_i + (binding_factor_i * binding_i) - (challenge * lagrange_i * sk_sig_share_i)
+    pub fn sign(
+        &self,
+        nonces: SigningNonces<N>,
+        commitments: &[SigningCommitments<N>],
+        message: &[Field<N>],
+    ) -> Result<SignatureShare<N>> {
+        // Ensure the nonces belong to this signer, and match its commitments.
+        ensure!(nonces.identifier == self.identifier, "The nonces belong to participant {}", nonces.identifier);
+        match commitments.iter().find(|commitment| commitment.identifier == self.identifier) {
+            Some(commitment) => ensure!(
+                commitment.hiding == N::g_scalar_multiply(&nonces.hiding)
+                    && commitment.binding == N::g_scalar_multiply(&nonces.binding),
+                "The commitments of participant {} do not match its nonces",
+                self.identifier
+            ),
+            None => bail!("The commitments do not include participant {}", self.identifier),
+        }
+
+        // Derive the signing context.
+        let context = self.public_key.signing_context(commitments, message)?;
+        let lagrange = lagrange_coefficient::<N>(self.identifier, &context.signers)?;
+        let binding_factor = context.binding_factors[&self.identifier];
+
+        // Compute the response share.
+        let response =
+            nonces.hiding + (binding_factor * nonces.binding) - (context.challenge * lagrange * self.sk_sig_share);
+        Ok(SignatureShare { identifier: self.identifier, response })
+    }
+}
+
+impl<N: Network> ThresholdPublicKey<N> {
+    /// Verifies the signature shares of the signers, and returns the aggregated account signature.
+    pub fn aggregate(
+        &self,
+        commitments: &[SigningCommitments<N>],
+        message: &[Field<N>],
+        shares: &[SignatureShare<N>],
+    ) -> Result<Signature<N>> {
+        // Derive the signing context.
+        let context = self.signing_context(commitments, message)?;
+        // Ensure there is exactly one share from each signer.
+        ensure!(
+            shares.len() == context.signers.len(),
+            "Expected {} shares, found {}",
+            context.signers.len(),
+            shares.len()
+        );
+
+        let mut response = Scalar::zero();
+        for (signer, share) in context.signers.iter().zip_eq(shares.iter().sorted_by_key(|share| share.identifier)) {
+            ensure!(*signer == share.identifier, "Missing the signature share of participant {signer}");
+            // Retrieve the commitments of the signer.
+            let commitment = commitments.iter().find(|commitment| commitment.identifier == *signer);
+            let commitment = commitment.ok_or_else(|| anyhow!("Missing the commitments of participant {signer}"))?;
+            // Verify the share as:
+            //     G^response_i == hiding_i + (binding_factor_i * binding_i) - (challenge * lagrange_i * verification_share_i)
+            let lagrange = lagrange_coefficient::<N>(*signer, &context.signers)?;
+            let candidate = commitment.hiding + (commitment.binding * context.binding_factors[signer])
+                - (self.verification_share(*signer)? * (context.challenge * lagrange));
+            ensure!(
+                N::g_scalar_multiply(&share.response) == candidate,
+                "Participant {signer} sent an invalid signature share"
+            );
+            response += share.response;
+        }
+
+        // Construct and verify the signature.
+        let signature = Signature::from((context.challenge, response, self.compute_key));
+        ensure!(signature.verify(&self.to_address(), message), "The aggregated signature is invalid");
+        Ok(signature)
+    }
+
+    /// Returns the signing context for the given commitments and message.
+    fn signing_context(
+        &self,
+        commitments: &[SigningCommitments<N>],
+        message: &[Field<N>],
+    ) -> Result<SigningContext<N>> {
+        // Ensure the number of field elements does not exceed the maximum allowed size.
+        ensure!(
+            message.len() <= N::MAX_DATA_SIZE_IN_FIELDS as usize,
+            "Cannot sign the message: the message exceeds maximum allowed size"
+        );
+        // Ensure the signers are known, unique, and meet the threshold.
+        let commitments = commitments.iter().sorted_by_key(|commitment| commitment.identifier).collect::<Vec<_>>();
+        let signers = commitments.iter().map(|commitment| commitment.identifier).collect::<Vec<_>>();
+        ensure!(
+            signers.len() >= self.parameters.threshold() as usize,
+            "Found {} signers, expected at least {}",
+            signers.len(),
+            self.parameters.threshold()
+        );
+        ensure!(signers.iter().tuple_windows().all(|(a, b)| a != b), "Found duplicate signers");
+        for signer in &signers {
+            self.parameters.ensure_participant(*signer)?;
+        }
+
+        // Compute the binding factor of each signer, as:
+        //     HashToScalar(domain, identifier, address, Hash(message), Hash(commitments))
+        // where the commitments are hashed as full points, so they cannot be swapped for their negations.
+        let address = self.to_address();
+        let message_hash = N::hash_psd8(message)?;
+        let commitments_hash = N::hash_psd8(
+            &commitments
+                .iter()
+                .flat_map(|commitment| {
+                    [
+                        Field::from_u16(commitment.identifier),
+                        commitment.hiding.to_x_coordinate(),
+                        commitment.hiding.to_y_coordinate(),
+                        commitment.binding.to_x_coordinate(),
+                        commitment.binding.to_y_coordinate(),
+                    ]
+                })
+                .collect::<Vec<_>>(),
+        )?;
+        let binding_factors = signers
+            .iter()
+            .map(|signer| {
+                let binding_factor = N::hash_to_scalar_psd4(&[
+                    Field::new_domain_separator(BINDING_FACTOR_DOMAIN),
+                    Field::from_u16(*signer),
+                    address.to_x_coordinate(),
+                    message_hash,
+                    commitments_hash,
+                ])?;
+                Ok((*signer, binding_factor))
+            })
+            .collect::<Result<BTreeMap<_, _>>>()?;
+
+        // Compute the group commitment, as the sum of `hiding_i + (binding_factor_i * binding_i)`.
+        let g_r = commitments
+            .iter()
+            .map(|commitment| commitment.hiding + (commitment.binding * binding_factors[&commitment.identifier]))
+            .sum::<Group<N>>();
+
+        // Compute the verifier challenge, as in `Signature::sign`.
+        let compute_key = &self.compute_key;
+        let mut preimage = Vec::with_capacity(4 + message.len());
+        preimage
+            .extend([g_r, compute_key.pk_sig(), compute_key.pr_sig(), *address].map(|point| point.to_x_coordinate()));
+        preimage.extend(message);
+        let challenge = N::hash_to_scalar_psd8(&preimage)?;
+
+        Ok(SigningContext { signers, binding_factors, challenge })
+    }
+}
@@ -22,4 +22,12 @@ impl<E: Environment, const NUM_WINDOWS: u8, const WINDOW_SIZE: u8> Hash for BHP<
     fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
         Ok(self.hash_uncompressed(input)?.to_x_coordinate())
     }
+
+    /// Returns the BHP hash of each of the given inputs as a field element, hashing the inputs in parallel.
+    fn hash_batched(&self, inputs: &[Vec<Self::Input>]) -> Result<Vec<Self::Output>> {
+        match inputs.len() {
+            0 | 1 => inputs.iter().map(|input| self.hash(input)).collect(),
+            _ => cfg_iter!(inputs).map(|input| self.hash(input)).collect(),
+        }
+    }
 }
@@ -22,7 +22,10 @@ mod hash_uncompressed;
 
 use snarkvm_console_types::prelude::*;
 
-use std::sync::Arc;
+use alloc::sync::Arc;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
 
 const BHP_CHUNK_SIZE: usize = 3;
 
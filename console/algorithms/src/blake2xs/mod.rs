@@ -18,6 +18,8 @@
 /// <https://www.blake2.net/blake2x.pdf>
 mod hash_to_curve;
 
+use alloc::{string::String, vec::Vec};
+
 pub struct Blake2Xs;
 
 impl Blake2Xs {
@@ -20,7 +20,7 @@ mod hash_uncompressed;
 use crate::Blake2Xs;
 use snarkvm_console_types::prelude::*;
 
-use std::{borrow::Cow, sync::Arc};
+use alloc::{borrow::Cow, sync::Arc};
 
 /// Pedersen64 is an *additively-homomorphic* collision-resistant hash function that takes up to a 64-bit input.
 pub type Pedersen64<E> = Pedersen<E, 64>;
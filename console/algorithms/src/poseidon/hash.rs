@@ -23,4 +23,10 @@ impl<E: Environment, const RATE: usize> Hash for Poseidon<E, RATE> {
     fn hash(&self, input: &[Self::Input]) -> Result<Self::Output> {
         Ok(self.hash_many(input, 1)[0])
     }
+
+    /// Returns the cryptographic hash for each list of field elements as input.
+    #[inline]
+    fn hash_batched(&self, inputs: &[Vec<Self::Input>]) -> Result<Vec<Self::Output>> {
+        Ok(self.hash_many_batched(inputs, 1).into_iter().map(|output| output[0]).collect())
+    }
 }
@@ -22,15 +22,65 @@ impl<E: Environment, const RATE: usize> HashMany for Poseidon<E, RATE> {
     /// and returns the specified number of field elements as output.
     #[inline]
     fn hash_many(&self, input: &[Self::Input], num_outputs: u16) -> Vec<Self::Output> {
-        // Construct the preimage: [ DOMAIN || LENGTH(INPUT) || [0; RATE-2] || INPUT ].
+        let preimage = self.to_preimage(input);
+
+        let mut sponge = PoseidonSponge::<E, RATE, CAPACITY>::new(&self.parameters);
+        sponge.absorb(&preimage);
+        sponge.squeeze(num_outputs).to_vec()
+    }
+
+    /// Returns the cryptographic hash for each list of field elements as input,
+    /// and returns the specified number of field elements as output for each.
+    ///
+    /// Inputs of the same length are hashed in lockstep, sharing each round of the permutation,
+    /// and independent groups of inputs are hashed in parallel.
+    fn hash_many_batched(&self, inputs: &[Vec<Self::Input>], num_outputs: u16) -> Vec<Vec<Self::Output>> {
+        match inputs.len() {
+            0 => vec![],
+            1 => vec![self.hash_many(&inputs[0], num_outputs)],
+            _ => {
+                let outputs = cfg_chunks!(inputs, LOCKSTEP_SIZE)
+                    .map(|chunk| self.hash_many_lockstep(chunk, num_outputs))
+                    .collect::<Vec<_>>();
+                outputs.into_iter().flatten().collect()
+            }
+        }
+    }
+}
+
+/// The maximum number of inputs hashed in lockstep.
+const LOCKSTEP_SIZE: usize = 32;
+
+impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
+    /// Returns the preimage for the given input: [ DOMAIN || LENGTH(INPUT) || [0; RATE-2] || INPUT ].
+    #[inline]
+    fn to_preimage(&self, input: &[Field<E>]) -> Vec<Field<E>> {
         let mut preimage = Vec::with_capacity(RATE + input.len());
         preimage.push(self.domain);
         preimage.push(Field::<E>::from_u128(input.len() as u128));
         preimage.resize(RATE, Field::<E>::zero()); // Pad up to RATE.
         preimage.extend_from_slice(input);
+        preimage
+    }
 
-        let mut sponge = PoseidonSponge::<E, RATE, CAPACITY>::new(&self.parameters);
-        sponge.absorb(&preimage);
-        sponge.squeeze(num_outputs).to_vec()
+    /// Returns the hash for each of the given inputs, hashing runs of inputs with the same length in lockstep.
+    fn hash_many_lockstep(&self, inputs: &[Vec<Field<E>>], num_outputs: u16) -> Vec<Vec<Field<E>>> {
+        let mut outputs = Vec::with_capacity(inputs.len());
+        let mut start = 0;
+        while start < inputs.len() {
+            // Find the run of inputs with the same length.
+            let length = inputs[start].len();
+            let end =
+                inputs[start..].iter().position(|input| input.len() != length).map_or(inputs.len(), |i| start + i);
+            // Hash the run in lockstep.
+            let preimages = inputs[start..end].iter().map(|input| self.to_preimage(input)).collect::<Vec<_>>();
+            outputs.extend(PoseidonSponge::<E, RATE, CAPACITY>::absorb_and_squeeze_batch(
+                &self.parameters,
+                &preimages,
+                num_outputs,
+            ));
+            start = end;
+        }
+        outputs
     }
 }
@@ -19,8 +19,9 @@ use crate::poseidon::{
 use snarkvm_console_types::{prelude::*, Field};
 use snarkvm_fields::PoseidonParameters;
 
+use alloc::sync::Arc;
+use core::ops::DerefMut;
 use smallvec::SmallVec;
-use std::{ops::DerefMut, sync::Arc};
 
 /// A duplex sponge based using the Poseidon permutation.
 ///
@@ -100,59 +101,122 @@ impl<E: Environment, const RATE: usize, const CAPACITY: usize> AlgebraicSponge<E
 
 impl<E: Environment, const RATE: usize, const CAPACITY: usize> PoseidonSponge<E, RATE, CAPACITY> {
     #[inline]
-    fn apply_ark(&mut self, round_number: usize) {
-        for (state_elem, ark_elem) in self.state.iter_mut().zip(&self.parameters.ark[round_number]) {
+    fn apply_ark(
+        parameters: &PoseidonParameters<E::Field, RATE, CAPACITY>,
+        state: &mut State<E, RATE, CAPACITY>,
+        round_number: usize,
+    ) {
+        for (state_elem, ark_elem) in state.iter_mut().zip(&parameters.ark[round_number]) {
             *state_elem += Field::<E>::new(*ark_elem);
         }
     }
 
     #[inline]
-    fn apply_s_box(&mut self, is_full_round: bool) {
+    fn apply_s_box(
+        parameters: &PoseidonParameters<E::Field, RATE, CAPACITY>,
+        state: &mut State<E, RATE, CAPACITY>,
+        is_full_round: bool,
+    ) {
         // Full rounds apply the S Box (x^alpha) to every element of state
         if is_full_round {
-            for elem in self.state.iter_mut() {
+            for elem in state.iter_mut() {
                 let e = elem.deref_mut();
-                *e = e.pow([self.parameters.alpha]);
+                *e = e.pow([parameters.alpha]);
             }
         }
         // Partial rounds apply the S Box (x^alpha) to just the first element of state
         else {
-            let e = self.state[0].deref_mut();
-            *e = e.pow([self.parameters.alpha]);
+            let e = state[0].deref_mut();
+            *e = e.pow([parameters.alpha]);
         }
     }
 
     #[inline]
-    fn apply_mds(&mut self) {
+    fn apply_mds(parameters: &PoseidonParameters<E::Field, RATE, CAPACITY>, state: &mut State<E, RATE, CAPACITY>) {
         let mut new_state = State::default();
-        new_state.iter_mut().zip(&self.parameters.mds).for_each(|(new_elem, mds_row)| {
-            *new_elem = Field::new(E::Field::sum_of_products(self.state.iter().map(|e| e.deref()), mds_row.iter()));
+        new_state.iter_mut().zip(&parameters.mds).for_each(|(new_elem, mds_row)| {
+            *new_elem = Field::new(E::Field::sum_of_products(state.iter().map(|e| e.deref()), mds_row.iter()));
         });
-        self.state = new_state;
+        *state = new_state;
     }
 
+    /// Applies the permutation to each of the given states. Each round is applied to all of the states
+    /// before the next round, so the round constants are loaded once per round for the whole batch.
     #[inline]
-    fn permute(&mut self) {
+    fn permute_batch(
+        parameters: &PoseidonParameters<E::Field, RATE, CAPACITY>,
+        states: &mut [State<E, RATE, CAPACITY>],
+    ) {
         // Determine the partial rounds range bound.
-        let partial_rounds = self.parameters.partial_rounds;
-        let full_rounds = self.parameters.full_rounds;
+        let partial_rounds = parameters.partial_rounds;
+        let full_rounds = parameters.full_rounds;
         let full_rounds_over_2 = full_rounds / 2;
         let partial_round_range = full_rounds_over_2..(full_rounds_over_2 + partial_rounds);
 
         // Iterate through all rounds to permute.
         for i in 0..(partial_rounds + full_rounds) {
             let is_full_round = !partial_round_range.contains(&i);
-            self.apply_ark(i);
-            self.apply_s_box(is_full_round);
-            self.apply_mds();
+            for state in states.iter_mut() {
+                Self::apply_ark(parameters, state, i);
+                Self::apply_s_box(parameters, state, is_full_round);
+                Self::apply_mds(parameters, state);
+            }
+        }
+    }
+
+    #[inline]
+    fn permute(&mut self) {
+        Self::permute_batch(&self.parameters, core::slice::from_mut(&mut self.state));
+    }
+
+    /// Absorbs each of the given preimages into a fresh sponge, and squeezes `num_elements` field elements
+    /// from each sponge, in lockstep. The preimages must all have the same length.
+    ///
+    /// The outputs are equal to absorbing and squeezing each preimage with its own sponge.
+    pub(in crate::poseidon) fn absorb_and_squeeze_batch(
+        parameters: &PoseidonParameters<E::Field, RATE, CAPACITY>,
+        preimages: &[Vec<Field<E>>],
+        num_elements: u16,
+    ) -> Vec<Vec<Field<E>>> {
+        // Ensure the preimages all have the same length.
+        let length = preimages.first().map_or(0, |preimage| preimage.len());
+        assert!(preimages.iter().all(|preimage| preimage.len() == length), "Preimages must have the same length");
+
+        // Initialize the state for each sponge.
+        let mut states = vec![State::<E, RATE, CAPACITY>::default(); preimages.len()];
+
+        // Absorb the preimages, `RATE` elements at a time, permuting between each chunk.
+        for offset in (0..length).step_by(RATE) {
+            if offset > 0 {
+                Self::permute_batch(parameters, &mut states);
+            }
+            let end = core::cmp::min(offset + RATE, length);
+            for (state, preimage) in states.iter_mut().zip(preimages) {
+                for (state_elem, element) in state.rate_state_mut().iter_mut().zip(&preimage[offset..end]) {
+                    *state_elem += element;
+                }
+            }
+        }
+
+        // Squeeze the outputs, `RATE` elements at a time, permuting before each chunk.
+        let mut outputs = vec![Vec::with_capacity(num_elements as usize); preimages.len()];
+        let mut remaining = num_elements as usize;
+        while remaining > 0 {
+            Self::permute_batch(parameters, &mut states);
+            let chunk_size = core::cmp::min(RATE, remaining);
+            for (output, state) in outputs.iter_mut().zip(&states) {
+                output.extend_from_slice(state.rate_state(0..chunk_size));
+            }
+            remaining -= chunk_size;
         }
+        outputs
     }
 
     /// Absorbs everything in elements, this does not end in an absorption.
     #[inline]
     fn absorb_internal(&mut self, mut rate_start: usize, input: &[Field<E>]) {
         if !input.is_empty() {
-            let first_chunk_size = std::cmp::min(RATE - rate_start, input.len());
+            let first_chunk_size = core::cmp::min(RATE - rate_start, input.len());
             let num_elements_remaining = input.len() - first_chunk_size;
             let (first_chunk, rest_chunk) = input.split_at(first_chunk_size);
             let rest_chunks = rest_chunk.chunks(RATE);
@@ -167,7 +231,7 @@ impl<E: Environment, const RATE: usize, const CAPACITY: usize> PoseidonSponge<E,
 
             // Absorb the input elements, `RATE` elements at a time, except for the first chunk, which
             // is of size `RATE - rate_start`.
-            for (i, chunk) in std::iter::once(first_chunk).chain(rest_chunks).enumerate() {
+            for (i, chunk) in core::iter::once(first_chunk).chain(rest_chunks).enumerate() {
                 for (element, state_elem) in chunk.iter().zip(&mut self.state.rate_state_mut()[rate_start..]) {
                     *state_elem += element;
                 }
@@ -189,7 +253,7 @@ impl<E: Environment, const RATE: usize, const CAPACITY: usize> PoseidonSponge<E,
     fn squeeze_internal(&mut self, mut rate_start: usize, output: &mut [Field<E>]) {
         let output_size = output.len();
         if output_size != 0 {
-            let first_chunk_size = std::cmp::min(RATE - rate_start, output.len());
+            let first_chunk_size = core::cmp::min(RATE - rate_start, output.len());
             let num_output_remaining = output.len() - first_chunk_size;
             let (first_chunk, rest_chunk) = output.split_at_mut(first_chunk_size);
             assert_eq!(rest_chunk.len(), num_output_remaining);
@@ -205,7 +269,7 @@ impl<E: Environment, const RATE: usize, const CAPACITY: usize> PoseidonSponge<E,
 
             // Absorb the input output, `RATE` output at a time, except for the first chunk, which
             // is of size `RATE - rate_start`.
-            for (i, chunk) in std::iter::once(first_chunk).chain(rest_chunks).enumerate() {
+            for (i, chunk) in core::iter::once(first_chunk).chain(rest_chunks).enumerate() {
                 let range = rate_start..(rate_start + chunk.len());
                 debug_assert_eq!(
                     chunk.len(),
@@ -24,7 +24,10 @@ use crate::{poseidon::helpers::*, Elligator2};
 use snarkvm_console_types::prelude::*;
 use snarkvm_fields::{PoseidonDefaultField, PoseidonParameters};
 
-use std::sync::Arc;
+use alloc::sync::Arc;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
 
 const CAPACITY: usize = 1;
 
@@ -154,6 +157,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_hash_many_batched() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let poseidon = Poseidon4::<CurrentEnvironment>::setup("PoseidonBatchTest")?;
+
+        // Sample inputs, with runs of the same length and runs of different lengths.
+        let lengths = [0, 1, 1, 1, 3, 4, 4, 5, 9, 9, 9, 9].into_iter().cycle().take(100);
+        let inputs =
+            lengths.map(|length| (0..length).map(|_| Uniform::rand(rng)).collect::<Vec<_>>()).collect::<Vec<_>>();
+
+        // Ensure the batched hashes match the individual hashes.
+        for num_outputs in [0, 1, 4, 5, 9] {
+            let expected = inputs.iter().map(|input| poseidon.hash_many(input, num_outputs)).collect::<Vec<_>>();
+            assert_eq!(expected, poseidon.hash_many_batched(&inputs, num_outputs));
+        }
+        let expected = inputs.iter().map(|input| poseidon.hash(input)).collect::<Result<Vec<_>>>()?;
+        assert_eq!(expected, poseidon.hash_batched(&inputs)?);
+        assert!(poseidon.hash_batched(&[])?.is_empty());
+        Ok(())
+    }
+
     #[test]
     fn test_parameters() {
         fn single_rate_test<const RATE: usize>() {
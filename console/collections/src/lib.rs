@@ -12,11 +12,37 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_code)]
 #![allow(clippy::too_many_arguments)]
 #![warn(clippy::cast_possible_truncation)]
 #![cfg_attr(test, allow(clippy::assertions_on_result_states))]
 
+#[macro_use]
+extern crate alloc;
+
 pub use snarkvm_console_types::prelude::*;
 
+/// Without `std`, the timer macros from `aleo-std` compile to no-ops.
+#[cfg(not(feature = "std"))]
+macro_rules! timer {
+    ($($arg:tt)*) => {
+        ()
+    };
+}
+
+#[cfg(not(feature = "std"))]
+macro_rules! lap {
+    ($timer:expr, $($arg:tt)*) => {
+        let _ = &$timer;
+    };
+}
+
+#[cfg(not(feature = "std"))]
+macro_rules! finish {
+    ($timer:expr) => {
+        let _ = $timer;
+    };
+}
+
 pub mod merkle_tree;
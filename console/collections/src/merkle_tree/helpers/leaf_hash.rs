@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::hashes_per_task;
 use snarkvm_console_algorithms::{Poseidon, BHP};
 use snarkvm_console_types::prelude::*;
 
@@ -26,12 +27,23 @@ pub trait LeafHash: Clone + Send + Sync {
     /// Returns the hash of the given leaf node.
     fn hash_leaf(&self, leaf: &Self::Leaf) -> Result<Self::Hash>;
 
+    /// Returns the hash for each leaf node in the given chunk, in order.
+    fn hash_leaf_chunk(&self, leaves: &[Self::Leaf]) -> Result<Vec<Self::Hash>> {
+        leaves.iter().map(|leaf| self.hash_leaf(leaf)).collect()
+    }
+
     /// Returns the hash for each leaf node.
     fn hash_leaves(&self, leaves: &[Self::Leaf]) -> Result<Vec<Self::Hash>> {
         match leaves.len() {
             0 => Ok(vec![]),
-            1..=100 => leaves.iter().map(|leaf| self.hash_leaf(leaf)).collect(),
-            _ => cfg_iter!(leaves).map(|leaf| self.hash_leaf(leaf)).collect(),
+            1..=100 => self.hash_leaf_chunk(leaves),
+            num_leaves => {
+                // Hash the leaves in contiguous chunks, one chunk per task.
+                let chunks = cfg_chunks!(leaves, hashes_per_task(num_leaves))
+                    .map(|chunk| self.hash_leaf_chunk(chunk))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(chunks.into_iter().flatten().collect())
+            }
         }
     }
 }
@@ -62,4 +74,20 @@ impl<E: Environment, const RATE: usize> LeafHash for Poseidon<E, RATE> {
         // Hash the input.
         Hash::hash(self, &input)
     }
+
+    /// Returns the hash for each leaf node in the given chunk, in order.
+    fn hash_leaf_chunk(&self, leaves: &[Self::Leaf]) -> Result<Vec<Self::Hash>> {
+        // Prepend each leaf with a `0field` element.
+        let inputs = leaves
+            .iter()
+            .map(|leaf| {
+                let mut input = Vec::with_capacity(1 + leaf.len());
+                input.push(Self::Hash::zero());
+                input.extend(leaf);
+                input
+            })
+            .collect::<Vec<_>>();
+        // Hash the inputs as a batch.
+        Hash::hash_batched(self, &inputs)
+    }
 }
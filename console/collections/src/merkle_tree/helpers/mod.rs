@@ -17,3 +17,17 @@ pub use leaf_hash::*;
 
 mod path_hash;
 pub use path_hash::*;
+
+/// The minimum number of hashes computed by each parallel task.
+const MIN_HASHES_PER_TASK: usize = 64;
+
+/// Returns the number of hashes computed by each parallel task, for the given number of hashes.
+/// The hashes are split into contiguous chunks, so the output order does not depend on the scheduling.
+pub(crate) fn hashes_per_task(num_hashes: usize) -> usize {
+    #[cfg(not(feature = "serial"))]
+    let num_tasks = rayon::current_num_threads().max(1);
+    #[cfg(feature = "serial")]
+    let num_tasks = 1;
+
+    ((num_hashes + num_tasks - 1) / num_tasks).max(MIN_HASHES_PER_TASK)
+}
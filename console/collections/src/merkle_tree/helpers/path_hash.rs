@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use super::hashes_per_task;
 use snarkvm_console_algorithms::{Poseidon, BHP};
 use snarkvm_console_types::prelude::*;
 
@@ -30,12 +31,23 @@ pub trait PathHash: Clone + Send + Sync {
     /// Returns the hash of the given child nodes.
     fn hash_children(&self, left: &Self::Hash, right: &Self::Hash) -> Result<Self::Hash>;
 
+    /// Returns the hash for each tuple of child nodes in the given chunk, in order.
+    fn hash_children_chunk(&self, child_nodes: &[(Self::Hash, Self::Hash)]) -> Result<Vec<Self::Hash>> {
+        child_nodes.iter().map(|(left, right)| self.hash_children(left, right)).collect()
+    }
+
     /// Returns the hash for each tuple of child nodes.
     fn hash_all_children(&self, child_nodes: &[(Self::Hash, Self::Hash)]) -> Result<Vec<Self::Hash>> {
         match child_nodes.len() {
             0 => Ok(vec![]),
-            1..=100 => child_nodes.iter().map(|(left, right)| self.hash_children(left, right)).collect(),
-            _ => cfg_iter!(child_nodes).map(|(left, right)| self.hash_children(left, right)).collect(),
+            1..=100 => self.hash_children_chunk(child_nodes),
+            num_nodes => {
+                // Hash the child nodes in contiguous chunks, one chunk per task.
+                let chunks = cfg_chunks!(child_nodes, hashes_per_task(num_nodes))
+                    .map(|chunk| self.hash_children_chunk(chunk))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(chunks.into_iter().flatten().collect())
+            }
         }
     }
 }
@@ -66,4 +78,12 @@ impl<E: Environment, const RATE: usize> PathHash for Poseidon<E, RATE> {
         // Hash the input.
         Hash::hash(self, &input)
     }
+
+    /// Returns the hash for each tuple of child nodes in the given chunk, in order.
+    fn hash_children_chunk(&self, child_nodes: &[(Self::Hash, Self::Hash)]) -> Result<Vec<Self::Hash>> {
+        // Prepend each tuple of nodes with a `1field` byte.
+        let inputs = child_nodes.iter().map(|(left, right)| vec![Self::Hash::one(), *left, *right]).collect::<Vec<_>>();
+        // Hash the inputs as a batch.
+        Hash::hash_batched(self, &inputs)
+    }
 }
@@ -23,9 +23,10 @@ mod tests;
 
 use snarkvm_console_types::prelude::*;
 
+#[cfg(feature = "std")]
 use aleo_std::prelude::*;
 
-use std::collections::BTreeMap;
+use alloc::collections::BTreeMap;
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -91,7 +92,8 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
             // Compute the end index of the current level.
             let end = left_child(start);
             // Construct the children for each node in the current level.
-            let tuples = (start..end).map(|i| (tree[left_child(i)], tree[right_child(i)])).collect::<Vec<_>>();
+            let tuples =
+                cfg_into_iter!(start..end).map(|i| (tree[left_child(i)], tree[right_child(i)])).collect::<Vec<_>>();
             // Compute and store the hashes for each node in the current level.
             tree[start..end].copy_from_slice(&path_hasher.hash_all_children(&tuples)?);
             // Update the start index for the next level.
@@ -124,8 +126,45 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
     pub fn prepare_append(&self, new_leaves: &[LH::Leaf]) -> Result<Self> {
         let timer = timer!("MerkleTree::prepare_append");
 
+        // Compute the new leaf hashes.
+        let new_leaf_hashes = self.leaf_hasher.hash_leaves(new_leaves)?;
+        lap!(timer, "Hashed {} new leaves", new_leaves.len());
+
+        // Compute the updated Merkle tree with the new leaf hashes.
+        let updated_tree = self.prepare_append_leaf_hashes(&new_leaf_hashes)?;
+
+        finish!(timer);
+        Ok(updated_tree)
+    }
+
+    #[inline]
+    /// Returns a new Merkle tree with the given batches of new leaves appended to it, in order.
+    /// The leaves of every batch are hashed together, and the tree is recomputed once.
+    pub fn prepare_append_many(&self, batches: &[&[LH::Leaf]]) -> Result<Self> {
+        let timer = timer!("MerkleTree::prepare_append_many");
+
+        // Compute the new leaf hashes for every batch.
+        let new_leaf_hashes = match batches.len() {
+            0 | 1 => batches.iter().map(|leaves| self.leaf_hasher.hash_leaves(leaves)).collect::<Result<Vec<_>>>()?,
+            _ => cfg_iter!(batches).map(|leaves| self.leaf_hasher.hash_leaves(leaves)).collect::<Result<Vec<_>>>()?,
+        };
+        let new_leaf_hashes = new_leaf_hashes.into_iter().flatten().collect::<Vec<_>>();
+        lap!(timer, "Hashed {} new leaves in {} batches", new_leaf_hashes.len(), batches.len());
+
+        // Compute the updated Merkle tree with the new leaf hashes.
+        let updated_tree = self.prepare_append_leaf_hashes(&new_leaf_hashes)?;
+
+        finish!(timer);
+        Ok(updated_tree)
+    }
+
+    #[inline]
+    /// Returns a new Merkle tree with the given new leaf hashes appended to it.
+    fn prepare_append_leaf_hashes(&self, new_leaf_hashes: &[LH::Hash]) -> Result<Self> {
+        let timer = timer!("MerkleTree::prepare_append_leaf_hashes");
+
         // Compute the maximum number of leaves.
-        let max_leaves = match (self.number_of_leaves + new_leaves.len()).checked_next_power_of_two() {
+        let max_leaves = match (self.number_of_leaves + new_leaf_hashes.len()).checked_next_power_of_two() {
             Some(num_leaves) => num_leaves,
             None => bail!("Integer overflow when computing the maximum number of leaves in the Merkle tree"),
         };
@@ -143,10 +182,9 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         // Extend the new Merkle tree with the existing leaf hashes.
         tree.extend(self.leaf_hashes()?);
         // Extend the new Merkle tree with the new leaf hashes.
-        tree.extend(&self.leaf_hasher.hash_leaves(new_leaves)?);
+        tree.extend(new_leaf_hashes);
         // Resize the new Merkle tree with empty hashes to pad up to `tree_size`.
         tree.resize(tree_size, self.empty_hash);
-        lap!(timer, "Hashed {} new leaves", new_leaves.len());
 
         // Initialize a start index to track the starting index of the current level.
         let start_index = num_nodes;
@@ -160,7 +198,7 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         // Initialize a precompute index to track the middle index of each precomputed level.
         let middle_precompute_index = match num_nodes == start_precompute_index {
             // If the old tree and new tree are of the same size, then we can copy over the right half of the old tree.
-            true => Some(start_precompute_index + self.number_of_leaves + new_leaves.len() + 1),
+            true => Some(start_precompute_index + self.number_of_leaves + new_leaf_hashes.len() + 1),
             // Otherwise, we need to compute the right half of the new tree.
             false => None,
         };
@@ -190,7 +228,7 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
             root: root_hash,
             tree,
             empty_hash: self.empty_hash,
-            number_of_leaves: self.number_of_leaves + new_leaves.len(),
+            number_of_leaves: self.number_of_leaves + new_leaf_hashes.len(),
         })
     }
 
@@ -208,6 +246,20 @@ impl<E: Environment, LH: LeafHash<Hash = PH::Hash>, PH: PathHash<Hash = Field<E>
         Ok(())
     }
 
+    #[inline]
+    /// Updates the Merkle tree with the given batches of new leaves appended to it, in order.
+    pub fn append_many(&mut self, batches: &[&[LH::Leaf]]) -> Result<()> {
+        let timer = timer!("MerkleTree::append_many");
+
+        // Compute the updated Merkle tree with the new leaves.
+        let updated_tree = self.prepare_append_many(batches)?;
+        // Update the tree at the very end, so the original tree is not altered in case of failure.
+        *self = updated_tree;
+
+        finish!(timer);
+        Ok(())
+    }
+
     #[inline]
     /// Updates the Merkle tree at the location of the given leaf index with the new leaf.
     pub fn update(&mut self, leaf_index: usize, new_leaf: &LH::Leaf) -> Result<()> {
@@ -581,6 +581,46 @@ fn test_merkle_tree_depth_4_poseidon() -> Result<()> {
     )
 }
 
+#[test]
+fn test_merkle_tree_append_many_poseidon() -> Result<()> {
+    const DEPTH: u8 = 16;
+
+    type LH = Poseidon<CurrentEnvironment, 4>;
+    type PH = Poseidon<CurrentEnvironment, 2>;
+
+    let leaf_hasher = LH::setup("AleoMerkleTreeTest0")?;
+    let path_hasher = PH::setup("AleoMerkleTreeTest1")?;
+
+    let mut rng = TestRng::default();
+
+    // Sample enough leaves to hash them in chunks.
+    let leaves = (0..1000).map(|_| vec![Uniform::rand(&mut rng)]).collect::<Vec<_>>();
+    let batches = [&leaves[0..1], &leaves[1..1], &leaves[1..300], &leaves[300..1000]];
+
+    // Construct the expected Merkle tree, from the leaves hashed one at a time.
+    let expected = MerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let leaf_hashes = leaves.iter().map(|leaf| leaf_hasher.hash_leaf(leaf)).collect::<Result<Vec<_>>>()?;
+    assert_eq!(expected.leaf_hashes()?, &leaf_hashes[..]);
+
+    // Append the batches to an empty Merkle tree, and ensure it matches.
+    let mut candidate = MerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::new(&leaf_hasher, &path_hasher, &[])?;
+    candidate.append_many(&batches)?;
+    assert_eq!(expected.root(), candidate.root());
+    assert_eq!(expected.tree(), candidate.tree());
+    assert_eq!(expected.number_of_leaves(), candidate.number_of_leaves());
+
+    // Append the batches to a non-empty Merkle tree, and ensure it matches appending them one at a time.
+    let mut expected = MerkleTree::<CurrentEnvironment, LH, PH, DEPTH>::new(&leaf_hasher, &path_hasher, &leaves)?;
+    let mut candidate = expected.clone();
+    for batch in &batches {
+        expected.append(batch)?;
+    }
+    candidate.append_many(&batches)?;
+    assert_eq!(expected.root(), candidate.root());
+    assert_eq!(expected.tree(), candidate.tree());
+    Ok(())
+}
+
 /// Use `cargo test profiler --features timer` to run this test.
 #[ignore]
 #[test]
@@ -23,7 +23,7 @@ use snarkvm_curves::{
     TwistedEdwardsParameters,
 };
 use snarkvm_fields::{PrimeField, SquareRootField};
-use snarkvm_utilities::BigInteger;
+use snarkvm_utilities::{string::String, BigInteger};
 
 use core::{fmt::Debug, hash::Hash};
 
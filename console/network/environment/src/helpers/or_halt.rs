@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::Environment;
+use snarkvm_utilities::string::ToString;
 
 /// A trait to unwrap a `Result` or `Halt`.
 pub trait OrHalt<T> {
@@ -15,6 +15,7 @@
 use snarkvm_utilities::{
     error,
     io::{Read, Result as IoResult},
+    vec::Vec,
     FromBytes,
 };
 
@@ -12,9 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_code)]
 #![allow(clippy::too_many_arguments)]
 
+#[cfg(not(feature = "std"))]
+#[macro_use]
+extern crate alloc;
+
+/// Without `std`, there is no stderr, so `eprintln!` only evaluates its arguments.
+#[cfg(not(feature = "std"))]
+#[macro_export]
+macro_rules! eprintln {
+    ($($arg:tt)*) => {
+        let _ = format_args!($($arg)*);
+    };
+}
+
 mod environment;
 pub use environment::*;
 
@@ -27,9 +41,20 @@ pub use traits::*;
 pub mod prelude {
     pub use crate::{environment::*, helpers::*, traits::*};
 
+    #[cfg(not(feature = "std"))]
+    pub use crate::eprintln;
+    #[cfg(not(feature = "std"))]
+    pub use alloc::{
+        borrow::ToOwned,
+        boxed::Box,
+        string::{String, ToString},
+        vec::Vec,
+    };
+
     pub use snarkvm_curves::{AffineCurve, MontgomeryParameters, ProjectiveCurve, TwistedEdwardsParameters};
     pub use snarkvm_fields::{Field as _, PrimeField as _, SquareRootField as _, Zero as _};
     pub use snarkvm_utilities::{
+        cfg_chunks,
         cfg_find,
         cfg_find_map,
         cfg_into_iter,
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use anyhow::Result;
+use snarkvm_utilities::vec::Vec;
 
 /// A trait for a commitment scheme.
 pub trait Commit {
@@ -41,6 +42,11 @@ pub trait Hash {
 
     /// Returns the hash of the given input.
     fn hash(&self, input: &[Self::Input]) -> Result<Self::Output>;
+
+    /// Returns the hash of each of the given inputs, in order.
+    fn hash_batched(&self, inputs: &[Vec<Self::Input>]) -> Result<Vec<Self::Output>> {
+        inputs.iter().map(|input| self.hash(input)).collect()
+    }
 }
 
 /// A trait for a hash function that produces multiple outputs.
@@ -50,6 +56,11 @@ pub trait HashMany {
 
     /// Returns the hash of the given input.
     fn hash_many(&self, input: &[Self::Input], num_outputs: u16) -> Vec<Self::Output>;
+
+    /// Returns the hash of each of the given inputs, in order.
+    fn hash_many_batched(&self, inputs: &[Vec<Self::Input>], num_outputs: u16) -> Vec<Vec<Self::Output>> {
+        inputs.iter().map(|input| self.hash_many(input, num_outputs)).collect()
+    }
 }
 
 /// A trait for a hash function that projects the value to an affine group element.
@@ -17,6 +17,7 @@ use nom::{
     Err as NomErr,
     IResult,
 };
+use snarkvm_utilities::string::{String, ToString};
 
 /// The `nom`-compatible parser return type.
 pub type ParserResult<'a, O> = IResult<&'a str, O, VerboseError<&'a str>>;
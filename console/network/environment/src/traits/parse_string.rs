@@ -36,6 +36,7 @@ pub mod string_parser {
         Err::Error,
         IResult,
     };
+    use snarkvm_utilities::string::String;
 
     /// Checks for supported code points.
     ///
@@ -89,7 +90,7 @@ pub mod string_parser {
     /// to parse sequences like \u{00AC}.
     fn parse_unicode<'a, E>(input: &'a str) -> IResult<&'a str, char, E>
     where
-        E: ParseError<&'a str> + FromExternalError<&'a str, std::num::ParseIntError>,
+        E: ParseError<&'a str> + FromExternalError<&'a str, core::num::ParseIntError>,
     {
         // `take_while_m_n` parses between `m` and `n` bytes (inclusive) that match
         // a predicate. `parse_hex` here parses between 1 and 6 hexadecimal numerals.
@@ -114,13 +115,13 @@ pub mod string_parser {
         // the function returns None, map_opt returns an error. In this case, because
         // not all u32 values are valid unicode code points, we have to fallibly
         // convert to char with from_u32.
-        map_opt(parse_u32, std::char::from_u32)(input)
+        map_opt(parse_u32, core::char::from_u32)(input)
     }
 
     /// Parse an escaped character: \n, \t, \r, \u{00AC}, etc.
     fn parse_escaped_char<'a, E>(input: &'a str) -> IResult<&'a str, char, E>
     where
-        E: ParseError<&'a str> + FromExternalError<&'a str, std::num::ParseIntError>,
+        E: ParseError<&'a str> + FromExternalError<&'a str, core::num::ParseIntError>,
     {
         preceded(
             char('\\'),
@@ -182,7 +183,7 @@ pub mod string_parser {
     /// into a StringFragment.
     fn parse_fragment<'a, E>(input: &'a str) -> IResult<&'a str, StringFragment<'a>, E>
     where
-        E: ParseError<&'a str> + FromExternalError<&'a str, std::num::ParseIntError>,
+        E: ParseError<&'a str> + FromExternalError<&'a str, core::num::ParseIntError>,
     {
         alt((
             // The `map` combinator runs a parser, then applies a function to the output
@@ -197,7 +198,7 @@ pub mod string_parser {
     /// into an output string.
     pub fn parse_string<'a, E>(input: &'a str) -> IResult<&'a str, String, E>
     where
-        E: ParseError<&'a str> + FromExternalError<&'a str, std::num::ParseIntError>,
+        E: ParseError<&'a str> + FromExternalError<&'a str, core::num::ParseIntError>,
     {
         // fold_many0 is the equivalent of iterator::fold. It runs a parser in a loop,
         // and for each output value, calls a folding function on each output value.
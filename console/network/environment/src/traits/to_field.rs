@@ -15,6 +15,7 @@
 use super::FieldTrait;
 
 use anyhow::Result;
+use snarkvm_utilities::vec::Vec;
 
 /// Unary operator for converting to a base field.
 pub trait ToField {
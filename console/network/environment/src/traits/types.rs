@@ -573,7 +573,7 @@ pub mod integer_type {
 
                 #[inline]
                 fn type_name() -> &'static str {
-                    std::any::type_name::<$t>()
+                    core::any::type_name::<$t>()
                 }
 
                 #[inline]
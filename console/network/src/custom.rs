@@ -0,0 +1,653 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use snarkvm_console_algorithms::Blake2Xs;
+
+/// The function names in `credits.aleo` that require circuit keys.
+pub const CREDITS_FUNCTION_NAMES: [&str; 13] = [
+    "bond_public",
+    "unbond_public",
+    "unbond_delegator_as_validator",
+    "claim_unbond_public",
+    "set_validator_state",
+    "transfer_private",
+    "transfer_public",
+    "transfer_private_to_public",
+    "transfer_public_to_private",
+    "join",
+    "split",
+    "fee_private",
+    "fee_public",
+];
+
+/// The parameters of a network that is defined with `define_network!`.
+///
+/// The circuit keys are loaded lazily, on first use. Each key is encoded with a leading version byte,
+/// as produced by the parameter setup (see `snarkvm_parameters::ParameterLoader` to fetch and verify them).
+pub trait NetworkParameters: 'static + Send + Sync {
+    /// The function name for the inclusion circuit.
+    const INCLUSION_FUNCTION_NAME: &'static str;
+
+    /// Returns the genesis block bytes.
+    fn genesis_bytes() -> &'static [u8];
+
+    /// Returns the proving key bytes for the given function name in `credits.aleo`.
+    fn credits_proving_key_bytes(function_name: &str) -> Result<Vec<u8>>;
+
+    /// Returns the verifying key bytes for the given function name in `credits.aleo`.
+    fn credits_verifying_key_bytes(function_name: &str) -> Result<Vec<u8>>;
+
+    /// Returns the proving key bytes for the inclusion circuit.
+    fn inclusion_proving_key_bytes() -> Result<Vec<u8>>;
+
+    /// Returns the verifying key bytes for the inclusion circuit.
+    fn inclusion_verifying_key_bytes() -> Result<Vec<u8>>;
+}
+
+/// Returns the group bases for the given domain message, up to the size of the scalar field (in bits).
+#[doc(hidden)]
+pub fn new_bases<E: Environment>(message: &str) -> Vec<Group<E>> {
+    // Hash the given message to a point on the curve, to initialize the starting base.
+    let (base, _, _) = Blake2Xs::hash_to_curve::<E::Affine>(message);
+
+    // Compute the bases up to the size of the scalar field (in bits).
+    let mut g = Group::<E>::new(base);
+    let mut g_bases = Vec::with_capacity(Scalar::<E>::size_in_bits());
+    for _ in 0..Scalar::<E>::size_in_bits() {
+        g_bases.push(g);
+        g = g.double();
+    }
+    g_bases
+}
+
+/// Returns the circuit key, from the given bytes (with a leading version byte).
+#[doc(hidden)]
+pub fn load_circuit_key<K: FromBytes>(name: &str, bytes: Result<Vec<u8>>) -> Result<K> {
+    let bytes = bytes.map_err(|error| anyhow!("Failed to load the circuit key for '{name}': {error}"))?;
+    // Skipping the first byte, which is the encoded version.
+    match bytes.get(1..) {
+        Some(bytes) => K::from_bytes_le(bytes).map_err(|error| anyhow!("Failed to recover '{name}': {error}")),
+        None => bail!("The circuit key for '{name}' is empty"),
+    }
+}
+
+/// Returns the circuit keys for the functions in `credits.aleo`, using the given loader.
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub fn load_credits_keys<K: FromBytes>(load: impl Fn(&str) -> Result<Vec<u8>>) -> Result<IndexMap<String, Arc<K>>> {
+    CREDITS_FUNCTION_NAMES
+        .iter()
+        .map(|name| Ok((name.to_string(), Arc::new(load_circuit_key(name, load(name))?))))
+        .collect()
+}
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::environment::prelude::*;
+    #[cfg(feature = "std")]
+    pub use crate::{FiatShamir, FiatShamirParameters, VarunaProvingKey, VarunaVerifyingKey};
+    pub use alloc::sync::Arc;
+    pub use anyhow::{anyhow, Result};
+    #[cfg(feature = "std")]
+    pub use indexmap::IndexMap;
+    pub use itertools::Itertools;
+    #[cfg(feature = "std")]
+    pub use once_cell::sync::OnceCell;
+    pub use serde;
+    #[cfg(feature = "std")]
+    pub use snarkvm_algorithms::{
+        polycommit::kzg10::UniversalParams,
+        srs::{UniversalProver, UniversalVerifier},
+        AlgebraicSponge,
+    };
+    pub use snarkvm_console_algorithms::{
+        Keccak256,
+        Keccak384,
+        Keccak512,
+        Pedersen128,
+        Pedersen64,
+        Poseidon2,
+        Poseidon4,
+        Poseidon8,
+        Sha3_256,
+        Sha3_384,
+        Sha3_512,
+        BHP1024,
+        BHP256,
+        BHP512,
+        BHP768,
+    };
+    pub use snarkvm_console_collections::merkle_tree::{MerklePath, MerkleTree};
+    pub use snarkvm_console_types::{Field, Group, Scalar};
+    pub use snarkvm_utilities::{FromBytes, ToBits};
+
+    /// Without `std`, a lazily-initialized static is a `OnceBox`, which may run its initializer more than once under contention.
+    #[cfg(not(feature = "std"))]
+    pub struct OnceCell<T>(once_cell::race::OnceBox<T>);
+
+    #[cfg(not(feature = "std"))]
+    impl<T> OnceCell<T> {
+        pub const fn new() -> Self {
+            Self(once_cell::race::OnceBox::new())
+        }
+
+        pub fn get_or_init(&self, f: impl FnOnce() -> T) -> &T {
+            self.0.get_or_init(|| Box::new(f()))
+        }
+    }
+}
+
+/// Defines a new network, outside of snarkVM, with the same cryptographic instantiation as `Testnet3`.
+///
+/// The network is identified by its ID, loads its genesis block and circuit keys from the given
+/// `NetworkParameters`, and may override any of the `Network` constants (e.g. `BLOCK_TIME`).
+///
+/// Note: The circuit environments (and so the VM) are currently only instantiated for `Testnet3`,
+/// so a custom network may not yet synthesize or verify program executions.
+///
+/// ```ignore
+/// define_network! {
+///     /// A canary network.
+///     pub struct CanaryV0 {
+///         id: 9,
+///         name: "Aleo Canary V0",
+///         edition: 0,
+///         parameters: CanaryV0Parameters,
+///     }
+///
+///     const BLOCK_TIME: u16 = 10;
+/// }
+/// ```
+#[macro_export]
+macro_rules! define_network {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            id: $id:expr,
+            name: $network_name:expr,
+            edition: $edition:expr,
+            parameters: $parameters:ty $(,)?
+        }
+        $($item:item)*
+    ) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        $vis struct $name;
+
+        const _: () = {
+            use $crate::__private::*;
+            use $crate::{Environment, Network, NetworkParameters};
+
+            macro_rules! instance {
+                ($type:ty, $init:expr) => {{
+                    static INSTANCE: OnceCell<$type> = OnceCell::new();
+                    INSTANCE.get_or_init(|| $init)
+                }};
+            }
+
+            fn bhp_256() -> &'static BHP256<$name> {
+                instance!(BHP256<$name>, BHP256::setup("AleoBHP256").expect("Failed to setup BHP256"))
+            }
+            fn bhp_512() -> &'static BHP512<$name> {
+                instance!(BHP512<$name>, BHP512::setup("AleoBHP512").expect("Failed to setup BHP512"))
+            }
+            fn bhp_768() -> &'static BHP768<$name> {
+                instance!(BHP768<$name>, BHP768::setup("AleoBHP768").expect("Failed to setup BHP768"))
+            }
+            fn bhp_1024() -> &'static BHP1024<$name> {
+                instance!(BHP1024<$name>, BHP1024::setup("AleoBHP1024").expect("Failed to setup BHP1024"))
+            }
+            fn pedersen_64() -> &'static Pedersen64<$name> {
+                instance!(Pedersen64<$name>, Pedersen64::setup("AleoPedersen64"))
+            }
+            fn pedersen_128() -> &'static Pedersen128<$name> {
+                instance!(Pedersen128<$name>, Pedersen128::setup("AleoPedersen128"))
+            }
+            fn poseidon_2() -> &'static Poseidon2<$name> {
+                instance!(Poseidon2<$name>, Poseidon2::setup("AleoPoseidon2").expect("Failed to setup Poseidon2"))
+            }
+            fn poseidon_4() -> &'static Poseidon4<$name> {
+                instance!(Poseidon4<$name>, Poseidon4::setup("AleoPoseidon4").expect("Failed to setup Poseidon4"))
+            }
+            fn poseidon_8() -> &'static Poseidon8<$name> {
+                instance!(Poseidon8<$name>, Poseidon8::setup("AleoPoseidon8").expect("Failed to setup Poseidon8"))
+            }
+            fn generator_g() -> &'static Vec<Group<$name>> {
+                instance!(Vec<Group<$name>>, $crate::new_bases("AleoAccountEncryptionAndSignatureScheme0"))
+            }
+
+            impl serde::Serialize for $name {
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.serialize_unit()
+                }
+            }
+
+            impl<'de> serde::Deserialize<'de> for $name {
+                fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    <() as serde::Deserialize>::deserialize(deserializer).map(|_| $name)
+                }
+            }
+
+            impl Environment for $name {
+                type Affine = <$crate::Console as Environment>::Affine;
+                type BigInteger = <$crate::Console as Environment>::BigInteger;
+                type Field = <$crate::Console as Environment>::Field;
+                type PairingCurve = <$crate::Console as Environment>::PairingCurve;
+                type Projective = <$crate::Console as Environment>::Projective;
+                type Scalar = <$crate::Console as Environment>::Scalar;
+
+                const EDWARDS_A: Self::Field = $crate::Console::EDWARDS_A;
+                const EDWARDS_D: Self::Field = $crate::Console::EDWARDS_D;
+                const MONTGOMERY_A: Self::Field = $crate::Console::MONTGOMERY_A;
+                const MONTGOMERY_B: Self::Field = $crate::Console::MONTGOMERY_B;
+            }
+
+            impl Network for $name {
+                type BlockHash = $crate::AleoID<Field<Self>, { u16::from_le_bytes(*b"ab") }>;
+                type StateRoot = $crate::AleoID<Field<Self>, { u16::from_le_bytes(*b"ar") }>;
+                type TransactionID = $crate::AleoID<Field<Self>, { u16::from_le_bytes(*b"at") }>;
+                type TransitionID = $crate::AleoID<Field<Self>, { u16::from_le_bytes(*b"as") }>;
+
+                const EDITION: u16 = $edition;
+                const ID: u16 = $id;
+                const NAME: &'static str = $network_name;
+
+                $($item)*
+
+                $crate::__network_circuit_keys!($name, $parameters);
+
+                fn g_powers() -> &'static Vec<Group<Self>> {
+                    generator_g()
+                }
+
+                fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self> {
+                    generator_g()
+                        .iter()
+                        .zip_eq(&scalar.to_bits_le())
+                        .filter_map(|(base, bit)| match bit {
+                            true => Some(base),
+                            false => None,
+                        })
+                        .sum()
+                }
+
+                fn encryption_domain() -> Field<Self> {
+                    *instance!(Field<$name>, Field::new_domain_separator("AleoSymmetricEncryption0"))
+                }
+
+                fn graph_key_domain() -> Field<Self> {
+                    *instance!(Field<$name>, Field::new_domain_separator("AleoGraphKey0"))
+                }
+
+                fn serial_number_domain() -> Field<Self> {
+                    *instance!(Field<$name>, Field::new_domain_separator("AleoSerialNumber0"))
+                }
+
+                fn commit_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+                    bhp_256().commit(input, randomizer)
+                }
+
+                fn commit_bhp512(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+                    bhp_512().commit(input, randomizer)
+                }
+
+                fn commit_bhp768(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+                    bhp_768().commit(input, randomizer)
+                }
+
+                fn commit_bhp1024(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+                    bhp_1024().commit(input, randomizer)
+                }
+
+                fn commit_ped64(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+                    pedersen_64().commit(input, randomizer)
+                }
+
+                fn commit_ped128(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+                    pedersen_128().commit(input, randomizer)
+                }
+
+                fn commit_to_group_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+                    bhp_256().commit_uncompressed(input, randomizer)
+                }
+
+                fn commit_to_group_bhp512(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+                    bhp_512().commit_uncompressed(input, randomizer)
+                }
+
+                fn commit_to_group_bhp768(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+                    bhp_768().commit_uncompressed(input, randomizer)
+                }
+
+                fn commit_to_group_bhp1024(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+                    bhp_1024().commit_uncompressed(input, randomizer)
+                }
+
+                fn commit_to_group_ped64(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+                    pedersen_64().commit_uncompressed(input, randomizer)
+                }
+
+                fn commit_to_group_ped128(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+                    pedersen_128().commit_uncompressed(input, randomizer)
+                }
+
+                fn hash_bhp256(input: &[bool]) -> Result<Field<Self>> {
+                    bhp_256().hash(input)
+                }
+
+                fn hash_bhp512(input: &[bool]) -> Result<Field<Self>> {
+                    bhp_512().hash(input)
+                }
+
+                fn hash_bhp768(input: &[bool]) -> Result<Field<Self>> {
+                    bhp_768().hash(input)
+                }
+
+                fn hash_bhp1024(input: &[bool]) -> Result<Field<Self>> {
+                    bhp_1024().hash(input)
+                }
+
+                fn hash_keccak256(input: &[bool]) -> Result<Vec<bool>> {
+                    Keccak256::default().hash(input)
+                }
+
+                fn hash_keccak384(input: &[bool]) -> Result<Vec<bool>> {
+                    Keccak384::default().hash(input)
+                }
+
+                fn hash_keccak512(input: &[bool]) -> Result<Vec<bool>> {
+                    Keccak512::default().hash(input)
+                }
+
+                fn hash_ped64(input: &[bool]) -> Result<Field<Self>> {
+                    pedersen_64().hash(input)
+                }
+
+                fn hash_ped128(input: &[bool]) -> Result<Field<Self>> {
+                    pedersen_128().hash(input)
+                }
+
+                fn hash_psd2(input: &[Field<Self>]) -> Result<Field<Self>> {
+                    poseidon_2().hash(input)
+                }
+
+                fn hash_psd4(input: &[Field<Self>]) -> Result<Field<Self>> {
+                    poseidon_4().hash(input)
+                }
+
+                fn hash_psd8(input: &[Field<Self>]) -> Result<Field<Self>> {
+                    poseidon_8().hash(input)
+                }
+
+                fn hash_sha3_256(input: &[bool]) -> Result<Vec<bool>> {
+                    Sha3_256::default().hash(input)
+                }
+
+                fn hash_sha3_384(input: &[bool]) -> Result<Vec<bool>> {
+                    Sha3_384::default().hash(input)
+                }
+
+                fn hash_sha3_512(input: &[bool]) -> Result<Vec<bool>> {
+                    Sha3_512::default().hash(input)
+                }
+
+                fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+                    poseidon_2().hash_many(input, num_outputs)
+                }
+
+                fn hash_many_psd4(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+                    poseidon_4().hash_many(input, num_outputs)
+                }
+
+                fn hash_many_psd8(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+                    poseidon_8().hash_many(input, num_outputs)
+                }
+
+                fn hash_to_group_bhp256(input: &[bool]) -> Result<Group<Self>> {
+                    bhp_256().hash_uncompressed(input)
+                }
+
+                fn hash_to_group_bhp512(input: &[bool]) -> Result<Group<Self>> {
+                    bhp_512().hash_uncompressed(input)
+                }
+
+                fn hash_to_group_bhp768(input: &[bool]) -> Result<Group<Self>> {
+                    bhp_768().hash_uncompressed(input)
+                }
+
+                fn hash_to_group_bhp1024(input: &[bool]) -> Result<Group<Self>> {
+                    bhp_1024().hash_uncompressed(input)
+                }
+
+                fn hash_to_group_ped64(input: &[bool]) -> Result<Group<Self>> {
+                    pedersen_64().hash_uncompressed(input)
+                }
+
+                fn hash_to_group_ped128(input: &[bool]) -> Result<Group<Self>> {
+                    pedersen_128().hash_uncompressed(input)
+                }
+
+                fn hash_to_group_psd2(input: &[Field<Self>]) -> Result<Group<Self>> {
+                    poseidon_2().hash_to_group(input)
+                }
+
+                fn hash_to_group_psd4(input: &[Field<Self>]) -> Result<Group<Self>> {
+                    poseidon_4().hash_to_group(input)
+                }
+
+                fn hash_to_group_psd8(input: &[Field<Self>]) -> Result<Group<Self>> {
+                    poseidon_8().hash_to_group(input)
+                }
+
+                fn hash_to_scalar_psd2(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+                    poseidon_2().hash_to_scalar(input)
+                }
+
+                fn hash_to_scalar_psd4(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+                    poseidon_4().hash_to_scalar(input)
+                }
+
+                fn hash_to_scalar_psd8(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+                    poseidon_8().hash_to_scalar(input)
+                }
+
+                fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<$crate::BHPMerkleTree<Self, DEPTH>> {
+                    MerkleTree::new(bhp_1024(), bhp_512(), leaves)
+                }
+
+                fn merkle_tree_psd<const DEPTH: u8>(leaves: &[Vec<Field<Self>>]) -> Result<$crate::PoseidonMerkleTree<Self, DEPTH>> {
+                    MerkleTree::new(poseidon_4(), poseidon_2(), leaves)
+                }
+
+                fn verify_merkle_path_bhp<const DEPTH: u8>(
+                    path: &MerklePath<Self, DEPTH>,
+                    root: &Field<Self>,
+                    leaf: &Vec<bool>,
+                ) -> bool {
+                    path.verify(bhp_1024(), bhp_512(), root, leaf)
+                }
+
+                fn verify_merkle_path_psd<const DEPTH: u8>(
+                    path: &MerklePath<Self, DEPTH>,
+                    root: &Field<Self>,
+                    leaf: &Vec<Field<Self>>,
+                ) -> bool {
+                    path.verify(poseidon_4(), poseidon_2(), root, leaf)
+                }
+            }
+        };
+    };
+}
+
+/// Expands to the `Network` items that load the genesis block and circuit keys, for `define_network!`.
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __network_circuit_keys {
+    ($name:ident, $parameters:ty) => {
+        const INCLUSION_FUNCTION_NAME: &'static str = <$parameters as NetworkParameters>::INCLUSION_FUNCTION_NAME;
+
+        fn genesis_bytes() -> &'static [u8] {
+            <$parameters as NetworkParameters>::genesis_bytes()
+        }
+
+        fn get_credits_proving_key(function_name: String) -> Result<&'static Arc<VarunaProvingKey<Self>>> {
+            static INSTANCE: OnceCell<IndexMap<String, Arc<VarunaProvingKey<$name>>>> = OnceCell::new();
+            INSTANCE
+                .get_or_try_init(|| {
+                    $crate::load_credits_keys(<$parameters as NetworkParameters>::credits_proving_key_bytes)
+                })?
+                .get(&function_name)
+                .ok_or_else(|| anyhow!("Proving key for credits.aleo/{function_name}' not found"))
+        }
+
+        fn get_credits_verifying_key(function_name: String) -> Result<&'static Arc<VarunaVerifyingKey<Self>>> {
+            static INSTANCE: OnceCell<IndexMap<String, Arc<VarunaVerifyingKey<$name>>>> = OnceCell::new();
+            INSTANCE
+                .get_or_try_init(|| {
+                    $crate::load_credits_keys(<$parameters as NetworkParameters>::credits_verifying_key_bytes)
+                })?
+                .get(&function_name)
+                .ok_or_else(|| anyhow!("Verifying key for credits.aleo/{function_name}' not found"))
+        }
+
+        fn inclusion_proving_key() -> &'static Arc<VarunaProvingKey<Self>> {
+            instance!(
+                Arc<VarunaProvingKey<$name>>,
+                Arc::new(
+                    $crate::load_circuit_key(
+                        "inclusion",
+                        <$parameters as NetworkParameters>::inclusion_proving_key_bytes()
+                    )
+                    .expect("Failed to load inclusion proving key."),
+                )
+            )
+        }
+
+        fn inclusion_verifying_key() -> &'static Arc<VarunaVerifyingKey<Self>> {
+            instance!(
+                Arc<VarunaVerifyingKey<$name>>,
+                Arc::new(
+                    $crate::load_circuit_key(
+                        "inclusion",
+                        <$parameters as NetworkParameters>::inclusion_verifying_key_bytes()
+                    )
+                    .expect("Failed to load inclusion verifying key."),
+                )
+            )
+        }
+
+        fn varuna_universal_prover() -> &'static UniversalProver<Self::PairingCurve> {
+            instance!(
+                UniversalProver<<$crate::Console as Environment>::PairingCurve>,
+                UniversalParams::load()
+                    .expect("Failed to load universal SRS (KZG10).")
+                    .to_universal_prover()
+                    .expect("Failed to convert universal SRS (KZG10) to the prover.")
+            )
+        }
+
+        fn varuna_universal_verifier() -> &'static UniversalVerifier<Self::PairingCurve> {
+            instance!(
+                UniversalVerifier<<$crate::Console as Environment>::PairingCurve>,
+                UniversalParams::load()
+                    .expect("Failed to load universal SRS (KZG10).")
+                    .to_universal_verifier()
+                    .expect("Failed to convert universal SRS (KZG10) to the verifier.")
+            )
+        }
+
+        fn varuna_fs_parameters() -> &'static FiatShamirParameters<Self> {
+            instance!(FiatShamirParameters<$name>, FiatShamir::<$name>::sample_parameters())
+        }
+    };
+}
+
+/// Without `std`, the genesis block and circuit keys are not available.
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __network_circuit_keys {
+    ($name:ident, $parameters:ty) => {};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The parameters of the sample network, which reuse the genesis block and circuit keys of `Testnet3`.
+    struct SampleParameters;
+
+    impl NetworkParameters for SampleParameters {
+        const INCLUSION_FUNCTION_NAME: &'static str = snarkvm_parameters::testnet3::TESTNET3_INCLUSION_FUNCTION_NAME;
+
+        fn genesis_bytes() -> &'static [u8] {
+            Testnet3::genesis_bytes()
+        }
+
+        fn credits_proving_key_bytes(function_name: &str) -> Result<Vec<u8>> {
+            bail!("The proving key for '{function_name}' is not available")
+        }
+
+        fn credits_verifying_key_bytes(function_name: &str) -> Result<Vec<u8>> {
+            bail!("The verifying key for '{function_name}' is not available")
+        }
+
+        fn inclusion_proving_key_bytes() -> Result<Vec<u8>> {
+            Ok(snarkvm_parameters::testnet3::INCLUSION_PROVING_KEY.clone())
+        }
+
+        fn inclusion_verifying_key_bytes() -> Result<Vec<u8>> {
+            Ok(snarkvm_parameters::testnet3::INCLUSION_VERIFYING_KEY.clone())
+        }
+    }
+
+    define_network! {
+        /// A sample network, with a custom ID and block time.
+        pub struct SampleNetwork {
+            id: u16::MAX,
+            name: "Sample Network",
+            edition: 0,
+            parameters: SampleParameters,
+        }
+
+        const BLOCK_TIME: u16 = 10;
+    }
+
+    #[test]
+    fn test_define_network() {
+        let mut rng = TestRng::default();
+
+        // Ensure the constants are defined, or overridden.
+        assert_eq!(SampleNetwork::ID, u16::MAX);
+        assert_eq!(SampleNetwork::NAME, "Sample Network");
+        assert_eq!(SampleNetwork::BLOCK_TIME, 10);
+        assert_eq!(SampleNetwork::ANCHOR_HEIGHT, SampleNetwork::ANCHOR_TIME as u32 / 10);
+        assert_eq!(SampleNetwork::MAX_FEE, Testnet3::MAX_FEE);
+
+        // Ensure the cryptographic instantiation matches `Testnet3`.
+        assert_eq!(*SampleNetwork::serial_number_domain(), *Testnet3::serial_number_domain());
+        for _ in 0..10 {
+            let input = (0..256).map(|_| rng.gen()).collect::<Vec<bool>>();
+            assert_eq!(*SampleNetwork::hash_bhp256(&input).unwrap(), *Testnet3::hash_bhp256(&input).unwrap());
+            let scalar = Scalar::<SampleNetwork>::new(Uniform::rand(&mut rng));
+            let expected = Testnet3::g_scalar_multiply(&Scalar::new(*scalar));
+            assert_eq!(SampleNetwork::g_scalar_multiply(&scalar).to_affine(), expected.to_affine());
+        }
+
+        // Ensure missing circuit keys are reported as errors.
+        assert!(SampleNetwork::get_credits_proving_key("transfer_public".to_string()).is_err());
+    }
+}
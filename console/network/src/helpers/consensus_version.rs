@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::prelude::*;
+
+/// The version of the consensus rules, which a network activates at a configured block height.
+///
+/// A behavior change is introduced by adding a new version, and gating the new behavior
+/// on `consensus_version >= ConsensusVersion::V{n}`, so blocks are always validated under the rules of their era.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u16)]
+pub enum ConsensusVersion {
+    /// The initial consensus rules.
+    V1 = 1,
+}
+
+impl ConsensusVersion {
+    /// The list of all consensus versions, in activation order.
+    pub const ALL: &'static [Self] = &[Self::V1];
+}
+
+impl TryFrom<u16> for ConsensusVersion {
+    type Error = Error;
+
+    /// Returns the consensus version for the given number.
+    fn try_from(version: u16) -> Result<Self> {
+        match version {
+            1 => Ok(Self::V1),
+            _ => bail!("Invalid consensus version: {version}"),
+        }
+    }
+}
+
+impl Display for ConsensusVersion {
+    /// Prints the consensus version as `v{n}`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "v{}", *self as u16)
+    }
+}
+
+/// Returns the consensus version that applies at the given block height, for the given activation schedule.
+/// The schedule is a list of `(version, activation height)` pairs, in activation order.
+pub fn consensus_version_at(schedule: &[(ConsensusVersion, u32)], block_height: u32) -> Result<ConsensusVersion> {
+    // Ensure the schedule starts at genesis.
+    ensure!(
+        matches!(schedule.first(), Some((_, 0))),
+        "The consensus version schedule must activate a version at genesis"
+    );
+    // Ensure the versions and activation heights are strictly increasing.
+    for ((previous_version, previous_height), (version, height)) in schedule.iter().tuple_windows() {
+        ensure!(previous_version < version, "The consensus versions must be strictly increasing ({version})");
+        ensure!(previous_height < height, "The activation heights must be strictly increasing ({version})");
+    }
+    // Return the latest version activated at or below the given height.
+    match schedule.iter().rev().find(|(_, height)| *height <= block_height) {
+        Some((version, _)) => Ok(*version),
+        None => bail!("No consensus version is active at block {block_height}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_consensus_version_at() {
+        // Ensure the versions round-trip through their number.
+        for version in ConsensusVersion::ALL {
+            assert_eq!(ConsensusVersion::try_from(*version as u16).unwrap(), *version);
+        }
+        assert!(ConsensusVersion::try_from(0).is_err());
+
+        // Ensure the version is selected by its activation height.
+        let schedule = [(ConsensusVersion::V1, 0)];
+        assert_eq!(consensus_version_at(&schedule, 0).unwrap(), ConsensusVersion::V1);
+        assert_eq!(consensus_version_at(&schedule, u32::MAX).unwrap(), ConsensusVersion::V1);
+
+        // Ensure an invalid schedule is rejected.
+        assert!(consensus_version_at(&[], 0).is_err());
+        assert!(consensus_version_at(&[(ConsensusVersion::V1, 1)], 1).is_err());
+        assert!(consensus_version_at(&[(ConsensusVersion::V1, 0), (ConsensusVersion::V1, 10)], 0).is_err());
+    }
+}
@@ -16,8 +16,8 @@ use crate::prelude::*;
 
 use anyhow::Result;
 use bech32::{self, FromBase32, ToBase32};
+use core::borrow::Borrow;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
-use std::borrow::Borrow;
 
 pub trait Bech32ID<F: FieldTrait>:
     From<F>
@@ -126,7 +126,7 @@ impl<F: FieldTrait, const PREFIX: u16> FromStr for AleoID<F, PREFIX> {
             bail!("Invalid byte size for a bech32m hash: {} bytes", string.len())
         }
 
-        let (hrp, data, variant) = bech32::decode(string)?;
+        let (hrp, data, variant) = bech32::decode(string).map_err(|e| anyhow!("{e}"))?;
         if hrp.as_bytes() != PREFIX.to_le_bytes() {
             bail!("Invalid prefix for a bech32m hash: {hrp}")
         };
@@ -136,7 +136,7 @@ impl<F: FieldTrait, const PREFIX: u16> FromStr for AleoID<F, PREFIX> {
         if variant != bech32::Variant::Bech32m {
             bail!("Hash is not a bech32m hash")
         }
-        Ok(Self::read_le(&*Vec::from_base32(&data)?)?)
+        Ok(Self::read_le(&*Vec::from_base32(&data).map_err(|e| anyhow!("{e}"))?)?)
     }
 }
 
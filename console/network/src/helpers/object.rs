@@ -16,8 +16,8 @@ use crate::prelude::*;
 
 use anyhow::Result;
 use bech32::{self, FromBase32, ToBase32};
+use core::borrow::Borrow;
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
-use std::borrow::Borrow;
 
 pub trait Bech32Object<T: Clone + Debug + ToBytes + FromBytes + PartialEq + Eq + Sync + Send>:
     From<T>
@@ -96,7 +96,7 @@ impl<T: Clone + Debug + ToBytes + FromBytes + PartialEq + Eq + Sync + Send, cons
     /// Reads in a bech32m string.
     #[inline]
     fn from_str(string: &str) -> Result<Self, Self::Err> {
-        let (hrp, data, variant) = bech32::decode(string)?;
+        let (hrp, data, variant) = bech32::decode(string).map_err(|e| anyhow!("{e}"))?;
         if hrp.as_bytes() != PREFIX.to_le_bytes() {
             bail!("Invalid prefix for a bech32m hash: {hrp}")
         };
@@ -106,7 +106,7 @@ impl<T: Clone + Debug + ToBytes + FromBytes + PartialEq + Eq + Sync + Send, cons
         if variant != bech32::Variant::Bech32m {
             bail!("Hash is not a bech32m hash")
         }
-        Ok(Self::read_le(&*Vec::from_base32(&data)?)?)
+        Ok(Self::read_le(&*Vec::from_base32(&data).map_err(|e| anyhow!("{e}"))?)?)
     }
 }
 
@@ -12,10 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_code)]
 #![allow(clippy::too_many_arguments)]
 #![warn(clippy::cast_possible_truncation)]
 
+#[macro_use]
+extern crate alloc;
 #[macro_use]
 extern crate lazy_static;
 
@@ -25,6 +28,9 @@ pub use snarkvm_console_network_environment::*;
 mod helpers;
 pub use helpers::*;
 
+mod custom;
+pub use custom::*;
+
 mod testnet3;
 pub use testnet3::*;
 
@@ -33,6 +39,7 @@ pub mod prelude {
 }
 
 use crate::environment::prelude::*;
+#[cfg(feature = "std")]
 use snarkvm_algorithms::{
     crypto_hash::PoseidonSponge,
     snark::varuna::{CircuitProvingKey, CircuitVerifyingKey, VarunaHidingMode},
@@ -42,11 +49,15 @@ use snarkvm_algorithms::{
 use snarkvm_console_algorithms::{Poseidon2, Poseidon4, BHP1024, BHP512};
 use snarkvm_console_collections::merkle_tree::{MerklePath, MerkleTree};
 use snarkvm_console_types::{Field, Group, Scalar};
+#[cfg(feature = "std")]
 use snarkvm_curves::PairingEngine;
 
+#[cfg(feature = "std")]
+use alloc::sync::Arc;
+#[cfg(feature = "std")]
 use indexmap::IndexMap;
+#[cfg(feature = "std")]
 use once_cell::sync::OnceCell;
-use std::sync::Arc;
 
 /// A helper type for the BHP Merkle tree.
 pub type BHPMerkleTree<N, const DEPTH: u8> = MerkleTree<N, BHP1024<N>, BHP512<N>, DEPTH>;
@@ -54,13 +65,18 @@ pub type BHPMerkleTree<N, const DEPTH: u8> = MerkleTree<N, BHP1024<N>, BHP512<N>
 pub type PoseidonMerkleTree<N, const DEPTH: u8> = MerkleTree<N, Poseidon4<N>, Poseidon2<N>, DEPTH>;
 
 /// Helper types for the Varuna parameters.
+#[cfg(feature = "std")]
 type Fq<N> = <<N as Environment>::PairingCurve as PairingEngine>::Fq;
+#[cfg(feature = "std")]
 pub type FiatShamir<N> = PoseidonSponge<Fq<N>, 2, 1>;
+#[cfg(feature = "std")]
 pub type FiatShamirParameters<N> = <FiatShamir<N> as AlgebraicSponge<Fq<N>, 2>>::Parameters;
 
 /// Helper types for the Varuna proving and verifying key.
-pub(crate) type VarunaProvingKey<N> = CircuitProvingKey<<N as Environment>::PairingCurve, VarunaHidingMode>;
-pub(crate) type VarunaVerifyingKey<N> = CircuitVerifyingKey<<N as Environment>::PairingCurve>;
+#[cfg(feature = "std")]
+pub type VarunaProvingKey<N> = CircuitProvingKey<<N as Environment>::PairingCurve, VarunaHidingMode>;
+#[cfg(feature = "std")]
+pub type VarunaVerifyingKey<N> = CircuitVerifyingKey<<N as Environment>::PairingCurve>;
 
 pub trait Network:
     'static
@@ -85,6 +101,7 @@ pub trait Network:
     const EDITION: u16;
 
     /// The function name for the inclusion circuit.
+    #[cfg(feature = "std")]
     const INCLUSION_FUNCTION_NAME: &'static str;
 
     /// The fixed timestamp of the genesis block.
@@ -98,8 +115,17 @@ pub trait Network:
     const STARTING_SUPPLY: u64 = 1_500_000_000_000_000; // 1.5B credits
     /// The cost in microcredits per byte for the deployment transaction.
     const DEPLOYMENT_FEE_MULTIPLIER: u64 = 1_000; // 1 millicredit per byte
+    /// The cost in microcredits per byte for the execution transaction.
+    const EXECUTION_FEE_MULTIPLIER: u64 = 1; // 1 microcredit per byte
+    /// The multiplier applied to the cost in microcredits of each command in finalize.
+    const FINALIZE_FEE_MULTIPLIER: u64 = 1;
+    /// The number of characters in a program name, at and above which the namespace cost is 1 credit.
+    /// Note: Each character fewer increases the namespace cost by a factor of 10.
+    const NAMESPACE_COST_CHARACTERS: u32 = 10;
     /// The maximum number of microcredits that can be spent as a fee.
     const MAX_FEE: u64 = 1_000_000_000_000_000;
+    /// The maximum cost in microcredits to run the finalize commands of an execution.
+    const MAX_FINALIZE_COST: u64 = Self::MAX_FEE;
 
     /// The anchor height, defined as the expected number of blocks to reach the coinbase target.
     const ANCHOR_HEIGHT: u32 = Self::ANCHOR_TIME as u32 / Self::BLOCK_TIME as u32;
@@ -111,6 +137,9 @@ pub trait Network:
     const COINBASE_PUZZLE_DEGREE: u32 = (1 << 13) - 1; // 8,191
     /// The maximum number of prover solutions that can be included per block.
     const MAX_PROVER_SOLUTIONS: usize = 1 << 8; // 256 prover solutions
+    /// The maximum number of transactions that can be included per block.
+    /// Note: This value is capped by the capacity of the transactions tree.
+    const MAX_TRANSACTIONS_PER_BLOCK: usize = 1 << 16; // 65,536 transactions
     /// The number of blocks per epoch.
     const NUM_BLOCKS_PER_EPOCH: u32 = 3600 / Self::BLOCK_TIME as u32; // 720 blocks == ~1 hour
 
@@ -155,6 +184,11 @@ pub trait Network:
     const MAX_INPUTS: usize = 16;
     /// The maximum number of outputs per transition.
     const MAX_OUTPUTS: usize = 16;
+    /// The maximum number of transitions per transaction, including the fee.
+    /// Note: This value is capped by the capacity of the transaction tree.
+    const MAX_TRANSITIONS_PER_TRANSACTION: usize = 1 << 5; // 32 transitions
+    /// The consensus versions and their activation heights, in activation order.
+    const CONSENSUS_VERSION_HEIGHTS: &'static [(ConsensusVersion, u32)] = &[(ConsensusVersion::V1, 0)];
 
     /// The state root type.
     type StateRoot: Bech32ID<Field<Self>>;
@@ -165,19 +199,29 @@ pub trait Network:
     /// The transition ID type.
     type TransitionID: Bech32ID<Field<Self>>;
 
+    /// Returns the consensus version that applies at the given block height.
+    fn consensus_version(block_height: u32) -> Result<ConsensusVersion> {
+        consensus_version_at(Self::CONSENSUS_VERSION_HEIGHTS, block_height)
+    }
+
     /// Returns the genesis block bytes.
+    #[cfg(feature = "std")]
     fn genesis_bytes() -> &'static [u8];
 
     /// Returns the proving key for the given function name in `credits.aleo`.
+    #[cfg(feature = "std")]
     fn get_credits_proving_key(function_name: String) -> Result<&'static Arc<VarunaProvingKey<Self>>>;
 
     /// Returns the verifying key for the given function name in `credits.aleo`.
+    #[cfg(feature = "std")]
     fn get_credits_verifying_key(function_name: String) -> Result<&'static Arc<VarunaVerifyingKey<Self>>>;
 
     /// Returns the `proving key` for the inclusion circuit.
+    #[cfg(feature = "std")]
     fn inclusion_proving_key() -> &'static Arc<VarunaProvingKey<Self>>;
 
     /// Returns the `verifying key` for the inclusion circuit.
+    #[cfg(feature = "std")]
     fn inclusion_verifying_key() -> &'static Arc<VarunaVerifyingKey<Self>>;
 
     /// Returns the powers of `G`.
@@ -187,12 +231,15 @@ pub trait Network:
     fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self>;
 
     /// Returns the Varuna universal prover.
+    #[cfg(feature = "std")]
     fn varuna_universal_prover() -> &'static UniversalProver<Self::PairingCurve>;
 
     /// Returns the Varuna universal verifier.
+    #[cfg(feature = "std")]
     fn varuna_universal_verifier() -> &'static UniversalVerifier<Self::PairingCurve>;
 
     /// Returns the sponge parameters for Varuna.
+    #[cfg(feature = "std")]
     fn varuna_fs_parameters() -> &'static FiatShamirParameters<Self>;
 
     /// Returns the encryption domain as a constant field element.
@@ -351,4 +398,26 @@ pub trait Network:
         root: &Field<Self>,
         leaf: &Vec<Field<Self>>,
     ) -> bool;
+
+    /// Returns the record commitment, for the given input bits of `(program_id || record_name || record)`.
+    ///
+    /// Note: A network that overrides the record commitment or serial number derivation
+    /// must override the same hooks in its circuit environment (see `snarkvm_circuit_network::Aleo`).
+    fn commit_record(input: &[bool]) -> Result<Field<Self>> {
+        Self::hash_bhp1024(input)
+    }
+
+    /// Returns the generator `H` of the serial number, for the given record commitment.
+    fn serial_number_generator(commitment: Field<Self>) -> Result<Group<Self>> {
+        Self::hash_to_group_psd2(&[Self::serial_number_domain(), commitment])
+    }
+
+    /// Returns the serial number, for the given `gamma` (as `sk_sig * H`) and record commitment.
+    fn serial_number_from_gamma(gamma: &Group<Self>, commitment: Field<Self>) -> Result<Field<Self>> {
+        // Compute `sn_nonce` as `Hash(COFACTOR * gamma)`.
+        let sn_nonce =
+            Self::hash_to_scalar_psd2(&[Self::serial_number_domain(), gamma.mul_by_cofactor().to_x_coordinate()])?;
+        // Compute `serial_number` as `Commit(commitment, sn_nonce)`.
+        Self::commit_bhp512(&(Self::serial_number_domain(), commitment).to_bits_le(), &sn_nonce)
+    }
 }
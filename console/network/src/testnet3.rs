@@ -14,7 +14,6 @@
 
 use super::*;
 use snarkvm_console_algorithms::{
-    Blake2Xs,
     Keccak256,
     Keccak384,
     Keccak512,
@@ -34,10 +33,7 @@ use snarkvm_console_algorithms::{
 
 lazy_static! {
     /// The group bases for the Aleo signature and encryption schemes.
-    pub static ref GENERATOR_G: Vec<Group<Testnet3>> = Testnet3::new_bases("AleoAccountEncryptionAndSignatureScheme0");
-
-    /// The Varuna sponge parameters.
-    pub static ref VARUNA_FS_PARAMETERS: FiatShamirParameters<Testnet3> = FiatShamir::<Testnet3>::sample_parameters();
+    pub static ref GENERATOR_G: Vec<Group<Testnet3>> = new_bases("AleoAccountEncryptionAndSignatureScheme0");
 
     /// The encryption domain as a constant field element.
     pub static ref ENCRYPTION_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoSymmetricEncryption0");
@@ -66,6 +62,12 @@ lazy_static! {
     pub static ref POSEIDON_4: Poseidon4<Testnet3> = Poseidon4::<Testnet3>::setup("AleoPoseidon4").expect("Failed to setup Poseidon4");
     /// The Poseidon hash function, using a rate of 8.
     pub static ref POSEIDON_8: Poseidon8<Testnet3> = Poseidon8::<Testnet3>::setup("AleoPoseidon8").expect("Failed to setup Poseidon8");
+}
+
+#[cfg(feature = "std")]
+lazy_static! {
+    /// The Varuna sponge parameters.
+    pub static ref VARUNA_FS_PARAMETERS: FiatShamirParameters<Testnet3> = FiatShamir::<Testnet3>::sample_parameters();
 
     pub static ref CREDITS_PROVING_KEYS: IndexMap<String, Arc<VarunaProvingKey<Console>>> = {
         let mut map = IndexMap::new();
@@ -84,23 +86,6 @@ pub const TRANSACTION_PREFIX: &str = "at";
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Testnet3;
 
-impl Testnet3 {
-    /// Initializes a new instance of group bases from a given input domain message.
-    fn new_bases(message: &str) -> Vec<Group<Self>> {
-        // Hash the given message to a point on the curve, to initialize the starting base.
-        let (base, _, _) = Blake2Xs::hash_to_curve::<<Self as Environment>::Affine>(message);
-
-        // Compute the bases up to the size of the scalar field (in bits).
-        let mut g = Group::<Self>::new(base);
-        let mut g_bases = Vec::with_capacity(Scalar::<Self>::size_in_bits());
-        for _ in 0..Scalar::<Self>::size_in_bits() {
-            g_bases.push(g);
-            g = g.double();
-        }
-        g_bases
-    }
-}
-
 impl Environment for Testnet3 {
     type Affine = <Console as Environment>::Affine;
     type BigInteger = <Console as Environment>::BigInteger;
@@ -134,16 +119,19 @@ impl Network for Testnet3 {
     /// The network ID.
     const ID: u16 = 3;
     /// The function name for the inclusion circuit.
+    #[cfg(feature = "std")]
     const INCLUSION_FUNCTION_NAME: &'static str = snarkvm_parameters::testnet3::TESTNET3_INCLUSION_FUNCTION_NAME;
     /// The network name.
     const NAME: &'static str = "Aleo Testnet 3";
 
     /// Returns the genesis block bytes.
+    #[cfg(feature = "std")]
     fn genesis_bytes() -> &'static [u8] {
         snarkvm_parameters::testnet3::GenesisBytes::load_bytes()
     }
 
     /// Returns the proving key for the given function name in `credits.aleo`.
+    #[cfg(feature = "std")]
     fn get_credits_proving_key(function_name: String) -> Result<&'static Arc<VarunaProvingKey<Self>>> {
         CREDITS_PROVING_KEYS
             .get(&function_name)
@@ -151,6 +139,7 @@ impl Network for Testnet3 {
     }
 
     /// Returns the verifying key for the given function name in `credits.aleo`.
+    #[cfg(feature = "std")]
     fn get_credits_verifying_key(function_name: String) -> Result<&'static Arc<VarunaVerifyingKey<Self>>> {
         CREDITS_VERIFYING_KEYS
             .get(&function_name)
@@ -158,6 +147,7 @@ impl Network for Testnet3 {
     }
 
     /// Returns the `proving key` for the inclusion circuit.
+    #[cfg(feature = "std")]
     fn inclusion_proving_key() -> &'static Arc<VarunaProvingKey<Self>> {
         static INSTANCE: OnceCell<Arc<VarunaProvingKey<Console>>> = OnceCell::new();
         INSTANCE.get_or_init(|| {
@@ -170,6 +160,7 @@ impl Network for Testnet3 {
     }
 
     /// Returns the `verifying key` for the inclusion circuit.
+    #[cfg(feature = "std")]
     fn inclusion_verifying_key() -> &'static Arc<VarunaVerifyingKey<Self>> {
         static INSTANCE: OnceCell<Arc<VarunaVerifyingKey<Console>>> = OnceCell::new();
         INSTANCE.get_or_init(|| {
@@ -199,6 +190,7 @@ impl Network for Testnet3 {
     }
 
     /// Returns the Varuna universal prover.
+    #[cfg(feature = "std")]
     fn varuna_universal_prover() -> &'static UniversalProver<Self::PairingCurve> {
         static INSTANCE: OnceCell<UniversalProver<<Console as Environment>::PairingCurve>> = OnceCell::new();
         INSTANCE.get_or_init(|| {
@@ -210,6 +202,7 @@ impl Network for Testnet3 {
     }
 
     /// Returns the Varuna universal verifier.
+    #[cfg(feature = "std")]
     fn varuna_universal_verifier() -> &'static UniversalVerifier<Self::PairingCurve> {
         static INSTANCE: OnceCell<UniversalVerifier<<Console as Environment>::PairingCurve>> = OnceCell::new();
         INSTANCE.get_or_init(|| {
@@ -221,6 +214,7 @@ impl Network for Testnet3 {
     }
 
     /// Returns the sponge parameters used for the sponge in the Varuna SNARK.
+    #[cfg(feature = "std")]
     fn varuna_fs_parameters() -> &'static FiatShamirParameters<Self> {
         &VARUNA_FS_PARAMETERS
     }
@@ -26,7 +26,7 @@ impl<N: Network> FromBytes for Plaintext<N> {
                 // Read the number of members in the struct.
                 let num_members = u8::read_le(&mut reader)?;
                 // Read the members.
-                let mut members = IndexMap::with_capacity(num_members as usize);
+                let mut members = IndexMap::with_capacity_and_hasher(num_members as usize, Default::default());
                 for _ in 0..num_members {
                     // Read the identifier.
                     let identifier = Identifier::<N>::read_le(&mut reader)?;
@@ -56,7 +56,7 @@ impl<N: Network> FromBits for Plaintext<N> {
                 bail!("Struct exceeds maximum of entries.");
             }
 
-            let mut members = IndexMap::with_capacity(num_members as usize);
+            let mut members = IndexMap::with_capacity_and_hasher(num_members as usize, Default::default());
             for _ in 0..num_members {
                 let identifier_size = u8::from_bits_le(next_bits(8)?)?;
                 let identifier = Identifier::from_bits_le(next_bits(identifier_size as usize)?)?;
@@ -137,7 +137,7 @@ impl<N: Network> FromBits for Plaintext<N> {
                 bail!("Struct exceeds maximum of entries.");
             }
 
-            let mut members = IndexMap::with_capacity(num_members as usize);
+            let mut members = IndexMap::with_capacity_and_hasher(num_members as usize, Default::default());
             for _ in 0..num_members {
                 let identifier_size = u8::from_bits_be(next_bits(8)?)?;
                 let identifier = Identifier::from_bits_be(next_bits(identifier_size as usize)?)?;
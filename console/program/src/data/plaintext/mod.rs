@@ -25,12 +25,14 @@ mod size_in_fields;
 mod to_bits;
 mod to_fields;
 
-use crate::{Access, Ciphertext, Identifier, Literal};
+use crate::{Access, Ciphertext, Identifier, IndexMap, Literal};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
-use indexmap::IndexMap;
+#[cfg(feature = "std")]
 use once_cell::sync::OnceCell;
+#[cfg(not(feature = "std"))]
+use once_cell::unsync::OnceCell;
 
 #[derive(Clone)]
 pub enum Plaintext<N: Network> {
@@ -14,15 +14,21 @@
 
 use super::*;
 
+/// The bit of the entry count that marks a record with a detection tag.
+/// Records without a detection tag keep their encoding, as the entry count never exceeds `N::MAX_DATA_ENTRIES`.
+const DETECTION_TAG_FLAG: u8 = 0x80;
+
 impl<N: Network, Private: Visibility> FromBytes for Record<N, Private> {
     /// Reads the record from a buffer.
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         // Read the owner.
         let owner = Owner::read_le(&mut reader)?;
-        // Read the number of entries in the record data.
+        // Read the number of entries in the record data, and whether the record has a detection tag.
         let num_entries = u8::read_le(&mut reader)?;
+        let (has_detection_tag, num_entries) =
+            (num_entries & DETECTION_TAG_FLAG != 0, num_entries & !DETECTION_TAG_FLAG);
         // Read the record data.
-        let mut data = IndexMap::with_capacity(num_entries as usize);
+        let mut data = IndexMap::with_capacity_and_hasher(num_entries as usize, Default::default());
         for _ in 0..num_entries {
             // Read the identifier.
             let identifier = Identifier::<N>::read_le(&mut reader)?;
@@ -37,6 +43,11 @@ impl<N: Network, Private: Visibility> FromBytes for Record<N, Private> {
         }
         // Read the nonce.
         let nonce = Group::read_le(&mut reader)?;
+        // Read the detection tag.
+        let detection_tag = match has_detection_tag {
+            true => Some(Field::read_le(&mut reader)?),
+            false => None,
+        };
 
         // Prepare the reserved entry names.
         let reserved = [Identifier::from_str("owner").map_err(|e| error(e.to_string()))?];
@@ -49,7 +60,7 @@ impl<N: Network, Private: Visibility> FromBytes for Record<N, Private> {
             return Err(error("Failed to parse record: too many entries"));
         }
 
-        Ok(Self { owner, data, nonce })
+        Ok(Self { owner, data, nonce, detection_tag })
     }
 }
 
@@ -58,8 +69,16 @@ impl<N: Network, Private: Visibility> ToBytes for Record<N, Private> {
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
         // Write the owner.
         self.owner.write_le(&mut writer)?;
-        // Write the number of entries in the record data.
-        u8::try_from(self.data.len()).or_halt_with::<N>("Record length exceeds u8::MAX").write_le(&mut writer)?;
+        // Write the number of entries in the record data, and whether the record has a detection tag.
+        let num_entries = u8::try_from(self.data.len()).or_halt_with::<N>("Record length exceeds u8::MAX");
+        if num_entries & DETECTION_TAG_FLAG != 0 {
+            return Err(error("Record length exceeds the maximum number of entries"));
+        }
+        match self.detection_tag {
+            Some(_) => num_entries | DETECTION_TAG_FLAG,
+            None => num_entries,
+        }
+        .write_le(&mut writer)?;
         // Write each entry.
         for (entry_name, entry_value) in &self.data {
             // Write the entry name.
@@ -74,7 +93,12 @@ impl<N: Network, Private: Visibility> ToBytes for Record<N, Private> {
             bytes.write_le(&mut writer)?;
         }
         // Write the nonce.
-        self.nonce.write_le(&mut writer)
+        self.nonce.write_le(&mut writer)?;
+        // Write the detection tag.
+        match &self.detection_tag {
+            Some(detection_tag) => detection_tag.write_le(&mut writer),
+            None => Ok(()),
+        }
     }
 }
 
@@ -96,6 +120,19 @@ mod tests {
         let expected_bytes = expected.to_bytes_le()?;
         assert_eq!(expected, Record::read_le(&expected_bytes[..])?);
         assert!(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::read_le(&expected_bytes[1..]).is_err());
+
+        // Check the byte representation with a detection tag.
+        let expected = expected.with_detection_tag(Field::from_u64(1));
+        let expected_bytes_with_tag = expected.to_bytes_le()?;
+        let candidate = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::read_le(&expected_bytes_with_tag[..])?;
+        assert_eq!(expected, candidate);
+        assert_eq!(Some(&Field::from_u64(1)), candidate.detection_tag());
+        // Ensure the detection tag only adds the tag itself to the encoding.
+        assert_eq!(expected_bytes.len() + Field::<CurrentNetwork>::size_in_bytes(), expected_bytes_with_tag.len());
+        assert_eq!(
+            None,
+            Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::read_le(&expected_bytes[..])?.detection_tag()
+        );
         Ok(())
     }
 }
@@ -57,7 +57,7 @@ impl<N: Network> Record<N, Ciphertext<N>> {
         }
 
         // Decrypt the program data.
-        let mut decrypted_data = IndexMap::with_capacity(self.data.len());
+        let mut decrypted_data = IndexMap::with_capacity_and_hasher(self.data.len(), Default::default());
         for (id, entry, num_randomizers) in self.data.iter().map(|(id, entry)| (id, entry, entry.num_randomizers())) {
             // Retrieve the result for `num_randomizers`.
             let num_randomizers = num_randomizers? as usize;
@@ -87,7 +87,9 @@ impl<N: Network> Record<N, Ciphertext<N>> {
         }
 
         // Return the decrypted record.
-        Self::from_plaintext(owner, decrypted_data, self.nonce)
+        let record = Self::from_plaintext(owner, decrypted_data, self.nonce)?;
+        // Preserve the detection tag.
+        Ok(Record { detection_tag: self.detection_tag, ..record })
     }
 }
 
@@ -120,6 +122,7 @@ mod tests {
                 .into_iter(),
             ),
             nonce: N::g_scalar_multiply(&randomizer),
+            detection_tag: None,
         };
         // Encrypt the record.
         let ciphertext = record.encrypt(randomizer)?;
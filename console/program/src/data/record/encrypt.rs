@@ -57,7 +57,7 @@ impl<N: Network> Record<N, Plaintext<N>> {
         }
 
         // Encrypt the data.
-        let mut encrypted_data = IndexMap::with_capacity(self.data.len());
+        let mut encrypted_data = IndexMap::with_capacity_and_hasher(self.data.len(), Default::default());
         for (id, entry, num_randomizers) in self.data.iter().map(|(id, entry)| (id, entry, entry.num_randomizers())) {
             // Retrieve the result for `num_randomizers`.
             let num_randomizers = num_randomizers? as usize;
@@ -88,6 +88,8 @@ impl<N: Network> Record<N, Plaintext<N>> {
         }
 
         // Return the encrypted record.
-        Self::from_ciphertext(owner, encrypted_data, self.nonce)
+        let record = Self::from_ciphertext(owner, encrypted_data, self.nonce)?;
+        // Preserve the detection tag.
+        Ok(Record { detection_tag: self.detection_tag, ..record })
     }
 }
@@ -19,12 +19,10 @@ mod num_randomizers;
 mod parse;
 mod to_bits;
 
-use crate::{Access, Ciphertext, Identifier, Literal, Plaintext};
+use crate::{Access, Ciphertext, Identifier, IndexMap, Literal, Plaintext};
 use snarkvm_console_network::Network;
 use snarkvm_console_types::prelude::*;
 
-use indexmap::IndexMap;
-
 /// An entry stored in program data.
 #[derive(Clone)]
 pub enum Entry<N: Network, Private: Visibility> {
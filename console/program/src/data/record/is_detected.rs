@@ -0,0 +1,77 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network> Record<N, Ciphertext<N>> {
+    /// Returns `true` if the detection tag of this record was computed for the given detection key.
+    /// Unlike `is_owner`, this does not require the view key, and does not allow decrypting the record.
+    pub fn is_detected(&self, detection_key: &DetectionKey<N>) -> bool {
+        match &self.detection_tag {
+            Some(tag) => detection_key.is_detected(&self.nonce, tag),
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_is_detected() -> Result<()> {
+        let mut rng = TestRng::default();
+        let program_id = ProgramID::<CurrentNetwork>::from_str("token.aleo")?;
+
+        for _ in 0..ITERATIONS {
+            // Sample a view key and address.
+            let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let address = Address::try_from(&private_key)?;
+            let detection_key = program_id.to_detection_key(&view_key)?;
+
+            // Prepare the record.
+            let randomizer = Scalar::rand(&mut rng);
+            let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>> {
+                owner: Owner::Private(Plaintext::from(Literal::Address(address))),
+                data: IndexMap::default(),
+                nonce: CurrentNetwork::g_scalar_multiply(&randomizer),
+                detection_tag: None,
+            };
+            let ciphertext = record.encrypt(randomizer)?;
+            // Ensure a record without a detection tag is not found.
+            assert!(!ciphertext.is_detected(&detection_key));
+
+            // Attach the detection tag as the sender, from the detection public key of the recipient.
+            let tag = DetectionKey::tag_from_randomizer(&detection_key.to_public_key(), &randomizer)?;
+            let ciphertext = Record::from_str(&ciphertext.with_detection_tag(tag).to_string())?;
+
+            // Ensure the detection key finds the record.
+            assert!(ciphertext.is_detected(&detection_key));
+            // Ensure the detection tag is preserved by decryption.
+            assert_eq!(Some(&tag), ciphertext.decrypt(&view_key)?.detection_tag());
+            // Ensure the detection key of another account does not find the record.
+            let other_view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+            assert!(!ciphertext.is_detected(&program_id.to_detection_key(&other_view_key)?));
+            // Ensure the detection key of another program does not find the record.
+            let other_program_id = ProgramID::<CurrentNetwork>::from_str("other.aleo")?;
+            assert!(!ciphertext.is_detected(&other_program_id.to_detection_key(&view_key)?));
+        }
+        Ok(())
+    }
+}
@@ -86,6 +86,7 @@ mod tests {
                 .into_iter(),
             ),
             nonce: N::g_scalar_multiply(&randomizer),
+            detection_tag: None,
         };
 
         // Encrypt the record.
@@ -23,10 +23,12 @@ mod decrypt;
 mod encrypt;
 mod equal;
 mod find;
+mod is_detected;
 mod is_owner;
 mod num_randomizers;
 mod parse_ciphertext;
 mod parse_plaintext;
+mod reencrypt;
 mod serial_number;
 mod serialize;
 mod tag;
@@ -34,13 +36,11 @@ mod to_bits;
 mod to_commitment;
 mod to_fields;
 
-use crate::{Access, Ciphertext, Identifier, Literal, Plaintext, ProgramID};
-use snarkvm_console_account::{Address, PrivateKey, ViewKey};
+use crate::{Access, Ciphertext, Identifier, IndexMap, Literal, Plaintext, ProgramID};
+use snarkvm_console_account::{Address, DetectionKey, PrivateKey, ViewKey};
 use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::{Boolean, Field, Group, Scalar};
 
-use indexmap::IndexMap;
-
 /// A value stored in program record.
 #[derive(Clone)]
 pub struct Record<N: Network, Private: Visibility> {
@@ -50,6 +50,10 @@ pub struct Record<N: Network, Private: Visibility> {
     data: IndexMap<Identifier<N>, Entry<N, Private>>,
     /// The nonce of the program record.
     nonce: Group<N>,
+    /// The detection tag of the program record, if the sender attached one.
+    ///
+    /// The tag is not part of the record commitment, so it may be attached after the record is created.
+    detection_tag: Option<Field<N>>,
 }
 
 impl<N: Network, Private: Visibility> Record<N, Private> {
@@ -65,7 +69,7 @@ impl<N: Network, Private: Visibility> Record<N, Private> {
         // Ensure the number of entries is within the maximum limit.
         ensure!(data.len() <= N::MAX_DATA_ENTRIES, "Found a record that exceeds size ({})", data.len());
         // Return the record.
-        Ok(Record { owner, data, nonce })
+        Ok(Record { owner, data, nonce, detection_tag: None })
     }
 
     /// Initializes a new record ciphertext.
@@ -80,7 +84,7 @@ impl<N: Network, Private: Visibility> Record<N, Private> {
         // Ensure the number of entries is within the maximum limit.
         ensure!(data.len() <= N::MAX_DATA_ENTRIES, "Found a record that exceeds size ({})", data.len());
         // Return the record.
-        Ok(Record { owner, data, nonce })
+        Ok(Record { owner, data, nonce, detection_tag: None })
     }
 }
 
@@ -99,6 +103,16 @@ impl<N: Network, Private: Visibility> Record<N, Private> {
     pub const fn nonce(&self) -> &Group<N> {
         &self.nonce
     }
+
+    /// Returns the detection tag of the program record, if one is attached.
+    pub const fn detection_tag(&self) -> Option<&Field<N>> {
+        self.detection_tag.as_ref()
+    }
+
+    /// Returns the program record with the given detection tag attached.
+    pub fn with_detection_tag(self, detection_tag: Field<N>) -> Self {
+        Self { detection_tag: Some(detection_tag), ..self }
+    }
 }
 
 impl<N: Network, Private: Visibility> Record<N, Private> {
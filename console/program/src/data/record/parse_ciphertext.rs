@@ -39,7 +39,7 @@ impl<N: Network> FromStr for Record<N, Ciphertext<N>> {
     /// Reads in the ciphertext string.
     fn from_str(ciphertext: &str) -> Result<Self, Self::Err> {
         // Decode the ciphertext string from bech32m.
-        let (hrp, data, variant) = bech32::decode(ciphertext)?;
+        let (hrp, data, variant) = bech32::decode(ciphertext).map_err(|e| anyhow!("{e}"))?;
         if hrp != RECORD_CIPHERTEXT_PREFIX {
             bail!("Failed to decode record ciphertext: '{hrp}' is an invalid prefix")
         } else if data.is_empty() {
@@ -48,7 +48,7 @@ impl<N: Network> FromStr for Record<N, Ciphertext<N>> {
             bail!("Found a record ciphertext that is not bech32m encoded: {ciphertext}");
         }
         // Decode the record ciphertext data from u5 to u8, and into the record ciphertext.
-        Ok(Self::read_le(&Vec::from_base32(&data)?[..])?)
+        Ok(Self::read_le(&Vec::from_base32(&data).map_err(|e| anyhow!("{e}"))?[..])?)
     }
 }
 
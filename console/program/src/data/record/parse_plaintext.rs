@@ -95,12 +95,30 @@ impl<N: Network> Parser for Record<N, Plaintext<N>> {
         // Parse the nonce from the string.
         let (string, (nonce, _)) = pair(Group::parse, tag(".public"))(string)?;
 
+        // Parse the whitespace and comments from the string.
+        let (string, _) = Sanitizer::parse(string)?;
+        // Parse the optional "_tag" from the string.
+        let (string, detection_tag) = opt(|string| {
+            // Parse the "," from the string.
+            let (string, _) = tag(",")(string)?;
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the "_tag" from the string.
+            let (string, _) = tag("_tag")(string)?;
+            // Parse the ":" from the string.
+            let (string, _) = tag(":")(string)?;
+            // Parse the whitespace and comments from the string.
+            let (string, _) = Sanitizer::parse(string)?;
+            // Parse the detection tag from the string.
+            terminated(Field::parse, tag(".public"))(string)
+        })(string)?;
+
         // Parse the whitespace and comments from the string.
         let (string, _) = Sanitizer::parse(string)?;
         // Parse the '}' from the string.
         let (string, _) = tag("}")(string)?;
         // Output the record.
-        Ok((string, Record { owner, data: IndexMap::from_iter(entries.into_iter()), nonce }))
+        Ok((string, Record { owner, data: IndexMap::from_iter(entries.into_iter()), nonce, detection_tag }))
     }
 }
 
@@ -168,6 +186,10 @@ impl<N: Network> Record<N, Plaintext<N>> {
         }
         // Print the nonce without a comma.
         write!(f, "\n{:indent$}_nonce: {}.public", "", self.nonce, indent = (depth + 1) * INDENT)?;
+        // Print the detection tag with a leading comma, if one is attached.
+        if let Some(detection_tag) = &self.detection_tag {
+            write!(f, ",\n{:indent$}_tag: {detection_tag}.public", "", indent = (depth + 1) * INDENT)?;
+        }
         // Print the closing brace.
         write!(f, "\n{:indent$}}}", "", indent = depth * INDENT)
     }
@@ -196,6 +218,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_with_detection_tag() -> Result<()> {
+        // Sanity check.
+        let expected = r"{
+  owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private,
+  _nonce: 0group.public,
+  _tag: 1field.public
+}";
+        let given = "{ owner: aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah.private, _nonce: 0group.public, _tag: 1field.public }";
+        let (remainder, candidate) = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::parse(given)?;
+        assert_eq!(expected, candidate.to_string());
+        assert_eq!(Some(&Field::from_u64(1)), candidate.detection_tag());
+        assert_eq!("", remainder);
+        Ok(())
+    }
+
     #[test]
     fn test_parse_with_literal_entry() -> Result<()> {
         let expected = r"{
@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network> Record<N, Ciphertext<N>> {
+    /// Re-encrypts `self` to the given new owner under the given randomizer, using the view key of the current owner.
+    ///
+    /// The record data is preserved, the owner keeps its visibility, and the nonce is replaced by `G^randomizer`.
+    /// As the owner and nonce change, the re-encrypted record has a different commitment than `self`,
+    /// and any detection tag is dropped.
+    pub fn reencrypt(&self, view_key: &ViewKey<N>, new_owner: Address<N>, randomizer: Scalar<N>) -> Result<Self> {
+        // Decrypt the record, which ensures the view key belongs to the current owner.
+        let record = self.decrypt(view_key)?;
+        // Replace the owner, preserving its visibility.
+        let owner = match record.owner.is_public() {
+            true => Owner::Public(new_owner),
+            false => Owner::Private(Plaintext::from(Literal::Address(new_owner))),
+        };
+        // Encrypt the record for the new owner.
+        let record = Record::<N, Plaintext<N>>::from_plaintext(owner, record.data, N::g_scalar_multiply(&randomizer))?;
+        record.encrypt(randomizer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    fn check_reencrypt(is_private_owner: bool, rng: &mut TestRng) -> Result<()> {
+        // Sample the current and new owners.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let view_key = ViewKey::try_from(&private_key)?;
+        let new_private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+        let new_view_key = ViewKey::try_from(&new_private_key)?;
+        let new_owner = Address::try_from(&new_private_key)?;
+
+        // Prepare the record.
+        let visibility = if is_private_owner { "private" } else { "public" };
+        let randomizer = Scalar::rand(rng);
+        let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {}.{visibility}, amount: {}u64.private, memo: {}field.public, _nonce: {}.public }}",
+            Address::try_from(&private_key)?,
+            u64::rand(rng),
+            u8::rand(rng),
+            CurrentNetwork::g_scalar_multiply(&randomizer)
+        ))?;
+        let ciphertext = record.encrypt(randomizer)?;
+
+        // Re-encrypt the record to the new owner.
+        let new_randomizer = Scalar::rand(rng);
+        let candidate = ciphertext.reencrypt(&view_key, new_owner, new_randomizer)?;
+        assert_eq!(candidate.nonce(), &CurrentNetwork::g_scalar_multiply(&new_randomizer));
+        assert_eq!(candidate.owner().is_public(), record.owner().is_public());
+
+        // Ensure the new owner can decrypt the record, and the data is preserved.
+        assert!(candidate.is_owner(&new_view_key));
+        assert!(!candidate.is_owner(&view_key));
+        let decrypted = candidate.decrypt(&new_view_key)?;
+        assert_eq!(**decrypted.owner(), new_owner);
+        assert_eq!(decrypted.data(), record.data());
+
+        // Ensure a view key of another account cannot re-encrypt the record.
+        assert!(ciphertext.reencrypt(&new_view_key, new_owner, new_randomizer).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_reencrypt() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            check_reencrypt(true, &mut rng)?;
+            check_reencrypt(false, &mut rng)?;
+        }
+        Ok(())
+    }
+}
@@ -19,8 +19,8 @@ impl<N: Network> Record<N, Plaintext<N>> {
     pub fn to_commitment(&self, program_id: &ProgramID<N>, record_name: &Identifier<N>) -> Result<Field<N>> {
         // Construct the input as `(program_id || record_name || record)`.
         let input = to_bits_le![program_id, record_name, self];
-        // Compute the BHP hash of the program record.
-        N::hash_bhp1024(&input)
+        // Compute the commitment of the program record.
+        N::commit_record(&input)
     }
 }
 
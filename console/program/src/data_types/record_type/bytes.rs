@@ -32,7 +32,7 @@ impl<N: Network> FromBytes for RecordType<N> {
             )));
         }
         // Read the entries.
-        let mut entries = IndexMap::with_capacity(num_entries as usize);
+        let mut entries = IndexMap::with_capacity_and_hasher(num_entries as usize, Default::default());
         for _ in 0..num_entries {
             // Read the identifier.
             let identifier = Identifier::read_le(&mut reader)?;
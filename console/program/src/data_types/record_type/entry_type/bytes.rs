@@ -17,11 +17,19 @@ use super::*;
 impl<N: Network> ToBytes for EntryType<N> {
     /// Writes the entry type to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        u8::try_from(self.enum_index()).or_halt_with::<N>("Invalid entry type variant").write_le(&mut writer)?;
         match self {
-            Self::Constant(plaintext_type) => plaintext_type.write_le(&mut writer),
-            Self::Public(plaintext_type) => plaintext_type.write_le(&mut writer),
-            Self::Private(plaintext_type) => plaintext_type.write_le(&mut writer),
+            Self::Constant(plaintext_type) => {
+                0u8.write_le(&mut writer)?;
+                plaintext_type.write_le(&mut writer)
+            }
+            Self::Public(plaintext_type) => {
+                1u8.write_le(&mut writer)?;
+                plaintext_type.write_le(&mut writer)
+            }
+            Self::Private(plaintext_type) => {
+                2u8.write_le(&mut writer)?;
+                plaintext_type.write_le(&mut writer)
+            }
         }
     }
 }
@@ -22,11 +22,9 @@ mod bytes;
 mod parse;
 mod serialize;
 
-use crate::Identifier;
+use crate::{Identifier, IndexMap};
 use snarkvm_console_network::prelude::*;
 
-use indexmap::IndexMap;
-
 /// The declared layout for program data.
 #[derive(Clone, PartialEq, Eq)]
 pub struct RecordType<N: Network> {
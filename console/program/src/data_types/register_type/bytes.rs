@@ -17,11 +17,19 @@ use super::*;
 impl<N: Network> ToBytes for RegisterType<N> {
     /// Writes the register type to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        u8::try_from(self.enum_index()).or_halt_with::<N>("Invalid register type variant").write_le(&mut writer)?;
         match self {
-            Self::Plaintext(plaintext_type) => plaintext_type.write_le(&mut writer),
-            Self::Record(identifier) => identifier.write_le(&mut writer),
-            Self::ExternalRecord(locator) => locator.write_le(&mut writer),
+            Self::Plaintext(plaintext_type) => {
+                0u8.write_le(&mut writer)?;
+                plaintext_type.write_le(&mut writer)
+            }
+            Self::Record(identifier) => {
+                1u8.write_le(&mut writer)?;
+                identifier.write_le(&mut writer)
+            }
+            Self::ExternalRecord(locator) => {
+                2u8.write_le(&mut writer)?;
+                locator.write_le(&mut writer)
+            }
         }
     }
 }
@@ -19,9 +19,7 @@ mod serialize;
 use crate::{Identifier, Locator, PlaintextType, ValueType};
 use snarkvm_console_network::prelude::*;
 
-use enum_index::EnumIndex;
-
-#[derive(Clone, PartialEq, Eq, Hash, EnumIndex)]
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub enum RegisterType<N: Network> {
     /// A plaintext type.
     Plaintext(PlaintextType<N>),
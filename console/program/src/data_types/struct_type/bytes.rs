@@ -30,7 +30,7 @@ impl<N: Network> FromBytes for StructType<N> {
             )));
         }
         // Read the members.
-        let mut members = IndexMap::with_capacity(num_members as usize);
+        let mut members = IndexMap::with_capacity_and_hasher(num_members as usize, Default::default());
         for _ in 0..num_members {
             // Read the identifier.
             let identifier = Identifier::read_le(&mut reader)?;
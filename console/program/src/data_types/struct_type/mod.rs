@@ -16,11 +16,9 @@ mod bytes;
 mod parse;
 mod serialize;
 
-use crate::{Identifier, PlaintextType};
+use crate::{Identifier, IndexMap, PlaintextType};
 use snarkvm_console_network::prelude::*;
 
-use indexmap::IndexMap;
-
 #[derive(Clone, PartialEq, Eq)]
 pub struct StructType<N: Network> {
     /// The name of the struct.
@@ -17,13 +17,27 @@ use super::*;
 impl<N: Network> ToBytes for ValueType<N> {
     /// Writes the value type to a buffer.
     fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
-        u8::try_from(self.enum_index()).or_halt_with::<N>("Invalid value type variant").write_le(&mut writer)?;
         match self {
-            Self::Constant(plaintext_type) => plaintext_type.write_le(&mut writer),
-            Self::Public(plaintext_type) => plaintext_type.write_le(&mut writer),
-            Self::Private(plaintext_type) => plaintext_type.write_le(&mut writer),
-            Self::Record(identifier) => identifier.write_le(&mut writer),
-            Self::ExternalRecord(locator) => locator.write_le(&mut writer),
+            Self::Constant(plaintext_type) => {
+                0u8.write_le(&mut writer)?;
+                plaintext_type.write_le(&mut writer)
+            }
+            Self::Public(plaintext_type) => {
+                1u8.write_le(&mut writer)?;
+                plaintext_type.write_le(&mut writer)
+            }
+            Self::Private(plaintext_type) => {
+                2u8.write_le(&mut writer)?;
+                plaintext_type.write_le(&mut writer)
+            }
+            Self::Record(identifier) => {
+                3u8.write_le(&mut writer)?;
+                identifier.write_le(&mut writer)
+            }
+            Self::ExternalRecord(locator) => {
+                4u8.write_le(&mut writer)?;
+                locator.write_le(&mut writer)
+            }
         }
     }
 }
@@ -19,9 +19,7 @@ mod serialize;
 use crate::{EntryType, Identifier, Locator, PlaintextType};
 use snarkvm_console_network::prelude::*;
 
-use enum_index::EnumIndex;
-
-#[derive(Clone, PartialEq, Eq, Hash, EnumIndex)]
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub enum ValueType<N: Network> {
     /// A constant type.
     Constant(PlaintextType<N>),
@@ -17,9 +17,11 @@ mod parse;
 mod serialize;
 mod to_address;
 mod to_bits;
+mod to_detection_key;
 mod to_fields;
 
 use crate::Identifier;
+use snarkvm_console_account::{DetectionKey, ViewKey};
 use snarkvm_console_network::prelude::*;
 use snarkvm_console_types::{Address, Field};
 
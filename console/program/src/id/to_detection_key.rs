@@ -0,0 +1,43 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network> ProgramID<N> {
+    /// Returns the detection key of the given view key for this program.
+    pub fn to_detection_key(&self, view_key: &ViewKey<N>) -> Result<DetectionKey<N>> {
+        DetectionKey::try_from((view_key, &self.to_address()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_account::PrivateKey;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_to_detection_key() -> Result<()> {
+        let mut rng = TestRng::default();
+        let view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(&mut rng)?)?;
+
+        // Ensure the detection key is scoped to the program.
+        let credits = ProgramID::<CurrentNetwork>::from_str("credits.aleo")?;
+        let token = ProgramID::<CurrentNetwork>::from_str("token.aleo")?;
+        assert_eq!(credits.to_detection_key(&view_key)?, credits.to_detection_key(&view_key)?);
+        assert_ne!(credits.to_detection_key(&view_key)?, token.to_detection_key(&view_key)?);
+        Ok(())
+    }
+}
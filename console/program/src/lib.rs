@@ -12,18 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_code)]
 #![allow(clippy::too_many_arguments)]
 #![warn(clippy::cast_possible_truncation)]
 #![cfg_attr(test, allow(clippy::assertions_on_result_states))]
 
 #[macro_use]
-extern crate enum_index_derive;
+extern crate alloc;
 
 pub use snarkvm_console_account::Signature;
 pub use snarkvm_console_network::Network;
 pub use snarkvm_console_types::prelude::*;
 
+#[cfg(feature = "std")]
+use indexmap::IndexMap;
+/// Without `std`, there is no source of randomness for the default hasher, so the maps use a fixed-key hasher.
+#[cfg(not(feature = "std"))]
+type IndexMap<K, V> = indexmap::IndexMap<K, V, core::hash::BuildHasherDefault<ahash::AHasher>>;
+
 mod data;
 pub use data::*;
 
@@ -19,7 +19,7 @@ impl<N: Network> FromStr for ProgramOwner<N> {
 
     /// Initializes the program owner from a JSON-string.
     fn from_str(owner: &str) -> Result<Self, Self::Err> {
-        Ok(serde_json::from_str(owner)?)
+        serde_json::from_str(owner).map_err(|e| anyhow!("{e}"))
     }
 }
 
@@ -19,7 +19,7 @@ impl<N: Network> FromStr for InputID<N> {
 
     /// Initializes the input ID from a JSON-string.
     fn from_str(input_id: &str) -> Result<Self, Self::Err> {
-        Ok(serde_json::from_str(input_id)?)
+        serde_json::from_str(input_id).map_err(|e| anyhow!("{e}"))
     }
 }
 
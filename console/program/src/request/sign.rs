@@ -167,7 +167,7 @@ impl<N: Network> Request<N> {
                     let commitment = record.to_commitment(&program_id, record_name)?;
 
                     // Compute the generator `H` as `HashToGroup(commitment)`.
-                    let h = N::hash_to_group_psd2(&[N::serial_number_domain(), commitment])?;
+                    let h = N::serial_number_generator(commitment)?;
                     // Compute `h_r` as `r * H`.
                     let h_r = h * r;
                     // Compute `gamma` as `sk_sig * H`.
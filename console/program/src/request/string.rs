@@ -19,7 +19,7 @@ impl<N: Network> FromStr for Request<N> {
 
     /// Initializes the request from a JSON-string.
     fn from_str(request: &str) -> Result<Self, Self::Err> {
-        Ok(serde_json::from_str(request)?)
+        serde_json::from_str(request).map_err(|e| anyhow!("{e}"))
     }
 }
 
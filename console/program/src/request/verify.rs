@@ -171,7 +171,7 @@ impl<N: Network> Request<N> {
                         ensure!(*serial_number == candidate_sn, "Expected a record input with the same serial number");
 
                         // Compute the generator `H` as `HashToGroup(commitment)`.
-                        let h = N::hash_to_group_psd2(&[N::serial_number_domain(), *commitment])?;
+                        let h = N::serial_number_generator(*commitment)?;
                         // Compute `h_r` as `(challenge * gamma) + (response * H)`, equivalent to `r * H`.
                         let h_r = (*gamma * challenge) + (h * response);
 
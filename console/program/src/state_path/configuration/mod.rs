@@ -40,6 +40,11 @@ pub type HeaderTree<N> = BHPMerkleTree<N, HEADER_DEPTH>;
 /// The Merkle path for the block header.
 pub type HeaderPath<N> = MerklePath<N, HEADER_DEPTH>;
 
+/// The Merkle tree for finalize operations in a block.
+pub type FinalizeTree<N> = BHPMerkleTree<N, FINALIZE_OPERATIONS_DEPTH>;
+/// The Merkle path for a finalize operation in a block.
+pub type FinalizePath<N> = MerklePath<N, FINALIZE_OPERATIONS_DEPTH>;
+
 /// The Merkle tree for transactions in a block.
 pub type TransactionsTree<N> = BHPMerkleTree<N, TRANSACTIONS_DEPTH>;
 /// The Merkle path for transaction in a block.
@@ -39,7 +39,7 @@ impl<N: Network> FromStr for StatePath<N> {
     /// Reads in the state path string.
     fn from_str(state_path: &str) -> Result<Self, Self::Err> {
         // Decode the state path string from bech32m.
-        let (hrp, data, variant) = bech32::decode(state_path)?;
+        let (hrp, data, variant) = bech32::decode(state_path).map_err(|e| anyhow!("{e}"))?;
         if hrp != STATE_PATH_PREFIX {
             bail!("Failed to decode state path: '{hrp}' is an invalid prefix")
         } else if data.is_empty() {
@@ -48,7 +48,7 @@ impl<N: Network> FromStr for StatePath<N> {
             bail!("Found an state path that is not bech32m encoded: {state_path}");
         }
         // Decode the state path data from u5 to u8, and into the state path.
-        Ok(Self::read_le(&Vec::from_base32(&data)?[..])?)
+        Ok(Self::read_le(&Vec::from_base32(&data).map_err(|e| anyhow!("{e}"))?[..])?)
     }
 }
 
@@ -19,7 +19,7 @@ impl<N: Network> FromStr for TransitionLeaf<N> {
 
     /// Initializes the leaf from a JSON-string.
     fn from_str(leaf: &str) -> Result<Self, Self::Err> {
-        Ok(serde_json::from_str(leaf)?)
+        serde_json::from_str(leaf).map_err(|e| anyhow!("{e}"))
     }
 }
 
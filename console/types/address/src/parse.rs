@@ -43,7 +43,7 @@ impl<E: Environment> FromStr for Address<E> {
             bail!("Invalid account address length: found {}, expected 63", address.len())
         }
         // Decode the address string from bech32m.
-        let (hrp, data, variant) = bech32::decode(address)?;
+        let (hrp, data, variant) = bech32::decode(address).map_err(|e| anyhow!("{e}"))?;
         if hrp != ADDRESS_PREFIX {
             bail!("Failed to decode address: '{hrp}' is an invalid prefix")
         } else if data.is_empty() {
@@ -52,7 +52,7 @@ impl<E: Environment> FromStr for Address<E> {
             bail!("Found an address that is not bech32m encoded: {address}");
         }
         // Decode the address data from u5 to u8, and into an account address.
-        Ok(Self::read_le(&Vec::from_base32(&data)?[..])?)
+        Ok(Self::read_le(&Vec::from_base32(&data).map_err(|e| anyhow!("{e}"))?[..])?)
     }
 }
 
@@ -12,24 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_code)]
 #![allow(clippy::too_many_arguments)]
 #![warn(clippy::cast_possible_truncation)]
 
-#[cfg(feature = "default")]
+#[cfg(feature = "cast")]
 pub mod cast;
-#[cfg(feature = "default")]
+#[cfg(feature = "cast")]
 pub use cast::*;
 
-#[cfg(feature = "default")]
+#[cfg(feature = "cast")]
 pub mod cast_lossy;
-#[cfg(feature = "default")]
+#[cfg(feature = "cast")]
 pub use cast_lossy::*;
 
 pub use modules::*;
 
 pub mod prelude {
-    #[cfg(feature = "default")]
+    #[cfg(feature = "cast")]
     pub use crate::{cast::*, cast_lossy::*};
 
     pub use crate::modules::*;
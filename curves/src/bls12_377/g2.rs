@@ -17,6 +17,7 @@ use snarkvm_utilities::{
     biginteger::{BigInteger256, BigInteger384},
     BigInteger,
     BitIteratorBE,
+    Vec,
 };
 
 use crate::{
@@ -26,7 +27,7 @@ use crate::{
     ProjectiveCurve,
 };
 
-use std::ops::Neg;
+use core::ops::Neg;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Bls12_377G2Parameters;
@@ -253,6 +253,35 @@ fn test_fq_sub_assign() {
     }
 }
 
+/// Checks the field multiplication and squaring against a reference multiplication over `BigUint`,
+/// so the selected limb arithmetic backend is tested against an independent implementation.
+fn check_mul_against_reference<F: PrimeField>(rng: &mut TestRng) {
+    let modulus = F::modulus().to_biguint();
+
+    // Sample random elements, and elements near the modulus.
+    let edge_cases = [F::zero(), F::one(), -F::one(), -F::one().double()];
+    let samples = edge_cases.into_iter().chain((0..1000).map(|_| F::rand(rng))).collect::<Vec<_>>();
+
+    for a in &samples {
+        for b in samples.iter().take(32) {
+            let expected = (a.to_bigint().to_biguint() * b.to_bigint().to_biguint()) % &modulus;
+            assert_eq!(expected, (*a * b).to_bigint().to_biguint());
+        }
+        let expected = (a.to_bigint().to_biguint() * a.to_bigint().to_biguint()) % &modulus;
+        assert_eq!(expected, a.square().to_bigint().to_biguint());
+    }
+}
+
+#[test]
+fn test_fr_mul_against_reference() {
+    check_mul_against_reference::<Fr>(&mut TestRng::default());
+}
+
+#[test]
+fn test_fq_mul_against_reference() {
+    check_mul_against_reference::<Fq>(&mut TestRng::default());
+}
+
 #[test]
 fn test_fq_mul_assign() {
     let mut rng = TestRng::default();
@@ -19,9 +19,9 @@ use crate::{
     traits::{AffineCurve, ModelParameters, MontgomeryParameters, TwistedEdwardsParameters},
 };
 use snarkvm_fields::field;
-use snarkvm_utilities::biginteger::BigInteger256;
+use snarkvm_utilities::{biginteger::BigInteger256, Vec};
 
-use std::str::FromStr;
+use core::str::FromStr;
 
 pub type EdwardsAffine = Affine<EdwardsParameters>;
 pub type EdwardsProjective = Projective<EdwardsParameters>;
@@ -12,14 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::module_inception)]
 // #![cfg_attr(nightly, feature(doc_cfg, external_doc))]
 // #![cfg_attr(nightly, warn(missing_docs))]
 #![cfg_attr(test, allow(clippy::assertions_on_result_states))]
 #![doc = include_str!("../documentation/the_aleo_curves/00_overview.md")]
 
+#[cfg(not(feature = "std"))]
 #[macro_use]
-extern crate thiserror;
+extern crate alloc;
 
 pub mod bls12_377;
 
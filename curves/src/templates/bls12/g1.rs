@@ -20,9 +20,12 @@ use crate::{
     traits::AffineCurve,
 };
 use snarkvm_fields::Zero;
-use snarkvm_utilities::{serialize::*, FromBytes, ToBytes};
-
-use std::io::{Read, Result as IoResult, Write};
+use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
+    serialize::*,
+    FromBytes,
+    ToBytes,
+};
 
 pub type G1Affine<P> = Affine<<P as Bls12Parameters>::G1Parameters>;
 pub type G1Projective<P> = Projective<<P as Bls12Parameters>::G1Parameters>;
@@ -20,9 +20,13 @@ use crate::{
     traits::{AffineCurve, ShortWeierstrassParameters},
 };
 use snarkvm_fields::{Field, Fp2, One, Zero};
-use snarkvm_utilities::{bititerator::BitIteratorBE, serialize::*, ToBytes};
-
-use std::io::{Result as IoResult, Write};
+use snarkvm_utilities::{
+    bititerator::BitIteratorBE,
+    io::{Result as IoResult, Write},
+    serialize::*,
+    ToBytes,
+    Vec,
+};
 
 pub type G2Affine<P> = Affine<<P as Bls12Parameters>::G2Parameters>;
 pub type G2Projective<P> = Projective<<P as Bls12Parameters>::G2Parameters>;
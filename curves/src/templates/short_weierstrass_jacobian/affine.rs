@@ -20,7 +20,8 @@ use crate::{
 use snarkvm_fields::{Field, One, SquareRootField, Zero};
 use snarkvm_utilities::{
     bititerator::BitIteratorBE,
-    io::{Error, ErrorKind, Read, Result as IoResult, Write},
+    error,
+    io::{Read, Result as IoResult, Write},
     rand::Uniform,
     serialize::*,
     FromBytes,
@@ -294,7 +295,7 @@ impl<P: Parameters> FromBytes for Affine<P> {
         let infinity = bool::read_le(&mut reader)?;
 
         if infinity != x.is_zero() && y.is_one() {
-            return Err(Error::new(ErrorKind::InvalidData, "Infinity flag is not valid"));
+            return Err(error("Infinity flag is not valid"));
         }
         Ok(Self::new(x, y, infinity))
     }
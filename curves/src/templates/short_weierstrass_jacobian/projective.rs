@@ -17,7 +17,14 @@ use crate::{
     traits::{AffineCurve, ProjectiveCurve, ShortWeierstrassParameters as Parameters},
 };
 use snarkvm_fields::{impl_add_sub_from_field_ref, Field, One, Zero};
-use snarkvm_utilities::{cfg_iter_mut, rand::Uniform, serialize::*, FromBytes, ToBytes};
+use snarkvm_utilities::{
+    cfg_iter_mut,
+    io::{Read, Result as IoResult, Write},
+    rand::Uniform,
+    serialize::*,
+    FromBytes,
+    ToBytes,
+};
 
 use core::{
     fmt::{Display, Formatter, Result as FmtResult},
@@ -30,7 +37,6 @@ use rand::{
 };
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
-use std::io::{Read, Result as IoResult, Write};
 
 #[derive(Copy, Clone, Debug)]
 pub struct Projective<P: Parameters> {
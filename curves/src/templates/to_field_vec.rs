@@ -20,6 +20,7 @@ use crate::{
     traits::{ProjectiveCurve, ShortWeierstrassParameters, TwistedEdwardsParameters},
 };
 use snarkvm_fields::{ConstraintFieldError, Field, ToConstraintField};
+use snarkvm_utilities::Vec;
 
 impl<M: TwistedEdwardsParameters, F: Field> ToConstraintField<F> for TEAffine<M>
 where
@@ -17,7 +17,14 @@ use crate::{
     traits::{AffineCurve, ProjectiveCurve, TwistedEdwardsParameters as Parameters},
 };
 use snarkvm_fields::{impl_add_sub_from_field_ref, Field, One, PrimeField, Zero};
-use snarkvm_utilities::{bititerator::BitIteratorBE, rand::Uniform, serialize::*, FromBytes, ToBytes};
+use snarkvm_utilities::{
+    bititerator::BitIteratorBE,
+    io::{Read, Result as IoResult, Write},
+    rand::Uniform,
+    serialize::*,
+    FromBytes,
+    ToBytes,
+};
 
 use core::{
     fmt::{Display, Formatter, Result as FmtResult},
@@ -28,7 +35,6 @@ use rand::{
     distributions::{Distribution, Standard},
     Rng,
 };
-use std::io::{Read, Result as IoResult, Write};
 
 #[derive(Copy, Clone, Debug)]
 pub struct Projective<P: Parameters> {
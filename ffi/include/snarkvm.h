@@ -0,0 +1,92 @@
+/*
+ * Copyright (C) 2019-2023 Aleo Systems Inc.
+ * This file is part of the snarkVM library.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at:
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#ifndef SNARKVM_H
+#define SNARKVM_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The size of an account seed in bytes. */
+#define SNARKVM_SEED_SIZE 32
+
+/* The status code returned by every function. The values are stable. */
+typedef enum SnarkvmStatus {
+    SNARKVM_STATUS_OK = 0,
+    SNARKVM_STATUS_NULL_POINTER = 1,
+    SNARKVM_STATUS_INVALID_STRING = 2,
+    SNARKVM_STATUS_INVALID_ARGUMENT = 3,
+    SNARKVM_STATUS_DECRYPTION_FAILED = 4,
+    SNARKVM_STATUS_SIGNING_FAILED = 5,
+    SNARKVM_STATUS_PANIC = 6,
+} SnarkvmStatus;
+
+/* An account, as the string encodings of its private key, view key, and address. */
+/* The account must be released with `snarkvm_account_free`. */
+typedef struct SnarkvmAccount {
+    char *private_key;
+    char *view_key;
+    char *address;
+} SnarkvmAccount;
+
+/* Returns the message of the most recent error on the calling thread, or NULL. */
+/* The returned string must be released with `snarkvm_string_free`. */
+char *snarkvm_last_error_message(void);
+
+/* Releases a string returned by the library. Passing NULL is a no-op. */
+void snarkvm_string_free(char *string);
+
+/* Samples a new account from the operating system's randomness. */
+SnarkvmStatus snarkvm_account_new(SnarkvmAccount *out);
+
+/* Derives the account for the given `SNARKVM_SEED_SIZE`-byte seed. */
+SnarkvmStatus snarkvm_account_from_seed(const uint8_t *seed, size_t seed_len, SnarkvmAccount *out);
+
+/* Derives the account for the given private key. */
+SnarkvmStatus snarkvm_account_from_private_key(const char *private_key, SnarkvmAccount *out);
+
+/* Releases the strings of the given account, and resets its fields to NULL. Passing NULL is a no-op. */
+void snarkvm_account_free(SnarkvmAccount *account);
+
+/* Derives the address for the given view key. */
+SnarkvmStatus snarkvm_address_from_view_key(const char *view_key, char **out);
+
+/* Determines whether the given record ciphertext is owned by the given view key. */
+SnarkvmStatus snarkvm_record_is_owner(const char *view_key, const char *record, bool *out);
+
+/* Decrypts the given record ciphertext with the given view key, into a record plaintext. */
+SnarkvmStatus snarkvm_record_decrypt(const char *view_key, const char *record, char **out);
+
+/* Signs a request to call the given function with the given inputs, into a JSON string. */
+SnarkvmStatus snarkvm_request_sign(
+    const char *private_key,
+    const char *program_id,
+    const char *function_name,
+    const char *const *inputs,
+    const char *const *input_types,
+    size_t num_inputs,
+    char **out);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* SNARKVM_H */
@@ -0,0 +1,270 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::{
+    ffi_call,
+    string::{read_str, write_out, write_string},
+    CurrentNetwork,
+    Error,
+    SnarkvmStatus,
+};
+use snarkvm_console::{
+    account::{PrivateKey, ViewKey},
+    network::prelude::*,
+    types::Field,
+};
+
+use std::ffi::{c_char, CString};
+
+/// The size of an account seed in bytes.
+pub const SNARKVM_SEED_SIZE: usize = 32;
+
+/// An account, as the string encodings of its private key, view key, and address.
+/// The account must be released with `snarkvm_account_free`.
+#[repr(C)]
+#[derive(Debug)]
+pub struct SnarkvmAccount {
+    /// The private key, as an `APrivateKey1...` string.
+    pub private_key: *mut c_char,
+    /// The view key, as an `AViewKey1...` string.
+    pub view_key: *mut c_char,
+    /// The address, as an `aleo1...` string.
+    pub address: *mut c_char,
+}
+
+impl Default for SnarkvmAccount {
+    /// Returns an account with all fields set to null.
+    fn default() -> Self {
+        Self { private_key: core::ptr::null_mut(), view_key: core::ptr::null_mut(), address: core::ptr::null_mut() }
+    }
+}
+
+impl SnarkvmAccount {
+    /// Initializes the account for the given private key.
+    fn new(private_key: &PrivateKey<CurrentNetwork>) -> Result<Self, Error> {
+        let view_key = ViewKey::try_from(private_key)
+            .map_err(|error| Error::new(SnarkvmStatus::InvalidArgument, format!("Invalid private key: {error}")))?;
+        let address = view_key.to_address();
+        // Encode the strings before handing any of them over, so that none are leaked on failure.
+        let encode = |string: String| {
+            CString::new(string)
+                .map_err(|_| Error::new(SnarkvmStatus::InvalidString, "The account contains a NUL byte"))
+        };
+        let (private_key, view_key, address) =
+            (encode(private_key.to_string())?, encode(view_key.to_string())?, encode(address.to_string())?);
+        Ok(Self { private_key: private_key.into_raw(), view_key: view_key.into_raw(), address: address.into_raw() })
+    }
+}
+
+/// Writes the account for the given private key through the given out-pointer.
+///
+/// # Safety
+///
+/// The pointer must be null, or valid for writes of a `SnarkvmAccount`.
+unsafe fn write_account(out: *mut SnarkvmAccount, private_key: &PrivateKey<CurrentNetwork>) -> Result<(), Error> {
+    // Check the out-pointer first, so that the account is never leaked.
+    if out.is_null() {
+        return Err(Error::new(SnarkvmStatus::NullPointer, "An output argument is null"));
+    }
+    // SAFETY: The pointer was checked above, and the caller guarantees it is valid for writes.
+    unsafe { write_out(out, SnarkvmAccount::new(private_key)?) }
+}
+
+/// Samples a new account from the operating system's randomness, and writes it to `out`.
+///
+/// # Safety
+///
+/// `out` must be valid for writes of a `SnarkvmAccount`.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_account_new(out: *mut SnarkvmAccount) -> SnarkvmStatus {
+    ffi_call(|| {
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rand::thread_rng())
+            .map_err(|error| Error::new(SnarkvmStatus::InvalidArgument, error))?;
+        // SAFETY: The caller guarantees `out` is valid for writes.
+        unsafe { write_account(out, &private_key) }
+    })
+}
+
+/// Derives the account for the given `SNARKVM_SEED_SIZE`-byte seed, and writes it to `out`.
+/// The seed is interpreted as a little-endian integer, reduced modulo the field order.
+///
+/// # Safety
+///
+/// `seed` must be valid for reads of `seed_len` bytes, and `out` must be valid for writes of a `SnarkvmAccount`.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_account_from_seed(
+    seed: *const u8,
+    seed_len: usize,
+    out: *mut SnarkvmAccount,
+) -> SnarkvmStatus {
+    ffi_call(|| {
+        if seed.is_null() {
+            return Err(Error::new(SnarkvmStatus::NullPointer, "The seed is null"));
+        }
+        if seed_len != SNARKVM_SEED_SIZE {
+            return Err(Error::new(
+                SnarkvmStatus::InvalidArgument,
+                format!("The seed must be {SNARKVM_SEED_SIZE} bytes, found {seed_len} bytes"),
+            ));
+        }
+        // SAFETY: The caller guarantees `seed` is valid for reads of `seed_len` bytes.
+        let seed = unsafe { core::slice::from_raw_parts(seed, seed_len) };
+        let seed = Field::<CurrentNetwork>::new(<CurrentNetwork as Environment>::Field::from_bytes_le_mod_order(seed));
+        let private_key = PrivateKey::<CurrentNetwork>::try_from(seed)
+            .map_err(|error| Error::new(SnarkvmStatus::InvalidArgument, error))?;
+        // SAFETY: The caller guarantees `out` is valid for writes.
+        unsafe { write_account(out, &private_key) }
+    })
+}
+
+/// Derives the account for the given private key string, and writes it to `out`.
+///
+/// # Safety
+///
+/// `private_key` must be a NUL-terminated string, and `out` must be valid for writes of a `SnarkvmAccount`.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_account_from_private_key(
+    private_key: *const c_char,
+    out: *mut SnarkvmAccount,
+) -> SnarkvmStatus {
+    ffi_call(|| {
+        // SAFETY: The caller guarantees `private_key` is a NUL-terminated string.
+        let private_key = PrivateKey::<CurrentNetwork>::from_str(unsafe { read_str(private_key)? })
+            .map_err(|error| Error::new(SnarkvmStatus::InvalidArgument, format!("Invalid private key: {error}")))?;
+        // SAFETY: The caller guarantees `out` is valid for writes.
+        unsafe { write_account(out, &private_key) }
+    })
+}
+
+/// Releases the strings of the given account, and resets its fields to null. Passing null is a no-op.
+///
+/// # Safety
+///
+/// `account` must be null, or point to an account written by the C bindings.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_account_free(account: *mut SnarkvmAccount) {
+    // SAFETY: The caller guarantees `account` is null, or points to an account written by the C bindings.
+    if let Some(account) = unsafe { account.as_mut() } {
+        for string in [&mut account.private_key, &mut account.view_key, &mut account.address] {
+            // SAFETY: The string was allocated by the C bindings, and is reset to null below.
+            unsafe { crate::snarkvm_string_free(*string) };
+            *string = core::ptr::null_mut();
+        }
+    }
+}
+
+/// Derives the address for the given view key string, and writes it to `out`.
+/// The returned string must be released with `snarkvm_string_free`.
+///
+/// # Safety
+///
+/// `view_key` must be a NUL-terminated string, and `out` must be valid for writes of a string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_address_from_view_key(
+    view_key: *const c_char,
+    out: *mut *mut c_char,
+) -> SnarkvmStatus {
+    ffi_call(|| {
+        // SAFETY: The caller guarantees `view_key` is a NUL-terminated string.
+        let view_key = ViewKey::<CurrentNetwork>::from_str(unsafe { read_str(view_key)? })
+            .map_err(|error| Error::new(SnarkvmStatus::InvalidArgument, format!("Invalid view key: {error}")))?;
+        // SAFETY: The caller guarantees `out` is valid for writes.
+        unsafe { write_string(out, view_key.to_address().to_string()) }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snarkvm_string_free;
+    use snarkvm_console::account::Address;
+
+    use std::ffi::CStr;
+
+    /// Returns the string behind the given pointer.
+    fn to_string(string: *const c_char) -> String {
+        unsafe { CStr::from_ptr(string) }.to_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn test_account_new_and_from_private_key() {
+        let mut account = SnarkvmAccount::default();
+        assert_eq!(unsafe { snarkvm_account_new(&mut account) }, SnarkvmStatus::Ok);
+
+        // Ensure the account matches its private key.
+        let private_key = PrivateKey::<CurrentNetwork>::from_str(&to_string(account.private_key)).unwrap();
+        assert_eq!(to_string(account.view_key), ViewKey::try_from(&private_key).unwrap().to_string());
+        assert_eq!(to_string(account.address), Address::try_from(&private_key).unwrap().to_string());
+
+        // Ensure the account is recovered from its private key.
+        let mut recovered = SnarkvmAccount::default();
+        assert_eq!(unsafe { snarkvm_account_from_private_key(account.private_key, &mut recovered) }, SnarkvmStatus::Ok);
+        assert_eq!(to_string(recovered.address), to_string(account.address));
+
+        // Ensure the address is derived from the view key.
+        let mut address = core::ptr::null_mut();
+        assert_eq!(unsafe { snarkvm_address_from_view_key(account.view_key, &mut address) }, SnarkvmStatus::Ok);
+        assert_eq!(to_string(address), to_string(account.address));
+
+        unsafe {
+            snarkvm_string_free(address);
+            snarkvm_account_free(&mut account);
+            snarkvm_account_free(&mut recovered);
+        }
+        assert!(account.private_key.is_null() && account.view_key.is_null() && account.address.is_null());
+    }
+
+    #[test]
+    fn test_account_from_seed() {
+        let seed = [7u8; SNARKVM_SEED_SIZE];
+        let mut first = SnarkvmAccount::default();
+        let mut second = SnarkvmAccount::default();
+        assert_eq!(unsafe { snarkvm_account_from_seed(seed.as_ptr(), seed.len(), &mut first) }, SnarkvmStatus::Ok);
+        assert_eq!(unsafe { snarkvm_account_from_seed(seed.as_ptr(), seed.len(), &mut second) }, SnarkvmStatus::Ok);
+        // Ensure the derivation is deterministic.
+        assert_eq!(to_string(first.private_key), to_string(second.private_key));
+
+        // Ensure a seed of the wrong length is rejected.
+        let status = unsafe { snarkvm_account_from_seed(seed.as_ptr(), seed.len() - 1, &mut second) };
+        assert_eq!(status, SnarkvmStatus::InvalidArgument);
+
+        unsafe {
+            snarkvm_account_free(&mut first);
+            snarkvm_account_free(&mut second);
+        }
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        let mut account = SnarkvmAccount::default();
+        // Ensure null pointers are rejected.
+        assert_eq!(unsafe { snarkvm_account_new(core::ptr::null_mut()) }, SnarkvmStatus::NullPointer);
+        assert_eq!(
+            unsafe { snarkvm_account_from_private_key(core::ptr::null(), &mut account) },
+            SnarkvmStatus::NullPointer
+        );
+
+        // Ensure an invalid private key is rejected, with an error message.
+        let invalid = CString::new("APrivateKey1invalid").unwrap();
+        let status = unsafe { snarkvm_account_from_private_key(invalid.as_ptr(), &mut account) };
+        assert_eq!(status, SnarkvmStatus::InvalidArgument);
+        let message = crate::snarkvm_last_error_message();
+        assert!(to_string(message).starts_with("Invalid private key"));
+        unsafe { snarkvm_string_free(message) };
+
+        // Ensure the error message is cleared by a successful call.
+        assert_eq!(unsafe { snarkvm_account_new(&mut account) }, SnarkvmStatus::Ok);
+        assert!(crate::snarkvm_last_error_message().is_null());
+        unsafe { snarkvm_account_free(&mut account) };
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::string::into_c_string;
+
+use core::fmt::Display;
+use std::{
+    cell::RefCell,
+    ffi::c_char,
+    panic::{catch_unwind, UnwindSafe},
+};
+
+/// The status code returned by every function of the C bindings.
+///
+/// The discriminants are part of the ABI, and must never be reordered or reused.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SnarkvmStatus {
+    /// The call succeeded.
+    Ok = 0,
+    /// A required pointer argument was null.
+    NullPointer = 1,
+    /// A string argument was not valid UTF-8, or a returned string contained a NUL byte.
+    InvalidString = 2,
+    /// An argument could not be parsed, or was otherwise invalid.
+    InvalidArgument = 3,
+    /// The record could not be decrypted with the given view key.
+    DecryptionFailed = 4,
+    /// The request could not be signed.
+    SigningFailed = 5,
+    /// The call panicked, and was aborted.
+    Panic = 6,
+}
+
+thread_local! {
+    /// The message of the most recent error on this thread.
+    static LAST_ERROR: RefCell<Option<String>> = RefCell::new(None);
+}
+
+/// An error raised by the C bindings, with the status code to return to the caller.
+#[derive(Debug)]
+pub(crate) struct Error {
+    /// The status code.
+    status: SnarkvmStatus,
+    /// The error message.
+    message: String,
+}
+
+impl Error {
+    /// Initializes a new error with the given status code and message.
+    pub(crate) fn new(status: SnarkvmStatus, message: impl Display) -> Self {
+        Self { status, message: message.to_string() }
+    }
+}
+
+/// Runs the given closure, records its error message (if any), and returns its status code.
+/// Panics are caught, so that they never unwind across the C ABI.
+pub(crate) fn ffi_call(call: impl FnOnce() -> Result<(), Error> + UnwindSafe) -> SnarkvmStatus {
+    let (status, message) = match catch_unwind(call) {
+        Ok(Ok(())) => (SnarkvmStatus::Ok, None),
+        Ok(Err(error)) => (error.status, Some(error.message)),
+        Err(_) => (SnarkvmStatus::Panic, Some("The call panicked".to_string())),
+    };
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = message);
+    status
+}
+
+/// Returns the message of the most recent error on the calling thread, or null if the most recent call succeeded.
+/// The returned string must be released with `snarkvm_string_free`.
+#[no_mangle]
+pub extern "C" fn snarkvm_last_error_message() -> *mut c_char {
+    LAST_ERROR
+        .with(|last_error| last_error.borrow().clone())
+        .and_then(|message| into_c_string(message).ok())
+        .unwrap_or(core::ptr::null_mut())
+}
@@ -0,0 +1,147 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::{
+    ffi_call,
+    string::{read_str, write_out, write_string},
+    CurrentNetwork,
+    Error,
+    SnarkvmStatus,
+};
+use snarkvm_console::{
+    account::ViewKey,
+    network::prelude::*,
+    program::{Ciphertext, Record},
+};
+
+use std::ffi::c_char;
+
+/// Parses the given view key and record ciphertext strings.
+///
+/// # Safety
+///
+/// Both pointers must be NUL-terminated strings.
+unsafe fn read_view_key_and_record(
+    view_key: *const c_char,
+    record: *const c_char,
+) -> Result<(ViewKey<CurrentNetwork>, Record<CurrentNetwork, Ciphertext<CurrentNetwork>>), Error> {
+    // SAFETY: The caller guarantees both pointers are NUL-terminated strings.
+    let (view_key, record) = unsafe { (read_str(view_key)?, read_str(record)?) };
+    let view_key = ViewKey::from_str(view_key)
+        .map_err(|error| Error::new(SnarkvmStatus::InvalidArgument, format!("Invalid view key: {error}")))?;
+    let record = Record::from_str(record)
+        .map_err(|error| Error::new(SnarkvmStatus::InvalidArgument, format!("Invalid record: {error}")))?;
+    Ok((view_key, record))
+}
+
+/// Determines whether the given record ciphertext is owned by the given view key, and writes the result to `out`.
+///
+/// # Safety
+///
+/// `view_key` and `record` must be NUL-terminated strings, and `out` must be valid for writes of a `bool`.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_record_is_owner(
+    view_key: *const c_char,
+    record: *const c_char,
+    out: *mut bool,
+) -> SnarkvmStatus {
+    ffi_call(|| {
+        // SAFETY: The caller guarantees the arguments are NUL-terminated strings.
+        let (view_key, record) = unsafe { read_view_key_and_record(view_key, record)? };
+        // SAFETY: The caller guarantees `out` is valid for writes.
+        unsafe { write_out(out, record.is_owner(&view_key)) }
+    })
+}
+
+/// Decrypts the given record ciphertext with the given view key, and writes the record plaintext to `out`.
+/// The returned string must be released with `snarkvm_string_free`.
+///
+/// # Safety
+///
+/// `view_key` and `record` must be NUL-terminated strings, and `out` must be valid for writes of a string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_record_decrypt(
+    view_key: *const c_char,
+    record: *const c_char,
+    out: *mut *mut c_char,
+) -> SnarkvmStatus {
+    ffi_call(|| {
+        // SAFETY: The caller guarantees the arguments are NUL-terminated strings.
+        let (view_key, record) = unsafe { read_view_key_and_record(view_key, record)? };
+        let plaintext =
+            record.decrypt(&view_key).map_err(|error| Error::new(SnarkvmStatus::DecryptionFailed, error))?;
+        // SAFETY: The caller guarantees `out` is valid for writes.
+        unsafe { write_string(out, plaintext.to_string()) }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snarkvm_string_free;
+    use snarkvm_console::{
+        account::PrivateKey,
+        program::Plaintext,
+        types::{Group, Scalar},
+    };
+
+    use std::ffi::{CStr, CString};
+
+    #[test]
+    fn test_record_decrypt() {
+        let rng = &mut TestRng::default();
+
+        // Sample the owner and a stranger.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let stranger = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+        // Encrypt a record for the owner.
+        let randomizer = Scalar::rand(rng);
+        let nonce: Group<CurrentNetwork> = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let expected = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {}.private, amount: 100u64.private, _nonce: {nonce}.public }}",
+            view_key.to_address()
+        ))
+        .unwrap();
+        let ciphertext = CString::new(expected.encrypt(randomizer).unwrap().to_string()).unwrap();
+
+        let owner = CString::new(view_key.to_string()).unwrap();
+        let stranger = CString::new(stranger.to_string()).unwrap();
+
+        // Ensure ownership is detected.
+        let mut is_owner = false;
+        assert_eq!(
+            unsafe { snarkvm_record_is_owner(owner.as_ptr(), ciphertext.as_ptr(), &mut is_owner) },
+            SnarkvmStatus::Ok
+        );
+        assert!(is_owner);
+        let status = unsafe { snarkvm_record_is_owner(stranger.as_ptr(), ciphertext.as_ptr(), &mut is_owner) };
+        assert_eq!(status, SnarkvmStatus::Ok);
+        assert!(!is_owner);
+
+        // Ensure the owner decrypts the record.
+        let mut plaintext = core::ptr::null_mut();
+        assert_eq!(
+            unsafe { snarkvm_record_decrypt(owner.as_ptr(), ciphertext.as_ptr(), &mut plaintext) },
+            SnarkvmStatus::Ok
+        );
+        let candidate = unsafe { CStr::from_ptr(plaintext) }.to_str().unwrap();
+        assert_eq!(Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(candidate).unwrap(), expected);
+        unsafe { snarkvm_string_free(plaintext) };
+
+        // Ensure the stranger does not.
+        let status = unsafe { snarkvm_record_decrypt(stranger.as_ptr(), ciphertext.as_ptr(), &mut plaintext) };
+        assert_eq!(status, SnarkvmStatus::DecryptionFailed);
+    }
+}
@@ -0,0 +1,160 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::{
+    ffi_call,
+    string::{read_str, write_string},
+    CurrentNetwork,
+    Error,
+    SnarkvmStatus,
+};
+use snarkvm_console::{
+    account::PrivateKey,
+    network::prelude::*,
+    program::{Identifier, ProgramID, Request, ValueType},
+};
+
+use std::ffi::c_char;
+
+/// Returns the strings of the given array.
+///
+/// # Safety
+///
+/// `strings` must be valid for reads of `length` pointers, each of which must be a NUL-terminated string.
+unsafe fn read_strs<'a>(strings: *const *const c_char, length: usize) -> Result<Vec<&'a str>, Error> {
+    match length {
+        0 => Ok(Vec::new()),
+        _ if strings.is_null() => Err(Error::new(SnarkvmStatus::NullPointer, "A string array argument is null")),
+        // SAFETY: The caller guarantees `strings` is valid for reads of `length` string pointers.
+        _ => unsafe { core::slice::from_raw_parts(strings, length) }
+            .iter()
+            // SAFETY: The caller guarantees each pointer is a NUL-terminated string.
+            .map(|string| unsafe { read_str(*string) })
+            .collect(),
+    }
+}
+
+/// Signs a request to call the given function with the given inputs, and writes the request to `out` as a JSON string.
+/// The inputs and input types are given as two arrays of `num_inputs` strings, e.g. `"1u64"` and `"u64.private"`.
+/// The returned string must be released with `snarkvm_string_free`.
+///
+/// # Safety
+///
+/// `private_key`, `program_id`, and `function_name` must be NUL-terminated strings,
+/// `inputs` and `input_types` must each be valid for reads of `num_inputs` NUL-terminated strings,
+/// and `out` must be valid for writes of a string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_request_sign(
+    private_key: *const c_char,
+    program_id: *const c_char,
+    function_name: *const c_char,
+    inputs: *const *const c_char,
+    input_types: *const *const c_char,
+    num_inputs: usize,
+    out: *mut *mut c_char,
+) -> SnarkvmStatus {
+    ffi_call(|| {
+        let invalid = |name: &'static str| {
+            move |error: anyhow::Error| Error::new(SnarkvmStatus::InvalidArgument, format!("Invalid {name}: {error}"))
+        };
+        // SAFETY: The caller guarantees the arguments are NUL-terminated strings.
+        let (private_key, program_id, function_name) =
+            unsafe { (read_str(private_key)?, read_str(program_id)?, read_str(function_name)?) };
+        let private_key = PrivateKey::<CurrentNetwork>::from_str(private_key).map_err(invalid("private key"))?;
+        let program_id = ProgramID::<CurrentNetwork>::from_str(program_id).map_err(invalid("program ID"))?;
+        let function_name = Identifier::<CurrentNetwork>::from_str(function_name).map_err(invalid("function name"))?;
+
+        // SAFETY: The caller guarantees both arrays hold `num_inputs` NUL-terminated strings.
+        let (inputs, input_types) = unsafe { (read_strs(inputs, num_inputs)?, read_strs(input_types, num_inputs)?) };
+        let input_types = input_types
+            .into_iter()
+            .map(ValueType::<CurrentNetwork>::from_str)
+            .collect::<Result<Vec<_>>>()
+            .map_err(invalid("input type"))?;
+
+        // Sign the request.
+        let request = Request::sign(
+            &private_key,
+            program_id,
+            function_name,
+            inputs.into_iter(),
+            &input_types,
+            &mut rand::thread_rng(),
+        )
+        .map_err(|error| Error::new(SnarkvmStatus::SigningFailed, error))?;
+        // SAFETY: The caller guarantees `out` is valid for writes.
+        unsafe { write_string(out, request.to_string()) }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snarkvm_string_free;
+
+    use std::ffi::{CStr, CString};
+
+    #[test]
+    fn test_request_sign() {
+        let rng = &mut TestRng::default();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let recipient = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let recipient = snarkvm_console::account::Address::try_from(&recipient).unwrap();
+
+        let to_c = |string: &str| CString::new(string).unwrap();
+        let (private_key, program_id, function_name) =
+            (to_c(&private_key.to_string()), to_c("credits.aleo"), to_c("transfer_public"));
+        let inputs = [to_c(&recipient.to_string()), to_c("1u64")];
+        let input_types = [to_c("address.public"), to_c("u64.public")];
+        let input_ptrs = inputs.iter().map(|input| input.as_ptr()).collect::<Vec<_>>();
+        let input_type_ptrs = input_types.iter().map(|input_type| input_type.as_ptr()).collect::<Vec<_>>();
+
+        // Sign the request.
+        let mut request = core::ptr::null_mut();
+        let status = unsafe {
+            snarkvm_request_sign(
+                private_key.as_ptr(),
+                program_id.as_ptr(),
+                function_name.as_ptr(),
+                input_ptrs.as_ptr(),
+                input_type_ptrs.as_ptr(),
+                inputs.len(),
+                &mut request,
+            )
+        };
+        assert_eq!(status, SnarkvmStatus::Ok);
+
+        // Ensure the request verifies.
+        let candidate =
+            Request::<CurrentNetwork>::from_str(unsafe { CStr::from_ptr(request) }.to_str().unwrap()).unwrap();
+        let input_types = [ValueType::from_str("address.public").unwrap(), ValueType::from_str("u64.public").unwrap()];
+        assert!(candidate.verify(&input_types));
+        unsafe { snarkvm_string_free(request) };
+
+        // Ensure an invalid program ID is rejected.
+        let invalid_program_id = to_c("credits");
+        let status = unsafe {
+            snarkvm_request_sign(
+                private_key.as_ptr(),
+                invalid_program_id.as_ptr(),
+                function_name.as_ptr(),
+                input_ptrs.as_ptr(),
+                input_type_ptrs.as_ptr(),
+                inputs.len(),
+                &mut request,
+            )
+        };
+        assert_eq!(status, SnarkvmStatus::InvalidArgument);
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::{Error, SnarkvmStatus};
+
+use std::ffi::{c_char, CStr, CString};
+
+/// Releases a string returned by the C bindings. Passing null is a no-op.
+///
+/// # Safety
+///
+/// The string must have been returned by the C bindings, and must not be used after this call.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_string_free(string: *mut c_char) {
+    if !string.is_null() {
+        // SAFETY: The caller guarantees the string was allocated by `into_c_string`.
+        drop(unsafe { CString::from_raw(string) });
+    }
+}
+
+/// Returns the UTF-8 string behind the given pointer.
+///
+/// # Safety
+///
+/// The pointer must be null, or point to a NUL-terminated string that outlives `'a`.
+pub(crate) unsafe fn read_str<'a>(string: *const c_char) -> Result<&'a str, Error> {
+    if string.is_null() {
+        return Err(Error::new(SnarkvmStatus::NullPointer, "A string argument is null"));
+    }
+    // SAFETY: The caller guarantees the pointer is a valid NUL-terminated string.
+    unsafe { CStr::from_ptr(string) }
+        .to_str()
+        .map_err(|error| Error::new(SnarkvmStatus::InvalidString, format!("A string argument is not UTF-8: {error}")))
+}
+
+/// Returns the given string as an owned C string, to be released with `snarkvm_string_free`.
+pub(crate) fn into_c_string(string: impl Into<Vec<u8>>) -> Result<*mut c_char, Error> {
+    CString::new(string)
+        .map(CString::into_raw)
+        .map_err(|_| Error::new(SnarkvmStatus::InvalidString, "The returned string contains a NUL byte"))
+}
+
+/// Writes the given value through the given out-pointer.
+///
+/// # Safety
+///
+/// The pointer must be null, or valid for writes of `T`.
+pub(crate) unsafe fn write_out<T>(out: *mut T, value: T) -> Result<(), Error> {
+    if out.is_null() {
+        return Err(Error::new(SnarkvmStatus::NullPointer, "An output argument is null"));
+    }
+    // SAFETY: The caller guarantees the pointer is valid for writes.
+    unsafe { out.write(value) };
+    Ok(())
+}
+
+/// Writes the given string through the given out-pointer, as an owned C string to be released with `snarkvm_string_free`.
+///
+/// # Safety
+///
+/// The pointer must be null, or valid for writes of a string pointer.
+pub(crate) unsafe fn write_string(out: *mut *mut c_char, string: impl Into<Vec<u8>>) -> Result<(), Error> {
+    // Check the out-pointer first, so that the string is never leaked.
+    if out.is_null() {
+        return Err(Error::new(SnarkvmStatus::NullPointer, "An output argument is null"));
+    }
+    // SAFETY: The pointer was checked above, and the caller guarantees it is valid for writes.
+    unsafe { write_out(out, into_c_string(string)?) }
+}
@@ -12,20 +12,35 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-#[derive(Debug, Error)]
-pub enum ConstraintFieldError {
-    #[error("{}", _0)]
-    AnyhowError(#[from] anyhow::Error),
+use snarkvm_utilities::{io, string::String};
 
-    #[error("{}: {}", _0, _1)]
+#[derive(Debug)]
+pub enum ConstraintFieldError {
+    AnyhowError(anyhow::Error),
     Crate(&'static str, String),
-
-    #[error("{}", _0)]
     Message(&'static str),
 }
 
-impl From<std::io::Error> for ConstraintFieldError {
-    fn from(error: std::io::Error) -> Self {
+impl core::fmt::Display for ConstraintFieldError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::AnyhowError(error) => write!(f, "{error}"),
+            Self::Crate(name, message) => write!(f, "{name}: {message}"),
+            Self::Message(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl snarkvm_utilities::error::Error for ConstraintFieldError {}
+
+impl From<anyhow::Error> for ConstraintFieldError {
+    fn from(error: anyhow::Error) -> Self {
+        ConstraintFieldError::AnyhowError(error)
+    }
+}
+
+impl From<io::Error> for ConstraintFieldError {
+    fn from(error: io::Error) -> Self {
         ConstraintFieldError::Crate("std::io", format!("{error:?}"))
     }
 }
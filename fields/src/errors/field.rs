@@ -12,35 +12,43 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-#[derive(Debug, Error)]
+use snarkvm_utilities::{io, string::String};
+
+#[derive(Debug)]
 pub enum FieldError {
-    #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
-
-    #[error("Invalid field element")]
     InvalidFieldElement,
-
-    #[error("Attempting to parse an invalid string into a field element")]
     InvalidString,
-
-    #[error("{}", _0)]
     Message(String),
-
-    #[error("Attempting to parse an empty string into a field element")]
     ParsingEmptyString,
-
-    #[error("Attempting to parse a non-digit character into a field element")]
     ParsingNonDigitCharacter,
 }
 
-impl From<std::io::Error> for FieldError {
-    fn from(error: std::io::Error) -> Self {
+impl core::fmt::Display for FieldError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Crate(name, message) => write!(f, "{name}: {message}"),
+            Self::InvalidFieldElement => write!(f, "Invalid field element"),
+            Self::InvalidString => write!(f, "Attempting to parse an invalid string into a field element"),
+            Self::Message(message) => write!(f, "{message}"),
+            Self::ParsingEmptyString => write!(f, "Attempting to parse an empty string into a field element"),
+            Self::ParsingNonDigitCharacter => {
+                write!(f, "Attempting to parse a non-digit character into a field element")
+            }
+        }
+    }
+}
+
+impl snarkvm_utilities::error::Error for FieldError {}
+
+impl From<io::Error> for FieldError {
+    fn from(error: io::Error) -> Self {
         FieldError::Crate("std::io", format!("{error:?}"))
     }
 }
 
-impl From<FieldError> for std::io::Error {
+impl From<FieldError> for io::Error {
     fn from(error: FieldError) -> Self {
-        std::io::Error::new(std::io::ErrorKind::Other, format!("{error}"))
+        snarkvm_utilities::error(format!("{error}"))
     }
 }
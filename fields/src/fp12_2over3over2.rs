@@ -13,18 +13,25 @@
 // limitations under the License.
 
 use crate::{fp6_3over2::*, Field, Fp2, Fp2Parameters, One, Zero};
-use snarkvm_utilities::{bititerator::BitIteratorBE, rand::Uniform, serialize::*, FromBytes, ToBits, ToBytes};
+use snarkvm_utilities::{
+    bititerator::BitIteratorBE,
+    io::{Read, Result as IoResult, Write},
+    rand::Uniform,
+    serialize::*,
+    FromBytes,
+    ToBits,
+    ToBytes,
+};
 
+use core::{
+    cmp::Ordering,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
 use rand::{
     distributions::{Distribution, Standard},
     Rng,
 };
 use serde::{Deserialize, Serialize};
-use std::{
-    cmp::Ordering,
-    io::{Read, Result as IoResult, Write},
-    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
-};
 
 pub trait Fp12Parameters: 'static + Send + Sync + Copy {
     type Fp6Params: Fp6Parameters;
@@ -197,8 +204,8 @@ impl<P: Fp12Parameters> Fp12<P> {
     }
 }
 
-impl<P: Fp12Parameters> std::fmt::Display for Fp12<P> {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+impl<P: Fp12Parameters> core::fmt::Display for Fp12<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "Fp12({} + {} * w)", self.c0, self.c1)
     }
 }
@@ -14,6 +14,7 @@
 
 use crate::{Field, LegendreSymbol, One, PrimeField, SquareRootField, Zero};
 use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
     rand::Uniform,
     serialize::{SerializationError, *},
     FromBytes,
@@ -21,16 +22,15 @@ use snarkvm_utilities::{
     ToBytes,
 };
 
+use core::{
+    cmp::{Ord, Ordering, PartialOrd},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
 use rand::{
     distributions::{Distribution, Standard},
     Rng,
 };
 use serde::{Deserialize, Serialize};
-use std::{
-    cmp::{Ord, Ordering, PartialOrd},
-    io::{Read, Result as IoResult, Write},
-    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
-};
 
 pub trait Fp2Parameters: 'static + Send + Sync + Serialize + for<'a> Deserialize<'a> {
     type Fp: PrimeField;
@@ -417,8 +417,8 @@ impl<'a, P: Fp2Parameters> DivAssign<&'a Self> for Fp2<P> {
     }
 }
 
-impl<P: Fp2Parameters> std::fmt::Display for Fp2<P> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<P: Fp2Parameters> core::fmt::Display for Fp2<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "Fp2({} + {} * u)", self.c0, self.c1)
     }
 }
@@ -14,6 +14,7 @@
 
 use crate::{Field, Fp2, Fp2Parameters, One, Zero};
 use snarkvm_utilities::{
+    io::{Read, Result as IoResult, Write},
     rand::Uniform,
     serialize::{SerializationError, *},
     FromBytes,
@@ -21,16 +22,15 @@ use snarkvm_utilities::{
     ToBytes,
 };
 
+use core::{
+    cmp::Ordering,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
 use rand::{
     distributions::{Distribution, Standard},
     Rng,
 };
 use serde::{Deserialize, Serialize};
-use std::{
-    cmp::Ordering,
-    io::{Read, Result as IoResult, Write},
-    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
-};
 
 pub trait Fp6Parameters: 'static + Send + Sync + Copy {
     type Fp2Params: Fp2Parameters;
@@ -299,8 +299,8 @@ impl<P: Fp6Parameters> Field for Fp6<P> {
     }
 }
 
-impl<P: Fp6Parameters> std::fmt::Display for Fp6<P> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<P: Fp6Parameters> core::fmt::Display for Fp6<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "Fq6_3over2({} + {} * v, {} * v^2)", self.c0, self.c1, self.c2)
     }
 }
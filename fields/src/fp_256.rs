@@ -29,16 +29,17 @@ use crate::{
 };
 use snarkvm_utilities::{
     biginteger::{arithmetic as fa, BigInteger as _BigInteger, BigInteger256 as BigInteger},
+    io::{Read, Result as IoResult, Write},
     serialize::CanonicalDeserialize,
     FromBytes,
     ToBits,
     ToBytes,
+    Vec,
 };
 
-use std::{
+use core::{
     cmp::{Ord, Ordering, PartialOrd},
     fmt::{Debug, Display, Formatter, Result as FmtResult},
-    io::{Read, Result as IoResult, Write},
     marker::PhantomData,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     str::FromStr,
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::fmt::Debug;
+use core::fmt::Debug;
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum LegendreSymbol {
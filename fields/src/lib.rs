@@ -12,14 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::module_inception)]
 #![forbid(unsafe_code)]
 
+#[cfg(not(feature = "std"))]
 #[macro_use]
-extern crate derivative;
+extern crate alloc;
 
 #[macro_use]
-extern crate thiserror;
+extern crate derivative;
 
 #[macro_use]
 mod macros;
@@ -55,6 +57,7 @@ use snarkvm_utilities::{
     serialize::{CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize, CanonicalSerializeWithFlags},
     FromBytes,
     ToBytes,
+    Vec,
 };
 
 impl_field_to_biginteger!(Fp256, BigInteger256, Fp256Parameters);
@@ -15,7 +15,7 @@
 #[macro_export]
 macro_rules! field {
     ($name:ident, $c0:expr) => {
-        $name { 0: $c0, 1: std::marker::PhantomData }
+        $name { 0: $c0, 1: core::marker::PhantomData }
     };
     ($name:ident, $c0:expr, $c1:expr $(,)?) => {
         $name { c0: $c0, c1: $c1 }
@@ -25,6 +25,21 @@ macro_rules! field {
     };
 }
 
+/// Without `std`, the timers are unavailable, so the timer macros do nothing.
+#[cfg(not(feature = "std"))]
+macro_rules! start_timer {
+    ($($arg:tt)*) => {
+        ()
+    };
+}
+
+#[cfg(not(feature = "std"))]
+macro_rules! end_timer {
+    ($timer:expr) => {
+        let _ = $timer;
+    };
+}
+
 macro_rules! impl_field_to_biginteger {
     ($field: ident, $biginteger: ident, $parameters: ident) => {
         #[allow(clippy::from_over_into)]
@@ -44,7 +59,7 @@ macro_rules! impl_primefield_standard_sample {
                 loop {
                     let mut tmp = $field(rng.sample(rand::distributions::Standard), PhantomData);
                     // Mask away the unused bits at the beginning.
-                    tmp.0.as_mut().last_mut().map(|val| *val &= std::u64::MAX >> P::REPR_SHAVE_BITS);
+                    tmp.0.as_mut().last_mut().map(|val| *val &= u64::MAX >> P::REPR_SHAVE_BITS);
 
                     if tmp.is_valid() {
                         return tmp;
@@ -94,7 +109,11 @@ macro_rules! sqrt_impl {
                 let v = $self.pow($P::T_MINUS_ONE_DIV_TWO);
                 let x = *$self * v.square();
 
-                let k = ((n - 1) as f64).sqrt().floor() as u64;
+                // `k` is the integer square root of `n - 1`, computed without floating-point arithmetic.
+                let mut k = 0u64;
+                while (k + 1) * (k + 1) < n {
+                    k += 1;
+                }
                 // It's important that k_2 results in a number which makes `l_minus_one_times_k`
                 // divisible by `k`, because the native arithmetic will not match the field
                 // arithmetic otherwise (native numbers will divide and round down, but field
@@ -323,16 +342,16 @@ macro_rules! impl_primefield_serializer {
             fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
                 match deserializer.is_human_readable() {
                     true => {
-                        let s: String = serde::Deserialize::deserialize(deserializer)?;
+                        let s: snarkvm_utilities::string::String = serde::Deserialize::deserialize(deserializer)?;
                         core::str::FromStr::from_str(&s).map_err(serde::de::Error::custom)
                     }
                     false => {
-                        struct SerVisitor<P>(std::marker::PhantomData<P>);
+                        struct SerVisitor<P>(core::marker::PhantomData<P>);
 
                         impl<'de, P: $params> serde::de::Visitor<'de> for SerVisitor<P> {
                             type Value = $field<P>;
 
-                            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
                                 formatter.write_str("a valid field element")
                             }
 
@@ -352,7 +371,7 @@ macro_rules! impl_primefield_serializer {
                             }
                         }
 
-                        let visitor = SerVisitor(std::marker::PhantomData);
+                        let visitor = SerVisitor(core::marker::PhantomData);
                         deserializer.deserialize_tuple(Self::SERIALIZED_SIZE, visitor)
                     }
                 }
@@ -29,7 +29,7 @@ use snarkvm_utilities::{
     ToBytes,
 };
 
-use std::{
+use core::{
     fmt::{Debug, Display},
     hash::Hash,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
@@ -13,8 +13,10 @@
 // limitations under the License.
 
 use crate::{serial_batch_inversion_and_mul, PoseidonGrainLFSR, PrimeField};
+#[cfg(feature = "std")]
 use aleo_std::{end_timer, start_timer};
 use itertools::Itertools;
+use snarkvm_utilities::Vec;
 
 use anyhow::{bail, Result};
 
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::{ConstraintFieldError, Field};
+use snarkvm_utilities::Vec;
 
 use core::fmt::Debug;
 
@@ -65,15 +65,14 @@ impl<N: Network> Header<N> {
 
         // Construct the Merkle leaves.
         let mut leaves: Vec<Vec<bool>> = Vec::with_capacity(num_leaves);
-        leaves.push(HeaderLeaf::<N>::new(0, *self.previous_state_root).to_bits_le());
-        leaves.push(HeaderLeaf::<N>::new(1, self.transactions_root).to_bits_le());
-        leaves.push(HeaderLeaf::<N>::new(2, self.finalize_root).to_bits_le());
-        leaves.push(HeaderLeaf::<N>::new(3, self.ratifications_root).to_bits_le());
-        leaves.push(HeaderLeaf::<N>::new(4, self.solutions_root).to_bits_le());
-        for i in 5..7 {
-            leaves.push(HeaderLeaf::<N>::new(i, Field::zero()).to_bits_le());
+        for index in 0..num_leaves as u8 {
+            // Note: The leaves that are not a header field are zero.
+            let leaf = match HeaderField::from_index(index) {
+                Ok(field) => self.to_field_leaf(field)?,
+                Err(_) => HeaderLeaf::<N>::new(index, Field::zero()),
+            };
+            leaves.push(leaf.to_bits_le());
         }
-        leaves.push(HeaderLeaf::<N>::new(7, self.metadata.to_hash()?).to_bits_le());
 
         // Ensure the correct number of leaves are allocated.
         ensure!(num_leaves == leaves.len(), "Incorrect number of leaves in the Merkle tree for the block header");
@@ -18,6 +18,8 @@ pub use metadata::*;
 mod bytes;
 mod genesis;
 mod merkle;
+mod proof;
+pub use proof::*;
 mod serialize;
 mod string;
 mod verify;
@@ -0,0 +1,253 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A field of the block header that is committed to as a leaf in the header Merkle tree.
+///
+/// The layout of the header tree is fixed: each field is always the leaf at `index`,
+/// and the unused leaves (5 and 6) are zero. The metadata is committed to by its hash,
+/// so a metadata value (e.g. the height) is proven by revealing the metadata alongside the path.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HeaderField {
+    PreviousStateRoot,
+    TransactionsRoot,
+    FinalizeRoot,
+    RatificationsRoot,
+    SolutionsRoot,
+    Metadata,
+}
+
+impl HeaderField {
+    /// The header fields, in the order of their leaves.
+    pub const ALL: [Self; 6] = [
+        Self::PreviousStateRoot,
+        Self::TransactionsRoot,
+        Self::FinalizeRoot,
+        Self::RatificationsRoot,
+        Self::SolutionsRoot,
+        Self::Metadata,
+    ];
+
+    /// Returns the index of the leaf for this field in the header tree.
+    pub const fn index(&self) -> u8 {
+        match self {
+            Self::PreviousStateRoot => 0,
+            Self::TransactionsRoot => 1,
+            Self::FinalizeRoot => 2,
+            Self::RatificationsRoot => 3,
+            Self::SolutionsRoot => 4,
+            Self::Metadata => 7,
+        }
+    }
+
+    /// Returns the header field for the given leaf index.
+    pub fn from_index(index: u8) -> Result<Self> {
+        match Self::ALL.into_iter().find(|field| field.index() == index) {
+            Some(field) => Ok(field),
+            None => bail!("Leaf {index} of the block header is not a header field"),
+        }
+    }
+}
+
+/// A proof that a single field is committed to in a block header, without the rest of the header.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HeaderFieldProof<N: Network> {
+    /// The leaf for the field.
+    leaf: HeaderLeaf<N>,
+    /// The Merkle path from the leaf to the header root.
+    path: HeaderPath<N>,
+    /// The metadata, if the field is the metadata.
+    metadata: Option<Metadata<N>>,
+}
+
+impl<N: Network> Header<N> {
+    /// Returns the Merkle leaf for the given field in the header.
+    pub fn to_field_leaf(&self, field: HeaderField) -> Result<HeaderLeaf<N>> {
+        let id = match field {
+            HeaderField::PreviousStateRoot => *self.previous_state_root,
+            HeaderField::TransactionsRoot => self.transactions_root,
+            HeaderField::FinalizeRoot => self.finalize_root,
+            HeaderField::RatificationsRoot => self.ratifications_root,
+            HeaderField::SolutionsRoot => self.solutions_root,
+            HeaderField::Metadata => self.metadata.to_hash()?,
+        };
+        Ok(HeaderLeaf::new(field.index(), id))
+    }
+
+    /// Returns a proof that the given field is committed to in the header root.
+    pub fn prove_field(&self, field: HeaderField) -> Result<HeaderFieldProof<N>> {
+        let leaf = self.to_field_leaf(field)?;
+        let path = self.to_path(&leaf)?;
+        let metadata = (field == HeaderField::Metadata).then_some(self.metadata);
+        Ok(HeaderFieldProof { leaf, path, metadata })
+    }
+}
+
+impl<N: Network> HeaderFieldProof<N> {
+    /// Returns the header field being proven.
+    pub fn field(&self) -> Result<HeaderField> {
+        HeaderField::from_index(self.leaf.index())
+    }
+
+    /// Returns the proven value of the field. For the metadata, this is the metadata hash.
+    pub const fn value(&self) -> Field<N> {
+        self.leaf.id()
+    }
+
+    /// Returns the proven metadata, if the field is the metadata.
+    pub const fn metadata(&self) -> Option<&Metadata<N>> {
+        self.metadata.as_ref()
+    }
+
+    /// Returns the Merkle leaf.
+    pub const fn leaf(&self) -> &HeaderLeaf<N> {
+        &self.leaf
+    }
+
+    /// Returns the Merkle path.
+    pub const fn path(&self) -> &HeaderPath<N> {
+        &self.path
+    }
+
+    /// Returns `true` if the field is committed to in the given header root.
+    pub fn verify(&self, header_root: &Field<N>) -> bool {
+        // Ensure the leaf is a header field.
+        let Ok(field) = self.field() else {
+            return false;
+        };
+        // Ensure the metadata is revealed only for the metadata leaf, and matches its hash.
+        match (field, &self.metadata) {
+            (HeaderField::Metadata, Some(metadata)) => match metadata.to_hash() {
+                Ok(hash) if hash == self.leaf.id() => (),
+                _ => return false,
+            },
+            (HeaderField::Metadata, None) => (),
+            (_, Some(_)) => return false,
+            (_, None) => (),
+        }
+        // Verify the Merkle path.
+        N::verify_merkle_path_bhp(&self.path, header_root, &self.leaf.to_bits_le())
+    }
+
+    /// Returns `true` if the field is committed to in the header of the block with the given hash.
+    pub fn verify_block_hash(
+        &self,
+        previous_hash: &N::BlockHash,
+        header_root: &Field<N>,
+        block_hash: &N::BlockHash,
+    ) -> bool {
+        match N::hash_bhp1024(&to_bits_le![previous_hash, header_root]) {
+            Ok(candidate_hash) => candidate_hash == **block_hash && self.verify(header_root),
+            Err(_) => false,
+        }
+    }
+}
+
+impl<N: Network> FromBytes for HeaderFieldProof<N> {
+    /// Reads the header field proof from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid header field proof version"));
+        }
+        // Read the leaf and path.
+        let leaf = HeaderLeaf::read_le(&mut reader)?;
+        let path = HeaderPath::read_le(&mut reader)?;
+        // Read the metadata.
+        let metadata = match u8::read_le(&mut reader)? {
+            0 => None,
+            1 => Some(Metadata::read_le(&mut reader)?),
+            variant => return Err(error(format!("Invalid metadata variant '{variant}'"))),
+        };
+        Ok(Self { leaf, path, metadata })
+    }
+}
+
+impl<N: Network> ToBytes for HeaderFieldProof<N> {
+    /// Writes the header field proof to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the leaf and path.
+        self.leaf.write_le(&mut writer)?;
+        self.path.write_le(&mut writer)?;
+        // Write the metadata.
+        match &self.metadata {
+            None => 0u8.write_le(&mut writer),
+            Some(metadata) => {
+                1u8.write_le(&mut writer)?;
+                metadata.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_prove_field() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let header = crate::header::test_helpers::sample_block_header(rng);
+        let root = header.to_root()?;
+
+        for field in HeaderField::ALL {
+            let proof = header.prove_field(field)?;
+            assert_eq!(proof.field()?, field);
+            assert!(proof.verify(&root));
+            assert!(!proof.verify(&Field::rand(rng)));
+
+            // Check serialization.
+            let bytes = proof.to_bytes_le()?;
+            assert_eq!(proof, HeaderFieldProof::read_le(&bytes[..])?);
+        }
+
+        // Ensure the metadata values are revealed.
+        let proof = header.prove_field(HeaderField::FinalizeRoot)?;
+        assert_eq!(proof.value(), header.finalize_root());
+        assert!(proof.metadata().is_none());
+        let proof = header.prove_field(HeaderField::Metadata)?;
+        assert_eq!(proof.metadata().map(|metadata| metadata.height()), Some(header.height()));
+
+        // Ensure metadata that does not match the leaf is rejected.
+        let mut tampered = proof.clone();
+        tampered.leaf = HeaderLeaf::new(HeaderField::Metadata.index(), Field::rand(rng));
+        assert!(!tampered.verify(&root));
+        // Ensure metadata is rejected for any other field.
+        let mut tampered = header.prove_field(HeaderField::FinalizeRoot)?;
+        tampered.metadata = Some(*header.metadata());
+        assert!(!tampered.verify(&root));
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_block_hash() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let block = crate::test_helpers::sample_genesis_block(rng);
+        let root = block.header().to_root()?;
+        let proof = block.header().prove_field(HeaderField::Metadata)?;
+        assert!(proof.verify_block_hash(&block.previous_hash(), &root, &block.hash()));
+        assert!(!proof.verify_block_hash(&block.hash(), &root, &block.hash()));
+        Ok(())
+    }
+}
@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Block, ConfirmedTransaction, Header, Transactions};
+use console::network::prelude::*;
+use ledger_authority::Authority;
+
+use core::marker::PhantomData;
+
+/// A borrowed view of a serialized block, which decodes its fields on demand.
+///
+/// The block hash, previous block hash, and header are at the front of the encoding,
+/// so they are decoded without decoding the authority or the transactions.
+/// Note that, unlike `Block::read_le`, the block hash is not checked against the contents.
+pub struct BlockBytes<'a, N: Network> {
+    /// The bytes of the block, after the version.
+    bytes: &'a [u8],
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+impl<'a, N: Network> BlockBytes<'a, N> {
+    /// Initializes a view of the given block bytes.
+    pub fn new(bytes: &'a [u8]) -> Result<Self> {
+        // Read the version.
+        let (version, bytes) = u8::from_bytes_le_prefix(bytes)?;
+        // Ensure the version is valid.
+        ensure!(version == 0, "Invalid block version");
+        Ok(Self { bytes, _phantom: PhantomData })
+    }
+
+    /// Returns the block hash.
+    pub fn hash(&self) -> Result<N::BlockHash> {
+        Ok(N::BlockHash::from_bytes_le_prefix(self.bytes)?.0)
+    }
+
+    /// Returns the previous block hash.
+    pub fn previous_hash(&self) -> Result<N::BlockHash> {
+        let (_, bytes) = N::BlockHash::from_bytes_le_prefix(self.bytes)?;
+        Ok(N::BlockHash::from_bytes_le_prefix(bytes)?.0)
+    }
+
+    /// Returns the block header.
+    pub fn header(&self) -> Result<Header<N>> {
+        Ok(self.read_header()?.0)
+    }
+
+    /// Returns the block height.
+    pub fn height(&self) -> Result<u32> {
+        Ok(self.header()?.height())
+    }
+
+    /// Returns the block authority.
+    pub fn authority(&self) -> Result<Authority<N>> {
+        let (_, bytes) = self.read_header()?;
+        Ok(Authority::from_bytes_le_prefix(bytes)?.0)
+    }
+
+    /// Returns a view of the transactions in the block.
+    pub fn transactions(&self) -> Result<TransactionsBytes<'a, N>> {
+        let (_, bytes) = self.read_header()?;
+        let (_, bytes) = Authority::<N>::from_bytes_le_prefix(bytes)?;
+        TransactionsBytes::new(bytes)
+    }
+
+    /// Decodes the full block, and ensures the block hash matches its contents.
+    pub fn to_block(&self) -> Result<Block<N>> {
+        // Prepend the version, and read the block.
+        Ok(Block::read_le([0u8].as_slice().chain(self.bytes))?)
+    }
+
+    /// Returns the block header, and the bytes after it.
+    fn read_header(&self) -> Result<(Header<N>, &'a [u8])> {
+        let (_, bytes) = N::BlockHash::from_bytes_le_prefix(self.bytes)?;
+        let (_, bytes) = N::BlockHash::from_bytes_le_prefix(bytes)?;
+        Header::<N>::from_bytes_le_prefix(bytes)
+    }
+}
+
+/// A borrowed view of serialized transactions, which decodes one transaction at a time.
+pub struct TransactionsBytes<'a, N: Network> {
+    /// The number of transactions.
+    num_transactions: u32,
+    /// The bytes of the transactions, after the number of transactions.
+    bytes: &'a [u8],
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+impl<'a, N: Network> TransactionsBytes<'a, N> {
+    /// Initializes a view of the given transactions bytes.
+    /// The bytes may extend past the end of the transactions.
+    pub fn new(bytes: &'a [u8]) -> Result<Self> {
+        // Read the version.
+        let (version, bytes) = u8::from_bytes_le_prefix(bytes)?;
+        // Ensure the version is valid.
+        ensure!(version == 0, "Invalid transactions version");
+        // Read the number of transactions.
+        let (num_transactions, bytes) = u32::from_bytes_le_prefix(bytes)?;
+        Ok(Self { num_transactions, bytes, _phantom: PhantomData })
+    }
+
+    /// Returns the number of transactions.
+    pub const fn len(&self) -> usize {
+        self.num_transactions as usize
+    }
+
+    /// Returns `true` if there are no transactions.
+    pub const fn is_empty(&self) -> bool {
+        self.num_transactions == 0
+    }
+
+    /// Returns an iterator over the transactions, decoding each transaction as it is reached.
+    pub fn iter(&self) -> impl '_ + Iterator<Item = Result<ConfirmedTransaction<N>>> {
+        let mut bytes = self.bytes;
+        (0..self.num_transactions).map(move |_| {
+            let (transaction, remaining) = ConfirmedTransaction::from_bytes_le_prefix(bytes)?;
+            bytes = remaining;
+            Ok(transaction)
+        })
+    }
+
+    /// Decodes all of the transactions.
+    pub fn to_transactions(&self) -> Result<Transactions<N>> {
+        Ok(Transactions::from(&self.iter().collect::<Result<Vec<_>>>()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_block_bytes() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let expected = crate::test_helpers::sample_genesis_block(rng);
+        let bytes = expected.to_bytes_le()?;
+
+        // Check the fields of the view.
+        let candidate = BlockBytes::<CurrentNetwork>::new(&bytes)?;
+        assert_eq!(expected.hash(), candidate.hash()?);
+        assert_eq!(expected.previous_hash(), candidate.previous_hash()?);
+        assert_eq!(expected.header(), &candidate.header()?);
+        assert_eq!(expected.height(), candidate.height()?);
+        assert_eq!(expected.authority(), &candidate.authority()?);
+        assert_eq!(expected, candidate.to_block()?);
+
+        // Check the transactions of the view.
+        let transactions = candidate.transactions()?;
+        assert_eq!(expected.transactions().len(), transactions.len());
+        for (expected, candidate) in expected.transactions().iter().zip_eq(transactions.iter()) {
+            assert_eq!(expected, &candidate?);
+        }
+        assert_eq!(expected.transactions(), &transactions.to_transactions()?);
+
+        // Ensure an invalid version is rejected.
+        let mut invalid_bytes = bytes;
+        invalid_bytes[0] = 1;
+        assert!(BlockBytes::<CurrentNetwork>::new(&invalid_bytes).is_err());
+        Ok(())
+    }
+}
@@ -12,5 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod borrowed;
+pub use borrowed::*;
+
 mod target;
 pub use target::*;
+
+mod wire;
+pub use wire::*;
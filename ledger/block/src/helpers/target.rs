@@ -14,6 +14,8 @@
 
 use console::prelude::{ensure, Result};
 
+pub use ledger_coinbase::{coinbase_target, proof_target};
+
 /// A safety bound (sanity-check) for the coinbase reward.
 pub const MAX_COINBASE_REWARD: u64 = 237_823_432; // Coinbase reward at block 1.
 
@@ -106,116 +108,6 @@ pub const fn block_height_at_year(block_time: u16, num_years: u32) -> u32 {
     block_height_at_year_1 * num_years
 }
 
-/// Calculate the coinbase target for the given block timestamps and target.
-pub fn coinbase_target(
-    previous_target: u64,
-    previous_block_timestamp: i64,
-    block_timestamp: i64,
-    anchor_time: u16,
-    num_blocks_per_epoch: u32,
-    genesis_target: u64,
-) -> Result<u64> {
-    // Compute the half life.
-    let half_life = num_blocks_per_epoch.saturating_div(2).saturating_mul(anchor_time as u32);
-    // Compute the new coinbase target.
-    let candidate_target =
-        retarget(previous_target, previous_block_timestamp, block_timestamp, anchor_time, half_life, true)?;
-    // Return the new coinbase target, floored at the genesis target.
-    Ok(candidate_target.max(genesis_target))
-}
-
-/// Calculate the minimum proof target for the given coinbase target.
-pub fn proof_target(coinbase_target: u64, genesis_proof_target: u64) -> u64 {
-    coinbase_target.checked_shr(7).map(|target| target.saturating_add(1)).unwrap_or(genesis_proof_target)
-}
-
-/// Retarget algorithm using fixed point arithmetic from https://www.reference.cash/protocol/forks/2020-11-15-asert.
-///     T_{i+1} = T_i * 2^(INV * (D - A) / TAU).
-///     T_i = Current target.
-///     D = Drift, defined as the number of blocks elapsed.
-///     A = Anchor timestamp, defined as expected number of seconds elapsed.
-///     TAU = Rate of doubling (or half-life) in seconds.
-///     INV = {-1, 1} depending on whether the target is increasing or decreasing.
-fn retarget(
-    previous_target: u64,
-    previous_block_timestamp: i64,
-    block_timestamp: i64,
-    anchor_time: u16,
-    half_life: u32,
-    is_inverse: bool,
-) -> Result<u64> {
-    // Determine the block time elapsed (in seconds) since the previous block.
-    // Note: This operation includes a safety check for a repeat block timestamp.
-    let block_time_elapsed = block_timestamp.saturating_sub(previous_block_timestamp).max(1);
-    // Compute the drift.
-    let mut drift = block_time_elapsed.saturating_sub(anchor_time as i64);
-
-    // If the drift is zero, return the previous target.
-    if drift == 0 {
-        return Ok(previous_target);
-    }
-
-    // Negate the drift if the inverse flag is set.
-    if is_inverse {
-        drift *= -1;
-    }
-
-    // Constants used for fixed point arithmetic.
-    const RBITS: u32 = 16;
-    const RADIX: u128 = 1 << RBITS;
-
-    // Compute the exponent factor, and decompose it into integral & fractional parts for fixed point arithmetic.
-    let (integral, fractional) = {
-        // Calculate the exponent factor.
-        let exponent = (RADIX as i128).saturating_mul(drift as i128) / half_life as i128;
-
-        // Decompose into the integral and fractional parts.
-        let integral = exponent >> RBITS;
-        let fractional = (exponent - (integral << RBITS)) as u128;
-        ensure!(fractional < RADIX, "Fractional part is not within the fixed point size");
-        ensure!(exponent == (integral * (RADIX as i128) + fractional as i128), "Exponent is decomposed incorrectly");
-
-        (integral, fractional)
-    };
-
-    // Approximate the fractional multiplier as 2^RBITS * 2^fractional, where:
-    // 2^x ~= (1 + 0.695502049*x + 0.2262698*x**2 + 0.0782318*x**3)
-    let fractional_multiplier = RADIX
-        + ((195_766_423_245_049_u128 * fractional
-            + 971_821_376_u128 * fractional.pow(2)
-            + 5_127_u128 * fractional.pow(3)
-            + 2_u128.pow(RBITS * 3 - 1))
-            >> (RBITS * 3));
-
-    // Cast the previous coinbase target from a u64 to a u128.
-    // The difficulty target must allow for leading zeros to account for overflows;
-    // an additional 64-bits for the leading zeros suffices.
-    let candidate_target = (previous_target as u128).saturating_mul(fractional_multiplier);
-
-    // Calculate the new difficulty.
-    // Shift the target to multiply by 2^(integer) / RADIX.
-    let shifts = integral - RBITS as i128;
-    let mut candidate_target = if shifts < 0 {
-        match candidate_target.checked_shr(u32::try_from(-shifts)?) {
-            Some(target) => core::cmp::max(target, 1),
-            None => 1,
-        }
-    } else {
-        match candidate_target.checked_shl(u32::try_from(shifts)?) {
-            Some(target) => core::cmp::max(target, 1),
-            None => u64::MAX as u128,
-        }
-    };
-
-    // Cap the target at `u64::MAX` if it has overflowed.
-    candidate_target = core::cmp::min(candidate_target, u64::MAX as u128);
-
-    // Ensure that the leading 64 bits are zeros.
-    ensure!(candidate_target.checked_shr(64) == Some(0), "The target has overflowed");
-    // Cast the new target down from a u128 to a u64.
-    Ok(u64::try_from(candidate_target)?)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -527,173 +419,4 @@ mod tests {
             assert_eq!(reward, 0);
         }
     }
-
-    #[test]
-    fn test_targets() {
-        let mut rng = TestRng::default();
-
-        let minimum_coinbase_target: u64 = 2u64.pow(10) - 1;
-
-        fn test_new_targets(rng: &mut TestRng, minimum_coinbase_target: u64) {
-            let previous_coinbase_target: u64 = rng.gen_range(minimum_coinbase_target..u64::MAX);
-            let previous_prover_target = proof_target(previous_coinbase_target, CurrentNetwork::GENESIS_PROOF_TARGET);
-
-            let previous_timestamp = rng.gen();
-
-            // Targets stay the same when the drift is as expected.
-            let next_timestamp = previous_timestamp + CurrentNetwork::ANCHOR_TIME as i64;
-            let new_coinbase_target = coinbase_target(
-                previous_coinbase_target,
-                previous_timestamp,
-                next_timestamp,
-                CurrentNetwork::ANCHOR_TIME,
-                CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
-                CurrentNetwork::GENESIS_COINBASE_TARGET,
-            )
-            .unwrap();
-            let new_prover_target = proof_target(new_coinbase_target, CurrentNetwork::GENESIS_PROOF_TARGET);
-            assert_eq!(new_coinbase_target, previous_coinbase_target);
-            assert_eq!(new_prover_target, previous_prover_target);
-
-            // Targets decrease (easier) when the drift is greater than expected.
-            let new_timestamp = previous_timestamp + 2 * CurrentNetwork::ANCHOR_TIME as i64;
-            let new_coinbase_target = coinbase_target(
-                previous_coinbase_target,
-                previous_timestamp,
-                new_timestamp,
-                CurrentNetwork::ANCHOR_TIME,
-                CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
-                CurrentNetwork::GENESIS_COINBASE_TARGET,
-            )
-            .unwrap();
-            let new_prover_target = proof_target(new_coinbase_target, CurrentNetwork::GENESIS_PROOF_TARGET);
-            assert!(new_coinbase_target < previous_coinbase_target);
-            assert!(new_prover_target < previous_prover_target);
-
-            // Targets increase (harder) when the drift is less than expected.
-            let next_timestamp = previous_timestamp + (CurrentNetwork::ANCHOR_TIME / 2) as i64;
-            let new_coinbase_target = coinbase_target(
-                previous_coinbase_target,
-                previous_timestamp,
-                next_timestamp,
-                CurrentNetwork::ANCHOR_TIME,
-                CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
-                CurrentNetwork::GENESIS_COINBASE_TARGET,
-            )
-            .unwrap();
-            let new_prover_target = proof_target(new_coinbase_target, CurrentNetwork::GENESIS_PROOF_TARGET);
-
-            assert!(new_coinbase_target > previous_coinbase_target);
-            assert!(new_prover_target > previous_prover_target);
-        }
-
-        for _ in 0..ITERATIONS {
-            test_new_targets(&mut rng, minimum_coinbase_target);
-        }
-    }
-
-    #[test]
-    fn test_target_halving() {
-        let mut rng = TestRng::default();
-
-        let minimum_coinbase_target: u64 = 2u64.pow(10) - 1;
-
-        for _ in 0..ITERATIONS {
-            let previous_coinbase_target: u64 = rng.gen_range(minimum_coinbase_target..u64::MAX);
-            let previous_timestamp = rng.gen();
-
-            let half_life = CurrentNetwork::NUM_BLOCKS_PER_EPOCH
-                .saturating_div(2)
-                .saturating_mul(CurrentNetwork::ANCHOR_TIME as u32) as i64;
-
-            // New coinbase target is greater than half if the drift equals the half life.
-            let next_timestamp = previous_timestamp + half_life;
-            let next_coinbase_target = coinbase_target(
-                previous_coinbase_target,
-                previous_timestamp,
-                next_timestamp,
-                CurrentNetwork::ANCHOR_TIME,
-                CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
-                CurrentNetwork::GENESIS_COINBASE_TARGET,
-            )
-            .unwrap();
-
-            assert!(next_coinbase_target > previous_coinbase_target / 2);
-
-            // New coinbase target is halved if the drift is 1 anchor height past the half life.
-            let next_timestamp = previous_timestamp + half_life + CurrentNetwork::ANCHOR_TIME as i64;
-            let next_coinbase_target = coinbase_target(
-                previous_coinbase_target,
-                previous_timestamp,
-                next_timestamp,
-                CurrentNetwork::ANCHOR_TIME,
-                CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
-                CurrentNetwork::GENESIS_COINBASE_TARGET,
-            )
-            .unwrap();
-
-            assert_eq!(next_coinbase_target, previous_coinbase_target / 2);
-
-            // New coinbase target is less than half if the drift is more than 1 anchor height past the half life.
-            let next_timestamp = previous_timestamp + half_life + 2 * CurrentNetwork::ANCHOR_TIME as i64;
-            let next_coinbase_target = coinbase_target(
-                previous_coinbase_target,
-                previous_timestamp,
-                next_timestamp,
-                CurrentNetwork::ANCHOR_TIME,
-                CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
-                CurrentNetwork::GENESIS_COINBASE_TARGET,
-            )
-            .unwrap();
-
-            assert!(next_coinbase_target < previous_coinbase_target / 2);
-        }
-    }
-
-    #[test]
-    fn test_target_doubling() {
-        let mut rng = TestRng::default();
-
-        // The custom block height drift that is faster than the anchor time.
-        const ANCHOR_TIME_DELTA: i64 = 15;
-        // The expected number of blocks before the coinbase target is doubled.
-        const EXPECTED_NUM_BLOCKS_TO_DOUBLE: u32 = 909;
-
-        let minimum_coinbase_target: u64 = 2u64.pow(10) - 1;
-
-        let initial_coinbase_target: u64 = rng.gen_range(minimum_coinbase_target..u64::MAX / 2);
-        let initial_timestamp: i64 = rng.gen();
-
-        let mut previous_coinbase_target: u64 = initial_coinbase_target;
-        let mut previous_timestamp = initial_timestamp;
-        let mut num_blocks = 0;
-
-        while previous_coinbase_target < initial_coinbase_target * 2 {
-            // Targets increase (harder) when the timestamp is less than expected.
-            let next_timestamp = previous_timestamp + ANCHOR_TIME_DELTA;
-            let next_coinbase_target = coinbase_target(
-                previous_coinbase_target,
-                previous_timestamp,
-                next_timestamp,
-                CurrentNetwork::ANCHOR_TIME,
-                CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
-                CurrentNetwork::GENESIS_COINBASE_TARGET,
-            )
-            .unwrap();
-
-            assert!(next_coinbase_target > previous_coinbase_target);
-
-            previous_coinbase_target = next_coinbase_target;
-            previous_timestamp = next_timestamp;
-            num_blocks += 1;
-        }
-
-        let seconds = previous_timestamp - initial_timestamp;
-        println!(
-            "For drifts of {ANCHOR_TIME_DELTA} seconds and epochs of {} blocks, doubling the coinbase target took {num_blocks} blocks. ({seconds} seconds)",
-            CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
-        );
-
-        assert_eq!(EXPECTED_NUM_BLOCKS_TO_DOUBLE, num_blocks);
-    }
 }
@@ -0,0 +1,376 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The wire format is a sequence of frames, where each frame is encoded as:
+//!
+//! | Field   | Type       | Description                                          |
+//! |---------|------------|------------------------------------------------------|
+//! | version | `u8`       | The wire format version, `WIRE_FORMAT_VERSION`.      |
+//! | kind    | `u8`       | The kind of the object, see `WireKind`.              |
+//! | length  | `u32` (LE) | The number of bytes in the payload.                  |
+//! | payload | `[u8]`     | The canonical (`ToBytes`) encoding of the object.    |
+//!
+//! A payload is only accepted if it decodes to an object that re-encodes to the exact same bytes.
+
+use crate::{Block, Transaction, Transition};
+use console::prelude::*;
+
+use core::marker::PhantomData;
+
+/// The current version of the wire format.
+pub const WIRE_FORMAT_VERSION: u8 = 1;
+/// The default maximum number of bytes in a frame payload.
+pub const DEFAULT_MAX_WIRE_PAYLOAD_SIZE: u32 = 1 << 28; // 256 MiB
+
+/// The maximum number of bytes in a block payload.
+pub const MAX_BLOCK_WIRE_PAYLOAD_SIZE: u32 = 1 << 28; // 256 MiB
+/// The maximum number of bytes in a transaction payload.
+pub const MAX_TRANSACTION_WIRE_PAYLOAD_SIZE: u32 = 1 << 25; // 32 MiB
+/// The maximum number of bytes in a transition payload.
+pub const MAX_TRANSITION_WIRE_PAYLOAD_SIZE: u32 = 1 << 22; // 4 MiB
+
+/// The number of bytes in a frame header.
+const WIRE_HEADER_SIZE: usize = 6;
+/// The number of bytes in a chunk of a frame payload, which is read incrementally.
+const WIRE_CHUNK_SIZE: usize = 1 << 16; // 64 KiB
+
+/// The kind of object in a frame.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum WireKind {
+    Block = 0,
+    Transaction = 1,
+    Transition = 2,
+}
+
+impl WireKind {
+    /// Returns the maximum number of bytes in a payload of this kind.
+    pub const fn max_payload_size(&self) -> u32 {
+        match self {
+            Self::Block => MAX_BLOCK_WIRE_PAYLOAD_SIZE,
+            Self::Transaction => MAX_TRANSACTION_WIRE_PAYLOAD_SIZE,
+            Self::Transition => MAX_TRANSITION_WIRE_PAYLOAD_SIZE,
+        }
+    }
+}
+
+impl TryFrom<u8> for WireKind {
+    type Error = Error;
+
+    /// Returns the wire kind for the given byte.
+    fn try_from(kind: u8) -> Result<Self> {
+        match kind {
+            0 => Ok(Self::Block),
+            1 => Ok(Self::Transaction),
+            2 => Ok(Self::Transition),
+            _ => bail!("Invalid wire kind '{kind}'"),
+        }
+    }
+}
+
+/// An object that may be encoded in the wire format.
+pub trait WireFormat: Sized + FromBytes + ToBytes {
+    /// The kind of the object.
+    const WIRE_KIND: WireKind;
+
+    /// Returns the object, encoded as a single frame.
+    fn to_wire_bytes(&self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        WireEncoder::new(&mut bytes).write(self)?;
+        Ok(bytes)
+    }
+
+    /// Returns the object, decoded from exactly one frame.
+    fn from_wire_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = bytes;
+        // Read the frame.
+        let (kind, payload) = match read_frame(&mut reader, DEFAULT_MAX_WIRE_PAYLOAD_SIZE)? {
+            Some(frame) => frame,
+            None => bail!("Missing wire frame"),
+        };
+        // Ensure there are no trailing bytes.
+        ensure!(reader.is_empty(), "Found {} trailing bytes after the wire frame", reader.len());
+        // Ensure the kind matches.
+        ensure!(kind == Self::WIRE_KIND, "Expected a {:?} wire frame, found {kind:?}", Self::WIRE_KIND);
+        decode_payload(&payload)
+    }
+}
+
+impl<N: Network> WireFormat for Block<N> {
+    const WIRE_KIND: WireKind = WireKind::Block;
+}
+
+impl<N: Network> WireFormat for Transaction<N> {
+    const WIRE_KIND: WireKind = WireKind::Transaction;
+}
+
+impl<N: Network> WireFormat for Transition<N> {
+    const WIRE_KIND: WireKind = WireKind::Transition;
+}
+
+/// An object decoded from the wire format.
+#[derive(Clone, PartialEq, Eq)]
+pub enum WireObject<N: Network> {
+    Block(Box<Block<N>>),
+    Transaction(Box<Transaction<N>>),
+    Transition(Box<Transition<N>>),
+}
+
+impl<N: Network> WireObject<N> {
+    /// Returns the kind of the object.
+    pub const fn kind(&self) -> WireKind {
+        match self {
+            Self::Block(..) => WireKind::Block,
+            Self::Transaction(..) => WireKind::Transaction,
+            Self::Transition(..) => WireKind::Transition,
+        }
+    }
+}
+
+/// An encoder that writes objects as frames in the wire format.
+pub struct WireEncoder<W: Write> {
+    /// The writer.
+    writer: W,
+}
+
+impl<W: Write> WireEncoder<W> {
+    /// Initializes a new encoder for the given writer.
+    pub const fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Writes the given object as a frame.
+    pub fn write<T: WireFormat>(&mut self, object: &T) -> Result<()> {
+        // Encode the payload.
+        let payload = object.to_bytes_le()?;
+        let length = match u32::try_from(payload.len()) {
+            Ok(length) => length,
+            Err(_) => bail!("The wire payload is too large ({} bytes)", payload.len()),
+        };
+        // Write the header.
+        WIRE_FORMAT_VERSION.write_le(&mut self.writer)?;
+        (T::WIRE_KIND as u8).write_le(&mut self.writer)?;
+        length.write_le(&mut self.writer)?;
+        // Write the payload.
+        self.writer.write_all(&payload)?;
+        Ok(())
+    }
+
+    /// Returns the writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// A streaming decoder that reads objects from frames in the wire format.
+///
+/// The decoder yields one object per frame, and stops once the reader is exhausted at a frame boundary.
+pub struct WireDecoder<N: Network, R: Read> {
+    /// The reader.
+    reader: R,
+    /// The maximum number of bytes in a frame payload.
+    max_payload_size: u32,
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network, R: Read> WireDecoder<N, R> {
+    /// Initializes a new decoder for the given reader.
+    pub const fn new(reader: R) -> Self {
+        Self::with_max_payload_size(reader, DEFAULT_MAX_WIRE_PAYLOAD_SIZE)
+    }
+
+    /// Initializes a new decoder for the given reader, rejecting payloads larger than the given size.
+    /// Note: Payloads are also rejected if they exceed the maximum size for their kind.
+    pub const fn with_max_payload_size(reader: R, max_payload_size: u32) -> Self {
+        Self { reader, max_payload_size, _phantom: PhantomData }
+    }
+
+    /// Returns the next object, or `None` if the reader is exhausted.
+    pub fn next_object(&mut self) -> Result<Option<WireObject<N>>> {
+        // Read the frame.
+        let (kind, payload) = match read_frame(&mut self.reader, self.max_payload_size)? {
+            Some(frame) => frame,
+            None => return Ok(None),
+        };
+        // Decode the payload.
+        let object = match kind {
+            WireKind::Block => WireObject::Block(Box::new(decode_payload(&payload)?)),
+            WireKind::Transaction => WireObject::Transaction(Box::new(decode_payload(&payload)?)),
+            WireKind::Transition => WireObject::Transition(Box::new(decode_payload(&payload)?)),
+        };
+        Ok(Some(object))
+    }
+
+    /// Returns the reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<N: Network, R: Read> Iterator for WireDecoder<N, R> {
+    type Item = Result<WireObject<N>>;
+
+    /// Returns the next object, or `None` if the reader is exhausted.
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_object().transpose()
+    }
+}
+
+/// Reads a frame from the given reader, returning `None` if the reader is exhausted at a frame boundary.
+///
+/// The payload is read in chunks, so that the memory allocated is bounded by the bytes received,
+/// rather than by the length in the frame header.
+fn read_frame<R: Read>(reader: &mut R, max_payload_size: u32) -> Result<Option<(WireKind, Vec<u8>)>> {
+    // Read the header, checking for the end of the stream.
+    let mut header = [0u8; WIRE_HEADER_SIZE];
+    let mut num_read = 0;
+    while num_read < WIRE_HEADER_SIZE {
+        match reader.read(&mut header[num_read..]) {
+            Ok(0) if num_read == 0 => return Ok(None),
+            Ok(0) => bail!("Truncated wire frame header"),
+            Ok(n) => num_read += n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    // Ensure the version is supported.
+    let version = header[0];
+    ensure!(version == WIRE_FORMAT_VERSION, "Unsupported wire format version '{version}'");
+    // Read the kind.
+    let kind = WireKind::try_from(header[1])?;
+    // Read the length, and ensure it is within bounds for the kind.
+    let length = u32::from_le_bytes([header[2], header[3], header[4], header[5]]);
+    let max_payload_size = max_payload_size.min(kind.max_payload_size());
+    ensure!(length <= max_payload_size, "The {kind:?} wire payload is too large ({length} > {max_payload_size} bytes)");
+
+    // Read the payload, one chunk at a time.
+    let length = length as usize;
+    let mut payload = Vec::with_capacity(length.min(WIRE_CHUNK_SIZE));
+    let mut chunk = [0u8; WIRE_CHUNK_SIZE];
+    while payload.len() < length {
+        let chunk = &mut chunk[..(length - payload.len()).min(WIRE_CHUNK_SIZE)];
+        if let Err(e) = reader.read_exact(chunk) {
+            bail!("Truncated wire frame payload: {e}")
+        }
+        payload.extend_from_slice(chunk);
+    }
+    Ok(Some((kind, payload)))
+}
+
+/// Decodes the given payload, ensuring it is the canonical encoding of the object.
+fn decode_payload<T: FromBytes + ToBytes>(payload: &[u8]) -> Result<T> {
+    let object = T::from_bytes_le(payload)?;
+    // Ensure the payload is canonical, which also ensures there are no trailing bytes.
+    ensure!(object.to_bytes_le()? == payload, "The wire payload is not canonically encoded");
+    Ok(object)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_wire_roundtrip() {
+        let rng = &mut TestRng::default();
+
+        let block = crate::test_helpers::sample_genesis_block(rng);
+        let transaction = block.transactions().iter().next().unwrap().transaction().clone();
+        let transition = crate::transition::test_helpers::sample_transition(rng);
+
+        // Ensure each object roundtrips through a single frame.
+        assert_eq!(Block::from_wire_bytes(&block.to_wire_bytes().unwrap()).unwrap(), block);
+        assert_eq!(Transaction::from_wire_bytes(&transaction.to_wire_bytes().unwrap()).unwrap(), transaction);
+        assert_eq!(Transition::from_wire_bytes(&transition.to_wire_bytes().unwrap()).unwrap(), transition);
+
+        // Ensure a frame of a different kind is rejected.
+        assert!(Transaction::<CurrentNetwork>::from_wire_bytes(&block.to_wire_bytes().unwrap()).is_err());
+
+        // Ensure the objects stream through a decoder.
+        let mut encoder = WireEncoder::new(Vec::new());
+        encoder.write(&transition).unwrap();
+        encoder.write(&block).unwrap();
+        encoder.write(&transaction).unwrap();
+        let bytes = encoder.into_inner();
+
+        let objects = WireDecoder::<CurrentNetwork, _>::new(&bytes[..]).collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(objects.iter().map(WireObject::kind).collect::<Vec<_>>(), vec![
+            WireKind::Transition,
+            WireKind::Block,
+            WireKind::Transaction
+        ]);
+        assert!(objects[0] == WireObject::Transition(Box::new(transition)));
+        assert!(objects[1] == WireObject::Block(Box::new(block)));
+        assert!(objects[2] == WireObject::Transaction(Box::new(transaction)));
+
+        // Ensure an empty stream yields no objects.
+        assert!(WireDecoder::<CurrentNetwork, _>::new(&[][..]).next().is_none());
+    }
+
+    #[test]
+    fn test_wire_malformed() {
+        let rng = &mut TestRng::default();
+
+        let transition = crate::transition::test_helpers::sample_transition(rng);
+        let bytes = transition.to_wire_bytes().unwrap();
+
+        // Ensure an unsupported version is rejected.
+        let mut invalid = bytes.clone();
+        invalid[0] = WIRE_FORMAT_VERSION + 1;
+        assert!(Transition::<CurrentNetwork>::from_wire_bytes(&invalid).is_err());
+
+        // Ensure an unknown kind is rejected.
+        let mut invalid = bytes.clone();
+        invalid[1] = u8::MAX;
+        assert!(Transition::<CurrentNetwork>::from_wire_bytes(&invalid).is_err());
+
+        // Ensure truncated frames are rejected.
+        for length in [1, WIRE_HEADER_SIZE - 1, WIRE_HEADER_SIZE, bytes.len() - 1] {
+            assert!(Transition::<CurrentNetwork>::from_wire_bytes(&bytes[..length]).is_err());
+            let mut decoder = WireDecoder::<CurrentNetwork, _>::new(&bytes[..length]);
+            assert!(decoder.next().unwrap().is_err());
+        }
+
+        // Ensure trailing bytes are rejected.
+        let mut invalid = bytes.clone();
+        invalid.push(0);
+        assert!(Transition::<CurrentNetwork>::from_wire_bytes(&invalid).is_err());
+
+        // Ensure a payload with trailing bytes is rejected.
+        let mut invalid = bytes.clone();
+        invalid.push(0);
+        let length = u32::try_from(invalid.len() - WIRE_HEADER_SIZE).unwrap();
+        invalid[2..WIRE_HEADER_SIZE].copy_from_slice(&length.to_le_bytes());
+        assert!(Transition::<CurrentNetwork>::from_wire_bytes(&invalid).is_err());
+
+        // Ensure a payload that exceeds the maximum size is rejected.
+        let max_payload_size = u32::try_from(bytes.len() - WIRE_HEADER_SIZE - 1).unwrap();
+        let mut decoder = WireDecoder::<CurrentNetwork, _>::with_max_payload_size(&bytes[..], max_payload_size);
+        assert!(decoder.next().unwrap().is_err());
+
+        // Ensure a payload that exceeds the maximum size for its kind is rejected, before it is read.
+        let mut invalid = bytes[..WIRE_HEADER_SIZE].to_vec();
+        invalid[2..WIRE_HEADER_SIZE].copy_from_slice(&(MAX_TRANSITION_WIRE_PAYLOAD_SIZE + 1).to_le_bytes());
+        let error = Transition::<CurrentNetwork>::from_wire_bytes(&invalid).unwrap_err();
+        assert!(error.to_string().contains("too large"), "{error}");
+        // Ensure a frame header with a large length, but no payload, is rejected as truncated.
+        invalid[2..WIRE_HEADER_SIZE].copy_from_slice(&MAX_TRANSITION_WIRE_PAYLOAD_SIZE.to_le_bytes());
+        let error = Transition::<CurrentNetwork>::from_wire_bytes(&invalid).unwrap_err();
+        assert!(error.to_string().contains("Truncated"), "{error}");
+    }
+}
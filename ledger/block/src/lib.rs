@@ -25,6 +25,9 @@ pub use helpers::*;
 pub mod ratify;
 pub use ratify::*;
 
+pub mod receipt;
+pub use receipt::*;
+
 pub mod transaction;
 pub use transaction::*;
 
@@ -40,6 +43,9 @@ mod serialize;
 mod string;
 mod verify;
 
+mod weight;
+pub use weight::*;
+
 use console::{
     account::PrivateKey,
     network::prelude::*,
@@ -16,7 +16,10 @@ mod bytes;
 mod serialize;
 mod string;
 
-use console::{network::prelude::*, types::Address};
+use console::{
+    network::{prelude::*, ConsensusVersion},
+    types::Address,
+};
 use ledger_committee::Committee;
 
 use indexmap::IndexMap;
@@ -25,6 +28,11 @@ type Variant = u8;
 /// A helper type to represent the public balances.
 type PublicBalances<N> = IndexMap<Address<N>, u64>;
 
+/// A ratification, which updates the finalize store as part of a block.
+///
+/// To add a kind of ratification, add a variant with its serialization, declare the consensus version
+/// that introduces it in `introduced_in`, its stage in `stage`, and register a handler for it
+/// in the VM's `RatifyRegistry`.
 #[derive(Clone, PartialEq, Eq)]
 pub enum Ratify<N: Network> {
     /// The genesis.
@@ -35,6 +43,46 @@ pub enum Ratify<N: Network> {
     PuzzleReward(u64),
 }
 
+/// The stage of finalize at which a ratification is applied.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RatifyStage {
+    /// The ratification is applied before the transactions are finalized.
+    PreFinalize,
+    /// The ratification is applied after the transactions are finalized.
+    PostFinalize,
+}
+
+impl<N: Network> Ratify<N> {
+    /// Returns the name of the kind of ratification.
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::Genesis(..) => "Genesis",
+            Self::BlockReward(..) => "BlockReward",
+            Self::PuzzleReward(..) => "PuzzleReward",
+        }
+    }
+
+    /// Returns the consensus version that introduced this kind of ratification.
+    pub const fn introduced_in(&self) -> ConsensusVersion {
+        match self {
+            Self::Genesis(..) | Self::BlockReward(..) | Self::PuzzleReward(..) => ConsensusVersion::V1,
+        }
+    }
+
+    /// Returns `true` if this kind of ratification is enabled in the given consensus version.
+    pub fn is_enabled(&self, consensus_version: ConsensusVersion) -> bool {
+        consensus_version >= self.introduced_in()
+    }
+
+    /// Returns the stage of finalize at which this ratification is applied.
+    pub const fn stage(&self) -> RatifyStage {
+        match self {
+            Self::Genesis(..) => RatifyStage::PreFinalize,
+            Self::BlockReward(..) | Self::PuzzleReward(..) => RatifyStage::PostFinalize,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test_helpers {
     use super::*;
@@ -56,3 +104,23 @@ mod test_helpers {
         ]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kind_and_stage() {
+        let rng = &mut TestRng::default();
+
+        let [genesis, block_reward, puzzle_reward]: [_; 3] =
+            test_helpers::sample_ratify_objects(rng).try_into().ok().unwrap();
+        assert_eq!(genesis.kind(), "Genesis");
+        assert_eq!(genesis.stage(), RatifyStage::PreFinalize);
+        assert_eq!(block_reward.stage(), RatifyStage::PostFinalize);
+        assert_eq!(puzzle_reward.stage(), RatifyStage::PostFinalize);
+        for ratify in [genesis, block_reward, puzzle_reward] {
+            assert!(ratify.is_enabled(ConsensusVersion::V1));
+        }
+    }
+}
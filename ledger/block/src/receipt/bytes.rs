@@ -0,0 +1,132 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> FromBytes for Receipt<N> {
+    /// Reads the receipt from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid receipt version"));
+        }
+
+        // Read the block.
+        let block_hash = FromBytes::read_le(&mut reader)?;
+        let previous_hash = FromBytes::read_le(&mut reader)?;
+        let height = u32::read_le(&mut reader)?;
+        let header_root = Field::read_le(&mut reader)?;
+        // Read the transaction.
+        let index = u32::read_le(&mut reader)?;
+        let unconfirmed_id = FromBytes::read_le(&mut reader)?;
+        let transaction_id = FromBytes::read_le(&mut reader)?;
+        let unconfirmed_path = match u8::read_le(&mut reader)? {
+            0u8 => None,
+            1u8 => Some((Field::read_le(&mut reader)?, TransactionPath::read_le(&mut reader)?)),
+            _ => return Err(error("Invalid receipt unconfirmed path variant")),
+        };
+        let transactions_root = Field::read_le(&mut reader)?;
+        let transactions_header_path = HeaderPath::read_le(&mut reader)?;
+        let transactions_path = TransactionsPath::read_le(&mut reader)?;
+        // Read the finalize operations.
+        let finalize_root = Field::read_le(&mut reader)?;
+        let finalize_header_path = HeaderPath::read_le(&mut reader)?;
+        let finalize_start = u32::read_le(&mut reader)?;
+        let num_finalize = NumFinalizeSize::read_le(&mut reader)?;
+        let mut finalize = Vec::with_capacity(num_finalize as usize);
+        for _ in 0..num_finalize {
+            finalize.push((FinalizeOperation::read_le(&mut reader)?, FinalizePath::read_le(&mut reader)?));
+        }
+
+        Ok(Self {
+            block_hash,
+            previous_hash,
+            height,
+            header_root,
+            index,
+            unconfirmed_id,
+            transaction_id,
+            unconfirmed_path,
+            transactions_root,
+            transactions_header_path,
+            transactions_path,
+            finalize_root,
+            finalize_header_path,
+            finalize_start,
+            finalize,
+        })
+    }
+}
+
+impl<N: Network> ToBytes for Receipt<N> {
+    /// Writes the receipt to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the block.
+        self.block_hash.write_le(&mut writer)?;
+        self.previous_hash.write_le(&mut writer)?;
+        self.height.write_le(&mut writer)?;
+        self.header_root.write_le(&mut writer)?;
+        // Write the transaction.
+        self.index.write_le(&mut writer)?;
+        self.unconfirmed_id.write_le(&mut writer)?;
+        self.transaction_id.write_le(&mut writer)?;
+        match &self.unconfirmed_path {
+            None => 0u8.write_le(&mut writer)?,
+            Some((fee_transition_id, unconfirmed_path)) => {
+                1u8.write_le(&mut writer)?;
+                fee_transition_id.write_le(&mut writer)?;
+                unconfirmed_path.write_le(&mut writer)?;
+            }
+        }
+        self.transactions_root.write_le(&mut writer)?;
+        self.transactions_header_path.write_le(&mut writer)?;
+        self.transactions_path.write_le(&mut writer)?;
+        // Write the finalize operations.
+        self.finalize_root.write_le(&mut writer)?;
+        self.finalize_header_path.write_le(&mut writer)?;
+        self.finalize_start.write_le(&mut writer)?;
+        NumFinalizeSize::try_from(self.finalize.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        for (operation, finalize_path) in &self.finalize {
+            operation.write_le(&mut writer)?;
+            finalize_path.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() {
+        let rng = &mut TestRng::default();
+
+        let block = crate::test_helpers::sample_genesis_block(rng);
+        for expected in Receipt::from_block(&block).unwrap() {
+            // Check the byte representation.
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, Receipt::read_le(&expected_bytes[..]).unwrap());
+            assert!(Receipt::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        }
+    }
+}
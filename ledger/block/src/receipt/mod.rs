@@ -0,0 +1,371 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod bytes;
+mod serialize;
+mod string;
+
+use crate::{Block, ConfirmedTransaction, NumFinalizeSize, Rejected, Transaction};
+use console::{
+    network::prelude::*,
+    program::{
+        FinalizePath,
+        HeaderLeaf,
+        HeaderPath,
+        TransactionLeaf,
+        TransactionPath,
+        TransactionsPath,
+        TRANSACTION_DEPTH,
+    },
+    types::Field,
+};
+use synthesizer_program::FinalizeOperation;
+
+/// The receipt of a transaction, which proves the transaction is in a block,
+/// and contains its status and its finalize operations (the changes it made to the program mappings).
+///
+/// The receipt is verified against its block hash, which the recipient must check is in the canonical chain.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Receipt<N: Network> {
+    /// The hash of the block.
+    block_hash: N::BlockHash,
+    /// The hash of the previous block.
+    previous_hash: N::BlockHash,
+    /// The height of the block.
+    /// Note: The height is not covered by the Merkle proofs.
+    height: u32,
+    /// The root of the block header.
+    header_root: Field<N>,
+    /// The index of the transaction in the block.
+    index: u32,
+    /// The transaction ID prior to confirmation.
+    unconfirmed_id: N::TransactionID,
+    /// The transaction ID in the block, which is the fee transaction ID if the transaction was rejected.
+    transaction_id: N::TransactionID,
+    /// The fee transition ID, with its Merkle path in the unconfirmed transaction, if the transaction was rejected.
+    unconfirmed_path: Option<(Field<N>, TransactionPath<N>)>,
+    /// The transactions root of the block.
+    transactions_root: Field<N>,
+    /// The Merkle path for the transactions root in the block header.
+    transactions_header_path: HeaderPath<N>,
+    /// The Merkle path for the transaction ID in the transactions tree.
+    transactions_path: TransactionsPath<N>,
+    /// The finalize root of the block.
+    finalize_root: Field<N>,
+    /// The Merkle path for the finalize root in the block header.
+    finalize_header_path: HeaderPath<N>,
+    /// The index of the first finalize operation of the transaction in the finalize tree.
+    /// Note: The start index is not covered by the Merkle proofs, as the block does not commit to
+    /// the number of finalize operations of each transaction.
+    finalize_start: u32,
+    /// The finalize operations of the transaction, with their Merkle paths in the finalize tree.
+    finalize: Vec<(FinalizeOperation<N>, FinalizePath<N>)>,
+}
+
+impl<N: Network> Receipt<N> {
+    /// Returns the receipts for the transactions in the given block.
+    pub fn from_block(block: &Block<N>) -> Result<Vec<Self>> {
+        // Construct the block header paths.
+        let header = block.header();
+        let header_root = header.to_root()?;
+        let transactions_root = header.transactions_root();
+        let transactions_header_path = header.to_path(&HeaderLeaf::<N>::new(1, transactions_root))?;
+        let finalize_root = header.finalize_root();
+        let finalize_header_path = header.to_path(&HeaderLeaf::<N>::new(2, finalize_root))?;
+
+        // Compute the transactions tree and the finalize tree.
+        let transactions = block.transactions();
+        let transactions_tree = transactions.to_tree()?;
+        let finalize_tree = transactions.to_finalize_tree()?;
+
+        // Note: The finalize operations are indexed across all transactions in the block.
+        let mut finalize_index = 0;
+        let mut receipts = Vec::with_capacity(transactions.len());
+        for (index, confirmed) in transactions.iter().enumerate() {
+            // Construct the transactions path.
+            let transaction_id = confirmed.id();
+            let transactions_path = transactions_tree.prove(index, &transaction_id.to_bits_le())?;
+
+            // If the transaction was rejected, construct the path for its fee transition in the unconfirmed transaction.
+            let unconfirmed_path = match confirmed {
+                ConfirmedTransaction::AcceptedDeploy(..) | ConfirmedTransaction::AcceptedExecute(..) => None,
+                ConfirmedTransaction::RejectedDeploy(_, Transaction::Fee(_, fee), rejected)
+                | ConfirmedTransaction::RejectedExecute(_, Transaction::Fee(_, fee), rejected) => {
+                    // Compute the unconfirmed transaction tree, where the fee transition is the last leaf.
+                    let (tree, fee_index) = match rejected {
+                        Rejected::Deployment(_, deployment) => (
+                            Transaction::deployment_tree(deployment, Some(fee))?,
+                            deployment.program().functions().len(),
+                        ),
+                        Rejected::Execution(execution) => {
+                            (Transaction::execution_tree(execution, &Some(fee.clone()))?, execution.len())
+                        }
+                    };
+                    let fee_transition_id = **fee.transition_id();
+                    let fee_leaf = TransactionLeaf::new_fee(u16::try_from(fee_index)?, fee_transition_id);
+                    Some((fee_transition_id, tree.prove(fee_index, &fee_leaf.to_bits_le())?))
+                }
+                _ => bail!("Invalid confirmed transaction '{transaction_id}'"),
+            };
+
+            // Construct the finalize paths.
+            let finalize_start = u32::try_from(finalize_index)?;
+            let mut finalize = Vec::new();
+            for operation in confirmed.finalize_operations().into_iter().flatten() {
+                let finalize_path = finalize_tree.prove(finalize_index, &operation.to_bits_le())?;
+                finalize.push((*operation, finalize_path));
+                finalize_index += 1;
+            }
+
+            receipts.push(Self {
+                block_hash: block.hash(),
+                previous_hash: block.previous_hash(),
+                height: block.height(),
+                header_root,
+                index: u32::try_from(index)?,
+                unconfirmed_id: confirmed.unconfirmed_id()?,
+                transaction_id,
+                unconfirmed_path,
+                transactions_root,
+                transactions_header_path: transactions_header_path.clone(),
+                transactions_path,
+                finalize_root,
+                finalize_header_path: finalize_header_path.clone(),
+                finalize_start,
+                finalize,
+            });
+        }
+        Ok(receipts)
+    }
+
+    /// Ensures the Merkle proofs in the receipt are valid for its block hash.
+    /// Note: The caller must check the block hash is in the canonical chain.
+    pub fn verify(&self) -> Result<()> {
+        // Ensure the block hash matches the block header root.
+        let candidate_hash = N::hash_bhp1024(&to_bits_le![self.previous_hash, self.header_root])?;
+        ensure!(*self.block_hash == candidate_hash, "The receipt block hash does not match its header root");
+
+        // Ensure the transactions root is in the block header.
+        let transactions_leaf = HeaderLeaf::<N>::new(1, self.transactions_root);
+        ensure!(
+            N::verify_merkle_path_bhp(
+                &self.transactions_header_path,
+                &self.header_root,
+                &transactions_leaf.to_bits_le()
+            ),
+            "The receipt transactions root is not in the block header"
+        );
+        // Ensure the transaction ID is in the transactions tree, at the transaction index.
+        ensure!(
+            *self.transactions_path.leaf_index() == self.index as u64,
+            "The receipt transactions path is not for index {}",
+            self.index
+        );
+        ensure!(
+            N::verify_merkle_path_bhp(
+                &self.transactions_path,
+                &self.transactions_root,
+                &self.transaction_id.to_bits_le()
+            ),
+            "The receipt transaction '{}' is not in the block",
+            self.transaction_id
+        );
+
+        // Ensure the unconfirmed transaction ID is bound to the transaction in the block.
+        match &self.unconfirmed_path {
+            // If the transaction was accepted, the unconfirmed transaction ID is the transaction ID.
+            None => ensure!(
+                self.unconfirmed_id == self.transaction_id,
+                "The receipt unconfirmed ID does not match the accepted transaction '{}'",
+                self.transaction_id
+            ),
+            // If the transaction was rejected, the transaction in the block is the fee transaction
+            // for the fee transition, and the unconfirmed transaction contains the fee transition.
+            Some((fee_transition_id, unconfirmed_path)) => {
+                let fee_leaf = TransactionLeaf::<N>::new_fee(0, *fee_transition_id);
+                let fee_tree = N::merkle_tree_bhp::<TRANSACTION_DEPTH>(&[fee_leaf.to_bits_le()])?;
+                ensure!(
+                    *self.transaction_id == *fee_tree.root(),
+                    "The receipt transaction '{}' is not the fee transaction for its fee transition",
+                    self.transaction_id
+                );
+                let fee_leaf =
+                    TransactionLeaf::<N>::new_fee(u16::try_from(*unconfirmed_path.leaf_index())?, *fee_transition_id);
+                ensure!(
+                    N::verify_merkle_path_bhp(unconfirmed_path, &*self.unconfirmed_id, &fee_leaf.to_bits_le()),
+                    "The receipt unconfirmed transaction '{}' does not contain its fee transition",
+                    self.unconfirmed_id
+                );
+            }
+        }
+
+        // Ensure the finalize root is in the block header.
+        let finalize_leaf = HeaderLeaf::<N>::new(2, self.finalize_root);
+        ensure!(
+            N::verify_merkle_path_bhp(&self.finalize_header_path, &self.header_root, &finalize_leaf.to_bits_le()),
+            "The receipt finalize root is not in the block header"
+        );
+        // Ensure a rejected transaction has no finalize operations.
+        ensure!(
+            self.is_accepted() || self.finalize.is_empty(),
+            "A rejected transaction cannot have finalize operations"
+        );
+        // Ensure the finalize operations are in the finalize tree, at the indices of the transaction.
+        for (offset, (operation, finalize_path)) in self.finalize.iter().enumerate() {
+            let finalize_index = self.finalize_start as u64 + offset as u64;
+            ensure!(
+                *finalize_path.leaf_index() == finalize_index,
+                "The receipt finalize path is not for index {finalize_index}"
+            );
+            ensure!(
+                N::verify_merkle_path_bhp(finalize_path, &self.finalize_root, &operation.to_bits_le()),
+                "The receipt finalize operation is not in the block"
+            );
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> Receipt<N> {
+    /// Returns the block hash.
+    pub const fn block_hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the previous block hash.
+    pub const fn previous_hash(&self) -> N::BlockHash {
+        self.previous_hash
+    }
+
+    /// Returns the block height.
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the index of the transaction in the block.
+    pub const fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns the transaction ID prior to confirmation.
+    pub const fn unconfirmed_id(&self) -> N::TransactionID {
+        self.unconfirmed_id
+    }
+
+    /// Returns the transaction ID in the block, which is the fee transaction ID if the transaction was rejected.
+    pub const fn transaction_id(&self) -> N::TransactionID {
+        self.transaction_id
+    }
+
+    /// Returns `true` if the transaction was accepted.
+    /// Note: A rejected transaction is stored in the block as its fee transaction, which has a different ID.
+    pub fn is_accepted(&self) -> bool {
+        self.transaction_id == self.unconfirmed_id
+    }
+
+    /// Returns `true` if the transaction was rejected.
+    pub fn is_rejected(&self) -> bool {
+        !self.is_accepted()
+    }
+
+    /// Returns the finalize operations of the transaction.
+    pub fn finalize_operations(&self) -> impl '_ + ExactSizeIterator<Item = &FinalizeOperation<N>> {
+        self.finalize.iter().map(|(operation, _)| operation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{transactions::confirmed::test_helpers, Header, Transactions};
+    use console::{account::PrivateKey, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_receipts() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis block.
+        let block = crate::test_helpers::sample_genesis_block(rng);
+
+        // Ensure there is a valid receipt for each transaction.
+        let receipts = Receipt::from_block(&block).unwrap();
+        assert_eq!(receipts.len(), block.transactions().len());
+        for (receipt, confirmed) in receipts.iter().zip(block.transactions().iter()) {
+            receipt.verify().unwrap();
+            assert_eq!(receipt.block_hash(), block.hash());
+            assert_eq!(receipt.index(), confirmed.index());
+            assert_eq!(receipt.transaction_id(), confirmed.id());
+            assert_eq!(receipt.is_accepted(), confirmed.is_accepted());
+            assert!(receipt.finalize_operations().eq(confirmed.finalize_operations().into_iter().flatten()));
+        }
+
+        // Ensure a receipt with another transaction ID is invalid.
+        let mut receipt = receipts[0].clone();
+        receipt.transaction_id = <CurrentNetwork as Network>::TransactionID::from(Field::rand(rng));
+        assert!(receipt.verify().is_err());
+
+        // Ensure a receipt with another block hash is invalid.
+        let mut receipt = receipts[0].clone();
+        receipt.block_hash = <CurrentNetwork as Network>::BlockHash::from(Field::rand(rng));
+        assert!(receipt.verify().is_err());
+
+        // Ensure a receipt with another unconfirmed ID is invalid.
+        let mut receipt = receipts[0].clone();
+        receipt.unconfirmed_id = <CurrentNetwork as Network>::TransactionID::from(Field::rand(rng));
+        assert!(receipt.verify().is_err());
+    }
+
+    #[test]
+    fn test_receipts_with_finalize_and_rejected() {
+        let rng = &mut TestRng::default();
+
+        // Sample a block with accepted and rejected transactions.
+        let transactions = Transactions::from_iter([
+            test_helpers::sample_accepted_deploy(0, true, rng),
+            test_helpers::sample_accepted_deploy(1, false, rng),
+            test_helpers::sample_rejected_execute(2, true, rng),
+            test_helpers::sample_rejected_deploy(3, false, rng),
+        ]);
+        let header = Header::genesis(&transactions).unwrap();
+        let private_key = PrivateKey::new(rng).unwrap();
+        let block =
+            Block::new_beacon(&private_key, Default::default(), header, vec![], None, transactions, rng).unwrap();
+
+        // Ensure there is a valid receipt for each transaction.
+        let receipts = Receipt::from_block(&block).unwrap();
+        assert_eq!(receipts.len(), 4);
+        for receipt in &receipts {
+            receipt.verify().unwrap();
+        }
+
+        // Ensure a rejected receipt with another unconfirmed ID is invalid.
+        let mut receipt = receipts[2].clone();
+        assert!(!receipt.is_accepted());
+        receipt.unconfirmed_id = receipts[3].unconfirmed_id;
+        assert!(receipt.verify().is_err());
+
+        // Ensure a receipt with its finalize operations at other indices is invalid.
+        let mut receipt = receipts[0].clone();
+        assert_eq!(receipt.finalize.len(), 1);
+        receipt.finalize_start += 1;
+        assert!(receipt.verify().is_err());
+
+        // Ensure a receipt with the finalize operations of another transaction is invalid.
+        let mut receipt = receipts[1].clone();
+        receipt.finalize = receipts[0].finalize.clone();
+        assert!(receipt.verify().is_err());
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network> Serialize for Receipt<N> {
+    /// Serializes the receipt to a JSON-string or buffer.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut receipt = serializer.serialize_struct("Receipt", 15)?;
+                receipt.serialize_field("block_hash", &self.block_hash)?;
+                receipt.serialize_field("previous_hash", &self.previous_hash)?;
+                receipt.serialize_field("height", &self.height)?;
+                receipt.serialize_field("header_root", &self.header_root)?;
+                receipt.serialize_field("index", &self.index)?;
+                receipt.serialize_field("unconfirmed_id", &self.unconfirmed_id)?;
+                receipt.serialize_field("transaction_id", &self.transaction_id)?;
+                receipt.serialize_field("unconfirmed_path", &self.unconfirmed_path)?;
+                receipt.serialize_field("transactions_root", &self.transactions_root)?;
+                receipt.serialize_field("transactions_header_path", &self.transactions_header_path)?;
+                receipt.serialize_field("transactions_path", &self.transactions_path)?;
+                receipt.serialize_field("finalize_root", &self.finalize_root)?;
+                receipt.serialize_field("finalize_header_path", &self.finalize_header_path)?;
+                receipt.serialize_field("finalize_start", &self.finalize_start)?;
+                receipt.serialize_field("finalize", &self.finalize)?;
+                receipt.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for Receipt<N> {
+    /// Deserializes the receipt from a JSON-string or buffer.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let mut receipt = serde_json::Value::deserialize(deserializer)?;
+                Ok(Self {
+                    block_hash: DeserializeExt::take_from_value::<D>(&mut receipt, "block_hash")?,
+                    previous_hash: DeserializeExt::take_from_value::<D>(&mut receipt, "previous_hash")?,
+                    height: DeserializeExt::take_from_value::<D>(&mut receipt, "height")?,
+                    header_root: DeserializeExt::take_from_value::<D>(&mut receipt, "header_root")?,
+                    index: DeserializeExt::take_from_value::<D>(&mut receipt, "index")?,
+                    unconfirmed_id: DeserializeExt::take_from_value::<D>(&mut receipt, "unconfirmed_id")?,
+                    transaction_id: DeserializeExt::take_from_value::<D>(&mut receipt, "transaction_id")?,
+                    unconfirmed_path: DeserializeExt::take_from_value::<D>(&mut receipt, "unconfirmed_path")?,
+                    transactions_root: DeserializeExt::take_from_value::<D>(&mut receipt, "transactions_root")?,
+                    transactions_header_path: DeserializeExt::take_from_value::<D>(
+                        &mut receipt,
+                        "transactions_header_path",
+                    )?,
+                    transactions_path: DeserializeExt::take_from_value::<D>(&mut receipt, "transactions_path")?,
+                    finalize_root: DeserializeExt::take_from_value::<D>(&mut receipt, "finalize_root")?,
+                    finalize_header_path: DeserializeExt::take_from_value::<D>(&mut receipt, "finalize_header_path")?,
+                    finalize_start: DeserializeExt::take_from_value::<D>(&mut receipt, "finalize_start")?,
+                    finalize: DeserializeExt::take_from_value::<D>(&mut receipt, "finalize")?,
+                })
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "receipt"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let block = crate::test_helpers::sample_genesis_block(rng);
+        for expected in Receipt::from_block(&block)? {
+            // Serialize
+            let expected_string = &expected.to_string();
+            let candidate_string = serde_json::to_string(&expected)?;
+
+            // Deserialize
+            assert_eq!(expected, Receipt::from_str(expected_string)?);
+            assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        let block = crate::test_helpers::sample_genesis_block(rng);
+        for expected in Receipt::from_block(&block)? {
+            // Serialize
+            let expected_bytes = expected.to_bytes_le()?;
+            let expected_bytes_with_size_encoding = bincode::serialize(&expected)?;
+            assert_eq!(&expected_bytes[..], &expected_bytes_with_size_encoding[8..]);
+
+            // Deserialize
+            assert_eq!(expected, Receipt::read_le(&expected_bytes[..])?);
+            assert_eq!(expected, bincode::deserialize(&expected_bytes_with_size_encoding[..])?);
+        }
+        Ok(())
+    }
+}
@@ -128,6 +128,11 @@ impl<N: Network> Deployment<N> {
     pub fn to_deployment_id(&self) -> Result<Field<N>> {
         Ok(*Transaction::deployment_tree(self, None)?.root())
     }
+
+    /// Returns the checksum of the deployed program at its edition.
+    pub fn program_checksum(&self) -> Result<[u8; 32]> {
+        self.program.checksum(self.edition)
+    }
 }
 
 #[cfg(test)]
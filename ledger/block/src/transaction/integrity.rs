@@ -0,0 +1,162 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::types::U16;
+
+/// A mismatch between an ID in a transaction and the ID recomputed from its contents.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IdMismatch<N: Network> {
+    /// The input ID at the given index of the transition does not match its contents.
+    InputId { transition_id: N::TransitionID, index: usize },
+    /// The output ID at the given index of the transition does not match its contents.
+    OutputId { transition_id: N::TransitionID, index: usize },
+    /// The transition ID does not match the one recomputed from its inputs, outputs, and commitment.
+    TransitionId { found: N::TransitionID, expected: N::TransitionID },
+    /// The transaction ID does not match the root of the recomputed transaction tree.
+    TransactionId { found: N::TransactionID, expected: N::TransactionID },
+    /// The fee is bound to a different deployment or execution ID than the one recomputed from the transaction.
+    FeeBinding { found: Field<N>, expected: Field<N> },
+}
+
+impl<N: Network> Display for IdMismatch<N> {
+    /// Prints the mismatch.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::InputId { transition_id, index } => {
+                write!(f, "Input {index} of transition '{transition_id}' has an incorrect ID")
+            }
+            Self::OutputId { transition_id, index } => {
+                write!(f, "Output {index} of transition '{transition_id}' has an incorrect ID")
+            }
+            Self::TransitionId { found, expected } => write!(f, "Transition ID '{found}' should be '{expected}'"),
+            Self::TransactionId { found, expected } => write!(f, "Transaction ID '{found}' should be '{expected}'"),
+            Self::FeeBinding { found, expected } => write!(f, "The fee is bound to '{found}' instead of '{expected}'"),
+        }
+    }
+}
+
+impl<N: Network> Transaction<N> {
+    /// Recomputes every ID in the transaction from scratch, and returns each ID that does not match.
+    ///
+    /// This checks the input and output IDs and the ID of each transition, the transaction ID as the root
+    /// of the transaction tree, and that the fee is bound to the deployment or execution ID.
+    /// An empty list means the IDs are intact. It does not verify any proofs.
+    pub fn verify_id_integrity(&self) -> Result<Vec<IdMismatch<N>>> {
+        let mut mismatches = Vec::new();
+
+        // Check the IDs of each transition.
+        for transition in self.transitions() {
+            // Compute the function ID.
+            let function_id = N::hash_bhp1024(
+                &(
+                    U16::<N>::new(N::ID),
+                    transition.program_id().name(),
+                    transition.program_id().network(),
+                    transition.function_name(),
+                )
+                    .to_bits_le(),
+            )?;
+            let num_inputs = transition.inputs().len();
+            for (index, input) in transition.inputs().iter().enumerate() {
+                if !input.verify(function_id, transition.tcm(), index) {
+                    mismatches.push(IdMismatch::InputId { transition_id: *transition.id(), index });
+                }
+            }
+            for (index, output) in transition.outputs().iter().enumerate() {
+                if !output.verify(function_id, transition.tcm(), num_inputs + index) {
+                    mismatches.push(IdMismatch::OutputId { transition_id: *transition.id(), index });
+                }
+            }
+
+            // Recompute the transition ID.
+            let expected = *Transition::new(
+                *transition.program_id(),
+                *transition.function_name(),
+                transition.inputs().to_vec(),
+                transition.outputs().to_vec(),
+                transition.finalize().cloned(),
+                *transition.tpk(),
+                *transition.tcm(),
+            )?
+            .id();
+            if expected != *transition.id() {
+                mismatches.push(IdMismatch::TransitionId { found: *transition.id(), expected });
+            }
+        }
+
+        // Check the transaction ID.
+        let expected = N::TransactionID::from(self.to_root()?);
+        if expected != self.id() {
+            mismatches.push(IdMismatch::TransactionId { found: self.id(), expected });
+        }
+
+        // Check the fee binding.
+        let binding = match self {
+            Self::Deploy(_, _, deployment, fee) => Some((fee, deployment.to_deployment_id()?)),
+            Self::Execute(_, execution, Some(fee)) => Some((fee, execution.to_execution_id()?)),
+            // Note: A fee transaction is bound to the ID of the rejected transaction, which it does not contain.
+            Self::Execute(_, _, None) | Self::Fee(..) => None,
+        };
+        if let Some((fee, expected)) = binding {
+            let found = fee.deployment_or_execution_id()?;
+            if found != expected {
+                mismatches.push(IdMismatch::FeeBinding { found, expected });
+            }
+        }
+
+        Ok(mismatches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_verify_id_integrity() {
+        let rng = &mut TestRng::default();
+
+        // Ensure valid transactions are intact.
+        for transaction in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(false, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            assert_eq!(transaction.verify_id_integrity().unwrap(), vec![]);
+        }
+
+        // Ensure a tampered transaction ID is reported.
+        let transaction = crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng);
+        let Transaction::Execute(id, execution, fee) = transaction else { unreachable!() };
+        let tampered_id = <CurrentNetwork as Network>::TransactionID::from(Field::from_u64(1));
+        let tampered = Transaction::Execute(tampered_id, execution.clone(), fee);
+        assert_eq!(tampered.verify_id_integrity().unwrap(), vec![IdMismatch::TransactionId {
+            found: tampered_id,
+            expected: id
+        }]);
+
+        // Ensure a fee that is bound to another transaction is reported.
+        let fee = crate::transaction::fee::test_helpers::sample_fee_public_hardcoded(rng);
+        let found = fee.deployment_or_execution_id().unwrap();
+        let transaction = Transaction::from_execution(execution.clone(), Some(fee)).unwrap();
+        assert_eq!(transaction.verify_id_integrity().unwrap(), vec![IdMismatch::FeeBinding {
+            found,
+            expected: execution.to_execution_id().unwrap()
+        }]);
+    }
+}
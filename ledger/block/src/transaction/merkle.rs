@@ -15,8 +15,12 @@
 use super::*;
 
 impl<N: Network> Transaction<N> {
-    /// The maximum number of transitions allowed in a transaction.
-    const MAX_TRANSITIONS: usize = usize::pow(2, TRANSACTION_DEPTH as u32);
+    /// The maximum number of transitions allowed in a transaction, capped by the capacity of the transaction tree.
+    pub const MAX_TRANSITIONS: usize =
+        match N::MAX_TRANSITIONS_PER_TRANSACTION < usize::pow(2, TRANSACTION_DEPTH as u32) {
+            true => N::MAX_TRANSITIONS_PER_TRANSACTION,
+            false => usize::pow(2, TRANSACTION_DEPTH as u32),
+        };
 
     /// Returns the transaction root, by computing the root for a Merkle tree of the transition IDs.
     pub fn to_root(&self) -> Result<Field<N>> {
@@ -21,6 +21,12 @@ pub use execution::*;
 mod fee;
 pub use fee::*;
 
+mod integrity;
+pub use integrity::*;
+
+mod weight;
+pub use weight::*;
+
 mod bytes;
 mod merkle;
 mod serialize;
@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::MAX_TRANSACTION_WIRE_PAYLOAD_SIZE;
+
+/// The weight of a transaction, measured against the consensus limits.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TransactionWeight {
+    /// The number of bytes in the transaction.
+    pub size_in_bytes: u64,
+    /// The maximum number of bytes in the transaction.
+    pub max_size_in_bytes: u64,
+    /// The number of bytes in the deployment or execution (zero for a fee transaction).
+    pub body_size_in_bytes: u64,
+    /// The number of bytes in the fee (zero if there is no fee).
+    pub fee_size_in_bytes: u64,
+    /// The number of functions in the deployment, or the number of transitions in the execution, excluding the fee.
+    pub num_leaves: usize,
+    /// The maximum number of functions or transitions, excluding the fee.
+    pub max_leaves: usize,
+    /// The number of transitions with finalize inputs.
+    pub num_finalize: usize,
+    /// The fee amount, in microcredits.
+    pub fee_amount: u64,
+    /// The maximum fee amount (exclusive), in microcredits.
+    pub max_fee_amount: u64,
+    /// Whether the transaction must pay a fee.
+    pub is_fee_required: bool,
+    /// The minimum cost as (storage cost, finalize or namespace cost) in microcredits, if known.
+    /// This requires the programs of the transaction, and is set by `with_minimum_cost`.
+    pub minimum_cost: Option<(u64, u64)>,
+}
+
+impl TransactionWeight {
+    /// Returns the weight with the given minimum cost as (storage cost, finalize or namespace cost) in microcredits.
+    pub const fn with_minimum_cost(mut self, storage_cost: u64, finalize_cost: u64) -> Self {
+        self.minimum_cost = Some((storage_cost, finalize_cost));
+        self
+    }
+
+    /// Returns the total minimum cost in microcredits, if known.
+    pub fn total_minimum_cost(&self) -> Result<Option<u64>> {
+        match self.minimum_cost {
+            Some((storage_cost, finalize_cost)) => match storage_cost.checked_add(finalize_cost) {
+                Some(total_cost) => Ok(Some(total_cost)),
+                None => bail!("The total cost computation overflowed for a transaction"),
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Ensures the weight is within the consensus limits.
+    /// If the minimum cost is known, this also ensures the fee covers it.
+    pub fn check(&self) -> Result<()> {
+        // Ensure the size is within the limit.
+        ensure!(
+            self.size_in_bytes <= self.max_size_in_bytes,
+            "Transaction must be at most {} bytes, found {}",
+            self.max_size_in_bytes,
+            self.size_in_bytes
+        );
+        // Ensure the number of functions or transitions is within the limit.
+        ensure!(
+            self.num_leaves <= self.max_leaves,
+            "Transaction must contain at most {} functions or transitions, found {}",
+            self.max_leaves,
+            self.num_leaves
+        );
+        // Ensure the fee amount is within the limit.
+        ensure!(self.fee_amount < self.max_fee_amount, "Transaction fee exceeds the maximum limit");
+        // Ensure the fee covers the minimum cost.
+        if self.is_fee_required {
+            if let Some(total_cost) = self.total_minimum_cost()? {
+                ensure!(
+                    self.fee_amount >= total_cost,
+                    "Transaction has an insufficient fee - expected at least {total_cost} microcredits"
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> Transaction<N> {
+    /// Returns the weight of the transaction.
+    pub fn weight(&self) -> Result<TransactionWeight> {
+        // Compute the size of the deployment or execution, and the number of leaves.
+        let (body_size_in_bytes, num_leaves) = match self {
+            Self::Deploy(_, _, deployment, _) => (deployment.size_in_bytes()?, deployment.program().functions().len()),
+            Self::Execute(_, execution, _) => (execution.size_in_bytes()?, execution.len()),
+            Self::Fee(..) => (0, 0),
+        };
+        // Compute the size of the fee.
+        let fee_size_in_bytes = match self.fee_transition() {
+            Some(fee) => u64::try_from(fee.to_bytes_le()?.len())?,
+            None => 0,
+        };
+        // If the transaction contains only 1 transition, and the transition is a split, then the fee can be skipped.
+        let is_fee_required = match self.execution() {
+            Some(execution) => !(execution.len() == 1 && self.contains_split()),
+            None => true,
+        };
+
+        Ok(TransactionWeight {
+            size_in_bytes: u64::try_from(self.to_bytes_le()?.len())?,
+            max_size_in_bytes: MAX_TRANSACTION_WIRE_PAYLOAD_SIZE as u64,
+            body_size_in_bytes,
+            fee_size_in_bytes,
+            num_leaves,
+            // Note: Observe we hold back 1 for the fee.
+            max_leaves: Self::MAX_TRANSITIONS - 1,
+            num_finalize: self.transitions().filter(|transition| transition.finalize().is_some()).count(),
+            fee_amount: *self.fee_amount()?,
+            max_fee_amount: N::MAX_FEE,
+            is_fee_required,
+            minimum_cost: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weight() {
+        let rng = &mut TestRng::default();
+
+        for transaction in [
+            crate::transaction::test_helpers::sample_deployment_transaction(true, rng),
+            crate::transaction::test_helpers::sample_execution_transaction_with_fee(true, rng),
+            crate::transaction::test_helpers::sample_fee_public_transaction(rng),
+        ] {
+            let weight = transaction.weight().unwrap();
+            // Ensure the sizes add up.
+            assert_eq!(weight.size_in_bytes, transaction.to_bytes_le().unwrap().len() as u64);
+            assert!(weight.body_size_in_bytes + weight.fee_size_in_bytes < weight.size_in_bytes);
+            assert_eq!(weight.fee_amount, *transaction.fee_amount().unwrap());
+            assert!(weight.check().is_ok());
+
+            // Ensure the fee must cover the minimum cost.
+            assert!(weight.with_minimum_cost(weight.fee_amount, 0).check().is_ok());
+            assert!(weight.with_minimum_cost(weight.fee_amount, 1).check().is_err());
+            assert!(weight.with_minimum_cost(u64::MAX, 1).check().is_err());
+
+            // Ensure the number of leaves is bounded.
+            let invalid = TransactionWeight { num_leaves: weight.max_leaves + 1, ..weight };
+            assert!(invalid.check().is_err());
+            // Ensure the size is bounded.
+            let invalid = TransactionWeight { size_in_bytes: weight.max_size_in_bytes + 1, ..weight };
+            assert!(invalid.check().is_err());
+        }
+    }
+}
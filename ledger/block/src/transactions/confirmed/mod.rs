@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod reason;
+pub use reason::*;
+
 mod bytes;
 mod serialize;
 mod string;
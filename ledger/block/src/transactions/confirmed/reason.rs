@@ -0,0 +1,328 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::program::{Identifier, ProgramID, Value};
+use synthesizer_program::Command;
+
+/// The reason a deployment or execution was rejected, when its `finalize` failed.
+///
+/// The rejection reason is not part of the block, and is recorded by each node as it finalizes the block.
+#[derive(Clone, PartialEq, Eq)]
+pub struct RejectionReason<N: Network> {
+    /// The program ID of the deployment, or of the transition that failed.
+    program_id: ProgramID<N>,
+    /// The function name of the transition that failed, if the rejection is for an execution.
+    function_name: Option<Identifier<N>>,
+    /// The index and the command that failed, if a finalize command failed.
+    command: Option<(u16, Command<N>)>,
+    /// The values of the operands of the command that failed.
+    operands: Vec<Value<N>>,
+    /// The error message.
+    error: String,
+}
+
+impl<N: Network> RejectionReason<N> {
+    /// The maximum number of bytes in the error message.
+    pub const MAX_ERROR_SIZE: usize = 1024;
+
+    /// Initializes a new rejection reason for a deployment of the given program.
+    pub fn new_deployment(program_id: ProgramID<N>, error: impl Display) -> Self {
+        Self { program_id, function_name: None, command: None, operands: Vec::new(), error: Self::truncate(error) }
+    }
+
+    /// Initializes a new rejection reason for an execution of the given function.
+    pub fn new_execution(program_id: ProgramID<N>, function_name: Identifier<N>, error: impl Display) -> Self {
+        Self {
+            program_id,
+            function_name: Some(function_name),
+            command: None,
+            operands: Vec::new(),
+            error: Self::truncate(error),
+        }
+    }
+
+    /// Returns the rejection reason with the given failed command, at the given index in the finalize scope,
+    /// and the values of its operands.
+    pub fn with_command(mut self, index: u16, command: Command<N>, operands: Vec<Value<N>>) -> Self {
+        self.command = Some((index, command));
+        self.operands = operands;
+        self
+    }
+
+    /// Returns the given error message, truncated to `MAX_ERROR_SIZE` bytes.
+    fn truncate(error: impl Display) -> String {
+        let mut error = error.to_string();
+        if error.len() > Self::MAX_ERROR_SIZE {
+            // Truncate the error message on a character boundary.
+            let index = (0..=Self::MAX_ERROR_SIZE).rev().find(|index| error.is_char_boundary(*index)).unwrap_or(0);
+            error.truncate(index);
+        }
+        error
+    }
+
+    /// Returns `true` if the rejection is for a deployment.
+    pub const fn is_deployment(&self) -> bool {
+        self.function_name.is_none()
+    }
+
+    /// Returns the program ID of the deployment, or of the transition that failed.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name of the transition that failed, if the rejection is for an execution.
+    pub const fn function_name(&self) -> Option<&Identifier<N>> {
+        self.function_name.as_ref()
+    }
+
+    /// Returns the index and the command that failed, if a finalize command failed.
+    pub fn command(&self) -> Option<(u16, &Command<N>)> {
+        self.command.as_ref().map(|(index, command)| (*index, command))
+    }
+
+    /// Returns the values of the operands of the command that failed.
+    pub fn operands(&self) -> &[Value<N>] {
+        &self.operands
+    }
+
+    /// Returns the error message.
+    pub fn error(&self) -> &str {
+        &self.error
+    }
+}
+
+impl<N: Network> FromBytes for RejectionReason<N> {
+    /// Reads the rejection reason from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid rejection reason version"));
+        }
+        // Read the program ID.
+        let program_id = ProgramID::read_le(&mut reader)?;
+        // Read the function name.
+        let function_name = match u8::read_le(&mut reader)? {
+            0 => None,
+            1 => Some(Identifier::read_le(&mut reader)?),
+            variant => return Err(error(format!("Invalid function name variant {variant} in a rejection reason"))),
+        };
+        // Read the command.
+        let command = match u8::read_le(&mut reader)? {
+            0 => None,
+            1 => Some((u16::read_le(&mut reader)?, Command::read_le(&mut reader)?)),
+            variant => return Err(error(format!("Invalid command variant {variant} in a rejection reason"))),
+        };
+        // Read the operands.
+        let num_operands = u8::read_le(&mut reader)?;
+        if num_operands as usize > N::MAX_OPERANDS {
+            return Err(error(format!("Too many operands ({num_operands}) in a rejection reason")));
+        }
+        let operands = (0..num_operands).map(|_| Value::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Read the error message.
+        let error_size = u16::read_le(&mut reader)? as usize;
+        if error_size > Self::MAX_ERROR_SIZE {
+            return Err(error(format!(
+                "The error message in a rejection reason exceeds {} bytes",
+                Self::MAX_ERROR_SIZE
+            )));
+        }
+        let mut bytes = vec![0u8; error_size];
+        reader.read_exact(&mut bytes)?;
+        let error_message = String::from_utf8(bytes).map_err(|e| error(e.to_string()))?;
+        // Return the rejection reason.
+        Ok(Self { program_id, function_name, command, operands, error: error_message })
+    }
+}
+
+impl<N: Network> ToBytes for RejectionReason<N> {
+    /// Writes the rejection reason to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the program ID.
+        self.program_id.write_le(&mut writer)?;
+        // Write the function name.
+        match &self.function_name {
+            None => 0u8.write_le(&mut writer)?,
+            Some(function_name) => {
+                1u8.write_le(&mut writer)?;
+                function_name.write_le(&mut writer)?;
+            }
+        }
+        // Write the command.
+        match &self.command {
+            None => 0u8.write_le(&mut writer)?,
+            Some((index, command)) => {
+                1u8.write_le(&mut writer)?;
+                index.write_le(&mut writer)?;
+                command.write_le(&mut writer)?;
+            }
+        }
+        // Write the operands.
+        u8::try_from(self.operands.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        for operand in &self.operands {
+            operand.write_le(&mut writer)?;
+        }
+        // Write the error message.
+        u16::try_from(self.error.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+        writer.write_all(self.error.as_bytes())
+    }
+}
+
+impl<N: Network> Serialize for RejectionReason<N> {
+    /// Serializes the rejection reason into a JSON object or bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match serializer.is_human_readable() {
+            true => {
+                let mut object = serializer.serialize_struct("RejectionReason", 6)?;
+                object.serialize_field("program_id", &self.program_id)?;
+                if let Some(function_name) = &self.function_name {
+                    object.serialize_field("function_name", function_name)?;
+                }
+                if let Some((index, command)) = &self.command {
+                    object.serialize_field("command_index", index)?;
+                    object.serialize_field("command", &command.to_string())?;
+                    object.serialize_field("operands", &self.operands)?;
+                }
+                object.serialize_field("error", &self.error)?;
+                object.end()
+            }
+            false => ToBytesSerializer::serialize_with_size_encoding(self, serializer),
+        }
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for RejectionReason<N> {
+    /// Deserializes the rejection reason from a JSON object or bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match deserializer.is_human_readable() {
+            true => {
+                let mut object = serde_json::Value::deserialize(deserializer)?;
+                // Parse the program ID.
+                let program_id: ProgramID<N> = DeserializeExt::take_from_value::<D>(&mut object, "program_id")?;
+                // Parse the error message.
+                let error: String = DeserializeExt::take_from_value::<D>(&mut object, "error")?;
+                // Parse the function name.
+                let mut reason = match object.get("function_name") {
+                    Some(_) => {
+                        let function_name = DeserializeExt::take_from_value::<D>(&mut object, "function_name")?;
+                        Self::new_execution(program_id, function_name, error)
+                    }
+                    None => Self::new_deployment(program_id, error),
+                };
+                // Parse the command.
+                if object.get("command").is_some() {
+                    let index: u16 = DeserializeExt::take_from_value::<D>(&mut object, "command_index")?;
+                    let command: String = DeserializeExt::take_from_value::<D>(&mut object, "command")?;
+                    let command = Command::from_str(&command).map_err(de::Error::custom)?;
+                    let operands: Vec<Value<N>> = DeserializeExt::take_from_value::<D>(&mut object, "operands")?;
+                    reason = reason.with_command(index, command, operands);
+                }
+                Ok(reason)
+            }
+            false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "rejection reason"),
+        }
+    }
+}
+
+impl<N: Network> Debug for RejectionReason<N> {
+    /// Prints the rejection reason.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for RejectionReason<N> {
+    /// Displays the rejection reason, as an error message.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match &self.function_name {
+            Some(function_name) => write!(f, "'finalize' failed on '{}/{function_name}'", self.program_id)?,
+            None => write!(f, "'finalize' failed on the deployment of '{}'", self.program_id)?,
+        }
+        if let Some((index, command)) = &self.command {
+            write!(f, " at command {index} ({command})")?;
+            if !self.operands.is_empty() {
+                let operands = self.operands.iter().map(|operand| operand.to_string()).collect::<Vec<_>>();
+                write!(f, " with operands [{}]", operands.join(", "))?;
+            }
+        }
+        write!(f, " - {}", self.error)
+    }
+}
+
+impl<N: Network> std::error::Error for RejectionReason<N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    fn sample_rejection_reasons() -> Vec<RejectionReason<CurrentNetwork>> {
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        let function_name = Identifier::from_str("transfer").unwrap();
+        let command = Command::from_str("sub r0 r1 into r2;").unwrap();
+        let operands = vec![Value::from_str("1u64").unwrap(), Value::from_str("2u64").unwrap()];
+        vec![
+            RejectionReason::new_deployment(program_id, "Program already exists"),
+            RejectionReason::new_execution(program_id, function_name, "Missing inputs"),
+            RejectionReason::new_execution(program_id, function_name, "Integer underflow")
+                .with_command(3, command, operands),
+        ]
+    }
+
+    #[test]
+    fn test_bytes() {
+        for expected in sample_rejection_reasons() {
+            let expected_bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, RejectionReason::read_le(&expected_bytes[..]).unwrap());
+            assert!(RejectionReason::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_serde_json() {
+        for expected in sample_rejection_reasons() {
+            let candidate_string = serde_json::to_string(&expected).unwrap();
+            assert_eq!(expected, serde_json::from_str(&candidate_string).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let reasons = sample_rejection_reasons();
+        assert_eq!(
+            reasons[0].to_string(),
+            "'finalize' failed on the deployment of 'token.aleo' - Program already exists"
+        );
+        assert_eq!(
+            reasons[2].to_string(),
+            "'finalize' failed on 'token.aleo/transfer' at command 3 (sub r0 r1 into r2;) with operands [1u64, 2u64] - Integer underflow"
+        );
+    }
+
+    #[test]
+    fn test_truncate() {
+        let program_id = ProgramID::<CurrentNetwork>::from_str("token.aleo").unwrap();
+        let reason =
+            RejectionReason::new_deployment(program_id, "é".repeat(RejectionReason::<CurrentNetwork>::MAX_ERROR_SIZE));
+        assert!(reason.error().len() <= RejectionReason::<CurrentNetwork>::MAX_ERROR_SIZE);
+        assert_eq!(reason, RejectionReason::read_le(&reason.to_bytes_le().unwrap()[..]).unwrap());
+    }
+}
@@ -17,12 +17,15 @@ use super::*;
 impl<N: Network> Transactions<N> {
     /// Returns the finalize root of the transactions.
     pub fn to_finalize_root(&self) -> Result<Field<N>> {
+        Ok(*self.to_finalize_tree()?.root())
+    }
+
+    /// The Merkle tree of finalize operations for the block.
+    pub fn to_finalize_tree(&self) -> Result<FinalizeTree<N>> {
         // Prepare the leaves.
         let leaves = self.finalize_operations().map(|op| op.to_bits_le());
         // Compute the finalize tree.
-        let tree = N::merkle_tree_bhp::<FINALIZE_OPERATIONS_DEPTH>(&leaves.collect::<Vec<_>>())?;
-        // Return the finalize root.
-        Ok(*tree.root())
+        N::merkle_tree_bhp::<FINALIZE_OPERATIONS_DEPTH>(&leaves.collect::<Vec<_>>())
     }
 }
 
@@ -28,6 +28,7 @@ use console::{
     network::prelude::*,
     program::{
         Ciphertext,
+        FinalizeTree,
         ProgramOwner,
         Record,
         TransactionsPath,
@@ -158,8 +159,11 @@ impl<N: Network> Transactions<N> {
 }
 
 impl<N: Network> Transactions<N> {
-    /// The maximum number of transactions allowed in a block.
-    pub const MAX_TRANSACTIONS: usize = usize::pow(2, TRANSACTIONS_DEPTH as u32);
+    /// The maximum number of transactions allowed in a block, capped by the capacity of the transactions tree.
+    pub const MAX_TRANSACTIONS: usize = match N::MAX_TRANSACTIONS_PER_BLOCK < usize::pow(2, TRANSACTIONS_DEPTH as u32) {
+        true => N::MAX_TRANSACTIONS_PER_BLOCK,
+        false => usize::pow(2, TRANSACTIONS_DEPTH as u32),
+    };
 
     /// Returns an iterator over all transactions, for all transactions in `self`.
     pub fn iter(&self) -> impl '_ + ExactSizeIterator<Item = &ConfirmedTransaction<N>> {
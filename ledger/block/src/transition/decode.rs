@@ -0,0 +1,275 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+use console::{
+    account::ViewKey,
+    program::{Plaintext, ValueType},
+    types::U16,
+};
+use synthesizer_program::ProgramManifest;
+
+/// A transition decoded against the manifest of its program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedTransition<N: Network> {
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The decoded inputs.
+    inputs: Vec<DecodedArgument<N>>,
+    /// The decoded outputs.
+    outputs: Vec<DecodedArgument<N>>,
+}
+
+impl<N: Network> DecodedTransition<N> {
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the decoded inputs.
+    pub fn inputs(&self) -> &[DecodedArgument<N>] {
+        &self.inputs
+    }
+
+    /// Returns the decoded outputs.
+    pub fn outputs(&self) -> &[DecodedArgument<N>] {
+        &self.outputs
+    }
+}
+
+impl<N: Network> Display for DecodedTransition<N> {
+    /// Prints the transition as a call, e.g. `credits.aleo/transfer_public(aleo1..., 10u64) -> ()`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let inputs = self.inputs.iter().map(|input| input.value.to_string()).join(", ");
+        let outputs = self.outputs.iter().map(|output| output.value.to_string()).join(", ");
+        write!(f, "{}/{}({inputs}) -> ({outputs})", self.program_id, self.function_name)
+    }
+}
+
+/// A named and typed transition input or output.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedArgument<N: Network> {
+    /// The name of the argument, from the manifest.
+    name: String,
+    /// The value type of the argument, from the manifest.
+    value_type: ValueType<N>,
+    /// The decoded value.
+    value: DecodedValue<N>,
+}
+
+impl<N: Network> DecodedArgument<N> {
+    /// Returns the name of the argument.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the value type of the argument.
+    pub const fn value_type(&self) -> &ValueType<N> {
+        &self.value_type
+    }
+
+    /// Returns the decoded value.
+    pub const fn value(&self) -> &DecodedValue<N> {
+        &self.value
+    }
+}
+
+/// The value of a decoded transition input or output.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecodedValue<N: Network> {
+    /// A plaintext value, which is either public or decrypted.
+    Plaintext(Plaintext<N>),
+    /// A record, decrypted with the view key of its owner.
+    Record(Record<N, Plaintext<N>>),
+    /// A value that is not visible, with its input or output ID.
+    Hidden(Field<N>),
+}
+
+impl<N: Network> Display for DecodedValue<N> {
+    /// Prints the decoded value, or `<hidden>` if it is not visible.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Plaintext(plaintext) => Display::fmt(plaintext, f),
+            Self::Record(record) => Display::fmt(record, f),
+            Self::Hidden(..) => write!(f, "<hidden>"),
+        }
+    }
+}
+
+impl<N: Network> Transition<N> {
+    /// Decodes the transition against the manifest of its program.
+    ///
+    /// Constant and public values are always decoded. If a view key is given, then private values
+    /// are decrypted if the view key belongs to the caller, and records are decrypted if the view key
+    /// belongs to their owner. All other values are returned as `DecodedValue::Hidden`.
+    pub fn decode(&self, manifest: &ProgramManifest<N>, view_key: Option<&ViewKey<N>>) -> Result<DecodedTransition<N>> {
+        // Ensure the manifest matches the transition.
+        ensure!(
+            manifest.program_id() == &self.program_id,
+            "Expected the manifest of '{}', found '{}'",
+            self.program_id,
+            manifest.program_id()
+        );
+        let function = manifest.get_function(&self.function_name)?;
+        ensure!(
+            function.inputs().len() == self.inputs.len() && function.outputs().len() == self.outputs.len(),
+            "The transition does not match the signature of '{}/{}'",
+            self.program_id,
+            self.function_name
+        );
+
+        // Compute the transition view key, if the view key belongs to the caller.
+        let tvk = match view_key {
+            Some(view_key) => {
+                let tvk = (self.tpk * **view_key).to_x_coordinate();
+                // Ensure the transition view key matches the transition commitment.
+                match N::hash_psd2(&[tvk])? == self.tcm {
+                    true => Some(tvk),
+                    false => None,
+                }
+            }
+            None => None,
+        };
+        // Compute the function ID as `Hash(network_id, program_id, function_name)`.
+        let function_id = N::hash_bhp1024(
+            &(U16::<N>::new(N::ID), self.program_id.name(), self.program_id.network(), self.function_name).to_bits_le(),
+        )?;
+        // Decrypts the ciphertext at the given index, if the transition view key is known.
+        let decrypt = |id: &Field<N>, ciphertext: &Option<Ciphertext<N>>, index: usize| -> Result<DecodedValue<N>> {
+            match (tvk, ciphertext) {
+                (Some(tvk), Some(ciphertext)) => {
+                    let index = Field::from_u16(u16::try_from(index)?);
+                    let plaintext_view_key = N::hash_psd4(&[function_id, tvk, index])?;
+                    Ok(DecodedValue::Plaintext(ciphertext.decrypt_symmetric(plaintext_view_key)?))
+                }
+                _ => Ok(DecodedValue::Hidden(*id)),
+            }
+        };
+
+        // Decode the inputs.
+        let inputs = self
+            .inputs
+            .iter()
+            .zip_eq(function.inputs())
+            .enumerate()
+            .map(|(index, (input, parameter))| {
+                let value = match input {
+                    Input::Constant(_, Some(plaintext)) | Input::Public(_, Some(plaintext)) => {
+                        DecodedValue::Plaintext(plaintext.clone())
+                    }
+                    Input::Private(id, ciphertext) => decrypt(id, ciphertext, index)?,
+                    _ => DecodedValue::Hidden(*input.id()),
+                };
+                Ok(DecodedArgument {
+                    name: parameter.name().to_string(),
+                    value_type: parameter.value_type().clone(),
+                    value,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Decode the outputs.
+        let outputs = self
+            .outputs
+            .iter()
+            .zip_eq(function.outputs())
+            .enumerate()
+            .map(|(index, (output, parameter))| {
+                let value = match (output, view_key) {
+                    (Output::Constant(_, Some(plaintext)), _) | (Output::Public(_, Some(plaintext)), _) => {
+                        DecodedValue::Plaintext(plaintext.clone())
+                    }
+                    (Output::Private(id, ciphertext), _) => decrypt(id, ciphertext, self.inputs.len() + index)?,
+                    (Output::Record(_, _, Some(record)), Some(view_key)) if record.is_owner(view_key) => {
+                        DecodedValue::Record(record.decrypt(view_key)?)
+                    }
+                    _ => DecodedValue::Hidden(*output.id()),
+                };
+                Ok(DecodedArgument {
+                    name: parameter.name().to_string(),
+                    value_type: parameter.value_type().clone(),
+                    value,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(DecodedTransition { program_id: self.program_id, function_name: self.function_name, inputs, outputs })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::account::{Address, PrivateKey};
+    use synthesizer_program::Program;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_decode() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis transition, which calls `credits.aleo/transfer_public_to_private`.
+        let (_, transaction, private_key) = crate::test_helpers::sample_genesis_block_and_components(rng);
+        let transition = transaction.transitions().next().unwrap();
+        let view_key = ViewKey::try_from(&private_key)?;
+        let address = Address::try_from(&private_key)?;
+        let manifest = ProgramManifest::from_program(&Program::<CurrentNetwork>::credits()?);
+
+        // Decode the transition with the view key of the caller.
+        let decoded = transition.decode(&manifest, Some(&view_key))?;
+        assert_eq!(decoded.function_name().to_string(), "transfer_public_to_private");
+        assert_eq!(decoded.inputs()[0].name(), "r0");
+        assert_eq!(decoded.inputs()[0].value(), &DecodedValue::Plaintext(Plaintext::from_str(&address.to_string())?));
+        assert_eq!(decoded.inputs()[1].value(), &DecodedValue::Plaintext(Plaintext::from_str("100000000u64")?));
+        match decoded.outputs()[0].value() {
+            DecodedValue::Record(record) => assert_eq!(**record.owner(), address),
+            value => panic!("Expected a decrypted record, found {value}"),
+        }
+        assert!(decoded.to_string().starts_with(&format!("credits.aleo/transfer_public_to_private({address}, ")));
+
+        // Decode the transition without a view key.
+        let decoded = transition.decode(&manifest, None)?;
+        assert!(matches!(decoded.inputs()[0].value(), DecodedValue::Hidden(..)));
+        assert_eq!(decoded.inputs()[1].value(), &DecodedValue::Plaintext(Plaintext::from_str("100000000u64")?));
+        assert!(matches!(decoded.outputs()[0].value(), DecodedValue::Hidden(..)));
+
+        // Decode the transition with the view key of another account.
+        let other = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng)?)?;
+        let decoded = transition.decode(&manifest, Some(&other))?;
+        assert!(matches!(decoded.inputs()[0].value(), DecodedValue::Hidden(..)));
+        assert!(matches!(decoded.outputs()[0].value(), DecodedValue::Hidden(..)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_rejects_mismatched_manifest() -> Result<()> {
+        let rng = &mut TestRng::default();
+        let (_, transaction, _) = crate::test_helpers::sample_genesis_block_and_components(rng);
+        let transition = transaction.transitions().next().unwrap();
+
+        // Ensure the manifest of another program is rejected.
+        let program = Program::<CurrentNetwork>::from_str(
+            "program other.aleo;\n\nfunction transfer_public_to_private:\n    input r0 as u64.public;",
+        )?;
+        assert!(transition.decode(&ProgramManifest::from_program(&program), None).is_err());
+        Ok(())
+    }
+}
@@ -18,6 +18,9 @@ pub use input::Input;
 pub mod output;
 pub use output::Output;
 
+mod decode;
+pub use decode::*;
+
 mod bytes;
 mod merkle;
 mod serialize;
@@ -216,7 +216,7 @@ impl<N: Network> Block<N> {
         let height = self.height();
 
         // Ensure there are sufficient ratifications.
-        ensure!(!self.ratifications.len() >= 2, "Block {height} must contain at least 2 ratifications");
+        ensure!(self.ratifications.len() >= 2, "Block {height} must contain at least 2 ratifications");
 
         // Retrieve the block reward from the first block ratification.
         let block_reward = match self.ratifications[0] {
@@ -228,6 +228,17 @@ impl<N: Network> Block<N> {
             Ratify::PuzzleReward(puzzle_reward) => puzzle_reward,
             _ => bail!("Block {height} is invalid - the second ratification must be a puzzle reward"),
         };
+        // Ensure the block does not contain any other ratifications.
+        if let Some(ratify) = self.ratifications.get(2) {
+            match ratify {
+                Ratify::Genesis(..) => {
+                    bail!("Block {height} is invalid - only the genesis block may ratify the genesis")
+                }
+                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => {
+                    bail!("Block {height} is invalid - it contains more than one Ratify::{}(..)", ratify.kind())
+                }
+            }
+        }
 
         // Ensure the block reward is correct.
         ensure!(
@@ -509,3 +520,47 @@ impl<N: Network> Block<N> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_ratifications() {
+        let rng = &mut TestRng::default();
+
+        // Sample a block, and replace its ratifications.
+        let block = crate::test_helpers::sample_genesis_block(rng);
+        let with_ratifications = |ratifications| {
+            Block::from_unchecked(
+                block.hash(),
+                block.previous_hash(),
+                *block.header(),
+                block.authority().clone(),
+                block.transactions().clone(),
+                ratifications,
+                None,
+            )
+            .unwrap()
+        };
+
+        // Ensure the block and puzzle rewards are verified.
+        let block_reward = Ratify::BlockReward(1);
+        let puzzle_reward = Ratify::PuzzleReward(2);
+        with_ratifications(vec![block_reward.clone(), puzzle_reward.clone()]).verify_ratifications(1, 2).unwrap();
+        assert!(with_ratifications(vec![block_reward.clone(), puzzle_reward.clone()])
+            .verify_ratifications(1, 3)
+            .is_err());
+        assert!(with_ratifications(vec![puzzle_reward.clone(), block_reward.clone()])
+            .verify_ratifications(1, 2)
+            .is_err());
+        assert!(with_ratifications(vec![block_reward.clone()]).verify_ratifications(1, 2).is_err());
+
+        // Ensure an additional ratification is rejected.
+        let genesis = block.ratifications()[0].clone();
+        for extra in [block_reward.clone(), puzzle_reward.clone(), genesis] {
+            let block = with_ratifications(vec![block_reward.clone(), puzzle_reward.clone(), extra]);
+            assert!(block.verify_ratifications(1, 2).is_err());
+        }
+    }
+}
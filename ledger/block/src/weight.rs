@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The weight of a block, measured against the consensus limits.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BlockWeight {
+    /// The number of bytes in the block.
+    pub size_in_bytes: u64,
+    /// The maximum number of bytes in the block.
+    pub max_size_in_bytes: u64,
+    /// The number of transactions.
+    pub num_transactions: usize,
+    /// The maximum number of transactions.
+    pub max_transactions: usize,
+    /// The number of prover solutions.
+    pub num_prover_solutions: usize,
+    /// The maximum number of prover solutions.
+    pub max_prover_solutions: usize,
+    /// The number of ratifications.
+    pub num_ratifications: usize,
+    /// The total fee amount of the transactions, in microcredits.
+    pub total_fee_amount: u64,
+    /// The weight of each transaction, in block order.
+    pub transactions: Vec<TransactionWeight>,
+}
+
+impl BlockWeight {
+    /// Ensures the weight is within the consensus limits, including the limits of each transaction.
+    pub fn check(&self) -> Result<()> {
+        // Ensure the size is within the limit.
+        ensure!(
+            self.size_in_bytes <= self.max_size_in_bytes,
+            "Block must be at most {} bytes, found {}",
+            self.max_size_in_bytes,
+            self.size_in_bytes
+        );
+        // Ensure the number of transactions is within the limit.
+        ensure!(
+            self.num_transactions <= self.max_transactions,
+            "Block must contain at most {} transactions, found {}",
+            self.max_transactions,
+            self.num_transactions
+        );
+        // Ensure the number of prover solutions is within the limit.
+        ensure!(
+            self.num_prover_solutions <= self.max_prover_solutions,
+            "Block must contain at most {} prover solutions, found {}",
+            self.max_prover_solutions,
+            self.num_prover_solutions
+        );
+        // Ensure each transaction is within the limits.
+        self.transactions.iter().try_for_each(TransactionWeight::check)
+    }
+}
+
+impl<N: Network> Block<N> {
+    /// Returns the weight of the block.
+    pub fn weight(&self) -> Result<BlockWeight> {
+        // Compute the weight of each transaction.
+        let transactions =
+            self.transactions.iter().map(|confirmed| confirmed.transaction().weight()).collect::<Result<Vec<_>>>()?;
+        // Compute the total fee amount.
+        let total_fee_amount = transactions.iter().try_fold(0u64, |total, weight| {
+            total.checked_add(weight.fee_amount).ok_or_else(|| anyhow!("The total fee amount overflowed for a block"))
+        })?;
+
+        Ok(BlockWeight {
+            size_in_bytes: u64::try_from(self.to_bytes_le()?.len())?,
+            max_size_in_bytes: MAX_BLOCK_WIRE_PAYLOAD_SIZE as u64,
+            num_transactions: self.transactions.len(),
+            max_transactions: Transactions::<N>::MAX_TRANSACTIONS,
+            num_prover_solutions: self.coinbase.as_ref().map_or(0, |coinbase| coinbase.len()),
+            max_prover_solutions: N::MAX_PROVER_SOLUTIONS,
+            num_ratifications: self.ratifications.len(),
+            total_fee_amount,
+            transactions,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weight() {
+        let rng = &mut TestRng::default();
+
+        let block = crate::test_helpers::sample_genesis_block(rng);
+        let weight = block.weight().unwrap();
+        assert_eq!(weight.size_in_bytes, block.to_bytes_le().unwrap().len() as u64);
+        assert_eq!(weight.num_transactions, block.transactions().len());
+        assert_eq!(weight.transactions.len(), weight.num_transactions);
+        assert_eq!(weight.num_ratifications, block.ratifications().len());
+        assert!(weight.check().is_ok());
+
+        // Ensure the number of transactions is bounded.
+        let invalid = BlockWeight { num_transactions: weight.max_transactions + 1, ..weight.clone() };
+        assert!(invalid.check().is_err());
+
+        // Ensure the number of prover solutions is bounded.
+        let invalid = BlockWeight { num_prover_solutions: weight.max_prover_solutions + 1, ..weight.clone() };
+        assert!(invalid.check().is_err());
+
+        // Ensure the size is bounded.
+        let invalid = BlockWeight { size_in_bytes: weight.max_size_in_bytes + 1, ..weight };
+        assert!(invalid.check().is_err());
+    }
+}
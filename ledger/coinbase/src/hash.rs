@@ -13,12 +13,17 @@
 // limitations under the License.
 
 use console::prelude::{bail, cfg_into_iter, ensure, Result, Zero};
-use snarkvm_algorithms::{fft::DensePolynomial, polycommit::kzg10::KZGCommitment};
+use snarkvm_algorithms::{
+    fft::DensePolynomial,
+    polycommit::kzg10::{KZGCommitment, KZGProof},
+};
 use snarkvm_curves::PairingEngine;
 use snarkvm_fields::PrimeField;
-use snarkvm_utilities::CanonicalSerialize;
+use snarkvm_utilities::{CanonicalSerialize, ToBytes};
 
 use blake2::Digest;
+use rand::SeedableRng;
+use rand_chacha::ChaChaRng;
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -77,3 +82,73 @@ pub fn hash_commitments<E: PairingEngine>(
     // Hash the commitment bytes into coefficients.
     Ok(hash_to_coefficients(&bytes, num_commitments + 1))
 }
+
+/// Returns an RNG seeded by the domain-separated hash of the given addresses, commitments, challenge points,
+/// claimed values, and proofs, for sampling the randomizers of a batched proof check.
+pub fn hash_to_rng<E: PairingEngine, A: ToBytes>(
+    addresses: &[A],
+    commitments: &[KZGCommitment<E>],
+    points: &[E::Fr],
+    values: &[E::Fr],
+    proofs: &[KZGProof<E>],
+) -> Result<ChaChaRng> {
+    // Ensure there is one address, commitment, point, value, and proof per solution.
+    let num_solutions = commitments.len();
+    ensure!(
+        [addresses.len(), points.len(), values.len(), proofs.len()].iter().all(|length| *length == num_solutions),
+        "Mismatching number of inputs for hashing to an RNG"
+    );
+
+    // Convert the domain separator, the number of solutions, and the inputs into bytes.
+    let mut bytes = Vec::with_capacity(32 + 256 * num_solutions);
+    bytes.extend_from_slice(b"AleoCoinbasePuzzleBatchCheck");
+    u32::try_from(num_solutions)?.write_le(&mut bytes)?;
+    for address in addresses {
+        address.write_le(&mut bytes)?;
+    }
+    for commitment in commitments {
+        commitment.serialize_uncompressed(&mut bytes)?;
+    }
+    for (point, value) in points.iter().zip(values) {
+        point.serialize_uncompressed(&mut bytes)?;
+        value.serialize_uncompressed(&mut bytes)?;
+    }
+    for proof in proofs {
+        proof.serialize_uncompressed(&mut bytes)?;
+    }
+    // Seed the RNG with the hash of the bytes.
+    Ok(ChaChaRng::from_seed(blake2::Blake2s256::digest(&bytes).into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr, G1Affine};
+    use snarkvm_utilities::{TestRng, Uniform};
+
+    use rand::RngCore;
+
+    #[test]
+    fn test_hash_to_rng() {
+        let rng = &mut TestRng::default();
+
+        let addresses = [[1u8; 32]];
+        let commitments = [KZGCommitment::<Bls12_377>(G1Affine::rand(rng))];
+        let points = [Fr::rand(rng)];
+        let values = [Fr::rand(rng)];
+        let proofs = [KZGProof::<Bls12_377> { w: G1Affine::rand(rng), random_v: None }];
+        let sample = |addresses: &[[u8; 32]], points: &[Fr], values: &[Fr]| {
+            hash_to_rng(addresses, &commitments, points, values, &proofs).unwrap().next_u64()
+        };
+
+        // Ensure the RNG is deterministic.
+        let expected = sample(&addresses, &points, &values);
+        assert_eq!(sample(&addresses, &points, &values), expected);
+        // Ensure the RNG is bound to the addresses, the points, and the values.
+        assert_ne!(sample(&[[2u8; 32]], &points, &values), expected);
+        assert_ne!(sample(&addresses, &[Fr::rand(rng)], &values), expected);
+        assert_ne!(sample(&addresses, &points, &[Fr::rand(rng)]), expected);
+        // Ensure the inputs must have matching lengths.
+        assert!(hash_to_rng(&addresses, &commitments, &[], &values, &proofs).is_err());
+    }
+}
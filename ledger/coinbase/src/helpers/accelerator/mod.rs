@@ -0,0 +1,32 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A hook to offload the verification of a batch of puzzle proofs to an external accelerator (e.g. a GPU).
+///
+/// The accelerator must perform the same check as `KZG10::batch_check`, namely that each `proofs[i]`
+/// is a valid evaluation proof for `commitments[i]` at `points[i]` with value `values[i]`.
+pub trait PuzzleAccelerator<N: Network>: Send + Sync {
+    /// Returns `Some(true)` if all of the proofs are valid, and `Some(false)` otherwise.
+    /// Returns `None` if the accelerator declines the batch, in which case the batch is verified on the CPU.
+    fn batch_check(
+        &self,
+        verifying_key: &CoinbaseVerifyingKey<N>,
+        commitments: &[KZGCommitment<N::PairingCurve>],
+        points: &[<N::PairingCurve as PairingEngine>::Fr],
+        values: &[<N::PairingCurve as PairingEngine>::Fr],
+        proofs: &[PuzzleProof<N>],
+    ) -> Result<Option<bool>>;
+}
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod accelerator;
+pub use accelerator::*;
+
 mod coinbase_solution;
 pub use coinbase_solution::*;
 
@@ -27,6 +30,9 @@ pub use prover_solution::*;
 mod puzzle_commitment;
 pub use puzzle_commitment::*;
 
+mod target;
+pub use target::*;
+
 use crate::{hash_commitment, hash_commitments, CoinbasePuzzle};
 use console::{account::Address, prelude::*, types::Field};
 use snarkvm_algorithms::{
@@ -0,0 +1,465 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use core::marker::PhantomData;
+
+/// Calculate the coinbase target for the given block timestamps and target.
+pub fn coinbase_target(
+    previous_target: u64,
+    previous_block_timestamp: i64,
+    block_timestamp: i64,
+    anchor_time: u16,
+    num_blocks_per_epoch: u32,
+    genesis_target: u64,
+) -> Result<u64> {
+    // Compute the half life.
+    let half_life = num_blocks_per_epoch.saturating_div(2).saturating_mul(anchor_time as u32);
+    // Compute the new coinbase target.
+    let candidate_target =
+        retarget(previous_target, previous_block_timestamp, block_timestamp, anchor_time, half_life, true)?;
+    // Return the new coinbase target, floored at the genesis target.
+    Ok(candidate_target.max(genesis_target))
+}
+
+/// Calculate the minimum proof target for the given coinbase target.
+pub fn proof_target(coinbase_target: u64, genesis_proof_target: u64) -> u64 {
+    coinbase_target.checked_shr(7).map(|target| target.saturating_add(1)).unwrap_or(genesis_proof_target)
+}
+
+/// Retarget algorithm using fixed point arithmetic from https://www.reference.cash/protocol/forks/2020-11-15-asert.
+///     T_{i+1} = T_i * 2^(INV * (D - A) / TAU).
+///     T_i = Current target.
+///     D = Drift, defined as the number of blocks elapsed.
+///     A = Anchor timestamp, defined as expected number of seconds elapsed.
+///     TAU = Rate of doubling (or half-life) in seconds.
+///     INV = {-1, 1} depending on whether the target is increasing or decreasing.
+fn retarget(
+    previous_target: u64,
+    previous_block_timestamp: i64,
+    block_timestamp: i64,
+    anchor_time: u16,
+    half_life: u32,
+    is_inverse: bool,
+) -> Result<u64> {
+    // Determine the block time elapsed (in seconds) since the previous block.
+    // Note: This operation includes a safety check for a repeat block timestamp.
+    let block_time_elapsed = block_timestamp.saturating_sub(previous_block_timestamp).max(1);
+    // Compute the drift.
+    let mut drift = block_time_elapsed.saturating_sub(anchor_time as i64);
+
+    // If the drift is zero, return the previous target.
+    if drift == 0 {
+        return Ok(previous_target);
+    }
+
+    // Negate the drift if the inverse flag is set.
+    if is_inverse {
+        drift *= -1;
+    }
+
+    // Constants used for fixed point arithmetic.
+    const RBITS: u32 = 16;
+    const RADIX: u128 = 1 << RBITS;
+
+    // Compute the exponent factor, and decompose it into integral & fractional parts for fixed point arithmetic.
+    let (integral, fractional) = {
+        // Calculate the exponent factor.
+        let exponent = (RADIX as i128).saturating_mul(drift as i128) / half_life as i128;
+
+        // Decompose into the integral and fractional parts.
+        let integral = exponent >> RBITS;
+        let fractional = (exponent - (integral << RBITS)) as u128;
+        ensure!(fractional < RADIX, "Fractional part is not within the fixed point size");
+        ensure!(exponent == (integral * (RADIX as i128) + fractional as i128), "Exponent is decomposed incorrectly");
+
+        (integral, fractional)
+    };
+
+    // Approximate the fractional multiplier as 2^RBITS * 2^fractional, where:
+    // 2^x ~= (1 + 0.695502049*x + 0.2262698*x**2 + 0.0782318*x**3)
+    let fractional_multiplier = RADIX
+        + ((195_766_423_245_049_u128 * fractional
+            + 971_821_376_u128 * fractional.pow(2)
+            + 5_127_u128 * fractional.pow(3)
+            + 2_u128.pow(RBITS * 3 - 1))
+            >> (RBITS * 3));
+
+    // Cast the previous coinbase target from a u64 to a u128.
+    // The difficulty target must allow for leading zeros to account for overflows;
+    // an additional 64-bits for the leading zeros suffices.
+    let candidate_target = (previous_target as u128).saturating_mul(fractional_multiplier);
+
+    // Calculate the new difficulty.
+    // Shift the target to multiply by 2^(integer) / RADIX.
+    let shifts = integral - RBITS as i128;
+    let mut candidate_target = if shifts < 0 {
+        match candidate_target.checked_shr(u32::try_from(-shifts)?) {
+            Some(target) => core::cmp::max(target, 1),
+            None => 1,
+        }
+    } else {
+        match candidate_target.checked_shl(u32::try_from(shifts)?) {
+            Some(target) => core::cmp::max(target, 1),
+            None => u64::MAX as u128,
+        }
+    };
+
+    // Cap the target at `u64::MAX` if it has overflowed.
+    candidate_target = core::cmp::min(candidate_target, u64::MAX as u128);
+
+    // Ensure that the leading 64 bits are zeros.
+    ensure!(candidate_target.checked_shr(64) == Some(0), "The target has overflowed");
+    // Cast the new target down from a u128 to a u64.
+    Ok(u64::try_from(candidate_target)?)
+}
+
+/// A deterministic simulator of the coinbase target and proof target of each block,
+/// which retargets exactly as consensus does, for forecasting the difficulty.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TargetSimulator<N: Network> {
+    /// The coinbase target of the latest block.
+    coinbase_target: u64,
+    /// The proof target of the latest block.
+    proof_target: u64,
+    /// The coinbase target of the last block that reached the coinbase target.
+    last_coinbase_target: u64,
+    /// The timestamp of the last block that reached the coinbase target.
+    last_coinbase_timestamp: i64,
+    /// The cumulative proof target of the latest block.
+    cumulative_proof_target: u128,
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network> TargetSimulator<N> {
+    /// Initializes a new simulator from the targets of the latest block.
+    pub const fn new(
+        coinbase_target: u64,
+        proof_target: u64,
+        last_coinbase_target: u64,
+        last_coinbase_timestamp: i64,
+        cumulative_proof_target: u128,
+    ) -> Self {
+        Self {
+            coinbase_target,
+            proof_target,
+            last_coinbase_target,
+            last_coinbase_timestamp,
+            cumulative_proof_target,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Initializes a new simulator from the genesis targets, at the given genesis timestamp.
+    pub const fn genesis(timestamp: i64) -> Self {
+        Self::new(N::GENESIS_COINBASE_TARGET, N::GENESIS_PROOF_TARGET, N::GENESIS_COINBASE_TARGET, timestamp, 0)
+    }
+
+    /// Returns the coinbase target of the latest block.
+    pub const fn coinbase_target(&self) -> u64 {
+        self.coinbase_target
+    }
+
+    /// Returns the proof target of the latest block.
+    pub const fn proof_target(&self) -> u64 {
+        self.proof_target
+    }
+
+    /// Returns the coinbase target of the last block that reached the coinbase target.
+    pub const fn last_coinbase_target(&self) -> u64 {
+        self.last_coinbase_target
+    }
+
+    /// Returns the timestamp of the last block that reached the coinbase target.
+    pub const fn last_coinbase_timestamp(&self) -> i64 {
+        self.last_coinbase_timestamp
+    }
+
+    /// Returns the cumulative proof target of the latest block.
+    pub const fn cumulative_proof_target(&self) -> u128 {
+        self.cumulative_proof_target
+    }
+
+    /// Advances the simulator to the next block, with the given timestamp and combined proof target
+    /// of its solutions, and returns the `(coinbase target, proof target)` of the next block.
+    pub fn next(&mut self, timestamp: i64, combined_proof_target: u128) -> Result<(u64, u64)> {
+        // Determine if the coinbase target is reached.
+        let cumulative_proof_target = self.cumulative_proof_target.saturating_add(combined_proof_target);
+        let is_coinbase_target_reached = cumulative_proof_target >= self.coinbase_target as u128;
+
+        // Compute the next coinbase target and proof target.
+        let coinbase_target = coinbase_target(
+            self.last_coinbase_target,
+            self.last_coinbase_timestamp,
+            timestamp,
+            N::ANCHOR_TIME,
+            N::NUM_BLOCKS_PER_EPOCH,
+            N::GENESIS_COINBASE_TARGET,
+        )?;
+        let proof_target = proof_target(coinbase_target, N::GENESIS_PROOF_TARGET);
+
+        // Update the state, resetting the cumulative proof target if the coinbase target was reached.
+        self.coinbase_target = coinbase_target;
+        self.proof_target = proof_target;
+        self.cumulative_proof_target = match is_coinbase_target_reached {
+            true => 0,
+            false => cumulative_proof_target,
+        };
+        if is_coinbase_target_reached {
+            self.last_coinbase_target = coinbase_target;
+            self.last_coinbase_timestamp = timestamp;
+        }
+        Ok((coinbase_target, proof_target))
+    }
+
+    /// Advances the simulator over the given `(timestamp, combined proof target)` of each block,
+    /// and returns the `(coinbase target, proof target)` of each block.
+    pub fn simulate(&mut self, blocks: impl IntoIterator<Item = (i64, u128)>) -> Result<Vec<(u64, u64)>> {
+        blocks
+            .into_iter()
+            .map(|(timestamp, combined_proof_target)| self.next(timestamp, combined_proof_target))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::{prelude::*, Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u32 = 1000;
+
+    #[test]
+    fn test_targets() {
+        let mut rng = TestRng::default();
+
+        let minimum_coinbase_target: u64 = 2u64.pow(10) - 1;
+
+        fn test_new_targets(rng: &mut TestRng, minimum_coinbase_target: u64) {
+            let previous_coinbase_target: u64 = rng.gen_range(minimum_coinbase_target..u64::MAX);
+            let previous_prover_target = proof_target(previous_coinbase_target, CurrentNetwork::GENESIS_PROOF_TARGET);
+
+            let previous_timestamp = rng.gen();
+
+            // Targets stay the same when the drift is as expected.
+            let next_timestamp = previous_timestamp + CurrentNetwork::ANCHOR_TIME as i64;
+            let new_coinbase_target = coinbase_target(
+                previous_coinbase_target,
+                previous_timestamp,
+                next_timestamp,
+                CurrentNetwork::ANCHOR_TIME,
+                CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
+                CurrentNetwork::GENESIS_COINBASE_TARGET,
+            )
+            .unwrap();
+            let new_prover_target = proof_target(new_coinbase_target, CurrentNetwork::GENESIS_PROOF_TARGET);
+            assert_eq!(new_coinbase_target, previous_coinbase_target);
+            assert_eq!(new_prover_target, previous_prover_target);
+
+            // Targets decrease (easier) when the drift is greater than expected.
+            let new_timestamp = previous_timestamp + 2 * CurrentNetwork::ANCHOR_TIME as i64;
+            let new_coinbase_target = coinbase_target(
+                previous_coinbase_target,
+                previous_timestamp,
+                new_timestamp,
+                CurrentNetwork::ANCHOR_TIME,
+                CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
+                CurrentNetwork::GENESIS_COINBASE_TARGET,
+            )
+            .unwrap();
+            let new_prover_target = proof_target(new_coinbase_target, CurrentNetwork::GENESIS_PROOF_TARGET);
+            assert!(new_coinbase_target < previous_coinbase_target);
+            assert!(new_prover_target < previous_prover_target);
+
+            // Targets increase (harder) when the drift is less than expected.
+            let next_timestamp = previous_timestamp + (CurrentNetwork::ANCHOR_TIME / 2) as i64;
+            let new_coinbase_target = coinbase_target(
+                previous_coinbase_target,
+                previous_timestamp,
+                next_timestamp,
+                CurrentNetwork::ANCHOR_TIME,
+                CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
+                CurrentNetwork::GENESIS_COINBASE_TARGET,
+            )
+            .unwrap();
+            let new_prover_target = proof_target(new_coinbase_target, CurrentNetwork::GENESIS_PROOF_TARGET);
+
+            assert!(new_coinbase_target > previous_coinbase_target);
+            assert!(new_prover_target > previous_prover_target);
+        }
+
+        for _ in 0..ITERATIONS {
+            test_new_targets(&mut rng, minimum_coinbase_target);
+        }
+    }
+
+    #[test]
+    fn test_target_halving() {
+        let mut rng = TestRng::default();
+
+        let minimum_coinbase_target: u64 = 2u64.pow(10) - 1;
+
+        for _ in 0..ITERATIONS {
+            let previous_coinbase_target: u64 = rng.gen_range(minimum_coinbase_target..u64::MAX);
+            let previous_timestamp = rng.gen();
+
+            let half_life = CurrentNetwork::NUM_BLOCKS_PER_EPOCH
+                .saturating_div(2)
+                .saturating_mul(CurrentNetwork::ANCHOR_TIME as u32) as i64;
+
+            // New coinbase target is greater than half if the drift equals the half life.
+            let next_timestamp = previous_timestamp + half_life;
+            let next_coinbase_target = coinbase_target(
+                previous_coinbase_target,
+                previous_timestamp,
+                next_timestamp,
+                CurrentNetwork::ANCHOR_TIME,
+                CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
+                CurrentNetwork::GENESIS_COINBASE_TARGET,
+            )
+            .unwrap();
+
+            assert!(next_coinbase_target > previous_coinbase_target / 2);
+
+            // New coinbase target is halved if the drift is 1 anchor height past the half life.
+            let next_timestamp = previous_timestamp + half_life + CurrentNetwork::ANCHOR_TIME as i64;
+            let next_coinbase_target = coinbase_target(
+                previous_coinbase_target,
+                previous_timestamp,
+                next_timestamp,
+                CurrentNetwork::ANCHOR_TIME,
+                CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
+                CurrentNetwork::GENESIS_COINBASE_TARGET,
+            )
+            .unwrap();
+
+            assert_eq!(next_coinbase_target, previous_coinbase_target / 2);
+
+            // New coinbase target is less than half if the drift is more than 1 anchor height past the half life.
+            let next_timestamp = previous_timestamp + half_life + 2 * CurrentNetwork::ANCHOR_TIME as i64;
+            let next_coinbase_target = coinbase_target(
+                previous_coinbase_target,
+                previous_timestamp,
+                next_timestamp,
+                CurrentNetwork::ANCHOR_TIME,
+                CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
+                CurrentNetwork::GENESIS_COINBASE_TARGET,
+            )
+            .unwrap();
+
+            assert!(next_coinbase_target < previous_coinbase_target / 2);
+        }
+    }
+
+    #[test]
+    fn test_target_doubling() {
+        let mut rng = TestRng::default();
+
+        // The custom block height drift that is faster than the anchor time.
+        const ANCHOR_TIME_DELTA: i64 = 15;
+        // The expected number of blocks before the coinbase target is doubled.
+        const EXPECTED_NUM_BLOCKS_TO_DOUBLE: u32 = 909;
+
+        let minimum_coinbase_target: u64 = 2u64.pow(10) - 1;
+
+        let initial_coinbase_target: u64 = rng.gen_range(minimum_coinbase_target..u64::MAX / 2);
+        let initial_timestamp: i64 = rng.gen();
+
+        let mut previous_coinbase_target: u64 = initial_coinbase_target;
+        let mut previous_timestamp = initial_timestamp;
+        let mut num_blocks = 0;
+
+        while previous_coinbase_target < initial_coinbase_target * 2 {
+            // Targets increase (harder) when the timestamp is less than expected.
+            let next_timestamp = previous_timestamp + ANCHOR_TIME_DELTA;
+            let next_coinbase_target = coinbase_target(
+                previous_coinbase_target,
+                previous_timestamp,
+                next_timestamp,
+                CurrentNetwork::ANCHOR_TIME,
+                CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
+                CurrentNetwork::GENESIS_COINBASE_TARGET,
+            )
+            .unwrap();
+
+            assert!(next_coinbase_target > previous_coinbase_target);
+
+            previous_coinbase_target = next_coinbase_target;
+            previous_timestamp = next_timestamp;
+            num_blocks += 1;
+        }
+
+        let seconds = previous_timestamp - initial_timestamp;
+        println!(
+            "For drifts of {ANCHOR_TIME_DELTA} seconds and epochs of {} blocks, doubling the coinbase target took {num_blocks} blocks. ({seconds} seconds)",
+            CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
+        );
+
+        assert_eq!(EXPECTED_NUM_BLOCKS_TO_DOUBLE, num_blocks);
+    }
+
+    #[test]
+    fn test_target_simulator() {
+        let mut rng = TestRng::default();
+
+        let genesis_timestamp: i64 = rng.gen_range(0..i64::MAX / 2);
+        let mut simulator = TargetSimulator::<CurrentNetwork>::genesis(genesis_timestamp);
+
+        // Track the targets with the consensus retargeting.
+        let (mut coinbase, mut last_coinbase, mut last_timestamp, mut cumulative) = (
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+            CurrentNetwork::GENESIS_COINBASE_TARGET,
+            genesis_timestamp,
+            0u128,
+        );
+
+        let mut timestamp = genesis_timestamp;
+        for _ in 0..ITERATIONS {
+            // Sample the block time, and the combined proof target of the solutions in the block.
+            timestamp += rng.gen_range(1..3 * CurrentNetwork::ANCHOR_TIME as i64);
+            let combined_proof_target = match rng.gen_bool(0.5) {
+                true => rng.gen_range(0..2 * coinbase as u128),
+                false => 0,
+            };
+
+            // Compute the next targets with the consensus retargeting.
+            let is_coinbase_target_reached = cumulative + combined_proof_target >= coinbase as u128;
+            let next_coinbase = coinbase_target(
+                last_coinbase,
+                last_timestamp,
+                timestamp,
+                CurrentNetwork::ANCHOR_TIME,
+                CurrentNetwork::NUM_BLOCKS_PER_EPOCH,
+                CurrentNetwork::GENESIS_COINBASE_TARGET,
+            )
+            .unwrap();
+            let next_proof = proof_target(next_coinbase, CurrentNetwork::GENESIS_PROOF_TARGET);
+            cumulative = if is_coinbase_target_reached { 0 } else { cumulative + combined_proof_target };
+            if is_coinbase_target_reached {
+                (last_coinbase, last_timestamp) = (next_coinbase, timestamp);
+            }
+            coinbase = next_coinbase;
+
+            // Ensure the simulator matches.
+            assert_eq!(simulator.next(timestamp, combined_proof_target).unwrap(), (next_coinbase, next_proof));
+            assert_eq!(simulator.last_coinbase_target(), last_coinbase);
+            assert_eq!(simulator.last_coinbase_timestamp(), last_timestamp);
+            assert_eq!(simulator.cumulative_proof_target(), cumulative);
+        }
+    }
+}
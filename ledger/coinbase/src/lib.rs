@@ -361,6 +361,78 @@ impl<N: Network> CoinbasePuzzle<N> {
         Ok(check)
     }
 
+    /// Returns `true` if all of the given prover solutions are valid.
+    ///
+    /// The prover solutions are verified with a single batched pairing check, instead of one pairing check each.
+    /// If an accelerator is given, the batched check is offloaded to it, unless it declines the batch.
+    pub fn verify_solutions_batch(
+        &self,
+        prover_solutions: &[ProverSolution<N>],
+        epoch_challenge: &EpochChallenge<N>,
+        proof_target: u64,
+        accelerator: Option<&dyn PuzzleAccelerator<N>>,
+    ) -> Result<bool> {
+        let timer = timer!("CoinbasePuzzle::verify_solutions_batch");
+
+        // Ensure the solutions are not empty.
+        if prover_solutions.is_empty() {
+            bail!("There are no solutions");
+        }
+
+        // Ensure the proofs are non-hiding.
+        if prover_solutions.iter().any(|solution| solution.proof().is_hiding()) {
+            return Ok(false);
+        }
+        lap!(timer, "Perform initial checks");
+
+        // Compute the challenge point and claimed value for each solution.
+        let (points, values): (Vec<_>, Vec<_>) = cfg_iter!(prover_solutions)
+            .map(|solution| {
+                // Ensure that the prover solution meets the required proof target.
+                if solution.to_target()? < proof_target {
+                    bail!("Prover puzzle does not meet the proof target requirements.")
+                }
+                // Compute the prover polynomial.
+                let prover_polynomial = solution.to_prover_polynomial(epoch_challenge)?;
+                // Compute the challenge point.
+                let challenge_point = hash_commitment(&solution.commitment())?;
+                // Compute the claimed value by multiplying the evaluations of the epoch and prover polynomials.
+                let claimed_value = epoch_challenge.epoch_polynomial().evaluate(challenge_point)
+                    * prover_polynomial.evaluate(challenge_point);
+                Ok((challenge_point, claimed_value))
+            })
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+        lap!(timer, "Compute the challenge points and claimed values");
+
+        // Collect the commitments and proofs.
+        let commitments = prover_solutions.iter().map(|solution| *solution.commitment()).collect::<Vec<_>>();
+        let proofs = prover_solutions.iter().map(|solution| *solution.proof()).collect::<Vec<_>>();
+
+        // Retrieve the coinbase verifying key.
+        let coinbase_verifying_key = self.coinbase_verifying_key();
+
+        // If an accelerator is given, offload the batched check to it.
+        if let Some(accelerator) = accelerator {
+            if let Some(check) =
+                accelerator.batch_check(coinbase_verifying_key, &commitments, &points, &values, &proofs)?
+            {
+                finish!(timer, "Verify the KZG10 proofs with the accelerator");
+                return Ok(check);
+            }
+        }
+
+        // Verify the KZG10 proofs, with randomizers derived from all of the inputs to the batched check.
+        let addresses = prover_solutions.iter().map(|solution| solution.address()).collect::<Vec<_>>();
+        let rng = &mut hash_to_rng(&addresses, &commitments, &points, &values, &proofs)?;
+        let check = KZG10::batch_check(coinbase_verifying_key, &commitments, &points, &values, &proofs, rng)?;
+        finish!(timer, "Verify the KZG10 proofs");
+
+        // Return the verification result.
+        Ok(check)
+    }
+
     /// Returns the coinbase proving key.
     pub fn coinbase_proving_key(&self) -> Result<&CoinbaseProvingKey<N>> {
         match self {
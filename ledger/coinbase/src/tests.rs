@@ -107,6 +107,76 @@ fn test_edge_case_for_degree() {
     assert!(puzzle.verify(&coinbase_solution, &epoch_challenge, 0u64).unwrap());
 }
 
+#[test]
+fn test_verify_solutions_batch() {
+    /// An accelerator that returns a fixed response, and counts the number of batches it receives.
+    struct SampleAccelerator(Option<bool>, std::sync::atomic::AtomicUsize);
+
+    impl PuzzleAccelerator<Testnet3> for SampleAccelerator {
+        fn batch_check(
+            &self,
+            _: &CoinbaseVerifyingKey<Testnet3>,
+            commitments: &[KZGCommitment<<Testnet3 as Environment>::PairingCurve>],
+            points: &[<<Testnet3 as Environment>::PairingCurve as PairingEngine>::Fr],
+            values: &[<<Testnet3 as Environment>::PairingCurve as PairingEngine>::Fr],
+            proofs: &[PuzzleProof<Testnet3>],
+        ) -> Result<Option<bool>> {
+            assert!(commitments.len() == points.len() && points.len() == values.len() && values.len() == proofs.len());
+            self.1.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(self.0)
+        }
+    }
+
+    let mut rng = TestRng::default();
+
+    let max_degree = 1 << 15;
+    let max_config = PuzzleConfig { degree: max_degree };
+    let srs = CoinbasePuzzle::<Testnet3>::setup(max_config).unwrap();
+
+    let degree = (1 << 9) - 1;
+    let puzzle = CoinbasePuzzle::<Testnet3>::trim(&srs, PuzzleConfig { degree }).unwrap();
+    let epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+
+    for batch_size in 1..10 {
+        let solutions = (0..batch_size)
+            .map(|_| {
+                let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+                let address = Address::try_from(private_key).unwrap();
+                puzzle.prove(&epoch_challenge, address, u64::rand(&mut rng), None).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        // Ensure the batch matches the individual verification.
+        assert!(puzzle.verify_solutions_batch(&solutions, &epoch_challenge, 0u64, None).unwrap());
+        for solution in &solutions {
+            assert!(solution.verify(puzzle.coinbase_verifying_key(), &epoch_challenge, 0u64).unwrap());
+        }
+
+        // Ensure the batch fails for a different epoch challenge.
+        let bad_epoch_challenge = EpochChallenge::new(rng.next_u32(), Default::default(), degree).unwrap();
+        assert!(!puzzle.verify_solutions_batch(&solutions, &bad_epoch_challenge, 0u64, None).unwrap());
+
+        // Ensure the batch fails if any solution fails to meet the proof target.
+        let max_target = solutions.iter().map(|solution| solution.to_target().unwrap()).max().unwrap();
+        assert!(puzzle
+            .verify_solutions_batch(&solutions, &epoch_challenge, max_target.saturating_add(1), None)
+            .is_err());
+
+        // Ensure the batch falls back to the CPU if the accelerator declines.
+        let accelerator = SampleAccelerator(None, Default::default());
+        assert!(!puzzle.verify_solutions_batch(&solutions, &bad_epoch_challenge, 0u64, Some(&accelerator)).unwrap());
+        assert_eq!(accelerator.1.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        // Ensure the batch uses the result of the accelerator.
+        let accelerator = SampleAccelerator(Some(false), Default::default());
+        assert!(!puzzle.verify_solutions_batch(&solutions, &epoch_challenge, 0u64, Some(&accelerator)).unwrap());
+        assert_eq!(accelerator.1.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    // Ensure an empty batch is rejected.
+    assert!(puzzle.verify_solutions_batch(&[], &epoch_challenge, 0u64, None).is_err());
+}
+
 /// Use `cargo test profiler --features timer` to run this test.
 #[ignore]
 #[test]
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 mod bytes;
+mod selector;
+pub use selector::*;
 mod serialize;
 mod string;
 
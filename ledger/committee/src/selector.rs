@@ -0,0 +1,147 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A rule for selecting the members of the next committee, and their voting weight, from the bonded stake.
+///
+/// The default, `StakeWeightedSelector`, is the rule used by consensus. A network with other rules
+/// (e.g. capped stake, or reputation adjustments) can implement this trait to derive its committees.
+pub trait CommitteeSelector<N: Network>: Send + Sync {
+    /// Returns the members of the next committee, as a map of `validator` to `(weight, is_open)`,
+    /// given the current committee and the total stake bonded to each validator.
+    fn select(
+        &self,
+        current_committee: &Committee<N>,
+        validator_stakes: &IndexMap<Address<N>, u64>,
+    ) -> Result<IndexMap<Address<N>, (u64, bool)>>;
+
+    /// Invoked once the next committee is selected, before it is returned.
+    /// An error rejects the rotation.
+    fn on_rotation(&self, _current_committee: &Committee<N>, _next_committee: &Committee<N>) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Selects every bonded validator, with a weight equal to its stake.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct StakeWeightedSelector;
+
+impl<N: Network> CommitteeSelector<N> for StakeWeightedSelector {
+    /// Returns every validator, with a weight equal to its stake.
+    fn select(
+        &self,
+        current_committee: &Committee<N>,
+        validator_stakes: &IndexMap<Address<N>, u64>,
+    ) -> Result<IndexMap<Address<N>, (u64, bool)>> {
+        Ok(validator_stakes
+            .iter()
+            .map(|(validator, stake)| (*validator, (*stake, current_committee.is_committee_member_open(*validator))))
+            .collect())
+    }
+}
+
+/// Selects every bonded validator, with a weight equal to its stake, up to the given maximum.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CappedStakeSelector {
+    /// The maximum weight of a validator, in microcredits.
+    pub max_weight: u64,
+}
+
+impl<N: Network> CommitteeSelector<N> for CappedStakeSelector {
+    /// Returns every validator, with a weight equal to its stake, up to the maximum weight.
+    fn select(
+        &self,
+        current_committee: &Committee<N>,
+        validator_stakes: &IndexMap<Address<N>, u64>,
+    ) -> Result<IndexMap<Address<N>, (u64, bool)>> {
+        ensure!(self.max_weight >= MIN_VALIDATOR_STAKE, "The maximum weight must be at least {MIN_VALIDATOR_STAKE}");
+        let members = StakeWeightedSelector.select(current_committee, validator_stakes)?;
+        Ok(members
+            .into_iter()
+            .map(|(validator, (stake, is_open))| (validator, (stake.min(self.max_weight), is_open)))
+            .collect())
+    }
+}
+
+impl<N: Network> Committee<N> {
+    /// Returns the next committee, starting at the given round, as selected by the given selector
+    /// from the total stake bonded to each validator.
+    pub fn select_next<S: CommitteeSelector<N> + ?Sized>(
+        &self,
+        next_round: u64,
+        validator_stakes: &IndexMap<Address<N>, u64>,
+        selector: &S,
+    ) -> Result<Self> {
+        // Select the members.
+        let members = selector.select(self, validator_stakes)?;
+        // Construct the next committee.
+        let next_committee = Self::new(next_round, members)?;
+        // Invoke the rotation hook.
+        selector.on_rotation(self, &next_committee)?;
+        Ok(next_committee)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// A selector that rejects any rotation that changes the members.
+    struct FixedMembers;
+
+    impl CommitteeSelector<CurrentNetwork> for FixedMembers {
+        fn select(
+            &self,
+            current_committee: &Committee<CurrentNetwork>,
+            validator_stakes: &IndexMap<Address<CurrentNetwork>, u64>,
+        ) -> Result<IndexMap<Address<CurrentNetwork>, (u64, bool)>> {
+            StakeWeightedSelector.select(current_committee, validator_stakes)
+        }
+
+        fn on_rotation(&self, current: &Committee<CurrentNetwork>, next: &Committee<CurrentNetwork>) -> Result<()> {
+            ensure!(current.members().keys().eq(next.members().keys()), "The committee members must not change");
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_select_next() {
+        let rng = &mut TestRng::default();
+
+        let committee = crate::test_helpers::sample_committee(rng);
+        let mut stakes =
+            committee.members().iter().map(|(validator, (stake, _))| (*validator, *stake)).collect::<IndexMap<_, _>>();
+
+        // Ensure the default selector preserves the members.
+        let next = committee.select_next(committee.starting_round() + 1, &stakes, &StakeWeightedSelector).unwrap();
+        assert_eq!(next.starting_round(), committee.starting_round() + 1);
+        assert_eq!(next.members(), committee.members());
+
+        // Ensure the capped selector caps the weights.
+        let max_weight = MIN_VALIDATOR_STAKE;
+        let next = committee.select_next(1, &stakes, &CappedStakeSelector { max_weight }).unwrap();
+        assert!(next.members().values().all(|(weight, _)| *weight == max_weight));
+        assert!(committee.select_next(1, &stakes, &CappedStakeSelector { max_weight: 0 }).is_err());
+
+        // Ensure the rotation hook can reject a rotation.
+        assert!(committee.select_next(1, &stakes, &FixedMembers).is_ok());
+        stakes.insert(Address::new(rng.gen()), MIN_VALIDATOR_STAKE);
+        assert!(committee.select_next(1, &stakes, &FixedMembers).is_err());
+        assert!(committee.select_next(1, &stakes, &StakeWeightedSelector).is_ok());
+    }
+}
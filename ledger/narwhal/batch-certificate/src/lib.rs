@@ -60,11 +60,15 @@ impl<N: Network> BatchCertificate<N> {
             bail!("Invalid batch certificate ID")
         }
         // Verify the signatures are valid.
-        for (signature, timestamp) in &signatures {
-            let preimage = [batch_header.batch_id(), Field::from_u64(*timestamp as u64)];
-            if !signature.verify(&signature.to_address(), &preimage) {
-                bail!("Invalid batch certificate signature")
-            }
+        let batch = signatures
+            .iter()
+            .map(|(signature, timestamp)| {
+                let preimage = vec![batch_header.batch_id(), Field::from_u64(*timestamp as u64)];
+                (*signature, signature.to_address(), preimage)
+            })
+            .collect::<Vec<_>>();
+        if !Signature::verify_batch(&batch) {
+            bail!("Invalid batch certificate signature")
         }
         // Return the batch certificate.
         Self::from_unchecked(certificate_id, batch_header, signatures)
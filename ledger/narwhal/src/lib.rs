@@ -44,3 +44,6 @@ pub use narwhal_transmission::Transmission;
 pub use narwhal_transmission_id as transmission_id;
 #[cfg(feature = "transmission-id")]
 pub use narwhal_transmission_id::TransmissionID;
+
+#[cfg(feature = "validation")]
+pub use narwhal_validation as validation;
@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// Validates the given batch certificate against the committee for its round.
+///
+/// The batch certificate is valid if:
+///  1. its batch header is valid (see `validate_batch_header`),
+///  2. its certificate ID matches its contents,
+///  3. every signer is a committee member, other than the author, and signs at most once,
+///     with a timestamp of at most `max_timestamp`,
+///  4. every signature is valid for the batch ID and its timestamp,
+///  5. the author and signers reach the quorum threshold of the committee.
+pub fn validate_batch_certificate<N: Network>(
+    certificate: &BatchCertificate<N>,
+    committee: &Committee<N>,
+    max_transmissions: usize,
+    max_timestamp: i64,
+) -> Result<(), BatchCertificateError<N>> {
+    // Ensure the batch header is valid.
+    validate_batch_header(certificate.batch_header(), committee, max_transmissions, max_timestamp)?;
+
+    // Ensure the certificate ID matches the contents.
+    let certificate_id = certificate.certificate_id();
+    let signatures = certificate.signatures().copied().zip(certificate.timestamps()).collect::<IndexMap<_, _>>();
+    match BatchCertificate::compute_certificate_id(certificate.batch_id(), &signatures) {
+        Ok(expected_certificate_id) if expected_certificate_id == certificate_id => (),
+        Ok(_) => return Err(BatchCertificateError::InvalidCertificateId { certificate_id }),
+        Err(error) => return Err(BatchCertificateError::Internal(error.to_string())),
+    }
+
+    // Initialize the set of signers, starting with the author.
+    let mut signers = HashSet::with_capacity(signatures.len() + 1);
+    signers.insert(certificate.author());
+    for (signature, timestamp) in &signatures {
+        let signer = signature.to_address();
+        // Ensure the signer is a committee member.
+        if !committee.is_committee_member(signer) {
+            return Err(BatchCertificateError::SignerNotInCommittee { signer });
+        }
+        // Ensure the signer is unique.
+        if !signers.insert(signer) {
+            return Err(BatchCertificateError::DuplicateSigner { signer });
+        }
+        // Ensure the signature timestamp is not too far in the future.
+        if *timestamp > max_timestamp {
+            return Err(BatchCertificateError::TimestampInFuture { signer, timestamp: *timestamp, max_timestamp });
+        }
+    }
+
+    // Ensure the signatures are valid for the batch ID and timestamps.
+    let batch = signatures
+        .iter()
+        .map(|(signature, timestamp)| {
+            (*signature, signature.to_address(), vec![certificate.batch_id(), Field::from_u64(*timestamp as u64)])
+        })
+        .collect::<Vec<_>>();
+    if !Signature::verify_batch(&batch) {
+        // Find the first invalid signature, to report its signer.
+        return match batch.iter().find(|(signature, signer, preimage)| !signature.verify(signer, preimage)) {
+            Some((_, signer, _)) => Err(BatchCertificateError::InvalidSignature { signer: *signer }),
+            None => Err(BatchCertificateError::Internal("Failed to verify the certificate signatures".to_string())),
+        };
+    }
+
+    // Ensure the author and signers reach the quorum threshold.
+    if !committee.is_quorum_threshold_reached(&signers) {
+        return Err(BatchCertificateError::InsufficientStake {
+            stake: total_stake(committee, &signers),
+            quorum_threshold: committee.quorum_threshold(),
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The reasons a batch header is invalid.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum BatchHeaderError<N: Network> {
+    /// The batch is in round 0 or 1, yet references previous certificates.
+    #[error("Batch in round {round} must not reference previous certificates")]
+    UnexpectedPreviousCertificates { round: u64 },
+    /// The batch is after round 1, yet references no previous certificates.
+    #[error("Batch in round {round} must reference previous certificates")]
+    MissingPreviousCertificates { round: u64 },
+    /// The batch round precedes the starting round of the committee.
+    #[error("Batch in round {round} precedes the committee starting round {starting_round}")]
+    RoundBeforeCommittee { round: u64, starting_round: u64 },
+    /// The batch author is not a member of the committee.
+    #[error("Batch author '{author}' is not in the committee")]
+    AuthorNotInCommittee { author: Address<N> },
+    /// The batch contains too many transmissions.
+    #[error("Batch contains {num_transmissions} transmissions, exceeding the maximum of {max_transmissions}")]
+    TooManyTransmissions { num_transmissions: usize, max_transmissions: usize },
+    /// The batch timestamp is too far in the future.
+    #[error("Batch timestamp {timestamp} exceeds the maximum timestamp {max_timestamp}")]
+    TimestampInFuture { timestamp: i64, max_timestamp: i64 },
+    /// The batch ID does not match the batch contents.
+    #[error("Batch ID '{batch_id}' does not match the batch contents")]
+    InvalidBatchId { batch_id: Field<N> },
+    /// The batch signature is not valid for the author.
+    #[error("Batch signature is invalid for author '{author}'")]
+    InvalidSignature { author: Address<N> },
+    /// A previous certificate referenced by the batch is missing.
+    #[error("Previous certificate '{certificate_id}' is missing")]
+    PreviousCertificateNotFound { certificate_id: Field<N> },
+    /// A previous certificate is not from the preceding round.
+    #[error("Previous certificate '{certificate_id}' is in round {round}, expected round {expected_round}")]
+    PreviousCertificateRound { certificate_id: Field<N>, round: u64, expected_round: u64 },
+    /// Two previous certificates are from the same author.
+    #[error("Previous certificates contain more than one certificate from '{author}'")]
+    DuplicatePreviousAuthor { author: Address<N> },
+    /// The previous certificates do not reach the quorum threshold of the previous committee.
+    #[error("Previous certificates have {stake} stake, below the quorum threshold of {quorum_threshold}")]
+    InsufficientPreviousStake { stake: u64, quorum_threshold: u64 },
+    /// The batch ID could not be computed.
+    #[error("Failed to compute the batch ID: {0}")]
+    Internal(String),
+}
+
+/// The reasons a batch certificate is invalid.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum BatchCertificateError<N: Network> {
+    /// The batch header is invalid.
+    #[error(transparent)]
+    Header(#[from] BatchHeaderError<N>),
+    /// The certificate ID does not match the certificate contents.
+    #[error("Certificate ID '{certificate_id}' does not match the certificate contents")]
+    InvalidCertificateId { certificate_id: Field<N> },
+    /// A signer is not a member of the committee.
+    #[error("Certificate signer '{signer}' is not in the committee")]
+    SignerNotInCommittee { signer: Address<N> },
+    /// A committee member signed more than once, or the author signed their own batch.
+    #[error("Certificate contains more than one signature from '{signer}'")]
+    DuplicateSigner { signer: Address<N> },
+    /// A signature timestamp is too far in the future.
+    #[error("Certificate signature from '{signer}' has timestamp {timestamp}, exceeding {max_timestamp}")]
+    TimestampInFuture { signer: Address<N>, timestamp: i64, max_timestamp: i64 },
+    /// A signature is not valid for the batch ID and timestamp.
+    #[error("Certificate signature from '{signer}' is invalid")]
+    InvalidSignature { signer: Address<N> },
+    /// The author and signers do not reach the quorum threshold of the committee.
+    #[error("Certificate has {stake} stake, below the quorum threshold of {quorum_threshold}")]
+    InsufficientStake { stake: u64, quorum_threshold: u64 },
+    /// The certificate ID could not be computed.
+    #[error("Failed to compute the certificate ID: {0}")]
+    Internal(String),
+}
+
+/// The reasons a transmission is invalid.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum TransmissionError<N: Network> {
+    /// The transmission is of a different kind than its ID.
+    #[error("Transmission '{transmission_id}' is of a different kind than its ID")]
+    KindMismatch { transmission_id: TransmissionID<N> },
+    /// The transmission could not be deserialized.
+    #[error("Transmission '{transmission_id}' is malformed: {reason}")]
+    Malformed { transmission_id: TransmissionID<N>, reason: String },
+    /// The transmission does not match its ID.
+    #[error("Transmission '{transmission_id}' does not match its contents")]
+    IdMismatch { transmission_id: TransmissionID<N> },
+    /// A transmission in the batch header is missing.
+    #[error("Transmission '{transmission_id}' is missing")]
+    Missing { transmission_id: TransmissionID<N> },
+    /// A transmission is not in the batch header.
+    #[error("Transmission '{transmission_id}' is not in the batch")]
+    Unexpected { transmission_id: TransmissionID<N> },
+}
@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// Validates the given batch header against the committee for its round.
+///
+/// The batch header is valid if:
+///  1. it references previous certificates if and only if it is after round 1,
+///  2. its round is covered by the committee, and its author is a committee member,
+///  3. it has at most `max_transmissions` transmissions, and a timestamp of at most `max_timestamp`,
+///  4. its batch ID matches its contents, and is signed by its author.
+pub fn validate_batch_header<N: Network>(
+    header: &BatchHeader<N>,
+    committee: &Committee<N>,
+    max_transmissions: usize,
+    max_timestamp: i64,
+) -> Result<(), BatchHeaderError<N>> {
+    let round = header.round();
+
+    // Ensure the previous certificates are referenced if and only if the round is after round 1.
+    match (round, header.previous_certificate_ids().is_empty()) {
+        (0 | 1, false) => return Err(BatchHeaderError::UnexpectedPreviousCertificates { round }),
+        (2.., true) => return Err(BatchHeaderError::MissingPreviousCertificates { round }),
+        _ => (),
+    }
+    // Ensure the round is covered by the committee.
+    if round < committee.starting_round() {
+        return Err(BatchHeaderError::RoundBeforeCommittee { round, starting_round: committee.starting_round() });
+    }
+    // Ensure the author is a committee member.
+    let author = header.author();
+    if !committee.is_committee_member(author) {
+        return Err(BatchHeaderError::AuthorNotInCommittee { author });
+    }
+    // Ensure the number of transmissions is within the limit.
+    if header.len() > max_transmissions {
+        return Err(BatchHeaderError::TooManyTransmissions { num_transmissions: header.len(), max_transmissions });
+    }
+    // Ensure the timestamp is not too far in the future.
+    if header.timestamp() > max_timestamp {
+        return Err(BatchHeaderError::TimestampInFuture { timestamp: header.timestamp(), max_timestamp });
+    }
+    // Ensure the batch ID matches the contents.
+    let batch_id = header.batch_id();
+    match header.to_id() {
+        Ok(expected_batch_id) if expected_batch_id == batch_id => (),
+        Ok(_) => return Err(BatchHeaderError::InvalidBatchId { batch_id }),
+        Err(error) => return Err(BatchHeaderError::Internal(error.to_string())),
+    }
+    // Ensure the batch ID is signed by the author.
+    if !header.signature().verify(&author, &[batch_id]) {
+        return Err(BatchHeaderError::InvalidSignature { author });
+    }
+    Ok(())
+}
+
+/// Validates the previous certificates referenced by the given batch header, against the committee of the previous round.
+///
+/// The previous certificates are valid if:
+///  1. every referenced certificate is given, and is from the preceding round,
+///  2. no two referenced certificates are from the same author,
+///  3. the authors of the referenced certificates reach the quorum threshold of the previous committee.
+pub fn validate_previous_certificates<N: Network>(
+    header: &BatchHeader<N>,
+    previous_certificates: &IndexMap<Field<N>, BatchCertificate<N>>,
+    previous_committee: &Committee<N>,
+) -> Result<(), BatchHeaderError<N>> {
+    // If the round has no previous certificates, there is nothing to validate.
+    if header.round() <= 1 {
+        return Ok(());
+    }
+    let expected_round = header.round() - 1;
+
+    // Initialize the set of authors of the previous certificates.
+    let mut authors = HashSet::with_capacity(header.previous_certificate_ids().len());
+    for certificate_id in header.previous_certificate_ids() {
+        // Ensure the certificate is given, and matches its ID.
+        let certificate = match previous_certificates.get(certificate_id) {
+            Some(certificate) if certificate.certificate_id() == *certificate_id => certificate,
+            _ => return Err(BatchHeaderError::PreviousCertificateNotFound { certificate_id: *certificate_id }),
+        };
+        // Ensure the certificate is from the preceding round.
+        if certificate.round() != expected_round {
+            return Err(BatchHeaderError::PreviousCertificateRound {
+                certificate_id: *certificate_id,
+                round: certificate.round(),
+                expected_round,
+            });
+        }
+        // Ensure the author is unique.
+        if !authors.insert(certificate.author()) {
+            return Err(BatchHeaderError::DuplicatePreviousAuthor { author: certificate.author() });
+        }
+    }
+
+    // Ensure the authors reach the quorum threshold.
+    if !previous_committee.is_quorum_threshold_reached(&authors) {
+        return Err(BatchHeaderError::InsufficientPreviousStake {
+            stake: total_stake(previous_committee, &authors),
+            quorum_threshold: previous_committee.quorum_threshold(),
+        });
+    }
+    Ok(())
+}
+
+/// Returns the combined stake of the given addresses in the committee.
+pub(crate) fn total_stake<N: Network>(committee: &Committee<N>, addresses: &HashSet<Address<N>>) -> u64 {
+    addresses.iter().fold(0u64, |stake, address| stake.saturating_add(committee.get_stake(*address)))
+}
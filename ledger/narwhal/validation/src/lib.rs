@@ -0,0 +1,45 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![forbid(unsafe_code)]
+#![warn(clippy::cast_possible_truncation)]
+
+mod certificate;
+pub use certificate::*;
+
+mod errors;
+pub use errors::*;
+
+mod header;
+pub use header::*;
+
+mod transmission;
+pub use transmission::*;
+
+#[cfg(test)]
+mod prop_tests;
+
+use console::{
+    account::{Address, Signature},
+    prelude::*,
+    types::Field,
+};
+use ledger_committee::Committee;
+use narwhal_batch_certificate::BatchCertificate;
+use narwhal_batch_header::BatchHeader;
+use narwhal_transmission::Transmission;
+use narwhal_transmission_id::TransmissionID;
+
+use indexmap::IndexMap;
+use std::collections::HashSet;
@@ -0,0 +1,281 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::{account::PrivateKey, network::Testnet3, prelude::TestRng};
+use ledger_committee::prop_tests::{CommitteeContext, Validator, ValidatorSet};
+
+use indexmap::IndexSet;
+use test_strategy::proptest;
+
+type CurrentNetwork = Testnet3;
+
+/// The maximum number of transmissions used in the tests.
+const MAX_TRANSMISSIONS: usize = 10;
+/// The maximum timestamp used in the tests.
+const MAX_TIMESTAMP: i64 = 1_000_000;
+
+/// Returns the validators of the committee, in a deterministic order.
+fn sorted_validators(validators: &ValidatorSet) -> Vec<&Validator> {
+    let mut validators = validators.0.iter().collect::<Vec<_>>();
+    validators.sort_by_key(|validator| validator.address.to_string());
+    validators
+}
+
+/// Returns a batch header from the given author, with the given number of transmissions.
+fn sample_header(
+    author: &PrivateKey<CurrentNetwork>,
+    round: u64,
+    timestamp: i64,
+    num_transmissions: usize,
+    previous_certificate_ids: IndexSet<Field<CurrentNetwork>>,
+    rng: &mut TestRng,
+) -> BatchHeader<CurrentNetwork> {
+    let transmission_ids = (0..num_transmissions)
+        .map(|_| TransmissionID::Transaction(<CurrentNetwork as Network>::TransactionID::from(Field::rand(rng))))
+        .collect();
+    BatchHeader::new(author, round, timestamp, transmission_ids, previous_certificate_ids, rng).unwrap()
+}
+
+/// Returns a batch certificate for the given batch header, signed by the given signers.
+fn sample_certificate<'a>(
+    header: BatchHeader<CurrentNetwork>,
+    signers: impl IntoIterator<Item = &'a PrivateKey<CurrentNetwork>>,
+    rng: &mut TestRng,
+) -> BatchCertificate<CurrentNetwork> {
+    let timestamp = header.timestamp();
+    let signatures = signers
+        .into_iter()
+        .map(|signer| {
+            let signature = signer.sign(&[header.batch_id(), Field::from_u64(timestamp as u64)], rng).unwrap();
+            (signature, timestamp)
+        })
+        .collect::<IndexMap<_, _>>();
+    let certificate_id = BatchCertificate::compute_certificate_id(header.batch_id(), &signatures).unwrap();
+    BatchCertificate::from_unchecked(certificate_id, header, signatures).unwrap()
+}
+
+#[proptest]
+fn valid_certificate(input: CommitteeContext, seed: u64, #[strategy(0..=MAX_TRANSMISSIONS)] num_transmissions: usize) {
+    let CommitteeContext(committee, validators) = input;
+    let rng = &mut TestRng::fixed(seed);
+    let validators = sorted_validators(&validators);
+
+    // Sample a batch from the first validator, signed by the rest.
+    let header = sample_header(&validators[0].private_key, 1, 0, num_transmissions, Default::default(), rng);
+    assert_eq!(validate_batch_header(&header, &committee, MAX_TRANSMISSIONS, MAX_TIMESTAMP), Ok(()));
+    let certificate = sample_certificate(header, validators[1..].iter().map(|v| &v.private_key), rng);
+    assert_eq!(validate_batch_certificate(&certificate, &committee, MAX_TRANSMISSIONS, MAX_TIMESTAMP), Ok(()));
+}
+
+#[proptest]
+fn certificate_quorum(input: CommitteeContext, seed: u64, #[strategy(1..4usize)] num_signers: usize) {
+    let CommitteeContext(committee, validators) = input;
+    let rng = &mut TestRng::fixed(seed);
+    let validators = sorted_validators(&validators);
+
+    // Sample a batch from the first validator, signed by a subset of the rest.
+    let header = sample_header(&validators[0].private_key, 1, 0, 0, Default::default(), rng);
+    let signers = &validators[1..1 + num_signers.min(validators.len() - 1)];
+    let certificate = sample_certificate(header, signers.iter().map(|v| &v.private_key), rng);
+
+    // Ensure the certificate is valid if and only if the author and signers reach the quorum threshold.
+    let addresses = signers.iter().map(|v| v.address).chain([validators[0].address]).collect::<HashSet<_>>();
+    let result = validate_batch_certificate(&certificate, &committee, MAX_TRANSMISSIONS, MAX_TIMESTAMP);
+    match committee.is_quorum_threshold_reached(&addresses) {
+        true => assert_eq!(result, Ok(())),
+        false => assert_eq!(
+            result,
+            Err(BatchCertificateError::InsufficientStake {
+                stake: total_stake(&committee, &addresses),
+                quorum_threshold: committee.quorum_threshold(),
+            })
+        ),
+    }
+}
+
+#[proptest]
+fn invalid_header_limits(
+    input: CommitteeContext,
+    seed: u64,
+    #[strategy(MAX_TRANSMISSIONS + 1..2 * MAX_TRANSMISSIONS)] num_transmissions: usize,
+    #[strategy(MAX_TIMESTAMP + 1..)] timestamp: i64,
+) {
+    let CommitteeContext(committee, validators) = input;
+    let rng = &mut TestRng::fixed(seed);
+    let author = &sorted_validators(&validators)[0].private_key;
+
+    // Ensure a batch with too many transmissions is rejected.
+    let header = sample_header(author, 1, 0, num_transmissions, Default::default(), rng);
+    assert_eq!(
+        validate_batch_header(&header, &committee, MAX_TRANSMISSIONS, MAX_TIMESTAMP),
+        Err(BatchHeaderError::TooManyTransmissions { num_transmissions, max_transmissions: MAX_TRANSMISSIONS })
+    );
+
+    // Ensure a batch with a future timestamp is rejected.
+    let header = sample_header(author, 1, timestamp, 0, Default::default(), rng);
+    assert_eq!(
+        validate_batch_header(&header, &committee, MAX_TRANSMISSIONS, MAX_TIMESTAMP),
+        Err(BatchHeaderError::TimestampInFuture { timestamp, max_timestamp: MAX_TIMESTAMP })
+    );
+
+    // Ensure a batch before the committee starting round is rejected.
+    let header = sample_header(author, 0, 0, 0, Default::default(), rng);
+    assert_eq!(
+        validate_batch_header(&header, &committee, MAX_TRANSMISSIONS, MAX_TIMESTAMP),
+        Err(BatchHeaderError::RoundBeforeCommittee { round: 0, starting_round: committee.starting_round() })
+    );
+}
+
+#[proptest]
+fn invalid_signers(input: CommitteeContext, seed: u64) {
+    let CommitteeContext(committee, validators) = input;
+    let rng = &mut TestRng::fixed(seed);
+    let validators = sorted_validators(&validators);
+    let outsider = PrivateKey::new(rng).unwrap();
+    let outsider_address = Address::try_from(&outsider).unwrap();
+
+    // Ensure a batch from a non-member is rejected.
+    let header = sample_header(&outsider, 1, 0, 0, Default::default(), rng);
+    assert_eq!(
+        validate_batch_header(&header, &committee, MAX_TRANSMISSIONS, MAX_TIMESTAMP),
+        Err(BatchHeaderError::AuthorNotInCommittee { author: outsider_address })
+    );
+
+    // Ensure a signature from a non-member is rejected.
+    let header = sample_header(&validators[0].private_key, 1, 0, 0, Default::default(), rng);
+    let signers = validators[1..].iter().map(|v| &v.private_key).chain([&outsider]);
+    let certificate = sample_certificate(header.clone(), signers, rng);
+    assert_eq!(
+        validate_batch_certificate(&certificate, &committee, MAX_TRANSMISSIONS, MAX_TIMESTAMP),
+        Err(BatchCertificateError::SignerNotInCommittee { signer: outsider_address })
+    );
+
+    // Ensure a signature from the author is rejected.
+    let signers = validators.iter().map(|v| &v.private_key);
+    let certificate = sample_certificate(header.clone(), signers, rng);
+    assert_eq!(
+        validate_batch_certificate(&certificate, &committee, MAX_TRANSMISSIONS, MAX_TIMESTAMP),
+        Err(BatchCertificateError::DuplicateSigner { signer: validators[0].address })
+    );
+
+    // Ensure a signature over a different timestamp is rejected.
+    let signer = &validators[1];
+    let signature = signer.private_key.sign(&[header.batch_id(), Field::from_u64(1)], rng).unwrap();
+    let signatures = [(signature, 0)].into_iter().collect::<IndexMap<_, _>>();
+    let certificate_id = BatchCertificate::compute_certificate_id(header.batch_id(), &signatures).unwrap();
+    let certificate = BatchCertificate::from_unchecked(certificate_id, header.clone(), signatures).unwrap();
+    assert_eq!(
+        validate_batch_certificate(&certificate, &committee, MAX_TRANSMISSIONS, MAX_TIMESTAMP),
+        Err(BatchCertificateError::InvalidSignature { signer: signer.address })
+    );
+
+    // Ensure a signature with a future timestamp is rejected.
+    let timestamp = MAX_TIMESTAMP + 1;
+    let signature = signer.private_key.sign(&[header.batch_id(), Field::from_u64(timestamp as u64)], rng).unwrap();
+    let signatures = [(signature, timestamp)].into_iter().collect::<IndexMap<_, _>>();
+    let certificate_id = BatchCertificate::compute_certificate_id(header.batch_id(), &signatures).unwrap();
+    let certificate = BatchCertificate::from_unchecked(certificate_id, header, signatures).unwrap();
+    assert_eq!(
+        validate_batch_certificate(&certificate, &committee, MAX_TRANSMISSIONS, MAX_TIMESTAMP),
+        Err(BatchCertificateError::TimestampInFuture {
+            signer: signer.address,
+            timestamp,
+            max_timestamp: MAX_TIMESTAMP
+        })
+    );
+}
+
+#[proptest]
+fn previous_certificates(input: CommitteeContext, seed: u64, #[strategy(1..=4usize)] num_previous: usize) {
+    let CommitteeContext(committee, validators) = input;
+    let rng = &mut TestRng::fixed(seed);
+    let validators = sorted_validators(&validators);
+
+    // Sample the certificates of round 1, from a subset of the validators.
+    let previous_certificates = validators[..num_previous]
+        .iter()
+        .map(|author| {
+            let header = sample_header(&author.private_key, 1, 0, 0, Default::default(), rng);
+            let signers = validators.iter().filter(|v| v.address != author.address).map(|v| &v.private_key);
+            let certificate = sample_certificate(header, signers, rng);
+            (certificate.certificate_id(), certificate)
+        })
+        .collect::<IndexMap<_, _>>();
+
+    // Sample a batch in round 2, referencing the certificates of round 1.
+    let previous_certificate_ids = previous_certificates.keys().copied().collect::<IndexSet<_>>();
+    let header = sample_header(&validators[0].private_key, 2, 0, 0, previous_certificate_ids, rng);
+
+    // Ensure the batch is valid if and only if the previous authors reach the quorum threshold.
+    let authors = validators[..num_previous].iter().map(|v| v.address).collect::<HashSet<_>>();
+    let result = validate_previous_certificates(&header, &previous_certificates, &committee);
+    match committee.is_quorum_threshold_reached(&authors) {
+        true => assert_eq!(result, Ok(())),
+        false => assert_eq!(
+            result,
+            Err(BatchHeaderError::InsufficientPreviousStake {
+                stake: total_stake(&committee, &authors),
+                quorum_threshold: committee.quorum_threshold(),
+            })
+        ),
+    }
+
+    // Ensure a missing previous certificate is rejected.
+    let (certificate_id, _) = previous_certificates.first().unwrap();
+    let mut missing = previous_certificates.clone();
+    missing.shift_remove(certificate_id);
+    assert_eq!(
+        validate_previous_certificates(&header, &missing, &committee),
+        Err(BatchHeaderError::PreviousCertificateNotFound { certificate_id: *certificate_id })
+    );
+}
+
+#[proptest]
+fn transmissions(input: CommitteeContext, seed: u64, #[strategy(1..=MAX_TRANSMISSIONS)] num_transmissions: usize) {
+    let CommitteeContext(_, validators) = input;
+    let rng = &mut TestRng::fixed(seed);
+    let author = &sorted_validators(&validators)[0].private_key;
+
+    // Sample a batch with a ratification, alongside transactions.
+    let header = sample_header(author, 1, 0, num_transmissions, Default::default(), rng);
+    let mut transmission_ids = header.transmission_ids().clone();
+    transmission_ids.insert(TransmissionID::Ratification);
+    let header = BatchHeader::new(author, 1, 0, transmission_ids, Default::default(), rng).unwrap();
+
+    // Ensure the ratification matches its ID, and the transactions do not match the ratification.
+    assert_eq!(
+        validate_transmission(TransmissionID::<CurrentNetwork>::Ratification, &Transmission::Ratification),
+        Ok(())
+    );
+    let transaction_id = header.transmission_ids()[0];
+    assert_eq!(
+        validate_transmission(transaction_id, &Transmission::Ratification),
+        Err(TransmissionError::KindMismatch { transmission_id: transaction_id })
+    );
+
+    // Ensure missing transmissions are rejected.
+    let transmissions = [(TransmissionID::Ratification, Transmission::Ratification)].into_iter().collect();
+    assert_eq!(
+        validate_transmissions(&header, &transmissions),
+        Err(TransmissionError::Missing { transmission_id: transaction_id })
+    );
+
+    // Ensure unexpected transmissions are rejected.
+    let empty_header = BatchHeader::new(author, 1, 0, Default::default(), Default::default(), rng).unwrap();
+    assert_eq!(
+        validate_transmissions(&empty_header, &transmissions),
+        Err(TransmissionError::Unexpected { transmission_id: TransmissionID::Ratification })
+    );
+}
@@ -0,0 +1,61 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// Validates that the given transmission matches its transmission ID.
+/// Note: This method deserializes the transmission, if it is a buffer.
+pub fn validate_transmission<N: Network>(
+    transmission_id: TransmissionID<N>,
+    transmission: &Transmission<N>,
+) -> Result<(), TransmissionError<N>> {
+    let malformed = |error: Error| TransmissionError::Malformed { transmission_id, reason: error.to_string() };
+
+    match (transmission_id, transmission) {
+        (TransmissionID::Ratification, Transmission::Ratification) => Ok(()),
+        (TransmissionID::Solution(commitment), Transmission::Solution(solution)) => {
+            match solution.clone().deserialize_blocking().map_err(malformed)?.commitment() == commitment {
+                true => Ok(()),
+                false => Err(TransmissionError::IdMismatch { transmission_id }),
+            }
+        }
+        (TransmissionID::Transaction(transaction_id), Transmission::Transaction(transaction)) => {
+            match transaction.clone().deserialize_blocking().map_err(malformed)?.id() == transaction_id {
+                true => Ok(()),
+                false => Err(TransmissionError::IdMismatch { transmission_id }),
+            }
+        }
+        _ => Err(TransmissionError::KindMismatch { transmission_id }),
+    }
+}
+
+/// Validates that the given transmissions are exactly the transmissions of the batch header,
+/// and that each transmission matches its transmission ID.
+pub fn validate_transmissions<N: Network>(
+    header: &BatchHeader<N>,
+    transmissions: &IndexMap<TransmissionID<N>, Transmission<N>>,
+) -> Result<(), TransmissionError<N>> {
+    // Ensure every transmission in the batch header is given.
+    if let Some(transmission_id) = header.transmission_ids().iter().find(|id| !transmissions.contains_key(*id)) {
+        return Err(TransmissionError::Missing { transmission_id: *transmission_id });
+    }
+    // Ensure every given transmission is in the batch header, and matches its ID.
+    for (transmission_id, transmission) in transmissions {
+        if !header.contains(*transmission_id) {
+            return Err(TransmissionError::Unexpected { transmission_id: *transmission_id });
+        }
+        validate_transmission(*transmission_id, transmission)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,666 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::QueryTrait;
+use console::{
+    network::prelude::*,
+    program::{ProgramID, StatePath},
+    types::Field,
+};
+use synthesizer_program::Program;
+
+use core::{marker::PhantomData, time::Duration};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Instant,
+};
+
+/// The maximum number of responses in the cache.
+const MAX_CACHE_ENTRIES: usize = 4096;
+
+/// A response to an HTTP request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Response {
+    /// The status code.
+    pub status: u16,
+    /// The body.
+    pub body: String,
+}
+
+/// A transport for HTTP requests, which may be replaced to customize networking (e.g. proxies or TLS).
+pub trait Transport: Send + Sync {
+    /// Performs a GET request to the given URL.
+    /// Returns an error if the request could not be sent, or the response could not be received.
+    fn get(&self, url: &str) -> Result<Response>;
+}
+
+/// An asynchronous transport for HTTP requests, which is used by the asynchronous queries.
+#[cfg(feature = "async")]
+#[async_trait(?Send)]
+pub trait AsyncTransport {
+    /// Performs a GET request to the given URL.
+    /// Returns an error if the request could not be sent, or the response could not be received.
+    async fn get(&self, url: &str) -> Result<Response>;
+}
+
+/// The default transport, which uses a blocking HTTP agent, and an asynchronous HTTP client.
+#[derive(Clone)]
+pub struct DefaultTransport {
+    /// The HTTP agent.
+    agent: ureq::Agent,
+    /// The asynchronous HTTP client.
+    #[cfg(feature = "async")]
+    client: reqwest::Client,
+}
+
+impl Default for DefaultTransport {
+    /// Initializes the default transport, with a 30 second timeout.
+    fn default() -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().timeout(Duration::from_secs(30)).build(),
+            #[cfg(feature = "async")]
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+impl Transport for DefaultTransport {
+    /// Performs a GET request to the given URL.
+    fn get(&self, url: &str) -> Result<Response> {
+        match self.agent.get(url).call() {
+            Ok(response) => Ok(Response { status: response.status(), body: response.into_string()? }),
+            Err(ureq::Error::Status(status, response)) => {
+                Ok(Response { status, body: response.into_string().unwrap_or_default() })
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait(?Send)]
+impl AsyncTransport for DefaultTransport {
+    /// Performs a GET request to the given URL.
+    async fn get(&self, url: &str) -> Result<Response> {
+        let request = self.client.get(url);
+        #[cfg(not(target_family = "wasm"))]
+        let request = request.timeout(Duration::from_secs(30));
+        let response = request.send().await?;
+        Ok(Response { status: response.status().as_u16(), body: response.text().await? })
+    }
+}
+
+/// The policy for retrying failed requests, with exponential backoff.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The maximum number of retries, after the first attempt.
+    pub max_retries: u32,
+    /// The delay before the first retry, which doubles with each retry.
+    pub initial_backoff: Duration,
+    /// The maximum delay between retries.
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    /// Returns the default retry policy, with 3 retries, starting at 250 milliseconds.
+    fn default() -> Self {
+        Self { max_retries: 3, initial_backoff: Duration::from_millis(250), max_backoff: Duration::from_secs(5) }
+    }
+}
+
+impl RetryPolicy {
+    /// Returns the delay before the given retry, starting at 0.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        self.initial_backoff.saturating_mul(2u32.saturating_pow(retry)).min(self.max_backoff)
+    }
+}
+
+/// The cached state paths, which all share one global state root.
+struct StatePathCache<N: Network> {
+    /// The global state root of the cached state paths.
+    state_root: N::StateRoot,
+    /// The time at which the first state path for the global state root was cached.
+    time: Instant,
+    /// The map of commitments to state paths.
+    state_paths: HashMap<Field<N>, StatePath<N>>,
+}
+
+/// An HTTP client for the REST API of a node, with response caching, retries, and batched state paths.
+///
+/// The current state root is never cached. Cached state paths are keyed by their global state root,
+/// so the state paths returned together always share one global state root.
+#[derive(Clone)]
+pub struct RestClient<N: Network> {
+    /// The base URL of the node.
+    base_url: String,
+    /// The transport.
+    transport: Arc<dyn Transport>,
+    /// The asynchronous transport.
+    #[cfg(feature = "async")]
+    async_transport: Arc<dyn AsyncTransport>,
+    /// The retry policy.
+    retry_policy: RetryPolicy,
+    /// The duration for which a response is cached.
+    cache_ttl: Duration,
+    /// The cache, mapping a URL to the time of the response and the response body.
+    cache: Arc<Mutex<HashMap<String, (Instant, String)>>>,
+    /// The cached state paths for the most recent global state root.
+    state_paths: Arc<Mutex<Option<StatePathCache<N>>>>,
+    /// PhantomData.
+    _phantom: PhantomData<N>,
+}
+
+impl<N: Network> RestClient<N> {
+    /// Initializes a new client for the given base URL, with the default transport, retry policy,
+    /// and a cache duration of 10 seconds.
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            transport: Arc::new(DefaultTransport::default()),
+            #[cfg(feature = "async")]
+            async_transport: Arc::new(DefaultTransport::default()),
+            retry_policy: RetryPolicy::default(),
+            cache_ttl: Duration::from_secs(10),
+            cache: Default::default(),
+            state_paths: Default::default(),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Sets the transport.
+    pub fn with_transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Arc::new(transport);
+        self
+    }
+
+    /// Sets the asynchronous transport.
+    #[cfg(feature = "async")]
+    pub fn with_async_transport(mut self, transport: impl AsyncTransport + 'static) -> Self {
+        self.async_transport = Arc::new(transport);
+        self
+    }
+
+    /// Sets the retry policy.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets the duration for which a response is cached. A duration of zero disables the cache.
+    pub fn with_cache_ttl(mut self, cache_ttl: Duration) -> Self {
+        self.cache_ttl = cache_ttl;
+        self
+    }
+
+    /// Returns the base URL of the node.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Returns the program for the given program ID.
+    pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        let url = self.url(&format!("program/{program_id}"))?;
+        // Retrieve the cached response, or fetch and cache the response.
+        let body = match self.get_cached(&url) {
+            Some(body) => body,
+            None => {
+                let body = self.fetch(&url)?;
+                self.insert_cached(url, body.clone());
+                body
+            }
+        };
+        Ok(serde_json::from_str(&body)?)
+    }
+
+    /// Returns the program for the given program ID.
+    #[cfg(feature = "async")]
+    pub async fn get_program_async(&self, program_id: &ProgramID<N>) -> Result<Program<N>> {
+        let url = self.url(&format!("program/{program_id}"))?;
+        // Retrieve the cached response, or fetch and cache the response.
+        let body = match self.get_cached(&url) {
+            Some(body) => body,
+            None => {
+                let body = self.fetch_async(&url).await?;
+                self.insert_cached(url, body.clone());
+                body
+            }
+        };
+        Ok(serde_json::from_str(&body)?)
+    }
+
+    /// Returns the state paths for the given commitments, in the same order.
+    /// The state paths share one global state root.
+    ///
+    /// Cached state paths are returned without a request. If more than one state path is missing,
+    /// they are requested from the batch endpoint, and if it is unavailable, they are requested one by one.
+    /// If the global state root has changed since the cached state paths were requested,
+    /// every state path is requested again, at the new global state root.
+    pub fn get_state_paths_for_commitments(&self, commitments: &[Field<N>]) -> Result<Vec<StatePath<N>>> {
+        // Retrieve the cached state paths.
+        let mut state_paths = self.get_cached_state_paths(commitments);
+        // Collect the distinct missing commitments.
+        let mut missing: Vec<Field<N>> = Vec::new();
+        for commitment in commitments {
+            if !state_paths.contains_key(commitment) && !missing.contains(commitment) {
+                missing.push(*commitment);
+            }
+        }
+
+        if !missing.is_empty() {
+            let mut fetched = self.fetch_state_paths(&missing)?;
+            // If the global state root has changed, request every state path at the new global state root.
+            let is_stale = |fetched: &[StatePath<N>]| match (fetched.first(), state_paths.values().next()) {
+                (Some(fetched), Some(cached)) => fetched.global_state_root() != cached.global_state_root(),
+                _ => false,
+            };
+            if is_stale(&fetched) {
+                state_paths.clear();
+                missing = commitments.iter().copied().fold(Vec::new(), |mut missing, commitment| {
+                    if !missing.contains(&commitment) {
+                        missing.push(commitment);
+                    }
+                    missing
+                });
+                fetched = self.fetch_state_paths(&missing)?;
+            }
+            self.insert_cached_state_paths(&fetched);
+            state_paths.extend(missing.into_iter().zip_eq(fetched));
+        }
+
+        // Return the state paths in the given order.
+        let state_paths = commitments
+            .iter()
+            .map(|commitment| {
+                state_paths.get(commitment).cloned().ok_or_else(|| anyhow!("Missing state path for '{commitment}'"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // Ensure the state paths share one global state root.
+        if let Some(first) = state_paths.first() {
+            ensure!(
+                state_paths.iter().all(|state_path| state_path.global_state_root() == first.global_state_root()),
+                "The global state root changed while requesting the state paths"
+            );
+        }
+        Ok(state_paths)
+    }
+
+    /// Clears the cache.
+    pub fn clear_cache(&self) {
+        if let Ok(mut cache) = self.cache.lock() {
+            cache.clear();
+        }
+        if let Ok(mut state_paths) = self.state_paths.lock() {
+            *state_paths = None;
+        }
+    }
+
+    /// Returns the URL for the given path, under the network of the node.
+    fn url(&self, path: &str) -> Result<String> {
+        match N::ID {
+            3 => Ok(format!("{}/testnet3/{path}", self.base_url)),
+            _ => bail!("Unsupported network ID in inclusion query"),
+        }
+    }
+
+    /// Requests the state paths for the given distinct commitments, in the same order.
+    fn fetch_state_paths(&self, commitments: &[Field<N>]) -> Result<Vec<StatePath<N>>> {
+        // Request the state paths from the batch endpoint.
+        if commitments.len() > 1 {
+            let list = commitments.iter().map(|commitment| commitment.to_string()).collect::<Vec<_>>().join(",");
+            let url = self.url(&format!("statePaths?commitments={list}"))?;
+            if let Ok(body) = self.fetch(&url) {
+                if let Ok(batch) = serde_json::from_str::<Vec<StatePath<N>>>(&body) {
+                    if batch.len() == commitments.len() {
+                        return Ok(batch);
+                    }
+                }
+            }
+        }
+        // Otherwise, request the state paths one by one.
+        commitments
+            .iter()
+            .map(|commitment| Ok(serde_json::from_str(&self.fetch(&self.url(&format!("statePath/{commitment}"))?)?)?))
+            .collect()
+    }
+
+    /// Performs a GET request to the given URL, retrying on transport errors, rate limits, and server errors.
+    fn fetch(&self, url: &str) -> Result<String> {
+        let mut retry = 0;
+        loop {
+            let error = match Self::check_response(url, self.transport.get(url))? {
+                Ok(body) => return Ok(body),
+                Err(error) => error,
+            };
+            // Return the error if there are no retries left.
+            if retry >= self.retry_policy.max_retries {
+                return Err(error);
+            }
+            std::thread::sleep(self.retry_policy.backoff(retry));
+            retry += 1;
+        }
+    }
+
+    /// Performs an asynchronous GET request to the given URL, retrying on transport errors, rate limits, and server errors.
+    #[cfg(feature = "async")]
+    async fn fetch_async(&self, url: &str) -> Result<String> {
+        let mut retry = 0;
+        loop {
+            let error = match Self::check_response(url, self.async_transport.get(url).await)? {
+                Ok(body) => return Ok(body),
+                Err(error) => error,
+            };
+            // Return the error if there are no retries left.
+            if retry >= self.retry_policy.max_retries {
+                return Err(error);
+            }
+            // Note: There is no asynchronous timer on WebAssembly, so the retries are not delayed.
+            #[cfg(not(target_family = "wasm"))]
+            tokio::time::sleep(self.retry_policy.backoff(retry)).await;
+            retry += 1;
+        }
+    }
+
+    /// Returns the body of a successful response, or the error of a response that should be retried,
+    /// or fails if the response should not be retried.
+    fn check_response(url: &str, response: Result<Response>) -> Result<Result<String, Error>> {
+        match response {
+            Ok(Response { status: 200, body }) => Ok(Ok(body)),
+            // Retry if the node is rate limiting or failing.
+            Ok(Response { status, .. }) if status == 429 || status >= 500 => {
+                Ok(Err(anyhow!("Failed to fetch from {url} (status {status})")))
+            }
+            Ok(Response { status, body }) => bail!("Failed to fetch from {url} (status {status}): {body}"),
+            Err(error) => Ok(Err(anyhow!("Failed to fetch from {url}: {error}"))),
+        }
+    }
+
+    /// Returns the cached response for the given URL, if it has not expired.
+    fn get_cached(&self, url: &str) -> Option<String> {
+        let cache = self.cache.lock().ok()?;
+        match cache.get(url) {
+            Some((time, body)) if time.elapsed() < self.cache_ttl => Some(body.clone()),
+            _ => None,
+        }
+    }
+
+    /// Caches the response for the given URL.
+    fn insert_cached(&self, url: String, body: String) {
+        if self.cache_ttl.is_zero() {
+            return;
+        }
+        if let Ok(mut cache) = self.cache.lock() {
+            // If the cache is full, remove the expired responses, and if it is still full, clear it.
+            if cache.len() >= MAX_CACHE_ENTRIES {
+                cache.retain(|_, (time, _)| time.elapsed() < self.cache_ttl);
+                if cache.len() >= MAX_CACHE_ENTRIES {
+                    cache.clear();
+                }
+            }
+            cache.insert(url, (Instant::now(), body));
+        }
+    }
+
+    /// Returns the cached state paths for the given commitments, if they have not expired.
+    fn get_cached_state_paths(&self, commitments: &[Field<N>]) -> HashMap<Field<N>, StatePath<N>> {
+        let Ok(cache) = self.state_paths.lock() else { return HashMap::new() };
+        match &*cache {
+            Some(cache) if cache.time.elapsed() < self.cache_ttl => commitments
+                .iter()
+                .filter_map(|commitment| Some((*commitment, cache.state_paths.get(commitment)?.clone())))
+                .collect(),
+            _ => HashMap::new(),
+        }
+    }
+
+    /// Caches the given state paths, which share one global state root.
+    /// If the global state root differs from that of the cached state paths, the cached state paths are replaced.
+    fn insert_cached_state_paths(&self, state_paths: &[StatePath<N>]) {
+        if self.cache_ttl.is_zero() {
+            return;
+        }
+        let Ok(mut cache) = self.state_paths.lock() else { return };
+        for state_path in state_paths {
+            let state_root = state_path.global_state_root();
+            let cache = match &mut *cache {
+                Some(cache)
+                    if cache.state_root == state_root
+                        && cache.time.elapsed() < self.cache_ttl
+                        && cache.state_paths.len() < MAX_CACHE_ENTRIES =>
+                {
+                    cache
+                }
+                cache => cache.insert(StatePathCache { state_root, time: Instant::now(), state_paths: HashMap::new() }),
+            };
+            cache.state_paths.insert(state_path.transition_leaf().id(), state_path.clone());
+        }
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait(?Send))]
+impl<N: Network> QueryTrait<N> for RestClient<N> {
+    /// Returns the current state root.
+    /// Note: The current state root is never cached.
+    fn current_state_root(&self) -> Result<N::StateRoot> {
+        Ok(serde_json::from_str(&self.fetch(&self.url("latest/stateRoot")?)?)?)
+    }
+
+    /// Returns the current state root.
+    /// Note: The current state root is never cached.
+    #[cfg(feature = "async")]
+    async fn current_state_root_async(&self) -> Result<N::StateRoot> {
+        Ok(serde_json::from_str(&self.fetch_async(&self.url("latest/stateRoot")?).await?)?)
+    }
+
+    /// Returns a state path for the given `commitment`.
+    fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        if let Some(state_path) = self.get_cached_state_paths(&[*commitment]).remove(commitment) {
+            return Ok(state_path);
+        }
+        let state_path: StatePath<N> =
+            serde_json::from_str(&self.fetch(&self.url(&format!("statePath/{commitment}"))?)?)?;
+        self.insert_cached_state_paths(core::slice::from_ref(&state_path));
+        Ok(state_path)
+    }
+
+    /// Returns a state path for the given `commitment`.
+    #[cfg(feature = "async")]
+    async fn get_state_path_for_commitment_async(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        if let Some(state_path) = self.get_cached_state_paths(&[*commitment]).remove(commitment) {
+            return Ok(state_path);
+        }
+        let state_path: StatePath<N> =
+            serde_json::from_str(&self.fetch_async(&self.url(&format!("statePath/{commitment}"))?).await?)?;
+        self.insert_cached_state_paths(core::slice::from_ref(&state_path));
+        Ok(state_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::Testnet3, program::test_helpers::sample_global_state_path};
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    type CurrentNetwork = Testnet3;
+
+    /// A transport that returns the given responses in order, and records the requested URLs.
+    #[derive(Default)]
+    struct MockTransport {
+        responses: Mutex<Vec<Result<Response>>>,
+        urls: Mutex<Vec<String>>,
+        num_requests: AtomicUsize,
+    }
+
+    impl MockTransport {
+        fn new(responses: Vec<Result<Response>>) -> Arc<Self> {
+            Arc::new(Self { responses: Mutex::new(responses), ..Default::default() })
+        }
+    }
+
+    impl Transport for Arc<MockTransport> {
+        fn get(&self, url: &str) -> Result<Response> {
+            self.num_requests.fetch_add(1, Ordering::SeqCst);
+            self.urls.lock().unwrap().push(url.to_string());
+            self.responses.lock().unwrap().remove(0)
+        }
+    }
+
+    fn ok(body: String) -> Result<Response> {
+        Ok(Response { status: 200, body })
+    }
+
+    fn mock_client(transport: &Arc<MockTransport>) -> RestClient<CurrentNetwork> {
+        let retry_policy = RetryPolicy {
+            max_retries: 2,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(1),
+        };
+        RestClient::new("http://node/").with_transport(transport.clone()).with_retry_policy(retry_policy)
+    }
+
+    /// Returns the given state path, at the given global state root.
+    fn with_state_root(
+        state_path: &StatePath<CurrentNetwork>,
+        state_root: <CurrentNetwork as Network>::StateRoot,
+    ) -> StatePath<CurrentNetwork> {
+        StatePath::from(
+            state_root,
+            state_path.block_path().clone(),
+            state_path.block_hash(),
+            state_path.previous_block_hash(),
+            *state_path.header_root(),
+            state_path.header_path().clone(),
+            *state_path.header_leaf(),
+            state_path.transactions_path().clone(),
+            *state_path.transaction_id(),
+            state_path.transaction_path().clone(),
+            *state_path.transaction_leaf(),
+            *state_path.transition_root(),
+            *state_path.tcm(),
+            state_path.transition_path().clone(),
+            *state_path.transition_leaf(),
+        )
+    }
+
+    /// Samples the given number of state paths, at one global state root.
+    fn sample_state_paths(num_paths: usize, rng: &mut TestRng) -> Result<Vec<StatePath<CurrentNetwork>>> {
+        let state_root = <CurrentNetwork as Network>::StateRoot::from(Field::rand(rng));
+        (0..num_paths)
+            .map(|_| Ok(with_state_root(&sample_global_state_path::<CurrentNetwork>(None, rng)?, state_root)))
+            .collect()
+    }
+
+    #[test]
+    fn test_retry_and_cache() -> Result<()> {
+        let rng = &mut TestRng::default();
+        let state_root = <CurrentNetwork as Network>::StateRoot::from(Field::rand(rng));
+        let body = serde_json::to_string(&state_root)?;
+
+        // Ensure transport errors and server errors are retried.
+        let transport = MockTransport::new(vec![
+            Err(anyhow!("Connection reset")),
+            Ok(Response { status: 503, body: String::new() }),
+            ok(body.clone()),
+            ok(body.clone()),
+        ]);
+        let client = mock_client(&transport);
+        assert_eq!(client.current_state_root()?, state_root);
+        assert_eq!(transport.num_requests.load(Ordering::SeqCst), 3);
+        assert_eq!(transport.urls.lock().unwrap()[0], "http://node/testnet3/latest/stateRoot");
+
+        // Ensure the current state root is not cached.
+        assert_eq!(client.current_state_root()?, state_root);
+        assert_eq!(transport.num_requests.load(Ordering::SeqCst), 4);
+
+        // Ensure client errors are not retried.
+        let transport = MockTransport::new(vec![Ok(Response { status: 404, body: String::new() }), ok(body)]);
+        assert!(mock_client(&transport).current_state_root().is_err());
+        assert_eq!(transport.num_requests.load(Ordering::SeqCst), 1);
+
+        // Ensure the retries are bounded.
+        let transport = MockTransport::new((0..3).map(|_| Err(anyhow!("Connection refused"))).collect());
+        assert!(mock_client(&transport).current_state_root().is_err());
+        assert_eq!(transport.num_requests.load(Ordering::SeqCst), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_state_paths_for_commitments() -> Result<()> {
+        let rng = &mut TestRng::default();
+        let state_paths = sample_state_paths(3, rng)?;
+        let commitments = state_paths.iter().map(|state_path| state_path.transition_leaf().id()).collect::<Vec<_>>();
+
+        // Ensure the missing state paths are requested in one batch, and are cached.
+        let transport = MockTransport::new(vec![ok(serde_json::to_string(&state_paths)?)]);
+        let client = mock_client(&transport);
+        assert_eq!(client.get_state_paths_for_commitments(&commitments)?, state_paths);
+        assert_eq!(client.get_state_path_for_commitment(&commitments[1])?, state_paths[1]);
+        assert_eq!(transport.num_requests.load(Ordering::SeqCst), 1);
+
+        // Ensure the state paths are requested one by one, if the batch endpoint is unavailable.
+        let transport = MockTransport::new(vec![
+            Ok(Response { status: 404, body: String::new() }),
+            ok(serde_json::to_string(&state_paths[0])?),
+            ok(serde_json::to_string(&state_paths[2])?),
+        ]);
+        let client = mock_client(&transport);
+        let expected = vec![state_paths[0].clone(), state_paths[2].clone(), state_paths[0].clone()];
+        assert_eq!(
+            client.get_state_paths_for_commitments(&[commitments[0], commitments[2], commitments[0]])?,
+            expected
+        );
+        assert_eq!(transport.num_requests.load(Ordering::SeqCst), 3);
+
+        // Ensure state paths at different global state roots are rejected.
+        let mixed = vec![state_paths[0].clone(), sample_global_state_path::<CurrentNetwork>(None, rng)?];
+        let commitments = mixed.iter().map(|state_path| state_path.transition_leaf().id()).collect::<Vec<_>>();
+        let transport = MockTransport::new(vec![ok(serde_json::to_string(&mixed)?)]);
+        assert!(mock_client(&transport).get_state_paths_for_commitments(&commitments).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_state_root_change_refetches_state_paths() -> Result<()> {
+        let rng = &mut TestRng::default();
+        let state_paths = sample_state_paths(2, rng)?;
+        let commitments = state_paths.iter().map(|state_path| state_path.transition_leaf().id()).collect::<Vec<_>>();
+
+        // Cache the first state path.
+        let new_state_root = <CurrentNetwork as Network>::StateRoot::from(Field::rand(rng));
+        let updated =
+            state_paths.iter().map(|state_path| with_state_root(state_path, new_state_root)).collect::<Vec<_>>();
+        let transport = MockTransport::new(vec![
+            ok(serde_json::to_string(&state_paths[0])?),
+            ok(serde_json::to_string(&updated[1])?),
+            ok(serde_json::to_string(&updated)?),
+        ]);
+        let client = mock_client(&transport);
+        assert_eq!(client.get_state_path_for_commitment(&commitments[0])?, state_paths[0]);
+
+        // Ensure the cached state path is requested again, once the global state root has changed.
+        assert_eq!(client.get_state_paths_for_commitments(&commitments)?, updated);
+        assert_eq!(transport.num_requests.load(Ordering::SeqCst), 3);
+        assert_eq!(
+            transport.urls.lock().unwrap()[2],
+            format!("http://node/testnet3/statePaths?commitments={},{}", commitments[0], commitments[1])
+        );
+
+        // Ensure the state paths at the new global state root are cached.
+        assert_eq!(client.get_state_path_for_commitment(&commitments[0])?, updated[0]);
+        assert_eq!(transport.num_requests.load(Ordering::SeqCst), 3);
+        Ok(())
+    }
+}
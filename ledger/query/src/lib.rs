@@ -18,10 +18,20 @@
 #[cfg_attr(feature = "async", macro_use)]
 extern crate async_trait;
 
+#[cfg(feature = "query")]
+mod client;
+#[cfg(feature = "query")]
+pub use client::*;
+
 #[cfg(feature = "query")]
 mod query;
 #[cfg(feature = "query")]
 pub use query::*;
 
+#[cfg(feature = "query")]
+mod static_query;
+#[cfg(feature = "query")]
+pub use static_query::*;
+
 mod traits;
 pub use traits::*;
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::QueryTrait;
+use crate::{QueryTrait, RestClient, StaticQuery};
 use console::{
     network::prelude::*,
     program::{ProgramID, StatePath},
@@ -27,6 +27,10 @@ pub enum Query<N: Network, B: BlockStorage<N>> {
     VM(BlockStore<N, B>),
     /// The base URL of the node.
     REST(String),
+    /// The REST client for the node, with caching and retries.
+    Client(RestClient<N>),
+    /// The state root and state paths exported ahead of time, for offline use.
+    Static(StaticQuery<N>),
 }
 
 impl<N: Network, B: BlockStorage<N>> From<BlockStore<N, B>> for Query<N, B> {
@@ -41,6 +45,18 @@ impl<N: Network, B: BlockStorage<N>> From<&BlockStore<N, B>> for Query<N, B> {
     }
 }
 
+impl<N: Network, B: BlockStorage<N>> From<RestClient<N>> for Query<N, B> {
+    fn from(client: RestClient<N>) -> Self {
+        Self::Client(client)
+    }
+}
+
+impl<N: Network, B: BlockStorage<N>> From<StaticQuery<N>> for Query<N, B> {
+    fn from(query: StaticQuery<N>) -> Self {
+        Self::Static(query)
+    }
+}
+
 impl<N: Network, B: BlockStorage<N>> From<String> for Query<N, B> {
     fn from(url: String) -> Self {
         Self::REST(url)
@@ -69,6 +85,8 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
                 3 => Ok(Self::get_request(&format!("{url}/testnet3/latest/stateRoot"))?.into_json()?),
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
+            Self::Client(client) => client.current_state_root(),
+            Self::Static(query) => query.current_state_root(),
         }
     }
 
@@ -81,6 +99,8 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
                 3 => Ok(Self::get_request_async(&format!("{url}/testnet3/latest/stateRoot")).await?.json().await?),
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
+            Self::Client(client) => client.current_state_root_async().await,
+            Self::Static(query) => query.current_state_root(),
         }
     }
 
@@ -92,6 +112,8 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
                 3 => Ok(Self::get_request(&format!("{url}/testnet3/statePath/{commitment}"))?.into_json()?),
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
+            Self::Client(client) => client.get_state_path_for_commitment(commitment),
+            Self::Static(query) => query.get_state_path_for_commitment(commitment),
         }
     }
 
@@ -106,6 +128,8 @@ impl<N: Network, B: BlockStorage<N>> QueryTrait<N> for Query<N, B> {
                 }
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
+            Self::Client(client) => client.get_state_path_for_commitment_async(commitment).await,
+            Self::Static(query) => query.get_state_path_for_commitment(commitment),
         }
     }
 }
@@ -121,6 +145,8 @@ impl<N: Network, B: BlockStorage<N>> Query<N, B> {
                 3 => Ok(Self::get_request(&format!("{url}/testnet3/program/{program_id}"))?.into_json()?),
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
+            Self::Client(client) => client.get_program(program_id),
+            Self::Static(_) => bail!("Programs are not available in a static query"),
         }
     }
 
@@ -135,6 +161,8 @@ impl<N: Network, B: BlockStorage<N>> Query<N, B> {
                 3 => Ok(Self::get_request_async(&format!("{url}/testnet3/program/{program_id}")).await?.json().await?),
                 _ => bail!("Unsupported network ID in inclusion query"),
             },
+            Self::Client(client) => client.get_program_async(program_id).await,
+            Self::Static(_) => bail!("Programs are not available in a static query"),
         }
     }
 
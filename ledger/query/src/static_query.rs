@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::QueryTrait;
+use console::{network::prelude::*, program::StatePath, types::Field};
+
+use std::{collections::HashMap, path::Path};
+
+/// A query backed by a state root and state paths that were exported ahead of time,
+/// which enables executions to be generated offline and broadcast later.
+///
+/// The exported state is stored as JSON, in the form `{ "state_root": "...", "state_paths": ["...", ...] }`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct StaticQuery<N: Network> {
+    /// The global state root.
+    state_root: N::StateRoot,
+    /// The state paths, keyed by commitment.
+    state_paths: HashMap<Field<N>, StatePath<N>>,
+}
+
+impl<N: Network> StaticQuery<N> {
+    /// Initializes a new static query from the given state root and state paths.
+    /// Each state path must be a path to the given state root.
+    pub fn new(state_root: N::StateRoot, state_paths: Vec<StatePath<N>>) -> Result<Self> {
+        // Ensure the global state root is not zero.
+        ensure!(*state_root != Field::zero(), "The global state root in a static query must not be zero");
+        // Ensure the state paths are to the global state root.
+        if let Some(state_path) = state_paths.iter().find(|state_path| state_path.global_state_root() != state_root) {
+            bail!("The state path for '{}' is not to the state root '{state_root}'", state_path.transition_leaf().id())
+        }
+        // Index the state paths by commitment.
+        let state_paths = state_paths.into_iter().map(|state_path| (state_path.transition_leaf().id(), state_path));
+        Ok(Self { state_root, state_paths: state_paths.collect() })
+    }
+
+    /// Exports the state paths for the given commitments from the given query, as a static query.
+    pub fn export(query: &impl QueryTrait<N>, commitments: &[Field<N>]) -> Result<Self> {
+        // Retrieve the state paths.
+        let state_paths = commitments
+            .iter()
+            .map(|commitment| query.get_state_path_for_commitment(commitment))
+            .collect::<Result<Vec<_>>>()?;
+        // Use the state root of the state paths, or the current state root if there are no state paths.
+        // Note: If the state root changes while the state paths are retrieved, the export fails and can be retried.
+        let state_root = match state_paths.first() {
+            Some(state_path) => state_path.global_state_root(),
+            None => query.current_state_root()?,
+        };
+        Self::new(state_root, state_paths)
+    }
+
+    /// Loads a static query from the given file.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_str(&std::fs::read_to_string(path)?)
+    }
+
+    /// Saves the static query to the given file.
+    pub fn to_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        Ok(std::fs::write(path, self.to_string())?)
+    }
+
+    /// Returns the global state root.
+    pub const fn state_root(&self) -> N::StateRoot {
+        self.state_root
+    }
+
+    /// Returns the number of state paths.
+    pub fn num_state_paths(&self) -> usize {
+        self.state_paths.len()
+    }
+}
+
+#[cfg_attr(feature = "async", async_trait(?Send))]
+impl<N: Network> QueryTrait<N> for StaticQuery<N> {
+    /// Returns the current state root.
+    fn current_state_root(&self) -> Result<N::StateRoot> {
+        Ok(self.state_root)
+    }
+
+    /// Returns the current state root.
+    #[cfg(feature = "async")]
+    async fn current_state_root_async(&self) -> Result<N::StateRoot> {
+        self.current_state_root()
+    }
+
+    /// Returns a state path for the given `commitment`.
+    fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        match self.state_paths.get(commitment) {
+            Some(state_path) => Ok(state_path.clone()),
+            None => bail!("Missing state path for '{commitment}' in the static query"),
+        }
+    }
+
+    /// Returns a state path for the given `commitment`.
+    #[cfg(feature = "async")]
+    async fn get_state_path_for_commitment_async(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
+        self.get_state_path_for_commitment(commitment)
+    }
+}
+
+impl<N: Network> FromStr for StaticQuery<N> {
+    type Err = Error;
+
+    /// Initializes the static query from a JSON-string.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let object = serde_json::from_str::<serde_json::Value>(string)?;
+        // Parse the state root.
+        let state_root = match object.get("state_root").and_then(|value| value.as_str()) {
+            Some(state_root) => {
+                N::StateRoot::from_str(state_root).map_err(|_| anyhow!("Invalid state root in the static query"))?
+            }
+            None => bail!("Missing the state root in the static query"),
+        };
+        // Parse the state paths.
+        let state_paths = match object.get("state_paths").and_then(|value| value.as_array()) {
+            Some(state_paths) => state_paths
+                .iter()
+                .map(|state_path| match state_path.as_str() {
+                    Some(state_path) => StatePath::from_str(state_path),
+                    None => bail!("Invalid state path in the static query"),
+                })
+                .collect::<Result<Vec<_>>>()?,
+            None => bail!("Missing the state paths in the static query"),
+        };
+        Self::new(state_root, state_paths)
+    }
+}
+
+impl<N: Network> Debug for StaticQuery<N> {
+    /// Prints the static query as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for StaticQuery<N> {
+    /// Displays the static query as a JSON-string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let object = serde_json::json!({
+            "state_root": self.state_root.to_string(),
+            "state_paths": self.state_paths.values().map(|state_path| state_path.to_string()).collect::<Vec<_>>(),
+        });
+        write!(f, "{object}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{network::Testnet3, program::test_helpers::sample_global_state_path};
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_static_query() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a state path.
+        let state_path = sample_global_state_path::<CurrentNetwork>(None, rng)?;
+        let commitment = state_path.transition_leaf().id();
+
+        // Initialize the static query.
+        let source = StaticQuery::new(state_path.global_state_root(), vec![state_path.clone()])?;
+        let query = StaticQuery::export(&source, &[commitment])?;
+        assert_eq!(query.current_state_root()?, state_path.global_state_root());
+        assert_eq!(query.get_state_path_for_commitment(&commitment)?, state_path);
+        assert!(query.get_state_path_for_commitment(&Field::rand(rng)).is_err());
+
+        // Ensure the static query round-trips through a file.
+        let file = std::env::temp_dir().join(format!("static-query-{}.json", u64::rand(rng)));
+        query.to_file(&file)?;
+        assert_eq!(StaticQuery::<CurrentNetwork>::from_file(&file)?, query);
+        std::fs::remove_file(file)?;
+
+        // Ensure a state path to another state root is rejected.
+        let other_state_path = sample_global_state_path::<CurrentNetwork>(None, rng)?;
+        assert!(StaticQuery::new(state_path.global_state_root(), vec![state_path, other_state_path]).is_err());
+        Ok(())
+    }
+}
@@ -74,12 +74,42 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         *current_block = block.clone();
         // Drop the write lock on the current block.
         drop(current_block);
+        // Clear the cached state paths, as they are to the previous state root.
+        self.clear_state_paths();
 
         // If the block is the start of a new epoch, or the epoch challenge has not been set, update the current epoch challenge.
         if block.height() % N::NUM_BLOCKS_PER_EPOCH == 0 || self.current_epoch_challenge.read().is_none() {
             // Update the current epoch challenge.
             self.current_epoch_challenge.write().clone_from(&self.get_epoch_challenge(block.height()).ok());
         }
+
+        // Notify the subscribers of the block.
+        self.notify_subscribers(block);
+        Ok(())
+    }
+
+    /// Reverts the ledger to the given block height, removing all blocks above the given height.
+    /// This unwinds the blocks, the finalize state, and the committees in a single atomic batch.
+    pub fn revert_to(&self, height: u32) -> Result<()> {
+        // Acquire the write lock on the current block.
+        let mut current_block = self.current_block.write();
+        // Ensure the given height is less than the current height.
+        ensure!(
+            height < current_block.height(),
+            "Cannot revert to block {height}, as the latest block is {}",
+            current_block.height()
+        );
+        // Revert the VM.
+        self.vm.revert_to(height)?;
+        // Update the current block.
+        *current_block = self.get_block(height)?;
+        // Drop the write lock on the current block.
+        drop(current_block);
+        // Clear the cached state paths, as they are to the reverted state root.
+        self.clear_state_paths();
+
+        // Update the current epoch challenge.
+        self.current_epoch_challenge.write().clone_from(&self.get_epoch_challenge(height).ok());
         Ok(())
     }
 }
@@ -157,22 +187,14 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             false => (previous_block.last_coinbase_target(), previous_block.last_coinbase_timestamp()),
         };
 
-        // Calculate the coinbase reward.
-        let coinbase_reward = ledger_block::coinbase_reward(
+        // Calculate the coinbase reward, block reward, and puzzle reward.
+        let crate::rewards::Rewards { block_reward, puzzle_reward, .. } = crate::rewards::Rewards::new::<N>(
             next_height,
-            N::STARTING_SUPPLY,
-            N::ANCHOR_HEIGHT,
-            N::BLOCK_TIME,
             combined_proof_target,
             u64::try_from(latest_cumulative_proof_target)?,
             latest_coinbase_target,
         )?;
 
-        // Compute the block reward.
-        let block_reward = ledger_block::block_reward(N::STARTING_SUPPLY, N::BLOCK_TIME, coinbase_reward);
-        // Compute the puzzle reward.
-        let puzzle_reward = coinbase_reward.saturating_div(2);
-
         // TODO (howardwu): We must first process the candidate ratifications to filter out invalid ratifications.
         //  We must ensure Ratify::Genesis is only present in the genesis block.
         // Construct the ratifications.
@@ -200,8 +222,10 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             previous_block.hash(),
         )?;
         // Select the transactions from the memory pool.
-        let (transactions, _aborted) =
-            self.vm.speculate(state, &ratifications, solutions.as_ref(), candidate_transactions.iter())?;
+        let (ratifications, transactions) = self
+            .vm
+            .propose_block(state, ratifications, solutions.as_ref(), candidate_transactions.iter(), Default::default())?
+            .into_parts();
 
         // Construct the metadata.
         let metadata = Metadata::new(
@@ -38,20 +38,22 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             }
         }
 
-        // Ensure each transaction is well-formed and unique.
-        // TODO: this intermediate allocation shouldn't be necessary; this is most likely https://github.com/rust-lang/rust/issues/89418.
-        let transactions = block.transactions().iter().collect::<Vec<_>>();
-        cfg_iter!(transactions).try_for_each(|transaction| {
-            // Construct the rejected ID.
-            let rejected_id = match transaction {
-                ConfirmedTransaction::AcceptedDeploy(..) | ConfirmedTransaction::AcceptedExecute(..) => None,
-                ConfirmedTransaction::RejectedDeploy(_, _, rejected) => Some(rejected.to_id()?),
-                ConfirmedTransaction::RejectedExecute(_, _, rejected) => Some(rejected.to_id()?),
-            };
-
-            self.check_transaction_basic(*transaction, rejected_id)
-                .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))
-        })?;
+        // Ensure each transaction is well-formed and unique, verifying their signatures and proofs together.
+        let transactions = block
+            .transactions()
+            .iter()
+            .map(|transaction| {
+                // Construct the rejected ID.
+                let rejected_id = match transaction {
+                    ConfirmedTransaction::AcceptedDeploy(..) | ConfirmedTransaction::AcceptedExecute(..) => None,
+                    ConfirmedTransaction::RejectedDeploy(_, _, rejected) => Some(rejected.to_id()?),
+                    ConfirmedTransaction::RejectedExecute(_, _, rejected) => Some(rejected.to_id()?),
+                };
+                Ok((&**transaction, rejected_id))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.check_transactions_basic(&transactions)
+            .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))?;
 
         // Ensure the block is correct.
         block.verify(
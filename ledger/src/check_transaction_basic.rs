@@ -17,6 +17,22 @@ use super::*;
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Checks the given transaction is well-formed and unique.
     pub fn check_transaction_basic(&self, transaction: &Transaction<N>, rejected_id: Option<Field<N>>) -> Result<()> {
+        self.check_transactions_basic(&[(transaction, rejected_id)])
+    }
+
+    /// Checks the given transactions, each with its rejected ID (if any), are well-formed and unique.
+    /// The signatures and proofs of the transactions are verified together.
+    /// Note: This does *not* check for duplicate elements across the given transactions.
+    pub fn check_transactions_basic(&self, transactions: &[(&Transaction<N>, Option<Field<N>>)]) -> Result<()> {
+        // Ensure the fee and IDs of each transaction are valid.
+        cfg_iter!(transactions).try_for_each(|(transaction, _)| self.check_transaction_fee_and_ids(transaction))?;
+
+        // Ensure the transactions are valid.
+        self.vm().check_transactions_batch(transactions)
+    }
+
+    /// Checks the given transaction has a sufficient fee and consistent IDs.
+    fn check_transaction_fee_and_ids(&self, transaction: &Transaction<N>) -> Result<()> {
         let transaction_id = transaction.id();
 
         /* Fee */
@@ -30,12 +46,16 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         if is_fee_required {
             // Retrieve the transaction fee.
             let fee_amount = *transaction.fee_amount()?;
+            // Determine the height of the block the transaction is checked for.
+            let block_height = self.latest_height().saturating_add(1);
             // Retrieve the minimum cost of the transaction.
             let (cost, _) = match transaction {
                 // Compute the deployment cost.
-                Transaction::Deploy(_, _, deployment, _) => synthesizer::deployment_cost(deployment)?,
+                Transaction::Deploy(_, _, deployment, _) => synthesizer::deployment_cost(deployment, block_height)?,
                 // Compute the execution cost.
-                Transaction::Execute(_, execution, _) => synthesizer::execution_cost(self.vm(), execution)?,
+                Transaction::Execute(_, execution, _) => {
+                    synthesizer::execution_cost(self.vm(), execution, block_height)?
+                }
                 // TODO (howardwu): Plug in the Rejected struct, to compute the cost.
                 Transaction::Fee(_, _) => (0, (0, 0)),
             };
@@ -47,8 +67,11 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
         /* Transaction */
 
-        // Ensure the transaction is valid.
-        self.vm().check_transaction(transaction, rejected_id)?;
+        // Ensure the IDs in the transaction match its contents.
+        let mismatches = transaction.verify_id_integrity()?;
+        if !mismatches.is_empty() {
+            bail!("Transaction '{transaction_id}' has inconsistent IDs - {}", mismatches.iter().join("; "))
+        }
 
         Ok(())
     }
@@ -0,0 +1,219 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use ledger_store::helpers::memory::ConsensusMemory;
+
+/// The puzzle parameters of a genesis block.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PuzzleParameters {
+    /// The coinbase target of the genesis block.
+    pub coinbase_target: u64,
+    /// The proof target of the genesis block.
+    pub proof_target: u64,
+    /// The timestamp of the genesis block.
+    pub timestamp: i64,
+}
+
+impl PuzzleParameters {
+    /// Returns the puzzle parameters of the given network.
+    pub const fn new<N: Network>() -> Self {
+        Self {
+            coinbase_target: N::GENESIS_COINBASE_TARGET,
+            proof_target: N::GENESIS_PROOF_TARGET,
+            timestamp: N::GENESIS_TIMESTAMP,
+        }
+    }
+}
+
+/// The parameters of a genesis block, as required to reproduce and operate a network from it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GenesisParameters<N: Network> {
+    /// The hash of the genesis block.
+    pub genesis_hash: N::BlockHash,
+    /// The genesis committee.
+    pub committee: Committee<N>,
+    /// The genesis public balances, in microcredits.
+    pub public_balances: IndexMap<Address<N>, u64>,
+    /// The starting supply, in microcredits.
+    pub starting_supply: u64,
+    /// The puzzle parameters.
+    pub puzzle: PuzzleParameters,
+    /// The deployment transactions of the preloaded programs, to be included in the block after genesis.
+    pub deployments: Vec<Transaction<N>>,
+}
+
+/// A builder for the genesis block of a custom network.
+///
+/// The genesis account (given by the private key) signs the genesis block, and pays for the deployments
+/// of the preloaded programs. Any supply that is not staked or assigned a public balance is assigned to it.
+#[derive(Clone)]
+pub struct GenesisBuilder<N: Network> {
+    /// The private key of the genesis account.
+    private_key: PrivateKey<N>,
+    /// The committee members, as a map of `address` to `(stake, is_open)`.
+    members: IndexMap<Address<N>, (u64, bool)>,
+    /// The public balances, in microcredits.
+    public_balances: IndexMap<Address<N>, u64>,
+    /// The preloaded programs, in deployment order.
+    programs: Vec<Program<N>>,
+}
+
+impl<N: Network> GenesisBuilder<N> {
+    /// Initializes a new genesis builder for the given genesis account.
+    pub fn new(private_key: PrivateKey<N>) -> Self {
+        Self {
+            private_key,
+            members: Default::default(),
+            public_balances: Default::default(),
+            programs: Default::default(),
+        }
+    }
+
+    /// Adds a committee member with the given stake (in microcredits), and whether it is open to delegators.
+    pub fn member(mut self, address: Address<N>, stake: u64, is_open: bool) -> Self {
+        self.members.insert(address, (stake, is_open));
+        self
+    }
+
+    /// Adds the given public balance (in microcredits) to the given address.
+    pub fn public_balance(mut self, address: Address<N>, microcredits: u64) -> Self {
+        let balance = self.public_balances.entry(address).or_default();
+        *balance = balance.saturating_add(microcredits);
+        self
+    }
+
+    /// Adds a program to deploy after the genesis block.
+    /// Note: A program may only import `credits.aleo` and the programs added before it.
+    pub fn program(mut self, program: Program<N>) -> Self {
+        self.programs.push(program);
+        self
+    }
+
+    /// Returns the genesis block and its parameters.
+    /// Note: The puzzle parameters of the genesis block are fixed by the network.
+    pub fn build<R: Rng + CryptoRng>(self, rng: &mut R) -> Result<(Block<N>, GenesisParameters<N>)> {
+        let Self { private_key, members, mut public_balances, programs } = self;
+
+        // Construct the committee.
+        let committee = Committee::<N>::new_genesis(members)?;
+
+        // Compute the allocated supply.
+        let allocated_supply = public_balances
+            .values()
+            .try_fold(committee.total_stake(), |total, balance| total.checked_add(*balance))
+            .ok_or_else(|| anyhow!("The genesis supply overflowed"))?;
+        ensure!(
+            allocated_supply <= N::STARTING_SUPPLY,
+            "The genesis supply ({allocated_supply} microcredits) exceeds the starting supply ({} microcredits)",
+            N::STARTING_SUPPLY
+        );
+        // Assign the remaining supply to the genesis account.
+        let address = Address::try_from(&private_key)?;
+        let balance = public_balances.entry(address).or_default();
+        *balance += N::STARTING_SUPPLY - allocated_supply;
+        // Ensure the genesis account can fund the genesis transactions.
+        let genesis_amount = ledger_committee::MIN_VALIDATOR_STAKE * Block::<N>::NUM_GENESIS_TRANSACTIONS as u64;
+        ensure!(
+            *balance >= genesis_amount,
+            "The genesis account must hold at least {genesis_amount} microcredits, found {balance}"
+        );
+
+        // Initialize an in-memory VM.
+        let vm = VM::from(ConsensusStore::<N, ConsensusMemory<N>>::open(None)?)?;
+        // Construct the genesis block.
+        let block = vm.genesis_quorum(&private_key, committee.clone(), public_balances.clone(), rng)?;
+        // Add the genesis block, so the deployments may pay their fees from the genesis account.
+        vm.add_next_block(&block)?;
+
+        // Construct the deployments.
+        let mut deployments = Vec::with_capacity(programs.len());
+        for program in &programs {
+            deployments.push(vm.deploy(&private_key, program, None, 0, None, rng)?);
+            // Add the program to the process, so later programs may import it.
+            vm.process().write().add_program(program)?;
+        }
+
+        // Construct the genesis parameters.
+        let parameters = GenesisParameters {
+            genesis_hash: block.hash(),
+            committee,
+            public_balances,
+            starting_supply: N::STARTING_SUPPLY,
+            puzzle: PuzzleParameters::new::<N>(),
+            deployments,
+        };
+        Ok((block, parameters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{CurrentLedger, CurrentNetwork};
+
+    #[test]
+    fn test_genesis_builder() {
+        let rng = &mut TestRng::default();
+
+        // Sample the genesis account and the committee members.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let addresses = (0..4).map(|_| Address::try_from(PrivateKey::new(rng).unwrap()).unwrap()).collect::<Vec<_>>();
+        let stake = ledger_committee::MIN_VALIDATOR_STAKE;
+
+        // Sample a program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program genesis_builder.aleo;
+
+function compute:
+    input r0 as u64.public;
+    add r0 r0 into r1;
+    output r1 as u64.public;",
+        )
+        .unwrap();
+
+        // Build the genesis block.
+        let (block, parameters) = addresses
+            .iter()
+            .fold(GenesisBuilder::new(private_key), |builder, address| builder.member(*address, stake, true))
+            .public_balance(addresses[0], 1_000_000)
+            .program(program.clone())
+            .build(rng)
+            .unwrap();
+        assert!(block.is_genesis());
+        assert_eq!(parameters.genesis_hash, block.hash());
+        assert_eq!(parameters.committee.total_stake(), 4 * stake);
+        assert_eq!(parameters.public_balances.values().sum::<u64>() + 4 * stake, CurrentNetwork::STARTING_SUPPLY);
+        assert_eq!(parameters.deployments.len(), 1);
+        assert_eq!(parameters.deployments[0].deployment().unwrap().program_id(), program.id());
+
+        // Ensure the ledger loads the genesis block, and accepts the deployment.
+        let ledger = CurrentLedger::load(block.clone(), None).unwrap();
+        assert_eq!(ledger.latest_committee().unwrap(), parameters.committee);
+        assert!(ledger.check_transaction_basic(&parameters.deployments[0], None).is_ok());
+
+        // Ensure the puzzle parameters match the genesis block.
+        assert_eq!(parameters.puzzle.coinbase_target, block.coinbase_target());
+        assert_eq!(parameters.puzzle.proof_target, block.proof_target());
+        assert_eq!(parameters.puzzle.timestamp, block.timestamp());
+
+        // Ensure the genesis supply may not exceed the starting supply.
+        let builder = addresses
+            .iter()
+            .fold(GenesisBuilder::new(private_key), |builder, address| builder.member(*address, stake, true));
+        assert!(builder.public_balance(addresses[0], CurrentNetwork::STARTING_SUPPLY).build(rng).is_err());
+    }
+}
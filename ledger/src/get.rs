@@ -31,8 +31,85 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     }
 
     /// Returns a state path for the given commitment.
+    /// The state paths to the latest state root are cached, until the ledger advances.
     pub fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
-        self.vm.block_store().get_state_path_for_commitment(commitment)
+        // Return the cached state path, if it is to the latest state root.
+        if let Some(state_path) = self.state_paths.read().get(&self.latest_state_root(), commitment) {
+            return Ok(state_path);
+        }
+        // Compute the state path, and cache it.
+        let state_path = self.vm.block_store().get_state_path_for_commitment(commitment)?;
+        self.state_paths.write().insert(*commitment, state_path.clone());
+        Ok(state_path)
+    }
+
+    /// Returns a static query with the state paths for the given commitments, to the latest state root.
+    /// The state paths are computed in parallel and cached, so that repeated executions
+    /// spending from the same records do not recompute them.
+    pub fn prefetch_state_paths(&self, commitments: &[Field<N>]) -> Result<StaticQuery<N>> {
+        // Retrieve the latest state root.
+        let state_root = self.latest_state_root();
+        // Retrieve the state paths.
+        let state_paths = cfg_iter!(commitments)
+            .map(|commitment| self.get_state_path_for_commitment(commitment))
+            .collect::<Result<Vec<_>>>()?;
+        // Note: If the ledger advanced while the state paths were retrieved, this fails and can be retried.
+        StaticQuery::new(state_root, state_paths)
+    }
+
+    /// Returns a state path for the given commitment, to the given state root.
+    /// The state root may be the latest state root, or a historical state root within the state root depth
+    /// (see `Ledger::set_state_root_depth`), so that a prover with a slightly stale state root can still construct
+    /// a valid inclusion proof while the ledger advances.
+    pub fn get_state_path_for_commitment_at(
+        &self,
+        commitment: &Field<N>,
+        state_root: N::StateRoot,
+    ) -> Result<StatePath<N>> {
+        // If the state root is the latest state root, use the cached state paths.
+        if state_root == self.latest_state_root() {
+            return self.get_state_path_for_commitment(commitment);
+        }
+        // Retrieve the block height of the state root.
+        let Some(height) = self.find_block_height_from_state_root(state_root)? else {
+            bail!("The state root '{state_root}' does not exist in the ledger")
+        };
+        // Ensure the state root is within the state root depth.
+        let depth = self.latest_height().saturating_sub(height);
+        let max_depth = self.state_root_depth();
+        ensure!(depth <= max_depth, "The state root '{state_root}' is {depth} blocks behind (max {max_depth})");
+        // Compute the state path.
+        self.vm.block_store().get_state_path_for_commitment_at(commitment, state_root)
+    }
+
+    /// Returns a static query with the state paths for the given commitments, to the given state root.
+    /// See `Ledger::get_state_path_for_commitment_at` for the state roots that are served.
+    pub fn prefetch_state_paths_at(
+        &self,
+        commitments: &[Field<N>],
+        state_root: N::StateRoot,
+    ) -> Result<StaticQuery<N>> {
+        // Retrieve the state paths.
+        let state_paths = cfg_iter!(commitments)
+            .map(|commitment| self.get_state_path_for_commitment_at(commitment, state_root))
+            .collect::<Result<Vec<_>>>()?;
+        StaticQuery::new(state_root, state_paths)
+    }
+
+    /// Returns the number of blocks behind the latest block for which state paths to historical state roots are served.
+    pub fn state_root_depth(&self) -> u32 {
+        *self.state_root_depth.read()
+    }
+
+    /// Sets the number of blocks behind the latest block for which state paths to historical state roots are served.
+    /// A depth of `0` serves state paths to the latest state root only.
+    pub fn set_state_root_depth(&self, depth: u32) {
+        *self.state_root_depth.write() = depth;
+    }
+
+    /// Clears the cached state paths.
+    pub fn clear_state_paths(&self) {
+        self.state_paths.write().clear();
     }
 
     /// Returns the epoch challenge for the given block height.
@@ -166,6 +243,22 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         }
     }
 
+    /// Returns the receipt for the given transaction ID.
+    /// Note: The transaction ID is the ID of the original (unconfirmed) transaction, even if it was rejected.
+    pub fn get_receipt(&self, transaction_id: &N::TransactionID) -> Result<Receipt<N>> {
+        match self.vm.block_store().get_receipt(transaction_id)? {
+            Some(receipt) => Ok(receipt),
+            None => bail!("Missing receipt for transaction ID {transaction_id}"),
+        }
+    }
+
+    /// Returns the rejection reason for the given rejected transaction ID, if it was recorded.
+    /// Note: The transaction ID is the ID of the original (unconfirmed) transaction, not of its fee transaction.
+    /// Note: The rejection reason is recorded when the block is checked, which speculates on its transactions.
+    pub fn get_rejection_reason(&self, transaction_id: &N::TransactionID) -> Result<Option<RejectionReason<N>>> {
+        self.vm.transaction_store().get_rejection_reason(transaction_id)
+    }
+
     /// Returns the program for the given program ID.
     pub fn get_program(&self, program_id: ProgramID<N>) -> Result<Program<N>> {
         match self.vm.transaction_store().get_program(&program_id)? {
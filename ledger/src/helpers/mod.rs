@@ -15,5 +15,11 @@
 mod bft;
 pub use bft::*;
 
+mod state_paths;
+pub use state_paths::*;
+
+mod subscription;
+pub use subscription::*;
+
 mod supply;
 pub use supply::*;
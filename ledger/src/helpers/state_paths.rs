@@ -0,0 +1,133 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{network::Network, program::StatePath, types::Field};
+
+use indexmap::IndexMap;
+
+/// The default maximum number of state paths in the cache.
+pub const MAX_CACHED_STATE_PATHS: usize = 4096;
+
+/// The default number of blocks behind the latest block for which state paths to historical state roots are served.
+pub const DEFAULT_STATE_ROOT_DEPTH: u32 = 10;
+
+/// A cache of state paths, keyed by commitment.
+///
+/// Every state path in the cache is to the same global state root. When a state path to a
+/// different global state root is inserted (i.e. the ledger advanced), the cache is cleared.
+#[derive(Clone, Debug)]
+pub struct StatePathCache<N: Network> {
+    /// The global state root of the cached state paths.
+    state_root: Option<N::StateRoot>,
+    /// The cached state paths, in insertion order.
+    state_paths: IndexMap<Field<N>, StatePath<N>>,
+    /// The maximum number of state paths in the cache.
+    capacity: usize,
+}
+
+impl<N: Network> Default for StatePathCache<N> {
+    /// Initializes a new cache, with the default capacity.
+    fn default() -> Self {
+        Self::new(MAX_CACHED_STATE_PATHS)
+    }
+}
+
+impl<N: Network> StatePathCache<N> {
+    /// Initializes a new cache, holding at most `capacity` state paths.
+    pub fn new(capacity: usize) -> Self {
+        Self { state_root: None, state_paths: IndexMap::new(), capacity }
+    }
+
+    /// Returns the number of state paths in the cache.
+    pub fn len(&self) -> usize {
+        self.state_paths.len()
+    }
+
+    /// Returns `true` if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.state_paths.is_empty()
+    }
+
+    /// Returns the cached state path for the given commitment, if it is to the given global state root.
+    pub fn get(&self, state_root: &N::StateRoot, commitment: &Field<N>) -> Option<StatePath<N>> {
+        match self.state_root == Some(*state_root) {
+            true => self.state_paths.get(commitment).cloned(),
+            false => None,
+        }
+    }
+
+    /// Inserts the given state path for the given commitment.
+    /// If the state path is to a different global state root than the cached state paths, the cache is cleared first.
+    pub fn insert(&mut self, commitment: Field<N>, state_path: StatePath<N>) {
+        // If the global state root changed, clear the cache.
+        let state_root = state_path.global_state_root();
+        if self.state_root != Some(state_root) {
+            self.clear();
+            self.state_root = Some(state_root);
+        }
+        // If the cache is full, evict the oldest state path.
+        if self.state_paths.len() >= self.capacity && !self.state_paths.contains_key(&commitment) {
+            self.state_paths.shift_remove_index(0);
+        }
+        if self.capacity > 0 {
+            self.state_paths.insert(commitment, state_path);
+        }
+    }
+
+    /// Clears the cache.
+    pub fn clear(&mut self) {
+        self.state_root = None;
+        self.state_paths.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{
+        network::{prelude::*, Testnet3},
+        program::test_helpers::sample_global_state_path,
+    };
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_state_path_cache() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample two state paths, to different global state roots.
+        let first = sample_global_state_path::<CurrentNetwork>(None, rng)?;
+        let second = sample_global_state_path::<CurrentNetwork>(None, rng)?;
+        let (first_commitment, second_commitment) = (first.transition_leaf().id(), second.transition_leaf().id());
+
+        let mut cache = StatePathCache::new(1);
+        assert!(cache.get(&first.global_state_root(), &first_commitment).is_none());
+
+        // Insert the first state path.
+        cache.insert(first_commitment, first.clone());
+        assert_eq!(cache.get(&first.global_state_root(), &first_commitment), Some(first.clone()));
+        assert!(cache.get(&second.global_state_root(), &first_commitment).is_none());
+
+        // Insert the second state path, which invalidates the first.
+        cache.insert(second_commitment, second.clone());
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get(&first.global_state_root(), &first_commitment).is_none());
+        assert_eq!(cache.get(&second.global_state_root(), &second_commitment), Some(second));
+
+        // Clear the cache.
+        cache.clear();
+        assert!(cache.is_empty());
+        Ok(())
+    }
+}
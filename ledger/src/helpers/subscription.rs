@@ -0,0 +1,177 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    account::ViewKey,
+    network::prelude::*,
+    program::{Ciphertext, Identifier, ProgramID, Record},
+    types::Field,
+};
+use ledger_block::Block;
+use synthesizer::program::FinalizeOperation;
+
+use anyhow::Result;
+use indexmap::IndexMap;
+use std::sync::mpsc::Sender;
+
+/// An event emitted by the ledger, when a block is advanced.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LedgerEvent<N: Network> {
+    /// A new record owned by a subscribed account, as (`block height`, `commitment`, `record`).
+    Record(u32, Field<N>, Record<N, Ciphertext<N>>),
+    /// An update to a subscribed mapping, as (`block height`, `program ID`, `mapping name`, `finalize operation`).
+    Mapping(u32, ProgramID<N>, Identifier<N>, FinalizeOperation<N>),
+    /// A new program deployment, as (`block height`, `transaction ID`, `program ID`).
+    Deployment(u32, N::TransactionID, ProgramID<N>),
+}
+
+impl<N: Network> LedgerEvent<N> {
+    /// Returns the block height of the event.
+    pub const fn height(&self) -> u32 {
+        match self {
+            Self::Record(height, ..) => *height,
+            Self::Mapping(height, ..) => *height,
+            Self::Deployment(height, ..) => *height,
+        }
+    }
+}
+
+/// A filter for the ledger events emitted to a subscriber.
+#[derive(Clone)]
+pub struct SubscriptionFilter<N: Network> {
+    /// The view keys of the accounts to emit new records for.
+    view_keys: Vec<ViewKey<N>>,
+    /// The mappings to emit updates for.
+    mappings: Vec<(ProgramID<N>, Identifier<N>)>,
+    /// Whether to emit program deployments.
+    deployments: bool,
+}
+
+impl<N: Network> Default for SubscriptionFilter<N> {
+    /// Returns a filter that emits no events.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> SubscriptionFilter<N> {
+    /// Initializes a new filter that emits no events.
+    pub const fn new() -> Self {
+        Self { view_keys: Vec::new(), mappings: Vec::new(), deployments: false }
+    }
+
+    /// Emits the new records owned by the account of the given view key.
+    pub fn records(mut self, view_key: ViewKey<N>) -> Self {
+        self.view_keys.push(view_key);
+        self
+    }
+
+    /// Emits the updates to the given `program ID` and `mapping name`.
+    pub fn mapping(mut self, program_id: ProgramID<N>, mapping_name: Identifier<N>) -> Self {
+        self.mappings.push((program_id, mapping_name));
+        self
+    }
+
+    /// Emits the program deployments.
+    pub const fn deployments(mut self) -> Self {
+        self.deployments = true;
+        self
+    }
+}
+
+/// A subscriber to the ledger events.
+pub(crate) struct Subscriber<N: Network> {
+    /// The view keys, with the x-coordinate of their address.
+    view_keys: Vec<(ViewKey<N>, Field<N>)>,
+    /// The mapping IDs, with their program ID and mapping name.
+    mappings: IndexMap<Field<N>, (ProgramID<N>, Identifier<N>)>,
+    /// Whether to emit program deployments.
+    deployments: bool,
+    /// The sender for the events.
+    sender: Sender<LedgerEvent<N>>,
+}
+
+impl<N: Network> Subscriber<N> {
+    /// Initializes a new subscriber from the given filter and sender.
+    pub(crate) fn new(filter: SubscriptionFilter<N>, sender: Sender<LedgerEvent<N>>) -> Result<Self> {
+        // Derive the x-coordinate of the address for each view key.
+        let view_keys =
+            filter.view_keys.into_iter().map(|view_key| (view_key, view_key.to_address().to_x_coordinate())).collect();
+        // Compute the mapping ID for each mapping.
+        let mappings = filter
+            .mappings
+            .into_iter()
+            .map(|(program_id, mapping_name)| {
+                let mapping_id = N::hash_bhp1024(&(program_id, mapping_name).to_bits_le())?;
+                Ok((mapping_id, (program_id, mapping_name)))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { view_keys, mappings, deployments: filter.deployments, sender })
+    }
+
+    /// Sends the events for the given block to the subscriber.
+    /// Returns `false` if the subscriber has disconnected.
+    pub(crate) fn notify(&self, block: &Block<N>) -> bool {
+        self.events(block).into_iter().all(|event| self.sender.send(event).is_ok())
+    }
+
+    /// Returns the events in the given block that match the filter of the subscriber.
+    fn events(&self, block: &Block<N>) -> Vec<LedgerEvent<N>> {
+        let height = block.height();
+        let mut events = Vec::new();
+
+        // Emit the new records that belong to the subscribed accounts.
+        if !self.view_keys.is_empty() {
+            for (commitment, record) in block.records() {
+                if self
+                    .view_keys
+                    .iter()
+                    .any(|(view_key, address_x)| record.is_owner_with_address_x_coordinate(view_key, address_x))
+                {
+                    events.push(LedgerEvent::Record(height, *commitment, record.clone()));
+                }
+            }
+        }
+
+        // Emit the updates to the subscribed mappings.
+        if !self.mappings.is_empty() {
+            for operation in block.transactions().finalize_operations() {
+                // Retrieve the mapping ID of the operation.
+                let mapping_id = match operation {
+                    FinalizeOperation::InitializeMapping(mapping_id)
+                    | FinalizeOperation::InsertKeyValue(mapping_id, ..)
+                    | FinalizeOperation::UpdateKeyValue(mapping_id, ..)
+                    | FinalizeOperation::RemoveKeyValue(mapping_id, ..)
+                    | FinalizeOperation::ReplaceMapping(mapping_id)
+                    | FinalizeOperation::RemoveMapping(mapping_id) => mapping_id,
+                };
+                if let Some((program_id, mapping_name)) = self.mappings.get(mapping_id) {
+                    events.push(LedgerEvent::Mapping(height, *program_id, *mapping_name, *operation));
+                }
+            }
+        }
+
+        // Emit the program deployments.
+        if self.deployments {
+            for confirmed in block.deployments() {
+                let transaction = confirmed.transaction();
+                if let Some(deployment) = transaction.deployment() {
+                    events.push(LedgerEvent::Deployment(height, transaction.id(), *deployment.program_id()));
+                }
+            }
+        }
+
+        events
+    }
+}
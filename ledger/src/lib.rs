@@ -31,6 +31,13 @@ pub use crate::block::*;
 mod helpers;
 pub use helpers::*;
 
+pub mod genesis;
+pub mod mempool;
+pub mod rewards;
+pub mod scanner;
+pub mod solution_pool;
+pub mod staking;
+
 mod advance;
 mod check_next_block;
 mod check_transaction_basic;
@@ -38,6 +45,8 @@ mod contains;
 mod find;
 mod get;
 mod iterators;
+mod replay;
+mod subscribe;
 
 #[cfg(test)]
 mod tests;
@@ -60,12 +69,29 @@ use console::{
     types::{Field, Group},
 };
 use ledger_authority::Authority;
-use ledger_block::{Block, ConfirmedTransaction, Header, Metadata, Ratify, Transaction, Transactions};
-use ledger_coinbase::{CoinbasePuzzle, CoinbaseSolution, EpochChallenge, ProverSolution, PuzzleCommitment};
+use ledger_block::{
+    Block,
+    ConfirmedTransaction,
+    Header,
+    Metadata,
+    Ratify,
+    Receipt,
+    RejectionReason,
+    Transaction,
+    Transactions,
+};
+use ledger_coinbase::{
+    CoinbasePuzzle,
+    CoinbaseSolution,
+    CoinbaseVerifyingKey,
+    EpochChallenge,
+    ProverSolution,
+    PuzzleCommitment,
+};
 use ledger_committee::Committee;
 use ledger_narwhal::{Subdag, Transmission, TransmissionID};
-use ledger_query::Query;
-use ledger_store::{ConsensusStorage, ConsensusStore};
+use ledger_query::{Query, StaticQuery};
+use ledger_store::{helpers::StorageConfig, ConsensusStorage, ConsensusStore};
 use synthesizer::{
     program::{FinalizeGlobalState, Program},
     vm::VM,
@@ -111,6 +137,12 @@ pub struct Ledger<N: Network, C: ConsensusStorage<N>> {
     current_epoch_challenge: Arc<RwLock<Option<EpochChallenge<N>>>>,
     /// The current block.
     current_block: Arc<RwLock<Block<N>>>,
+    /// The subscribers to the ledger events.
+    subscribers: Arc<RwLock<Vec<Subscriber<N>>>>,
+    /// The cache of state paths to the latest state root.
+    state_paths: Arc<RwLock<StatePathCache<N>>>,
+    /// The number of blocks behind the latest block for which state paths to historical state roots are served.
+    state_root_depth: Arc<RwLock<u32>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
@@ -146,6 +178,17 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         Ok(ledger)
     }
 
+    /// Loads the ledger from storage, opening the storage with the given storage configuration.
+    ///
+    /// The storage configuration is applied when the storage is first opened in this process,
+    /// and loading the ledger again with a different storage configuration fails.
+    pub fn load_with_config(genesis_block: Block<N>, dev: Option<u16>, config: &StorageConfig) -> Result<Self> {
+        // Set the storage configuration.
+        C::configure(config)?;
+        // Load the ledger.
+        Self::load(genesis_block, dev)
+    }
+
     /// Loads the ledger from storage, without performing integrity checks.
     pub fn load_unchecked(genesis_block: Block<N>, dev: Option<u16>) -> Result<Self> {
         let timer = timer!("Ledger::load_unchecked");
@@ -168,6 +211,9 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             coinbase_puzzle: CoinbasePuzzle::<N>::load()?,
             current_epoch_challenge: Default::default(),
             current_block: Arc::new(RwLock::new(genesis_block.clone())),
+            subscribers: Default::default(),
+            state_paths: Default::default(),
+            state_root_depth: Arc::new(RwLock::new(DEFAULT_STATE_ROOT_DEPTH)),
         };
 
         // If the block store is empty, initialize the genesis block.
@@ -0,0 +1,200 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use indexmap::IndexSet;
+
+/// A pool of pending transactions, ordered by fee.
+///
+/// The mempool guarantees that no two pending transactions share an input ID.
+/// A transaction that conflicts with pending transactions replaces them,
+/// if and only if its fee is strictly greater than the sum of their fees (replace-by-fee).
+#[derive(Clone)]
+pub struct Mempool<N: Network> {
+    /// The maximum number of pending transactions.
+    capacity: usize,
+    /// The pending transactions, as a map of `transaction ID` to `(fee, transaction)`.
+    transactions: IndexMap<N::TransactionID, (u64, Transaction<N>)>,
+    /// The input IDs of the pending transactions, as a map of `input ID` to `transaction ID`.
+    input_ids: IndexMap<Field<N>, N::TransactionID>,
+}
+
+impl<N: Network> Mempool<N> {
+    /// Initializes a new mempool with the given capacity.
+    pub fn new(capacity: usize) -> Result<Self> {
+        // Ensure the capacity is non-zero.
+        ensure!(capacity > 0, "The mempool capacity must be non-zero");
+        Ok(Self { capacity, transactions: Default::default(), input_ids: Default::default() })
+    }
+
+    /// Returns the maximum number of pending transactions.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of pending transactions.
+    pub fn len(&self) -> usize {
+        self.transactions.len()
+    }
+
+    /// Returns `true` if there are no pending transactions.
+    pub fn is_empty(&self) -> bool {
+        self.transactions.is_empty()
+    }
+
+    /// Returns `true` if the given transaction ID is pending.
+    pub fn contains(&self, transaction_id: &N::TransactionID) -> bool {
+        self.transactions.contains_key(transaction_id)
+    }
+
+    /// Returns `true` if the given input ID is spent by a pending transaction.
+    pub fn contains_input_id(&self, input_id: &Field<N>) -> bool {
+        self.input_ids.contains_key(input_id)
+    }
+
+    /// Returns the pending transaction for the given transaction ID.
+    pub fn get(&self, transaction_id: &N::TransactionID) -> Option<&Transaction<N>> {
+        self.transactions.get(transaction_id).map(|(_, transaction)| transaction)
+    }
+
+    /// Returns the pending transaction IDs that conflict with the given transaction.
+    pub fn conflicts(&self, transaction: &Transaction<N>) -> IndexSet<N::TransactionID> {
+        transaction.input_ids().filter_map(|input_id| self.input_ids.get(input_id).copied()).collect()
+    }
+
+    /// Returns up to `num_transactions` pending transactions, in descending order of fee.
+    /// Transactions with equal fees are returned in the order they were inserted.
+    pub fn select(&self, num_transactions: usize) -> Vec<Transaction<N>> {
+        self.ordered().take(num_transactions).map(|(_, (_, transaction))| transaction.clone()).collect()
+    }
+
+    /// Inserts the given transaction into the mempool, **without** verifying it.
+    /// To verify the transaction against the ledger, use `Ledger::insert_into_mempool`.
+    ///
+    /// On success, returns the IDs of the pending transactions that were replaced or evicted.
+    pub fn insert(&mut self, transaction: Transaction<N>) -> Result<Vec<N::TransactionID>> {
+        let transaction_id = transaction.id();
+        // Ensure the transaction is not already pending.
+        ensure!(!self.contains(&transaction_id), "Transaction '{transaction_id}' is already in the mempool");
+        // Ensure the transaction does not spend an input ID twice.
+        ensure!(
+            !has_duplicates(transaction.input_ids()),
+            "Transaction '{transaction_id}' contains a duplicate input ID"
+        );
+
+        // Retrieve the transaction fee.
+        let fee = *transaction.fee_amount()?;
+
+        // Determine the conflicting transactions.
+        let conflicts = self.conflicts(&transaction);
+        // Ensure the fee exceeds the sum of the fees of the conflicting transactions.
+        if !conflicts.is_empty() {
+            let conflicting_fee = conflicts
+                .iter()
+                .filter_map(|id| self.transactions.get(id))
+                .fold(0u64, |sum, (fee, _)| sum.saturating_add(*fee));
+            ensure!(
+                fee > conflicting_fee,
+                "Transaction '{transaction_id}' conflicts with {} pending transaction(s) - its fee ({fee}) must exceed {conflicting_fee} microcredits",
+                conflicts.len()
+            );
+        }
+
+        // Determine the transaction to evict, if the mempool is full after the replacements.
+        let evicted = match self.len() - conflicts.len() < self.capacity {
+            true => None,
+            false => match self.ordered().filter(|(id, _)| !conflicts.contains(*id)).last() {
+                // Ensure the fee exceeds the fee of the lowest-fee pending transaction.
+                Some((id, (lowest_fee, _))) if fee > *lowest_fee => Some(*id),
+                _ => bail!("Transaction '{transaction_id}' has an insufficient fee ({fee}) for a full mempool"),
+            },
+        };
+
+        // Remove the conflicting and evicted transactions.
+        let removed = conflicts.into_iter().chain(evicted).collect::<Vec<_>>();
+        for id in removed.iter() {
+            self.remove(id);
+        }
+
+        // Insert the transaction.
+        for input_id in transaction.input_ids() {
+            self.input_ids.insert(*input_id, transaction_id);
+        }
+        self.transactions.insert(transaction_id, (fee, transaction));
+
+        Ok(removed)
+    }
+
+    /// Removes the given transaction ID from the mempool, returning the transaction if it was pending.
+    pub fn remove(&mut self, transaction_id: &N::TransactionID) -> Option<Transaction<N>> {
+        let (_, transaction) = self.transactions.shift_remove(transaction_id)?;
+        for input_id in transaction.input_ids() {
+            self.input_ids.shift_remove(input_id);
+        }
+        Some(transaction)
+    }
+
+    /// Removes the transactions that are confirmed in the given block,
+    /// along with the pending transactions that conflict with them.
+    ///
+    /// Returns the IDs of the removed transactions.
+    pub fn remove_confirmed(&mut self, block: &Block<N>) -> Vec<N::TransactionID> {
+        // Determine the confirmed transaction IDs and their conflicts.
+        let mut removed = IndexSet::new();
+        for confirmed in block.transactions().iter() {
+            let transaction = confirmed.transaction();
+            removed.insert(transaction.id());
+            // Note: The unconfirmed ID of a rejected transaction is the ID of the transaction in the mempool.
+            if let Ok(unconfirmed_id) = confirmed.unconfirmed_id() {
+                removed.insert(unconfirmed_id);
+            }
+            removed.extend(self.conflicts(transaction));
+        }
+        // Remove the transactions.
+        removed.into_iter().filter(|id| self.remove(id).is_some()).collect()
+    }
+
+    /// Returns an iterator over the pending transactions, in descending order of fee.
+    fn ordered(&self) -> impl '_ + Iterator<Item = (&N::TransactionID, &(u64, Transaction<N>))> {
+        let mut transactions = self.transactions.iter().collect::<Vec<_>>();
+        // Note: The sort is stable, so transactions with equal fees retain their insertion order.
+        transactions.sort_by(|(_, (a, _)), (_, (b, _))| b.cmp(a));
+        transactions.into_iter()
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Verifies the given transaction against the ledger, and inserts it into the given mempool.
+    ///
+    /// On success, returns the IDs of the pending transactions that were replaced or evicted.
+    pub fn insert_into_mempool(
+        &self,
+        mempool: &mut Mempool<N>,
+        transaction: Transaction<N>,
+    ) -> Result<Vec<N::TransactionID>> {
+        let transaction_id = transaction.id();
+        // Ensure the transaction is not already in the ledger.
+        ensure!(!self.contains_transaction_id(&transaction_id)?, "Transaction '{transaction_id}' already exists");
+        // Ensure the transaction does not spend an input ID that exists in the ledger.
+        let input_ids = transaction.input_ids().copied().collect::<Vec<_>>();
+        for (input_id, exists) in input_ids.iter().zip_eq(self.contains_input_ids(&input_ids)?) {
+            ensure!(!exists, "Input ID '{input_id}' already exists in the ledger");
+        }
+        // Ensure the transaction is well-formed and unique.
+        self.check_transaction_basic(&transaction, None)?;
+        // Insert the transaction into the mempool.
+        mempool.insert(transaction)
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Replays the given blocks on top of the ledger, re-validating each block under the consensus rules
+    /// of its era before adding it. The blocks must be given in order, starting from the next block height.
+    ///
+    /// On failure, returns an error with the height and consensus version of the offending block.
+    /// The blocks before it remain in the ledger.
+    pub fn replay(&self, blocks: impl IntoIterator<Item = Block<N>>) -> Result<()> {
+        for block in blocks {
+            let height = block.height();
+            // Retrieve the consensus version of the block.
+            let consensus_version = N::consensus_version(height)?;
+            // Ensure the block is valid, and add it to the ledger.
+            self.check_next_block(&block).and_then(|_| self.advance_to_next_block(&block)).map_err(|error| {
+                anyhow!("Failed to replay block {height} (consensus {consensus_version}) - {error}")
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Replays the blocks in the given height range from the given ledger, on top of this ledger.
+    /// See `Ledger::replay` for details.
+    pub fn replay_from<C2: ConsensusStorage<N>>(&self, source: &Ledger<N, C2>, heights: Range<u32>) -> Result<()> {
+        self.replay(heights.map(|height| source.get_block(height)).collect::<Result<Vec<_>>>()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{sample_test_env, CurrentLedger};
+
+    #[test]
+    fn test_replay() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the source ledger, and advance it by a few blocks.
+        let crate::test_helpers::TestEnv { ledger: source, private_key, address, .. } = sample_test_env(rng);
+        for _ in 0..2 {
+            let transaction = source.create_transfer(&private_key, address, 100, 0, None).unwrap();
+            let block = source
+                .prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng)
+                .unwrap();
+            source.check_next_block(&block).unwrap();
+            source.advance_to_next_block(&block).unwrap();
+        }
+
+        // Replay the blocks onto a new ledger from the same genesis block.
+        let ledger = CurrentLedger::load(source.get_block(0).unwrap(), None).unwrap();
+        ledger.replay_from(&source, 1..source.latest_height() + 1).unwrap();
+        assert_eq!(ledger.latest_hash(), source.latest_hash());
+        assert_eq!(ledger.latest_state_root(), source.latest_state_root());
+
+        // Ensure a block may not be replayed twice.
+        let error = ledger.replay([source.latest_block()]).unwrap_err();
+        assert!(error.to_string().contains(&format!("Failed to replay block {}", source.latest_height())));
+    }
+}
@@ -0,0 +1,190 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The reward formulas of the ledger, for block producers, explorers, and stakers.
+//!
+//! These are the same functions the ledger uses to construct the reward ratifications of each block,
+//! so a projection computed here matches the reward that is later ratified.
+
+use super::*;
+
+pub use ledger_block::{block_height_at_year, block_reward, coinbase_reward, MAX_COINBASE_REWARD};
+pub use synthesizer::vm::{proving_rewards, staking_rewards};
+
+/// Returns the puzzle reward, given the coinbase reward.
+pub const fn puzzle_reward(coinbase_reward: u64) -> u64 {
+    coinbase_reward.saturating_div(2)
+}
+
+/// The rewards of a block.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Rewards {
+    /// The height of the block.
+    pub height: u32,
+    /// The coinbase reward.
+    pub coinbase_reward: u64,
+    /// The block reward, which is distributed to the stakers.
+    pub block_reward: u64,
+    /// The puzzle reward, which is distributed to the provers.
+    pub puzzle_reward: u64,
+}
+
+impl Rewards {
+    /// Returns the rewards of a block at the given height, given its combined proof target,
+    /// the cumulative proof target and coinbase target of the previous block.
+    pub fn new<N: Network>(
+        height: u32,
+        combined_proof_target: u128,
+        cumulative_proof_target: u64,
+        coinbase_target: u64,
+    ) -> Result<Self> {
+        // Calculate the coinbase reward.
+        let coinbase_reward = coinbase_reward(
+            height,
+            N::STARTING_SUPPLY,
+            N::ANCHOR_HEIGHT,
+            N::BLOCK_TIME,
+            combined_proof_target,
+            cumulative_proof_target,
+            coinbase_target,
+        )?;
+        // Compute the block reward.
+        let block_reward = block_reward(N::STARTING_SUPPLY, N::BLOCK_TIME, coinbase_reward);
+        // Return the rewards.
+        Ok(Self { height, coinbase_reward, block_reward, puzzle_reward: puzzle_reward(coinbase_reward) })
+    }
+
+    /// Returns the maximum rewards of a block at the given height, which are attained when
+    /// the solutions in the block reach the full coinbase target.
+    pub fn project<N: Network>(height: u32, coinbase_target: u64) -> Result<Self> {
+        Self::new::<N>(height, coinbase_target as u128, 0, coinbase_target)
+    }
+}
+
+/// Returns the staking reward of each staker, for the given committee and block reward,
+/// grouped by validator, as a map of `validator` to a map of `staker` to `microcredits`.
+///
+/// The validators are included as stakers bonded to themselves. Stakers that are not eligible
+/// for a reward (see `staking_rewards`) are included with a reward of zero.
+pub fn staking_reward_splits<N: Network>(
+    stakers: &IndexMap<Address<N>, (Address<N>, u64)>,
+    committee: &Committee<N>,
+    block_reward: u64,
+) -> IndexMap<Address<N>, IndexMap<Address<N>, u64>> {
+    // Compute the updated stakers.
+    let next_stakers = staking_rewards(stakers, committee, block_reward);
+    // Group the difference in stake by validator.
+    let mut splits = IndexMap::<_, IndexMap<_, _>>::new();
+    for (staker, (validator, stake)) in stakers {
+        let next_stake = next_stakers.get(staker).map_or(*stake, |(_, next_stake)| *next_stake);
+        splits.entry(*validator).or_default().insert(*staker, next_stake.saturating_sub(*stake));
+    }
+    splits
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the maximum rewards of the block at the given height,
+    /// using the latest coinbase target for blocks after the latest block.
+    pub fn project_rewards(&self, height: u32) -> Result<Rewards> {
+        ensure!(height > 0, "The genesis block does not have rewards");
+        // Retrieve the coinbase target that applies to the block.
+        let coinbase_target = match height <= self.latest_height() {
+            true => self.get_header(height - 1)?.coinbase_target(),
+            false => self.latest_coinbase_target(),
+        };
+        Rewards::project::<N>(height, coinbase_target)
+    }
+
+    /// Returns the staking reward of each latest staker for the given block reward,
+    /// grouped by validator, as a map of `validator` to a map of `staker` to `microcredits`.
+    pub fn project_staking_rewards(
+        &self,
+        block_reward: u64,
+    ) -> Result<IndexMap<Address<N>, IndexMap<Address<N>, u64>>> {
+        Ok(staking_reward_splits(&self.get_stakers()?, &self.latest_committee()?, block_reward))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_rewards() {
+        // Ensure the maximum rewards at block 1 are the maximum coinbase reward.
+        let rewards = Rewards::project::<CurrentNetwork>(1, CurrentNetwork::GENESIS_COINBASE_TARGET).unwrap();
+        assert_eq!(rewards.coinbase_reward, MAX_COINBASE_REWARD);
+        assert_eq!(rewards.puzzle_reward, MAX_COINBASE_REWARD / 2);
+        assert_eq!(
+            rewards.block_reward,
+            block_reward(CurrentNetwork::STARTING_SUPPLY, CurrentNetwork::BLOCK_TIME, MAX_COINBASE_REWARD)
+        );
+
+        // Ensure the rewards decrease over time, and without solutions.
+        let later = Rewards::project::<CurrentNetwork>(1_000_000, CurrentNetwork::GENESIS_COINBASE_TARGET).unwrap();
+        assert!(later.coinbase_reward < rewards.coinbase_reward);
+        let empty = Rewards::new::<CurrentNetwork>(1, 0, 0, CurrentNetwork::GENESIS_COINBASE_TARGET).unwrap();
+        assert_eq!(empty.coinbase_reward, 0);
+        assert_eq!(empty.puzzle_reward, 0);
+    }
+
+    #[test]
+    fn test_staking_reward_splits() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the test environment.
+        let crate::test_helpers::TestEnv { ledger, .. } = crate::test_helpers::sample_test_env(rng);
+
+        // Retrieve the committee, with each validator bonded to itself.
+        let committee = ledger.latest_committee().unwrap();
+        let stakers = committee
+            .members()
+            .iter()
+            .map(|(validator, (stake, _))| (*validator, (*validator, *stake)))
+            .collect::<IndexMap<_, _>>();
+
+        let block_reward = 1_000_000;
+        let splits = staking_reward_splits(&stakers, &committee, block_reward);
+        // Ensure each validator has a split, and the rewards match the updated stakers.
+        let next_stakers = staking_rewards(&stakers, &committee, block_reward);
+        assert_eq!(splits.len(), stakers.len());
+        for (validator, split) in &splits {
+            assert_eq!(split[validator], next_stakers[validator].1 - stakers[validator].1);
+        }
+        // Ensure the total reward does not exceed the block reward.
+        assert!(splits.values().flat_map(|split| split.values()).sum::<u64>() <= block_reward);
+    }
+
+    #[test]
+    fn test_project_rewards() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the test environment.
+        let crate::test_helpers::TestEnv { ledger, .. } = crate::test_helpers::sample_test_env(rng);
+
+        assert!(ledger.project_rewards(0).is_err());
+        let rewards = ledger.project_rewards(ledger.latest_height() + 1).unwrap();
+        assert_eq!(
+            rewards,
+            Rewards::project::<CurrentNetwork>(ledger.latest_height() + 1, ledger.latest_coinbase_target()).unwrap()
+        );
+
+        // Ensure the validators receive the block reward.
+        let splits = ledger.project_staking_rewards(rewards.block_reward).unwrap();
+        assert_eq!(splits.len(), ledger.latest_committee().unwrap().members().len());
+    }
+}
@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A resumable position of a scan over the blocks in the ledger.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ScanCursor {
+    /// The height of the next block to scan.
+    next_height: u32,
+}
+
+impl ScanCursor {
+    /// Initializes a new cursor, starting at the given block height.
+    pub const fn new(next_height: u32) -> Self {
+        Self { next_height }
+    }
+
+    /// Returns the height of the next block to scan.
+    pub const fn next_height(&self) -> u32 {
+        self.next_height
+    }
+}
+
+/// A record found by the scanner.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScannedRecord<N: Network> {
+    /// The height of the block that contains the record.
+    pub height: u32,
+    /// The address of the record owner.
+    pub owner: Address<N>,
+    /// The commitment of the record.
+    pub commitment: Field<N>,
+    /// The tag of the record.
+    pub tag: Field<N>,
+    /// The decrypted record.
+    pub record: Record<N, Plaintext<N>>,
+}
+
+/// A scanner for the records owned by a set of view keys.
+#[derive(Clone)]
+pub struct Scanner<N: Network> {
+    /// The view keys, with the x-coordinate of their address and their `sk_tag`.
+    accounts: Vec<(ViewKey<N>, Field<N>, Field<N>)>,
+}
+
+impl<N: Network> Scanner<N> {
+    /// Initializes a new scanner for the given view keys.
+    pub fn new(view_keys: impl IntoIterator<Item = ViewKey<N>>) -> Result<Self> {
+        let accounts = view_keys
+            .into_iter()
+            .map(|view_key| {
+                // Derive the x-coordinate of the address corresponding to the view key.
+                let address_x_coordinate = view_key.to_address().to_x_coordinate();
+                // Derive the `sk_tag` from the graph key.
+                let sk_tag = match GraphKey::try_from(view_key) {
+                    Ok(graph_key) => graph_key.sk_tag(),
+                    Err(e) => bail!("Failed to derive the graph key from the view key: {e}"),
+                };
+                Ok((view_key, address_x_coordinate, sk_tag))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { accounts })
+    }
+
+    /// Returns the number of view keys in the scanner.
+    pub fn num_view_keys(&self) -> usize {
+        self.accounts.len()
+    }
+
+    /// Returns the records in the given block that are owned by one of the view keys.
+    pub fn scan_block(&self, block: &Block<N>) -> Result<Vec<ScannedRecord<N>>> {
+        // Collect the record ciphertexts, so they may be matched in parallel.
+        let records = block.records().collect::<Vec<_>>();
+
+        cfg_into_iter!(records)
+            .filter_map(|(commitment, record)| {
+                // Find the view key that owns the record, by matching the owner against the address x-coordinate.
+                self.accounts
+                    .iter()
+                    .find(|(view_key, address_x, _)| record.is_owner_with_address_x_coordinate(view_key, address_x))
+                    .map(|(view_key, _, sk_tag)| {
+                        Ok(ScannedRecord {
+                            height: block.height(),
+                            owner: view_key.to_address(),
+                            commitment: *commitment,
+                            tag: Record::<N, Plaintext<N>>::tag(*sk_tag, *commitment)?,
+                            record: record.decrypt(view_key)?,
+                        })
+                    })
+            })
+            .collect()
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Scans up to `max_blocks` blocks from the given cursor, for the records owned by the scanner.
+    ///
+    /// Returns the records found, and the cursor to resume the scan from.
+    /// If the cursor is above the latest block, no records are returned and the cursor is unchanged.
+    pub fn scan(
+        &self,
+        scanner: &Scanner<N>,
+        cursor: ScanCursor,
+        max_blocks: u32,
+    ) -> Result<(Vec<ScannedRecord<N>>, ScanCursor)> {
+        // Ensure the number of blocks to scan is non-zero.
+        ensure!(max_blocks > 0, "Cannot scan zero blocks");
+
+        // Determine the range of blocks to scan.
+        let start_height = cursor.next_height();
+        let end_height = start_height.saturating_add(max_blocks).min(self.latest_height().saturating_add(1));
+        // If there are no blocks to scan, return the cursor unchanged.
+        if start_height >= end_height {
+            return Ok((Vec::new(), cursor));
+        }
+
+        // Scan the blocks in parallel.
+        let records = cfg_into_iter!(start_height..end_height)
+            .map(|height| scanner.scan_block(&self.get_block(height)?))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok((records.into_iter().flatten().collect(), ScanCursor::new(end_height)))
+    }
+}
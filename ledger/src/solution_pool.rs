@@ -0,0 +1,270 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A pool of pending prover solutions for the current epoch, ordered by target.
+///
+/// The pool only holds solutions that are valid for its epoch challenge and meet its proof target,
+/// with at most one solution per puzzle commitment. When the epoch changes, all pending solutions are dropped, as they are no longer valid.
+/// When the proof target rises, the solutions below the new target are evicted.
+#[derive(Clone)]
+pub struct SolutionPool<N: Network> {
+    /// The maximum number of pending solutions.
+    capacity: usize,
+    /// The epoch challenge of the pending solutions.
+    epoch_challenge: EpochChallenge<N>,
+    /// The minimum target of a pending solution.
+    proof_target: u64,
+    /// The pending solutions, as a map of `puzzle commitment` to `(target, solution)`.
+    solutions: IndexMap<PuzzleCommitment<N>, (u64, ProverSolution<N>)>,
+}
+
+impl<N: Network> SolutionPool<N> {
+    /// Initializes a new solution pool with the given capacity, for the given epoch challenge and proof target.
+    pub fn new(capacity: usize, epoch_challenge: EpochChallenge<N>, proof_target: u64) -> Result<Self> {
+        // Ensure the capacity is non-zero.
+        ensure!(capacity > 0, "The solution pool capacity must be non-zero");
+        Ok(Self { capacity, epoch_challenge, proof_target, solutions: Default::default() })
+    }
+
+    /// Returns the maximum number of pending solutions.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the epoch challenge of the pending solutions.
+    pub const fn epoch_challenge(&self) -> &EpochChallenge<N> {
+        &self.epoch_challenge
+    }
+
+    /// Returns the epoch number of the pending solutions.
+    pub const fn epoch_number(&self) -> u32 {
+        self.epoch_challenge.epoch_number()
+    }
+
+    /// Returns the minimum target of a pending solution.
+    pub const fn proof_target(&self) -> u64 {
+        self.proof_target
+    }
+
+    /// Returns the number of pending solutions.
+    pub fn len(&self) -> usize {
+        self.solutions.len()
+    }
+
+    /// Returns `true` if there are no pending solutions.
+    pub fn is_empty(&self) -> bool {
+        self.solutions.is_empty()
+    }
+
+    /// Returns `true` if a solution for the given puzzle commitment is pending.
+    pub fn contains(&self, puzzle_commitment: &PuzzleCommitment<N>) -> bool {
+        self.solutions.contains_key(puzzle_commitment)
+    }
+
+    /// Returns the pending solution for the given puzzle commitment.
+    pub fn get(&self, puzzle_commitment: &PuzzleCommitment<N>) -> Option<&ProverSolution<N>> {
+        self.solutions.get(puzzle_commitment).map(|(_, solution)| solution)
+    }
+
+    /// Returns up to `num_solutions` pending solutions, in descending order of target,
+    /// and no more than the maximum number of solutions in a block.
+    /// Solutions with equal targets are returned in the order they were inserted.
+    pub fn select(&self, num_solutions: usize) -> Vec<ProverSolution<N>> {
+        let num_solutions = num_solutions.min(N::MAX_PROVER_SOLUTIONS);
+        self.ordered().take(num_solutions).map(|(_, (_, solution))| solution.clone()).collect()
+    }
+
+    /// Inserts the given solution into the pool, if it is valid for the epoch challenge and proof target of the pool.
+    /// To also check the solution is not already in the ledger, use `Ledger::insert_into_solution_pool`.
+    ///
+    /// On success, returns the puzzle commitment of the pending solution that was evicted, if the pool was full.
+    pub fn insert(
+        &mut self,
+        solution: ProverSolution<N>,
+        verifying_key: &CoinbaseVerifyingKey<N>,
+    ) -> Result<Option<PuzzleCommitment<N>>> {
+        let commitment = solution.commitment();
+        // Ensure the solution is not already pending.
+        ensure!(!self.contains(&commitment), "Solution '{commitment}' is already in the solution pool");
+        // Ensure the solution meets the proof target.
+        let target = solution.to_target()?;
+        ensure!(
+            target >= self.proof_target,
+            "Solution '{commitment}' has a target ({target}) below the proof target ({})",
+            self.proof_target
+        );
+        // Ensure the solution is valid for the epoch challenge.
+        ensure!(
+            solution.verify(verifying_key, &self.epoch_challenge, self.proof_target)?,
+            "Solution '{commitment}' is invalid for epoch {}",
+            self.epoch_number()
+        );
+
+        // Determine the solution to evict, if the pool is full.
+        let evicted = match self.len() < self.capacity {
+            true => None,
+            false => match self.ordered().last() {
+                // Ensure the target exceeds the target of the lowest-target pending solution.
+                Some((lowest, (lowest_target, _))) if target > *lowest_target => Some(*lowest),
+                _ => bail!("Solution '{commitment}' has an insufficient target ({target}) for a full solution pool"),
+            },
+        };
+        if let Some(lowest) = &evicted {
+            self.solutions.shift_remove(lowest);
+        }
+
+        // Insert the solution.
+        self.solutions.insert(commitment, (target, solution));
+        Ok(evicted)
+    }
+
+    /// Updates the epoch challenge and proof target of the pool, and evicts the pending solutions that are no longer valid.
+    /// If the epoch challenge changes, all pending solutions are evicted.
+    ///
+    /// Returns the puzzle commitments of the evicted solutions.
+    pub fn update_target(&mut self, epoch_challenge: EpochChallenge<N>, proof_target: u64) -> Vec<PuzzleCommitment<N>> {
+        let is_new_epoch = epoch_challenge != self.epoch_challenge;
+        self.epoch_challenge = epoch_challenge;
+        self.proof_target = proof_target;
+
+        let evicted = self
+            .solutions
+            .iter()
+            .filter(|(_, (target, _))| is_new_epoch || *target < proof_target)
+            .map(|(commitment, _)| *commitment)
+            .collect::<Vec<_>>();
+        for commitment in &evicted {
+            self.solutions.shift_remove(commitment);
+        }
+        evicted
+    }
+
+    /// Removes the given puzzle commitment from the pool, returning the solution if it was pending.
+    pub fn remove(&mut self, puzzle_commitment: &PuzzleCommitment<N>) -> Option<ProverSolution<N>> {
+        self.solutions.shift_remove(puzzle_commitment).map(|(_, solution)| solution)
+    }
+
+    /// Removes the solutions that are included in the given block.
+    ///
+    /// Returns the puzzle commitments of the removed solutions.
+    pub fn remove_confirmed(&mut self, block: &Block<N>) -> Vec<PuzzleCommitment<N>> {
+        match block.puzzle_commitments() {
+            Some(commitments) => commitments.filter(|commitment| self.remove(commitment).is_some()).collect(),
+            None => vec![],
+        }
+    }
+
+    /// Returns an iterator over the pending solutions, in descending order of target.
+    fn ordered(&self) -> impl '_ + Iterator<Item = (&PuzzleCommitment<N>, &(u64, ProverSolution<N>))> {
+        let mut solutions = self.solutions.iter().collect::<Vec<_>>();
+        // Note: The sort is stable, so solutions with equal targets retain their insertion order.
+        solutions.sort_by(|(_, (a, _)), (_, (b, _))| b.cmp(a));
+        solutions.into_iter()
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Updates the given solution pool to the latest epoch challenge and proof target,
+    /// and evicts the pending solutions that are no longer valid.
+    ///
+    /// Returns the puzzle commitments of the evicted solutions.
+    pub fn update_solution_pool(&self, pool: &mut SolutionPool<N>) -> Result<Vec<PuzzleCommitment<N>>> {
+        Ok(pool.update_target(self.latest_epoch_challenge()?, self.latest_proof_target()))
+    }
+
+    /// Verifies the given solution against the latest epoch challenge and proof target,
+    /// and inserts it into the given solution pool.
+    ///
+    /// On success, returns the puzzle commitments of the pending solutions that were evicted.
+    pub fn insert_into_solution_pool(
+        &self,
+        pool: &mut SolutionPool<N>,
+        solution: ProverSolution<N>,
+    ) -> Result<Vec<PuzzleCommitment<N>>> {
+        let commitment = solution.commitment();
+        // Update the pool to the latest epoch challenge and proof target.
+        let mut evicted = self.update_solution_pool(pool)?;
+        // Ensure the solution is not already in the ledger.
+        ensure!(!self.contains_puzzle_commitment(&commitment)?, "Solution '{commitment}' already exists in the ledger");
+        // Insert the solution into the pool, if it is valid for the latest epoch challenge and proof target.
+        evicted.extend(pool.insert(solution, self.coinbase_puzzle.coinbase_verifying_key())?);
+        Ok(evicted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+    use ledger_coinbase::PuzzleConfig;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_solution_pool() {
+        let rng = &mut TestRng::default();
+
+        // Sample the solutions.
+        let degree = (1 << 5) - 1;
+        let srs = CoinbasePuzzle::<CurrentNetwork>::setup(PuzzleConfig { degree: 1 << 5 }).unwrap();
+        let puzzle = CoinbasePuzzle::<CurrentNetwork>::trim(&srs, PuzzleConfig { degree }).unwrap();
+        let epoch_challenge = EpochChallenge::new(rng.gen(), Default::default(), degree).unwrap();
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let mut solutions =
+            (0..4).map(|_| puzzle.prove(&epoch_challenge, address, rng.gen(), None).unwrap()).collect::<Vec<_>>();
+        solutions.sort_by_key(|solution| solution.to_target().unwrap());
+        let targets = solutions.iter().map(|solution| solution.to_target().unwrap()).collect::<Vec<_>>();
+        let verifying_key = puzzle.coinbase_verifying_key();
+
+        // Ensure the pool cannot have zero capacity.
+        assert!(SolutionPool::<CurrentNetwork>::new(0, epoch_challenge.clone(), 0).is_err());
+
+        // Ensure a solution for another epoch challenge is rejected.
+        let next_epoch_challenge = EpochChallenge::new(1, Default::default(), degree).unwrap();
+        let mut pool = SolutionPool::new(3, next_epoch_challenge.clone(), 0).unwrap();
+        assert!(pool.insert(solutions[1].clone(), verifying_key).is_err());
+        assert!(pool.is_empty());
+
+        // Insert the solutions into a pool with capacity for 3.
+        let mut pool = SolutionPool::new(3, epoch_challenge.clone(), 0).unwrap();
+        for solution in &solutions[1..] {
+            assert_eq!(pool.insert(solution.clone(), verifying_key).unwrap(), None);
+        }
+        // Ensure duplicates are rejected.
+        assert!(pool.insert(solutions[1].clone(), verifying_key).is_err());
+        // Ensure the lowest-target solution cannot enter a full pool.
+        if targets[0] < targets[1] {
+            assert!(pool.insert(solutions[0].clone(), verifying_key).is_err());
+        }
+        // Ensure the solutions are selected in descending order of target.
+        let selected = pool.select(2);
+        assert_eq!(selected, vec![solutions[3].clone(), solutions[2].clone()]);
+
+        // Ensure a rise in the proof target evicts the solutions below it.
+        if targets[1] < targets[2] {
+            assert_eq!(pool.update_target(epoch_challenge.clone(), targets[2]), vec![solutions[1].commitment()]);
+            assert_eq!(pool.len(), 2);
+            // Ensure solutions below the proof target are rejected.
+            assert!(pool.insert(solutions[1].clone(), verifying_key).is_err());
+        }
+
+        // Ensure a new epoch evicts every solution.
+        let num_pending = pool.len();
+        assert_eq!(pool.update_target(next_epoch_challenge, 0).len(), num_pending);
+        assert!(pool.is_empty());
+        assert_eq!(pool.epoch_number(), 1);
+    }
+}
@@ -0,0 +1,314 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use ledger_committee::CommitteeSelector;
+use ledger_store::FinalizeUndo;
+use synthesizer::{
+    program::FinalizeOperation,
+    vm::{bonded_map_into_stakers, to_next_committee, to_next_committee_with_selector, StakingState},
+};
+
+/// The bonded balance of a staker, as of a given block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BondedBalance<N: Network> {
+    /// The address of the staker.
+    pub staker: Address<N>,
+    /// The address of the validator the staker is bonded to.
+    pub validator: Address<N>,
+    /// The amount of microcredits bonded by the staker.
+    pub microcredits: u64,
+    /// The total stake of the validator in the committee, in microcredits.
+    pub validator_stake: u64,
+    /// The height of the block the balance was read at.
+    pub height: u32,
+    /// The state root of the block the balance was read at.
+    pub state_root: N::StateRoot,
+    /// The proof of the bond state written by the latest bonding transaction of the staker,
+    /// or `None` if the staker has been bonded since genesis.
+    pub proof: Option<BondedBalanceProof<N>>,
+}
+
+/// A proof of the bond state of a staker, as written to the `bonded` mapping of `credits.aleo`
+/// by a transaction in a block. The bond state is proven by a Merkle path for its finalize operation
+/// against the finalize root of the block, via the receipt of the transaction.
+///
+/// Note: The proof covers the bond state written by the transaction, which excludes the staking rewards
+/// of later blocks, as the rewards are applied by ratifications that are not in the finalize root.
+/// The caller must check the block hash is in the canonical chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BondedBalanceProof<N: Network> {
+    /// The receipt of the bonding transaction.
+    receipt: Receipt<N>,
+    /// The address of the staker.
+    staker: Address<N>,
+    /// The bond state written by the transaction, as `{ validator, microcredits }`.
+    bond_state: Value<N>,
+}
+
+impl<N: Network> BondedBalanceProof<N> {
+    /// Initializes a new proof of the bond state of the staker, from the receipt of the bonding transaction.
+    pub const fn new(receipt: Receipt<N>, staker: Address<N>, bond_state: Value<N>) -> Self {
+        Self { receipt, staker, bond_state }
+    }
+
+    /// Returns the receipt of the bonding transaction.
+    pub const fn receipt(&self) -> &Receipt<N> {
+        &self.receipt
+    }
+
+    /// Returns the address of the staker.
+    pub const fn staker(&self) -> Address<N> {
+        self.staker
+    }
+
+    /// Returns the bond state written by the transaction.
+    pub const fn bond_state(&self) -> &Value<N> {
+        &self.bond_state
+    }
+
+    /// Ensures the bond state was written by the transaction in the block, and returns the bonded
+    /// `(validator, microcredits)` of the staker.
+    pub fn verify(&self) -> Result<(Address<N>, u64)> {
+        // Ensure the receipt is valid, and the transaction was accepted.
+        self.receipt.verify()?;
+        ensure!(self.receipt.is_accepted(), "The bonding transaction was rejected");
+
+        // Compute the IDs of the bond state in the finalize tree.
+        let (mapping_id, key_id, value_id) = to_bonded_ids(&self.staker, &self.bond_state)?;
+        // Retrieve the value ID of the last write of the bond state in the transaction.
+        let mut written = None;
+        for operation in self.receipt.finalize_operations() {
+            match operation {
+                FinalizeOperation::InsertKeyValue(mapping, key, value)
+                | FinalizeOperation::UpdateKeyValue(mapping, _, key, value)
+                    if *mapping == mapping_id && *key == key_id =>
+                {
+                    written = Some(*value)
+                }
+                // Note: A removal is indexed by position, so any removal from the mapping is treated as removing the bond state.
+                FinalizeOperation::RemoveKeyValue(mapping, _)
+                | FinalizeOperation::ReplaceMapping(mapping)
+                | FinalizeOperation::RemoveMapping(mapping)
+                    if *mapping == mapping_id =>
+                {
+                    written = None
+                }
+                _ => (),
+            }
+        }
+        // Ensure the bond state matches the last write.
+        ensure!(written == Some(value_id), "The bond state of '{}' is not written by the transaction", self.staker);
+
+        // Parse the bond state.
+        match bonded_map_into_stakers(vec![(Plaintext::from(Literal::Address(self.staker)), self.bond_state.clone())])?
+            .get(&self.staker)
+        {
+            Some(bond_state) => Ok(*bond_state),
+            None => bail!("Invalid bond state of '{}'", self.staker),
+        }
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the latest stakers, as a map of `staker` to `(validator, microcredits)`.
+    /// Note: The stakers include the validators, which are bonded to themselves.
+    pub fn get_stakers(&self) -> Result<IndexMap<Address<N>, (Address<N>, u64)>> {
+        // Retrieve the bonded mapping from storage.
+        let bonded_map = self.vm.finalize_store().get_mapping_confirmed(&credits_program_id()?, &bonded_mapping()?)?;
+        // Convert the bonded map into stakers.
+        bonded_map_into_stakers(bonded_map)
+    }
+
+    /// Returns the latest delegators of the given validator, as a map of `delegator` to `microcredits`.
+    /// Note: The delegators exclude the validator itself.
+    pub fn get_delegators(&self, validator: &Address<N>) -> Result<IndexMap<Address<N>, u64>> {
+        Ok(self
+            .get_stakers()?
+            .into_iter()
+            .filter(|(staker, (staker_validator, _))| staker_validator == validator && staker != validator)
+            .map(|(staker, (_, microcredits))| (staker, microcredits))
+            .collect())
+    }
+
+    /// Returns the latest bonded balance of the given staker, or `None` if the staker is not bonded.
+    ///
+    /// The balance is cross-checked against the latest committee, and anchored to the latest block,
+    /// so it may be verified against the state root by any node at the same height.
+    pub fn get_bonded_balance(&self, staker: &Address<N>) -> Result<Option<BondedBalance<N>>> {
+        // Retrieve the latest block, to anchor the balance.
+        let block = self.latest_block();
+        // Retrieve the stakers and the committee.
+        let stakers = self.get_stakers()?;
+        let committee = self.latest_committee()?;
+
+        // Retrieve the bonded balance of the staker.
+        let Some((validator, microcredits)) = stakers.get(staker).copied() else {
+            return Ok(None);
+        };
+        // Compute the total stake bonded to the validator.
+        let validator_stake = stakers
+            .values()
+            .filter(|(staker_validator, _)| *staker_validator == validator)
+            .fold(0u64, |total, (_, microcredits)| total.saturating_add(*microcredits));
+        // Ensure the total stake matches the committee.
+        ensure!(
+            committee.get_stake(validator) == validator_stake,
+            "The stake of validator '{validator}' does not match the committee"
+        );
+
+        Ok(Some(BondedBalance {
+            staker: *staker,
+            validator,
+            microcredits,
+            validator_stake,
+            height: block.height(),
+            state_root: self.latest_state_root(),
+            proof: self.prove_bond_state(staker)?,
+        }))
+    }
+
+    /// Returns the proof of the bond state written by the latest bonding transaction of the given staker,
+    /// or `None` if no transaction has written the bond state of the staker.
+    pub fn prove_bond_state(&self, staker: &Address<N>) -> Result<Option<BondedBalanceProof<N>>> {
+        let key = Plaintext::from(Literal::Address(*staker));
+        let (mapping_id, key_id) = to_bonded_key_id::<N>(&key)?;
+
+        // Find the latest accepted transaction that wrote the bond state of the staker.
+        let latest_height = self.latest_height();
+        for height in (0..=latest_height).rev() {
+            let block = self.get_block(height)?;
+            let index = block.transactions().iter().position(|confirmed| {
+                confirmed.finalize_operations().into_iter().flatten().any(|operation| match operation {
+                    FinalizeOperation::InsertKeyValue(mapping, key, _)
+                    | FinalizeOperation::UpdateKeyValue(mapping, _, key, _) => (*mapping, *key) == (mapping_id, key_id),
+                    _ => false,
+                })
+            });
+            let Some(index) = index else { continue };
+            let receipt = Receipt::from_block(&block)?.swap_remove(index);
+
+            // Collect the values the bond state has held since the block, from the undo logs and the latest state.
+            let store = self.vm.finalize_store();
+            let (program_id, mapping_name) = (credits_program_id()?, bonded_mapping()?);
+            let mut candidates = Vec::new();
+            for undo_height in height..=latest_height {
+                for undo in store.get_undo_log(undo_height)?.into_iter().flatten() {
+                    match undo {
+                        FinalizeUndo::RestoreKeyValue(undo_program_id, undo_mapping_name, undo_key, Some(value))
+                            if undo_program_id == program_id
+                                && undo_mapping_name == mapping_name
+                                && undo_key == key =>
+                        {
+                            candidates.push(value)
+                        }
+                        FinalizeUndo::RestoreMapping(undo_program_id, undo_mapping_name, Some(entries))
+                            if undo_program_id == program_id && undo_mapping_name == mapping_name =>
+                        {
+                            candidates.extend(entries.into_iter().filter(|(entry, _)| *entry == key).map(|(_, v)| v))
+                        }
+                        _ => (),
+                    }
+                }
+            }
+            candidates.extend(store.get_value_confirmed(&program_id, &mapping_name, &key)?);
+
+            // Find the bond state that verifies against the receipt.
+            for bond_state in candidates {
+                let proof = BondedBalanceProof::new(receipt.clone(), *staker, bond_state);
+                if proof.verify().is_ok() {
+                    return Ok(Some(proof));
+                }
+            }
+            bail!("Failed to find the bond state of '{staker}' written in block {height}")
+        }
+        Ok(None)
+    }
+
+    /// Returns the projected committee for the next round, given the latest stakers.
+    /// Note: The projection excludes the staking rewards of the next block.
+    pub fn projected_committee(&self) -> Result<Committee<N>> {
+        // Retrieve the latest committee.
+        let committee = self.latest_committee()?;
+        // Compute the next committee, using the latest stakers.
+        to_next_committee(&committee, self.latest_round().saturating_add(1), &self.get_stakers()?)
+    }
+
+    /// Returns the committee for the next round, as selected by the given selector from the latest stakers.
+    pub fn projected_committee_with_selector<S: CommitteeSelector<N> + ?Sized>(
+        &self,
+        selector: &S,
+    ) -> Result<Committee<N>> {
+        // Retrieve the latest committee.
+        let committee = self.latest_committee()?;
+        // Compute the next committee, using the latest stakers.
+        to_next_committee_with_selector(
+            &committee,
+            self.latest_round().saturating_add(1),
+            &self.get_stakers()?,
+            selector,
+        )
+    }
+
+    /// Returns the latest staking state, with the public balances of the given accounts.
+    pub fn get_staking_state(&self, accounts: impl IntoIterator<Item = Address<N>>) -> Result<StakingState<N>> {
+        let store = self.vm.finalize_store();
+        let program_id = credits_program_id()?;
+        // Retrieve the public balances of the accounts.
+        let account_mapping = Identifier::from_str("account")?;
+        let mut balances = IndexMap::new();
+        for account in accounts {
+            let key = Plaintext::from(Literal::Address(account));
+            match store.get_value_confirmed(&program_id, &account_mapping, &key)? {
+                Some(Value::Plaintext(Plaintext::Literal(Literal::U64(balance), _))) => {
+                    balances.insert(account, *balance);
+                }
+                Some(value) => bail!("Invalid public balance of '{account}' - {value}"),
+                None => (),
+            }
+        }
+        // Retrieve the staking mappings.
+        StakingState::from_mappings(
+            store.get_mapping_confirmed(&program_id, &Identifier::from_str("committee")?)?,
+            store.get_mapping_confirmed(&program_id, &bonded_mapping()?)?,
+            store.get_mapping_confirmed(&program_id, &Identifier::from_str("unbonding")?)?,
+            balances,
+        )
+    }
+}
+
+/// Returns the program ID for `credits.aleo`.
+fn credits_program_id<N: Network>() -> Result<ProgramID<N>> {
+    ProgramID::from_str("credits.aleo")
+}
+
+/// Returns the mapping name for the `bonded` mapping.
+fn bonded_mapping<N: Network>() -> Result<Identifier<N>> {
+    Identifier::from_str("bonded")
+}
+
+/// Returns the mapping ID of the `bonded` mapping, and the key ID of the given key in it.
+fn to_bonded_key_id<N: Network>(key: &Plaintext<N>) -> Result<(Field<N>, Field<N>)> {
+    let mapping_id = N::hash_bhp1024(&(credits_program_id::<N>()?, bonded_mapping::<N>()?).to_bits_le())?;
+    let key_id = N::hash_bhp1024(&(mapping_id, N::hash_bhp1024(&key.to_bits_le())?).to_bits_le())?;
+    Ok((mapping_id, key_id))
+}
+
+/// Returns the mapping ID, key ID, and value ID of the given bond state of the staker in the `bonded` mapping.
+fn to_bonded_ids<N: Network>(staker: &Address<N>, bond_state: &Value<N>) -> Result<(Field<N>, Field<N>, Field<N>)> {
+    let (mapping_id, key_id) = to_bonded_key_id(&Plaintext::from(Literal::Address(*staker)))?;
+    let value_id = N::hash_bhp1024(&(key_id, N::hash_bhp1024(&bond_state.to_bits_le())?).to_bits_le())?;
+    Ok((mapping_id, key_id, value_id))
+}
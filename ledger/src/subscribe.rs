@@ -0,0 +1,43 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::sync::mpsc::{channel, Receiver};
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Subscribes to the ledger events that match the given filter.
+    ///
+    /// The events are emitted on the returned receiver, in order, each time a block is advanced.
+    /// Once the receiver is dropped, the subscription is dropped on its next event.
+    pub fn subscribe(&self, filter: SubscriptionFilter<N>) -> Result<Receiver<LedgerEvent<N>>> {
+        // Initialize the channel.
+        let (sender, receiver) = channel();
+        // Initialize the subscriber.
+        let subscriber = Subscriber::new(filter, sender)?;
+        // Register the subscriber.
+        self.subscribers.write().push(subscriber);
+        Ok(receiver)
+    }
+
+    /// Returns the number of subscribers to the ledger events.
+    pub fn num_subscribers(&self) -> usize {
+        self.subscribers.read().len()
+    }
+
+    /// Sends the events for the given block to the subscribers, and drops the disconnected subscribers.
+    pub(crate) fn notify_subscribers(&self, block: &Block<N>) {
+        self.subscribers.write().retain(|subscriber| subscriber.notify(block));
+    }
+}
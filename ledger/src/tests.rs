@@ -13,15 +13,18 @@
 // limitations under the License.
 
 use crate::{
+    staking::BondedBalanceProof,
     test_helpers::{CurrentLedger, CurrentNetwork},
     RecordsFilter,
 };
 use console::{
-    account::PrivateKey,
+    account::{Address, PrivateKey, ViewKey},
     network::prelude::*,
-    program::{Entry, Identifier, Literal, Plaintext, Value},
+    program::{Entry, Identifier, Literal, Plaintext, ProgramID, Value},
+    types::Field,
 };
 use ledger_block::{ConfirmedTransaction, Rejected, Transaction};
+use ledger_query::QueryTrait;
 use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
 use synthesizer::{program::Program, vm::VM};
 
@@ -80,6 +83,69 @@ fn test_state_path() {
     let _state_path = ledger.get_state_path_for_commitment(commitment).unwrap();
 }
 
+#[test]
+fn test_prefetch_state_paths() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the ledger.
+    let ledger = crate::test_helpers::sample_ledger(PrivateKey::<CurrentNetwork>::new(rng).unwrap(), rng);
+    // Retrieve the genesis block.
+    let block = ledger.get_block(0).unwrap();
+    let commitments = block.transactions().commitments().copied().collect::<Vec<_>>();
+
+    // Prefetch the state paths.
+    let query = ledger.prefetch_state_paths(&commitments).unwrap();
+    assert_eq!(query.state_root(), ledger.latest_state_root());
+    assert_eq!(query.num_state_paths(), commitments.len());
+
+    // Ensure the cached state paths match the computed state paths.
+    for commitment in &commitments {
+        let expected = ledger.vm().block_store().get_state_path_for_commitment(commitment).unwrap();
+        assert_eq!(ledger.get_state_path_for_commitment(commitment).unwrap(), expected);
+        assert_eq!(query.get_state_path_for_commitment(commitment).unwrap(), expected);
+    }
+}
+
+#[test]
+fn test_historical_state_paths() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+    // Retrieve the genesis state root and commitments.
+    let genesis_state_root = ledger.latest_state_root();
+    let commitments = ledger.get_block(0).unwrap().transactions().commitments().copied().collect::<Vec<_>>();
+
+    // Advance the ledger by two blocks.
+    for _ in 0..2 {
+        let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+    }
+    assert_ne!(ledger.latest_state_root(), genesis_state_root);
+
+    // Ensure the state paths to the genesis state root are valid.
+    let query = ledger.prefetch_state_paths_at(&commitments, genesis_state_root).unwrap();
+    assert_eq!(query.state_root(), genesis_state_root);
+    for commitment in &commitments {
+        let state_path = ledger.get_state_path_for_commitment_at(commitment, genesis_state_root).unwrap();
+        assert_eq!(state_path.global_state_root(), genesis_state_root);
+        assert_eq!(query.get_state_path_for_commitment(commitment).unwrap(), state_path);
+    }
+    // Ensure the state paths to the latest state root match the cached state paths.
+    let latest_state_root = ledger.latest_state_root();
+    assert_eq!(
+        ledger.get_state_path_for_commitment_at(&commitments[0], latest_state_root).unwrap(),
+        ledger.get_state_path_for_commitment(&commitments[0]).unwrap()
+    );
+
+    // Ensure the state paths to a state root beyond the state root depth are not served.
+    ledger.set_state_root_depth(1);
+    assert!(ledger.get_state_path_for_commitment_at(&commitments[0], genesis_state_root).is_err());
+    // Ensure the state paths to an unknown state root are not served.
+    let unknown_state_root = <CurrentNetwork as Network>::StateRoot::from(Field::rand(rng));
+    assert!(ledger.get_state_path_for_commitment_at(&commitments[0], unknown_state_root).is_err());
+}
+
 #[test]
 fn test_insufficient_fees() {
     let rng = &mut TestRng::default();
@@ -330,6 +396,7 @@ finalize failed_assert:
 
     // Deploy the program.
     let deployment_transaction = ledger.vm().deploy(&private_key, &program, Some(record_1), 0, None, rng).unwrap();
+    let deployment_transaction_id = deployment_transaction.id();
 
     // Construct the deployment block.
     let deployment_block = ledger
@@ -388,4 +455,449 @@ finalize failed_assert:
 
     // Add the block with the rejected transaction to the ledger.
     ledger.advance_to_next_block(&next_block).unwrap();
+
+    // Check that the rejection reason names the failing finalize command.
+    let reason = ledger.get_rejection_reason(&failed_assert_transaction_id).unwrap().unwrap();
+    assert_eq!(reason.program_id().to_string(), program_id);
+    assert_eq!(reason.function_name().unwrap().to_string(), "failed_assert");
+    let (command_index, command) = reason.command().unwrap();
+    assert_eq!(command_index, 0);
+    assert_eq!(command.to_string(), "assert.eq false true;");
+    assert_eq!(reason.operands().len(), 2);
+
+    // Check that the receipt of the rejected execution is valid.
+    let receipt = ledger.get_receipt(&failed_assert_transaction_id).unwrap();
+    receipt.verify().unwrap();
+    assert!(receipt.is_rejected());
+    assert_eq!(receipt.block_hash(), next_block.hash());
+    assert_eq!(receipt.transaction_id(), confirmed_transaction.id());
+    assert_eq!(receipt.finalize_operations().len(), 0);
+
+    // Check that the receipt of the deployment is valid.
+    let receipt = ledger.get_receipt(&deployment_transaction_id).unwrap();
+    receipt.verify().unwrap();
+    assert!(receipt.is_accepted());
+    assert_eq!(receipt.block_hash(), deployment_block.hash());
+}
+
+#[test]
+fn test_revert_to() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Prepare the 'credits.aleo' program ID and the account mapping name.
+    let credits_id = ProgramID::from_str("credits.aleo").unwrap();
+    let account_mapping = Identifier::from_str("account").unwrap();
+
+    // A helper function to capture the state of the ledger.
+    let snapshot = |ledger: &CurrentLedger, program_id: &ProgramID<CurrentNetwork>| {
+        (
+            ledger.latest_block(),
+            ledger.latest_state_root(),
+            ledger.latest_committee().unwrap(),
+            ledger.latest_epoch_challenge().unwrap(),
+            ledger.vm().finalize_store().get_mapping_confirmed(&credits_id, &account_mapping).unwrap(),
+            ledger.vm().contains_program(program_id),
+        )
+    };
+
+    // Deploy a test program to the ledger.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program dummy_revert.aleo;
+function foo:
+    input r0 as u8.private;
+    finalize r0;
+finalize foo:
+    input r0 as u8.public;
+    add r0 r0 into r1;",
+    )
+    .unwrap();
+    let program_id = *program.id();
+
+    // Initialize the snapshots with the genesis state.
+    let mut snapshots = vec![snapshot(&ledger, &program_id)];
+
+    // Construct the deployment transaction.
+    let deployment = ledger.vm().deploy(&private_key, &program, None, 0, None, rng).unwrap();
+    // Construct the transfer transactions.
+    let transfers = (0..2)
+        .map(|_| {
+            let recipient = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+            let inputs = [Value::from_str(&format!("{recipient}")).unwrap(), Value::from_str("1_000u64").unwrap()];
+            ledger.vm().execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        })
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+
+    // Advance the ledger, one block per transaction.
+    let mut blocks = Vec::new();
+    for transaction in std::iter::once(deployment).chain(transfers) {
+        let block =
+            ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+        ledger.check_next_block(&block).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+        blocks.push(block);
+        snapshots.push(snapshot(&ledger, &program_id));
+    }
+    let latest_height = ledger.latest_height();
+    assert_eq!(latest_height, 3);
+    assert!(ledger.vm().contains_program(&program_id));
+
+    // Ensure the ledger cannot revert to the current height or above.
+    assert!(ledger.revert_to(latest_height).is_err());
+    assert!(ledger.revert_to(latest_height + 1).is_err());
+
+    for _ in 0..3 {
+        // Sample a fork height.
+        let height = rng.gen_range(0..latest_height);
+
+        // Revert the ledger to the fork height.
+        ledger.revert_to(height).unwrap();
+        assert_eq!(ledger.latest_height(), height);
+        assert_eq!(snapshot(&ledger, &program_id), snapshots[height as usize]);
+        for block in &blocks[height as usize..] {
+            assert!(!ledger.contains_block_hash(&block.hash()).unwrap());
+        }
+
+        // Advance the ledger on a competing fork.
+        let fork = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+        ledger.check_next_block(&fork).unwrap();
+        ledger.advance_to_next_block(&fork).unwrap();
+        assert_eq!(ledger.latest_height(), height + 1);
+
+        // Revert the competing fork, and re-apply the original blocks.
+        ledger.revert_to(height).unwrap();
+        assert_eq!(snapshot(&ledger, &program_id), snapshots[height as usize]);
+        for block in &blocks[height as usize..] {
+            ledger.check_next_block(block).unwrap();
+            ledger.advance_to_next_block(block).unwrap();
+        }
+        assert_eq!(snapshot(&ledger, &program_id), snapshots[latest_height as usize]);
+    }
+}
+
+#[test]
+fn test_subscribe() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, address } =
+        crate::test_helpers::sample_test_env(rng);
+
+    // Prepare the 'credits.aleo' program ID and the account mapping name.
+    let credits_id = ProgramID::from_str("credits.aleo").unwrap();
+    let account_mapping = Identifier::from_str("account").unwrap();
+
+    // Subscribe to the records, the account mapping, and the deployments.
+    let filter = crate::SubscriptionFilter::new().records(view_key).mapping(credits_id, account_mapping).deployments();
+    let receiver = ledger.subscribe(filter).unwrap();
+    // Subscribe to nothing.
+    let idle = ledger.subscribe(crate::SubscriptionFilter::new()).unwrap();
+    assert_eq!(ledger.num_subscribers(), 2);
+
+    // Deploy a test program to the ledger.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program dummy_subscribe.aleo;
+function foo:
+    input r0 as u8.private;
+    output r0 as u8.private;",
+    )
+    .unwrap();
+    let deployment = ledger.vm().deploy(&private_key, &program, None, 0, None, rng).unwrap();
+    let deployment_id = deployment.id();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![deployment], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the deployment and the fee update to the account mapping are emitted.
+    let events = receiver.try_iter().collect::<Vec<_>>();
+    assert!(events.iter().all(|event| event.height() == 1));
+    assert!(events.contains(&crate::LedgerEvent::Deployment(1, deployment_id, *program.id())));
+    assert!(events.iter().any(|event| match event {
+        crate::LedgerEvent::Mapping(1, program_id, mapping_name, _) => {
+            *program_id == credits_id && *mapping_name == account_mapping
+        }
+        _ => false,
+    }));
+    assert!(!events.iter().any(|event| matches!(event, crate::LedgerEvent::Record(..))));
+
+    // Transfer public credits to a private record owned by the account.
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1_000u64").unwrap()];
+    let transaction = ledger
+        .vm()
+        .execute(&private_key, ("credits.aleo", "transfer_public_to_private"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the new record is emitted.
+    let events = receiver.try_iter().collect::<Vec<_>>();
+    let records = block.records().map(|(commitment, record)| (*commitment, record.clone())).collect::<Vec<_>>();
+    assert_eq!(records.len(), 1);
+    assert!(events.contains(&crate::LedgerEvent::Record(2, records[0].0, records[0].1.clone())));
+    assert!(!events.iter().any(|event| matches!(event, crate::LedgerEvent::Deployment(..))));
+
+    // Ensure the idle subscriber received no events.
+    assert_eq!(idle.try_iter().count(), 0);
+
+    // Ensure the subscriber is dropped on its next event, once its receiver is dropped.
+    drop(receiver);
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1_000u64").unwrap()];
+    let transaction = ledger
+        .vm()
+        .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, 0, None, rng)
+        .unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(ledger.num_subscribers(), 1);
+}
+
+#[test]
+fn test_scanner() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, address } =
+        crate::test_helpers::sample_test_env(rng);
+
+    // Transfer public credits to a private record owned by the account, over a few blocks.
+    for _ in 0..3 {
+        let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1_000u64").unwrap()];
+        let transaction = ledger
+            .vm()
+            .execute(&private_key, ("credits.aleo", "transfer_public_to_private"), inputs.iter(), None, 0, None, rng)
+            .unwrap();
+        let block =
+            ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+    }
+    assert_eq!(ledger.latest_height(), 3);
+
+    // Initialize a scanner for the account, and an unrelated account.
+    let other_view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    let scanner = crate::scanner::Scanner::new([other_view_key, view_key]).unwrap();
+    assert_eq!(scanner.num_view_keys(), 2);
+
+    // Ensure a zero-block scan fails.
+    assert!(ledger.scan(&scanner, Default::default(), 0).is_err());
+
+    // Scan the ledger, two blocks at a time.
+    let mut cursor = crate::scanner::ScanCursor::default();
+    let mut scanned = Vec::new();
+    while cursor.next_height() <= ledger.latest_height() {
+        let (records, next_cursor) = ledger.scan(&scanner, cursor, 2).unwrap();
+        assert!(next_cursor.next_height() > cursor.next_height());
+        scanned.extend(records);
+        cursor = next_cursor;
+    }
+    assert_eq!(cursor.next_height(), 4);
+
+    // Ensure a scan from the end of the ledger returns the cursor unchanged.
+    let (records, next_cursor) = ledger.scan(&scanner, cursor, 2).unwrap();
+    assert!(records.is_empty());
+    assert_eq!(next_cursor, cursor);
+
+    // Ensure the scanned records match the records found by the ledger.
+    let expected = ledger.find_records(&view_key, RecordsFilter::All).unwrap().collect::<Vec<_>>();
+    assert_eq!(scanned.len(), expected.len());
+    for scanned in scanned.iter() {
+        assert_eq!(scanned.owner, address);
+        assert!(expected.contains(&(scanned.commitment, scanned.record.clone())));
+        assert!(!ledger.contains_tag(&scanned.tag).unwrap());
+    }
+    assert_eq!(scanned.iter().filter(|record| record.height > 0).count(), 3);
+}
+
+#[test]
+fn test_mempool() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, view_key, address } =
+        crate::test_helpers::sample_test_env(rng);
+
+    // Retrieve a record owned by the account.
+    let record = ledger.find_unspent_credits_records(&view_key).unwrap().values().next().unwrap().clone();
+
+    // A helper function to spend the record, with the given priority fee.
+    let mut spend_record = |priority_fee: u64| {
+        let inputs = [
+            Value::Record(record.clone()),
+            Value::from_str(&format!("{address}")).unwrap(),
+            Value::from_str("1u64").unwrap(),
+        ];
+        ledger
+            .vm()
+            .execute(
+                &private_key,
+                ("credits.aleo", "transfer_private_to_public"),
+                inputs.iter(),
+                None,
+                priority_fee,
+                None,
+                rng,
+            )
+            .unwrap()
+    };
+    let spend_low = spend_record(0);
+    let spend_high = spend_record(100_000);
+    let spend_mid = spend_record(50_000);
+
+    // Ensure the mempool cannot have zero capacity.
+    assert!(crate::mempool::Mempool::<CurrentNetwork>::new(0).is_err());
+
+    // Initialize the mempool.
+    let mut mempool = crate::mempool::Mempool::new(2).unwrap();
+    assert!(mempool.is_empty());
+
+    // Insert the low-fee spend.
+    assert!(ledger.insert_into_mempool(&mut mempool, spend_low.clone()).unwrap().is_empty());
+    assert!(mempool.contains(&spend_low.id()));
+    assert!(spend_low.input_ids().all(|input_id| mempool.contains_input_id(input_id)));
+    // Ensure the same transaction cannot be inserted twice.
+    assert!(ledger.insert_into_mempool(&mut mempool, spend_low.clone()).is_err());
+
+    // Replace the low-fee spend with the high-fee spend.
+    assert_eq!(mempool.conflicts(&spend_high).into_iter().collect::<Vec<_>>(), vec![spend_low.id()]);
+    assert_eq!(ledger.insert_into_mempool(&mut mempool, spend_high.clone()).unwrap(), vec![spend_low.id()]);
+    assert_eq!(mempool.len(), 1);
+    assert!(!mempool.contains(&spend_low.id()));
+    assert!(mempool.contains(&spend_high.id()));
+
+    // Ensure the mid-fee spend cannot replace the high-fee spend.
+    assert!(ledger.insert_into_mempool(&mut mempool, spend_mid.clone()).is_err());
+    assert_eq!(mempool.len(), 1);
+
+    // Fill the mempool with non-conflicting transfers, with increasing fees.
+    let mut transfer = |priority_fee: u64| {
+        let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1u64").unwrap()];
+        ledger
+            .vm()
+            .execute(&private_key, ("credits.aleo", "transfer_public"), inputs.iter(), None, priority_fee, None, rng)
+            .unwrap()
+    };
+    let transfer_low = transfer(0);
+    let transfer_high = transfer(1_000_000);
+    assert!(ledger.insert_into_mempool(&mut mempool, transfer_low.clone()).unwrap().is_empty());
+    assert_eq!(mempool.len(), 2);
+    assert_eq!(mempool.select(2), vec![spend_high.clone(), transfer_low.clone()]);
+
+    // Ensure the high-fee transfer evicts the lowest-fee transaction from the full mempool.
+    assert_eq!(ledger.insert_into_mempool(&mut mempool, transfer_high.clone()).unwrap(), vec![transfer_low.id()]);
+    assert_eq!(mempool.select(1), vec![transfer_high.clone()]);
+    assert_eq!(mempool.select(3), vec![transfer_high.clone(), spend_high.clone()]);
+    // Ensure a low-fee transaction cannot be inserted into the full mempool.
+    assert!(ledger.insert_into_mempool(&mut mempool, transfer_low.clone()).is_err());
+
+    // Confirm the high-fee transfer in a block.
+    let block = ledger
+        .prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transfer_high.clone()], rng)
+        .unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+    assert_eq!(mempool.remove_confirmed(&block), vec![transfer_high.id()]);
+    assert_eq!(mempool.select(2), vec![spend_high.clone()]);
+
+    // Ensure a confirmed transaction cannot be inserted.
+    assert!(ledger.insert_into_mempool(&mut mempool, transfer_high.clone()).is_err());
+
+    // Ensure the spent inputs are found in one batch.
+    let spent = transfer_high.serial_numbers().chain(spend_high.serial_numbers()).copied().collect::<Vec<_>>();
+    let expected = transfer_high.serial_numbers().map(|_| true).chain(spend_high.serial_numbers().map(|_| false));
+    assert_eq!(ledger.contains_serial_numbers(&spent).unwrap(), expected.collect::<Vec<_>>());
+    let input_ids = transfer_high.input_ids().copied().collect::<Vec<_>>();
+    assert!(ledger.contains_input_ids(&input_ids).unwrap().into_iter().all(|exists| exists));
+
+    // Remove the remaining transaction.
+    assert_eq!(mempool.remove(&spend_high.id()), Some(spend_high));
+    assert!(mempool.is_empty());
+}
+
+#[test]
+fn test_staking() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Retrieve the committee.
+    let committee = ledger.latest_committee().unwrap();
+
+    // Ensure the stakers are the committee members, bonded to themselves.
+    let stakers = ledger.get_stakers().unwrap();
+    assert_eq!(stakers.len(), committee.num_members());
+    for (staker, (validator, microcredits)) in &stakers {
+        assert_eq!(staker, validator);
+        assert_eq!(*microcredits, committee.get_stake(*validator));
+        // Ensure the validator has no delegators.
+        assert!(ledger.get_delegators(validator).unwrap().is_empty());
+    }
+
+    // Ensure the bonded balance of the validator is anchored to the latest block.
+    let balance = ledger.get_bonded_balance(&address).unwrap().unwrap();
+    assert_eq!(balance.staker, address);
+    assert_eq!(balance.validator, address);
+    assert_eq!(balance.microcredits, committee.get_stake(address));
+    assert_eq!(balance.validator_stake, committee.get_stake(address));
+    assert_eq!(balance.height, ledger.latest_height());
+    assert_eq!(balance.state_root, ledger.latest_state_root());
+    // Ensure the validator has no bond state proof, as it is bonded since genesis.
+    assert!(balance.proof.is_none());
+
+    // Ensure an unbonded account has no bonded balance.
+    let unbonded = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    assert!(ledger.get_bonded_balance(&unbonded).unwrap().is_none());
+    assert!(ledger.get_delegators(&unbonded).unwrap().is_empty());
+
+    // Ensure the projected committee starts at the next round, with the same members.
+    let projected = ledger.projected_committee().unwrap();
+    assert_eq!(projected.starting_round(), ledger.latest_round() + 1);
+    assert_eq!(projected.members(), committee.members());
+    let selector = ledger_committee::CappedStakeSelector { max_weight: ledger_committee::MIN_VALIDATOR_STAKE };
+    assert_eq!(ledger.projected_committee_with_selector(&selector).unwrap().total_stake(), projected.total_stake());
+
+    // Ensure the historical committee lookups match the latest committee.
+    assert_eq!(ledger.get_committee_for_round(ledger.latest_round()).unwrap(), Some(committee.clone()));
+
+    // Ensure the staking state matches the committee and stakers.
+    let state = ledger.get_staking_state([address]).unwrap();
+    assert_eq!(&state.committee, committee.members());
+    assert_eq!(state.bonded, stakers);
+    assert!(state.unbonding.is_empty());
+    assert!(state.balances.contains_key(&address));
+    assert_eq!(state.to_committee(committee.starting_round()).unwrap(), committee);
+
+    // Bond more microcredits to the validator.
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1_000_000u64").unwrap()];
+    let transaction =
+        ledger.vm().execute(&private_key, ("credits.aleo", "bond_public"), inputs.iter(), None, 0, None, rng).unwrap();
+    let block = ledger
+        .prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction.clone()], rng)
+        .unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the bonded balance has a proof of the bond state written by the transaction.
+    let bonded = ledger.get_bonded_balance(&address).unwrap().unwrap();
+    let proof = bonded.proof.unwrap();
+    assert_eq!(proof.receipt().block_hash(), block.hash());
+    assert_eq!(proof.receipt().unconfirmed_id(), transaction.id());
+    let (validator, microcredits) = proof.verify().unwrap();
+    assert_eq!(validator, address);
+    // Note: The bond state excludes the staking rewards of the block.
+    assert!(microcredits >= balance.microcredits + 1_000_000);
+    assert!(microcredits <= bonded.microcredits);
+
+    // Ensure a proof of another bond state is invalid.
+    let bond_state =
+        Value::from_str(&format!("{{ validator: {address}, microcredits: {}u64 }}", microcredits + 1)).unwrap();
+    let tampered = BondedBalanceProof::new(proof.receipt().clone(), address, bond_state);
+    assert!(tampered.verify().is_err());
+    // Ensure a proof for another staker is invalid.
+    let tampered = BondedBalanceProof::new(proof.receipt().clone(), unbonded, proof.bond_state().clone());
+    assert!(tampered.verify().is_err());
 }
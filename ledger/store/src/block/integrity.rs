@@ -0,0 +1,247 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::ops::RangeInclusive;
+
+/// An inconsistency found in block storage.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IntegrityIssue<N: Network> {
+    /// The block hash for the given `block height` is missing.
+    MissingBlockHash(u32),
+    /// The `block hash` of the given `block height` does not map back to the block height.
+    MissingBlockHeight(u32, N::BlockHash),
+    /// The state root for the given `block height` is missing.
+    MissingStateRoot(u32),
+    /// The `state root` of the given `block height` does not map back to the block height.
+    MissingStateRootHeight(u32, N::StateRoot),
+    /// The stored state root for the given `block height` does not match the re-derived block tree root.
+    MismatchedStateRoot(u32),
+    /// The block for the given `block height` is missing data, or its contents do not match its block hash.
+    InvalidBlock(u32, String),
+    /// The confirmed `transaction ID` in the given `block height` belongs to another block.
+    MismatchedTransaction(u32, N::TransactionID),
+    /// The `block hash` maps to the given `block height`, which does not map back to the block hash.
+    OrphanedBlockHeight(N::BlockHash, u32),
+    /// The `state root` maps to the given `block height`, which does not map back to the state root.
+    OrphanedStateRoot(N::StateRoot, u32),
+    /// The confirmed `transaction ID` belongs to a `block hash` that is not in storage.
+    OrphanedTransaction(N::TransactionID, N::BlockHash),
+}
+
+impl<N: Network> IntegrityIssue<N> {
+    /// Returns `true` if the issue is an index entry that can be re-derived from the primary data.
+    pub const fn is_repairable(&self) -> bool {
+        matches!(
+            self,
+            Self::MissingBlockHeight(..)
+                | Self::MissingStateRootHeight(..)
+                | Self::OrphanedBlockHeight(..)
+                | Self::OrphanedStateRoot(..)
+        )
+    }
+}
+
+impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
+    /// Checks the integrity of the blocks in the given range of block heights, and returns the issues found.
+    ///
+    /// For each block, this re-derives the block tree root and compares it to the stored state root,
+    /// cross-checks the block hash, state root, and transaction indexes against the primary data,
+    /// and decodes the block to ensure its contents match its block hash.
+    /// This also scans the indexes for orphaned entries, which point to blocks that are not in storage.
+    pub fn check_integrity(&self, heights: RangeInclusive<u32>) -> Result<Vec<IntegrityIssue<N>>> {
+        let mut issues = Vec::new();
+
+        // Prepare the block tree up to the start of the range.
+        // Note: If a block hash is missing, the block tree can not be re-derived.
+        let mut tree = {
+            let leaves = (0..*heights.start())
+                .map(|height| Ok(self.storage.get_block_hash(height)?.map(|hash| hash.to_bits_le())))
+                .collect::<Result<Option<Vec<_>>>>()?;
+            match leaves {
+                Some(leaves) => Some::<BlockTree<N>>(N::merkle_tree_bhp(&leaves)?),
+                None => None,
+            }
+        };
+
+        for height in heights.clone() {
+            // Retrieve the block hash.
+            let Some(block_hash) = self.storage.get_block_hash(height)? else {
+                issues.push(IntegrityIssue::MissingBlockHash(height));
+                tree = None;
+                continue;
+            };
+            // Ensure the block hash maps back to the block height.
+            if self.storage.reverse_id_map().get_confirmed(&block_hash)?.map(|h| cow_to_copied!(h)) != Some(height) {
+                issues.push(IntegrityIssue::MissingBlockHeight(height, block_hash));
+            }
+
+            // Re-derive the block tree with the block hash.
+            tree = match tree {
+                Some(tree) => Some(tree.prepare_append(&[block_hash.to_bits_le()])?),
+                None => None,
+            };
+
+            // Retrieve the state root.
+            match self.storage.state_root_map().get_confirmed(&height)? {
+                Some(state_root) => {
+                    let state_root = cow_to_copied!(state_root);
+                    // Ensure the state root maps back to the block height.
+                    let reverse_height = self.storage.reverse_state_root_map().get_confirmed(&state_root)?;
+                    if reverse_height.map(|h| cow_to_copied!(h)) != Some(height) {
+                        issues.push(IntegrityIssue::MissingStateRootHeight(height, state_root));
+                    }
+                    // Ensure the state root matches the re-derived block tree root.
+                    if let Some(tree) = &tree {
+                        if state_root != N::StateRoot::from(*tree.root()) {
+                            issues.push(IntegrityIssue::MismatchedStateRoot(height));
+                        }
+                    }
+                }
+                None => issues.push(IntegrityIssue::MissingStateRoot(height)),
+            }
+
+            // Decode the block, and ensure it matches the block hash.
+            // Note: The block is only reachable through its block height, which was checked above.
+            match self.storage.get_block(&block_hash) {
+                Ok(Some(block)) if block.hash() == block_hash && block.height() == height => {
+                    // Ensure the confirmed transactions belong to the block.
+                    for transaction_id in block.transaction_ids() {
+                        match self.storage.confirmed_transactions_map().get_confirmed(transaction_id)? {
+                            Some(confirmed) if confirmed.0 == block_hash => (),
+                            _ => issues.push(IntegrityIssue::MismatchedTransaction(height, *transaction_id)),
+                        }
+                    }
+                }
+                Ok(Some(_)) => issues.push(IntegrityIssue::InvalidBlock(height, "Mismatching block hash".to_string())),
+                Ok(None) => (),
+                Err(error) => issues.push(IntegrityIssue::InvalidBlock(height, error.to_string())),
+            }
+        }
+
+        // Scan the block height index for orphaned entries.
+        for (block_hash, height) in self.storage.reverse_id_map().iter_confirmed() {
+            let (block_hash, height) = (cow_to_copied!(block_hash), cow_to_copied!(height));
+            if heights.contains(&height) && self.storage.get_block_hash(height)? != Some(block_hash) {
+                issues.push(IntegrityIssue::OrphanedBlockHeight(block_hash, height));
+            }
+        }
+        // Scan the state root index for orphaned entries.
+        for (state_root, height) in self.storage.reverse_state_root_map().iter_confirmed() {
+            let (state_root, height) = (cow_to_copied!(state_root), cow_to_copied!(height));
+            if heights.contains(&height)
+                && self.storage.state_root_map().get_confirmed(&height)?.map(|r| cow_to_copied!(r)) != Some(state_root)
+            {
+                issues.push(IntegrityIssue::OrphanedStateRoot(state_root, height));
+            }
+        }
+        // Scan the confirmed transactions for entries that belong to a missing block.
+        // Note: As the block height is unknown, this scan is not restricted to the given range.
+        for (transaction_id, confirmed) in self.storage.confirmed_transactions_map().iter_confirmed() {
+            let block_hash = confirmed.0;
+            if !self.storage.reverse_id_map().contains_key_confirmed(&block_hash)? {
+                issues.push(IntegrityIssue::OrphanedTransaction(cow_to_copied!(transaction_id), block_hash));
+            }
+        }
+
+        Ok(issues)
+    }
+
+    /// Repairs the given integrity issues, and returns the number of issues repaired.
+    ///
+    /// Missing index entries are re-derived from the primary data, and orphaned index entries are removed.
+    /// Issues that are not repairable (see `IntegrityIssue::is_repairable`) are skipped,
+    /// as they require the affected blocks to be removed and synced again.
+    pub fn repair_integrity(&self, issues: &[IntegrityIssue<N>]) -> Result<usize> {
+        atomic_batch_scope!(self, {
+            let mut num_repaired = 0;
+            // Remove the orphaned index entries.
+            // Note: This is performed first, as an orphaned entry may share its key with a missing entry.
+            for issue in issues {
+                match issue {
+                    IntegrityIssue::OrphanedBlockHeight(block_hash, _) => {
+                        self.storage.reverse_id_map().remove(block_hash)?
+                    }
+                    IntegrityIssue::OrphanedStateRoot(state_root, _) => {
+                        self.storage.reverse_state_root_map().remove(state_root)?
+                    }
+                    _ => continue,
+                }
+                num_repaired += 1;
+            }
+            // Restore the missing index entries.
+            for issue in issues {
+                match issue {
+                    IntegrityIssue::MissingBlockHeight(height, block_hash) => {
+                        self.storage.reverse_id_map().insert(*block_hash, *height)?
+                    }
+                    IntegrityIssue::MissingStateRootHeight(height, state_root) => {
+                        self.storage.reverse_state_root_map().insert(*state_root, *height)?
+                    }
+                    _ => continue,
+                }
+                num_repaired += 1;
+            }
+            Ok(num_repaired)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::memory::BlockMemory;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_check_and_repair_integrity() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+        let block_hash = block.hash();
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        block_store.insert(&block).unwrap();
+
+        // Ensure there are no issues.
+        assert!(block_store.check_integrity(0..=0).unwrap().is_empty());
+
+        // Remove the block height index entry, and add an orphaned state root index entry.
+        let orphaned_state_root = <CurrentNetwork as Network>::StateRoot::from(Field::rand(rng));
+        block_store.storage.reverse_id_map().remove(&block_hash).unwrap();
+        block_store.storage.reverse_state_root_map().insert(orphaned_state_root, 0).unwrap();
+
+        // Ensure the issues are found.
+        let issues = block_store.check_integrity(0..=0).unwrap();
+        assert_eq!(issues, vec![
+            IntegrityIssue::MissingBlockHeight(0, block_hash),
+            IntegrityIssue::OrphanedStateRoot(orphaned_state_root, 0),
+        ]);
+        assert!(issues.iter().all(|issue| issue.is_repairable()));
+
+        // Repair the issues.
+        assert_eq!(2, block_store.repair_integrity(&issues).unwrap());
+        assert!(block_store.check_integrity(0..=0).unwrap().is_empty());
+        assert_eq!(Some(block), block_store.get_block(&block_hash).unwrap());
+
+        // Ensure a missing block is found, and is not repairable.
+        let issues = block_store.check_integrity(0..=1).unwrap();
+        assert_eq!(issues, vec![IntegrityIssue::MissingBlockHash(1)]);
+        assert_eq!(0, block_store.repair_integrity(&issues).unwrap());
+    }
+}
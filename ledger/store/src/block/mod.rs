@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod integrity;
+pub use integrity::*;
+
 use crate::{
     atomic_batch_scope,
     cow_to_cloned,
@@ -28,7 +31,7 @@ use console::{
     types::Field,
 };
 use ledger_authority::Authority;
-use ledger_block::{Block, ConfirmedTransaction, Header, NumFinalizeSize, Ratify, Transaction, Transactions};
+use ledger_block::{Block, ConfirmedTransaction, Header, NumFinalizeSize, Ratify, Receipt, Transaction, Transactions};
 use ledger_coinbase::{CoinbaseSolution, PuzzleCommitment};
 use ledger_narwhal_batch_certificate::BatchCertificate;
 use synthesizer_program::Program;
@@ -40,6 +43,9 @@ use std::{borrow::Cow, io::Cursor, sync::Arc};
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
 
+#[cfg(feature = "async")]
+use tokio::task;
+
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ConfirmedTxType {
     /// A deploy transaction that was accepted.
@@ -145,6 +151,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     type CoinbaseSolutionMap: for<'a> Map<'a, N::BlockHash, Option<CoinbaseSolution<N>>>;
     /// The mapping of `puzzle commitment` to `block height`.
     type CoinbasePuzzleCommitmentMap: for<'a> Map<'a, PuzzleCommitment<N>, u32>;
+    /// The mapping of `unconfirmed transaction ID` to `receipt`.
+    type ReceiptsMap: for<'a> Map<'a, N::TransactionID, Receipt<N>>;
 
     /// Initializes the block storage.
     fn open(dev: Option<u16>) -> Result<Self>;
@@ -175,6 +183,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     fn coinbase_solution_map(&self) -> &Self::CoinbaseSolutionMap;
     /// Returns the coinbase puzzle commitment map.
     fn coinbase_puzzle_commitment_map(&self) -> &Self::CoinbasePuzzleCommitmentMap;
+    /// Returns the receipts map.
+    fn receipts_map(&self) -> &Self::ReceiptsMap;
 
     /// Returns the transition store.
     fn transition_store(&self) -> &TransitionStore<N, Self::TransitionStorage> {
@@ -201,6 +211,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.ratifications_map().start_atomic();
         self.coinbase_solution_map().start_atomic();
         self.coinbase_puzzle_commitment_map().start_atomic();
+        self.receipts_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -218,6 +229,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.ratifications_map().is_atomic_in_progress()
             || self.coinbase_solution_map().is_atomic_in_progress()
             || self.coinbase_puzzle_commitment_map().is_atomic_in_progress()
+            || self.receipts_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -235,6 +247,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.ratifications_map().atomic_checkpoint();
         self.coinbase_solution_map().atomic_checkpoint();
         self.coinbase_puzzle_commitment_map().atomic_checkpoint();
+        self.receipts_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -252,6 +265,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.ratifications_map().clear_latest_checkpoint();
         self.coinbase_solution_map().clear_latest_checkpoint();
         self.coinbase_puzzle_commitment_map().clear_latest_checkpoint();
+        self.receipts_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -269,6 +283,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.ratifications_map().atomic_rewind();
         self.coinbase_solution_map().atomic_rewind();
         self.coinbase_puzzle_commitment_map().atomic_rewind();
+        self.receipts_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -286,6 +301,7 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.ratifications_map().abort_atomic();
         self.coinbase_solution_map().abort_atomic();
         self.coinbase_puzzle_commitment_map().abort_atomic();
+        self.receipts_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -302,7 +318,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.transaction_store().finish_atomic()?;
         self.ratifications_map().finish_atomic()?;
         self.coinbase_solution_map().finish_atomic()?;
-        self.coinbase_puzzle_commitment_map().finish_atomic()
+        self.coinbase_puzzle_commitment_map().finish_atomic()?;
+        self.receipts_map().finish_atomic()
     }
 
     /// Stores the given `(state root, block)` pair into storage.
@@ -314,6 +331,8 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             .cloned()
             .map(|confirmed| to_confirmed_tuple(confirmed))
             .collect::<Result<Vec<_>, _>>()?;
+        // Prepare the receipts.
+        let receipts = Receipt::from_block(block)?;
 
         // Retrieve the certificate IDs to store.
         let certificates_to_store = match block.authority() {
@@ -356,6 +375,11 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 self.transaction_store().insert(&transaction)?;
             }
 
+            // Store the receipts.
+            for receipt in receipts {
+                self.receipts_map().insert(receipt.unconfirmed_id(), receipt)?;
+            }
+
             // Store the block ratifications.
             self.ratifications_map().insert(block.hash(), block.ratifications().clone())?;
 
@@ -409,6 +433,30 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             None => bail!("Failed to remove block: missing authority for block '{block_height}' ('{block_hash}')"),
         };
 
+        // Determine the unconfirmed transaction IDs, to remove the receipts and the rejection reasons.
+        let mut unconfirmed_ids_to_remove = Vec::with_capacity(transaction_ids.len());
+        let mut rejected_ids_to_remove = Vec::new();
+        for transaction_id in transaction_ids.iter() {
+            // Check if the transaction is rejected.
+            let is_rejected = match self.confirmed_transactions_map().get_confirmed(transaction_id)? {
+                Some(attributes) => {
+                    matches!(attributes.1, ConfirmedTxType::RejectedDeploy(..) | ConfirmedTxType::RejectedExecute(..))
+                }
+                None => false,
+            };
+            // Retrieve the unconfirmed transaction ID of the rejected transaction.
+            // Note: The unconfirmed transaction ID of an accepted transaction is its transaction ID.
+            if is_rejected {
+                if let Some(confirmed) = self.get_confirmed_transaction(*transaction_id)? {
+                    let unconfirmed_id = confirmed.unconfirmed_id()?;
+                    unconfirmed_ids_to_remove.push(unconfirmed_id);
+                    rejected_ids_to_remove.push(unconfirmed_id);
+                }
+            } else {
+                unconfirmed_ids_to_remove.push(*transaction_id);
+            }
+        }
+
         atomic_batch_scope!(self, {
             // Remove the (block height, state root) pair.
             self.state_root_map().remove(&block_height)?;
@@ -441,6 +489,16 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 self.transaction_store().remove(transaction_id)?;
             }
 
+            // Remove the rejection reasons.
+            for unconfirmed_id in rejected_ids_to_remove.iter() {
+                self.transaction_store().remove_rejection_reason(unconfirmed_id)?;
+            }
+
+            // Remove the receipts.
+            for unconfirmed_id in unconfirmed_ids_to_remove.iter() {
+                self.receipts_map().remove(unconfirmed_id)?;
+            }
+
             // Remove the block ratifications.
             self.ratifications_map().remove(block_hash)?;
 
@@ -522,6 +580,11 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             None => bail!("The block '{block_hash}' for commitment '{commitment}' is missing in storage"),
         };
 
+        // Ensure the block is in the block tree.
+        if block.height() as usize >= block_tree.number_of_leaves() {
+            bail!("The block '{block_hash}' for commitment '{commitment}' is not in the given state root");
+        }
+
         // Construct the global state root and block path.
         let global_state_root = *block_tree.root();
         let block_path = block_tree.prove(block.height() as usize, &block.hash().to_bits_le())?;
@@ -685,6 +748,14 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         to_confirmed_transaction(confirmed_type, transaction, blob).map(Some)
     }
 
+    /// Returns the receipt for the given unconfirmed `transaction ID`.
+    fn get_receipt(&self, transaction_id: &N::TransactionID) -> Result<Option<Receipt<N>>> {
+        match self.receipts_map().get_confirmed(transaction_id)? {
+            Some(receipt) => Ok(Some(cow_to_cloned!(receipt))),
+            None => Ok(None),
+        }
+    }
+
     /// Returns the block ratifications for the given `block hash`.
     fn get_block_ratifications(&self, block_hash: &N::BlockHash) -> Result<Option<Vec<Ratify<N>>>> {
         match self.ratifications_map().get_confirmed(block_hash)? {
@@ -797,6 +868,42 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         Ok(())
     }
 
+    /// Stores the given block into storage, and runs the given operations in the same atomic write batch.
+    ///
+    /// The operations may write to other stores (e.g. the finalize store) that share the same database.
+    /// If the block insertion or the operations fail, neither the block nor the effects of the operations are written.
+    pub fn insert_with<T>(&self, block: &Block<N>, operations: impl FnOnce() -> Result<T>) -> Result<T> {
+        // Ensure that there is no atomic batch write in progress.
+        ensure!(!self.is_atomic_in_progress(), "Cannot insert a block while an atomic batch write is in progress");
+
+        // Acquire the write lock on the block tree.
+        let mut tree = self.tree.write();
+        // Prepare an updated Merkle tree containing the new block hash.
+        let updated_tree = tree.prepare_append(&[block.hash().to_bits_le()])?;
+        // Ensure the next block height is correct.
+        if block.height() != u32::try_from(updated_tree.number_of_leaves())? - 1 {
+            bail!("Attempted to insert a block at the incorrect height into storage")
+        }
+
+        // Start the atomic batch.
+        self.start_atomic();
+        // Insert the (state root, block height) pair, and run the operations.
+        let result = self.storage.insert((*updated_tree.root()).into(), block).and_then(|_| operations());
+        // Commit the atomic batch IFF the block and the operations succeeded.
+        match result.and_then(|result| self.finish_atomic().map(|_| result)) {
+            Ok(result) => {
+                // Update the block tree.
+                *tree = updated_tree;
+                Ok(result)
+            }
+            Err(error) => {
+                // Abort the atomic batch, which discards all of the queued writes.
+                self.abort_atomic();
+                Err(error)
+            }
+        }
+    }
+
     /// Removes the last 'n' blocks from storage.
     pub fn remove_last_n(&self, n: u32) -> Result<()> {
         // Ensure 'n' is non-zero.
@@ -924,6 +1031,11 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         (*self.tree.read().root()).into()
     }
 
+    /// Returns the current block height, or `None` if there are no blocks.
+    pub fn current_block_height(&self) -> Option<u32> {
+        u32::try_from(self.tree.read().number_of_leaves()).ok()?.checked_sub(1)
+    }
+
     /// Returns the state root that contains the given `block height`.
     pub fn get_state_root(&self, block_height: u32) -> Result<Option<N::StateRoot>> {
         self.storage.get_state_root(block_height)
@@ -934,6 +1046,38 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.get_state_path_for_commitment(commitment, &self.tree.read())
     }
 
+    /// Returns a state path for the given `commitment`, to the given historical `state root`.
+    ///
+    /// The block tree for the state root is reconstructed from the current block tree, which costs
+    /// more as the state root is further behind the current state root.
+    pub fn get_state_path_for_commitment_at(
+        &self,
+        commitment: &Field<N>,
+        state_root: N::StateRoot,
+    ) -> Result<StatePath<N>> {
+        // Acquire the read lock on the block tree.
+        let tree = self.tree.read();
+        // Retrieve the block height of the state root.
+        let Some(height) = self.storage.find_block_height_from_state_root(state_root)? else {
+            bail!("The state root '{state_root}' does not exist in storage")
+        };
+        // Determine the number of blocks after the state root.
+        let num_blocks = match tree.number_of_leaves().checked_sub(height as usize + 1) {
+            Some(num_blocks) => num_blocks,
+            None => bail!("The state root '{state_root}' is ahead of the block tree"),
+        };
+        match num_blocks {
+            // Use the current block tree, if the state root is the current state root.
+            0 => self.storage.get_state_path_for_commitment(commitment, &tree),
+            // Otherwise, reconstruct the block tree for the state root.
+            num_blocks => {
+                let historical_tree = tree.prepare_remove_last_n(num_blocks)?;
+                ensure!(*historical_tree.root() == *state_root, "Failed to reconstruct the state root '{state_root}'");
+                self.storage.get_state_path_for_commitment(commitment, &historical_tree)
+            }
+        }
+    }
+
     /// Returns the previous block hash of the given `block height`.
     pub fn get_previous_block_hash(&self, height: u32) -> Result<Option<N::BlockHash>> {
         self.storage.get_previous_block_hash(height)
@@ -987,10 +1131,20 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.get_confirmed_transaction(*transaction_id)
     }
 
+    /// Returns the receipt for the given unconfirmed `transaction ID`.
+    pub fn get_receipt(&self, transaction_id: &N::TransactionID) -> Result<Option<Receipt<N>>> {
+        self.storage.get_receipt(transaction_id)
+    }
+
     /// Returns the program for the given `program ID`.
     pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
         self.storage.transaction_store().get_program(program_id)
     }
+
+    /// Returns the checksum of the latest edition of the program for the given `program ID`.
+    pub fn get_program_checksum(&self, program_id: &ProgramID<N>) -> Result<Option<[u8; 32]>> {
+        self.storage.transaction_store().get_program_checksum(program_id)
+    }
 }
 
 impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
@@ -1042,6 +1196,73 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
     }
 }
 
+#[cfg(feature = "async")]
+impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
+    /// Stores the given block into storage, without blocking the async runtime.
+    pub async fn insert_async(&self, block: Block<N>) -> Result<()> {
+        self.spawn_blocking(move |store| store.insert(&block)).await
+    }
+
+    /// Returns the block hash for the given `block height`, without blocking the async runtime.
+    pub async fn get_block_hash_async(&self, height: u32) -> Result<Option<N::BlockHash>> {
+        self.spawn_blocking(move |store| store.get_block_hash(height)).await
+    }
+
+    /// Returns the block for the given `block hash`, without blocking the async runtime.
+    pub async fn get_block_async(&self, block_hash: N::BlockHash) -> Result<Option<Block<N>>> {
+        self.spawn_blocking(move |store| store.get_block(&block_hash)).await
+    }
+
+    /// Returns the blocks for the given `block heights`, reading all of them in a single blocking task.
+    pub async fn get_blocks_async(&self, heights: Vec<u32>) -> Result<Vec<Option<Block<N>>>> {
+        self.spawn_blocking(move |store| {
+            heights
+                .into_iter()
+                .map(|height| match store.get_block_hash(height)? {
+                    Some(block_hash) => store.get_block(&block_hash),
+                    None => Ok(None),
+                })
+                .collect()
+        })
+        .await
+    }
+
+    /// Returns the block transactions for the given `block hash`, without blocking the async runtime.
+    pub async fn get_block_transactions_async(&self, block_hash: N::BlockHash) -> Result<Option<Transactions<N>>> {
+        self.spawn_blocking(move |store| store.get_block_transactions(&block_hash)).await
+    }
+
+    /// Returns the confirmed transaction for the given `transaction ID`, without blocking the async runtime.
+    pub async fn get_confirmed_transaction_async(
+        &self,
+        transaction_id: N::TransactionID,
+    ) -> Result<Option<ConfirmedTransaction<N>>> {
+        self.spawn_blocking(move |store| store.get_confirmed_transaction(&transaction_id)).await
+    }
+
+    /// Returns a state path for the given `commitment`, without blocking the async runtime.
+    pub async fn get_state_path_for_commitment_async(&self, commitment: Field<N>) -> Result<StatePath<N>> {
+        self.spawn_blocking(move |store| store.get_state_path_for_commitment(&commitment)).await
+    }
+
+    /// Returns the program for the given `program ID`, without blocking the async runtime.
+    pub async fn get_program_async(&self, program_id: ProgramID<N>) -> Result<Option<Program<N>>> {
+        self.spawn_blocking(move |store| store.get_program(&program_id)).await
+    }
+
+    /// Runs the given operation on a clone of the block store, on the blocking thread pool.
+    async fn spawn_blocking<T: Send + 'static>(
+        &self,
+        operation: impl FnOnce(Self) -> Result<T> + Send + 'static,
+    ) -> Result<T> {
+        let store = self.clone();
+        match task::spawn_blocking(move || operation(store)).await {
+            Ok(result) => result,
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1079,6 +1300,41 @@ mod tests {
         assert_eq!(None, candidate);
     }
 
+    #[test]
+    fn test_insert_with() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+        let block_hash = block.hash();
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        let state_root = block_store.current_state_root();
+
+        // Insert the block with failing operations.
+        let result = block_store.insert_with(&block, || -> Result<()> { bail!("Failed to run the operations") });
+        assert!(result.is_err());
+
+        // Ensure the block does not exist, and the block tree is unchanged.
+        assert_eq!(None, block_store.get_block(&block_hash).unwrap());
+        assert_eq!(state_root, block_store.current_state_root());
+        assert!(!block_store.is_atomic_in_progress());
+
+        // Insert the block with succeeding operations.
+        let result = block_store.insert_with(&block, || {
+            // Ensure the block is not yet written.
+            assert!(!block_store.contains_block_hash(&block_hash)?);
+            Ok(1u8)
+        });
+        assert_eq!(1u8, result.unwrap());
+
+        // Ensure the block exists, and the block tree is updated.
+        assert_eq!(Some(block), block_store.get_block(&block_hash).unwrap());
+        assert_ne!(state_root, block_store.current_state_root());
+        assert!(!block_store.is_atomic_in_progress());
+    }
+
     #[test]
     fn test_find_block_hash() {
         let rng = &mut TestRng::default();
@@ -1119,4 +1375,39 @@ mod tests {
             assert_eq!(None, candidate);
         }
     }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_insert_get_async() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+        let block_hash = block.hash();
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+        // Ensure the block does not exist.
+        let candidate = block_store.get_block_async(block_hash).await.unwrap();
+        assert_eq!(None, candidate);
+
+        // Insert the block.
+        block_store.insert_async(block.clone()).await.unwrap();
+
+        // Retrieve the block.
+        assert_eq!(Some(block_hash), block_store.get_block_hash_async(0).await.unwrap());
+        let candidate = block_store.get_block_async(block_hash).await.unwrap();
+        assert_eq!(Some(block.clone()), candidate);
+        let candidates = block_store.get_blocks_async(vec![0, 1]).await.unwrap();
+        assert_eq!(vec![Some(block.clone()), None], candidates);
+
+        // Retrieve the transactions.
+        let candidate = block_store.get_block_transactions_async(block_hash).await.unwrap();
+        assert_eq!(Some(block.transactions()), candidate.as_ref());
+        for transaction in block.transactions().iter() {
+            let candidate = block_store.get_confirmed_transaction_async(transaction.id()).await.unwrap();
+            assert_eq!(Some(transaction), candidate.as_ref());
+        }
+    }
 }
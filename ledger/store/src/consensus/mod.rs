@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::{
+    helpers::StorageConfig,
     BlockStorage,
     BlockStore,
     FinalizeStorage,
@@ -41,6 +42,12 @@ pub trait ConsensusStorage<N: Network>: 'static + Clone + Send + Sync {
     /// Initializes the consensus storage.
     fn open(dev: Option<u16>) -> Result<Self>;
 
+    /// Sets the storage configuration, which is applied when the consensus storage is first opened.
+    /// Note: The storage configuration is ignored by storage that is not persistent.
+    fn configure(_config: &StorageConfig) -> Result<()> {
+        Ok(())
+    }
+
     /// Returns the finalize storage.
     fn finalize_store(&self) -> &FinalizeStore<N, Self::FinalizeStorage>;
     /// Returns the block storage.
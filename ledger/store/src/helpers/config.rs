@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The compression algorithm for the persistent storage.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Compression {
+    /// No compression.
+    None,
+    /// LZ4 compression, which is fast, with a moderate compression ratio.
+    Lz4,
+    /// Zstandard compression, which is slower, with a high compression ratio.
+    Zstd,
+}
+
+/// The compaction style for the persistent storage.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CompactionStyle {
+    /// Level compaction, which favors read and space amplification.
+    Level,
+    /// Universal compaction, which favors write amplification.
+    Universal,
+}
+
+/// The tuning options for the persistent storage.
+///
+/// The options are applied when the database is first opened, and are ignored by in-memory storage.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageConfig {
+    /// The size of the block cache, in bytes. If `None`, the RocksDB default is used.
+    pub block_cache_size: Option<usize>,
+    /// The compression algorithm for all levels, except the bottommost level.
+    pub compression: Compression,
+    /// The compression algorithm for the bottommost level, which holds most of the data.
+    /// If `None`, the bottommost level uses `compression`.
+    pub bottommost_compression: Option<Compression>,
+    /// The compaction style.
+    pub compaction_style: CompactionStyle,
+    /// The number of background threads for flushes and compactions.
+    pub parallelism: i32,
+    /// The maximum total size of the write-ahead log files, in bytes. If `None`, the RocksDB default is used.
+    pub max_total_wal_size: Option<u64>,
+    /// The number of bytes written to the write-ahead log between background syncs. If `0`, this is disabled.
+    pub wal_bytes_per_sync: u64,
+}
+
+impl Default for StorageConfig {
+    /// Returns the default storage configuration.
+    fn default() -> Self {
+        Self {
+            block_cache_size: None,
+            compression: Compression::Lz4,
+            bottommost_compression: None,
+            compaction_style: CompactionStyle::Level,
+            parallelism: 2,
+            max_total_wal_size: None,
+            wal_bytes_per_sync: 0,
+        }
+    }
+}
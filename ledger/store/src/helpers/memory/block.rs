@@ -21,7 +21,7 @@ use crate::{
 };
 use console::{prelude::*, types::Field};
 use ledger_authority::Authority;
-use ledger_block::{Header, Ratify};
+use ledger_block::{Header, Ratify, Receipt};
 use ledger_coinbase::{CoinbaseSolution, PuzzleCommitment};
 
 /// An in-memory block storage.
@@ -53,6 +53,8 @@ pub struct BlockMemory<N: Network> {
     coinbase_solution_map: MemoryMap<N::BlockHash, Option<CoinbaseSolution<N>>>,
     /// The coinbase puzzle commitment map.
     coinbase_puzzle_commitment_map: MemoryMap<PuzzleCommitment<N>, u32>,
+    /// The receipts map.
+    receipts_map: MemoryMap<N::TransactionID, Receipt<N>>,
 }
 
 #[rustfmt::skip]
@@ -71,6 +73,7 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     type RatificationsMap = MemoryMap<N::BlockHash, Vec<Ratify<N>>>;
     type CoinbaseSolutionMap = MemoryMap<N::BlockHash, Option<CoinbaseSolution<N>>>;
     type CoinbasePuzzleCommitmentMap = MemoryMap<PuzzleCommitment<N>, u32>;
+    type ReceiptsMap = MemoryMap<N::TransactionID, Receipt<N>>;
 
     /// Initializes the block storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -93,6 +96,7 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
             ratifications_map: MemoryMap::default(),
             coinbase_solution_map: MemoryMap::default(),
             coinbase_puzzle_commitment_map: MemoryMap::default(),
+            receipts_map: MemoryMap::default(),
         })
     }
 
@@ -160,4 +164,9 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     fn coinbase_puzzle_commitment_map(&self) -> &Self::CoinbasePuzzleCommitmentMap {
         &self.coinbase_puzzle_commitment_map
     }
+
+    /// Returns the receipts map.
+    fn receipts_map(&self) -> &Self::ReceiptsMap {
+        &self.receipts_map
+    }
 }
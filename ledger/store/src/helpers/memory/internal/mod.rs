@@ -253,6 +253,15 @@ impl<
         Ok(self.map.read().contains_key(&bincode::serialize(key)?))
     }
 
+    ///
+    /// Returns `true` for each of the given keys that exists in the map, in the same order.
+    /// This method performs a single batched lookup, instead of one lookup per key.
+    ///
+    fn contains_keys_confirmed(&self, keys: &[K]) -> Result<Vec<bool>> {
+        let map = self.map.read();
+        keys.iter().map(|key| Ok(map.contains_key(&bincode::serialize(key)?))).collect()
+    }
+
     ///
     /// Returns `true` if the given key exists in the map.
     /// This method first checks the atomic batch, and if it does not exist, then checks the map.
@@ -378,6 +387,14 @@ mod tests {
         assert!(map.contains_key_confirmed(&address).unwrap());
     }
 
+    #[test]
+    fn test_contains_keys() {
+        // Initialize a map.
+        let map: MemoryMap<usize, String> = [(1, "1".to_string()), (3, "3".to_string())].into_iter().collect();
+        assert_eq!(map.contains_keys_confirmed(&[0, 1, 2, 3]).unwrap(), vec![false, true, false, true]);
+        assert!(map.contains_keys_confirmed(&[]).unwrap().is_empty());
+    }
+
     #[test]
     fn test_insert_and_get_speculative() {
         // Initialize a map.
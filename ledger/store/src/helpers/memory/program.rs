@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{helpers::memory::MemoryMap, CommitteeStorage, CommitteeStore, FinalizeStorage};
+use crate::{helpers::memory::MemoryMap, CommitteeStorage, CommitteeStore, FinalizeStorage, FinalizeUndo};
 use console::{
     prelude::*,
     program::{Identifier, Plaintext, ProgramID, Value},
@@ -37,6 +37,8 @@ pub struct FinalizeMemory<N: Network> {
     key_map: MemoryMap<Field<N>, Plaintext<N>>,
     /// The value map.
     value_map: MemoryMap<Field<N>, Value<N>>,
+    /// The undo map.
+    undo_map: MemoryMap<u32, Vec<FinalizeUndo<N>>>,
     /// The optional development ID.
     dev: Option<u16>,
 }
@@ -49,6 +51,7 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
     type KeyValueIDMap = MemoryMap<Field<N>, IndexMap<Field<N>, Field<N>>>;
     type KeyMap = MemoryMap<Field<N>, Plaintext<N>>;
     type ValueMap = MemoryMap<Field<N>, Value<N>>;
+    type UndoMap = MemoryMap<u32, Vec<FinalizeUndo<N>>>;
 
     /// Initializes the finalize storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -62,6 +65,7 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
             key_value_id_map: MemoryMap::default(),
             key_map: MemoryMap::default(),
             value_map: MemoryMap::default(),
+            undo_map: MemoryMap::default(),
             dev,
         })
     }
@@ -96,6 +100,11 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
         &self.value_map
     }
 
+    /// Returns the undo map.
+    fn undo_map(&self) -> &Self::UndoMap {
+        &self.undo_map
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
         self.dev
@@ -28,6 +28,7 @@ use console::{
     prelude::*,
     program::{Identifier, ProgramID, ProgramOwner},
 };
+use ledger_block::RejectionReason;
 use synthesizer_program::Program;
 use synthesizer_snark::{Certificate, Proof, VerifyingKey};
 
@@ -36,6 +37,8 @@ use synthesizer_snark::{Certificate, Proof, VerifyingKey};
 pub struct TransactionMemory<N: Network> {
     /// The mapping of `transaction ID` to `transaction type`.
     id_map: MemoryMap<N::TransactionID, TransactionType>,
+    /// The mapping of `unconfirmed transaction ID` to `rejection reason`.
+    rejection_reason_map: MemoryMap<N::TransactionID, RejectionReason<N>>,
     /// The deployment store.
     deployment_store: DeploymentStore<N, DeploymentMemory<N>>,
     /// The execution store.
@@ -47,6 +50,7 @@ pub struct TransactionMemory<N: Network> {
 #[rustfmt::skip]
 impl<N: Network> TransactionStorage<N> for TransactionMemory<N> {
     type IDMap = MemoryMap<N::TransactionID, TransactionType>;
+    type RejectionReasonMap = MemoryMap<N::TransactionID, RejectionReason<N>>;
     type DeploymentStorage = DeploymentMemory<N>;
     type ExecutionStorage = ExecutionMemory<N>;
     type FeeStorage = FeeMemory<N>;
@@ -61,7 +65,7 @@ impl<N: Network> TransactionStorage<N> for TransactionMemory<N> {
         // Initialize the execution store.
         let execution_store = ExecutionStore::<N, ExecutionMemory<N>>::open(fee_store.clone())?;
         // Return the transaction storage.
-        Ok(Self { id_map: MemoryMap::default(), deployment_store, execution_store, fee_store })
+        Ok(Self { id_map: MemoryMap::default(), rejection_reason_map: MemoryMap::default(), deployment_store, execution_store, fee_store })
     }
 
     /// Returns the ID map.
@@ -69,6 +73,11 @@ impl<N: Network> TransactionStorage<N> for TransactionMemory<N> {
         &self.id_map
     }
 
+    /// Returns the rejection reason map.
+    fn rejection_reason_map(&self) -> &Self::RejectionReasonMap {
+        &self.rejection_reason_map
+    }
+
     /// Returns the deployment store.
     fn deployment_store(&self) -> &DeploymentStore<N, Self::DeploymentStorage> {
         &self.deployment_store
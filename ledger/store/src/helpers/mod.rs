@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod config;
+pub use config::*;
+
 pub mod memory;
 #[cfg(feature = "rocks")]
 pub mod rocksdb;
@@ -99,6 +102,12 @@ pub trait MapRead<
         K: Borrow<Q>,
         Q: PartialEq + Eq + Hash + Serialize + ?Sized;
 
+    ///
+    /// Returns `true` for each of the given keys that exists in the map, in the same order.
+    /// This method performs a single batched lookup, instead of one lookup per key.
+    ///
+    fn contains_keys_confirmed(&self, keys: &[K]) -> Result<Vec<bool>>;
+
     ///
     /// Returns `true` if the given key exists in the map.
     /// This method first checks the atomic batch, and if it does not exist, then checks the map.
@@ -27,7 +27,7 @@ use crate::{
 };
 use console::{prelude::*, types::Field};
 use ledger_authority::Authority;
-use ledger_block::{Header, Ratify};
+use ledger_block::{Header, Ratify, Receipt};
 use ledger_coinbase::{CoinbaseSolution, PuzzleCommitment};
 
 /// A RocksDB block storage.
@@ -59,6 +59,8 @@ pub struct BlockDB<N: Network> {
     coinbase_solution_map: DataMap<N::BlockHash, Option<CoinbaseSolution<N>>>,
     /// The coinbase puzzle commitment map.
     coinbase_puzzle_commitment_map: DataMap<PuzzleCommitment<N>, u32>,
+    /// The receipts map.
+    receipts_map: DataMap<N::TransactionID, Receipt<N>>,
 }
 
 #[rustfmt::skip]
@@ -77,6 +79,7 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
     type RatificationsMap = DataMap<N::BlockHash, Vec<Ratify<N>>>;
     type CoinbaseSolutionMap = DataMap<N::BlockHash, Option<CoinbaseSolution<N>>>;
     type CoinbasePuzzleCommitmentMap = DataMap<PuzzleCommitment<N>, u32>;
+    type ReceiptsMap = DataMap<N::TransactionID, Receipt<N>>;
 
     /// Initializes the block storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -99,6 +102,7 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
             ratifications_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::Ratifications))?,
             coinbase_solution_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::CoinbaseSolution))?,
             coinbase_puzzle_commitment_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::CoinbasePuzzleCommitment))?,
+            receipts_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::Receipts))?,
         })
     }
 
@@ -166,4 +170,9 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
     fn coinbase_puzzle_commitment_map(&self) -> &Self::CoinbasePuzzleCommitmentMap {
         &self.coinbase_puzzle_commitment_map
     }
+
+    /// Returns the receipts map.
+    fn receipts_map(&self) -> &Self::ReceiptsMap {
+        &self.receipts_map
+    }
 }
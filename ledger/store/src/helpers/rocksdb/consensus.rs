@@ -13,7 +13,10 @@
 // limitations under the License.
 
 use crate::{
-    helpers::rocksdb::{BlockDB, FinalizeDB, TransactionDB, TransitionDB},
+    helpers::{
+        rocksdb::{internal, BlockDB, FinalizeDB, TransactionDB, TransitionDB},
+        StorageConfig,
+    },
     BlockStore,
     ConsensusStorage,
     FinalizeStore,
@@ -49,6 +52,11 @@ impl<N: Network> ConsensusStorage<N> for ConsensusDB<N> {
         })
     }
 
+    /// Sets the storage configuration, which is applied when the database is first opened.
+    fn configure(config: &StorageConfig) -> Result<()> {
+        internal::RocksDB::configure(config.clone())
+    }
+
     /// Returns the finalize store.
     fn finalize_store(&self) -> &FinalizeStore<N, Self::FinalizeStorage> {
         &self.finalize_store
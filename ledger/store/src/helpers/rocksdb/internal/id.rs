@@ -68,6 +68,7 @@ pub enum BlockMap {
     Ratifications = DataID::BlockRatificationsMap as u16,
     CoinbaseSolution = DataID::BlockCoinbaseSolutionMap as u16,
     CoinbasePuzzleCommitment = DataID::BlockCoinbasePuzzleCommitmentMap as u16,
+    Receipts = DataID::BlockReceiptsMap as u16,
 }
 
 /// The RocksDB map prefix for committee-related entries.
@@ -156,6 +157,7 @@ pub enum TransitionOutputMap {
 #[repr(u16)]
 pub enum TransactionMap {
     ID = DataID::TransactionIDMap as u16,
+    RejectionReason = DataID::TransactionRejectionReasonMap as u16,
 }
 
 /// The RocksDB map prefix for transition-related entries.
@@ -183,6 +185,7 @@ pub enum ProgramMap {
     KeyValueID = DataID::KeyValueIDMap as u16,
     Key = DataID::KeyMap as u16,
     Value = DataID::ValueMap as u16,
+    Undo = DataID::FinalizeUndoMap as u16,
 }
 
 /// The RocksDB map prefix for test-related entries.
@@ -270,6 +273,12 @@ enum DataID {
     KeyValueIDMap,
     KeyMap,
     ValueMap,
+    // Transaction
+    TransactionRejectionReasonMap,
+    // Block
+    BlockReceiptsMap,
+    // Program
+    FinalizeUndoMap,
 
     // Testing
     #[cfg(test)]
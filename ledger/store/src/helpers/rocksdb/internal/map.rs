@@ -51,6 +51,8 @@ impl<
             }
             // Otherwise, insert the key-value pair directly into the map.
             false => {
+                #[cfg(feature = "metrics")]
+                let _timer = self.timer("snarkvm_storage_write_seconds");
                 // Prepare the prefixed key and serialized value.
                 let raw_key = self.create_prefixed_key(&key)?;
                 let raw_value = bincode::serialize(&value)?;
@@ -73,6 +75,8 @@ impl<
             }
             // Otherwise, remove the key-value pair directly from the map.
             false => {
+                #[cfg(feature = "metrics")]
+                let _timer = self.timer("snarkvm_storage_write_seconds");
                 // Prepare the prefixed key.
                 let raw_key = self.create_prefixed_key(key)?;
                 self.database.delete(raw_key)?;
@@ -208,8 +212,12 @@ impl<
         if previous_atomic_depth == 1 {
             // Empty the collection of pending operations.
             let batch = mem::take(&mut *self.database.atomic_batch.lock());
+            #[cfg(feature = "metrics")]
+            let start = std::time::Instant::now();
             // Execute all the operations atomically.
             self.database.rocksdb.write(batch)?;
+            #[cfg(feature = "metrics")]
+            metrics::histogram!("snarkvm_storage_batch_write_seconds", start.elapsed().as_secs_f64());
             // Ensure that the database atomic batch is empty.
             assert!(self.database.atomic_batch.lock().is_empty());
         }
@@ -241,6 +249,15 @@ impl<
         self.get_raw(key).map(|v| v.is_some())
     }
 
+    ///
+    /// Returns `true` for each of the given keys that exists in the map, in the same order.
+    /// This method performs a single batched lookup, instead of one lookup per key.
+    ///
+    fn contains_keys_confirmed(&self, keys: &[K]) -> Result<Vec<bool>> {
+        let raw_keys = keys.iter().map(|key| self.create_prefixed_key(key)).collect::<Result<Vec<_>>>()?;
+        self.database.multi_get(raw_keys).into_iter().map(|result| Ok(result?.is_some())).collect()
+    }
+
     ///
     /// Returns `true` if the given key exists in the map.
     /// This method first checks the atomic batch, and if it does not exist, then checks the map.
@@ -349,6 +366,8 @@ impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> DataMap<K
         K: Borrow<Q>,
         Q: Serialize + ?Sized,
     {
+        #[cfg(feature = "metrics")]
+        let _timer = self.timer("snarkvm_storage_read_seconds");
         let raw_key = self.create_prefixed_key(key)?;
         match self.database.get_pinned(&raw_key)? {
             Some(data) => Ok(Some(data)),
@@ -1486,4 +1505,105 @@ mod tests {
         assert_eq!(test_storage.extra_maps.own_map2.iter_confirmed().count(), 1);
         assert_eq!(test_storage.extra_maps.extra_maps.own_map.iter_confirmed().count(), 0);
     }
+
+    #[test]
+    #[serial]
+    fn test_stats() -> Result<()> {
+        let database = RocksDB::open_testing(temp_dir(), None)?;
+        let map_1: DataMap<usize, String> = open_map_testing_from_db(database.clone(), MapID::Test(TestMap::Test));
+        let map_2: DataMap<usize, String> = open_map_testing_from_db(database.clone(), MapID::Test(TestMap::Test2));
+
+        // Ensure the maps are empty.
+        assert_eq!(map_1.stats()?, MapStats::default());
+        assert!(database.stats()?.is_empty());
+
+        // Insert entries into the maps.
+        for i in 0..10 {
+            map_1.insert(i, i.to_string())?;
+        }
+        map_2.insert(0, "0".to_string())?;
+
+        // Ensure the statistics match the raw entries.
+        let raw_entry_size = |key: usize, value: &str| {
+            (PREFIX_LEN + bincode::serialized_size(&key).unwrap() as usize) as u64
+                + bincode::serialized_size(value).unwrap()
+        };
+        let expected_1 =
+            MapStats { num_entries: 10, num_bytes: (0..10).map(|i| raw_entry_size(i, &i.to_string())).sum() };
+        let expected_2 = MapStats { num_entries: 1, num_bytes: raw_entry_size(0, "0") };
+        assert_eq!(map_1.stats()?, expected_1);
+        assert_eq!(map_2.stats()?, expected_2);
+
+        // Ensure the database statistics match the map statistics.
+        let stats = database.stats()?;
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats.get(&map_1.map_id()), Some(&expected_1));
+        assert_eq!(stats.get(&map_2.map_id()), Some(&expected_2));
+        Ok(())
+    }
+
+    #[test]
+    #[serial]
+    fn test_atomic_finalize_nested_in_another_store() -> Result<()> {
+        // Opens the maps of two stores that share the database at the given path.
+        fn open_maps(path: std::path::PathBuf) -> (DataMap<usize, String>, DataMap<usize, String>) {
+            let database = RocksDB::open_testing(path, None).expect("Failed to open a test database");
+            let block_map = open_map_testing_from_db(database.clone(), MapID::Test(TestMap::Test));
+            let finalize_map = open_map_testing_from_db(database, MapID::Test(TestMap::Test2));
+            (block_map, finalize_map)
+        }
+
+        let path = temp_dir();
+
+        {
+            let (block_map, finalize_map) = open_maps(path.clone());
+
+            // Start an atomic batch in the first store, and queue a write.
+            block_map.start_atomic();
+            block_map.insert(0, "0".to_string())?;
+
+            // Perform an atomic finalize in the second store.
+            atomic_finalize!(finalize_map, FinalizeMode::RealRun, {
+                finalize_map.insert(1, "1".to_string()).map_err(|e| e.to_string())
+            })?;
+
+            // Ensure the finalize writes are queued in the database-wide batch, and not yet written.
+            assert!(finalize_map.iter_confirmed().next().is_none());
+            assert!(!finalize_map.database.atomic_batch.lock().is_empty());
+
+            // Simulate a crash, by dropping the maps before the outermost batch is finished.
+        }
+
+        {
+            let (block_map, finalize_map) = open_maps(path.clone());
+
+            // Ensure nothing was written.
+            assert!(block_map.iter_confirmed().next().is_none());
+            assert!(finalize_map.iter_confirmed().next().is_none());
+
+            // Start an atomic batch in the first store, and queue a write.
+            block_map.start_atomic();
+            block_map.insert(0, "0".to_string())?;
+
+            // Perform an atomic finalize in the second store.
+            atomic_finalize!(finalize_map, FinalizeMode::RealRun, {
+                finalize_map.insert(1, "1".to_string()).map_err(|e| e.to_string())
+            })?;
+
+            // Finish the outermost batch, which writes both stores at once.
+            block_map.finish_atomic()?;
+            assert_eq!(block_map.iter_confirmed().count(), 1);
+            assert_eq!(finalize_map.iter_confirmed().count(), 1);
+        }
+
+        {
+            let (block_map, finalize_map) = open_maps(path);
+
+            // Ensure both writes persist.
+            assert_eq!(block_map.get_confirmed(&0)?, Some(Cow::Owned("0".to_string())));
+            assert_eq!(finalize_map.get_confirmed(&1)?, Some(Cow::Owned("1".to_string())));
+        }
+
+        Ok(())
+    }
 }
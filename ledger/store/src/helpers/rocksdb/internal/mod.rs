@@ -21,16 +21,22 @@ use iterator::*;
 pub mod map;
 pub use map::*;
 
+pub mod stats;
+pub use stats::*;
+
 #[cfg(test)]
 mod tests;
 
+use crate::helpers::{CompactionStyle, Compression, StorageConfig};
+
 use anyhow::{bail, Result};
 use core::{fmt::Debug, hash::Hash};
-use once_cell::sync::OnceCell;
+use once_cell::sync::{Lazy, OnceCell};
 use parking_lot::Mutex;
 use serde::{de::DeserializeOwned, Serialize};
 use std::{
     borrow::Borrow,
+    collections::HashMap,
     marker::PhantomData,
     ops::Deref,
     sync::{
@@ -41,6 +47,13 @@ use std::{
 
 pub const PREFIX_LEN: usize = 4; // N::ID (u16) + DataID (u16)
 
+/// The storage configuration, which is applied when the database is first opened.
+static CONFIG: OnceCell<StorageConfig> = OnceCell::new();
+
+/// The open databases, keyed by network ID and development ID.
+/// Note: Each network is stored in its own directory, so one process can host the ledgers of several networks.
+static DATABASES: Lazy<Mutex<HashMap<(u16, Option<u16>), RocksDB>>> = Lazy::new(Default::default);
+
 pub trait Database {
     /// Opens the database.
     fn open(network_id: u16, dev: Option<u16>) -> Result<Self>
@@ -85,43 +98,10 @@ impl Database for RocksDB {
     ///
     /// In production mode, the database opens directory `~/.aleo/storage/ledger-{network}`.
     /// In development mode, the database opens directory `/path/to/repo/.ledger-{network}-{id}`.
+    ///
+    /// The database is opened once per network ID and development ID, and shared by all of its maps.
     fn open(network_id: u16, dev: Option<u16>) -> Result<Self> {
-        static DB: OnceCell<RocksDB> = OnceCell::new();
-
-        // Retrieve the database.
-        let database = DB
-            .get_or_try_init(|| {
-                // Customize database options.
-                let mut options = rocksdb::Options::default();
-                options.set_compression_type(rocksdb::DBCompressionType::Lz4);
-
-                // Register the prefix length.
-                let prefix_extractor = rocksdb::SliceTransform::create_fixed_prefix(PREFIX_LEN);
-                options.set_prefix_extractor(prefix_extractor);
-
-                let primary = aleo_std::aleo_ledger_dir(network_id, dev);
-                let rocksdb = {
-                    options.increase_parallelism(2);
-                    options.create_if_missing(true);
-
-                    Arc::new(rocksdb::DB::open(&options, primary)?)
-                };
-
-                Ok::<_, anyhow::Error>(RocksDB {
-                    rocksdb,
-                    network_id,
-                    dev,
-                    atomic_batch: Default::default(),
-                    atomic_depth: Default::default(),
-                })
-            })?
-            .clone();
-
-        // Ensure the database network ID and development ID match.
-        match database.network_id == network_id && database.dev == dev {
-            true => Ok(database),
-            false => bail!("Mismatching network ID or development ID in the database"),
-        }
+        Self::open_in(network_id, dev, aleo_std::aleo_ledger_dir(network_id, dev))
     }
 
     /// Opens the map with the given `network_id`, `(optional) development ID`, and `map_id` from storage.
@@ -132,34 +112,112 @@ impl Database for RocksDB {
     ) -> Result<DataMap<K, V>> {
         // Open the RocksDB database.
         let database = Self::open(network_id, dev)?;
+        // Return the DataMap.
+        Ok(database.to_map(map_id))
+    }
+}
 
+impl RocksDB {
+    /// Opens the database for the given network ID and development ID in the given directory,
+    /// or returns the database if it is already open.
+    fn open_in(network_id: u16, dev: Option<u16>, primary: std::path::PathBuf) -> Result<Self> {
+        let mut databases = DATABASES.lock();
+
+        // Retrieve the database, if it is already open.
+        if let Some(database) = databases.get(&(network_id, dev)) {
+            return Ok(database.clone());
+        }
+
+        // Customize database options.
+        let options = Self::options(CONFIG.get_or_init(Default::default));
+
+        let rocksdb = Arc::new(rocksdb::DB::open(&options, primary)?);
+
+        let database =
+            RocksDB { rocksdb, network_id, dev, atomic_batch: Default::default(), atomic_depth: Default::default() };
+        databases.insert((network_id, dev), database.clone());
+        Ok(database)
+    }
+
+    /// Returns the map with the given `map_id` in this database.
+    fn to_map<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned, T: Into<u16>>(
+        &self,
+        map_id: T,
+    ) -> DataMap<K, V> {
         // Combine contexts to create a new scope.
-        let mut context = database.network_id.to_le_bytes().to_vec();
+        let mut context = self.network_id.to_le_bytes().to_vec();
         context.extend_from_slice(&(map_id.into()).to_le_bytes());
 
-        // Return the DataMap.
-        Ok(DataMap {
-            database,
+        DataMap {
+            database: self.clone(),
             context,
             batch_in_progress: Default::default(),
             atomic_batch: Default::default(),
             checkpoints: Default::default(),
-        })
+        }
+    }
+
+    /// Sets the storage configuration, which is applied when the database is first opened.
+    ///
+    /// This must be called before any database is opened, as the configuration is shared by the databases of all networks.
+    /// Setting the same configuration again succeeds, while setting a different configuration fails.
+    pub fn configure(config: StorageConfig) -> Result<()> {
+        match CONFIG.try_insert(config) {
+            Ok(_) => Ok(()),
+            Err((current, config)) if *current == config => Ok(()),
+            Err(_) => bail!("The storage configuration is already set (the database may already be open)"),
+        }
+    }
+
+    /// Returns the database options for the given storage configuration.
+    fn options(config: &StorageConfig) -> rocksdb::Options {
+        // Converts the compression algorithm.
+        let compression = |compression| match compression {
+            Compression::None => rocksdb::DBCompressionType::None,
+            Compression::Lz4 => rocksdb::DBCompressionType::Lz4,
+            Compression::Zstd => rocksdb::DBCompressionType::Zstd,
+        };
+
+        let mut options = rocksdb::Options::default();
+        options.set_compression_type(compression(config.compression));
+        if let Some(bottommost_compression) = config.bottommost_compression {
+            options.set_bottommost_compression_type(compression(bottommost_compression));
+        }
+
+        // Register the prefix length.
+        let prefix_extractor = rocksdb::SliceTransform::create_fixed_prefix(PREFIX_LEN);
+        options.set_prefix_extractor(prefix_extractor);
+
+        // Set the block cache.
+        if let Some(block_cache_size) = config.block_cache_size {
+            let mut block_options = rocksdb::BlockBasedOptions::default();
+            block_options.set_block_cache(&rocksdb::Cache::new_lru_cache(block_cache_size));
+            options.set_block_based_table_factory(&block_options);
+        }
+
+        // Set the compaction style.
+        options.set_compaction_style(match config.compaction_style {
+            CompactionStyle::Level => rocksdb::DBCompactionStyle::Level,
+            CompactionStyle::Universal => rocksdb::DBCompactionStyle::Universal,
+        });
+
+        // Set the write-ahead log options.
+        if let Some(max_total_wal_size) = config.max_total_wal_size {
+            options.set_max_total_wal_size(max_total_wal_size);
+        }
+        options.set_wal_bytes_per_sync(config.wal_bytes_per_sync);
+
+        options.increase_parallelism(config.parallelism);
+        options.create_if_missing(true);
+        options
     }
-}
 
-impl RocksDB {
     /// Opens the test database.
     #[cfg(test)]
     fn open_testing(temp_dir: std::path::PathBuf, dev: Option<u16>) -> Result<Self> {
         let database = {
             // Customize database options.
-            let mut options = rocksdb::Options::default();
-            options.set_compression_type(rocksdb::DBCompressionType::Lz4);
-
-            // Register the prefix length.
-            let prefix_extractor = rocksdb::SliceTransform::create_fixed_prefix(PREFIX_LEN);
-            options.set_prefix_extractor(prefix_extractor);
+            let options = Self::options(&StorageConfig::default());
 
             // Construct the directory for the test database.
             let primary = match dev {
@@ -167,11 +225,7 @@ impl RocksDB {
                 None => temp_dir,
             };
 
-            let rocksdb = {
-                options.increase_parallelism(2);
-                options.create_if_missing(true);
-                Arc::new(rocksdb::DB::open(&options, primary)?)
-            };
+            let rocksdb = Arc::new(rocksdb::DB::open(&options, primary)?);
 
             Ok::<_, anyhow::Error>(RocksDB {
                 rocksdb,
@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Statistics for the maps in the database.
+//!
+//! The entry counts and byte sizes of each map are computed on demand, by scanning the map.
+//! With the `metrics` feature, each map read and direct write records its duration in seconds
+//! to the `snarkvm_storage_read_seconds` and `snarkvm_storage_write_seconds` histograms, labeled
+//! with the map ID, and each atomic batch records its duration to `snarkvm_storage_batch_write_seconds`.
+
+use super::*;
+
+use indexmap::IndexMap;
+
+/// The statistics of a map in the database.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MapStats {
+    /// The number of entries in the map.
+    pub num_entries: u64,
+    /// The total size of the keys and values in the map, in bytes.
+    pub num_bytes: u64,
+}
+
+impl MapStats {
+    /// Adds the given raw entry to the statistics.
+    fn add(&mut self, key: &[u8], value: &[u8]) {
+        self.num_entries += 1;
+        self.num_bytes += (key.len() + value.len()) as u64;
+    }
+}
+
+impl RocksDB {
+    /// Returns the statistics of each map in the database for this network, keyed by map ID.
+    /// Note: This scans every entry in the database.
+    pub fn stats(&self) -> Result<IndexMap<u16, MapStats>> {
+        // Ignore the prefix extractor, in order to scan across maps.
+        let mut options = rocksdb::ReadOptions::default();
+        options.set_total_order_seek(true);
+
+        let network_id = self.network_id.to_le_bytes();
+        let mut stats = IndexMap::<u16, MapStats>::new();
+        for entry in self.rocksdb.iterator_opt(rocksdb::IteratorMode::Start, options) {
+            let (key, value) = entry?;
+            // Skip the entries of other networks.
+            if key.len() < PREFIX_LEN || key[..2] != network_id {
+                continue;
+            }
+            let map_id = u16::from_le_bytes([key[2], key[3]]);
+            stats.entry(map_id).or_default().add(&key, &value);
+        }
+        Ok(stats)
+    }
+}
+
+impl<K: Serialize + DeserializeOwned, V: Serialize + DeserializeOwned> DataMap<K, V> {
+    /// Returns the ID of the map.
+    pub fn map_id(&self) -> u16 {
+        u16::from_le_bytes([self.context[2], self.context[3]])
+    }
+
+    /// Returns the statistics of the map.
+    /// Note: This scans every entry in the map.
+    pub fn stats(&self) -> Result<MapStats> {
+        let mut stats = MapStats::default();
+        for entry in self.database.prefix_iterator(&self.context) {
+            let (key, value) = entry?;
+            stats.add(&key, &value);
+        }
+        Ok(stats)
+    }
+
+    /// Records the statistics of the map to the `snarkvm_storage_entries` and `snarkvm_storage_bytes` gauges,
+    /// labeled with the map ID, and returns them.
+    #[cfg(feature = "metrics")]
+    pub fn record_stats(&self) -> Result<MapStats> {
+        let stats = self.stats()?;
+        metrics::gauge!("snarkvm_storage_entries", stats.num_entries as f64, "map" => self.map_id().to_string());
+        metrics::gauge!("snarkvm_storage_bytes", stats.num_bytes as f64, "map" => self.map_id().to_string());
+        Ok(stats)
+    }
+
+    /// Starts a timer for an operation on the map, which records its duration to the given histogram when dropped.
+    #[cfg(feature = "metrics")]
+    pub(super) fn timer(&self, histogram: &'static str) -> MapTimer {
+        MapTimer { histogram, map_id: self.map_id(), start: std::time::Instant::now() }
+    }
+}
+
+/// A timer for an operation on a map.
+#[cfg(feature = "metrics")]
+#[must_use = "the operation is only timed until this is dropped"]
+pub(super) struct MapTimer {
+    /// The name of the duration histogram.
+    histogram: &'static str,
+    /// The ID of the map.
+    map_id: u16,
+    /// The start time of the operation.
+    start: std::time::Instant,
+}
+
+#[cfg(feature = "metrics")]
+impl Drop for MapTimer {
+    fn drop(&mut self) {
+        metrics::histogram!(self.histogram, self.start.elapsed().as_secs_f64(), "map" => self.map_id.to_string());
+    }
+}
@@ -14,8 +14,11 @@
 
 use crate::helpers::{
     rocksdb::{DataMap, MapID, RocksDB, TestMap as TestMapID},
+    CompactionStyle,
+    Compression,
     Map,
     MapRead,
+    StorageConfig,
 };
 use console::{
     network::{Network, Testnet3},
@@ -24,7 +27,7 @@ use console::{
 };
 
 use serial_test::serial;
-use std::borrow::Cow;
+use std::{borrow::Cow, sync::Arc};
 
 type TestMap = DataMap<u32, String>;
 
@@ -42,6 +45,63 @@ fn test_open() {
     let _storage = RocksDB::open_testing(temp_dir(), None).expect("Failed to open storage");
 }
 
+#[test]
+#[serial]
+fn test_configure() {
+    // Initialize a storage configuration for an archive node.
+    let config = StorageConfig {
+        block_cache_size: Some(64 * 1024 * 1024),
+        compression: Compression::Lz4,
+        bottommost_compression: Some(Compression::Zstd),
+        compaction_style: CompactionStyle::Universal,
+        parallelism: 4,
+        max_total_wal_size: Some(256 * 1024 * 1024),
+        wal_bytes_per_sync: 1024 * 1024,
+    };
+    // Ensure the database opens with the storage configuration.
+    let _storage = rocksdb::DB::open(&RocksDB::options(&config), temp_dir()).expect("Failed to open storage");
+
+    // Ensure the storage configuration can be set again, but can not be changed.
+    RocksDB::configure(config.clone()).expect("Failed to set the storage configuration");
+    RocksDB::configure(config).expect("Failed to set the same storage configuration");
+    assert!(RocksDB::configure(StorageConfig::default()).is_err());
+}
+
+#[test]
+#[serial]
+fn test_open_networks_side_by_side() {
+    let directory = temp_dir();
+    // Use a development ID that is not used by any other test, as the open databases are shared by the process.
+    let dev = Some(u16::MAX - 1);
+
+    // Open the databases of two networks in one process.
+    let first = RocksDB::open_in(0, dev, directory.join("0")).expect("Failed to open the first network");
+    let second = RocksDB::open_in(1, dev, directory.join("1")).expect("Failed to open the second network");
+    // Ensure each network has its own database, which is shared when it is opened again.
+    assert!(!Arc::ptr_eq(&*first, &*second));
+    let first_again = RocksDB::open_in(0, dev, directory.join("0")).expect("Failed to reopen the first network");
+    assert!(Arc::ptr_eq(&*first, &*first_again));
+
+    // Insert the same key into the same map of each network.
+    let first_map: TestMap = first.to_map(MapID::Test(TestMapID::Test));
+    let second_map: TestMap = second.to_map(MapID::Test(TestMapID::Test));
+    first_map.insert(1, "first".to_string()).expect("Failed to insert");
+    second_map.insert(1, "second".to_string()).expect("Failed to insert");
+
+    // Ensure each network reads its own value.
+    let first_map: TestMap = first_again.to_map(MapID::Test(TestMapID::Test));
+    assert_eq!(
+        first_map.get_confirmed(&1).expect("Failed to get").map(|value| value.into_owned()),
+        Some("first".into())
+    );
+    assert_eq!(
+        second_map.get_confirmed(&1).expect("Failed to get").map(|value| value.into_owned()),
+        Some("second".into())
+    );
+    assert_eq!(first_map.iter_confirmed().count(), 1);
+    assert_eq!(second_map.iter_confirmed().count(), 1);
+}
+
 #[test]
 #[serial]
 fn test_open_map() {
@@ -60,6 +120,19 @@ fn test_insert_and_contains_key() {
     assert!(!map.contains_key_confirmed(&000000000).expect("Failed to call contains key"));
 }
 
+#[test]
+#[serial]
+fn test_insert_and_contains_keys() {
+    let map =
+        RocksDB::open_map_testing(temp_dir(), None, MapID::Test(TestMapID::Test)).expect("Failed to open data map");
+
+    map.insert(1, "1".to_string()).expect("Failed to insert");
+    map.insert(3, "3".to_string()).expect("Failed to insert");
+    let contains = map.contains_keys_confirmed(&[0, 1, 2, 3]).expect("Failed to call contains keys");
+    assert_eq!(contains, vec![false, true, false, true]);
+    assert!(map.contains_keys_confirmed(&[]).expect("Failed to call contains keys").is_empty());
+}
+
 #[test]
 #[serial]
 fn test_insert_and_get() {
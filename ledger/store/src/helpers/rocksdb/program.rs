@@ -17,6 +17,7 @@ use crate::{
     CommitteeStorage,
     CommitteeStore,
     FinalizeStorage,
+    FinalizeUndo,
 };
 use console::{
     prelude::*,
@@ -42,6 +43,8 @@ pub struct FinalizeDB<N: Network> {
     key_map: DataMap<Field<N>, Plaintext<N>>,
     /// The value map.
     value_map: DataMap<Field<N>, Value<N>>,
+    /// The undo map.
+    undo_map: DataMap<u32, Vec<FinalizeUndo<N>>>,
     /// The optional development ID.
     dev: Option<u16>,
 }
@@ -54,6 +57,7 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
     type KeyValueIDMap = DataMap<Field<N>, IndexMap<Field<N>, Field<N>>>;
     type KeyMap = DataMap<Field<N>, Plaintext<N>>;
     type ValueMap = DataMap<Field<N>, Value<N>>;
+    type UndoMap = DataMap<u32, Vec<FinalizeUndo<N>>>;
 
     /// Initializes the finalize storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -67,6 +71,7 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
             key_value_id_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::KeyValueID))?,
             key_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::Key))?,
             value_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::Value))?,
+            undo_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::Undo))?,
             dev,
         })
     }
@@ -101,6 +106,11 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
         &self.value_map
     }
 
+    /// Returns the undo map.
+    fn undo_map(&self) -> &Self::UndoMap {
+        &self.undo_map
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
         self.dev
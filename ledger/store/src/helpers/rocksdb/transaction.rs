@@ -38,6 +38,7 @@ use console::{
     prelude::*,
     program::{Identifier, ProgramID, ProgramOwner},
 };
+use ledger_block::RejectionReason;
 use synthesizer_program::Program;
 use synthesizer_snark::{Certificate, Proof, VerifyingKey};
 
@@ -46,6 +47,8 @@ use synthesizer_snark::{Certificate, Proof, VerifyingKey};
 pub struct TransactionDB<N: Network> {
     /// The mapping of `transaction ID` to `transaction type`.
     id_map: DataMap<N::TransactionID, TransactionType>,
+    /// The mapping of `unconfirmed transaction ID` to `rejection reason`.
+    rejection_reason_map: DataMap<N::TransactionID, RejectionReason<N>>,
     /// The deployment store.
     deployment_store: DeploymentStore<N, DeploymentDB<N>>,
     /// The execution store.
@@ -57,6 +60,7 @@ pub struct TransactionDB<N: Network> {
 #[rustfmt::skip]
 impl<N: Network> TransactionStorage<N> for TransactionDB<N> {
     type IDMap = DataMap<N::TransactionID, TransactionType>;
+    type RejectionReasonMap = DataMap<N::TransactionID, RejectionReason<N>>;
     type DeploymentStorage = DeploymentDB<N>;
     type ExecutionStorage = ExecutionDB<N>;
     type FeeStorage = FeeDB<N>;
@@ -71,7 +75,13 @@ impl<N: Network> TransactionStorage<N> for TransactionDB<N> {
         // Initialize the execution store.
         let execution_store = ExecutionStore::<N, ExecutionDB<N>>::open(fee_store.clone())?;
         // Return the transaction storage.
-        Ok(Self { id_map: rocksdb::RocksDB::open_map(N::ID, execution_store.dev(), MapID::Transaction(TransactionMap::ID))?, deployment_store, execution_store, fee_store })
+        Ok(Self {
+            id_map: rocksdb::RocksDB::open_map(N::ID, execution_store.dev(), MapID::Transaction(TransactionMap::ID))?,
+            rejection_reason_map: rocksdb::RocksDB::open_map(N::ID, execution_store.dev(), MapID::Transaction(TransactionMap::RejectionReason))?,
+            deployment_store,
+            execution_store,
+            fee_store,
+        })
     }
 
     /// Returns the ID map.
@@ -79,6 +89,11 @@ impl<N: Network> TransactionStorage<N> for TransactionDB<N> {
         &self.id_map
     }
 
+    /// Returns the rejection reason map.
+    fn rejection_reason_map(&self) -> &Self::RejectionReasonMap {
+        &self.rejection_reason_map
+    }
+
     /// Returns the deployment store.
     fn deployment_store(&self) -> &DeploymentStore<N, Self::DeploymentStorage> {
         &self.deployment_store
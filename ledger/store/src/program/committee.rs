@@ -227,6 +227,60 @@ pub trait CommitteeStorage<N: Network>: 'static + Clone + Send + Sync {
         })
     }
 
+    /// Removes all committees above the given `height`, in the process
+    /// removing all round to height entries above the given `height`,
+    /// and restoring the current round to the latest round at or below the given `height`.
+    fn remove_after(&self, height: u32) -> Result<()> {
+        // Retrieve the current round.
+        let current_round = self.current_round()?;
+        // Retrieve the current height.
+        let current_height = self.current_height()?;
+        // Ensure the given height is less than the current height.
+        ensure!(height < current_height, "Cannot remove committees after height {height} (current: {current_height})");
+
+        // Determine the rounds to remove, and the next current round.
+        let mut rounds = Vec::new();
+        let mut next_current_round = current_round;
+        loop {
+            match self.get_height_for_round(next_current_round)? {
+                // If the round is at or below the given height, then we have found the next current round.
+                Some(round_height) if round_height <= height => break,
+                // Otherwise, if the round is above the given height, mark it for removal.
+                Some(_) => rounds.push(next_current_round),
+                // Otherwise, the round is a hole, and there is nothing to remove.
+                None => (),
+            }
+            // Ensure there is a round at or below the given height.
+            ensure!(next_current_round > 0, "Committee not found at or below height {height} in committee storage");
+            // Decrement the next current round.
+            next_current_round = next_current_round.saturating_sub(1);
+        }
+
+        // Determine the committee heights to remove.
+        let heights = self
+            .committee_map()
+            .keys_confirmed()
+            .map(|committee_height| cow_to_copied!(committee_height))
+            .filter(|committee_height| *committee_height > height)
+            .collect::<Vec<_>>();
+
+        // Start an atomic batch.
+        atomic_batch_scope!(self, {
+            // Update the current round.
+            self.current_round_map().insert(ROUND_KEY, next_current_round)?;
+            // Remove the round to height mappings.
+            for round in rounds.iter() {
+                self.round_to_height_map().remove(round)?;
+            }
+            // Remove the committees.
+            for committee_height in heights.iter() {
+                self.committee_map().remove(committee_height)?;
+            }
+
+            Ok(())
+        })
+    }
+
     /// Returns the current round.
     fn current_round(&self) -> Result<u64> {
         match self.current_round_map().get_confirmed(&ROUND_KEY)? {
@@ -357,6 +411,13 @@ impl<N: Network, C: CommitteeStorage<N>> CommitteeStore<N, C> {
     pub fn remove(&self, height: u32) -> Result<()> {
         self.storage.remove(height)
     }
+
+    /// Removes all committees above the given `height`, in the process
+    /// removing all round to height entries above the given `height`,
+    /// and restoring the current round to the latest round at or below the given `height`.
+    pub fn remove_after(&self, height: u32) -> Result<()> {
+        self.storage.remove_after(height)
+    }
 }
 
 impl<N: Network, C: CommitteeStorage<N>> CommitteeStore<N, C> {
@@ -597,4 +658,54 @@ mod tests {
         assert_eq!(store.get_committee_for_round(4).unwrap(), None);
         assert_eq!(store.get_committee_for_round(5).unwrap(), None);
     }
+
+    #[test]
+    fn test_remove_after() {
+        let rng = &mut TestRng::default();
+
+        // Sample the committees.
+        let committee_0 = ledger_committee::test_helpers::sample_committee_for_round(0, rng);
+        let committee_1 = ledger_committee::test_helpers::sample_committee_for_round(5, rng);
+        let committee_2 = ledger_committee::test_helpers::sample_committee_for_round(8, rng);
+
+        // Initialize a new committee store.
+        let store = CommitteeStore::<CurrentNetwork, CommitteeMemory<_>>::open(None).unwrap();
+
+        // Insert the committees.
+        store.insert(0, committee_0.clone()).unwrap();
+        store.insert(1, committee_1.clone()).unwrap();
+        store.insert(2, committee_2.clone()).unwrap();
+        assert_eq!(store.current_round().unwrap(), 8);
+        assert_eq!(store.current_height().unwrap(), 2);
+        assert_eq!(store.current_committee().unwrap(), committee_2);
+
+        // Ensure the committees cannot be removed after the current height.
+        assert!(store.remove_after(2).is_err());
+        assert!(store.remove_after(3).is_err());
+
+        // Remove the committees after height 0.
+        store.remove_after(0).unwrap();
+        assert_eq!(store.current_round().unwrap(), 4);
+        assert_eq!(store.current_height().unwrap(), 0);
+        assert_eq!(store.current_committee().unwrap(), committee_0);
+
+        for round in 0..5 {
+            assert_eq!(store.get_height_for_round(round).unwrap().unwrap(), 0);
+            assert_eq!(store.get_committee_for_round(round).unwrap().unwrap(), committee_0);
+        }
+        for round in 5..10 {
+            assert_eq!(store.get_height_for_round(round).unwrap(), None);
+            assert_eq!(store.get_committee_for_round(round).unwrap(), None);
+        }
+
+        assert_eq!(store.get_committee(0).unwrap().unwrap(), committee_0);
+        assert_eq!(store.get_committee(1).unwrap(), None);
+        assert_eq!(store.get_committee(2).unwrap(), None);
+
+        // Ensure the committees can be inserted again.
+        store.insert(1, committee_1.clone()).unwrap();
+        assert_eq!(store.current_round().unwrap(), 5);
+        assert_eq!(store.current_height().unwrap(), 1);
+        assert_eq!(store.current_committee().unwrap(), committee_1);
+    }
 }
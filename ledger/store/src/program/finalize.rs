@@ -17,7 +17,7 @@ use crate::{
     cow_to_cloned,
     cow_to_copied,
     helpers::{Map, MapRead},
-    program::{CommitteeStorage, CommitteeStore},
+    program::{CommitteeStorage, CommitteeStore, FinalizeUndo},
 };
 use console::{
     network::prelude::*,
@@ -29,6 +29,8 @@ use synthesizer_program::{FinalizeOperation, FinalizeStoreTrait};
 use anyhow::Result;
 use core::marker::PhantomData;
 use indexmap::{IndexMap, IndexSet};
+use parking_lot::Mutex;
+use std::{borrow::Cow, sync::Arc};
 
 /// A trait for program state storage. Note: For the program logic, see `DeploymentStorage`.
 ///
@@ -54,6 +56,8 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     type KeyMap: for<'a> Map<'a, Field<N>, Plaintext<N>>;
     /// The mapping of `key ID` to `value`.
     type ValueMap: for<'a> Map<'a, Field<N>, Value<N>>;
+    /// The mapping of `block height` to `[undo entry]`.
+    type UndoMap: for<'a> Map<'a, u32, Vec<FinalizeUndo<N>>>;
 
     /// Initializes the program state storage.
     fn open(dev: Option<u16>) -> Result<Self>;
@@ -70,6 +74,8 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     fn key_map(&self) -> &Self::KeyMap;
     /// Returns the value map.
     fn value_map(&self) -> &Self::ValueMap;
+    /// Returns the undo map.
+    fn undo_map(&self) -> &Self::UndoMap;
 
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16>;
@@ -82,6 +88,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().start_atomic();
         self.key_map().start_atomic();
         self.value_map().start_atomic();
+        self.undo_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -92,6 +99,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.key_value_id_map().is_atomic_in_progress()
             || self.key_map().is_atomic_in_progress()
             || self.value_map().is_atomic_in_progress()
+            || self.undo_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -102,6 +110,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().atomic_checkpoint();
         self.key_map().atomic_checkpoint();
         self.value_map().atomic_checkpoint();
+        self.undo_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -112,6 +121,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().clear_latest_checkpoint();
         self.key_map().clear_latest_checkpoint();
         self.value_map().clear_latest_checkpoint();
+        self.undo_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -122,6 +132,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().atomic_rewind();
         self.key_map().atomic_rewind();
         self.value_map().atomic_rewind();
+        self.undo_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -132,6 +143,7 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.key_value_id_map().abort_atomic();
         self.key_map().abort_atomic();
         self.value_map().abort_atomic();
+        self.undo_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
@@ -141,7 +153,8 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.mapping_id_map().finish_atomic()?;
         self.key_value_id_map().finish_atomic()?;
         self.key_map().finish_atomic()?;
-        self.value_map().finish_atomic()
+        self.value_map().finish_atomic()?;
+        self.undo_map().finish_atomic()
     }
 
     /// Initializes the given `program ID` and `mapping name` in storage.
@@ -751,6 +764,10 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
 pub struct FinalizeStore<N: Network, P: FinalizeStorage<N>> {
     /// The finalize storage.
     storage: P,
+    /// The undo log of the finalize operations, if it is being recorded.
+    undo_log: Arc<Mutex<Option<Vec<FinalizeUndo<N>>>>>,
+    /// The lengths of the undo log at each atomic checkpoint.
+    undo_checkpoints: Arc<Mutex<Vec<usize>>>,
     /// PhantomData.
     _phantom: PhantomData<N>,
 }
@@ -764,7 +781,7 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     /// Initializes a finalize store from storage.
     pub fn from(storage: P) -> Result<Self> {
         // Return the finalize store.
-        Ok(Self { storage, _phantom: PhantomData })
+        Ok(Self { storage, undo_log: Default::default(), undo_checkpoints: Default::default(), _phantom: PhantomData })
     }
 
     /// Starts an atomic batch write operation.
@@ -780,21 +797,37 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     /// Checkpoints the atomic batch.
     pub fn atomic_checkpoint(&self) {
         self.storage.atomic_checkpoint();
+        // Checkpoint the undo log, if it is being recorded.
+        if let Some(undo_log) = self.undo_log.lock().as_ref() {
+            self.undo_checkpoints.lock().push(undo_log.len());
+        }
     }
 
     /// Clears the latest atomic batch checkpoint.
     pub fn clear_latest_checkpoint(&self) {
         self.storage.clear_latest_checkpoint();
+        // Clear the latest checkpoint of the undo log.
+        self.undo_checkpoints.lock().pop();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
     pub fn atomic_rewind(&self) {
         self.storage.atomic_rewind();
+        // Rewind the undo log to the previous checkpoint, if it is being recorded.
+        let checkpoint = self.undo_checkpoints.lock().pop().unwrap_or(0);
+        if let Some(undo_log) = self.undo_log.lock().as_mut() {
+            undo_log.truncate(checkpoint);
+        }
     }
 
     /// Aborts an atomic batch write operation.
     pub fn abort_atomic(&self) {
         self.storage.abort_atomic();
+        // Clear the undo log, if it is being recorded.
+        if let Some(undo_log) = self.undo_log.lock().as_mut() {
+            undo_log.clear();
+        }
+        self.undo_checkpoints.lock().clear();
     }
 
     /// Finishes an atomic batch write operation.
@@ -851,6 +884,8 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         key: Plaintext<N>,
         value: Value<N>,
     ) -> Result<FinalizeOperation<N>> {
+        // Note: The key must not exist, so the key is removed on undo.
+        self.record_undo(|| Ok(vec![FinalizeUndo::RestoreKeyValue(*program_id, *mapping_name, key.clone(), None)]))?;
         self.storage.insert_key_value(program_id, mapping_name, key, value)
     }
 
@@ -865,6 +900,10 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         key: Plaintext<N>,
         value: Value<N>,
     ) -> Result<FinalizeOperation<N>> {
+        self.record_undo(|| {
+            let value = self.storage.get_value_speculative(program_id, mapping_name, &key)?;
+            Ok(vec![FinalizeUndo::RestoreKeyValue(*program_id, *mapping_name, key.clone(), value)])
+        })?;
         self.storage.update_key_value(program_id, mapping_name, key, value)
     }
 
@@ -875,6 +914,10 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         mapping_name: &Identifier<N>,
         key: &Plaintext<N>,
     ) -> Result<Option<FinalizeOperation<N>>> {
+        self.record_undo(|| {
+            let value = self.storage.get_value_speculative(program_id, mapping_name, key)?;
+            Ok(vec![FinalizeUndo::RestoreKeyValue(*program_id, *mapping_name, key.clone(), value)])
+        })?;
         self.storage.remove_key_value(program_id, mapping_name, key)
     }
 }
@@ -887,6 +930,8 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         program_id: &ProgramID<N>,
         mapping_name: &Identifier<N>,
     ) -> Result<FinalizeOperation<N>> {
+        // Note: The mapping must not exist, so the mapping is removed on undo.
+        self.record_undo(|| Ok(vec![FinalizeUndo::RestoreMapping(*program_id, *mapping_name, None)]))?;
         self.storage.initialize_mapping(program_id, mapping_name)
     }
 
@@ -898,6 +943,10 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         mapping_name: &Identifier<N>,
         entries: Vec<(Plaintext<N>, Value<N>)>,
     ) -> Result<FinalizeOperation<N>> {
+        self.record_undo(|| {
+            let entries = self.storage.get_mapping_speculative(program_id, mapping_name)?;
+            Ok(vec![FinalizeUndo::RestoreMapping(*program_id, *mapping_name, Some(entries))])
+        })?;
         self.storage.replace_mapping(program_id, mapping_name, entries)
     }
 
@@ -908,16 +957,118 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         program_id: &ProgramID<N>,
         mapping_name: &Identifier<N>,
     ) -> Result<FinalizeOperation<N>> {
+        self.record_undo(|| {
+            let entries = self.storage.get_mapping_speculative(program_id, mapping_name)?;
+            Ok(vec![FinalizeUndo::RestoreMapping(*program_id, *mapping_name, Some(entries))])
+        })?;
         self.storage.remove_mapping(program_id, mapping_name)
     }
 
     /// Removes the program for the given `program ID` from storage,
     /// along with all associated mappings and key-value pairs in storage.
     pub fn remove_program(&self, program_id: &ProgramID<N>) -> Result<()> {
+        self.record_undo(|| {
+            let mapping_names = self.storage.get_mapping_names_speculative(program_id)?.unwrap_or_default();
+            mapping_names
+                .into_iter()
+                .map(|mapping_name| {
+                    let entries = self.storage.get_mapping_speculative(program_id, &mapping_name)?;
+                    Ok(FinalizeUndo::RestoreMapping(*program_id, mapping_name, Some(entries)))
+                })
+                .collect()
+        })?;
         self.storage.remove_program(program_id)
     }
 }
 
+impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
+    /// Starts recording the undo log of the finalize operations, to be able to revert a block.
+    pub fn start_undo_log(&self) {
+        *self.undo_log.lock() = Some(Vec::new());
+        self.undo_checkpoints.lock().clear();
+    }
+
+    /// Stops recording the undo log, and discards it.
+    pub fn abort_undo_log(&self) {
+        *self.undo_log.lock() = None;
+        self.undo_checkpoints.lock().clear();
+    }
+
+    /// Stops recording the undo log, and stores it for the given block height.
+    pub fn finish_undo_log(&self, height: u32) -> Result<()> {
+        // Retrieve the undo log.
+        let undo_log = self.undo_log.lock().take();
+        self.undo_checkpoints.lock().clear();
+        match undo_log {
+            Some(undo_log) => self.storage.undo_map().insert(height, undo_log),
+            None => bail!("Cannot store the undo log of block {height}, as it is not being recorded"),
+        }
+    }
+
+    /// Returns `true` if the undo log for the given block height exists.
+    pub fn contains_undo_log(&self, height: u32) -> Result<bool> {
+        self.storage.undo_map().contains_key_speculative(&height)
+    }
+
+    /// Returns the undo log for the given block height, if it exists.
+    pub fn get_undo_log(&self, height: u32) -> Result<Option<Vec<FinalizeUndo<N>>>> {
+        match self.storage.undo_map().get_confirmed(&height)? {
+            Some(undo_log) => Ok(Some(cow_to_cloned!(undo_log))),
+            None => Ok(None),
+        }
+    }
+
+    /// Reverts the finalize operations of the given block height, by applying its undo log in reverse order.
+    /// Note: The blocks above the given height must be reverted first.
+    pub fn revert_block(&self, height: u32) -> Result<()> {
+        // Retrieve the undo log.
+        let Some(undo_log) = self.storage.undo_map().get_speculative(&height)? else {
+            bail!("Cannot revert block {height}, as its undo log is not found in storage")
+        };
+
+        atomic_batch_scope!(self, {
+            for undo in undo_log.iter().rev() {
+                match undo {
+                    FinalizeUndo::RestoreKeyValue(program_id, mapping_name, key, None) => {
+                        self.storage.remove_key_value(program_id, mapping_name, key)?;
+                    }
+                    FinalizeUndo::RestoreKeyValue(program_id, mapping_name, key, Some(value)) => {
+                        self.storage.update_key_value(program_id, mapping_name, key.clone(), value.clone())?;
+                    }
+                    FinalizeUndo::RestoreMapping(program_id, mapping_name, None) => {
+                        self.storage.remove_mapping(program_id, mapping_name)?;
+                        // Remove the program, if this was its last mapping.
+                        if let Some(mapping_names) = self.storage.get_mapping_names_speculative(program_id)? {
+                            if mapping_names.is_empty() {
+                                self.storage.remove_program(program_id)?;
+                            }
+                        }
+                    }
+                    FinalizeUndo::RestoreMapping(program_id, mapping_name, Some(entries)) => {
+                        // Initialize the mapping, if it was removed.
+                        if self.storage.get_mapping_id_speculative(program_id, mapping_name)?.is_none() {
+                            self.storage.initialize_mapping(program_id, mapping_name)?;
+                        }
+                        self.storage.replace_mapping(program_id, mapping_name, entries.clone())?;
+                    }
+                }
+            }
+            // Remove the undo log.
+            self.storage.undo_map().remove(&height)?;
+
+            Ok(())
+        })
+    }
+
+    /// Appends the undo entries computed by the given closure to the undo log, if it is being recorded.
+    fn record_undo(&self, undo: impl FnOnce() -> Result<Vec<FinalizeUndo<N>>>) -> Result<()> {
+        if let Some(undo_log) = self.undo_log.lock().as_mut() {
+            undo_log.extend(undo()?);
+        }
+        Ok(())
+    }
+}
+
 impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     /// Returns `true` if the given `program ID` exist.
     pub fn contains_program_confirmed(&self, program_id: &ProgramID<N>) -> Result<bool> {
@@ -980,6 +1131,13 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     }
 }
 
+impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
+    /// Returns an iterator over the program IDs, for all programs in `self`.
+    pub fn program_ids(&self) -> impl '_ + Iterator<Item = Cow<'_, ProgramID<N>>> {
+        self.storage.program_id_map().keys_confirmed()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1481,4 +1639,54 @@ mod tests {
         check_initialize_insert_remove(&finalize_store, program_id, mapping_name);
         check_initialize_update_remove(&finalize_store, program_id, mapping_name);
     }
+
+    #[test]
+    fn test_revert_block() {
+        // Initialize a program ID and mapping names.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let other_mapping_name = Identifier::from_str("other").unwrap();
+        // Prepare the keys and values.
+        let key = |item: u64| Plaintext::<CurrentNetwork>::from_str(&format!("{item}field")).unwrap();
+        let value = |item: u64| Value::<CurrentNetwork>::from_str(&format!("{item}u64")).unwrap();
+
+        // Initialize a new finalize store.
+        let finalize_store = FinalizeStore::from(FinalizeMemory::open(None).unwrap()).unwrap();
+        finalize_store.initialize_mapping(&program_id, &mapping_name).unwrap();
+        finalize_store.insert_key_value(&program_id, &mapping_name, key(0), value(0)).unwrap();
+        finalize_store.insert_key_value(&program_id, &mapping_name, key(1), value(1)).unwrap();
+        let expected = finalize_store.get_mapping_confirmed(&program_id, &mapping_name).unwrap();
+
+        // Ensure a block without an undo log cannot be reverted.
+        assert!(finalize_store.revert_block(1).is_err());
+
+        // Record the undo log of the finalize operations of block 1, where the same key is written more than once.
+        finalize_store.start_undo_log();
+        finalize_store.update_key_value(&program_id, &mapping_name, key(0), value(10)).unwrap();
+        finalize_store.update_key_value(&program_id, &mapping_name, key(0), value(20)).unwrap();
+        finalize_store.remove_key_value(&program_id, &mapping_name, &key(1)).unwrap();
+        finalize_store.insert_key_value(&program_id, &mapping_name, key(2), value(2)).unwrap();
+        finalize_store.initialize_mapping(&program_id, &other_mapping_name).unwrap();
+        finalize_store.insert_key_value(&program_id, &other_mapping_name, key(3), value(3)).unwrap();
+        // Ensure the operations of a rewound checkpoint are not recorded.
+        finalize_store.start_atomic();
+        finalize_store.atomic_checkpoint();
+        finalize_store.update_key_value(&program_id, &mapping_name, key(4), value(4)).unwrap();
+        finalize_store.atomic_rewind();
+        finalize_store.finish_atomic().unwrap();
+        finalize_store.finish_undo_log(1).unwrap();
+        assert!(finalize_store.contains_undo_log(1).unwrap());
+
+        // Ensure the undo log is no longer recorded.
+        finalize_store.update_key_value(&program_id, &mapping_name, key(5), value(5)).unwrap();
+        assert!(finalize_store.finish_undo_log(2).is_err());
+        finalize_store.remove_key_value(&program_id, &mapping_name, &key(5)).unwrap();
+
+        // Revert block 1.
+        finalize_store.revert_block(1).unwrap();
+        assert!(!finalize_store.contains_undo_log(1).unwrap());
+        assert_eq!(finalize_store.get_mapping_confirmed(&program_id, &mapping_name).unwrap(), expected);
+        assert!(!finalize_store.contains_mapping_confirmed(&program_id, &other_mapping_name).unwrap());
+        assert!(finalize_store.contains_program_confirmed(&program_id).unwrap());
+    }
 }
@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID, Value},
+};
+
+/// An entry in the undo log of a block, which restores the finalize state written by the block.
+///
+/// The entries of a block are applied in reverse order, so that a key or mapping
+/// written more than once in the block is restored to its state before the block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FinalizeUndo<N: Network> {
+    /// Restores the value of the key in the mapping, or removes the key if there was no value.
+    RestoreKeyValue(ProgramID<N>, Identifier<N>, Plaintext<N>, Option<Value<N>>),
+    /// Restores the entries of the mapping, or removes the mapping if it was not initialized.
+    RestoreMapping(ProgramID<N>, Identifier<N>, Option<Vec<(Plaintext<N>, Value<N>)>>),
+}
+
+impl<N: Network> FromBytes for FinalizeUndo<N> {
+    /// Reads the undo entry from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the variant.
+        let variant = u8::read_le(&mut reader)?;
+        // Read the program ID and the mapping name.
+        let program_id = ProgramID::read_le(&mut reader)?;
+        let mapping_name = Identifier::read_le(&mut reader)?;
+        match variant {
+            0 => {
+                // Read the key.
+                let key = Plaintext::read_le(&mut reader)?;
+                // Read the value.
+                let value = match u8::read_le(&mut reader)? {
+                    0 => None,
+                    1 => Some(Value::read_le(&mut reader)?),
+                    variant => return Err(error(format!("Invalid value variant {variant} in an undo entry"))),
+                };
+                Ok(Self::RestoreKeyValue(program_id, mapping_name, key, value))
+            }
+            1 => {
+                // Read the entries.
+                let entries = match u8::read_le(&mut reader)? {
+                    0 => None,
+                    1 => {
+                        let num_entries = u32::read_le(&mut reader)?;
+                        let mut entries = Vec::new();
+                        for _ in 0..num_entries {
+                            entries.push((Plaintext::read_le(&mut reader)?, Value::read_le(&mut reader)?));
+                        }
+                        Some(entries)
+                    }
+                    variant => return Err(error(format!("Invalid entries variant {variant} in an undo entry"))),
+                };
+                Ok(Self::RestoreMapping(program_id, mapping_name, entries))
+            }
+            variant => Err(error(format!("Invalid undo entry variant {variant}"))),
+        }
+    }
+}
+
+impl<N: Network> ToBytes for FinalizeUndo<N> {
+    /// Writes the undo entry to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::RestoreKeyValue(program_id, mapping_name, key, value) => {
+                0u8.write_le(&mut writer)?;
+                program_id.write_le(&mut writer)?;
+                mapping_name.write_le(&mut writer)?;
+                key.write_le(&mut writer)?;
+                match value {
+                    None => 0u8.write_le(&mut writer),
+                    Some(value) => {
+                        1u8.write_le(&mut writer)?;
+                        value.write_le(&mut writer)
+                    }
+                }
+            }
+            Self::RestoreMapping(program_id, mapping_name, entries) => {
+                1u8.write_le(&mut writer)?;
+                program_id.write_le(&mut writer)?;
+                mapping_name.write_le(&mut writer)?;
+                match entries {
+                    None => 0u8.write_le(&mut writer),
+                    Some(entries) => {
+                        1u8.write_le(&mut writer)?;
+                        u32::try_from(entries.len()).map_err(|e| error(e.to_string()))?.write_le(&mut writer)?;
+                        for (key, value) in entries {
+                            key.write_le(&mut writer)?;
+                            value.write_le(&mut writer)?;
+                        }
+                        Ok(())
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<N: Network> Serialize for FinalizeUndo<N> {
+    /// Serializes the undo entry into bytes.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ToBytesSerializer::serialize_with_size_encoding(self, serializer)
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for FinalizeUndo<N> {
+    /// Deserializes the undo entry from bytes.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "finalize undo entry")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_bytes() {
+        let program_id = ProgramID::<CurrentNetwork>::from_str("credits.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("0u8").unwrap();
+        let value = Value::from_str("5u64").unwrap();
+
+        for expected in [
+            FinalizeUndo::RestoreKeyValue(program_id, mapping_name, key.clone(), None),
+            FinalizeUndo::RestoreKeyValue(program_id, mapping_name, key.clone(), Some(value.clone())),
+            FinalizeUndo::RestoreMapping(program_id, mapping_name, None),
+            FinalizeUndo::RestoreMapping(program_id, mapping_name, Some(vec![(key, value)])),
+        ] {
+            let bytes = expected.to_bytes_le().unwrap();
+            assert_eq!(expected, FinalizeUndo::read_le(&bytes[..]).unwrap());
+            let bytes = bincode::serialize(&expected).unwrap();
+            assert_eq!(expected, bincode::deserialize(&bytes).unwrap());
+        }
+    }
+}
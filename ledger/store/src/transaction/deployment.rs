@@ -579,6 +579,14 @@ impl<N: Network, D: DeploymentStorage<N>> DeploymentStore<N, D> {
         self.storage.get_program(program_id)
     }
 
+    /// Returns the checksum of the latest edition of the program for the given `program ID`.
+    pub fn get_program_checksum(&self, program_id: &ProgramID<N>) -> Result<Option<[u8; 32]>> {
+        match (self.get_program(program_id)?, self.get_edition(program_id)?) {
+            (Some(program), Some(edition)) => Ok(Some(program.checksum(edition)?)),
+            _ => Ok(None),
+        }
+    }
+
     /// Returns the verifying key for the given `(program ID, function name)`.
     pub fn get_verifying_key(
         &self,
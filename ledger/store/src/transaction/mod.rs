@@ -23,6 +23,7 @@ pub use fee::*;
 
 use crate::{
     atomic_batch_scope,
+    cow_to_cloned,
     cow_to_copied,
     helpers::{Map, MapRead},
     TransitionStorage,
@@ -32,7 +33,7 @@ use console::{
     network::prelude::*,
     program::{Identifier, ProgramID},
 };
-use ledger_block::{Deployment, Execution, Transaction};
+use ledger_block::{Deployment, Execution, RejectionReason, Transaction};
 use synthesizer_program::Program;
 use synthesizer_snark::{Certificate, VerifyingKey};
 
@@ -54,6 +55,8 @@ pub enum TransactionType {
 pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// The mapping of `transaction ID` to `transaction type`.
     type IDMap: for<'a> Map<'a, N::TransactionID, TransactionType>;
+    /// The mapping of `unconfirmed transaction ID` to `rejection reason`.
+    type RejectionReasonMap: for<'a> Map<'a, N::TransactionID, RejectionReason<N>>;
     /// The deployment storage.
     type DeploymentStorage: DeploymentStorage<N, FeeStorage = Self::FeeStorage>;
     /// The execution storage.
@@ -68,6 +71,8 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
 
     /// Returns the ID map.
     fn id_map(&self) -> &Self::IDMap;
+    /// Returns the rejection reason map.
+    fn rejection_reason_map(&self) -> &Self::RejectionReasonMap;
     /// Returns the deployment store.
     fn deployment_store(&self) -> &DeploymentStore<N, Self::DeploymentStorage>;
     /// Returns the execution store.
@@ -89,6 +94,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Starts an atomic batch write operation.
     fn start_atomic(&self) {
         self.id_map().start_atomic();
+        self.rejection_reason_map().start_atomic();
         self.deployment_store().start_atomic();
         self.execution_store().start_atomic();
         self.fee_store().start_atomic();
@@ -97,6 +103,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Checks if an atomic batch is in progress.
     fn is_atomic_in_progress(&self) -> bool {
         self.id_map().is_atomic_in_progress()
+            || self.rejection_reason_map().is_atomic_in_progress()
             || self.deployment_store().is_atomic_in_progress()
             || self.execution_store().is_atomic_in_progress()
             || self.fee_store().is_atomic_in_progress()
@@ -105,6 +112,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Checkpoints the atomic batch.
     fn atomic_checkpoint(&self) {
         self.id_map().atomic_checkpoint();
+        self.rejection_reason_map().atomic_checkpoint();
         self.deployment_store().atomic_checkpoint();
         self.execution_store().atomic_checkpoint();
         self.fee_store().atomic_checkpoint();
@@ -113,6 +121,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Clears the latest atomic batch checkpoint.
     fn clear_latest_checkpoint(&self) {
         self.id_map().clear_latest_checkpoint();
+        self.rejection_reason_map().clear_latest_checkpoint();
         self.deployment_store().clear_latest_checkpoint();
         self.execution_store().clear_latest_checkpoint();
         self.fee_store().clear_latest_checkpoint();
@@ -121,6 +130,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Rewinds the atomic batch to the previous checkpoint.
     fn atomic_rewind(&self) {
         self.id_map().atomic_rewind();
+        self.rejection_reason_map().atomic_rewind();
         self.deployment_store().atomic_rewind();
         self.execution_store().atomic_rewind();
         self.fee_store().atomic_rewind();
@@ -129,6 +139,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Aborts an atomic batch write operation.
     fn abort_atomic(&self) {
         self.id_map().abort_atomic();
+        self.rejection_reason_map().abort_atomic();
         self.deployment_store().abort_atomic();
         self.execution_store().abort_atomic();
         self.fee_store().abort_atomic();
@@ -137,6 +148,7 @@ pub trait TransactionStorage<N: Network>: Clone + Send + Sync {
     /// Finishes an atomic batch write operation.
     fn finish_atomic(&self) -> Result<()> {
         self.id_map().finish_atomic()?;
+        self.rejection_reason_map().finish_atomic()?;
         self.deployment_store().finish_atomic()?;
         self.execution_store().finish_atomic()?;
         self.fee_store().finish_atomic()
@@ -261,6 +273,20 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.remove(transaction_id)
     }
 
+    /// Stores the rejection reason for the given `unconfirmed transaction ID`.
+    pub fn insert_rejection_reason(
+        &self,
+        unconfirmed_transaction_id: N::TransactionID,
+        reason: RejectionReason<N>,
+    ) -> Result<()> {
+        self.storage.rejection_reason_map().insert(unconfirmed_transaction_id, reason)
+    }
+
+    /// Removes the rejection reason for the given `unconfirmed transaction ID`.
+    pub fn remove_rejection_reason(&self, unconfirmed_transaction_id: &N::TransactionID) -> Result<()> {
+        self.storage.rejection_reason_map().remove(unconfirmed_transaction_id)
+    }
+
     /// Returns the deployment store.
     pub fn deployment_store(&self) -> &DeploymentStore<N, T::DeploymentStorage> {
         self.storage.deployment_store()
@@ -318,6 +344,17 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.get_transaction(transaction_id)
     }
 
+    /// Returns the rejection reason for the given `unconfirmed transaction ID`, if it was rejected.
+    pub fn get_rejection_reason(
+        &self,
+        unconfirmed_transaction_id: &N::TransactionID,
+    ) -> Result<Option<RejectionReason<N>>> {
+        match self.storage.rejection_reason_map().get_confirmed(unconfirmed_transaction_id)? {
+            Some(reason) => Ok(Some(cow_to_cloned!(reason))),
+            None => Ok(None),
+        }
+    }
+
     /// Returns the deployment for the given `transaction ID`.
     pub fn get_deployment(&self, transaction_id: &N::TransactionID) -> Result<Option<Deployment<N>>> {
         // Retrieve the transaction type.
@@ -389,6 +426,11 @@ impl<N: Network, T: TransactionStorage<N>> TransactionStore<N, T> {
         self.storage.deployment_store().get_program(program_id)
     }
 
+    /// Returns the checksum of the latest edition of the program for the given `program ID`.
+    pub fn get_program_checksum(&self, program_id: &ProgramID<N>) -> Result<Option<[u8; 32]>> {
+        self.storage.deployment_store().get_program_checksum(program_id)
+    }
+
     /// Returns the verifying key for the given `(program ID, function name)`.
     pub fn get_verifying_key(
         &self,
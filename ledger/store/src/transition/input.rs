@@ -418,6 +418,21 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
     pub fn contains_tag(&self, tag: &Field<N>) -> Result<bool> {
         self.record_tag.contains_key_confirmed(tag)
     }
+
+    /// Returns `true` for each of the given input IDs that exists, in the same order.
+    pub fn contains_input_ids(&self, input_ids: &[Field<N>]) -> Result<Vec<bool>> {
+        self.storage.reverse_id_map().contains_keys_confirmed(input_ids)
+    }
+
+    /// Returns `true` for each of the given serial numbers that exists, in the same order.
+    pub fn contains_serial_numbers(&self, serial_numbers: &[Field<N>]) -> Result<Vec<bool>> {
+        self.record.contains_keys_confirmed(serial_numbers)
+    }
+
+    /// Returns `true` for each of the given tags that exists, in the same order.
+    pub fn contains_tags(&self, tags: &[Field<N>]) -> Result<Vec<bool>> {
+        self.record_tag.contains_keys_confirmed(tags)
+    }
 }
 
 impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
@@ -472,6 +472,21 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         self.inputs.contains_tag(tag)
     }
 
+    /// Returns `true` for each of the given input IDs that exists, in the same order.
+    pub fn contains_input_ids(&self, input_ids: &[Field<N>]) -> Result<Vec<bool>> {
+        self.inputs.contains_input_ids(input_ids)
+    }
+
+    /// Returns `true` for each of the given serial numbers that exists, in the same order.
+    pub fn contains_serial_numbers(&self, serial_numbers: &[Field<N>]) -> Result<Vec<bool>> {
+        self.inputs.contains_serial_numbers(serial_numbers)
+    }
+
+    /// Returns `true` for each of the given tags that exists, in the same order.
+    pub fn contains_tags(&self, tags: &[Field<N>]) -> Result<Vec<bool>> {
+        self.inputs.contains_tags(tags)
+    }
+
     /* Output */
 
     /// Returns `true` if the given output ID exists.
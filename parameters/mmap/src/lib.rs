@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![deny(unsafe_code)]
+
+use std::{fs::File, io, ops::Deref, path::Path};
+
+/// A read-only memory mapping of a parameter file.
+///
+/// The pages of the file are only brought into memory as they are read, and may be reclaimed by the OS afterwards.
+#[derive(Debug)]
+pub struct MappedFile(memmap2::Mmap);
+
+impl MappedFile {
+    /// Memory-maps the file at the given path.
+    ///
+    /// Note: The contents of the mapping change if the file is modified while it is mapped.
+    /// Parameter files are only written when they are downloaded, before they are mapped,
+    /// and their contents are checked against their expected checksum.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let file = File::open(path)?;
+        // Note: Mapping a file is unsafe, as the file may be modified by another process while it is mapped.
+        // The mapping is read-only, and parameter files are never modified once they are stored.
+        #[allow(unsafe_code)]
+        let mmap = unsafe { memmap2::Mmap::map(&file)? };
+        Ok(Self(mmap))
+    }
+}
+
+impl Deref for MappedFile {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mapped_file() {
+        let bytes = (0..=255u8).cycle().take(10_000).collect::<Vec<_>>();
+
+        // Ensure the mapping dereferences to the contents of the file.
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        io::Write::write_all(&mut file, &bytes).unwrap();
+        let mapped = MappedFile::open(file.path()).unwrap();
+        assert_eq!(&*mapped, bytes.as_slice());
+    }
+}
@@ -0,0 +1,415 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A bundle is a single archive of the remote parameter files of a network, for provers
+//! that cannot download parameter files on demand (e.g. air-gapped machines).
+//!
+//! The archive is laid out as follows (integers are little-endian):
+//! - the magic bytes `ALEOPRMS`, followed by a version byte,
+//! - the number of entries (`u32`),
+//! - for each entry, the filename length (`u16`), the filename, the size (`u64`),
+//!   the SHA-256 digest of the file, and the file bytes,
+//! - the SHA-256 digest of all of the preceding bytes.
+//!
+//! A bundle is verified against the checksums that are compiled into this crate, so a bundle that
+//! verifies contains exactly the parameter files that would otherwise have been downloaded.
+
+use crate::{errors::ParameterError, testnet3::*};
+
+use sha2::{Digest, Sha256};
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    ops::RangeInclusive,
+    path::Path,
+};
+
+/// The magic bytes at the start of a bundle.
+const MAGIC: [u8; 8] = *b"ALEOPRMS";
+/// The version of the bundle format.
+const VERSION: u8 = 1;
+/// The directory (relative to the Aleo directory) that remote parameter files are stored in.
+const LOCAL_DIR: &str = "resources/";
+/// The range of degrees (as powers of two) with remote powers of beta.
+const REMOTE_DEGREES: RangeInclusive<u8> = 16..=28;
+/// The range of degrees (as powers of two) with remote shifted powers of beta.
+const REMOTE_SHIFTED_DEGREES: RangeInclusive<u8> = 16..=27;
+
+/// A parameter file in a bundle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BundleEntry {
+    /// The (versioned) filename of the parameter file.
+    pub filename: String,
+    /// The (SHA-256) checksum of the parameter file, in hex.
+    pub checksum: String,
+    /// The size of the parameter file, in bytes.
+    pub size: usize,
+}
+
+/// The metadata and loader of a remote parameter file.
+type Parameter = (fn() -> (String, String, usize), fn() -> Result<Vec<u8>, ParameterError>);
+
+macro_rules! parameter {
+    ($name:ident) => {
+        (
+            $name::file_metadata as fn() -> (String, String, usize),
+            $name::load_bytes as fn() -> Result<Vec<u8>, ParameterError>,
+        )
+    };
+}
+
+/// Returns the remote powers of beta for the given degrees.
+fn powers(degrees: RangeInclusive<u8>) -> Vec<Parameter> {
+    let powers = [
+        parameter!(Degree16),
+        parameter!(Degree17),
+        parameter!(Degree18),
+        parameter!(Degree19),
+        parameter!(Degree20),
+        parameter!(Degree21),
+        parameter!(Degree22),
+        parameter!(Degree23),
+        parameter!(Degree24),
+        parameter!(Degree25),
+        parameter!(Degree26),
+        parameter!(Degree27),
+        parameter!(Degree28),
+    ];
+    let shifted_powers = [
+        parameter!(ShiftedDegree16),
+        parameter!(ShiftedDegree17),
+        parameter!(ShiftedDegree18),
+        parameter!(ShiftedDegree19),
+        parameter!(ShiftedDegree20),
+        parameter!(ShiftedDegree21),
+        parameter!(ShiftedDegree22),
+        parameter!(ShiftedDegree23),
+        parameter!(ShiftedDegree24),
+        parameter!(ShiftedDegree25),
+        parameter!(ShiftedDegree26),
+        parameter!(ShiftedDegree27),
+    ];
+    let select = |range: RangeInclusive<u8>, parameters: &[Parameter]| {
+        parameters
+            .iter()
+            .zip(range.clone())
+            .filter(|(_, degree)| degrees.contains(degree))
+            .map(|(parameter, _)| *parameter)
+            .collect::<Vec<_>>()
+    };
+    let mut selected = select(REMOTE_DEGREES, &powers);
+    selected.extend(select(REMOTE_SHIFTED_DEGREES, &shifted_powers));
+    selected
+}
+
+/// Returns the remote proving and verifying keys.
+fn keys() -> Vec<Parameter> {
+    vec![
+        parameter!(BondPublicProver),
+        parameter!(BondPublicVerifier),
+        parameter!(UnbondPublicProver),
+        parameter!(UnbondPublicVerifier),
+        parameter!(UnbondDelegatorAsValidatorProver),
+        parameter!(UnbondDelegatorAsValidatorVerifier),
+        parameter!(ClaimUnbondPublicProver),
+        parameter!(ClaimUnbondPublicVerifier),
+        parameter!(SetValidatorStateProver),
+        parameter!(SetValidatorStateVerifier),
+        parameter!(TransferPrivateProver),
+        parameter!(TransferPrivateVerifier),
+        parameter!(TransferPublicProver),
+        parameter!(TransferPublicVerifier),
+        parameter!(TransferPrivateToPublicProver),
+        parameter!(TransferPrivateToPublicVerifier),
+        parameter!(TransferPublicToPrivateProver),
+        parameter!(TransferPublicToPrivateVerifier),
+        parameter!(JoinProver),
+        parameter!(JoinVerifier),
+        parameter!(SplitProver),
+        parameter!(SplitVerifier),
+        parameter!(FeePrivateProver),
+        parameter!(FeePrivateVerifier),
+        parameter!(FeePublicProver),
+        parameter!(FeePublicVerifier),
+        parameter!(InclusionProver),
+        parameter!(InclusionVerifier),
+    ]
+}
+
+/// Returns the metadata of every remote parameter file that a bundle may contain.
+fn known_entries() -> Vec<BundleEntry> {
+    powers(REMOTE_DEGREES)
+        .into_iter()
+        .chain(keys())
+        .map(|(metadata, _)| {
+            let (filename, checksum, size) = metadata();
+            BundleEntry { filename, checksum, size }
+        })
+        .collect()
+}
+
+/// Writes a bundle of the remote parameter files to the given path, including the powers of beta
+/// for the given degrees (as powers of two), and all proving and verifying keys.
+/// Parameter files that are not stored locally are downloaded first.
+pub fn export(path: &Path, degree_range: RangeInclusive<u8>) -> Result<Vec<BundleEntry>, ParameterError> {
+    // Ensure the degrees are supported.
+    if degree_range.is_empty() || *degree_range.start() < 15 || *degree_range.end() > *REMOTE_DEGREES.end() {
+        return Err(ParameterError::Message(format!(
+            "Invalid degree range {degree_range:?} - expected a range within 15..={}",
+            REMOTE_DEGREES.end()
+        )));
+    }
+    let parameters = powers(degree_range).into_iter().chain(keys()).collect::<Vec<_>>();
+
+    let mut writer = HashingWriter::new(BufWriter::new(File::create(path)?));
+    writer.write_all(&MAGIC)?;
+    writer.write_all(&[VERSION])?;
+    writer.write_all(&(parameters.len() as u32).to_le_bytes())?;
+
+    let mut entries = Vec::with_capacity(parameters.len());
+    for (metadata, load_bytes) in parameters {
+        let (filename, checksum, size) = metadata();
+        // Note: `load_bytes` ensures the size and checksum match.
+        let bytes = load_bytes()?;
+        writer.write_all(&(filename.len() as u16).to_le_bytes())?;
+        writer.write_all(filename.as_bytes())?;
+        writer.write_all(&(bytes.len() as u64).to_le_bytes())?;
+        writer.write_all(&Sha256::digest(&bytes))?;
+        writer.write_all(&bytes)?;
+        entries.push(BundleEntry { filename, checksum, size });
+    }
+
+    // Write the digest of the bundle.
+    let (mut writer, digest) = writer.finalize();
+    writer.write_all(&digest)?;
+    writer.flush()?;
+    Ok(entries)
+}
+
+/// Verifies the bundle at the given path, and returns its entries.
+///
+/// This ensures the bundle is well-formed and untruncated, and that every entry is a known
+/// parameter file whose size and checksum match the ones compiled into this crate.
+pub fn verify(path: &Path) -> Result<Vec<BundleEntry>, ParameterError> {
+    read_bundle(path, |_| Ok(Box::new(std::io::sink())))
+}
+
+/// Verifies the bundle at the given path, and stores its entries in the local parameter directory,
+/// so they are loaded without being downloaded.
+pub fn install(path: &Path) -> Result<Vec<BundleEntry>, ParameterError> {
+    // Verify the bundle before writing any files, so that an invalid bundle leaves no trace.
+    verify(path)?;
+
+    let mut directory = aleo_std::aleo_dir();
+    directory.push(LOCAL_DIR);
+    std::fs::create_dir_all(&directory)?;
+    read_bundle(path, |entry| Ok(Box::new(BufWriter::new(File::create(directory.join(&entry.filename))?))))
+}
+
+/// Reads and verifies the bundle at the given path, copying each entry into the writer returned by `open`.
+fn read_bundle(
+    path: &Path,
+    mut open: impl FnMut(&BundleEntry) -> Result<Box<dyn Write>, ParameterError>,
+) -> Result<Vec<BundleEntry>, ParameterError> {
+    let known_entries = known_entries();
+    let mut reader = HashingReader::new(BufReader::new(File::open(path)?));
+
+    // Read the header.
+    let mut magic = [0u8; 8];
+    reader.read_exact(&mut magic)?;
+    if magic != MAGIC {
+        return Err(ParameterError::Message("Invalid bundle - unrecognized magic bytes".to_string()));
+    }
+    let version = read_array::<1>(&mut reader)?[0];
+    if version != VERSION {
+        return Err(ParameterError::Message(format!("Invalid bundle - unsupported version {version}")));
+    }
+    let num_entries = u32::from_le_bytes(read_array(&mut reader)?) as usize;
+    if num_entries > known_entries.len() {
+        return Err(ParameterError::Message(format!("Invalid bundle - found {num_entries} entries")));
+    }
+
+    // Read the entries.
+    let mut entries: Vec<BundleEntry> = Vec::with_capacity(num_entries);
+    for _ in 0..num_entries {
+        let filename_length = u16::from_le_bytes(read_array(&mut reader)?) as usize;
+        let mut filename = vec![0u8; filename_length];
+        reader.read_exact(&mut filename)?;
+        let filename = String::from_utf8(filename)
+            .map_err(|_| ParameterError::Message("Invalid bundle - malformed filename".to_string()))?;
+        let size = u64::from_le_bytes(read_array(&mut reader)?) as usize;
+        let checksum = hex::encode(read_array::<32>(&mut reader)?);
+        let entry = BundleEntry { filename, checksum, size };
+
+        // Ensure the entry is a known parameter file, and is not repeated.
+        if !known_entries.contains(&entry) {
+            return Err(ParameterError::Message(format!("Invalid bundle - unknown entry {entry:?}")));
+        }
+        if entries.contains(&entry) {
+            return Err(ParameterError::Message(format!("Invalid bundle - duplicate entry '{}'", entry.filename)));
+        }
+
+        // Copy the file bytes, and ensure they match the entry.
+        let mut writer = HashingWriter::new(open(&entry)?);
+        let copied = std::io::copy(&mut (&mut reader).take(size as u64), &mut writer)? as usize;
+        if copied != size {
+            return Err(ParameterError::SizeMismatch(size, copied));
+        }
+        let (mut writer, digest) = writer.finalize();
+        writer.flush()?;
+        let candidate_checksum = hex::encode(digest);
+        if entry.checksum != candidate_checksum {
+            return checksum_error!(entry.checksum, candidate_checksum);
+        }
+        entries.push(entry);
+    }
+
+    // Ensure the digest of the bundle matches, and that nothing follows it.
+    let (mut reader, digest) = reader.finalize();
+    let expected_digest = read_array::<32>(&mut reader)?;
+    if digest != expected_digest {
+        return checksum_error!(hex::encode(expected_digest), hex::encode(digest));
+    }
+    if reader.read(&mut [0u8; 1])? != 0 {
+        return Err(ParameterError::Message("Invalid bundle - found trailing bytes".to_string()));
+    }
+    Ok(entries)
+}
+
+/// Reads a fixed number of bytes from the given reader.
+fn read_array<const N: usize>(reader: &mut impl Read) -> Result<[u8; N], ParameterError> {
+    let mut bytes = [0u8; N];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// A writer that computes the SHA-256 digest of the bytes written through it.
+struct HashingWriter<W: Write> {
+    inner: W,
+    hasher: Sha256,
+}
+
+impl<W: Write> HashingWriter<W> {
+    fn new(inner: W) -> Self {
+        Self { inner, hasher: Sha256::new() }
+    }
+
+    /// Returns the inner writer, and the digest of the bytes written so far.
+    fn finalize(self) -> (W, [u8; 32]) {
+        (self.inner, self.hasher.finalize().into())
+    }
+}
+
+impl<W: Write> Write for HashingWriter<W> {
+    fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(bytes)?;
+        self.hasher.update(&bytes[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// A reader that computes the SHA-256 digest of the bytes read through it.
+struct HashingReader<R: Read> {
+    inner: R,
+    hasher: Sha256,
+}
+
+impl<R: Read> HashingReader<R> {
+    fn new(inner: R) -> Self {
+        Self { inner, hasher: Sha256::new() }
+    }
+
+    /// Returns the inner reader, and the digest of the bytes read so far.
+    fn finalize(self) -> (R, [u8; 32]) {
+        (self.inner, self.hasher.finalize().into())
+    }
+}
+
+impl<R: Read> Read for HashingReader<R> {
+    fn read(&mut self, bytes: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(bytes)?;
+        self.hasher.update(&bytes[..read]);
+        Ok(read)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_file_path(name: &str) -> std::path::PathBuf {
+        let mut path = std::env::temp_dir();
+        path.push(format!("snarkvm-bundle-{name}-{}", std::process::id()));
+        path
+    }
+
+    #[test]
+    fn test_powers_selection() {
+        // Ensure the embedded degree is not bundled.
+        assert_eq!(powers(15..=15).len(), 0);
+        assert_eq!(powers(15..=16).len(), 2);
+        // Ensure the largest degree has no shifted powers.
+        assert_eq!(powers(27..=28).len(), 3);
+        assert_eq!(powers(REMOTE_DEGREES).len(), 25);
+    }
+
+    #[test]
+    fn test_export_rejects_invalid_degrees() {
+        let path = temp_file_path("invalid");
+        assert!(export(&path, 14..=16).is_err());
+        assert!(export(&path, 16..=29).is_err());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_verify_rejects_corrupt_bundles() {
+        // Ensure a bundle with no entries verifies.
+        let mut bundle = MAGIC.to_vec();
+        bundle.push(VERSION);
+        bundle.extend_from_slice(&0u32.to_le_bytes());
+        let digest = Sha256::digest(&bundle);
+        let path = temp_file_path("empty");
+        std::fs::write(&path, [bundle.as_slice(), digest.as_slice()].concat()).unwrap();
+        assert_eq!(verify(&path).unwrap(), vec![]);
+
+        // Ensure a bundle with a tampered digest is rejected.
+        let mut tampered_digest = digest;
+        tampered_digest[0] ^= 1;
+        std::fs::write(&path, [bundle.as_slice(), tampered_digest.as_slice()].concat()).unwrap();
+        assert!(matches!(verify(&path), Err(ParameterError::ChecksumMismatch(..))));
+
+        // Ensure a truncated bundle is rejected.
+        std::fs::write(&path, &bundle).unwrap();
+        assert!(verify(&path).is_err());
+
+        // Ensure a bundle with an unknown entry is rejected.
+        let mut bundle = MAGIC.to_vec();
+        bundle.push(VERSION);
+        bundle.extend_from_slice(&1u32.to_le_bytes());
+        bundle.extend_from_slice(&4u16.to_le_bytes());
+        bundle.extend_from_slice(b"file");
+        bundle.extend_from_slice(&1u64.to_le_bytes());
+        bundle.extend_from_slice(&Sha256::digest([0u8]));
+        bundle.push(0u8);
+        let digest = Sha256::digest(&bundle);
+        std::fs::write(&path, [bundle.as_slice(), digest.as_slice()].concat()).unwrap();
+        assert!(matches!(verify(&path), Err(ParameterError::Message(..))));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,367 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::ParameterError;
+
+use std::{
+    fs::File,
+    io::{Read, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+};
+
+/// A transport for fetching remote parameter files.
+///
+/// The default transport uses `curl`, if the `remote` feature is enabled. A custom transport may be injected with `set_parameter_transport`,
+/// e.g. to route downloads through a proxy, or to read them from an air-gapped mirror.
+pub trait Transport: Send + Sync {
+    /// Fetches the file at the given URL, starting at the given byte offset, and passes its bytes to `sink` in order.
+    /// The first byte passed to `sink` must be the byte at `offset`.
+    fn fetch(
+        &self,
+        url: &str,
+        offset: u64,
+        sink: &mut dyn FnMut(&[u8]) -> Result<(), ParameterError>,
+    ) -> Result<(), ParameterError>;
+}
+
+lazy_static! {
+    /// The additional mirrors to fetch parameter files from, after the default URL of each file.
+    static ref MIRRORS: RwLock<Vec<String>> = RwLock::new(Vec::new());
+    /// The transport to fetch parameter files with, if not the default.
+    static ref TRANSPORT: RwLock<Option<Arc<dyn Transport>>> = RwLock::new(None);
+}
+
+/// Sets the additional mirrors (as base URLs) to fetch parameter files from, in order of preference,
+/// if the default URL of a file fails.
+pub fn set_parameter_mirrors(mirrors: Vec<String>) {
+    *MIRRORS.write().unwrap_or_else(|error| error.into_inner()) = mirrors;
+}
+
+/// Sets the transport to fetch parameter files with.
+pub fn set_parameter_transport(transport: impl Transport + 'static) {
+    *TRANSPORT.write().unwrap_or_else(|error| error.into_inner()) = Some(Arc::new(transport));
+}
+
+/// A downloader for parameter files, which resumes interrupted downloads, fails over between mirrors,
+/// and verifies the size and checksum of each file before it is stored.
+///
+/// A download is written to `{file_path}.partial`, and moved to `file_path` once it is verified.
+/// If the download is interrupted, the next attempt resumes from the end of the partial file.
+#[derive(Clone)]
+pub struct Downloader {
+    /// The base URLs to fetch from, in order of preference.
+    mirrors: Vec<String>,
+    /// The transport to fetch with.
+    transport: Arc<dyn Transport>,
+    /// The number of passes over the mirrors, before the download fails.
+    max_rounds: usize,
+}
+
+impl Downloader {
+    /// The default number of passes over the mirrors, before a download fails.
+    pub const DEFAULT_MAX_ROUNDS: usize = 3;
+
+    /// Initializes a new downloader, with the given mirrors and transport.
+    pub fn new(mirrors: Vec<String>, transport: Arc<dyn Transport>) -> Self {
+        Self { mirrors, transport, max_rounds: Self::DEFAULT_MAX_ROUNDS }
+    }
+
+    /// Initializes the downloader for the given default URL, followed by the configured mirrors,
+    /// using the configured transport.
+    pub fn for_remote_url(remote_url: &str) -> Self {
+        let mut mirrors = vec![remote_url.to_string()];
+        mirrors.extend(MIRRORS.read().unwrap_or_else(|error| error.into_inner()).iter().cloned());
+        let transport = match TRANSPORT.read().unwrap_or_else(|error| error.into_inner()).as_ref() {
+            Some(transport) => transport.clone(),
+            #[cfg(feature = "remote")]
+            None => Arc::new(CurlTransport),
+            #[cfg(not(feature = "remote"))]
+            None => Arc::new(DisabledTransport),
+        };
+        Self::new(mirrors, transport)
+    }
+
+    /// Sets the number of passes over the mirrors, before a download fails.
+    pub fn with_max_rounds(mut self, max_rounds: usize) -> Self {
+        self.max_rounds = max_rounds.max(1);
+        self
+    }
+
+    /// Downloads the given file, verifies its size and (SHA-256) checksum, and stores it in the given path.
+    /// If the file cannot be stored, the verified bytes are still returned.
+    pub fn download(
+        &self,
+        filename: &str,
+        expected_size: usize,
+        expected_checksum: &str,
+        file_path: &Path,
+    ) -> Result<Vec<u8>, ParameterError> {
+        let partial_path = partial_path(file_path);
+        // Resume from the partial file, if it exists.
+        let mut buffer = Vec::with_capacity(expected_size);
+        if let Ok(mut partial) = File::open(&partial_path) {
+            partial.read_to_end(&mut buffer)?;
+        }
+        // Open the partial file for appending.
+        // Note: If the file cannot be opened, the download continues in memory.
+        let mut partial = open_partial(&partial_path, &buffer);
+
+        let mut last_error = ParameterError::Message(format!("No mirrors to download \"{filename}\" from"));
+        for _ in 0..self.max_rounds {
+            for mirror in &self.mirrors {
+                // Discard a partial download that is larger than the file, as it is corrupt.
+                if buffer.len() > expected_size {
+                    buffer.clear();
+                    partial = open_partial(&partial_path, &buffer);
+                }
+                // Fetch the remainder of the file.
+                if buffer.len() < expected_size {
+                    let url = format!("{}/{filename}", mirror.trim_end_matches('/'));
+                    let result = self.transport.fetch(&url, buffer.len() as u64, &mut |bytes| {
+                        buffer.extend_from_slice(bytes);
+                        if let Some(file) = partial.as_mut() {
+                            file.write_all(bytes)?;
+                        }
+                        Ok(())
+                    });
+                    if let Err(error) = result {
+                        last_error = error;
+                        continue;
+                    }
+                }
+                // Ensure the size matches.
+                if buffer.len() != expected_size {
+                    last_error = ParameterError::SizeMismatch(expected_size, buffer.len());
+                    continue;
+                }
+                // Ensure the checksum matches, and restart the download otherwise.
+                let candidate_checksum = checksum!(&buffer);
+                if expected_checksum != candidate_checksum {
+                    last_error = ParameterError::ChecksumMismatch(expected_checksum.to_string(), candidate_checksum);
+                    buffer.clear();
+                    partial = open_partial(&partial_path, &buffer);
+                    continue;
+                }
+                // Store the verified file.
+                drop(partial);
+                if std::fs::rename(&partial_path, file_path).is_err() {
+                    if std::fs::write(file_path, &buffer).is_err() {
+                        eprintln!(
+                            "\n❗ Error - Failed to store \"{filename}\" locally. Please download this file manually and ensure it is stored in {file_path:?}.\n"
+                        );
+                    }
+                    let _ = std::fs::remove_file(&partial_path);
+                }
+                return Ok(buffer);
+            }
+        }
+        Err(last_error)
+    }
+}
+
+/// Returns the path of the partial download for the given file path.
+fn partial_path(file_path: &Path) -> PathBuf {
+    let mut path = file_path.as_os_str().to_owned();
+    path.push(".partial");
+    PathBuf::from(path)
+}
+
+/// Opens the partial download for appending, truncating it to the given buffer.
+fn open_partial(partial_path: &Path, buffer: &[u8]) -> Option<File> {
+    if let Some(directory) = partial_path.parent() {
+        std::fs::create_dir_all(directory).ok()?;
+    }
+    let mut file = File::create(partial_path).ok()?;
+    file.write_all(buffer).ok()?;
+    Some(file)
+}
+
+/// The default transport, if the `remote` feature is disabled, which fails to fetch.
+#[cfg(not(feature = "remote"))]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct DisabledTransport;
+
+#[cfg(not(feature = "remote"))]
+impl Transport for DisabledTransport {
+    fn fetch(
+        &self,
+        _url: &str,
+        _offset: u64,
+        _sink: &mut dyn FnMut(&[u8]) -> Result<(), ParameterError>,
+    ) -> Result<(), ParameterError> {
+        Err(ParameterError::RemoteFetchDisabled)
+    }
+}
+
+/// The default transport, which fetches over HTTP(S) with `curl`, using range requests to resume.
+#[cfg(feature = "remote")]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct CurlTransport;
+
+#[cfg(feature = "remote")]
+impl Transport for CurlTransport {
+    fn fetch(
+        &self,
+        url: &str,
+        offset: u64,
+        sink: &mut dyn FnMut(&[u8]) -> Result<(), ParameterError>,
+    ) -> Result<(), ParameterError> {
+        let mut easy = curl::easy::Easy::new();
+        easy.follow_location(true)?;
+        easy.fail_on_error(true)?;
+        easy.url(url)?;
+        if offset > 0 {
+            easy.range(&format!("{offset}-"))?;
+        }
+
+        #[cfg(not(feature = "no_std_out"))]
+        {
+            use colored::*;
+
+            let output = format!("{:>15} - Downloading \"{}\" (from byte {offset})", "Installation", url);
+            println!("{}", output.dimmed());
+
+            easy.progress(true)?;
+            easy.progress_function(|total_download, current_download, _, _| {
+                let percent = (current_download / total_download) * 100.0;
+                let size_in_megabytes = total_download as u64 / 1_048_576;
+                let output =
+                    format!("\r{:>15} - {:.2}% complete ({:#} MB total)", "Installation", percent, size_in_megabytes);
+                print!("{}", output.dimmed());
+                true
+            })?;
+        }
+
+        // Note: A server that ignores the range request returns the entire file, so the bytes before the offset are skipped.
+        let to_skip = std::cell::Cell::new(None);
+        let mut sink_error = None;
+        let result = {
+            let mut transfer = easy.transfer();
+            transfer.header_function(|header| {
+                if header.starts_with(b"HTTP/") {
+                    let is_partial = header.split(|byte| *byte == b' ').nth(1) == Some(b"206".as_slice());
+                    to_skip.set(Some(if is_partial { 0 } else { offset as usize }));
+                }
+                true
+            })?;
+            transfer.write_function(|data| {
+                let skip = to_skip.get().unwrap_or(0);
+                let start = skip.min(data.len());
+                to_skip.set(Some(skip - start));
+                if let Err(error) = sink(&data[start..]) {
+                    sink_error = Some(error);
+                    // Note: Returning a short write aborts the transfer.
+                    return Ok(0);
+                }
+                Ok(data.len())
+            })?;
+            transfer.perform()
+        };
+        if let Some(error) = sink_error {
+            return Err(error);
+        }
+        result?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// A transport that serves a file from memory, failing after the given number of bytes per fetch.
+    struct FlakyTransport {
+        file: Vec<u8>,
+        max_bytes_per_fetch: usize,
+        urls: Mutex<Vec<(String, u64)>>,
+    }
+
+    impl Transport for FlakyTransport {
+        fn fetch(
+            &self,
+            url: &str,
+            offset: u64,
+            sink: &mut dyn FnMut(&[u8]) -> Result<(), ParameterError>,
+        ) -> Result<(), ParameterError> {
+            self.urls.lock().unwrap().push((url.to_string(), offset));
+            if url.starts_with("https://down") {
+                return Err(ParameterError::Message("Mirror is down".to_string()));
+            }
+            let remaining = &self.file[offset as usize..];
+            sink(&remaining[..remaining.len().min(self.max_bytes_per_fetch)])?;
+            match remaining.len() > self.max_bytes_per_fetch {
+                true => Err(ParameterError::Message("Connection reset".to_string())),
+                false => Ok(()),
+            }
+        }
+    }
+
+    fn temp_file_path(name: &str) -> PathBuf {
+        let mut path = std::env::temp_dir();
+        path.push(format!("snarkvm-parameters-{name}-{}", std::process::id()));
+        path
+    }
+
+    #[test]
+    fn test_download_resumes_and_fails_over() {
+        let file = (0..100u8).collect::<Vec<_>>();
+        let checksum = checksum!(&file);
+        let transport =
+            Arc::new(FlakyTransport { file: file.clone(), max_bytes_per_fetch: 40, urls: Default::default() });
+        let mirrors = vec!["https://down.example".to_string(), "https://up.example/".to_string()];
+        let downloader = Downloader::new(mirrors, transport.clone());
+
+        let file_path = temp_file_path("resume");
+        let bytes = downloader.download("file", file.len(), &checksum, &file_path).unwrap();
+        assert_eq!(bytes, file);
+        assert_eq!(std::fs::read(&file_path).unwrap(), file);
+        assert!(!partial_path(&file_path).exists());
+        std::fs::remove_file(&file_path).unwrap();
+
+        // Ensure each fetch from the working mirror resumed where the previous one stopped.
+        let offsets = transport
+            .urls
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(url, _)| url == "https://up.example/file")
+            .map(|(_, offset)| *offset)
+            .collect::<Vec<_>>();
+        assert_eq!(offsets, vec![0, 40, 80]);
+    }
+
+    #[test]
+    fn test_download_verifies_checksum_and_size() {
+        let file = (0..100u8).collect::<Vec<_>>();
+        let transport =
+            Arc::new(FlakyTransport { file: file.clone(), max_bytes_per_fetch: 100, urls: Default::default() });
+        let downloader = Downloader::new(vec!["https://up.example".to_string()], transport).with_max_rounds(2);
+
+        // Ensure a checksum mismatch is rejected, and nothing is stored.
+        let file_path = temp_file_path("checksum");
+        let result = downloader.download("file", file.len(), "00", &file_path);
+        assert!(matches!(result, Err(ParameterError::ChecksumMismatch(..))));
+        assert!(!file_path.exists());
+        let _ = std::fs::remove_file(partial_path(&file_path));
+
+        // Ensure a size mismatch is rejected.
+        let file_path = temp_file_path("size");
+        let result = downloader.download("file", file.len() + 1, &checksum!(&file), &file_path);
+        assert!(matches!(result, Err(ParameterError::SizeMismatch(..))));
+        assert!(!file_path.exists());
+        let _ = std::fs::remove_file(partial_path(&file_path));
+    }
+}
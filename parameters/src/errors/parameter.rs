@@ -25,7 +25,7 @@ pub enum ParameterError {
     #[error("{}", _0)]
     Message(String),
 
-    #[error("Remote fetch is disabled, enable compiler flag for feature")]
+    #[error("Remote fetch is disabled, enable the \"remote\" feature or set a parameter transport")]
     RemoteFetchDisabled,
 
     #[error("Expected size of {}, found size of {}", _0, _1)]
@@ -35,7 +35,7 @@ pub enum ParameterError {
     Wasm(String),
 }
 
-#[cfg(not(feature = "wasm"))]
+#[cfg(all(not(feature = "wasm"), feature = "remote"))]
 impl From<curl::Error> for ParameterError {
     fn from(error: curl::Error) -> Self {
         ParameterError::Crate("curl::error", format!("{error:?}"))
@@ -27,9 +27,23 @@ extern crate thiserror;
 #[macro_use]
 pub mod macros;
 
+#[cfg(not(feature = "wasm"))]
+pub mod bundle;
+
+#[cfg(not(feature = "wasm"))]
+pub mod downloader;
+#[cfg(not(feature = "wasm"))]
+pub use downloader::*;
+
 pub mod errors;
 pub use errors::*;
 
+pub mod loader;
+pub use loader::*;
+
+pub mod mapped;
+pub use mapped::*;
+
 pub mod testnet3;
 
 pub mod prelude {
@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// A loader for the parameter files of a network that is defined outside of snarkVM.
+///
+/// A parameter file is loaded from `~/.aleo/{local_dir}/{filename}` if it exists, and is otherwise
+/// fetched from `{remote_url}/{filename}` and stored locally. In both cases, the size and checksum
+/// of the file are verified before it is returned.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParameterLoader {
+    /// The URL to fetch missing parameter files from.
+    remote_url: String,
+    /// The directory (relative to the Aleo directory) to store parameter files in.
+    local_dir: String,
+}
+
+impl ParameterLoader {
+    impl_store_and_remote_fetch!();
+
+    /// Initializes a new parameter loader.
+    pub fn new(remote_url: impl Into<String>, local_dir: impl Into<String>) -> Self {
+        Self { remote_url: remote_url.into(), local_dir: local_dir.into() }
+    }
+
+    /// Returns the URL to fetch missing parameter files from.
+    pub fn remote_url(&self) -> &str {
+        &self.remote_url
+    }
+
+    /// Returns the directory (relative to the Aleo directory) to store parameter files in.
+    pub fn local_dir(&self) -> &str {
+        &self.local_dir
+    }
+
+    /// Returns the bytes of the given parameter file, if its size and (SHA-256) checksum match.
+    pub fn load_bytes(
+        &self,
+        filename: &str,
+        expected_checksum: &str,
+        expected_size: usize,
+    ) -> Result<Vec<u8>, crate::errors::ParameterError> {
+        let expected_checksum = expected_checksum.to_string();
+        impl_load_bytes_logic_remote!(
+            &self.remote_url,
+            &self.local_dir,
+            filename,
+            (),
+            expected_checksum,
+            expected_size
+        );
+    }
+}
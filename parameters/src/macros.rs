@@ -43,64 +43,6 @@ macro_rules! remove_file {
 
 macro_rules! impl_store_and_remote_fetch {
     () => {
-        #[cfg(not(feature = "wasm"))]
-        fn store_bytes(buffer: &[u8], file_path: &std::path::Path) -> Result<(), $crate::errors::ParameterError> {
-            use snarkvm_utilities::Write;
-
-            #[cfg(not(feature = "no_std_out"))]
-            {
-                use colored::*;
-                let output = format!("{:>15} - Storing file in {:?}", "Installation", file_path);
-                println!("{}", output.dimmed());
-            }
-
-            // Ensure the folders up to the file path all exist.
-            let mut directory_path = file_path.to_path_buf();
-            directory_path.pop();
-            let _ = std::fs::create_dir_all(directory_path)?;
-
-            // Attempt to write the parameter buffer to a file.
-            match std::fs::File::create(file_path) {
-                Ok(mut file) => file.write_all(&buffer)?,
-                Err(error) => eprintln!("{}", error),
-            }
-            Ok(())
-        }
-
-        #[cfg(not(feature = "wasm"))]
-        fn remote_fetch(buffer: &mut Vec<u8>, url: &str) -> Result<(), $crate::errors::ParameterError> {
-            let mut easy = curl::easy::Easy::new();
-            easy.follow_location(true)?;
-            easy.url(url)?;
-
-            #[cfg(not(feature = "no_std_out"))]
-            {
-                use colored::*;
-
-                let output = format!("{:>15} - Downloading \"{}\"", "Installation", url);
-                println!("{}", output.dimmed());
-
-                easy.progress(true)?;
-                easy.progress_function(|total_download, current_download, _, _| {
-                    let percent = (current_download / total_download) * 100.0;
-                    let size_in_megabytes = total_download as u64 / 1_048_576;
-                    let output = format!(
-                        "\r{:>15} - {:.2}% complete ({:#} MB total)",
-                        "Installation", percent, size_in_megabytes
-                    );
-                    print!("{}", output.dimmed());
-                    true
-                })?;
-            }
-
-            let mut transfer = easy.transfer();
-            transfer.write_function(|data| {
-                buffer.extend_from_slice(data);
-                Ok(data.len())
-            })?;
-            Ok(transfer.perform()?)
-        }
-
         #[cfg(feature = "wasm")]
         fn remote_fetch(url: &str) -> Result<Vec<u8>, $crate::errors::ParameterError> {
             // Use the browser's XmlHttpRequest object to download the parameter file synchronously.
@@ -188,32 +130,19 @@ macro_rules! impl_load_bytes_logic_remote {
                 );
             }
 
-            // Construct the URL.
-            let url = format!("{}/{}", $remote_url, $filename);
-
             // Load remote file
             cfg_if::cfg_if! {
                 if #[cfg(not(feature = "wasm"))] {
-                    let mut buffer = vec![];
-                    Self::remote_fetch(&mut buffer, &url)?;
-
-                    // Ensure the checksum matches.
-                    let candidate_checksum = checksum!(&buffer);
-                    if $expected_checksum != candidate_checksum {
-                        return checksum_error!($expected_checksum, candidate_checksum)
-                    }
-
-                    match Self::store_bytes(&buffer, &file_path) {
-                        Ok(()) => buffer,
-                        Err(_) => {
-                            eprintln!(
-                                "\n❗ Error - Failed to store \"{}\" locally. Please download this file manually and ensure it is stored in {:?}.\n",
-                                $filename, file_path
-                            );
-                            buffer
-                        }
-                    }
+                    // Download the file, resuming any partial download and failing over to the configured mirrors.
+                    $crate::Downloader::for_remote_url(&$remote_url).download(
+                        $filename,
+                        $expected_size,
+                        &$expected_checksum,
+                        &file_path,
+                    )?
                 } else if #[cfg(feature = "wasm")] {
+                    // Construct the URL.
+                    let url = format!("{}/{}", $remote_url, $filename);
                     let buffer = Self::remote_fetch(&url)?;
 
                     // Ensure the checksum matches.
@@ -317,6 +246,25 @@ macro_rules! impl_remote {
         impl $name {
             impl_store_and_remote_fetch!();
 
+            /// Returns the versioned filename, (SHA-256) checksum, and size of the parameter file.
+            pub fn file_metadata() -> (String, String, usize) {
+                const METADATA: &'static str = include_str!(concat!($local_dir, $fname, ".metadata"));
+
+                let metadata: serde_json::Value =
+                    serde_json::from_str(METADATA).expect("Metadata was not well-formatted");
+                let expected_checksum: String =
+                    metadata["checksum"].as_str().expect("Failed to parse checksum").to_string();
+                let expected_size: usize =
+                    metadata["size"].to_string().parse().expect("Failed to retrieve the file size");
+
+                // Construct the versioned filename.
+                let filename = match expected_checksum.get(0..7) {
+                    Some(sum) => format!("{}.{}.{}", $fname, "usrs", sum),
+                    _ => format!("{}.{}", $fname, "usrs"),
+                };
+                (filename, expected_checksum, expected_size)
+            }
+
             pub fn load_bytes() -> Result<Vec<u8>, $crate::errors::ParameterError> {
                 const METADATA: &'static str = include_str!(concat!($local_dir, $fname, ".metadata"));
 
@@ -342,6 +290,53 @@ macro_rules! impl_remote {
                     expected_size
                 );
             }
+
+            /// Returns the bytes of the parameter file, memory-mapping the local copy of the file if it exists.
+            pub fn load_mapped() -> Result<$crate::ParameterBytes, $crate::errors::ParameterError> {
+                #[cfg(all(feature = "mmap", not(target_family = "wasm")))]
+                {
+                    const METADATA: &'static str = include_str!(concat!($local_dir, $fname, ".metadata"));
+
+                    let metadata: serde_json::Value =
+                        serde_json::from_str(METADATA).expect("Metadata was not well-formatted");
+                    let expected_checksum: String =
+                        metadata["checksum"].as_str().expect("Failed to parse checksum").to_string();
+                    let expected_size: usize =
+                        metadata["size"].to_string().parse().expect("Failed to retrieve the file size");
+
+                    // Construct the versioned filename.
+                    let filename = match expected_checksum.get(0..7) {
+                        Some(sum) => format!("{}.{}.{}", $fname, "usrs", sum),
+                        _ => format!("{}.{}", $fname, "usrs"),
+                    };
+
+                    // Compose the correct file path for the parameter file.
+                    let mut file_path = aleo_std::aleo_dir();
+                    file_path.push($local_dir);
+                    file_path.push(&filename);
+
+                    if file_path.exists() {
+                        // Ensure the size matches.
+                        let candidate_size = std::fs::metadata(&file_path)?.len() as usize;
+                        if expected_size != candidate_size {
+                            remove_file!(file_path);
+                            return Err($crate::errors::ParameterError::SizeMismatch(expected_size, candidate_size));
+                        }
+
+                        // Ensure the checksum matches.
+                        // Note: The file is read in chunks, so the checksum does not bring the mapping into memory.
+                        let candidate_checksum = $crate::mapped::checksum_file(&file_path)?;
+                        if expected_checksum != candidate_checksum {
+                            return checksum_error!(expected_checksum, candidate_checksum);
+                        }
+
+                        // Memory-map the parameter file.
+                        return $crate::ParameterBytes::map(&file_path);
+                    }
+                }
+                // Otherwise, download the parameter file, and store it for the next load.
+                Self::load_bytes().map($crate::ParameterBytes::Owned)
+            }
         }
         paste::item! {
             #[cfg(test)]
@@ -357,6 +352,25 @@ macro_rules! impl_remote {
         impl $name {
             impl_store_and_remote_fetch!();
 
+            /// Returns the versioned filename, (SHA-256) checksum, and size of the parameter file.
+            pub fn file_metadata() -> (String, String, usize) {
+                const METADATA: &'static str = include_str!(concat!($local_dir, $fname, ".metadata"));
+
+                let metadata: serde_json::Value =
+                    serde_json::from_str(METADATA).expect("Metadata was not well-formatted");
+                let expected_checksum: String =
+                    metadata[concat!($ftype, "_checksum")].as_str().expect("Failed to parse checksum").to_string();
+                let expected_size: usize =
+                    metadata[concat!($ftype, "_size")].to_string().parse().expect("Failed to retrieve the file size");
+
+                // Construct the versioned filename.
+                let filename = match expected_checksum.get(0..7) {
+                    Some(sum) => format!("{}.{}.{}", $fname, $ftype, sum),
+                    _ => format!("{}.{}", $fname, $ftype),
+                };
+                (filename, expected_checksum, expected_size)
+            }
+
             pub fn load_bytes() -> Result<Vec<u8>, $crate::errors::ParameterError> {
                 const METADATA: &'static str = include_str!(concat!($local_dir, $fname, ".metadata"));
 
@@ -0,0 +1,215 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::errors::ParameterError;
+use snarkvm_utilities::CanonicalDeserialize;
+
+use core::{
+    marker::PhantomData,
+    ops::{Deref, Range},
+};
+use std::sync::Arc;
+
+/// The bytes of a parameter file.
+///
+/// With the `mmap` feature, a parameter file that exists locally is memory-mapped rather than read into memory,
+/// so that its pages are only brought into memory as they are read, and may be reclaimed by the OS afterwards.
+#[derive(Debug)]
+pub enum ParameterBytes {
+    /// The bytes of the parameter file, read into memory.
+    Owned(Vec<u8>),
+    /// The bytes of the parameter file, memory-mapped from the local copy of the file.
+    #[cfg(all(feature = "mmap", not(target_family = "wasm")))]
+    Mapped(snarkvm_parameters_mmap::MappedFile),
+}
+
+impl ParameterBytes {
+    /// Memory-maps the parameter file at the given path.
+    #[cfg(all(feature = "mmap", not(target_family = "wasm")))]
+    pub fn map(file_path: &std::path::Path) -> Result<Self, ParameterError> {
+        Ok(Self::Mapped(snarkvm_parameters_mmap::MappedFile::open(file_path)?))
+    }
+
+    /// Returns `true` if the bytes are memory-mapped.
+    pub const fn is_mapped(&self) -> bool {
+        match self {
+            Self::Owned(..) => false,
+            #[cfg(all(feature = "mmap", not(target_family = "wasm")))]
+            Self::Mapped(..) => true,
+        }
+    }
+}
+
+impl Deref for ParameterBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Owned(bytes) => bytes,
+            #[cfg(all(feature = "mmap", not(target_family = "wasm")))]
+            Self::Mapped(mmap) => mmap,
+        }
+    }
+}
+
+impl From<Vec<u8>> for ParameterBytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::Owned(bytes)
+    }
+}
+
+/// Returns the checksum of the file at the given path, reading the file in chunks,
+/// so that computing the checksum does not bring the file into the memory of the process.
+#[cfg(all(feature = "mmap", not(target_family = "wasm")))]
+pub(crate) fn checksum_file(file_path: &std::path::Path) -> Result<String, ParameterError> {
+    use sha2::Digest;
+    use std::io::Read;
+
+    let mut file = std::fs::File::open(file_path)?;
+    let mut hasher = sha2::Sha256::new();
+    let mut buffer = vec![0u8; 1 << 16];
+    loop {
+        match file.read(&mut buffer)? {
+            0 => break,
+            num_bytes => hasher.update(&buffer[..num_bytes]),
+        }
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// A vector of elements, serialized (uncompressed) in the bytes of a parameter file,
+/// whose elements are deserialized as they are requested.
+///
+/// If the bytes are memory-mapped, the pages of the elements that are never requested are never read.
+#[derive(Clone, Debug)]
+pub struct MappedElements<T> {
+    /// The bytes of the parameter file.
+    bytes: Arc<ParameterBytes>,
+    /// The number of elements in the vector.
+    num_elements: usize,
+    /// The size (in bytes) of each serialized element.
+    element_size: usize,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: CanonicalDeserialize> MappedElements<T> {
+    /// The size (in bytes) of the length prefix of a serialized vector.
+    const PREFIX_SIZE: usize = 8;
+
+    /// Initializes the vector from the given bytes, which hold a vector of uncompressed elements of equal size.
+    pub fn new(bytes: ParameterBytes) -> Result<Self, ParameterError> {
+        // Read the number of elements.
+        let prefix = bytes.get(..Self::PREFIX_SIZE).ok_or_else(|| error("Missing the number of elements"))?;
+        let num_elements = u64::from_le_bytes(prefix.try_into().map_err(|_| error("Invalid length prefix"))?);
+        let num_elements = usize::try_from(num_elements).map_err(|_| error("Too many elements"))?;
+        // Determine the size of each element.
+        let num_bytes = bytes.len() - Self::PREFIX_SIZE;
+        let element_size = match num_elements {
+            0 => 0,
+            _ => num_bytes / num_elements,
+        };
+        if element_size * num_elements != num_bytes || (num_elements > 0 && element_size == 0) {
+            return Err(error(format!("Expected {num_elements} elements of equal size in {num_bytes} bytes")));
+        }
+        Ok(Self { bytes: Arc::new(bytes), num_elements, element_size, _phantom: PhantomData })
+    }
+
+    /// Returns the number of elements in the vector.
+    pub const fn len(&self) -> usize {
+        self.num_elements
+    }
+
+    /// Returns `true` if the vector is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.num_elements == 0
+    }
+
+    /// Deserializes the elements within the given range.
+    /// Note: The elements are not checked to be valid (e.g. on the curve, and in the prime-order subgroup), as for the
+    /// other powers. This trusts the parameter file: its checksum is computed by reading the file before it is mapped,
+    /// so a modification of the file after it is checked, or while it is mapped, is not detected.
+    pub fn deserialize_range(&self, range: Range<usize>) -> Result<Vec<T>, ParameterError> {
+        if range.start > range.end || range.end > self.num_elements {
+            return Err(error(format!("Invalid range {range:?} for {} elements", self.num_elements)));
+        }
+        let start = Self::PREFIX_SIZE + range.start * self.element_size;
+        let end = Self::PREFIX_SIZE + range.end * self.element_size;
+        self.bytes[start..end]
+            .chunks_exact(self.element_size.max(1))
+            .map(|element| {
+                T::deserialize_uncompressed_unchecked(element)
+                    .map_err(|e| error(format!("Failed to deserialize an element - {e}")))
+            })
+            .collect()
+    }
+}
+
+/// Returns a parameter error with the given message.
+fn error<S: Into<String>>(message: S) -> ParameterError {
+    ParameterError::Message(message.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::{bls12_377::G1Affine, AffineCurve, ProjectiveCurve};
+    use snarkvm_utilities::CanonicalSerialize;
+
+    #[test]
+    fn test_parameter_bytes() {
+        let bytes = (0..=255u8).cycle().take(10_000).collect::<Vec<_>>();
+
+        // Ensure owned bytes dereference to the original bytes.
+        let owned = ParameterBytes::from(bytes.clone());
+        assert!(!owned.is_mapped());
+        assert_eq!(&*owned, bytes.as_slice());
+
+        // Ensure mapped bytes dereference to the contents of the file.
+        #[cfg(all(feature = "mmap", not(target_family = "wasm")))]
+        {
+            let mut file = tempfile::NamedTempFile::new().unwrap();
+            std::io::Write::write_all(&mut file, &bytes).unwrap();
+            let mapped = ParameterBytes::map(file.path()).unwrap();
+            assert!(mapped.is_mapped());
+            assert_eq!(&*mapped, bytes.as_slice());
+            assert_eq!(checksum!(&*mapped), checksum!(&*owned));
+            assert_eq!(checksum_file(file.path()).unwrap(), checksum!(&*owned));
+        }
+    }
+
+    #[test]
+    fn test_mapped_elements() {
+        let generator = G1Affine::prime_subgroup_generator().to_projective();
+        let elements = (1..=100).scan(generator, |element, _| {
+            let output = element.to_affine();
+            *element += &generator;
+            Some(output)
+        });
+        let elements = elements.collect::<Vec<_>>();
+        let mut bytes = Vec::new();
+        elements.serialize_uncompressed(&mut bytes).unwrap();
+
+        // Ensure the elements are deserialized within any range.
+        let mapped = MappedElements::<G1Affine>::new(ParameterBytes::from(bytes.clone())).unwrap();
+        assert_eq!(mapped.len(), elements.len());
+        assert_eq!(mapped.deserialize_range(0..100).unwrap(), elements);
+        assert_eq!(mapped.deserialize_range(10..20).unwrap(), elements[10..20]);
+        assert!(mapped.deserialize_range(20..20).unwrap().is_empty());
+        assert!(mapped.deserialize_range(90..101).is_err());
+
+        // Ensure malformed bytes are rejected.
+        assert!(MappedElements::<G1Affine>::new(ParameterBytes::from(bytes[..bytes.len() - 1].to_vec())).is_err());
+        assert!(MappedElements::<G1Affine>::new(ParameterBytes::from(vec![0u8; 4])).is_err());
+    }
+}
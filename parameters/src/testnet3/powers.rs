@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::*;
+use crate::MappedElements;
 use snarkvm_curves::traits::{PairingCurve, PairingEngine};
 use snarkvm_utilities::{
     CanonicalDeserialize,
@@ -28,7 +29,11 @@ use snarkvm_utilities::{
 };
 
 use anyhow::{anyhow, bail, ensure, Result};
-use std::{collections::BTreeMap, ops::Range, sync::Arc};
+use std::{
+    collections::{BTreeMap, VecDeque},
+    ops::Range,
+    sync::Arc,
+};
 
 const NUM_POWERS_15: usize = 1 << 15;
 const NUM_POWERS_16: usize = 1 << 16;
@@ -224,10 +229,13 @@ impl<E: PairingEngine> ToBytes for PowersOfG<E> {
     }
 }
 
-#[derive(Debug, Clone, CanonicalSerialize, CanonicalDeserialize)]
+#[derive(Debug, Clone)]
 pub struct PowersOfBetaG<E: PairingEngine> {
     /// Group elements of form `[G, \beta * G, \beta^2 * G, ..., \beta^d G]`.
     powers_of_beta_g: Vec<E::G1Affine>,
+    /// The downloaded powers that directly follow `powers_of_beta_g`, and are not yet deserialized,
+    /// along with the number of elements of each that have already been moved into `powers_of_beta_g`.
+    pending_powers_of_beta_g: VecDeque<(MappedElements<E::G1Affine>, usize)>,
     /// Group elements of form `[\beta^i * G, \beta^2 * G, ..., \beta^D G]`.
     /// where D is the maximum degree supported by the SRS.
     shifted_powers_of_beta_g: Vec<E::G1Affine>,
@@ -237,6 +245,7 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
     /// Returns the number of contiguous powers of beta G starting from the 0-th power.
     pub fn num_powers(&self) -> usize {
         self.powers_of_beta_g.len()
+            + self.pending_powers_of_beta_g.iter().map(|(elements, offset)| elements.len() - offset).sum::<usize>()
     }
 
     /// Initializes the hard-coded instance of the powers.
@@ -249,7 +258,7 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
 
         let shifted_powers_of_beta_g = Vec::deserialize_uncompressed_unchecked(&**SHIFTED_POWERS_OF_BETA_G_15)?;
         ensure!(shifted_powers_of_beta_g.len() == NUM_POWERS_15, "Incorrect number of powers in the recovered SRS");
-        Ok(PowersOfBetaG { powers_of_beta_g, shifted_powers_of_beta_g })
+        Ok(PowersOfBetaG { powers_of_beta_g, pending_powers_of_beta_g: VecDeque::new(), shifted_powers_of_beta_g })
     }
 
     /// Returns the range of powers of beta G.
@@ -263,10 +272,10 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
     pub fn available_powers(&self) -> (Range<usize>, Range<usize>) {
         if !self.shifted_powers_of_beta_g.is_empty() {
             let lower_shifted_bound = MAX_NUM_POWERS - self.shifted_powers_of_beta_g.len();
-            ((0..self.powers_of_beta_g.len()), (lower_shifted_bound..MAX_NUM_POWERS))
+            ((0..self.num_powers()), (lower_shifted_bound..MAX_NUM_POWERS))
         } else {
             // We can only be in this case if have downloaded all possible powers.
-            assert_eq!(self.powers_of_beta_g.len(), MAX_NUM_POWERS, "Incorrect number of powers in the recovered SRS");
+            assert_eq!(self.num_powers(), MAX_NUM_POWERS, "Incorrect number of powers in the recovered SRS");
             ((0..MAX_NUM_POWERS), (0..MAX_NUM_POWERS))
         }
     }
@@ -316,9 +325,28 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
     /// Assumes that we have the requisite powers.
     fn normal_powers(&self, range: Range<usize>) -> Result<&[E::G1Affine]> {
         ensure!(self.contains_in_normal_powers(&range), "Requested range is not contained in the available powers");
+        ensure!(range.end <= self.powers_of_beta_g.len(), "Requested range is not yet deserialized");
         Ok(&self.powers_of_beta_g[range])
     }
 
+    /// Deserializes the pending powers of beta G, until the first `end` powers are deserialized.
+    fn deserialize_powers_up_to(&mut self, end: usize) -> Result<()> {
+        while self.powers_of_beta_g.len() < end {
+            let Some((elements, offset)) = self.pending_powers_of_beta_g.front_mut() else {
+                bail!("Requested more powers than have been downloaded");
+            };
+            // Deserialize only as many of the pending powers as are needed.
+            let num_elements = (end - self.powers_of_beta_g.len()).min(elements.len() - *offset);
+            self.powers_of_beta_g.extend(elements.deserialize_range(*offset..*offset + num_elements)?);
+            *offset += num_elements;
+            // Drop the pending powers once they are fully deserialized.
+            if *offset == elements.len() {
+                self.pending_powers_of_beta_g.pop_front();
+            }
+        }
+        Ok(())
+    }
+
     /// Returns the power of beta times G specified by `target`.
     fn power(&mut self, target: usize) -> Result<E::G1Affine> {
         self.powers(target..(target + 1)).map(|s| s[0])
@@ -335,6 +363,10 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
             // We must download the powers.
             self.download_powers_for(&range)?;
         }
+        // Deserialize the pending powers, if the range lies within them.
+        if self.contains_in_normal_powers(&range) || self.shifted_powers_of_beta_g.is_empty() {
+            self.deserialize_powers_up_to(range.end)?;
+        }
         match self.contains_in_normal_powers(&range) {
             true => self.normal_powers(range),
             false => self.shifted_powers(range),
@@ -371,11 +403,8 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
         ensure!(final_power_of_two <= MAX_NUM_POWERS, "Requesting more powers than exist in the SRS");
 
         // Retrieve the current power of two.
-        let current_power_of_two = self
-            .powers_of_beta_g
-            .len()
-            .checked_next_power_of_two()
-            .ok_or_else(|| anyhow!("The current degree is too large"))?;
+        let current_power_of_two =
+            self.num_powers().checked_next_power_of_two().ok_or_else(|| anyhow!("The current degree is too large"))?;
 
         // Initialize a vector for the powers of two to be downloaded.
         let mut download_queue = Vec::with_capacity(14);
@@ -390,11 +419,7 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
         }
         ensure!(final_power_of_two * 2 == accumulator, "Ensure the loop terminates at the right power of two");
 
-        // Reserve capacity for the new powers of two.
-        let additional_size = final_power_of_two
-            .checked_sub(self.powers_of_beta_g.len())
-            .ok_or_else(|| anyhow!("final_power_of_two is smaller than existing powers"))?;
-        self.powers_of_beta_g.reserve(additional_size);
+        ensure!(final_power_of_two >= self.num_powers(), "final_power_of_two is smaller than existing powers");
 
         // Download the powers of two.
         for num_powers in &download_queue {
@@ -403,28 +428,27 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
 
             // Download the universal SRS powers if they're not already on disk.
             let additional_bytes = match *num_powers {
-                NUM_POWERS_16 => Degree16::load_bytes()?,
-                NUM_POWERS_17 => Degree17::load_bytes()?,
-                NUM_POWERS_18 => Degree18::load_bytes()?,
-                NUM_POWERS_19 => Degree19::load_bytes()?,
-                NUM_POWERS_20 => Degree20::load_bytes()?,
-                NUM_POWERS_21 => Degree21::load_bytes()?,
-                NUM_POWERS_22 => Degree22::load_bytes()?,
-                NUM_POWERS_23 => Degree23::load_bytes()?,
-                NUM_POWERS_24 => Degree24::load_bytes()?,
-                NUM_POWERS_25 => Degree25::load_bytes()?,
-                NUM_POWERS_26 => Degree26::load_bytes()?,
-                NUM_POWERS_27 => Degree27::load_bytes()?,
-                NUM_POWERS_28 => Degree28::load_bytes()?,
+                NUM_POWERS_16 => Degree16::load_mapped()?,
+                NUM_POWERS_17 => Degree17::load_mapped()?,
+                NUM_POWERS_18 => Degree18::load_mapped()?,
+                NUM_POWERS_19 => Degree19::load_mapped()?,
+                NUM_POWERS_20 => Degree20::load_mapped()?,
+                NUM_POWERS_21 => Degree21::load_mapped()?,
+                NUM_POWERS_22 => Degree22::load_mapped()?,
+                NUM_POWERS_23 => Degree23::load_mapped()?,
+                NUM_POWERS_24 => Degree24::load_mapped()?,
+                NUM_POWERS_25 => Degree25::load_mapped()?,
+                NUM_POWERS_26 => Degree26::load_mapped()?,
+                NUM_POWERS_27 => Degree27::load_mapped()?,
+                NUM_POWERS_28 => Degree28::load_mapped()?,
                 _ => bail!("Cannot download an invalid degree of '{num_powers}'"),
             };
 
-            // Deserialize the group elements.
-            let additional_powers = Vec::deserialize_uncompressed_unchecked(&*additional_bytes)?;
-            // Extend the powers.
-            self.powers_of_beta_g.extend(&additional_powers);
+            // Defer the deserialization of the group elements until they are requested.
+            let additional_powers = MappedElements::new(additional_bytes)?;
+            self.pending_powers_of_beta_g.push_back((additional_powers, 0));
         }
-        ensure!(self.powers_of_beta_g.len() == final_power_of_two, "Loaded an incorrect number of powers");
+        ensure!(self.num_powers() == final_power_of_two, "Loaded an incorrect number of powers");
         Ok(())
     }
 
@@ -481,18 +505,18 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
 
             // Download the universal SRS powers if they're not already on disk.
             let additional_bytes = match *num_powers {
-                NUM_POWERS_16 => ShiftedDegree16::load_bytes()?,
-                NUM_POWERS_17 => ShiftedDegree17::load_bytes()?,
-                NUM_POWERS_18 => ShiftedDegree18::load_bytes()?,
-                NUM_POWERS_19 => ShiftedDegree19::load_bytes()?,
-                NUM_POWERS_20 => ShiftedDegree20::load_bytes()?,
-                NUM_POWERS_21 => ShiftedDegree21::load_bytes()?,
-                NUM_POWERS_22 => ShiftedDegree22::load_bytes()?,
-                NUM_POWERS_23 => ShiftedDegree23::load_bytes()?,
-                NUM_POWERS_24 => ShiftedDegree24::load_bytes()?,
-                NUM_POWERS_25 => ShiftedDegree25::load_bytes()?,
-                NUM_POWERS_26 => ShiftedDegree26::load_bytes()?,
-                NUM_POWERS_27 => ShiftedDegree27::load_bytes()?,
+                NUM_POWERS_16 => ShiftedDegree16::load_mapped()?,
+                NUM_POWERS_17 => ShiftedDegree17::load_mapped()?,
+                NUM_POWERS_18 => ShiftedDegree18::load_mapped()?,
+                NUM_POWERS_19 => ShiftedDegree19::load_mapped()?,
+                NUM_POWERS_20 => ShiftedDegree20::load_mapped()?,
+                NUM_POWERS_21 => ShiftedDegree21::load_mapped()?,
+                NUM_POWERS_22 => ShiftedDegree22::load_mapped()?,
+                NUM_POWERS_23 => ShiftedDegree23::load_mapped()?,
+                NUM_POWERS_24 => ShiftedDegree24::load_mapped()?,
+                NUM_POWERS_25 => ShiftedDegree25::load_mapped()?,
+                NUM_POWERS_26 => ShiftedDegree26::load_mapped()?,
+                NUM_POWERS_27 => ShiftedDegree27::load_mapped()?,
                 _ => bail!("Cannot download an invalid degree of '{num_powers}'"),
             };
 
@@ -516,6 +540,52 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
     }
 }
 
+impl<E: PairingEngine> CanonicalSerialize for PowersOfBetaG<E> {
+    fn serialize_with_mode<W: Write>(&self, mut writer: W, mode: Compress) -> Result<(), SerializationError> {
+        // Write the powers of beta G, including the pending powers, as a single vector.
+        (self.num_powers() as u64).serialize_with_mode(&mut writer, mode)?;
+        for power in &self.powers_of_beta_g {
+            power.serialize_with_mode(&mut writer, mode)?;
+        }
+        for (elements, offset) in &self.pending_powers_of_beta_g {
+            // Deserialize the pending powers in chunks, to bound the memory usage.
+            for start in (*offset..elements.len()).step_by(NUM_POWERS_16) {
+                let range = start..(start + NUM_POWERS_16).min(elements.len());
+                let powers = elements.deserialize_range(range).map_err(|e| SerializationError::IoError(e.into()))?;
+                for power in &powers {
+                    power.serialize_with_mode(&mut writer, mode)?;
+                }
+            }
+        }
+        self.shifted_powers_of_beta_g.serialize_with_mode(&mut writer, mode)
+    }
+
+    fn serialized_size(&self, mode: Compress) -> usize {
+        let power_size = self.powers_of_beta_g.first().map_or(0, |power| power.serialized_size(mode));
+        8 + self.num_powers() * power_size + self.shifted_powers_of_beta_g.serialized_size(mode)
+    }
+}
+
+impl<E: PairingEngine> CanonicalDeserialize for PowersOfBetaG<E> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let powers_of_beta_g = Vec::deserialize_with_mode(&mut reader, compress, validate)?;
+        let shifted_powers_of_beta_g = Vec::deserialize_with_mode(&mut reader, compress, validate)?;
+        Ok(Self { powers_of_beta_g, pending_powers_of_beta_g: VecDeque::new(), shifted_powers_of_beta_g })
+    }
+}
+
+impl<E: PairingEngine> Valid for PowersOfBetaG<E> {
+    fn check(&self) -> Result<(), SerializationError> {
+        // Note: The pending powers are checked against the checksum of their parameter file when they are loaded.
+        self.powers_of_beta_g.check()?;
+        self.shifted_powers_of_beta_g.check()
+    }
+}
+
 impl<E: PairingEngine> FromBytes for PowersOfBetaG<E> {
     /// Reads the powers from the buffer.
     fn read_le<R: Read>(reader: R) -> std::io::Result<Self> {
@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(all(feature = "mmap", target_os = "linux"))]
+
+use snarkvm_curves::{bls12_377::G1Affine, AffineCurve};
+use snarkvm_parameters::{MappedElements, ParameterBytes};
+use snarkvm_utilities::CanonicalSerialize;
+
+use std::io::Write;
+
+/// The number of elements in the parameter file.
+const NUM_ELEMENTS: usize = 1 << 18;
+
+/// Returns the resident set size of this process, in bytes.
+fn resident_set_size() -> usize {
+    let status = std::fs::read_to_string("/proc/self/status").unwrap();
+    let line = status.lines().find(|line| line.starts_with("VmRSS:")).unwrap();
+    let kilobytes = line.trim_start_matches("VmRSS:").trim_end_matches("kB").trim();
+    kilobytes.parse::<usize>().unwrap() * 1024
+}
+
+#[test]
+fn test_mapped_elements_resident_set_size() {
+    // Write a parameter file with a vector of uncompressed elements.
+    let element = G1Affine::prime_subgroup_generator();
+    let mut element_bytes = Vec::new();
+    element.serialize_uncompressed(&mut element_bytes).unwrap();
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    file.write_all(&(NUM_ELEMENTS as u64).to_le_bytes()).unwrap();
+    for _ in 0..NUM_ELEMENTS {
+        file.write_all(&element_bytes).unwrap();
+    }
+    file.flush().unwrap();
+    let file_size = file.as_file().metadata().unwrap().len() as usize;
+
+    // Map the file, and deserialize a small range of the elements.
+    let before = resident_set_size();
+    let mapped = MappedElements::<G1Affine>::new(ParameterBytes::map(file.path()).unwrap()).unwrap();
+    assert_eq!(mapped.len(), NUM_ELEMENTS);
+    assert_eq!(mapped.deserialize_range(0..1024).unwrap(), vec![element; 1024]);
+    let after = resident_set_size();
+
+    // Ensure the elements that were not requested were not brought into memory.
+    let growth = after.saturating_sub(before);
+    assert!(growth < file_size / 4, "Mapping grew the resident set by {growth} bytes for a {file_size} byte file");
+}
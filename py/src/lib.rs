@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod process;
+pub use process::PyProcess;
+
+mod program;
+pub use program::PyProgram;
+
+mod transaction;
+pub use transaction::PyTransaction;
+
+use snarkvm_circuit::network::AleoV0;
+use snarkvm_console::network::Testnet3;
+
+use core::fmt::Display;
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+/// The network used by the Python bindings.
+pub(crate) type CurrentNetwork = Testnet3;
+/// The circuit environment used by the Python bindings.
+pub(crate) type CurrentAleo = AleoV0;
+
+/// Converts the given error into a Python `ValueError`.
+pub(crate) fn to_py_err(error: impl Display) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+/// The `snarkvm` Python module.
+#[pymodule]
+fn snarkvm(_py: Python, module: &PyModule) -> PyResult<()> {
+    module.add_class::<PyProcess>()?;
+    module.add_class::<PyProgram>()?;
+    module.add_class::<PyTransaction>()?;
+    Ok(())
+}
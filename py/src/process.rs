@@ -0,0 +1,91 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::{to_py_err, CurrentAleo, CurrentNetwork, PyProgram, PyTransaction};
+use snarkvm_console::{
+    account::PrivateKey,
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+};
+use snarkvm_ledger_block::Transaction;
+use snarkvm_synthesizer::{deployment_cost, execution_cost_with_process, Process};
+
+use pyo3::prelude::*;
+
+/// A process, to evaluate functions and estimate costs against a set of programs.
+#[pyclass(name = "Process")]
+pub struct PyProcess(Process<CurrentNetwork>);
+
+#[pymethods]
+impl PyProcess {
+    /// Initializes a new process, with the `credits.aleo` program.
+    #[new]
+    fn new() -> PyResult<Self> {
+        Process::load().map(Self).map_err(to_py_err)
+    }
+
+    /// Adds the given program to the process.
+    fn add_program(&mut self, program: &PyProgram) -> PyResult<()> {
+        self.0.add_program(&program.0).map_err(to_py_err)
+    }
+
+    /// Returns `True` if the process contains the given program.
+    fn contains_program(&self, program_id: &str) -> PyResult<bool> {
+        Ok(self.0.contains_program(&ProgramID::from_str(program_id).map_err(to_py_err)?))
+    }
+
+    /// Returns the program with the given ID.
+    fn get_program(&self, program_id: &str) -> PyResult<PyProgram> {
+        self.0.get_program(program_id).map(|program| PyProgram(program.clone())).map_err(to_py_err)
+    }
+
+    /// Evaluates the given function on the given inputs, as signed by the given private key,
+    /// and returns the outputs. No proof is produced.
+    fn evaluate(
+        &self,
+        private_key: &str,
+        program_id: &str,
+        function_name: &str,
+        inputs: Vec<String>,
+    ) -> PyResult<Vec<String>> {
+        let private_key = PrivateKey::<CurrentNetwork>::from_str(private_key).map_err(to_py_err)?;
+        let program_id = ProgramID::<CurrentNetwork>::from_str(program_id).map_err(to_py_err)?;
+        let function_name = Identifier::<CurrentNetwork>::from_str(function_name).map_err(to_py_err)?;
+
+        // Authorize the function call.
+        let authorization = self
+            .0
+            .authorize::<CurrentAleo, _>(
+                &private_key,
+                program_id,
+                function_name,
+                inputs.iter(),
+                &mut rand::thread_rng(),
+            )
+            .map_err(to_py_err)?;
+        // Evaluate the function.
+        let response = self.0.evaluate::<CurrentAleo>(authorization).map_err(to_py_err)?;
+        Ok(response.outputs().iter().map(ToString::to_string).collect())
+    }
+
+    /// Returns the minimum cost in microcredits to publish the given transaction at the given block height,
+    /// as `(total cost, (storage cost, finalize or namespace cost))`.
+    fn transaction_cost(&self, transaction: &PyTransaction, block_height: u32) -> PyResult<(u64, (u64, u64))> {
+        match &transaction.0 {
+            Transaction::Deploy(_, _, deployment, _) => deployment_cost(deployment, block_height),
+            Transaction::Execute(_, execution, _) => execution_cost_with_process(&self.0, execution, block_height),
+            Transaction::Fee(..) => Ok((0, (0, 0))),
+        }
+        .map_err(to_py_err)
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::{to_py_err, CurrentNetwork};
+use snarkvm_console::{network::prelude::*, program::Identifier};
+use snarkvm_synthesizer::{cost_in_microcredits, Program};
+
+use pyo3::prelude::*;
+
+/// A program, parsed from its Aleo instructions source.
+#[pyclass(name = "Program", frozen)]
+#[derive(Clone)]
+pub struct PyProgram(pub(crate) Program<CurrentNetwork>);
+
+impl PyProgram {
+    /// Returns the function with the given name.
+    fn function(&self, function_name: &str) -> PyResult<snarkvm_synthesizer::Function<CurrentNetwork>> {
+        let function_name = Identifier::from_str(function_name).map_err(to_py_err)?;
+        self.0.get_function(&function_name).map_err(to_py_err)
+    }
+}
+
+#[pymethods]
+impl PyProgram {
+    /// Parses the program from the given source.
+    #[new]
+    fn new(source: &str) -> PyResult<Self> {
+        Program::from_str(source).map(Self).map_err(to_py_err)
+    }
+
+    /// Returns the `credits.aleo` program.
+    #[staticmethod]
+    fn credits() -> PyResult<Self> {
+        Program::credits().map(Self).map_err(to_py_err)
+    }
+
+    /// Returns the program ID.
+    #[getter]
+    fn id(&self) -> String {
+        self.0.id().to_string()
+    }
+
+    /// Returns the IDs of the imported programs.
+    fn imports(&self) -> Vec<String> {
+        self.0.imports().keys().map(ToString::to_string).collect()
+    }
+
+    /// Returns the names of the mappings.
+    fn mappings(&self) -> Vec<String> {
+        self.0.mappings().keys().map(ToString::to_string).collect()
+    }
+
+    /// Returns the names of the functions.
+    fn functions(&self) -> Vec<String> {
+        self.0.functions().keys().map(ToString::to_string).collect()
+    }
+
+    /// Returns the input types of the given function, e.g. `u64.private`.
+    fn function_inputs(&self, function_name: &str) -> PyResult<Vec<String>> {
+        Ok(self.function(function_name)?.input_types().iter().map(ToString::to_string).collect())
+    }
+
+    /// Returns the output types of the given function, e.g. `u64.private`.
+    fn function_outputs(&self, function_name: &str) -> PyResult<Vec<String>> {
+        Ok(self.function(function_name)?.output_types().iter().map(ToString::to_string).collect())
+    }
+
+    /// Returns the cost in microcredits to run the finalize of the given function at the given block height,
+    /// or zero if the function has no finalize.
+    fn finalize_cost(&self, function_name: &str, block_height: u32) -> PyResult<u64> {
+        match self.function(function_name)?.finalize() {
+            Some((_, finalize)) => cost_in_microcredits(finalize, block_height).map_err(to_py_err),
+            None => Ok(0),
+        }
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Program('{}')", self.0.id())
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::{to_py_err, CurrentNetwork, PyProgram};
+use snarkvm_console::network::prelude::*;
+use snarkvm_ledger_block::Transaction;
+use snarkvm_synthesizer::deployment_cost;
+
+use pyo3::prelude::*;
+
+/// A transaction, parsed from its JSON encoding.
+#[pyclass(name = "Transaction", frozen)]
+#[derive(Clone)]
+pub struct PyTransaction(pub(crate) Transaction<CurrentNetwork>);
+
+#[pymethods]
+impl PyTransaction {
+    /// Parses the transaction from the given JSON string.
+    #[new]
+    fn new(json: &str) -> PyResult<Self> {
+        Transaction::from_str(json).map(Self).map_err(to_py_err)
+    }
+
+    /// Returns the transaction ID.
+    #[getter]
+    fn id(&self) -> String {
+        self.0.id().to_string()
+    }
+
+    /// Returns the kind of the transaction, as one of `deploy`, `execute`, or `fee`.
+    #[getter]
+    fn kind(&self) -> &'static str {
+        match self.0 {
+            Transaction::Deploy(..) => "deploy",
+            Transaction::Execute(..) => "execute",
+            Transaction::Fee(..) => "fee",
+        }
+    }
+
+    /// Returns the transitions, as `(transition ID, program ID, function name)` tuples.
+    fn transitions(&self) -> Vec<(String, String, String)> {
+        self.0
+            .transitions()
+            .map(|transition| {
+                (
+                    transition.id().to_string(),
+                    transition.program_id().to_string(),
+                    transition.function_name().to_string(),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the serial numbers of the consumed records.
+    fn serial_numbers(&self) -> Vec<String> {
+        self.0.serial_numbers().map(ToString::to_string).collect()
+    }
+
+    /// Returns the commitments of the created records.
+    fn commitments(&self) -> Vec<String> {
+        self.0.commitments().map(ToString::to_string).collect()
+    }
+
+    /// Returns the fee amount in microcredits.
+    fn fee_amount(&self) -> PyResult<u64> {
+        self.0.fee_amount().map(|amount| *amount).map_err(to_py_err)
+    }
+
+    /// Returns the deployed program, if this is a deploy transaction.
+    fn deployed_program(&self) -> Option<PyProgram> {
+        self.0.deployment().map(|deployment| PyProgram(deployment.program().clone()))
+    }
+
+    /// Returns the minimum cost in microcredits to publish this deploy transaction at the given block height,
+    /// as `(total cost, (storage cost, namespace cost))`.
+    fn deployment_cost(&self, block_height: u32) -> PyResult<(u64, (u64, u64))> {
+        match self.0.deployment() {
+            Some(deployment) => deployment_cost(deployment, block_height).map_err(to_py_err),
+            None => Err(to_py_err(format!("Transaction '{}' is not a deployment", self.0.id()))),
+        }
+    }
+
+    /// Returns the JSON encoding of the transaction.
+    fn to_json(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Transaction('{}')", self.0.id())
+    }
+}
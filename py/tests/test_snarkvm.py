@@ -0,0 +1,62 @@
+# Copyright (C) 2019-2023 Aleo Systems Inc.
+# This file is part of the snarkVM library.
+
+# Licensed under the Apache License, Version 2.0 (the "License");
+# you may not use this file except in compliance with the License.
+# You may obtain a copy of the License at:
+# http://www.apache.org/licenses/LICENSE-2.0
+
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS,
+# WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+# See the License for the specific language governing permissions and
+# limitations under the License.
+
+import pytest
+import snarkvm
+
+PRIVATE_KEY = "APrivateKey1zkp8cC4jgHEBnbtu3xxs1Ndja2EMizcvTRDq5Nikdkukg1p"
+
+SOURCE = """
+program hello.aleo;
+
+function add:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;
+"""
+
+
+def test_program_parse():
+    program = snarkvm.Program(SOURCE)
+    assert program.id == "hello.aleo"
+    assert program.functions() == ["add"]
+    assert program.imports() == []
+    assert program.function_inputs("add") == ["u32.public", "u32.private"]
+    assert program.function_outputs("add") == ["u32.private"]
+    assert program.finalize_cost("add", 0) == 0
+
+
+def test_program_parse_invalid():
+    with pytest.raises(ValueError):
+        snarkvm.Program("program hello.aleo; function")
+
+
+def test_credits_finalize_cost():
+    credits = snarkvm.Program.credits()
+    assert credits.id == "credits.aleo"
+    assert "account" in credits.mappings()
+    assert credits.finalize_cost("transfer_public", 0) > 0
+
+
+def test_process_evaluate():
+    process = snarkvm.Process()
+    process.add_program(snarkvm.Program(SOURCE))
+    assert process.contains_program("hello.aleo")
+    assert process.evaluate(PRIVATE_KEY, "hello.aleo", "add", ["2u32", "3u32"]) == ["5u32"]
+
+
+def test_transaction_parse_invalid():
+    with pytest.raises(ValueError):
+        snarkvm.Transaction("{}")
@@ -0,0 +1,269 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The matrices of the constraint system, in the order of the verifying key commitments.
+const MATRICES: [&str; 3] = ["a", "b", "c"];
+/// The indexed polynomials of each matrix, in the order of the verifying key commitments.
+const POLYNOMIALS: [&str; 4] = ["row", "col", "row_col", "row_col_val"];
+
+/// A difference between a submitted verifying key and the re-synthesized verifying key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KeyDifference {
+    /// A field of the circuit info differs, as (field name, submitted value, synthesized value).
+    CircuitInfo(&'static str, usize, usize),
+    /// The number of commitments differs, as (submitted count, synthesized count).
+    NumCommitments(usize, usize),
+    /// The commitment to an indexed polynomial differs, as (matrix, polynomial, commitment index).
+    Commitment(&'static str, &'static str, usize),
+    /// The circuit ID differs.
+    CircuitID,
+}
+
+impl Display for KeyDifference {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::CircuitInfo(name, submitted, synthesized) => {
+                write!(f, "'{name}' is {submitted}, but synthesized {synthesized}")
+            }
+            Self::NumCommitments(submitted, synthesized) => {
+                write!(f, "{submitted} commitments, but synthesized {synthesized}")
+            }
+            Self::Commitment(matrix, polynomial, index) => {
+                write!(f, "the '{polynomial}' commitment of matrix '{matrix}' (index {index}) differs")
+            }
+            Self::CircuitID => write!(f, "the circuit ID differs"),
+        }
+    }
+}
+
+/// The audit of a function in a deployment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionAudit<N: Network> {
+    /// The function name.
+    pub function_name: Identifier<N>,
+    /// The differences between the submitted and the re-synthesized verifying key.
+    pub differences: Vec<KeyDifference>,
+    /// `true` if the submitted certificate matches the re-synthesized certificate.
+    pub is_matching_certificate: bool,
+}
+
+impl<N: Network> FunctionAudit<N> {
+    /// Returns `true` if the submitted verifying key and certificate match the re-synthesized ones.
+    pub fn is_match(&self) -> bool {
+        self.differences.is_empty() && self.is_matching_certificate
+    }
+}
+
+/// The audit of a deployment, which compares the submitted verifying keys and certificates
+/// against the ones re-synthesized from the program on this machine.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeploymentAudit<N: Network> {
+    /// The program ID.
+    pub program_id: ProgramID<N>,
+    /// `true` if the program in the deployment matches the given program, once it is optimized as in `Process::deploy`.
+    pub is_matching_program: bool,
+    /// The audits of the functions in both the program and the deployment, in the order of the program functions.
+    pub functions: Vec<FunctionAudit<N>>,
+    /// The functions in the program that are missing from the deployment.
+    pub missing_functions: Vec<Identifier<N>>,
+    /// The functions in the deployment that are not in the program.
+    pub unexpected_functions: Vec<Identifier<N>>,
+}
+
+impl<N: Network> DeploymentAudit<N> {
+    /// Returns `true` if the deployment matches the program, and every verifying key and certificate matches.
+    pub fn is_match(&self) -> bool {
+        self.is_matching_program
+            && self.missing_functions.is_empty()
+            && self.unexpected_functions.is_empty()
+            && self.functions.iter().all(FunctionAudit::is_match)
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Re-synthesizes the circuits of the given program, and compares the resulting verifying keys and certificates
+    /// against the ones in the given deployment, to diagnose certificate mismatches in deployments.
+    ///
+    /// Key synthesis is deterministic, so a difference indicates that the deployment was synthesized
+    /// from a different program or with a different version of the circuits.
+    pub fn audit_deployment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program: &Program<N>,
+        deployment: &Deployment<N>,
+        rng: &mut R,
+    ) -> Result<DeploymentAudit<N>> {
+        let timer = timer!("Process::audit_deployment");
+
+        // Ensure the program ID matches.
+        ensure!(
+            program.id() == deployment.program_id(),
+            "The program '{}' does not match the deployment program '{}'",
+            program.id(),
+            deployment.program_id()
+        );
+
+        // Re-synthesize the verifying keys and certificates.
+        let synthesized = self.deploy::<A, _>(program, rng)?;
+        lap!(timer, "Re-synthesize the deployment");
+
+        // Compare the verifying keys and certificates of each function.
+        let mut functions = Vec::with_capacity(synthesized.verifying_keys().len());
+        let mut missing_functions = Vec::new();
+        for (function_name, (verifying_key, certificate)) in synthesized.verifying_keys() {
+            match deployment.verifying_keys().iter().find(|(name, _)| name == function_name) {
+                Some((_, (submitted_key, submitted_certificate))) => functions.push(FunctionAudit {
+                    function_name: *function_name,
+                    differences: diff_verifying_keys(submitted_key, verifying_key),
+                    is_matching_certificate: submitted_certificate == certificate,
+                }),
+                None => missing_functions.push(*function_name),
+            }
+        }
+        // Collect the functions in the deployment that are not in the program.
+        let unexpected_functions = deployment
+            .verifying_keys()
+            .iter()
+            .map(|(function_name, _)| *function_name)
+            .filter(|function_name| !program.contains_function(function_name))
+            .collect();
+        finish!(timer, "Compare the verifying keys and certificates");
+
+        Ok(DeploymentAudit {
+            program_id: *program.id(),
+            is_matching_program: synthesized.program() == deployment.program(),
+            functions,
+            missing_functions,
+            unexpected_functions,
+        })
+    }
+}
+
+/// Returns the differences between the submitted verifying key and the synthesized verifying key.
+fn diff_verifying_keys<N: Network>(submitted: &VerifyingKey<N>, synthesized: &VerifyingKey<N>) -> Vec<KeyDifference> {
+    let mut differences = Vec::new();
+
+    // Compare the circuit info.
+    let (submitted_info, synthesized_info) = (&submitted.circuit_info, &synthesized.circuit_info);
+    for (name, submitted_value, synthesized_value) in [
+        ("num_public_inputs", submitted_info.num_public_inputs, synthesized_info.num_public_inputs),
+        ("num_variables", submitted_info.num_variables, synthesized_info.num_variables),
+        ("num_constraints", submitted_info.num_constraints, synthesized_info.num_constraints),
+        ("num_non_zero_a", submitted_info.num_non_zero_a, synthesized_info.num_non_zero_a),
+        ("num_non_zero_b", submitted_info.num_non_zero_b, synthesized_info.num_non_zero_b),
+        ("num_non_zero_c", submitted_info.num_non_zero_c, synthesized_info.num_non_zero_c),
+    ] {
+        if submitted_value != synthesized_value {
+            differences.push(KeyDifference::CircuitInfo(name, submitted_value, synthesized_value));
+        }
+    }
+
+    // Compare the commitments to the indexed polynomials.
+    let (submitted_commitments, synthesized_commitments) =
+        (&submitted.circuit_commitments, &synthesized.circuit_commitments);
+    if submitted_commitments.len() != synthesized_commitments.len() {
+        differences.push(KeyDifference::NumCommitments(submitted_commitments.len(), synthesized_commitments.len()));
+    }
+    for (index, (submitted_commitment, synthesized_commitment)) in
+        submitted_commitments.iter().zip(synthesized_commitments).enumerate()
+    {
+        if submitted_commitment != synthesized_commitment {
+            // Note: The commitments are ordered by matrix, and then by polynomial.
+            let matrix = MATRICES.get(index / POLYNOMIALS.len()).copied().unwrap_or("unknown");
+            let polynomial = POLYNOMIALS[index % POLYNOMIALS.len()];
+            differences.push(KeyDifference::Commitment(matrix, polynomial, index));
+        }
+    }
+
+    // Compare the circuit ID.
+    if submitted.id != synthesized.id {
+        differences.push(KeyDifference::CircuitID);
+    }
+    differences
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_audit_deployment() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::load().unwrap();
+
+        // Initialize two programs with the same ID, where the second program differs in one function.
+        let program = Program::from_str(
+            r"
+program audit.aleo;
+
+function add:
+    input r0 as u64.public;
+    input r1 as u64.private;
+    add r0 r1 into r2;
+    output r2 as u64.private;
+
+function mul:
+    input r0 as u64.public;
+    input r1 as u64.private;
+    mul r0 r1 into r2;
+    output r2 as u64.private;",
+        )
+        .unwrap();
+        let other_program = Program::from_str(
+            r"
+program audit.aleo;
+
+function add:
+    input r0 as u64.public;
+    input r1 as u64.private;
+    add r0 r1 into r2;
+    output r2 as u64.private;
+
+function mul:
+    input r0 as u64.public;
+    input r1 as u64.private;
+    mul r0 r1 into r2;
+    mul r2 r1 into r3;
+    output r3 as u64.private;",
+        )
+        .unwrap();
+
+        // Ensure the audit of a deployment of the same program matches.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        let audit = process.audit_deployment::<CurrentAleo, _>(&program, &deployment, rng).unwrap();
+        assert!(audit.is_match(), "{audit:?}");
+        assert_eq!(audit.functions.len(), 2);
+
+        // Ensure the audit of a deployment of the other program reports the differing function.
+        let other_deployment = process.deploy::<CurrentAleo, _>(&other_program, rng).unwrap();
+        let audit = process.audit_deployment::<CurrentAleo, _>(&program, &other_deployment, rng).unwrap();
+        assert!(!audit.is_match());
+        assert!(!audit.is_matching_program);
+        assert!(audit.functions[0].is_match());
+        assert!(!audit.functions[1].is_match());
+        let (_, (submitted_key, _)) = &other_deployment.verifying_keys()[1];
+        let (_, (synthesized_key, _)) = &deployment.verifying_keys()[1];
+        assert!(audit.functions[1].differences.contains(&KeyDifference::CircuitInfo(
+            "num_constraints",
+            submitted_key.circuit_info.num_constraints,
+            synthesized_key.circuit_info.num_constraints,
+        )));
+        assert!(!audit.functions[1].is_matching_certificate);
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use synthesizer_program::ProgramDiff;
+
+/// The transitive import graph of a program.
+#[derive(Clone)]
+pub struct DependencyGraph<N: Network> {
+    /// The program ID of the root program.
+    root: ProgramID<N>,
+    /// The programs in topological order (each after its imports), and whether each is in the process.
+    programs: IndexMap<ProgramID<N>, (Program<N>, bool)>,
+    /// The programs in the process that differ from the resolved source, with their differences.
+    conflicts: Vec<(ProgramID<N>, ProgramDiff)>,
+}
+
+impl<N: Network> DependencyGraph<N> {
+    /// Returns the program ID of the root program.
+    pub const fn root(&self) -> &ProgramID<N> {
+        &self.root
+    }
+
+    /// Returns the program with the given ID, if it is in the graph.
+    pub fn get(&self, program_id: &ProgramID<N>) -> Option<&Program<N>> {
+        self.programs.get(program_id).map(|(program, _)| program)
+    }
+
+    /// Returns the direct imports of the given program, if it is in the graph.
+    pub fn imports(&self, program_id: &ProgramID<N>) -> Option<Vec<ProgramID<N>>> {
+        self.get(program_id).map(|program| program.imports().keys().copied().collect())
+    }
+
+    /// Returns every program in the graph, with each program after all of its imports, and the root last.
+    pub fn topological_order(&self) -> Vec<ProgramID<N>> {
+        self.programs.keys().copied().collect()
+    }
+
+    /// Returns the programs that are not yet in the process, in the order they must be deployed.
+    pub fn deployment_order(&self) -> Vec<ProgramID<N>> {
+        self.programs.iter().filter(|(_, (_, is_in_process))| !is_in_process).map(|(id, _)| *id).collect()
+    }
+
+    /// Returns the programs in the process that differ from the source given by the resolver,
+    /// along with the differences from the program in the process to the resolved source.
+    pub fn conflicts(&self) -> &[(ProgramID<N>, ProgramDiff)] {
+        &self.conflicts
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns the dependency graph of the given program, whose imports must all be in the process.
+    #[inline]
+    pub fn dependency_graph(&self, program: &Program<N>) -> Result<DependencyGraph<N>> {
+        self.dependency_graph_with_resolver(program, &|_: &ProgramID<N>| -> Result<Option<Program<N>>> { Ok(None) })
+    }
+
+    /// Returns the dependency graph of the given program, retrieving the imports that are not in the process
+    /// from the given resolver. If an import is in the process, that version is used, and any difference
+    /// from the resolved source is reported as a conflict.
+    #[inline]
+    pub fn dependency_graph_with_resolver(
+        &self,
+        program: &Program<N>,
+        resolver: &impl ProgramResolver<N>,
+    ) -> Result<DependencyGraph<N>> {
+        let mut graph = DependencyGraph { root: *program.id(), programs: IndexMap::new(), conflicts: Vec::new() };
+        self.add_dependencies(program, resolver, &mut vec![*program.id()], &mut graph)?;
+        graph.programs.insert(*program.id(), (program.clone(), self.contains_program(program.id())));
+        Ok(graph)
+    }
+
+    /// Adds the imports of the given program to the graph, where `path` is the chain of programs importing it.
+    fn add_dependencies(
+        &self,
+        program: &Program<N>,
+        resolver: &impl ProgramResolver<N>,
+        path: &mut Vec<ProgramID<N>>,
+        graph: &mut DependencyGraph<N>,
+    ) -> Result<()> {
+        for import_id in program.imports().keys() {
+            // Ensure the imports are not cyclic.
+            if path.contains(import_id) {
+                bail!("Found a cyclic import: {} -> {import_id}", path.iter().join(" -> "))
+            }
+            // Skip the imports that are already in the graph.
+            if graph.programs.contains_key(import_id) {
+                continue;
+            }
+
+            // Retrieve the import from the resolver.
+            let resolved = resolver.resolve(import_id)?;
+            if let Some(resolved) = &resolved {
+                ensure!(
+                    resolved.id() == import_id,
+                    "Resolved '{}' in place of the import '{import_id}'",
+                    resolved.id()
+                );
+            }
+            // Prefer the import in the process, and record any conflict with the resolved source.
+            let (import, is_in_process) = match (self.get_program(import_id), resolved) {
+                (Ok(existing), resolved) => {
+                    if let Some(resolved) = resolved.filter(|resolved| resolved != existing) {
+                        graph.conflicts.push((*import_id, existing.diff(&resolved)?));
+                    }
+                    (existing.clone(), true)
+                }
+                (Err(_), Some(resolved)) => (resolved, false),
+                (Err(_), None) => bail!("Failed to resolve the import '{import_id}' of '{}'", program.id()),
+            };
+
+            // Add the imports of the import, and then the import itself.
+            path.push(*import_id);
+            self.add_dependencies(&import, resolver, path, graph)?;
+            path.pop();
+            graph.programs.insert(*import_id, (import, is_in_process));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns a program with the given name, which imports the given programs, and outputs the given literal.
+    fn sample_program(name: &str, imports: &[&str], output: &str) -> Program<CurrentNetwork> {
+        let imports = imports.iter().map(|import| format!("import {import}.aleo;\n")).join("");
+        Program::from_str(&format!(
+            "{imports}program {name}.aleo;\n\nfunction main:\n    input r0 as u8.public;\n    output {output} as u8.public;\n"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_dependency_graph() {
+        // Construct a diamond, where `top` imports `left` and `right`, which both import `base`.
+        let resolver = |program_id: &ProgramID<CurrentNetwork>| -> Result<Option<Program<CurrentNetwork>>> {
+            Ok(match program_id.to_string().as_str() {
+                "base.aleo" => Some(sample_program("base", &[], "1u8")),
+                "left.aleo" => Some(sample_program("left", &["base"], "r0")),
+                "right.aleo" => Some(sample_program("right", &["base"], "r0")),
+                _ => None,
+            })
+        };
+        let program = sample_program("top", &["left", "right"], "r0");
+
+        // Deploy `base` with a different output.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        process.add_program(&sample_program("base", &[], "0u8")).unwrap();
+        assert!(process.dependency_graph(&program).is_err());
+
+        let graph = process.dependency_graph_with_resolver(&program, &resolver).unwrap();
+        let ids = |names: &[&str]| names.iter().map(|name| ProgramID::from_str(name).unwrap()).collect::<Vec<_>>();
+        assert_eq!(graph.root(), &ProgramID::from_str("top.aleo").unwrap());
+        assert_eq!(graph.topological_order(), ids(&["base.aleo", "left.aleo", "right.aleo", "top.aleo"]));
+        assert_eq!(graph.deployment_order(), ids(&["left.aleo", "right.aleo", "top.aleo"]));
+        assert_eq!(graph.imports(&ProgramID::from_str("left.aleo").unwrap()).unwrap(), ids(&["base.aleo"]));
+
+        // Ensure the deployed `base` is used, and its difference from the source is reported.
+        assert_eq!(graph.conflicts().len(), 1);
+        assert_eq!(graph.conflicts()[0].0, ProgramID::from_str("base.aleo").unwrap());
+        assert_eq!(graph.conflicts()[0].1.to_string(), "~ function main\n");
+    }
+}
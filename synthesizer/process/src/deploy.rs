@@ -16,6 +16,8 @@ use super::*;
 
 impl<N: Network> Process<N> {
     /// Deploys the given program ID, if it does not exist.
+    /// If the optimizer passes of the process are enabled (see `Process::set_optimizer_config`),
+    /// the program is optimized before it is synthesized, so the deployment contains the optimized program.
     #[inline]
     pub fn deploy<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
@@ -24,12 +26,21 @@ impl<N: Network> Process<N> {
     ) -> Result<Deployment<N>> {
         let timer = timer!("Process::deploy");
 
+        // Optimize the program.
+        let program = program.optimize_with(self.optimizer_config)?;
+        lap!(timer, "Optimize the program");
+
         // Compute the stack.
-        let stack = Stack::new(self, program)?;
+        let stack = Stack::new(self, &program)?;
         lap!(timer, "Compute the stack");
 
         // Return the deployment.
-        let deployment = stack.deploy::<A, R>(rng);
+        let deployment = install_with_rng!(
+            self.thread_pools,
+            ThreadPoolKind::Synthesis,
+            rng,
+            self.synthesize_with_memory_limit::<A, _>(|| stack.deploy::<A, _>(rng)).0
+        );
         lap!(timer, "Construct the deployment");
 
         finish!(timer);
@@ -20,6 +20,17 @@ impl<N: Network> Process<N> {
     pub fn execute<A: circuit::Aleo<Network = N>>(
         &self,
         authorization: Authorization<N>,
+    ) -> Result<(Response<N>, Trace<N>)> {
+        self.execute_with_proving_mode::<A>(authorization, ProvingMode::Standard)
+    }
+
+    /// Executes the given authorization, and returns a trace that is proven with the given proving mode.
+    /// Note: `ProvingMode::LowMemory` bounds the memory usage of the prover, at the cost of proving time.
+    #[inline]
+    pub fn execute_with_proving_mode<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: Authorization<N>,
+        proving_mode: ProvingMode,
     ) -> Result<(Response<N>, Trace<N>)> {
         let timer = timer!("Process::execute");
 
@@ -32,17 +43,25 @@ impl<N: Network> Process<N> {
         println!("{}", format!(" • Executing '{locator}'...",).dimmed());
 
         // Initialize the trace.
-        let trace = Arc::new(RwLock::new(Trace::new()));
+        let trace = Arc::new(RwLock::new(Trace::new_with_proving_mode(proving_mode)));
         // Initialize the call stack.
         let call_stack = CallStack::execute(authorization, trace.clone())?;
         lap!(timer, "Initialize call stack");
 
         // Execute the circuit.
-        let response = self.get_stack(request.program_id())?.execute_function::<A>(call_stack)?;
+        let stack = self.get_stack(request.program_id())?;
+        let (response, peak_memory_usage) = self.thread_pools.install(ThreadPoolKind::Synthesis, || {
+            self.synthesize_with_memory_limit::<A, _>(|| stack.execute_function::<A>(call_stack))
+        });
+        let response = response?;
         lap!(timer, "Execute the function");
 
         // Extract the trace.
-        let trace = Arc::try_unwrap(trace).unwrap().into_inner();
+        let mut trace = Arc::try_unwrap(trace).unwrap().into_inner();
+        // Record the peak estimated memory usage of the circuits.
+        trace.set_peak_memory_usage(peak_memory_usage);
+        // Set the memory limit for proving the trace.
+        trace.set_memory_limit(self.memory_limit);
         // Ensure the trace is not empty.
         ensure!(!trace.transitions().is_empty(), "Execution of '{locator}' is empty");
 
@@ -131,4 +150,32 @@ mod tests {
         let transition = trace.transitions()[0].clone();
         assert!(transition.is_fee_public(), "Transition must be for 'credits.aleo/fee_public'");
     }
+
+    #[test]
+    fn test_execute_with_proving_mode() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::<CurrentNetwork>::load().unwrap();
+
+        // Sample a private key.
+        let private_key = PrivateKey::new(rng).unwrap();
+        // Sample a fee in microcredits.
+        let fee_in_microcredits = rng.gen();
+        // Sample a deployment or execution ID.
+        let deployment_or_execution_id = Field::rand(rng);
+
+        // Compute the authorization.
+        let authorization =
+            process.authorize_fee_public(&private_key, fee_in_microcredits, deployment_or_execution_id, rng).unwrap();
+
+        // Ensure the trace defaults to the standard proving mode.
+        let (_, trace) = process.execute::<CurrentAleo>(authorization.replicate()).unwrap();
+        assert_eq!(trace.proving_mode(), ProvingMode::Standard);
+
+        // Ensure the trace uses the given proving mode.
+        let (_, trace) =
+            process.execute_with_proving_mode::<CurrentAleo>(authorization, ProvingMode::LowMemory).unwrap();
+        assert_eq!(trace.proving_mode(), ProvingMode::LowMemory);
+    }
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use super::*;
+use ledger_block::RejectionReason;
 
 impl<N: Network> Process<N> {
     /// Finalizes the deployment and fee.
@@ -119,6 +120,8 @@ impl<N: Network> Process<N> {
                 match finalize_transition(state, store, stack, transition) {
                     // If the evaluation succeeds with an operation, add it to the list.
                     Ok(operations) => finalize_operations.extend(operations),
+                    // If a command fails, return the rejection reason.
+                    Err(error) if error.is::<RejectionReason<N>>() => return Err(error),
                     // If the evaluation fails, bail and return the error.
                     Err(error) => bail!("'finalize' failed on '{program_id}/{function_name}' - {error}"),
                 }
@@ -176,6 +179,8 @@ fn finalize_fee_transition<N: Network, P: FinalizeStorage<N>>(
     match finalize_transition(state, store, stack, fee) {
         // If the evaluation succeeds, return the finalize operations.
         Ok(finalize_operations) => Ok(finalize_operations),
+        // If a command fails, return the rejection reason.
+        Err(error) if error.is::<RejectionReason<N>>() => Err(error),
         // If the evaluation fails, bail and return the error.
         Err(error) => bail!("'finalize' failed on '{}/{}' - {error}", fee.program_id(), fee.function_name()),
     }
@@ -226,6 +231,8 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
 
         // Evaluate the commands.
         while counter < finalize.commands().len() {
+            // Retrieve the index of the command.
+            let index = counter;
             // Retrieve the command.
             let command = &finalize.commands()[counter];
             // Finalize the command.
@@ -250,10 +257,15 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
                 Ok(Ok(Some(finalize_operation))) => finalize_operations.push(finalize_operation),
                 // If the evaluation succeeds with no operation, continue.
                 Ok(Ok(None)) => (),
-                // If the evaluation fails, bail and return the error.
-                Ok(Err(error)) => bail!("'finalize' failed to evaluate command ({command}): {error}"),
-                // If the evaluation fails, bail and return the error.
-                Err(_) => bail!("'finalize' failed to evaluate command ({command})"),
+                // If the evaluation fails, return the rejection reason.
+                Ok(Err(error)) => {
+                    return Err(rejection_reason(stack, transition, index, command, &registers, error).into());
+                }
+                // If the evaluation panics, return the rejection reason.
+                Err(_) => {
+                    let error = "The command failed to evaluate";
+                    return Err(rejection_reason(stack, transition, index, command, &registers, error).into());
+                }
             }
         }
     }
@@ -261,6 +273,25 @@ fn finalize_transition<N: Network, P: FinalizeStorage<N>>(
     Ok(finalize_operations)
 }
 
+/// Returns the rejection reason for the given failed command, with the values of its operands.
+fn rejection_reason<N: Network>(
+    stack: &Stack<N>,
+    transition: &Transition<N>,
+    index: usize,
+    command: &Command<N>,
+    registers: &FinalizeRegisters<N>,
+    error: impl Display,
+) -> RejectionReason<N> {
+    // Load the values of the operands, skipping the operands that are not assigned.
+    let operands = command.operands().iter().filter_map(|operand| registers.load(stack, operand).ok()).collect();
+    // Construct the rejection reason.
+    RejectionReason::new_execution(*transition.program_id(), *transition.function_name(), error).with_command(
+        u16::try_from(index).unwrap_or(u16::MAX),
+        command.clone(),
+        operands,
+    )
+}
+
 // A helper function that returns the index to branch to.
 #[inline]
 fn branch_to<N: Network, const VARIANT: u8>(
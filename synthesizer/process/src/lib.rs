@@ -24,18 +24,34 @@ pub use stack::*;
 mod trace;
 pub use trace::*;
 
+mod dependency_graph;
+pub use dependency_graph::*;
+
+mod resolver;
+pub use resolver::*;
+
+mod thread_pools;
+pub use thread_pools::*;
+
 mod traits;
 pub use traits::*;
 
+mod audit_deployment;
 mod authorize;
 mod deploy;
 mod evaluate;
 mod execute;
 mod finalize;
+mod verify_batch;
 mod verify_deployment;
 mod verify_execution;
 mod verify_fee;
 
+pub use audit_deployment::{DeploymentAudit, FunctionAudit, KeyDifference};
+pub use verify_batch::ProofBatch;
+pub use verify_deployment::{DeploymentResponse, FunctionStatistics};
+pub use verify_execution::{ExecutionVerification, TransitionVerification, TransitionVerifierInputs};
+
 #[cfg(test)]
 mod tests;
 
@@ -68,17 +84,25 @@ use synthesizer_program::{
     FinalizeOperation,
     Function,
     Instruction,
+    OptimizerConfig,
     Program,
     RegistersLoad,
     RegistersStore,
     StackProgram,
 };
-use synthesizer_snark::{ProvingKey, UniversalSRS, VerifyingKey};
+use synthesizer_snark::{ProvingKey, ProvingMode, UniversalSRS, VerifyingKey};
 
 use aleo_std::prelude::{finish, lap, timer};
 use indexmap::IndexMap;
 use parking_lot::RwLock;
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    panic,
+    sync::{
+        atomic::{self, AtomicBool},
+        Arc,
+    },
+};
 
 #[cfg(feature = "aleo-cli")]
 use colored::Colorize;
@@ -89,6 +113,15 @@ pub struct Process<N: Network> {
     universal_srs: Arc<UniversalSRS<N>>,
     /// The mapping of program IDs to stacks.
     stacks: IndexMap<ProgramID<N>, Stack<N>>,
+    /// The thread pools for synthesis, proving, and verification.
+    thread_pools: ThreadPools,
+    /// The limit on the estimated memory usage (in bytes) of each circuit during synthesis,
+    /// and of each batch of circuits during proving, if any.
+    memory_limit: Option<u64>,
+    /// Whether the proving keys precompute the window tables for their committer keys.
+    precompute_proving_keys: Arc<AtomicBool>,
+    /// The optimizer passes applied to a program before it is deployed, which are disabled by default.
+    optimizer_config: OptimizerConfig,
 }
 
 impl<N: Network> Process<N> {
@@ -98,7 +131,14 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process:setup");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            thread_pools: Default::default(),
+            memory_limit: None,
+            precompute_proving_keys: Default::default(),
+            optimizer_config: OptimizerConfig::disabled(),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -149,7 +189,14 @@ impl<N: Network> Process<N> {
         let timer = timer!("Process::load");
 
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            thread_pools: Default::default(),
+            memory_limit: None,
+            precompute_proving_keys: Default::default(),
+            optimizer_config: OptimizerConfig::disabled(),
+        };
         lap!(timer, "Initialize process");
 
         // Initialize the 'credits.aleo' program.
@@ -182,7 +229,14 @@ impl<N: Network> Process<N> {
     #[cfg(feature = "wasm")]
     pub fn load_web() -> Result<Self> {
         // Initialize the process.
-        let mut process = Self { universal_srs: Arc::new(UniversalSRS::load()?), stacks: IndexMap::new() };
+        let mut process = Self {
+            universal_srs: Arc::new(UniversalSRS::load()?),
+            stacks: IndexMap::new(),
+            thread_pools: Default::default(),
+            memory_limit: None,
+            precompute_proving_keys: Default::default(),
+            optimizer_config: OptimizerConfig::disabled(),
+        };
 
         // Initialize the 'credits.aleo' program.
         let program = Program::credits()?;
@@ -203,6 +257,88 @@ impl<N: Network> Process<N> {
         &self.universal_srs
     }
 
+    /// Returns the thread pools for synthesis, proving, and verification.
+    #[inline]
+    pub const fn thread_pools(&self) -> &ThreadPools {
+        &self.thread_pools
+    }
+
+    /// Sets the thread pools for synthesis, proving, and verification.
+    #[inline]
+    pub fn set_thread_pools(&mut self, thread_pools: ThreadPools) {
+        self.thread_pools = thread_pools;
+    }
+
+    /// Returns the limit on the estimated memory usage (in bytes) of each circuit during synthesis,
+    /// and of each batch of circuits during proving, if any.
+    #[inline]
+    pub const fn memory_limit(&self) -> Option<u64> {
+        self.memory_limit
+    }
+
+    /// Sets the limit on the estimated memory usage (in bytes) of each circuit during synthesis,
+    /// and of each batch of circuits during proving, or removes it if `None`.
+    /// If a circuit exceeds the limit, its synthesis or proof fails with an error, instead of exhausting the memory of the process.
+    /// Note: The limit applies to executions and deployments, and never to the verification of a deployment.
+    #[inline]
+    pub fn set_memory_limit(&mut self, memory_limit: Option<u64>) {
+        self.memory_limit = memory_limit;
+    }
+
+    /// Returns the optimizer passes applied to a program before it is deployed.
+    #[inline]
+    pub const fn optimizer_config(&self) -> OptimizerConfig {
+        self.optimizer_config
+    }
+
+    /// Sets the optimizer passes applied to a program before it is deployed.
+    /// By default, programs are deployed as they are written, i.e. with `OptimizerConfig::disabled()`.
+    /// Note: This does not affect the verification of a deployment, which synthesizes the deployed program as it is.
+    #[inline]
+    pub fn set_optimizer_config(&mut self, optimizer_config: OptimizerConfig) {
+        self.optimizer_config = optimizer_config;
+    }
+
+    /// Returns `true` if the proving keys precompute the window tables for their committer keys.
+    #[inline]
+    pub fn precompute_proving_keys(&self) -> bool {
+        self.precompute_proving_keys.load(atomic::Ordering::Relaxed)
+    }
+
+    /// Sets whether the proving keys precompute the window tables for their committer keys.
+    /// If enabled, the proving keys that are already loaded are precomputed as well.
+    #[inline]
+    pub fn set_precompute_proving_keys(&self, precompute: bool) {
+        self.precompute_proving_keys.store(precompute, atomic::Ordering::Relaxed);
+        if precompute {
+            self.stacks.values().for_each(Stack::precompute_proving_keys);
+        }
+    }
+
+    /// Runs the given synthesis with the memory limit of the process, in the current thread,
+    /// and returns its output with the peak estimated memory usage (in bytes) of its circuits.
+    fn synthesize_with_memory_limit<A: circuit::Aleo<Network = N>, T>(
+        &self,
+        synthesize: impl FnOnce() -> Result<T>,
+    ) -> (Result<T>, u64) {
+        A::set_memory_limit(self.memory_limit);
+        A::reset_peak_memory_usage();
+        let output = match self.memory_limit {
+            // Note: A circuit halts as soon as it exceeds the memory limit, so the halt is caught and the circuit is reset.
+            Some(_) => panic::catch_unwind(panic::AssertUnwindSafe(synthesize)).unwrap_or_else(|error| {
+                A::reset();
+                match error.downcast_ref::<String>() {
+                    Some(message) => Err(anyhow!("{message}")),
+                    None => Err(anyhow!("Synthesis halted")),
+                }
+            }),
+            None => synthesize(),
+        };
+        let peak_memory_usage = A::peak_memory_usage();
+        A::set_memory_limit(None);
+        (output, peak_memory_usage)
+    }
+
     /// Returns `true` if the process contains the program with the given ID.
     #[inline]
     pub fn contains_program(&self, program_id: &ProgramID<N>) -> bool {
@@ -287,6 +423,32 @@ impl<N: Network> Process<N> {
         // Synthesize the proving and verifying key.
         self.get_stack(program_id)?.synthesize_key::<A, R>(function_name, rng)
     }
+
+    /// Loads the missing proving and verifying keys for the functions of the given program,
+    /// by awaiting the given callback for the bytes of the proving and verifying key of each function.
+    /// This allows web contexts, where blocking requests are unavailable, to fetch the keys ahead of execution.
+    pub async fn load_keys_with<F, Fut>(&self, program_id: &ProgramID<N>, fetch: F) -> Result<()>
+    where
+        F: Fn(Identifier<N>) -> Fut,
+        Fut: core::future::Future<Output = Result<(Vec<u8>, Vec<u8>)>>,
+    {
+        // Retrieve the stack.
+        let stack = self.get_stack(program_id)?;
+        for function_name in stack.program().functions().keys() {
+            // Skip the function, if its keys are already loaded.
+            if stack.contains_proving_key(function_name) && stack.contains_verifying_key(function_name) {
+                continue;
+            }
+            // Fetch the keys.
+            let (proving_key, verifying_key) = fetch(*function_name).await?;
+            let proving_key = ProvingKey::from_bytes_le(&proving_key)?;
+            let verifying_key = VerifyingKey::from_bytes_le(&verifying_key)?;
+            // Insert the keys.
+            stack.insert_proving_key(function_name, proving_key)?;
+            stack.insert_verifying_key(function_name, verifying_key)?;
+        }
+        Ok(())
+    }
 }
 
 #[cfg(any(test, feature = "test"))]
@@ -0,0 +1,184 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use ledger_store::{BlockStorage, BlockStore};
+
+use std::path::PathBuf;
+
+/// A source of programs, used to resolve the imports of a program that are not yet in the process.
+pub trait ProgramResolver<N: Network> {
+    /// Returns the program with the given ID, or `None` if it cannot be found.
+    fn resolve(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>>;
+}
+
+/// Resolves programs with the given function, e.g. to fetch them from a remote registry.
+impl<N: Network, F: Fn(&ProgramID<N>) -> Result<Option<Program<N>>>> ProgramResolver<N> for F {
+    fn resolve(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
+        self(program_id)
+    }
+}
+
+/// Resolves programs that are deployed on the ledger.
+impl<N: Network, B: BlockStorage<N>> ProgramResolver<N> for BlockStore<N, B> {
+    fn resolve(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
+        self.get_program(program_id)
+    }
+}
+
+/// Resolves programs from source files in a local workspace.
+///
+/// For each directory, in order, the program `foo.aleo` is read from `<directory>/foo.aleo`
+/// (e.g. the `imports` directory of a package), or else from `<directory>/foo/main.aleo`
+/// (e.g. a directory of packages).
+#[derive(Clone, Debug, Default)]
+pub struct WorkspaceResolver {
+    /// The directories to search.
+    directories: Vec<PathBuf>,
+}
+
+impl WorkspaceResolver {
+    /// Initializes a new resolver over the given directories.
+    pub fn new(directories: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+        Self { directories: directories.into_iter().map(Into::into).collect() }
+    }
+}
+
+impl<N: Network> ProgramResolver<N> for WorkspaceResolver {
+    fn resolve(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
+        for directory in &self.directories {
+            let candidates = [
+                directory.join(program_id.to_string()),
+                directory.join(program_id.name().to_string()).join("main.aleo"),
+            ];
+            if let Some(path) = candidates.iter().find(|path| path.is_file()) {
+                // Parse the program.
+                let source = std::fs::read_to_string(path)?;
+                let program = Program::from_str(&source)
+                    .map_err(|error| anyhow!("Failed to parse '{}' - {error}", path.display()))?;
+                // Ensure the program ID matches.
+                ensure!(
+                    program.id() == program_id,
+                    "Expected '{program_id}' in '{}', found '{}'",
+                    path.display(),
+                    program.id()
+                );
+                return Ok(Some(program));
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Adds the imports of the given program that are not yet in the process, along with their own imports,
+    /// by retrieving them from the given resolver.
+    #[inline]
+    pub fn resolve_imports(&mut self, program: &Program<N>, resolver: &impl ProgramResolver<N>) -> Result<()> {
+        // Construct the dependency graph.
+        let graph = self.dependency_graph_with_resolver(program, resolver)?;
+        // Add the missing imports, each after its own imports.
+        for program_id in graph.deployment_order().iter().filter(|id| *id != program.id()) {
+            match graph.get(program_id) {
+                Some(import) => self.add_program(import)?,
+                None => bail!("Missing '{program_id}' in the dependency graph of '{}'", program.id()),
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds the given program to the process, after adding its missing imports from the given resolver.
+    #[inline]
+    pub fn add_program_with_resolver(
+        &mut self,
+        program: &Program<N>,
+        resolver: &impl ProgramResolver<N>,
+    ) -> Result<()> {
+        self.resolve_imports(program, resolver)?;
+        self.add_program(program)
+    }
+
+    /// Deploys the given program, after adding its missing imports from the given resolver.
+    #[inline]
+    pub fn deploy_with_resolver<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &mut self,
+        program: &Program<N>,
+        resolver: &impl ProgramResolver<N>,
+        rng: &mut R,
+    ) -> Result<Deployment<N>> {
+        self.resolve_imports(program, resolver)?;
+        self.deploy::<A, R>(program, rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns a program with the given name, which imports the given programs.
+    fn sample_program(name: &str, imports: &[&str]) -> Program<CurrentNetwork> {
+        let imports = imports.iter().map(|import| format!("import {import}.aleo;\n")).join("");
+        Program::from_str(&format!(
+            "{imports}program {name}.aleo;\n\nfunction main:\n    input r0 as u8.public;\n    output r0 as u8.public;\n"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_workspace_resolver() {
+        let workspace = tempfile::tempdir().unwrap();
+        // Write `base.aleo` into an imports directory, and `middle.aleo` as a package.
+        let imports = workspace.path().join("imports");
+        std::fs::create_dir_all(&imports).unwrap();
+        std::fs::write(imports.join("base.aleo"), sample_program("base", &[]).to_string()).unwrap();
+        std::fs::create_dir_all(workspace.path().join("middle")).unwrap();
+        std::fs::write(workspace.path().join("middle/main.aleo"), sample_program("middle", &["base"]).to_string())
+            .unwrap();
+
+        let resolver = WorkspaceResolver::new([imports, workspace.path().to_path_buf()]);
+        let program = sample_program("top", &["middle"]);
+
+        // Ensure the imports are added transitively.
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        assert!(process.add_program(&program).is_err());
+        process.add_program_with_resolver(&program, &resolver).unwrap();
+        for name in ["base.aleo", "middle.aleo", "top.aleo"] {
+            assert!(process.contains_program(&ProgramID::from_str(name).unwrap()));
+        }
+
+        // Ensure a missing import is reported.
+        let program = sample_program("other", &["missing"]);
+        let error = process.add_program_with_resolver(&program, &resolver).unwrap_err();
+        assert!(error.to_string().contains("Failed to resolve the import 'missing.aleo'"));
+    }
+
+    #[test]
+    fn test_cyclic_imports() {
+        // Resolve programs that import each other.
+        let resolver = |program_id: &ProgramID<CurrentNetwork>| -> Result<Option<Program<CurrentNetwork>>> {
+            Ok(match program_id.to_string().as_str() {
+                "first.aleo" => Some(sample_program("first", &["second"])),
+                "second.aleo" => Some(sample_program("second", &["first"])),
+                _ => None,
+            })
+        };
+
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        let error = process.add_program_with_resolver(&sample_program("first", &["second"]), &resolver).unwrap_err();
+        assert_eq!(error.to_string(), "Found a cyclic import: first.aleo -> second.aleo -> first.aleo");
+    }
+}
@@ -12,7 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod signing_payload;
+pub use signing_payload::*;
+
 use console::{network::prelude::*, program::Request};
+use ledger_block::Transition;
 
 use parking_lot::RwLock;
 use std::{collections::VecDeque, sync::Arc};
@@ -21,12 +25,14 @@ use std::{collections::VecDeque, sync::Arc};
 pub struct Authorization<N: Network> {
     /// The authorized requests.
     requests: Arc<RwLock<VecDeque<Request<N>>>>,
+    /// The transitions, in the order they are produced when the authorization is evaluated.
+    transitions: Arc<RwLock<Vec<Transition<N>>>>,
 }
 
 impl<N: Network> From<Vec<Request<N>>> for Authorization<N> {
     /// Initialize a new `Authorization` instance, with the given request.
     fn from(requests: Vec<Request<N>>) -> Self {
-        Self { requests: Arc::new(RwLock::new(VecDeque::from(requests))) }
+        Self { requests: Arc::new(RwLock::new(VecDeque::from(requests))), transitions: Default::default() }
     }
 }
 
@@ -88,7 +94,10 @@ impl<N: Network> Authorization<N> {
 impl<N: Network> Authorization<N> {
     /// Returns a new and independent replica of the authorization.
     pub fn replicate(&self) -> Self {
-        Self { requests: Arc::new(RwLock::new(self.requests.read().clone())) }
+        Self {
+            requests: Arc::new(RwLock::new(self.requests.read().clone())),
+            transitions: Arc::new(RwLock::new(self.transitions.read().clone())),
+        }
     }
 
     /// Returns the next `Request` in the authorization.
@@ -125,4 +134,15 @@ impl<N: Network> Authorization<N> {
     pub fn to_vec_deque(&self) -> VecDeque<Request<N>> {
         self.requests.read().clone()
     }
+
+    /// Appends the given `Transition` to the authorization.
+    pub fn insert_transition(&self, transition: Transition<N>) {
+        self.transitions.write().push(transition);
+    }
+
+    /// Returns the transitions produced by evaluating the authorization, in the order they were produced.
+    /// Note: The transitions are only populated once the authorization is evaluated, and contain no proofs.
+    pub fn transitions(&self) -> Vec<Transition<N>> {
+        self.transitions.read().clone()
+    }
 }
@@ -0,0 +1,425 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+use console::{
+    account::PrivateKey,
+    program::{Identifier, InputID, Literal, LiteralType, ProgramID, Value, ValueType},
+    types::{Field, U64},
+};
+
+use sha2::{Digest, Sha256};
+
+/// The version of the signing payload format.
+const SIGNING_PAYLOAD_VERSION: u8 = 1;
+/// The number of bytes in the header of each signing payload chunk, as `(version, index, count)`.
+const CHUNK_HEADER_SIZE: usize = 1 + 2 + 2;
+/// The number of bytes in the checksum of a signing payload.
+const CHECKSUM_SIZE: usize = 32;
+
+/// A compact description of an unsigned request, for display and signing on a hardware wallet.
+///
+/// The payload holds the program ID, function name, inputs and input types of the request, along with the
+/// fee and the deployment or execution ID it pays for, if a fee is given. It is built before anything is signed,
+/// so the device signs exactly what it displays. It is split into chunks that fit the transport of the device,
+/// and is checked against a SHA-256 checksum when the chunks are reassembled.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SigningPayload<N: Network> {
+    /// The network ID.
+    network_id: u16,
+    /// The program ID.
+    program_id: ProgramID<N>,
+    /// The function name.
+    function_name: Identifier<N>,
+    /// The inputs.
+    inputs: Vec<Value<N>>,
+    /// The input types.
+    input_types: Vec<ValueType<N>>,
+    /// The fee in microcredits and the deployment or execution ID, if a fee is given.
+    fee: Option<(u64, Field<N>)>,
+}
+
+impl<N: Network> SigningPayload<N> {
+    /// Returns the signing payload for the given unsigned request, and the given fee in microcredits
+    /// with the deployment or execution ID it pays for.
+    pub fn new(
+        program_id: ProgramID<N>,
+        function_name: Identifier<N>,
+        inputs: Vec<Value<N>>,
+        input_types: Vec<ValueType<N>>,
+        fee: Option<(u64, Field<N>)>,
+    ) -> Result<Self> {
+        // Ensure the number of inputs matches the number of input types.
+        ensure!(
+            inputs.len() == input_types.len(),
+            "'{program_id}/{function_name}' expects {} inputs, but {} were provided",
+            input_types.len(),
+            inputs.len()
+        );
+        // Ensure the number of inputs fits in the payload.
+        ensure!(inputs.len() <= u16::MAX as usize, "The signing payload has too many inputs");
+        Ok(Self { network_id: N::ID, program_id, function_name, inputs, input_types, fee })
+    }
+
+    /// Returns the network ID.
+    pub const fn network_id(&self) -> u16 {
+        self.network_id
+    }
+
+    /// Returns the program ID.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the function name.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the inputs.
+    pub fn inputs(&self) -> &[Value<N>] {
+        &self.inputs
+    }
+
+    /// Returns the input types.
+    pub fn input_types(&self) -> &[ValueType<N>] {
+        &self.input_types
+    }
+
+    /// Returns the fee in microcredits and the deployment or execution ID, if a fee is given.
+    pub const fn fee(&self) -> Option<(u64, Field<N>)> {
+        self.fee
+    }
+
+    /// Signs the request, and the fee as a `credits.aleo/fee_public` request if a fee is given.
+    /// This is the step an external signer runs on the reassembled payload.
+    pub fn sign<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        rng: &mut R,
+    ) -> Result<(Authorization<N>, Option<Authorization<N>>)> {
+        // Ensure the payload is for the current network.
+        ensure!(self.network_id == N::ID, "The signing payload is for network {}", self.network_id);
+        // Sign the request.
+        let request = Request::sign(
+            private_key,
+            self.program_id,
+            self.function_name,
+            self.inputs.iter(),
+            &self.input_types,
+            rng,
+        )?;
+        // Sign the fee.
+        let fee = match self.fee {
+            Some((fee_in_microcredits, deployment_or_execution_id)) => {
+                let inputs = [
+                    Value::from(Literal::U64(U64::new(fee_in_microcredits))),
+                    Value::from(Literal::Field(deployment_or_execution_id)),
+                ];
+                let request = Request::sign(
+                    private_key,
+                    ProgramID::from_str("credits.aleo")?,
+                    Identifier::from_str("fee_public")?,
+                    inputs.iter(),
+                    &Self::fee_public_input_types(),
+                    rng,
+                )?;
+                Some(Authorization::from(request))
+            }
+            None => None,
+        };
+        Ok((Authorization::from(request), fee))
+    }
+
+    /// Ensures the signed authorization and fee authorization match the signing payload.
+    pub fn ensure_matches(&self, authorization: &Authorization<N>, fee: Option<&Authorization<N>>) -> Result<()> {
+        // Retrieve the root request.
+        let request = authorization.peek_next()?;
+        // Ensure the request matches the payload.
+        ensure!(**request.network_id() == self.network_id, "The request does not match the signing payload network");
+        ensure!(
+            *request.program_id() == self.program_id && *request.function_name() == self.function_name,
+            "The request does not match the signing payload function"
+        );
+        ensure!(request.inputs() == self.inputs.as_slice(), "The request does not match the signing payload inputs");
+        // Ensure the request is signed for the input types.
+        Self::ensure_signed_for(&request, &self.input_types)?;
+
+        // Ensure the fee matches the payload.
+        match (self.fee, fee) {
+            (None, None) => Ok(()),
+            (Some((fee_in_microcredits, deployment_or_execution_id)), Some(fee)) => {
+                // Retrieve the fee request.
+                let request = fee.peek_next()?;
+                // Determine the index of the fee amount, and the input types of the fee function.
+                let (index, input_types) = match (fee.is_fee_public(), fee.is_fee_private()) {
+                    (true, _) => (0, Self::fee_public_input_types().to_vec()),
+                    (_, true) => {
+                        let mut input_types = vec![ValueType::Record(Identifier::from_str("credits")?)];
+                        input_types.extend(Self::fee_public_input_types());
+                        (1, input_types)
+                    }
+                    _ => bail!(
+                        "The fee authorization is not for 'credits.aleo/fee_public' or 'credits.aleo/fee_private'"
+                    ),
+                };
+                // Ensure the fee amount and the deployment or execution ID match the payload.
+                let expected = [
+                    Value::from(Literal::U64(U64::new(fee_in_microcredits))),
+                    Value::from(Literal::Field(deployment_or_execution_id)),
+                ];
+                ensure!(
+                    request.inputs().get(index..) == Some(&expected[..]),
+                    "The fee does not match the signing payload fee"
+                );
+                // Ensure the fee request is signed for its input types.
+                Self::ensure_signed_for(&request, &input_types)
+            }
+            (Some(..), None) => bail!("The signing payload expects a fee authorization"),
+            (None, Some(..)) => bail!("The signing payload does not expect a fee authorization"),
+        }
+    }
+
+    /// Ensures the given request is signed for the given input types.
+    fn ensure_signed_for(request: &Request<N>, input_types: &[ValueType<N>]) -> Result<()> {
+        ensure!(request.input_ids().len() == input_types.len(), "The request has the wrong number of inputs");
+        // Ensure each input ID is of the given input type, as the signature is verified against the input IDs.
+        ensure!(
+            request.input_ids().iter().zip_eq(input_types).all(|(input_id, input_type)| matches!(
+                (input_id, input_type),
+                (InputID::Constant(..), ValueType::Constant(..))
+                    | (InputID::Public(..), ValueType::Public(..))
+                    | (InputID::Private(..), ValueType::Private(..))
+                    | (InputID::Record(..), ValueType::Record(..))
+                    | (InputID::ExternalRecord(..), ValueType::ExternalRecord(..))
+            )),
+            "The request is not signed for the expected input types"
+        );
+        ensure!(request.verify(input_types), "The request signature is invalid");
+        Ok(())
+    }
+
+    /// Returns the input types of `credits.aleo/fee_public`.
+    fn fee_public_input_types() -> [ValueType<N>; 2] {
+        [ValueType::Public(LiteralType::U64.into()), ValueType::Public(LiteralType::Field.into())]
+    }
+
+    /// Returns the signing payload in chunks of at most `chunk_size` bytes.
+    /// Each chunk is prefixed with the payload version, its index, and the number of chunks.
+    pub fn to_chunks(&self, chunk_size: usize) -> Result<Vec<Vec<u8>>> {
+        ensure!(chunk_size > CHUNK_HEADER_SIZE, "The chunk size must exceed {CHUNK_HEADER_SIZE} bytes");
+        // Append the checksum to the payload.
+        let mut bytes = self.to_bytes_le()?;
+        let checksum = Sha256::digest(&bytes);
+        bytes.extend_from_slice(&checksum);
+
+        // Split the payload into chunks.
+        let chunks = bytes.chunks(chunk_size - CHUNK_HEADER_SIZE);
+        let num_chunks = u16::try_from(chunks.len()).map_err(|_| anyhow!("The signing payload has too many chunks"))?;
+        chunks
+            .enumerate()
+            .map(|(index, chunk)| -> Result<Vec<u8>> {
+                let mut bytes = Vec::with_capacity(CHUNK_HEADER_SIZE + chunk.len());
+                SIGNING_PAYLOAD_VERSION.write_le(&mut bytes)?;
+                u16::try_from(index)?.write_le(&mut bytes)?;
+                num_chunks.write_le(&mut bytes)?;
+                bytes.extend_from_slice(chunk);
+                Ok(bytes)
+            })
+            .collect()
+    }
+
+    /// Reassembles the signing payload from the given chunks, in order, and verifies its checksum.
+    pub fn from_chunks<B: AsRef<[u8]>>(chunks: &[B]) -> Result<Self> {
+        ensure!(!chunks.is_empty(), "The signing payload has no chunks");
+        let num_chunks = chunks.len();
+
+        // Concatenate the chunks, after checking each header.
+        let mut bytes = Vec::new();
+        for (expected_index, chunk) in chunks.iter().enumerate() {
+            let mut chunk = chunk.as_ref();
+            ensure!(chunk.len() > CHUNK_HEADER_SIZE, "Chunk {expected_index} of the signing payload is too short");
+            let version = u8::read_le(&mut chunk)?;
+            let index = u16::read_le(&mut chunk)?;
+            let count = u16::read_le(&mut chunk)?;
+            ensure!(version == SIGNING_PAYLOAD_VERSION, "Unsupported signing payload version {version}");
+            ensure!(index as usize == expected_index, "Expected chunk {expected_index}, found chunk {index}");
+            ensure!(count as usize == num_chunks, "Expected {count} chunks in the signing payload, found {num_chunks}");
+            bytes.extend_from_slice(chunk);
+        }
+
+        // Verify the checksum.
+        ensure!(bytes.len() > CHECKSUM_SIZE, "The signing payload is too short");
+        let (payload, checksum) = bytes.split_at(bytes.len() - CHECKSUM_SIZE);
+        ensure!(Sha256::digest(payload).as_slice() == checksum, "The signing payload checksum is invalid");
+
+        // Parse the payload.
+        Self::from_bytes_le(payload)
+    }
+}
+
+impl<N: Network> FromBytes for SigningPayload<N> {
+    /// Reads the signing payload from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != SIGNING_PAYLOAD_VERSION {
+            return Err(error("Invalid signing payload version"));
+        }
+        // Read the request fields.
+        let network_id = u16::read_le(&mut reader)?;
+        let program_id = ProgramID::read_le(&mut reader)?;
+        let function_name = Identifier::read_le(&mut reader)?;
+        // Read the inputs.
+        let num_inputs = u16::read_le(&mut reader)?;
+        let mut inputs = Vec::with_capacity(num_inputs as usize);
+        let mut input_types = Vec::with_capacity(num_inputs as usize);
+        for _ in 0..num_inputs {
+            input_types.push(ValueType::read_le(&mut reader)?);
+            inputs.push(Value::read_le(&mut reader)?);
+        }
+        // Read the fee.
+        let fee = match u8::read_le(&mut reader)? {
+            0 => None,
+            1 => Some((u64::read_le(&mut reader)?, Field::read_le(&mut reader)?)),
+            _ => return Err(error("Invalid signing payload fee")),
+        };
+        Ok(Self { network_id, program_id, function_name, inputs, input_types, fee })
+    }
+}
+
+impl<N: Network> ToBytes for SigningPayload<N> {
+    /// Writes the signing payload to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        SIGNING_PAYLOAD_VERSION.write_le(&mut writer)?;
+        // Write the request fields.
+        self.network_id.write_le(&mut writer)?;
+        self.program_id.write_le(&mut writer)?;
+        self.function_name.write_le(&mut writer)?;
+        // Write the inputs.
+        u16::try_from(self.inputs.len())
+            .map_err(|_| error("Too many signing payload inputs"))?
+            .write_le(&mut writer)?;
+        for (input, input_type) in self.inputs.iter().zip_eq(&self.input_types) {
+            input_type.write_le(&mut writer)?;
+            input.write_le(&mut writer)?;
+        }
+        // Write the fee.
+        match self.fee {
+            None => 0u8.write_le(&mut writer),
+            Some((fee_in_microcredits, deployment_or_execution_id)) => {
+                1u8.write_le(&mut writer)?;
+                fee_in_microcredits.write_le(&mut writer)?;
+                deployment_or_execution_id.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::Address, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the unsigned signing payload for a public transfer of the given amount, with a fee.
+    fn sample_payload(amount: u64, rng: &mut TestRng) -> SigningPayload<CurrentNetwork> {
+        let recipient = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        SigningPayload::new(
+            ProgramID::from_str("credits.aleo").unwrap(),
+            Identifier::from_str("transfer_public").unwrap(),
+            vec![Value::from_str(&recipient.to_string()).unwrap(), Value::from_str(&format!("{amount}u64")).unwrap()],
+            vec![ValueType::from_str("address.public").unwrap(), ValueType::from_str("u64.public").unwrap()],
+            Some((300, Field::rand(rng))),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_signing_payload_chunks() {
+        let rng = &mut TestRng::default();
+        let payload = sample_payload(1000, rng);
+
+        // Ensure the payload round-trips through chunks.
+        let chunks = payload.to_chunks(64).unwrap();
+        assert!(chunks.len() > 1 && chunks.iter().all(|chunk| chunk.len() <= 64));
+        assert_eq!(SigningPayload::from_chunks(&chunks).unwrap(), payload);
+
+        // Ensure a missing, reordered, or tampered chunk is rejected.
+        assert!(SigningPayload::<CurrentNetwork>::from_chunks(&chunks[1..]).is_err());
+        let mut reordered = chunks.clone();
+        reordered.swap(0, 1);
+        assert!(SigningPayload::<CurrentNetwork>::from_chunks(&reordered).is_err());
+        let mut tampered = chunks;
+        *tampered[0].last_mut().unwrap() ^= 1;
+        assert!(SigningPayload::<CurrentNetwork>::from_chunks(&tampered).is_err());
+    }
+
+    #[test]
+    fn test_external_signer() {
+        let rng = &mut TestRng::default();
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // The host builds the payload from the unsigned request and fee, and sends it in chunks.
+        let payload = sample_payload(1000, rng);
+        let chunks = payload.to_chunks(64).unwrap();
+
+        // The device reassembles the payload, displays its fields, and signs it.
+        let received = SigningPayload::<CurrentNetwork>::from_chunks(&chunks).unwrap();
+        assert_eq!(received.function_name().to_string(), "transfer_public");
+        assert_eq!(received.inputs()[1], Value::from_str("1000u64").unwrap());
+        assert_eq!(received.fee(), payload.fee());
+        let (authorization, fee) = received.sign(&private_key, rng).unwrap();
+        let fee = fee.unwrap();
+        assert!(fee.is_fee_public());
+
+        // The host ensures the signed requests match the payload it sent.
+        payload.ensure_matches(&authorization, Some(&fee)).unwrap();
+        assert!(payload.ensure_matches(&authorization, None).is_err());
+
+        // Ensure a request or fee signed for anything else is rejected.
+        let (other_authorization, other_fee) = sample_payload(1001, rng).sign(&private_key, rng).unwrap();
+        assert!(payload.ensure_matches(&other_authorization, Some(&fee)).is_err());
+        assert!(payload.ensure_matches(&authorization, other_fee.as_ref()).is_err());
+        // Ensure a request signed with other input types is rejected.
+        let request = Request::sign(
+            &private_key,
+            *payload.program_id(),
+            *payload.function_name(),
+            payload.inputs().iter(),
+            &[ValueType::from_str("address.public").unwrap(), ValueType::from_str("u64.private").unwrap()],
+            rng,
+        )
+        .unwrap();
+        assert!(payload.ensure_matches(&Authorization::from(request), Some(&fee)).is_err());
+    }
+
+    #[test]
+    fn test_signing_payload_rejects_invalid_inputs() {
+        let rng = &mut TestRng::default();
+        let payload = sample_payload(1000, rng);
+
+        // Ensure the inputs must match the input types.
+        assert!(SigningPayload::new(
+            *payload.program_id(),
+            *payload.function_name(),
+            payload.inputs().to_vec(),
+            payload.input_types()[..1].to_vec(),
+            None,
+        )
+        .is_err());
+    }
+}
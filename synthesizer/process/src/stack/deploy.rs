@@ -14,6 +14,8 @@
 
 use super::*;
 
+use std::time::Instant;
+
 impl<N: Network> Stack<N> {
     /// Deploys the given program ID, if it does not exist.
     #[inline]
@@ -51,13 +53,14 @@ impl<N: Network> Stack<N> {
         Deployment::new(N::EDITION, self.program.clone(), verifying_keys)
     }
 
-    /// Checks each function in the program on the given verifying key and certificate.
+    /// Checks each function in the program on the given verifying key and certificate,
+    /// and returns the statistics of the circuit of each function, in the order of the program functions.
     #[inline]
     pub fn verify_deployment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
         deployment: &Deployment<N>,
         rng: &mut R,
-    ) -> Result<()> {
+    ) -> Result<Vec<FunctionStatistics<N>>> {
         let timer = timer!("Stack::verify_deployment");
 
         // Sanity Checks //
@@ -115,13 +118,16 @@ impl<N: Network> Stack<N> {
             call_stacks.push((function.name(), call_stack, assignments));
         }
 
-        // Verify the certificates.
-        cfg_iter!(call_stacks).zip_eq(deployment.verifying_keys()).try_for_each(
-            |((function_name, call_stack, assignments), (_, (verifying_key, certificate)))| {
+        // Verify the certificates, and collect the statistics of each function.
+        let statistics = cfg_iter!(call_stacks)
+            .zip_eq(deployment.verifying_keys())
+            .map(|((function_name, call_stack, assignments), (_, (verifying_key, certificate)))| {
                 // Synthesize the circuit.
+                let synthesis_start = Instant::now();
                 if let Err(err) = self.execute_function::<A>(call_stack.clone()) {
                     bail!("Failed to synthesize the circuit for '{function_name}': {err}")
                 }
+                let synthesis_elapsed = synthesis_start.elapsed();
                 // Check the certificate.
                 match assignments.read().last() {
                     None => bail!("The assignment for function '{function_name}' is missing in '{program_id}'"),
@@ -130,14 +136,24 @@ impl<N: Network> Stack<N> {
                         if !certificate.verify(&function_name.to_string(), assignment, verifying_key) {
                             bail!("The certificate for function '{function_name}' is invalid in '{program_id}'")
                         }
+                        // Collect the statistics of the function.
+                        Ok(FunctionStatistics {
+                            function_name: **function_name,
+                            num_public: assignment.num_public(),
+                            num_private: assignment.num_private(),
+                            num_constraints: assignment.num_constraints(),
+                            num_nonzeros: assignment.num_nonzeros(),
+                            synthesis_elapsed,
+                            verifying_key_size: verifying_key.to_bytes_le()?.len(),
+                            certificate_size: certificate.to_bytes_le()?.len(),
+                        })
                     }
-                };
-                Ok(())
-            },
-        )?;
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
 
         finish!(timer);
 
-        Ok(())
+        Ok(statistics)
     }
 }
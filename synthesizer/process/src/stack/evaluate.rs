@@ -209,7 +209,7 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
             .collect::<Vec<_>>();
 
         // Compute the response.
-        Response::new(
+        let response = Response::new(
             request.network_id(),
             self.program.id(),
             function.name(),
@@ -219,6 +219,39 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
             outputs,
             &function.output_types(),
             &output_registers,
-        )
+        )?;
+
+        // If this function has the finalize command, then construct the finalize inputs.
+        let finalize = match function.finalize_command() {
+            Some(command) => Some(
+                command
+                    .operands()
+                    .iter()
+                    .map(|operand| match registers.load(self, operand)? {
+                        // Ensure the value is a plaintext.
+                        // See `RegisterTypes::initialize_function_types()` for the same set of checks.
+                        value @ Value::Plaintext(..) => Ok(value),
+                        Value::Record(..) => {
+                            bail!(
+                                "'{}/{}' attempts to pass a 'record' into 'finalize'",
+                                self.program_id(),
+                                function.name()
+                            )
+                        }
+                    })
+                    .collect::<Result<Vec<_>>>()?,
+            ),
+            None => None,
+        };
+
+        // Construct the transition, and add it to the authorization.
+        // Note: The transition contains no proof, and is used to finalize the evaluation without executing it.
+        if let CallStack::Evaluate(authorization) = registers.call_stack() {
+            let transition =
+                Transition::from(&request, &response, finalize, &function.output_types(), &output_registers)?;
+            authorization.insert_transition(transition);
+        }
+
+        Ok(response)
     }
 }
@@ -64,7 +64,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         lap!(timer, "Store the inputs");
 
         // Execute the instructions.
-        for instruction in closure.instructions() {
+        for (index, instruction) in closure.instructions().iter().enumerate() {
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
                 // If the evaluation fails, bail and return the error.
@@ -73,7 +73,9 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 }
             }
             // Execute the instruction.
-            instruction.execute(self, &mut registers)?;
+            self.scope_instruction::<A, _>(closure.name(), index, instruction, || {
+                instruction.execute(self, &mut registers)
+            })?;
         }
         lap!(timer, "Execute the instructions");
 
@@ -135,6 +137,11 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 
         // Retrieve the next request.
         let console_request = call_stack.pop()?;
+        let _span = instrument!(
+            "synthesize_function",
+            program = %console_request.program_id(),
+            function = %console_request.function_name()
+        );
 
         // Ensure the network ID matches.
         ensure!(
@@ -219,7 +226,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         let mut contains_function_call = false;
 
         // Execute the instructions.
-        for instruction in function.instructions() {
+        for (index, instruction) in function.instructions().iter().enumerate() {
             // If the circuit is in execute mode, then evaluate the instructions.
             if let CallStack::Execute(..) = registers.call_stack() {
                 // Evaluate the instruction.
@@ -236,12 +243,12 @@ impl<N: Network> StackExecute<N> for Stack<N> {
             }
 
             // Execute the instruction.
-            let result = match instruction {
+            let result = self.scope_instruction::<A, _>(function.name(), index, instruction, || match instruction {
                 // If the instruction is a `call` instruction, we need to handle it separately.
                 Instruction::Call(call) => CallTrait::execute(call, self, &mut registers),
                 // Otherwise, execute the instruction normally.
                 _ => instruction.execute(self, &mut registers),
-            };
+            });
             // If the execution fails, bail and return the error.
             if let Err(error) = result {
                 bail!("Failed to execute instruction ({instruction}): {error}");
@@ -412,13 +419,19 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         // If the circuit is in `Execute` mode, then ensure the circuit is satisfied.
         if let CallStack::Execute(..) = registers.call_stack() {
             // If the circuit is empty or not satisfied, then throw an error.
-            ensure!(
-                A::num_constraints() > 0 && A::is_satisfied(),
-                "'{}/{}' is not satisfied on the given inputs ({} constraints).",
-                self.program.id(),
-                function.name(),
-                A::num_constraints()
-            );
+            if A::num_constraints() == 0 || !A::is_satisfied() {
+                // Report the first unsatisfied constraint, if there is one.
+                let reason = match A::first_unsatisfied_constraint() {
+                    Some(constraint) => format!(" {constraint}."),
+                    None => String::new(),
+                };
+                bail!(
+                    "'{}/{}' is not satisfied on the given inputs ({} constraints).{reason}",
+                    self.program.id(),
+                    function.name(),
+                    A::num_constraints()
+                );
+            }
         }
 
         // Eject the circuit assignment and reset the circuit.
@@ -486,6 +499,27 @@ impl<N: Network> StackExecute<N> for Stack<N> {
 }
 
 impl<N: Network> Stack<N> {
+    /// Runs the given logic for an instruction. In constraint graph mode, the constraints it enforces
+    /// are labeled with the instruction, as `program/function[index]: instruction`.
+    fn scope_instruction<A: circuit::Aleo<Network = N>, T>(
+        &self,
+        name: &Identifier<N>,
+        index: usize,
+        instruction: &Instruction<N>,
+        logic: impl FnOnce() -> T,
+    ) -> T {
+        // Only label the constraints in constraint graph mode, to avoid allocating the label when proving.
+        if !A::is_constraint_graph_mode() {
+            return logic();
+        }
+        // Note: Scope names cannot contain periods, so they are replaced with `·`.
+        let label = format!("{}/{name}[{index}]: {instruction}", self.program.id()).replace('.', "·");
+        A::push_scope(label.clone());
+        let output = logic();
+        A::pop_scope(label);
+        output
+    }
+
     /// Prints the current state of the circuit.
     #[cfg(debug_assertions)]
     pub(crate) fn log_circuit<A: circuit::Aleo<Network = N>, S: Into<String>>(scope: S) {
@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Stack<N> {
+    /// Returns the constraint graph of the given function, synthesized on sampled inputs.
+    /// Each constraint is scoped by the instruction that produced it, as `program/function[index]: instruction`,
+    /// with periods replaced by `·`. Constraints enforced outside of an instruction (e.g. to verify
+    /// the request and response) are unscoped.
+    #[inline]
+    pub fn to_constraint_graph<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<circuit::environment::ConstraintGraph> {
+        // Retrieve the program ID.
+        let program_id = self.program_id();
+        // Retrieve the function input types.
+        let input_types = self.get_function(function_name)?.input_types();
+
+        // Initialize a burner private key.
+        let burner_private_key = PrivateKey::new(rng)?;
+        // Compute the burner address.
+        let burner_address = Address::try_from(&burner_private_key)?;
+        // Sample the inputs.
+        let inputs = input_types
+            .iter()
+            .map(|input_type| match input_type {
+                ValueType::ExternalRecord(locator) => {
+                    // Retrieve the external stack.
+                    let stack = self.get_external_stack(locator.program_id())?;
+                    // Sample the input.
+                    stack.sample_value(&burner_address, &ValueType::Record(*locator.resource()), rng)
+                }
+                _ => self.sample_value(&burner_address, input_type, rng),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Compute the request, with a burner private key.
+        let request =
+            Request::sign(&burner_private_key, *program_id, *function_name, inputs.into_iter(), &input_types, rng)?;
+        // Initialize the call stack, which synthesizes the circuit without requiring it to be satisfied.
+        let call_stack = CallStack::CheckDeployment(vec![request], burner_private_key, Default::default());
+
+        // Synthesize the circuit in constraint graph mode.
+        A::set_constraint_graph_mode(true);
+        let result = self.execute_function::<A>(call_stack);
+        A::set_constraint_graph_mode(false);
+        result?;
+
+        // Return the constraint graph of the function, which is the last circuit to be ejected.
+        A::take_constraint_graph()
+            .ok_or_else(|| anyhow!("Missing the constraint graph for '{program_id}/{function_name}'"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Process;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_to_constraint_graph() {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program graph.aleo;
+
+function compute:
+    input r0 as u8.private;
+    input r1 as field.private;
+    add.w r0 r0 into r2;
+    mul r1 r1 into r3;
+    mul r1 r1 into r4;
+    output r2 as u8.private;
+    output r4 as field.private;
+",
+        )
+        .unwrap();
+        let mut process = Process::<CurrentNetwork>::load().unwrap();
+        process.add_program(&program).unwrap();
+        let stack = process.get_stack(program.id()).unwrap();
+
+        let rng = &mut TestRng::default();
+        let function_name = Identifier::from_str("compute").unwrap();
+        let graph = stack.to_constraint_graph::<CurrentAleo, _>(&function_name, rng).unwrap();
+
+        // Ensure the constraint graph mode is disabled afterwards.
+        assert!(!<CurrentAleo as circuit::Environment>::is_constraint_graph_mode());
+
+        // Ensure each instruction is labeled.
+        let scopes = graph.constraints_per_scope();
+        let count_of = |label: &str| scopes.iter().find(|(scope, _)| scope.ends_with(label)).map(|(_, count)| *count);
+        assert!(count_of("compute[0]: add·w r0 r0 into r2;").is_some());
+        assert_eq!(count_of("compute[1]: mul r1 r1 into r3;"), Some(1));
+        assert_eq!(count_of("compute[2]: mul r1 r1 into r4;"), Some(1));
+
+        // Ensure the duplicated multiplication is flagged as redundant.
+        let redundant = graph.redundant_constraints();
+        assert!(redundant.iter().any(|(first, duplicate)| {
+            graph.constraints()[*first].scope.ends_with("compute[1]: mul r1 r1 into r3;")
+                && graph.constraints()[*duplicate].scope.ends_with("compute[2]: mul r1 r1 into r4;")
+        }));
+
+        // Ensure the graph exports.
+        assert!(graph.to_dot().starts_with("digraph circuit {"));
+        assert_eq!(graph.to_json()["constraints"].as_array().unwrap().len(), graph.constraints().len());
+    }
+}
@@ -25,6 +25,7 @@ impl<N: Network> Stack<N> {
             register_types: Default::default(),
             finalize_types: Default::default(),
             universal_srs: process.universal_srs().clone(),
+            precompute_proving_keys: process.precompute_proving_keys.clone(),
             proving_keys: Default::default(),
             verifying_keys: Default::default(),
         };
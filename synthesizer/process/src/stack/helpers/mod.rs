@@ -14,6 +14,7 @@
 
 use super::*;
 
+mod constraint_graph;
 mod initialize;
 mod matches;
 mod sample;
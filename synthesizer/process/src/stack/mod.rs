@@ -36,7 +36,7 @@ mod evaluate;
 mod execute;
 mod helpers;
 
-use crate::{traits::*, CallMetrics, Process, Trace};
+use crate::{traits::*, CallMetrics, FunctionStatistics, Process, Trace};
 use console::{
     account::{Address, PrivateKey},
     network::prelude::*,
@@ -62,12 +62,15 @@ use console::{
 };
 use ledger_block::{Deployment, Transition};
 use synthesizer_program::{traits::*, CallOperator, Closure, Function, Instruction, Operand, Program};
-use synthesizer_snark::{Certificate, ProvingKey, UniversalSRS, VerifyingKey};
+use synthesizer_snark::{instrument, Certificate, ProvingKey, UniversalSRS, VerifyingKey};
 
 use aleo_std::prelude::{finish, lap, timer};
 use indexmap::IndexMap;
 use parking_lot::RwLock;
-use std::sync::Arc;
+use std::sync::{
+    atomic::{self, AtomicBool},
+    Arc,
+};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -168,6 +171,8 @@ pub struct Stack<N: Network> {
     finalize_types: IndexMap<Identifier<N>, FinalizeTypes<N>>,
     /// The universal SRS.
     universal_srs: Arc<UniversalSRS<N>>,
+    /// Whether the proving keys precompute the window tables for their committer keys.
+    precompute_proving_keys: Arc<AtomicBool>,
     /// The mapping of function name to proving key.
     proving_keys: Arc<RwLock<IndexMap<Identifier<N>, ProvingKey<N>>>>,
     /// The mapping of function name to verifying key.
@@ -344,11 +349,22 @@ impl<N: Network> Stack<N> {
             "Function '{function_name}' does not exist in program '{}'.",
             self.program.id()
         );
+        // Precompute the window tables of the proving key, if enabled.
+        let proving_key = match self.precompute_proving_keys.load(atomic::Ordering::Relaxed) {
+            true => proving_key.precompute(),
+            false => proving_key,
+        };
         // Insert the proving key.
         self.proving_keys.write().insert(*function_name, proving_key);
         Ok(())
     }
 
+    /// Precomputes the window tables of the proving keys in the stack.
+    #[inline]
+    pub(crate) fn precompute_proving_keys(&self) {
+        self.proving_keys.write().values_mut().for_each(|proving_key| *proving_key = proving_key.precompute());
+    }
+
     /// Inserts the given verifying key for the given function name.
     #[inline]
     pub fn insert_verifying_key(&self, function_name: &Identifier<N>, verifying_key: VerifyingKey<N>) -> Result<()> {
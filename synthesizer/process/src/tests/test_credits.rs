@@ -227,6 +227,8 @@ fn execute_function(
 
     // Prove the execution.
     let execution = trace.prove_execution::<CurrentAleo, _>(function, rng)?;
+    // Ensure the execution size is computed without the proof.
+    assert_eq!(trace.execution_size_in_bytes()?, execution.size_in_bytes()?);
 
     // Finalize the execution.
     let block_height = block_height.unwrap_or(1);
@@ -15,7 +15,9 @@
 use crate::{
     traits::{StackEvaluate, StackExecute},
     CallStack,
+    ExecutionVerification,
     Process,
+    ProofBatch,
     Trace,
 };
 use circuit::{network::AleoV0, Aleo};
@@ -25,7 +27,7 @@ use console::{
     program::{Identifier, Literal, Plaintext, ProgramID, Record, Value},
     types::{Field, U64},
 };
-use ledger_block::Fee;
+use ledger_block::{Deployment, Execution, Fee};
 use ledger_query::Query;
 use ledger_store::{
     helpers::memory::{BlockMemory, FinalizeMemory},
@@ -34,8 +36,8 @@ use ledger_store::{
     FinalizeStorage,
     FinalizeStore,
 };
-use synthesizer_program::{FinalizeGlobalState, FinalizeStoreTrait, Program};
-use synthesizer_snark::UniversalSRS;
+use synthesizer_program::{FinalizeGlobalState, FinalizeStoreTrait, OptimizerConfig, Program};
+use synthesizer_snark::{UniversalSRS, VerifyingKey};
 
 use indexmap::IndexMap;
 use parking_lot::RwLock;
@@ -540,6 +542,73 @@ fn test_process_execute_transfer_public() {
     // assert_eq!(79386, CurrentAleo::num_gates());
 }
 
+#[test]
+fn test_process_execute_with_external_proof() {
+    // Initialize a new program.
+    let program = Program::<CurrentNetwork>::credits().unwrap();
+
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+    // Declare the input value.
+    let r0 = Value::<CurrentNetwork>::from_str(&format!("{caller}")).unwrap();
+    let r1 = Value::<CurrentNetwork>::from_str("1_000_000_u64").unwrap();
+
+    // Construct the process.
+    let process = Process::load().unwrap();
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+    // Authorize the function call.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key,
+            program.id(),
+            Identifier::from_str("transfer_public_to_private").unwrap(),
+            [r0, r1].iter(),
+            rng,
+        )
+        .unwrap();
+
+    // Execute the request, and prepare the trace.
+    let (_, mut trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+    trace.prepare(Query::from(block_store)).unwrap();
+
+    // Export the proving tasks in the interchange format.
+    let proving_tasks = trace.to_proving_tasks::<CurrentAleo>().unwrap();
+    let exported = proving_tasks
+        .iter()
+        .map(|(proving_key, assignments)| {
+            (proving_key.clone(), assignments.iter().map(|assignment| assignment.to_bytes_le().unwrap()).collect())
+        })
+        .collect::<Vec<(_, Vec<_>)>>();
+
+    // Prove the exported tasks, as an external prover would.
+    let imported = exported
+        .into_iter()
+        .map(|(proving_key, assignments)| {
+            let assignments = assignments.iter().map(|bytes| circuit::Assignment::read_le(&bytes[..]).unwrap());
+            (proving_key, assignments.collect())
+        })
+        .collect::<Vec<_>>();
+    let proof = synthesizer_snark::ProvingKey::prove_batch(
+        "credits.aleo/transfer_public_to_private",
+        &imported,
+        synthesizer_snark::ProvingMode::Standard,
+        rng,
+    )
+    .unwrap();
+
+    // Import the proof, and verify the execution.
+    let proof = synthesizer_snark::Proof::read_le(&proof.to_bytes_le().unwrap()[..]).unwrap();
+    let execution = trace.execution_with_proof(proof).unwrap();
+    process.verify_execution(&execution).unwrap();
+    // Ensure the trace cannot be imported as a fee.
+    assert!(trace.fee_with_proof(execution.proof().unwrap().clone()).is_err());
+}
+
 #[test]
 fn test_process_circuit_key() {
     // Initialize a new program.
@@ -1283,7 +1352,43 @@ finalize compute:
     let execution = trace.prove_execution::<CurrentAleo, _>("testing", rng).unwrap();
 
     // Verify the execution.
-    process.verify_execution(&execution).unwrap();
+    let report = process.verify_execution(&execution).unwrap();
+    assert!(report.is_valid());
+    assert_eq!(report.is_valid_proof, Some(true));
+    assert_eq!(report.transitions.len(), execution.len());
+    for (transition_report, transition) in report.transitions.iter().zip(execution.transitions()) {
+        assert_eq!(transition_report.transition_id, *transition.id());
+        assert!(transition_report.is_valid());
+    }
+
+    // Ensure a deferred proof is reported as pending, until the batch is verified.
+    let batch = ProofBatch::new();
+    let report = process.verify_execution_deferred(&execution, &batch).unwrap();
+    assert!(report.is_proof_pending);
+    assert!(!report.is_valid());
+    assert_eq!(report.is_valid_proof, None);
+    assert_eq!(batch.len(), 1);
+    process.verify_proof_batch(batch).unwrap();
+
+    // Ensure an execution without a proof is reported as invalid, after its transitions are checked.
+    let execution_without_proof =
+        Execution::from(execution.transitions().cloned(), execution.global_state_root(), None).unwrap();
+    let error = process.verify_execution(&execution_without_proof).unwrap_err();
+    let report = error.downcast_ref::<ExecutionVerification<CurrentNetwork>>().unwrap();
+    assert!(!report.is_valid());
+    assert!(report.transitions.iter().all(|transition_report| transition_report.is_valid()));
+    assert_eq!(report.is_valid_proof, Some(false));
+
+    // Ensure the public inputs of the transition can be extracted, for an external verifier.
+    let transition = execution.peek().unwrap();
+    let verifying_key = process.get_verifying_key(*program.id(), function_name).unwrap();
+    let verifier_inputs = process.get_transition_verifier_inputs(&execution, transition.id(), &verifying_key).unwrap();
+    let (tpk_x, tpk_y) = transition.tpk().to_xy_coordinates();
+    assert_eq!(verifier_inputs.inputs[..4], [*Field::<CurrentNetwork>::one(), *tpk_x, *tpk_y, **transition.tcm()]);
+    // Ensure the execution proof verifies with the batch, as in `Process::verify_execution`.
+    // Note: The transition does not spend a record, so the batch does not contain an inclusion circuit.
+    assert_eq!(verifier_inputs.batch, vec![(verifying_key, vec![verifier_inputs.inputs.clone()])]);
+    assert!(VerifyingKey::verify_batch("testing", verifier_inputs.batch, execution.proof().unwrap()));
 
     // Now, finalize the execution.
     process.finalize_execution(sample_finalize_state(1), &finalize_store, &execution, None).unwrap();
@@ -2105,6 +2210,8 @@ fn test_complex_execution_order() {
     trace.prepare(Query::from(block_store)).unwrap();
     // Prove the execution.
     let execution = trace.prove_execution::<CurrentAleo, _>("four", rng).unwrap();
+    // Ensure the execution size is computed without the proof.
+    assert_eq!(trace.execution_size_in_bytes().unwrap(), execution.size_in_bytes().unwrap());
 
     // Verify the execution.
     process.verify_execution(&execution).unwrap();
@@ -2334,8 +2441,14 @@ fn test_process_deploy_credits_program() {
     let rng = &mut TestRng::default();
 
     // Initialize an empty process without the `credits` program.
-    let empty_process =
-        Process { universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()), stacks: IndexMap::new() };
+    let empty_process = Process {
+        universal_srs: Arc::new(UniversalSRS::<CurrentNetwork>::load().unwrap()),
+        stacks: IndexMap::new(),
+        thread_pools: Default::default(),
+        memory_limit: None,
+        precompute_proving_keys: Default::default(),
+        optimizer_config: OptimizerConfig::disabled(),
+    };
 
     // Construct the process.
     let process = Process::load().unwrap();
@@ -2376,6 +2489,118 @@ function compute:
     assert!(process.verify_deployment::<CurrentAleo, _>(&deployment, rng).is_err());
 }
 
+#[test]
+fn test_process_deploy_optimized_program() {
+    let rng = &mut TestRng::default();
+
+    // Initialize a program with a constant, a redundant cast, and a dead register.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program optimized.aleo;
+
+function compute:
+    input r0 as u32.public;
+    add 1u32 2u32 into r1;
+    add r0 r1 into r2;
+    cast r2 into r3 as u32;
+    mul.w r0 r0 into r4;
+    output r3 as u32.public;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+
+    // Ensure the program is deployed as it is written, by default.
+    let unoptimized = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    assert_eq!(unoptimized.program(), &program);
+    process.verify_deployment::<CurrentAleo, _>(&unoptimized, rng).unwrap();
+
+    // Ensure the program is optimized before it is synthesized, if the optimizer is enabled.
+    process.set_optimizer_config(OptimizerConfig::default());
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    assert_eq!(deployment.program(), &program.optimize().unwrap());
+    assert_ne!(deployment.program(), &program);
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    // Ensure the deployment matches the program in an audit.
+    assert!(process.audit_deployment::<CurrentAleo, _>(&program, &deployment, rng).unwrap().is_match());
+
+    // Ensure the optimized circuit has fewer constraints.
+    let num_constraints =
+        |deployment: &Deployment<CurrentNetwork>| deployment.verifying_keys()[0].1 .0.circuit_info.num_constraints;
+    assert!(num_constraints(&deployment) < num_constraints(&unoptimized));
+}
+
+#[test]
+fn test_process_deploy_common_subexpressions() {
+    let rng = &mut TestRng::default();
+
+    // Initialize a program with a repeated hash and multiplication.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program subexpressions.aleo;
+
+function repeated:
+    input r0 as field.private;
+    hash.bhp256 r0 into r1 as field;
+    hash.bhp256 r0 into r2 as field;
+    mul r1 r2 into r3;
+    mul r1 r2 into r4;
+    add r3 r4 into r5;
+    output r5 as field.private;
+
+function single:
+    input r0 as field.private;
+    hash.bhp256 r0 into r1 as field;
+    mul r1 r1 into r2;
+    add r2 r2 into r3;
+    output r3 as field.private;",
+    )
+    .unwrap();
+
+    // Construct the process.
+    let mut process = Process::load().unwrap();
+    let num_constraints = |deployment: &Deployment<CurrentNetwork>, function_name: &str| {
+        let function_name = Identifier::from_str(function_name).unwrap();
+        let (_, (verifying_key, _)) =
+            deployment.verifying_keys().iter().find(|(name, _)| name == &function_name).unwrap();
+        verifying_key.circuit_info.num_constraints
+    };
+
+    // Ensure the program is deployed as it is written, by default.
+    let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    assert_eq!(deployment.program(), &program);
+    assert!(num_constraints(&deployment, "repeated") > num_constraints(&deployment, "single"));
+
+    // Ensure the common subexpressions are eliminated, if the pass is enabled.
+    process.set_optimizer_config(OptimizerConfig { eliminate_common_subexpressions: true, ..Default::default() });
+    let optimized = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+    assert_ne!(optimized.program(), &program);
+    process.verify_deployment::<CurrentAleo, _>(&optimized, rng).unwrap();
+    assert_eq!(num_constraints(&optimized, "repeated"), num_constraints(&optimized, "single"));
+
+    // Add the program with the verifying keys of the default deployment.
+    process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+    process.load_deployment(&deployment).unwrap();
+
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+    // Execute the function with the repeated instructions.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let inputs = [Value::<CurrentNetwork>::from_str("5field").unwrap()];
+    let authorization =
+        process.authorize::<CurrentAleo, _>(&private_key, program.id(), "repeated", inputs.iter(), rng).unwrap();
+    let expected = process.evaluate::<CurrentAleo>(authorization.replicate()).unwrap();
+    let (response, mut trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+    assert_eq!(response.outputs(), expected.outputs());
+
+    // Ensure the execution verifies against the deployed verifying key.
+    trace.prepare(Query::from(block_store)).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("subexpressions", rng).unwrap();
+    process.verify_execution(&execution).unwrap();
+}
+
 #[test]
 fn test_process_zero_input_zero_output_executions() {
     // Initialize the RNG.
@@ -2455,3 +2680,168 @@ function {function_name}:
     assert_ne!(execution_1.peek().unwrap().id(), execution_2.peek().unwrap().id());
     assert_ne!(execution_1.to_execution_id().unwrap(), execution_2.to_execution_id().unwrap());
 }
+
+#[test]
+fn test_process_load_keys_with() {
+    use std::future::Future;
+
+    // A waker that does nothing, as the key futures below are always ready.
+    struct NoopWaker;
+    impl std::task::Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program load_keys.aleo;
+
+function add:
+    input r0 as u32.public;
+    input r1 as u32.private;
+    add r0 r1 into r2;
+    output r2 as u32.private;",
+    )
+    .unwrap();
+    let function_name = Identifier::from_str("add").unwrap();
+
+    // Synthesize the keys in the first process.
+    let process = crate::test_helpers::sample_process(&program);
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, &mut TestRng::default()).unwrap();
+    let proving_key = process.get_proving_key(program.id(), function_name).unwrap().to_bytes_le().unwrap();
+    let verifying_key = process.get_verifying_key(program.id(), function_name).unwrap().to_bytes_le().unwrap();
+
+    // Add the program to a second process, without its keys.
+    let mut process = Process::load().unwrap();
+    process.add_program(&program).unwrap();
+    assert!(process.get_proving_key(program.id(), function_name).is_err());
+
+    // Load the keys through the callback.
+    let future = process.load_keys_with(program.id(), |name| {
+        assert_eq!(name, function_name);
+        std::future::ready(Ok((proving_key.clone(), verifying_key.clone())))
+    });
+    let mut future = std::pin::pin!(future);
+    let waker = std::task::Waker::from(Arc::new(NoopWaker));
+    let mut context = std::task::Context::from_waker(&waker);
+    assert!(matches!(future.as_mut().poll(&mut context), std::task::Poll::Ready(Ok(()))));
+
+    // Ensure the keys are now present.
+    assert_eq!(process.get_proving_key(program.id(), function_name).unwrap().to_bytes_le().unwrap(), proving_key);
+    assert_eq!(process.get_verifying_key(program.id(), function_name).unwrap().to_bytes_le().unwrap(), verifying_key);
+}
+
+#[test]
+fn test_process_execute_memory_limit() {
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program memory_limit.aleo;
+
+function foo:
+    input r0 as field.public;
+    input r1 as field.private;
+    hash.bhp256 r0 into r2 as field;
+    add r1 r2 into r3;
+    output r3 as field.private;
+",
+    )
+    .unwrap();
+
+    // Declare the function name and inputs.
+    let function_name = Identifier::from_str("foo").unwrap();
+    let inputs = [
+        Value::<CurrentNetwork>::Plaintext(Plaintext::from_str("2field").unwrap()),
+        Value::Plaintext(Plaintext::from_str("3field").unwrap()),
+    ];
+
+    // Construct the process.
+    let mut process = crate::test_helpers::sample_process(&program);
+
+    // Compute the authorization.
+    let rng = &mut TestRng::default();
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+
+    // Ensure the execution reports its peak memory usage.
+    let (_, trace) = process.execute::<CurrentAleo>(authorization.replicate()).unwrap();
+    let peak_memory_usage = trace.peak_memory_usage();
+    assert!(peak_memory_usage > 0);
+
+    // Ensure the execution fails gracefully if it exceeds the memory limit.
+    process.set_memory_limit(Some(1024));
+    let error = process.execute::<CurrentAleo>(authorization.replicate()).unwrap_err();
+    assert!(error.to_string().contains("memory limit"), "{error}");
+
+    // Ensure the execution succeeds within the memory limit.
+    process.set_memory_limit(Some(peak_memory_usage));
+    let (_, mut trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+    assert_eq!(trace.peak_memory_usage(), peak_memory_usage);
+
+    // Ensure the proof fails gracefully if the batch of circuits exceeds the memory limit.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    trace.prepare(Query::from(block_store)).unwrap();
+    trace.set_memory_limit(Some(1024));
+    let error = trace.prove_execution::<CurrentAleo, _>("memory_limit.aleo/foo", rng).unwrap_err();
+    assert!(error.to_string().contains("Proving exceeded the memory limit"), "{error}");
+
+    // Ensure the deployment fails gracefully if it exceeds the memory limit.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+    process.set_memory_limit(Some(1024));
+    let error = process.deploy::<CurrentAleo, _>(&program, rng).unwrap_err();
+    assert!(error.to_string().contains("Synthesis exceeded the memory limit"), "{error}");
+}
+
+#[test]
+fn test_process_execute_precompute_proving_keys() {
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program precompute.aleo;
+
+function foo:
+    input r0 as field.public;
+    input r1 as field.private;
+    hash.bhp256 r0 into r2 as field;
+    add r1 r2 into r3;
+    output r3 as field.private;
+",
+    )
+    .unwrap();
+
+    // Declare the function name and inputs.
+    let function_name = Identifier::from_str("foo").unwrap();
+    let inputs = [
+        Value::<CurrentNetwork>::Plaintext(Plaintext::from_str("2field").unwrap()),
+        Value::Plaintext(Plaintext::from_str("3field").unwrap()),
+    ];
+
+    // Construct the process, and synthesize the keys without precomputation.
+    let rng = &mut TestRng::default();
+    let process = crate::test_helpers::sample_process(&program);
+    process.synthesize_key::<CurrentAleo, _>(program.id(), &function_name, rng).unwrap();
+    let proving_key = process.get_proving_key(program.id(), function_name).unwrap();
+    assert!(proving_key.precomputed_powers_of_beta_g.is_none());
+
+    // Enable precomputation, and ensure the loaded proving key is precomputed.
+    process.set_precompute_proving_keys(true);
+    assert!(process.precompute_proving_keys());
+    let precomputed = process.get_proving_key(program.id(), function_name).unwrap();
+    assert!(precomputed.precomputed_powers_of_beta_g.is_some());
+    assert_eq!(*precomputed, *proving_key);
+    assert_eq!(precomputed.to_bytes_le().unwrap(), proving_key.to_bytes_le().unwrap());
+
+    // Ensure proving keys inserted afterwards are precomputed.
+    process.insert_proving_key(program.id(), &function_name, proving_key).unwrap();
+    assert!(process.get_proving_key(program.id(), function_name).unwrap().precomputed_powers_of_beta_g.is_some());
+
+    // Execute the function, and ensure the proof verifies.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let authorization = process
+        .authorize::<CurrentAleo, _>(&caller_private_key, program.id(), function_name, inputs.iter(), rng)
+        .unwrap();
+    let (_, mut trace) = process.execute::<CurrentAleo>(authorization).unwrap();
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+    trace.prepare(Query::from(block_store)).unwrap();
+    let execution = trace.prove_execution::<CurrentAleo, _>("precompute", rng).unwrap();
+    process.verify_execution(&execution).unwrap();
+}
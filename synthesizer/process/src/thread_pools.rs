@@ -0,0 +1,256 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A handler that runs on each thread of a pool when it starts, with the index of the thread.
+pub type StartHandler = Arc<dyn Fn(usize) + Send + Sync>;
+
+/// The kinds of work that may be isolated in a dedicated thread pool.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ThreadPoolKind {
+    /// Executing functions and synthesizing circuit keys.
+    Synthesis,
+    /// Computing proofs.
+    Proving,
+    /// Verifying deployments, executions, and fees.
+    Verification,
+}
+
+impl ThreadPoolKind {
+    /// The kinds of thread pools.
+    pub const ALL: [Self; 3] = [Self::Synthesis, Self::Proving, Self::Verification];
+
+    /// Returns the index of the kind.
+    const fn index(&self) -> usize {
+        match self {
+            Self::Synthesis => 0,
+            Self::Proving => 1,
+            Self::Verification => 2,
+        }
+    }
+}
+
+impl Display for ThreadPoolKind {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Synthesis => write!(f, "synthesis"),
+            Self::Proving => write!(f, "proving"),
+            Self::Verification => write!(f, "verification"),
+        }
+    }
+}
+
+/// The settings of a dedicated thread pool.
+#[derive(Clone, Default)]
+struct PoolSettings {
+    /// The number of threads, or `0` for the number of logical CPUs.
+    num_threads: usize,
+    /// The handler to run on each thread when it starts.
+    start_handler: Option<StartHandler>,
+}
+
+/// The configuration of the thread pools of a process.
+///
+/// By default, all work runs in the global rayon pool. Each kind of work may instead be given a dedicated pool,
+/// e.g. so that block verification remains responsive while proofs are computed in the background.
+#[derive(Clone, Default)]
+pub struct ThreadPoolConfig {
+    /// The settings of the dedicated pools, indexed by kind.
+    pools: [Option<PoolSettings>; 3],
+}
+
+impl ThreadPoolConfig {
+    /// Initializes a new configuration, in which all work runs in the global rayon pool.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Dedicates a pool with the given number of threads (or `0` for the number of logical CPUs) to the given kind of work.
+    pub fn with_pool(mut self, kind: ThreadPoolKind, num_threads: usize) -> Self {
+        self.pools[kind.index()].get_or_insert_with(Default::default).num_threads = num_threads;
+        self
+    }
+
+    /// Sets a handler that runs on each thread of the dedicated pool for the given kind of work when it starts,
+    /// e.g. to lower the OS priority of the proving threads. If the kind has no dedicated pool, one is created.
+    pub fn with_start_handler(mut self, kind: ThreadPoolKind, handler: impl Fn(usize) + Send + Sync + 'static) -> Self {
+        self.pools[kind.index()].get_or_insert_with(Default::default).start_handler = Some(Arc::new(handler));
+        self
+    }
+}
+
+/// The thread pools of a process.
+#[derive(Clone, Default)]
+pub struct ThreadPools {
+    /// The dedicated pools, indexed by kind.
+    #[cfg(feature = "rayon")]
+    pools: [Option<Arc<rayon::ThreadPool>>; 3],
+}
+
+impl ThreadPools {
+    /// Initializes the thread pools for the given configuration.
+    /// Note: Without the `rayon` feature, all work runs in the current thread, and the configuration is ignored.
+    pub fn new(config: ThreadPoolConfig) -> Result<Self> {
+        #[cfg(feature = "rayon")]
+        {
+            let mut pools: [Option<Arc<rayon::ThreadPool>>; 3] = Default::default();
+            for kind in ThreadPoolKind::ALL {
+                if let Some(settings) = &config.pools[kind.index()] {
+                    let mut builder = rayon::ThreadPoolBuilder::new()
+                        .num_threads(settings.num_threads)
+                        .thread_name(move |index| format!("{kind}-{index}"));
+                    if let Some(handler) = settings.start_handler.clone() {
+                        builder = builder.start_handler(move |index| handler(index));
+                    }
+                    let pool = builder.build().map_err(|e| anyhow!("Failed to build the {kind} thread pool - {e}"))?;
+                    pools[kind.index()] = Some(Arc::new(pool));
+                }
+            }
+            Ok(Self { pools })
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            let _ = config;
+            Ok(Self::default())
+        }
+    }
+
+    /// Returns `true` if the given kind of work has a dedicated pool.
+    pub fn is_configured(&self, kind: ThreadPoolKind) -> bool {
+        #[cfg(feature = "rayon")]
+        {
+            self.pools[kind.index()].is_some()
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            let _ = kind;
+            false
+        }
+    }
+
+    /// Returns the number of threads available to the given kind of work.
+    pub fn num_threads(&self, kind: ThreadPoolKind) -> usize {
+        #[cfg(feature = "rayon")]
+        {
+            match &self.pools[kind.index()] {
+                Some(pool) => pool.current_num_threads(),
+                None => rayon::current_num_threads(),
+            }
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            let _ = kind;
+            1
+        }
+    }
+
+    /// Runs the given operation in the pool for the given kind of work, if it has a dedicated pool,
+    /// and in the current thread otherwise.
+    pub fn install<T: Send>(&self, kind: ThreadPoolKind, operation: impl FnOnce() -> T + Send) -> T {
+        #[cfg(feature = "rayon")]
+        if let Some(pool) = &self.pools[kind.index()] {
+            return pool.install(operation);
+        }
+        let _ = kind;
+        operation()
+    }
+}
+
+/// Runs the given operation with `ThreadPools::install`, for an operation that uses the given `rng`.
+/// If the kind of work has a dedicated pool, the `rng` is replaced in the operation with an RNG seeded from it,
+/// as the given `rng` may not be sent across threads. Otherwise, the operation runs with the given `rng`.
+///
+/// Example: `install_with_rng!(thread_pools, ThreadPoolKind::Proving, rng, trace.prove_fee::<A, _>(rng))`
+#[macro_export]
+macro_rules! install_with_rng {
+    ($thread_pools:expr, $kind:expr, $rng:ident, $operation:expr) => {{
+        let thread_pools = &$thread_pools;
+        match thread_pools.is_configured($kind) {
+            true => {
+                let mut pool_rng = <rand::rngs::StdRng as rand::SeedableRng>::from_seed(rand::Rng::gen(&mut *$rng));
+                let $rng = &mut pool_rng;
+                thread_pools.install($kind, || $operation)
+            }
+            false => $operation,
+        }
+    }};
+}
+
+#[cfg(all(test, feature = "rayon"))]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_default_runs_in_current_thread() {
+        let thread_pools = ThreadPools::new(ThreadPoolConfig::new()).unwrap();
+        let current = std::thread::current().id();
+        for kind in ThreadPoolKind::ALL {
+            assert!(!thread_pools.is_configured(kind));
+            assert_eq!(thread_pools.install(kind, || std::thread::current().id()), current);
+        }
+    }
+
+    #[test]
+    fn test_dedicated_pools() {
+        let num_started = Arc::new(AtomicUsize::new(0));
+        let counter = num_started.clone();
+        let config = ThreadPoolConfig::new()
+            .with_pool(ThreadPoolKind::Proving, 2)
+            .with_pool(ThreadPoolKind::Verification, 1)
+            .with_start_handler(ThreadPoolKind::Proving, move |_| {
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+        let thread_pools = ThreadPools::new(config).unwrap();
+
+        assert!(!thread_pools.is_configured(ThreadPoolKind::Synthesis));
+        assert_eq!(thread_pools.num_threads(ThreadPoolKind::Proving), 2);
+        assert_eq!(thread_pools.num_threads(ThreadPoolKind::Verification), 1);
+
+        // Ensure the work runs in the dedicated pools.
+        let thread_name = |kind| thread_pools.install(kind, || std::thread::current().name().map(str::to_string));
+        assert!(thread_name(ThreadPoolKind::Proving).unwrap().starts_with("proving-"));
+        assert_eq!(thread_name(ThreadPoolKind::Verification).unwrap(), "verification-0");
+
+        // Ensure nested parallel work is bounded by the dedicated pool.
+        let num_threads = thread_pools.install(ThreadPoolKind::Verification, rayon::current_num_threads);
+        assert_eq!(num_threads, 1);
+
+        // Ensure the start handler ran on each proving thread.
+        let thread_pools_clone = thread_pools.clone();
+        thread_pools_clone.install(ThreadPoolKind::Proving, || rayon::broadcast(|_| ()));
+        assert_eq!(num_started.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_install_with_rng() {
+        let config = ThreadPoolConfig::new().with_pool(ThreadPoolKind::Proving, 1);
+        let thread_pools = ThreadPools::new(config).unwrap();
+
+        let rng = &mut TestRng::default();
+        let (value, thread) = install_with_rng!(thread_pools, ThreadPoolKind::Proving, rng, {
+            (rng.gen::<u64>(), std::thread::current().name().map(str::to_string))
+        });
+        assert_eq!(thread.unwrap(), "proving-0");
+
+        // Ensure the operation runs with the given RNG, if the kind has no dedicated pool.
+        let expected = TestRng::default().gen::<u64>();
+        let rng = &mut TestRng::default();
+        let value_without_pool = install_with_rng!(thread_pools, ThreadPoolKind::Synthesis, rng, rng.gen::<u64>());
+        assert_eq!(value_without_pool, expected);
+        assert_ne!(value, expected);
+    }
+}
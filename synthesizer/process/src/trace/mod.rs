@@ -25,7 +25,7 @@ use console::{
 };
 use ledger_block::{Execution, Fee, Transition};
 use ledger_query::QueryTrait;
-use synthesizer_snark::{Proof, ProvingKey, VerifyingKey};
+use synthesizer_snark::{Proof, ProvingKey, ProvingMode, VerifyingKey};
 
 use once_cell::sync::OnceCell;
 use std::collections::HashMap;
@@ -40,11 +40,17 @@ pub struct Trace<N: Network> {
     inclusion_tasks: Inclusion<N>,
     /// A list of call metrics.
     call_metrics: Vec<CallMetrics<N>>,
+    /// The memory usage of the prover.
+    proving_mode: ProvingMode,
 
     /// A tracker for the inclusion assignments.
     inclusion_assignments: OnceCell<Vec<InclusionAssignment<N>>>,
     /// A tracker for the global state root.
     global_state_root: OnceCell<N::StateRoot>,
+    /// The peak estimated memory usage (in bytes) of the circuits of the execution.
+    peak_memory_usage: u64,
+    /// The limit on the estimated memory usage (in bytes) of the batch of circuits during proving, if any.
+    memory_limit: Option<u64>,
 }
 
 impl<N: Network> Trace<N> {
@@ -57,18 +63,46 @@ impl<N: Network> Trace<N> {
             inclusion_assignments: OnceCell::new(),
             global_state_root: OnceCell::new(),
             call_metrics: Vec::new(),
+            proving_mode: ProvingMode::Standard,
+            peak_memory_usage: 0,
+            memory_limit: None,
         }
     }
 
+    /// Initializes a new trace, which is proven with the given proving mode.
+    pub fn new_with_proving_mode(proving_mode: ProvingMode) -> Self {
+        Self { proving_mode, ..Self::new() }
+    }
+
     /// Returns the list of transitions.
     pub fn transitions(&self) -> &[Transition<N>] {
         &self.transitions
     }
 
+    /// Returns the peak estimated memory usage (in bytes) of the circuits of the execution.
+    pub const fn peak_memory_usage(&self) -> u64 {
+        self.peak_memory_usage
+    }
+
+    /// Sets the peak estimated memory usage (in bytes) of the circuits of the execution.
+    pub(crate) fn set_peak_memory_usage(&mut self, peak_memory_usage: u64) {
+        self.peak_memory_usage = peak_memory_usage;
+    }
+
+    /// Sets the limit on the estimated memory usage (in bytes) of the batch of circuits during proving.
+    pub(crate) fn set_memory_limit(&mut self, memory_limit: Option<u64>) {
+        self.memory_limit = memory_limit;
+    }
+
     /// Returns the call metrics.
     pub fn call_metrics(&self) -> &[CallMetrics<N>] {
         &self.call_metrics
     }
+
+    /// Returns the proving mode.
+    pub const fn proving_mode(&self) -> ProvingMode {
+        self.proving_mode
+    }
 }
 
 impl<N: Network> Trace<N> {
@@ -146,6 +180,29 @@ impl<N: Network> Trace<N> {
         Ok(())
     }
 
+    /// Returns the size in bytes of the execution, for the current inclusion assignments and global state root,
+    /// without computing the proof.
+    pub fn execution_size_in_bytes(&self) -> Result<u64> {
+        // Ensure this is not a fee.
+        ensure!(!self.is_fee(), "The trace cannot call 'execution_size_in_bytes' for a fee type");
+        // Retrieve the inclusion assignments.
+        let inclusion_assignments =
+            self.inclusion_assignments.get().ok_or_else(|| anyhow!("Inclusion assignments have not been set"))?;
+        // Retrieve the global state root.
+        let global_state_root =
+            self.global_state_root.get().ok_or_else(|| anyhow!("Global state root has not been set"))?;
+        // Compute the batch sizes of the proof, including the inclusion task.
+        let mut batch_sizes =
+            self.transition_tasks.values().map(|(_, assignments)| assignments.len()).collect::<Vec<_>>();
+        if !inclusion_assignments.is_empty() {
+            batch_sizes.push(inclusion_assignments.len());
+        }
+        // Compute the size of the execution without a proof, which includes the proof variant byte.
+        let execution = Execution::from(self.transitions.iter().cloned(), *global_state_root, None)?;
+        // Return the size of the execution with the proof.
+        Ok(execution.size_in_bytes()? + u64::try_from(Proof::<N>::size_in_bytes_for(&batch_sizes))?)
+    }
+
     /// Returns a new execution with a proof, for the current inclusion assignments and global state root.
     pub fn prove_execution<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
@@ -168,12 +225,60 @@ impl<N: Network> Trace<N> {
         // Construct the proving tasks.
         let proving_tasks = self.transition_tasks.values().cloned().collect();
         // Compute the proof.
-        let (global_state_root, proof) =
-            Self::prove_batch::<A, R>(locator, proving_tasks, inclusion_assignments, *global_state_root, rng)?;
+        let (global_state_root, proof) = Self::prove_batch::<A, R>(
+            locator,
+            proving_tasks,
+            inclusion_assignments,
+            *global_state_root,
+            self.proving_mode,
+            self.memory_limit,
+            rng,
+        )?;
         // Return the execution.
         Execution::from(self.transitions.iter().cloned(), global_state_root, Some(proof))
     }
 
+    /// Returns the proving tasks for the execution or fee, as (proving key, assignments) pairs, including the inclusion task.
+    /// The assignments can be exported (e.g. with `ToBytes`) to prove the trace with an external prover,
+    /// and the resulting proof can be imported with `Trace::execution_with_proof` or `Trace::fee_with_proof`.
+    pub fn to_proving_tasks<A: circuit::Aleo<Network = N>>(
+        &self,
+    ) -> Result<Vec<(ProvingKey<N>, Vec<Assignment<N::Field>>)>> {
+        // Retrieve the inclusion assignments.
+        let inclusion_assignments =
+            self.inclusion_assignments.get().ok_or_else(|| anyhow!("Inclusion assignments have not been set"))?;
+        // Retrieve the global state root.
+        let global_state_root =
+            self.global_state_root.get().ok_or_else(|| anyhow!("Global state root has not been set"))?;
+        // Construct the proving tasks.
+        let proving_tasks = self.transition_tasks.values().cloned().collect();
+        Self::prepare_proving_tasks::<A>(proving_tasks, inclusion_assignments, *global_state_root)
+    }
+
+    /// Returns a new execution with the given proof, which was computed by an external prover for `Trace::to_proving_tasks`.
+    /// Note: The proof is *not* verified here, and the execution must be verified with `Process::verify_execution`.
+    pub fn execution_with_proof(&self, proof: Proof<N>) -> Result<Execution<N>> {
+        // Ensure this is not a fee.
+        ensure!(!self.is_fee(), "The trace cannot call 'execution_with_proof' for a fee type");
+        // Retrieve the global state root.
+        let global_state_root =
+            self.global_state_root.get().ok_or_else(|| anyhow!("Global state root has not been set"))?;
+        // Return the execution.
+        Execution::from(self.transitions.iter().cloned(), *global_state_root, Some(proof))
+    }
+
+    /// Returns a new fee with the given proof, which was computed by an external prover for `Trace::to_proving_tasks`.
+    /// Note: The proof is *not* verified here, and the fee must be verified with `Process::verify_fee`.
+    pub fn fee_with_proof(&self, proof: Proof<N>) -> Result<Fee<N>> {
+        // Ensure this is a fee.
+        ensure!(self.is_fee(), "The trace cannot call 'fee_with_proof' for an execution type");
+        // Retrieve the global state root.
+        let global_state_root =
+            self.global_state_root.get().ok_or_else(|| anyhow!("Global state root has not been set"))?;
+        // Return the fee.
+        Ok(Fee::from_unchecked(self.transitions[0].clone(), *global_state_root, Some(proof)))
+    }
+
     /// Returns a new fee with a proof, for the current inclusion assignment and global state root.
     pub fn prove_fee<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<Fee<N>> {
         // Ensure this is a fee.
@@ -201,6 +306,8 @@ impl<N: Network> Trace<N> {
             proving_tasks,
             inclusion_assignments,
             *global_state_root,
+            self.proving_mode,
+            self.memory_limit,
             rng,
         )?;
         // Return the fee.
@@ -214,6 +321,33 @@ impl<N: Network> Trace<N> {
         verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
         execution: &Execution<N>,
     ) -> Result<()> {
+        // Construct the verifier inputs, and retrieve the proof.
+        let (verifier_inputs, proof) = Self::to_execution_verifier_inputs(verifier_inputs, execution)?;
+        // Verify the execution proof.
+        match VerifyingKey::verify_batch(locator, verifier_inputs, proof) {
+            true => Ok(()),
+            false => bail!("Execution is invalid - Failed to verify proof"),
+        }
+    }
+
+    /// Checks the proof for the fee.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    pub fn verify_fee_proof(verifier_inputs: (VerifyingKey<N>, Vec<Vec<N::Field>>), fee: &Fee<N>) -> Result<()> {
+        // Construct the verifier inputs, and retrieve the proof.
+        let (verifier_inputs, proof) = Self::to_fee_verifier_inputs(verifier_inputs, fee)?;
+        // Verify the fee proof.
+        match VerifyingKey::verify_batch("credits.aleo/fee (private or public)", verifier_inputs, proof) {
+            true => Ok(()),
+            false => bail!("Fee is invalid - Failed to verify proof"),
+        }
+    }
+
+    /// Returns the verifier inputs for the execution proof, including the inclusion verifier inputs, and the proof.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    pub fn to_execution_verifier_inputs(
+        verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        execution: &Execution<N>,
+    ) -> Result<(Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>, &Proof<N>)> {
         // Retrieve the global state root.
         let global_state_root = execution.global_state_root();
         // Ensure the global state root is not zero.
@@ -222,16 +356,19 @@ impl<N: Network> Trace<N> {
         }
         // Retrieve the proof.
         let Some(proof) = execution.proof() else { bail!("Expected the execution to contain a proof") };
-        // Verify the execution proof.
-        match Self::verify_batch(locator, verifier_inputs, global_state_root, execution.transitions(), proof) {
-            Ok(()) => Ok(()),
+        // Insert the inclusion verifier inputs.
+        match Self::with_inclusion_verifier_inputs(verifier_inputs, global_state_root, execution.transitions()) {
+            Ok(verifier_inputs) => Ok((verifier_inputs, proof)),
             Err(e) => bail!("Execution is invalid - {e}"),
         }
     }
 
-    /// Checks the proof for the fee.
+    /// Returns the verifier inputs for the fee proof, including the inclusion verifier inputs, and the proof.
     /// Note: This does *not* check that the global state root exists in the ledger.
-    pub fn verify_fee_proof(verifier_inputs: (VerifyingKey<N>, Vec<Vec<N::Field>>), fee: &Fee<N>) -> Result<()> {
+    pub fn to_fee_verifier_inputs(
+        verifier_inputs: (VerifyingKey<N>, Vec<Vec<N::Field>>),
+        fee: &Fee<N>,
+    ) -> Result<(Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>, &Proof<N>)> {
         // Retrieve the global state root.
         let global_state_root = fee.global_state_root();
         // Ensure the global state root is not zero.
@@ -240,15 +377,13 @@ impl<N: Network> Trace<N> {
         }
         // Retrieve the proof.
         let Some(proof) = fee.proof() else { bail!("Expected the fee to contain a proof") };
-        // Verify the fee proof.
-        match Self::verify_batch(
-            "credits.aleo/fee (private or public)",
+        // Insert the inclusion verifier inputs.
+        match Self::with_inclusion_verifier_inputs(
             vec![verifier_inputs],
             global_state_root,
             [fee.transition()].into_iter(),
-            proof,
         ) {
-            Ok(()) => Ok(()),
+            Ok(verifier_inputs) => Ok((verifier_inputs, proof)),
             Err(e) => bail!("Fee is invalid - {e}"),
         }
     }
@@ -258,11 +393,38 @@ impl<N: Network> Trace<N> {
     /// Returns the global state root and proof for the given assignments.
     fn prove_batch<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         locator: &str,
-        mut proving_tasks: Vec<(ProvingKey<N>, Vec<Assignment<N::Field>>)>,
+        proving_tasks: Vec<(ProvingKey<N>, Vec<Assignment<N::Field>>)>,
         inclusion_assignments: &[InclusionAssignment<N>],
         global_state_root: N::StateRoot,
+        proving_mode: ProvingMode,
+        memory_limit: Option<u64>,
         rng: &mut R,
     ) -> Result<(N::StateRoot, Proof<N>)> {
+        // Construct the proving tasks, including the inclusion task.
+        let proving_tasks = Self::prepare_proving_tasks::<A>(proving_tasks, inclusion_assignments, global_state_root)?;
+        // Ensure the batch of circuits does not exceed the memory limit.
+        if let Some(memory_limit) = memory_limit {
+            let memory_usage = proving_tasks
+                .iter()
+                .flat_map(|(_, assignments)| assignments)
+                .fold(0u64, |memory_usage, assignment| memory_usage.saturating_add(assignment.memory_usage()));
+            ensure!(
+                memory_usage <= memory_limit,
+                "Proving exceeded the memory limit ({memory_usage} of {memory_limit} bytes)"
+            );
+        }
+        // Compute the proof.
+        let proof = ProvingKey::prove_batch(locator, &proving_tasks, proving_mode, rng)?;
+        // Return the global state root and proof.
+        Ok((global_state_root, proof))
+    }
+
+    /// Returns the given proving tasks, with the inclusion task for the given inclusion assignments.
+    fn prepare_proving_tasks<A: circuit::Aleo<Network = N>>(
+        mut proving_tasks: Vec<(ProvingKey<N>, Vec<Assignment<N::Field>>)>,
+        inclusion_assignments: &[InclusionAssignment<N>],
+        global_state_root: N::StateRoot,
+    ) -> Result<Vec<(ProvingKey<N>, Vec<Assignment<N::Field>>)>> {
         // Ensure the global state root is not zero.
         // Note: To protect user privacy, even when there are *no* inclusion assignments,
         // the user must provide a real global state root (which is checked in consensus).
@@ -289,21 +451,15 @@ impl<N: Network> Trace<N> {
             proving_tasks.push((proving_key, batch_inclusions));
         }
 
-        // Compute the proof.
-        let proof = ProvingKey::prove_batch(locator, &proving_tasks, rng)?;
-        // Return the global state root and proof.
-        Ok((global_state_root, proof))
+        Ok(proving_tasks)
     }
 
-    /// Checks the proof for the given inputs.
-    /// Note: This does *not* check that the global state root exists in the ledger.
-    fn verify_batch<'a>(
-        locator: &str,
+    /// Returns the given verifier inputs, with the inclusion verifier inputs for the given transitions.
+    fn with_inclusion_verifier_inputs<'a>(
         mut verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
         global_state_root: N::StateRoot,
         transitions: impl ExactSizeIterator<Item = &'a Transition<N>>,
-        proof: &Proof<N>,
-    ) -> Result<()> {
+    ) -> Result<Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>> {
         // Construct the batch of inclusion verifier inputs.
         let batch_inclusion_inputs = Inclusion::prepare_verifier_inputs(global_state_root, transitions)?;
         // Insert the batch of inclusion verifier inputs to the verifier inputs.
@@ -313,10 +469,6 @@ impl<N: Network> Trace<N> {
             // Insert the inclusion verifier inputs.
             verifier_inputs.push((verifying_key, batch_inclusion_inputs));
         }
-        // Verify the proof.
-        match VerifyingKey::verify_batch(locator, verifier_inputs, proof) {
-            true => Ok(()),
-            false => bail!("Failed to verify proof"),
-        }
+        Ok(verifier_inputs)
     }
 }
@@ -0,0 +1,180 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::account::{Address, Signature};
+use synthesizer_snark::{PreparedVerifyingKey, PreparedVerifyingKeyCache, Proof};
+
+use parking_lot::Mutex;
+use std::collections::BTreeMap;
+
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
+/// A proof in a batch, as (locator, transition ID, verifier inputs, proof).
+type BatchedProof<N> =
+    (String, <N as Network>::TransitionID, Vec<(VerifyingKey<N>, Vec<Vec<<N as Environment>::Field>>)>, Proof<N>);
+
+/// A signature in a batch, as (description, signature, address, message).
+type BatchedSignature<N> = (String, Signature<N>, Address<N>, Vec<Field<N>>);
+
+/// The execution and fee proofs of one or more transactions, with their verifier inputs, to verify together.
+///
+/// The proofs are collected by `Process::verify_execution_deferred` and `Process::verify_fee_deferred`,
+/// and checked by `Process::verify_proof_batch`, which prepares each distinct verifying key once for the batch,
+/// and combines the pairing checks of the proofs for the same circuits into one Varuna batch check.
+/// The signatures of the transactions are collected in the batch as well, and checked with `Signature::verify_batch`.
+pub struct ProofBatch<N: Network> {
+    /// The proofs in the batch.
+    proofs: Mutex<Vec<BatchedProof<N>>>,
+    /// The signatures in the batch.
+    signatures: Mutex<Vec<BatchedSignature<N>>>,
+}
+
+impl<N: Network> Default for ProofBatch<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<N: Network> ProofBatch<N> {
+    /// Initializes a new, empty batch.
+    pub fn new() -> Self {
+        Self { proofs: Mutex::new(Vec::new()), signatures: Mutex::new(Vec::new()) }
+    }
+
+    /// Returns the number of proofs in the batch.
+    pub fn len(&self) -> usize {
+        self.proofs.lock().len()
+    }
+
+    /// Returns `true` if the batch is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds the given proof to the batch, with its verifier inputs (including the inclusion verifier inputs).
+    pub(crate) fn push(
+        &self,
+        locator: String,
+        transition_id: N::TransitionID,
+        verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        proof: Proof<N>,
+    ) {
+        self.proofs.lock().push((locator, transition_id, verifier_inputs, proof));
+    }
+
+    /// Adds the given signature to the batch, with its address and message.
+    /// The description names the signature in the error, if the signature is invalid.
+    pub fn push_signature(
+        &self,
+        description: String,
+        signature: Signature<N>,
+        address: Address<N>,
+        message: Vec<Field<N>>,
+    ) {
+        self.signatures.lock().push((description, signature, address, message));
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Verifies the signatures and proofs in the given batch.
+    /// On failure, returns an error for the first invalid signature or proof.
+    #[inline]
+    pub fn verify_proof_batch(&self, batch: ProofBatch<N>) -> Result<()> {
+        // Verify the signatures.
+        Self::verify_signature_batch(batch.signatures.into_inner())?;
+        // Verify the proofs.
+        let proofs = batch.proofs.into_inner();
+        self.thread_pools.install(ThreadPoolKind::Verification, || Self::verify_proof_batch_in_pool(proofs))
+    }
+
+    /// Verifies the given signatures with one batch check.
+    fn verify_signature_batch(signatures: Vec<BatchedSignature<N>>) -> Result<()> {
+        let batch = signatures
+            .iter()
+            .map(|(_, signature, address, message)| (*signature, *address, message.clone()))
+            .collect::<Vec<_>>();
+        // If the batch is invalid, verify the signatures one by one, to report the first invalid signature.
+        if !Signature::verify_batch(&batch) {
+            for (description, signature, address, message) in &signatures {
+                if !signature.verify(address, message) {
+                    bail!("Invalid {description}")
+                }
+            }
+            bail!("Failed to verify a batch of {} signatures", signatures.len())
+        }
+        Ok(())
+    }
+
+    /// Verifies the given proofs, in the current thread pool.
+    fn verify_proof_batch_in_pool(proofs: Vec<BatchedProof<N>>) -> Result<()> {
+        let timer = timer!("Process::verify_proof_batch");
+
+        if proofs.is_empty() {
+            return Ok(());
+        }
+        let num_proofs = proofs.len();
+
+        // Collect the distinct verifying keys in the batch.
+        let mut verifying_keys = BTreeMap::new();
+        for (_, _, verifier_inputs, _) in &proofs {
+            for (verifying_key, _) in verifier_inputs {
+                verifying_keys.entry(verifying_key.id).or_insert(verifying_key);
+            }
+        }
+        // Prepare each verifying key once.
+        let cache = PreparedVerifyingKeyCache::default();
+        let verifying_keys = verifying_keys.into_values().collect::<Vec<_>>();
+        cfg_iter!(verifying_keys).try_for_each(|verifying_key| cache.get_or_prepare(verifying_key).map(|_| ()))?;
+        lap!(timer, "Prepare {} verifying keys", verifying_keys.len());
+
+        // Group the proofs by the circuits they prove, with the prepared verifier inputs for each proof.
+        let mut groups = BTreeMap::<_, Vec<_>>::new();
+        for (locator, transition_id, verifier_inputs, proof) in proofs {
+            let mut circuit_ids = verifier_inputs.iter().map(|(verifying_key, _)| verifying_key.id).collect::<Vec<_>>();
+            circuit_ids.sort_unstable();
+            let verifier_inputs = verifier_inputs
+                .into_iter()
+                .map(|(verifying_key, inputs)| Ok((cache.get_or_prepare(&verifying_key)?, inputs)))
+                .collect::<Result<Vec<_>>>()?;
+            groups.entry(circuit_ids).or_default().push((locator, transition_id, verifier_inputs, proof));
+        }
+        let groups = groups.into_values().collect::<Vec<_>>();
+
+        // Verify the proofs of each group with one batch check.
+        let results: Vec<bool> = cfg_iter!(groups)
+            .map(|group| {
+                let batches = group
+                    .iter()
+                    .map(|(locator, _, verifier_inputs, proof)| (locator.as_str(), verifier_inputs.clone(), proof))
+                    .collect();
+                PreparedVerifyingKey::verify_batches(batches, &mut rand::thread_rng())
+            })
+            .collect();
+        finish!(timer, "Verify {num_proofs} proofs in {} groups", groups.len());
+
+        // If a group is invalid, verify its proofs one by one, to report the first invalid proof.
+        if let Some((group, _)) = groups.iter().zip_eq(results).find(|(_, is_valid)| !is_valid) {
+            for (locator, transition_id, verifier_inputs, proof) in group {
+                if !PreparedVerifyingKey::verify_batch(locator, verifier_inputs.clone(), proof) {
+                    bail!("Failed to verify the proof for '{locator}' (transition '{transition_id}')")
+                }
+            }
+            bail!("Failed to verify a batch of {} proofs", group.len())
+        }
+        Ok(())
+    }
+}
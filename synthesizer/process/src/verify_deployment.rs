@@ -14,15 +14,77 @@
 
 use super::*;
 
+use std::time::{Duration, Instant};
+
+/// The statistics of the circuit of a function in a deployment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionStatistics<N: Network> {
+    /// The function name.
+    pub function_name: Identifier<N>,
+    /// The number of public variables.
+    pub num_public: u64,
+    /// The number of private variables.
+    pub num_private: u64,
+    /// The number of constraints.
+    pub num_constraints: u64,
+    /// The number of non-zero entries in the A, B, and C matrices.
+    pub num_nonzeros: (u64, u64, u64),
+    /// The time spent synthesizing the circuit.
+    pub synthesis_elapsed: Duration,
+    /// The size of the verifying key, in bytes.
+    pub verifying_key_size: usize,
+    /// The size of the certificate, in bytes.
+    pub certificate_size: usize,
+}
+
+impl<N: Network> FunctionStatistics<N> {
+    /// Returns the number of variables.
+    pub const fn num_variables(&self) -> u64 {
+        self.num_public.saturating_add(self.num_private)
+    }
+
+    /// Returns the total number of non-zero entries in the A, B, and C matrices.
+    pub const fn num_total_nonzeros(&self) -> u64 {
+        self.num_nonzeros.0.saturating_add(self.num_nonzeros.1).saturating_add(self.num_nonzeros.2)
+    }
+}
+
+/// The response of a deployment verification, with the statistics of the circuit of each function.
+///
+/// The verifying key and certificate sizes count toward the storage cost of the deployment,
+/// while the circuit sizes determine the cost to synthesize and prove each function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeploymentResponse<N: Network> {
+    /// The program ID.
+    pub program_id: ProgramID<N>,
+    /// The statistics of each function, in the order of the program functions.
+    pub functions: Vec<FunctionStatistics<N>>,
+    /// The time spent verifying the deployment.
+    pub elapsed: Duration,
+}
+
+impl<N: Network> DeploymentResponse<N> {
+    /// Returns the total number of constraints across all functions.
+    pub fn num_constraints(&self) -> u64 {
+        self.functions.iter().fold(0, |total, function| total.saturating_add(function.num_constraints))
+    }
+
+    /// Returns the function with the most constraints, if any.
+    pub fn largest_function(&self) -> Option<&FunctionStatistics<N>> {
+        self.functions.iter().max_by_key(|function| function.num_constraints)
+    }
+}
+
 impl<N: Network> Process<N> {
-    /// Verifies the given deployment is ordered.
+    /// Verifies the given deployment is ordered, and returns the statistics of the circuit of each function.
     #[inline]
     pub fn verify_deployment<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
         deployment: &Deployment<N>,
         rng: &mut R,
-    ) -> Result<()> {
+    ) -> Result<DeploymentResponse<N>> {
         let timer = timer!("Process::verify_deployment");
+        let start = Instant::now();
         // Retrieve the program ID.
         let program_id = deployment.program().id();
         // Ensure the program does not already exist in the process.
@@ -33,11 +95,16 @@ impl<N: Network> Process<N> {
         lap!(timer, "Compute the stack");
 
         // Ensure the verifying keys are well-formed and the certificates are valid.
-        let verification = stack.verify_deployment::<A, R>(deployment, rng);
+        let functions = install_with_rng!(
+            self.thread_pools,
+            ThreadPoolKind::Verification,
+            rng,
+            stack.verify_deployment::<A, _>(deployment, rng)
+        )?;
         lap!(timer, "Verify the deployment");
 
         finish!(timer);
-        verification
+        Ok(DeploymentResponse { program_id: *program_id, functions, elapsed: start.elapsed() })
     }
 }
 
@@ -47,6 +114,54 @@ mod tests {
 
     type CurrentAleo = circuit::network::AleoV0;
 
+    #[test]
+    fn test_verify_deployment_statistics() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the process.
+        let process = Process::load().unwrap();
+
+        // Initialize a program with a small function and a larger function.
+        let program = Program::from_str(
+            r"
+program statistics.aleo;
+
+function add:
+    input r0 as u64.public;
+    input r1 as u64.private;
+    add r0 r1 into r2;
+    output r2 as u64.private;
+
+function hash:
+    input r0 as u64.public;
+    hash.bhp256 r0 into r1 as field;
+    hash.bhp256 r1 into r2 as field;
+    output r2 as field.private;",
+        )
+        .unwrap();
+
+        // Verify the deployment.
+        let deployment = process.deploy::<CurrentAleo, _>(&program, rng).unwrap();
+        let response = process.verify_deployment::<CurrentAleo, _>(&deployment, rng).unwrap();
+        assert_eq!(response.program_id, *program.id());
+
+        // Ensure the statistics are reported for each function, in order.
+        let function_names = response.functions.iter().map(|function| function.function_name).collect::<Vec<_>>();
+        assert_eq!(function_names, program.functions().keys().copied().collect::<Vec<_>>());
+        for function in &response.functions {
+            assert!(function.num_constraints > 0);
+            assert!(function.num_variables() > 0);
+            assert!(function.num_nonzeros.0 > 0 && function.num_nonzeros.1 > 0 && function.num_nonzeros.2 > 0);
+            assert!(function.verifying_key_size > 0);
+            assert!(function.certificate_size > 0);
+        }
+        assert_eq!(
+            response.num_constraints(),
+            response.functions.iter().map(|function| function.num_constraints).sum::<u64>()
+        );
+        assert_eq!(response.largest_function().unwrap().function_name.to_string(), "hash");
+    }
+
     /// Use `cargo test profiler --features timer` to run this test.
     #[ignore]
     #[test]
@@ -63,7 +178,16 @@ mod tests {
         let deployment = process.deploy::<CurrentAleo, _>(&large_program, rng)?;
 
         // Verify the deployment.
-        assert!(process.verify_deployment::<CurrentAleo, _>(&deployment, rng).is_ok());
+        let response = process.verify_deployment::<CurrentAleo, _>(&deployment, rng)?;
+        // Ensure the statistics are reported for each function, in order.
+        let function_names = response.functions.iter().map(|function| function.function_name).collect::<Vec<_>>();
+        assert_eq!(function_names, large_program.functions().keys().copied().collect::<Vec<_>>());
+        assert!(response.functions.iter().all(|function| function.num_constraints > 0));
+        // Ensure the functions that join more records have more constraints.
+        let joins = response.functions.iter().filter(|function| function.function_name.to_string().starts_with("join"));
+        let num_constraints = joins.map(|function| function.num_constraints).collect::<Vec<_>>();
+        assert_eq!(num_constraints.len(), 7);
+        assert!(num_constraints.windows(2).all(|pair| pair[0] < pair[1]));
 
         bail!("\n\nRemember to #[ignore] this test!\n\n")
     }
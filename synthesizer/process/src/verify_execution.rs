@@ -14,11 +14,153 @@
 
 use super::*;
 
+use std::time::{Duration, Instant};
+
+/// The report of the checks on a transition, from the verification of its execution.
+/// The checks run in the order of the fields, and stop at the first failed check.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransitionVerification<N: Network> {
+    /// The transition ID.
+    pub transition_id: N::TransitionID,
+    /// The program ID.
+    pub program_id: ProgramID<N>,
+    /// The function name.
+    pub function_name: Identifier<N>,
+    /// `true` if the transition is a fee transition, which is not allowed in an execution.
+    pub is_fee_transition: bool,
+    /// `true` if the number of inputs and outputs is within the limits.
+    pub is_within_limits: bool,
+    /// `true` if the input IDs are valid, or `None` if they were not checked.
+    pub is_valid_inputs: Option<bool>,
+    /// `true` if the output IDs are valid, or `None` if they were not checked.
+    pub is_valid_outputs: Option<bool>,
+    /// `true` if the verifier inputs were constructed, which requires the calls and the finalize inputs
+    /// to match the function, or `None` if they were not constructed.
+    pub is_valid_verifier_inputs: Option<bool>,
+    /// The time spent checking the transition.
+    pub elapsed: Duration,
+}
+
+impl<N: Network> TransitionVerification<N> {
+    /// Returns `true` if all of the checks on the transition passed.
+    pub fn is_valid(&self) -> bool {
+        !self.is_fee_transition
+            && self.is_within_limits
+            && self.is_valid_inputs == Some(true)
+            && self.is_valid_outputs == Some(true)
+            && self.is_valid_verifier_inputs == Some(true)
+    }
+}
+
+/// The report of the verification of an execution.
+///
+/// On failure, `Process::verify_execution` returns the report as its error, which is retrieved with
+/// `error.downcast_ref::<ExecutionVerification<N>>()`. The transitions after the first invalid transition are not checked.
+/// Note: The execution proof covers all of the transitions, so it is reported for the execution, not per transition.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionVerification<N: Network> {
+    /// The reports of the checked transitions, in the order of the execution.
+    pub transitions: Vec<TransitionVerification<N>>,
+    /// `true` if the execution proof is valid, or `None` if it was not checked.
+    pub is_valid_proof: Option<bool>,
+    /// `true` if the execution proof was added to a batch, and is pending until the batch is verified.
+    pub is_proof_pending: bool,
+    /// The time spent verifying the execution proof.
+    pub proof_elapsed: Duration,
+    /// The time spent verifying the execution.
+    pub elapsed: Duration,
+    /// The reason the execution is invalid, if any.
+    pub error: Option<String>,
+}
+
+impl<N: Network> ExecutionVerification<N> {
+    /// Initializes an empty report.
+    fn new() -> Self {
+        Self {
+            transitions: Vec::new(),
+            is_valid_proof: None,
+            is_proof_pending: false,
+            proof_elapsed: Duration::ZERO,
+            elapsed: Duration::ZERO,
+            error: None,
+        }
+    }
+
+    /// Returns `true` if the execution is valid.
+    /// Note: An execution with a pending proof is not valid until its batch is verified.
+    pub fn is_valid(&self) -> bool {
+        self.error.is_none() && !self.is_proof_pending
+    }
+}
+
+impl<N: Network> Display for ExecutionVerification<N> {
+    /// Prints the reason the execution is invalid, or a summary of the verification.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match &self.error {
+            Some(error) => write!(f, "{error}"),
+            None if self.is_proof_pending => {
+                write!(f, "Verified {} transitions in {:?}, the proof is pending", self.transitions.len(), self.elapsed)
+            }
+            None => write!(f, "Verified {} transitions in {:?}", self.transitions.len(), self.elapsed),
+        }
+    }
+}
+
+impl<N: Network> std::error::Error for ExecutionVerification<N> {}
+
 impl<N: Network> Process<N> {
-    /// Verifies the given execution is valid.
+    /// Verifies the given execution is valid, and returns a report of the checks on each transition.
+    /// On failure, returns the report as the error (see `ExecutionVerification`).
     /// Note: This does *not* check that the global state root exists in the ledger.
     #[inline]
-    pub fn verify_execution(&self, execution: &Execution<N>) -> Result<()> {
+    pub fn verify_execution(&self, execution: &Execution<N>) -> Result<ExecutionVerification<N>> {
+        self.verify_execution_with_report(execution, None)
+    }
+
+    /// Verifies the given execution is valid, except for its proof, which is added to the given batch
+    /// to be checked with `Process::verify_proof_batch`. Returns a report of the checks on each transition,
+    /// in which the proof is pending until the batch is verified.
+    /// On failure, returns the report as the error (see `ExecutionVerification`).
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    #[inline]
+    pub fn verify_execution_deferred(
+        &self,
+        execution: &Execution<N>,
+        batch: &ProofBatch<N>,
+    ) -> Result<ExecutionVerification<N>> {
+        self.verify_execution_with_report(execution, Some(batch))
+    }
+
+    /// Verifies the given execution is valid, and returns a report of the checks on each transition.
+    /// If a batch is given, the proof is added to the batch instead of being verified.
+    fn verify_execution_with_report(
+        &self,
+        execution: &Execution<N>,
+        batch: Option<&ProofBatch<N>>,
+    ) -> Result<ExecutionVerification<N>> {
+        self.thread_pools.install(ThreadPoolKind::Verification, || {
+            let start = Instant::now();
+            let mut report = ExecutionVerification::new();
+            let result = self.verify_execution_in_pool(execution, batch, &mut report);
+            report.elapsed = start.elapsed();
+            match result {
+                Ok(()) => Ok(report),
+                Err(error) => {
+                    report.error = Some(error.to_string());
+                    Err(Error::new(report))
+                }
+            }
+        })
+    }
+
+    /// Verifies the given execution is valid, in the current thread pool, and records the checks in the given report.
+    /// If a batch is given, the proof is added to the batch instead of being verified.
+    fn verify_execution_in_pool(
+        &self,
+        execution: &Execution<N>,
+        batch: Option<&ProofBatch<N>>,
+        report: &mut ExecutionVerification<N>,
+    ) -> Result<()> {
         let timer = timer!("Process::verify_execution");
 
         // Ensure the execution contains transitions.
@@ -62,55 +204,73 @@ impl<N: Network> Process<N> {
                 "The transition ID is incorrect"
             );
 
-            // Ensure the transition is not a fee transition.
-            let is_fee_transition = transition.is_fee_private() || transition.is_fee_public();
-            ensure!(!is_fee_transition, "Fee transitions are not allowed in executions");
-            // Ensure the number of inputs is within the allowed range.
-            ensure!(transition.inputs().len() <= N::MAX_INPUTS, "Transition exceeded maximum number of inputs");
-            // Ensure the number of outputs is within the allowed range.
-            ensure!(transition.outputs().len() <= N::MAX_OUTPUTS, "Transition exceeded maximum number of outputs");
-
-            // Compute the function ID as `Hash(network_id, program_id, function_name)`.
-            let function_id = N::hash_bhp1024(
-                &(
-                    U16::<N>::new(N::ID),
-                    transition.program_id().name(),
-                    transition.program_id().network(),
-                    transition.function_name(),
-                )
-                    .to_bits_le(),
-            )?;
-
-            // Ensure each input is valid.
-            if transition
-                .inputs()
-                .iter()
-                .enumerate()
-                .any(|(index, input)| !input.verify(function_id, transition.tcm(), index))
-            {
-                bail!("Failed to verify a transition input")
-            }
-            lap!(timer, "Verify the inputs");
+            let transition_start = Instant::now();
 
-            // Ensure each output is valid.
+            // Check the transition, stopping at the first failed check.
             let num_inputs = transition.inputs().len();
-            if transition
-                .outputs()
-                .iter()
-                .enumerate()
-                .any(|(index, output)| !output.verify(function_id, transition.tcm(), num_inputs + index))
-            {
-                bail!("Failed to verify a transition output")
-            }
-            lap!(timer, "Verify the outputs");
+            let mut transition_report = TransitionVerification {
+                transition_id: *transition.id(),
+                program_id: *transition.program_id(),
+                function_name: *transition.function_name(),
+                is_fee_transition: transition.is_fee_private() || transition.is_fee_public(),
+                is_within_limits: num_inputs <= N::MAX_INPUTS && transition.outputs().len() <= N::MAX_OUTPUTS,
+                is_valid_inputs: None,
+                is_valid_outputs: None,
+                is_valid_verifier_inputs: None,
+                elapsed: Duration::ZERO,
+            };
+            let result = (|| {
+                // Ensure the transition is not a fee transition.
+                ensure!(!transition_report.is_fee_transition, "Fee transitions are not allowed in executions");
+                // Ensure the number of inputs is within the allowed range.
+                ensure!(num_inputs <= N::MAX_INPUTS, "Transition exceeded maximum number of inputs");
+                // Ensure the number of outputs is within the allowed range.
+                ensure!(transition_report.is_within_limits, "Transition exceeded maximum number of outputs");
+
+                // Compute the function ID as `Hash(network_id, program_id, function_name)`.
+                let function_id = N::hash_bhp1024(
+                    &(
+                        U16::<N>::new(N::ID),
+                        transition.program_id().name(),
+                        transition.program_id().network(),
+                        transition.function_name(),
+                    )
+                        .to_bits_le(),
+                )?;
+
+                // Ensure each input is valid.
+                let is_valid_inputs = transition
+                    .inputs()
+                    .iter()
+                    .enumerate()
+                    .all(|(index, input)| input.verify(function_id, transition.tcm(), index));
+                transition_report.is_valid_inputs = Some(is_valid_inputs);
+                ensure!(is_valid_inputs, "Failed to verify a transition input");
+                lap!(timer, "Verify the inputs");
+
+                // Ensure each output is valid.
+                let is_valid_outputs = transition
+                    .outputs()
+                    .iter()
+                    .enumerate()
+                    .all(|(index, output)| output.verify(function_id, transition.tcm(), num_inputs + index));
+                transition_report.is_valid_outputs = Some(is_valid_outputs);
+                ensure!(is_valid_outputs, "Failed to verify a transition output");
+                lap!(timer, "Verify the outputs");
 
-            // Retrieve the stack.
-            let stack = self.get_stack(transition.program_id())?;
-            // Retrieve the function from the stack.
-            let function = stack.get_function(transition.function_name())?;
-
-            // Construct the verifier inputs for the transition.
-            let inputs = self.to_transition_verifier_inputs(transition, &function, &call_graph, &mut transition_map)?;
+                // Retrieve the stack.
+                let stack = self.get_stack(transition.program_id())?;
+                // Retrieve the function from the stack.
+                let function = stack.get_function(transition.function_name())?;
+                // Construct the verifier inputs for the transition.
+                let inputs =
+                    self.to_transition_verifier_inputs(transition, &function, &call_graph, &mut transition_map);
+                transition_report.is_valid_verifier_inputs = Some(inputs.is_ok());
+                Ok((stack, function, inputs?))
+            })();
+            transition_report.elapsed = transition_start.elapsed();
+            report.transitions.push(transition_report);
+            let (stack, function, inputs) = result?;
             lap!(timer, "Constructed the verifier inputs for a transition of {}", function.name());
 
             // Save the verifying key and its inputs.
@@ -133,15 +293,197 @@ impl<N: Network> Process<N> {
 
         // Construct the list of verifier inputs.
         let verifier_inputs = verifier_inputs.values().cloned().collect();
-        // Verify the execution proof.
-        Trace::verify_execution_proof(&locator, verifier_inputs, execution)?;
-        lap!(timer, "Verify the proof");
+        match batch {
+            // Add the execution proof to the batch.
+            Some(batch) => {
+                let (verifier_inputs, proof) = Trace::to_execution_verifier_inputs(verifier_inputs, execution)?;
+                batch.push(locator, *execution.peek()?.id(), verifier_inputs, proof.clone());
+                report.is_proof_pending = true;
+                lap!(timer, "Add the proof to the batch");
+            }
+            // Verify the execution proof.
+            None => {
+                let proof_start = Instant::now();
+                let result = Trace::verify_execution_proof(&locator, verifier_inputs, execution);
+                report.proof_elapsed = proof_start.elapsed();
+                report.is_valid_proof = Some(result.is_ok());
+                result?;
+                lap!(timer, "Verify the proof");
+            }
+        }
 
         finish!(timer);
         Ok(())
     }
 }
 
+/// The public inputs to verify the proof for a transition, within the batch proof of its execution.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransitionVerifierInputs<N: Network> {
+    /// The public inputs of the transition, in the exact order they are fed to the SNARK verifier.
+    pub inputs: Vec<N::Field>,
+    /// The verifying keys and public inputs of every circuit in the batch proof of the execution,
+    /// including the inclusion circuit, in the order the SNARK verifier processes them.
+    pub batch: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
+    /// The index of the circuit of the transition in the batch.
+    pub circuit_index: usize,
+    /// The index of the public inputs of the transition, among the instances of its circuit.
+    pub instance_index: usize,
+}
+
+impl<N: Network> FromBytes for TransitionVerifierInputs<N> {
+    /// Reads the verifier inputs from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid transition verifier inputs version"));
+        }
+        // Reads a list of field elements, prefixed by its length.
+        let read_fields = |reader: &mut R| -> IoResult<Vec<N::Field>> {
+            let num_fields = u32::read_le(&mut *reader)?;
+            (0..num_fields).map(|_| N::Field::read_le(&mut *reader)).collect()
+        };
+        // Read the circuit index and the instance index.
+        let circuit_index = u32::read_le(&mut reader)? as usize;
+        let instance_index = u32::read_le(&mut reader)? as usize;
+        // Read the circuits in the batch.
+        let num_circuits = u32::read_le(&mut reader)?;
+        let mut batch = Vec::new();
+        for _ in 0..num_circuits {
+            // Read the verifying key.
+            let verifying_key = VerifyingKey::read_le(&mut reader)?;
+            // Read the instances.
+            let num_instances = u32::read_le(&mut reader)?;
+            let instances = (0..num_instances).map(|_| read_fields(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+            batch.push((verifying_key, instances));
+        }
+        // Retrieve the public inputs of the transition.
+        let Some(inputs) = batch.get(circuit_index).and_then(|(_, instances)| instances.get(instance_index)) else {
+            return Err(error("The transition is not in the batch"));
+        };
+        Ok(Self { inputs: inputs.clone(), batch, circuit_index, instance_index })
+    }
+}
+
+impl<N: Network> ToBytes for TransitionVerifierInputs<N> {
+    /// Writes the verifier inputs to a buffer.
+    /// Note: The public inputs of the transition are written once, as the instance at its index in the batch.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Converts the given length into a `u32`.
+        let to_u32 = |length: usize| u32::try_from(length).map_err(|e| error(e.to_string()));
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the circuit index and the instance index.
+        to_u32(self.circuit_index)?.write_le(&mut writer)?;
+        to_u32(self.instance_index)?.write_le(&mut writer)?;
+        // Write the circuits in the batch.
+        to_u32(self.batch.len())?.write_le(&mut writer)?;
+        for (verifying_key, instances) in &self.batch {
+            // Write the verifying key.
+            verifying_key.write_le(&mut writer)?;
+            // Write the instances.
+            to_u32(instances.len())?.write_le(&mut writer)?;
+            for instance in instances {
+                to_u32(instance.len())?.write_le(&mut writer)?;
+                instance.write_le(&mut writer)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Returns the public inputs to verify the proof for the given transition in the given execution,
+    /// in the exact order they are fed to the SNARK verifier with the given verifying key.
+    ///
+    /// The execution proof is a batch proof over the transitions and their inclusion proofs, so the verifier
+    /// inputs of every circuit in the batch are returned with the inputs of the transition. The public inputs
+    /// of a transition include the input and output IDs of the transitions it calls, so the execution that
+    /// contains the transition is required. This allows external verifiers (e.g. hardware verifiers or audit tools)
+    /// to re-verify the execution proof independently of snarkVM.
+    /// Note: This does *not* check that the execution is valid, nor that the global state root exists in the ledger.
+    pub fn get_transition_verifier_inputs(
+        &self,
+        execution: &Execution<N>,
+        transition_id: &N::TransitionID,
+        verifying_key: &VerifyingKey<N>,
+    ) -> Result<TransitionVerifierInputs<N>> {
+        // Retrieve the transition.
+        let Some(transition) = execution.find_transition(transition_id) else {
+            bail!("Transition '{transition_id}' is not in the execution")
+        };
+        // Ensure the verifying key is the verifying key of the function.
+        ensure!(
+            self.get_verifying_key(*transition.program_id(), *transition.function_name())? == *verifying_key,
+            "The verifying key does not match '{}/{}'",
+            transition.program_id(),
+            transition.function_name()
+        );
+
+        // Construct the call graph of the execution.
+        let call_graph = self.construct_call_graph(execution)?;
+        // Initialize a map of verifying keys to public inputs.
+        let mut verifier_inputs = HashMap::new();
+        // Initialize a map of transition IDs to references of the transition.
+        let mut transition_map = HashMap::new();
+        // Construct the verifier inputs for each transition, as in `Process::verify_execution`.
+        for transition in execution.transitions() {
+            // Retrieve the stack.
+            let stack = self.get_stack(transition.program_id())?;
+            // Retrieve the function from the stack.
+            let function = stack.get_function(transition.function_name())?;
+            // Construct the verifier inputs for the transition.
+            let inputs = self.to_transition_verifier_inputs(transition, &function, &call_graph, &mut transition_map)?;
+            // Save the verifying key and its inputs.
+            verifier_inputs
+                .entry(Locator::new(*stack.program_id(), *function.name()))
+                // Retrieve the verifying key, if it does not already exist.
+                .or_insert((stack.get_verifying_key(function.name())?, vec![]))
+                .1
+                .push(inputs);
+            // Add the transition to the transition map.
+            transition_map.insert(*transition.id(), transition);
+        }
+
+        // Insert the inclusion verifier inputs.
+        let (mut batch, _) = Trace::to_execution_verifier_inputs(verifier_inputs.into_values().collect(), execution)?;
+        // Sort the circuits in the order the SNARK verifier processes them.
+        batch.sort_by(|(a, _), (b, _)| Ord::cmp(&**a, &**b));
+
+        // Locate the public inputs of the transition in the batch.
+        let Some(circuit_index) = batch.iter().position(|(key, _)| key == verifying_key) else {
+            bail!("The verifying key is not in the batch")
+        };
+        // Note: The transitions of a function are ordered as in the execution.
+        let instance_index = execution
+            .transitions()
+            .filter(|t| t.program_id() == transition.program_id() && t.function_name() == transition.function_name())
+            .position(|t| t.id() == transition_id)
+            .ok_or_else(|| anyhow!("Transition '{transition_id}' is not in the execution"))?;
+        let inputs = batch[circuit_index].1[instance_index].clone();
+        // Ensure the number of verifier inputs is within the number of public inputs of the verifying key.
+        ensure!(
+            inputs.len() <= verifying_key.circuit_info.num_public_inputs,
+            "The number of verifier inputs exceeds the number of public inputs of the verifying key"
+        );
+        Ok(TransitionVerifierInputs { inputs, batch, circuit_index, instance_index })
+    }
+
+    /// Returns the public inputs to verify the proof for the given transition in the given execution,
+    /// with the verifier inputs of the batch proof, serialized in little-endian order.
+    /// See `Process::get_transition_verifier_inputs` for the order of the public inputs.
+    pub fn get_transition_verifier_inputs_bytes(
+        &self,
+        execution: &Execution<N>,
+        transition_id: &N::TransitionID,
+        verifying_key: &VerifyingKey<N>,
+    ) -> Result<Vec<u8>> {
+        self.get_transition_verifier_inputs(execution, transition_id, verifying_key)?.to_bytes_le()
+    }
+}
+
 impl<N: Network> Process<N> {
     /// Returns the public inputs to verify the proof for the given transition.
     fn to_transition_verifier_inputs(
@@ -19,6 +19,33 @@ impl<N: Network> Process<N> {
     /// Note: This does *not* check that the global state root exists in the ledger.
     #[inline]
     pub fn verify_fee(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> Result<()> {
+        self.thread_pools
+            .install(ThreadPoolKind::Verification, || self.verify_fee_in_pool(fee, deployment_or_execution_id, None))
+    }
+
+    /// Verifies the given fee is valid, except for its proof, which is added to the given batch
+    /// to be checked with `Process::verify_proof_batch`.
+    /// Note: This does *not* check that the global state root exists in the ledger.
+    #[inline]
+    pub fn verify_fee_deferred(
+        &self,
+        fee: &Fee<N>,
+        deployment_or_execution_id: Field<N>,
+        batch: &ProofBatch<N>,
+    ) -> Result<()> {
+        self.thread_pools.install(ThreadPoolKind::Verification, || {
+            self.verify_fee_in_pool(fee, deployment_or_execution_id, Some(batch))
+        })
+    }
+
+    /// Verifies the given fee is valid, in the current thread pool.
+    /// If a batch is given, the proof is added to the batch instead of being verified.
+    fn verify_fee_in_pool(
+        &self,
+        fee: &Fee<N>,
+        deployment_or_execution_id: Field<N>,
+        batch: Option<&ProofBatch<N>>,
+    ) -> Result<()> {
         let timer = timer!("Process::verify_fee");
 
         #[cfg(debug_assertions)]
@@ -62,18 +89,34 @@ impl<N: Network> Process<N> {
         lap!(timer, "Verify the deployment or execution ID");
 
         // Verify the fee transition is well-formed.
-        match is_fee_private {
+        let verifier_inputs = match is_fee_private {
             true => self.verify_fee_private(&fee)?,
             false => self.verify_fee_public(&fee)?,
+        };
+        lap!(timer, "Verify the fee transition");
+
+        match batch {
+            // Add the fee proof to the batch.
+            Some(batch) => {
+                let (verifier_inputs, proof) = Trace::to_fee_verifier_inputs(verifier_inputs, fee)?;
+                let locator = Locator::new(*fee.program_id(), *fee.function_name()).to_string();
+                batch.push(locator, *fee.id(), verifier_inputs, proof.clone());
+                finish!(timer, "Add the fee proof to the batch");
+            }
+            // Ensure the fee proof is valid.
+            None => {
+                Trace::verify_fee_proof(verifier_inputs, fee)?;
+                finish!(timer, "Verify the fee proof");
+            }
         }
-        finish!(timer, "Verify the fee transition");
         Ok(())
     }
 }
 
 impl<N: Network> Process<N> {
-    /// Verifies the transition for `credits.aleo/fee_private` is well-formed.
-    fn verify_fee_private(&self, fee: &&Fee<N>) -> Result<()> {
+    /// Verifies the transition for `credits.aleo/fee_private` is well-formed,
+    /// and returns the verifying key and inputs for the fee proof.
+    fn verify_fee_private(&self, fee: &&Fee<N>) -> Result<(VerifyingKey<N>, Vec<Vec<N::Field>>)> {
         let timer = timer!("Process::verify_fee_private");
 
         // Compute the function ID as `Hash(network_id, program_id, function_name)`.
@@ -133,15 +176,14 @@ impl<N: Network> Process<N> {
         // Retrieve the verifying key.
         let verifying_key = self.get_verifying_key(fee.program_id(), fee.function_name())?;
 
-        // Ensure the fee proof is valid.
-        Trace::verify_fee_proof((verifying_key, vec![inputs]), fee)?;
-        finish!(timer, "Verify the fee proof");
-        Ok(())
+        finish!(timer, "Retrieve the verifying key");
+        Ok((verifying_key, vec![inputs]))
     }
 
-    /// Verifies the transition for `credits.aleo/fee_public` is well-formed.
+    /// Verifies the transition for `credits.aleo/fee_public` is well-formed,
+    /// and returns the verifying key and inputs for the fee proof.
     /// Attention: This method does *not* verify the account balance is sufficient.
-    fn verify_fee_public(&self, fee: &&Fee<N>) -> Result<()> {
+    fn verify_fee_public(&self, fee: &&Fee<N>) -> Result<(VerifyingKey<N>, Vec<Vec<N::Field>>)> {
         let timer = timer!("Process::verify_fee_public");
 
         // Compute the function ID as `Hash(network_id, program_id, function_name)`.
@@ -204,10 +246,8 @@ impl<N: Network> Process<N> {
         // Retrieve the verifying key.
         let verifying_key = self.get_verifying_key(fee.program_id(), fee.function_name())?;
 
-        // Ensure the fee proof is valid.
-        Trace::verify_fee_proof((verifying_key, vec![inputs]), fee)?;
-        finish!(timer, "Verify the fee proof");
-        Ok(())
+        finish!(timer, "Retrieve the verifying key");
+        Ok((verifying_key, vec![inputs]))
     }
 }
 
@@ -0,0 +1,127 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::network::prelude::*;
+
+impl<N: Network, Instruction: InstructionTrait<N> + PartialEq, Command: CommandTrait<N> + PartialEq>
+    ProgramCore<N, Instruction, Command>
+{
+    /// Returns the program in canonical form, so that two sources of the same program are byte-comparable.
+    ///
+    /// The canonical form drops comments, indents each statement by four spaces, separates each
+    /// declaration by a single blank line, and ends with a single newline. Struct and record members
+    /// keep their declaration order, and literals are written in their standard form (e.g. `1u8`).
+    pub fn to_canonical_string(&self) -> Result<String> {
+        // Normalize the whitespace of each line, and collapse consecutive blank lines.
+        let mut canonical = String::new();
+        for line in self.to_string().lines().map(str::trim_end) {
+            if !(line.is_empty() && (canonical.is_empty() || canonical.ends_with("\n\n"))) {
+                canonical.push_str(line);
+                canonical.push('\n');
+            }
+        }
+        // Ensure the program ends with a single newline.
+        let canonical = format!("{}\n", canonical.trim_end());
+
+        // Ensure the canonical form is the same program.
+        ensure!(Self::from_str(&canonical)? == *self, "The canonical form of '{}' is not the same program", self.id);
+        Ok(canonical)
+    }
+
+    /// Returns the checksum of the program at the given edition, as the SHA3-256 hash of the edition
+    /// (as 2 little-endian bytes) followed by the canonical form of the program.
+    /// Two sources of the same program have the same checksum, so audited source can be matched to on-chain code.
+    pub fn checksum(&self, edition: u16) -> Result<[u8; 32]> {
+        // Construct the preimage.
+        let mut preimage = edition.to_le_bytes().to_vec();
+        preimage.extend_from_slice(self.to_canonical_string()?.as_bytes());
+        // Hash the preimage.
+        let digest = N::hash_sha3_256(&preimage.to_bits_le())?;
+        // Convert the digest into bytes.
+        let mut checksum = [0u8; 32];
+        for (byte, bits) in checksum.iter_mut().zip_eq(digest.chunks(8)) {
+            *byte = u8::from_bits_le(bits)?;
+        }
+        Ok(checksum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Program;
+    use console::network::{prelude::*, Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    const CANONICAL: &str = r"import credits.aleo;
+
+program canonical.aleo;
+
+struct point:
+    y as u8;
+    x as u8;
+
+mapping points:
+    key owner as address.public;
+    value point as point.public;
+
+function move:
+    input r0 as point.private;
+    add r0.x 1u8 into r1;
+    cast r0.y r1 into r2 as point;
+    output r2 as point.private;
+";
+
+    #[test]
+    fn test_to_canonical_string() -> Result<()> {
+        let source = r"
+// A program with irregular formatting.
+import credits.aleo;
+program canonical.aleo;
+struct point:
+  y as u8;   // The members keep their order.
+  x as u8;
+
+
+mapping points:
+        key owner as address.public;
+        value point as point.public;
+function move:
+    input r0 as point.private;
+    add r0.x 1u8 into r1;
+    cast  r0.y  r1 into r2 as point;
+    output r2 as point.private;";
+
+        // Ensure the source formats to the canonical form.
+        let program = Program::<CurrentNetwork>::from_str(source)?;
+        assert_eq!(program.to_canonical_string()?, CANONICAL);
+
+        // Ensure the canonical form is a fixed point.
+        assert_eq!(Program::<CurrentNetwork>::from_str(CANONICAL)?.to_canonical_string()?, CANONICAL);
+        Ok(())
+    }
+
+    #[test]
+    fn test_checksum() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(CANONICAL)?;
+        let reformatted = Program::<CurrentNetwork>::from_str(&CANONICAL.replace("    ", "  "))?;
+
+        // Ensure the checksum depends on the program and edition, but not on the formatting.
+        assert_eq!(program.checksum(0)?, reformatted.checksum(0)?);
+        assert_ne!(program.checksum(0)?, program.checksum(1)?);
+        assert_ne!(program.checksum(0)?, Program::<CurrentNetwork>::credits()?.checksum(0)?);
+        Ok(())
+    }
+}
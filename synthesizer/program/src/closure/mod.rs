@@ -21,7 +21,7 @@ use output::*;
 mod bytes;
 mod parse;
 
-use crate::InstructionTrait;
+use crate::{InstructionTrait, Operand};
 use console::{
     network::prelude::*,
     program::{Identifier, Register, RegisterType},
@@ -138,6 +138,44 @@ impl<N: Network, Instruction: InstructionTrait<N>> ClosureCore<N, Instruction> {
         self.outputs.insert(output);
         Ok(())
     }
+
+    /// Replaces the instructions and the output operands of the closure.
+    /// Note: This method is used by the program optimizer, which ensures the new body is equivalent.
+    ///
+    /// # Errors
+    /// This method will halt if there are no instructions, or the maximum number of instructions is exceeded.
+    /// This method will halt if the number of output operands does not match the number of outputs.
+    #[inline]
+    pub(crate) fn replace_body(
+        &mut self,
+        instructions: Vec<Instruction>,
+        output_operands: Vec<Operand<N>>,
+    ) -> Result<()> {
+        // Ensure the number of instructions is within the bounds.
+        ensure!(!instructions.is_empty(), "Cannot remove every instruction from closure '{}'", self.name);
+        ensure!(instructions.len() <= N::MAX_INSTRUCTIONS, "Cannot add more than {} instructions", N::MAX_INSTRUCTIONS);
+        // Ensure there is an operand for each output.
+        ensure!(
+            output_operands.len() == self.outputs.len(),
+            "Expected {} output operands, found {}",
+            self.outputs.len(),
+            output_operands.len()
+        );
+        // Ensure the destination registers are locators.
+        for register in instructions.iter().flat_map(|instruction| instruction.destinations()) {
+            ensure!(matches!(register, Register::Locator(..)), "Destination register must be a locator");
+        }
+
+        // Replace the instructions and output statements.
+        self.instructions = instructions;
+        self.outputs = self
+            .outputs
+            .iter()
+            .zip_eq(output_operands)
+            .map(|(output, operand)| Output::new(operand, output.register_type().clone()))
+            .collect();
+        Ok(())
+    }
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>> TypeName for ClosureCore<N, Instruction> {
@@ -30,6 +30,12 @@ pub struct Output<N: Network> {
 }
 
 impl<N: Network> Output<N> {
+    /// Initializes a new output statement.
+    #[inline]
+    pub(crate) const fn new(operand: Operand<N>, register_type: RegisterType<N>) -> Self {
+        Self { operand, register_type }
+    }
+
     /// Returns the output register.
     #[inline]
     pub const fn operand(&self) -> &Operand<N> {
@@ -0,0 +1,249 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use core::hash::Hash;
+
+/// The kind of a program definition.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DefinitionKind {
+    Import,
+    Mapping,
+    Struct,
+    Record,
+    Closure,
+    Function,
+}
+
+impl Display for DefinitionKind {
+    /// Prints the kind as its keyword.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Import => write!(f, "import"),
+            Self::Mapping => write!(f, "mapping"),
+            Self::Struct => write!(f, "struct"),
+            Self::Record => write!(f, "record"),
+            Self::Closure => write!(f, "closure"),
+            Self::Function => write!(f, "function"),
+        }
+    }
+}
+
+/// The way a program definition changed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChangeType {
+    Added,
+    Removed,
+    Changed,
+}
+
+/// A change to a single program definition.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DefinitionChange {
+    /// The kind of the definition.
+    pub kind: DefinitionKind,
+    /// The name of the definition.
+    pub name: String,
+    /// The way the definition changed.
+    pub change: ChangeType,
+    /// Whether the change leaves existing state and interfaces valid.
+    pub is_state_compatible: bool,
+}
+
+/// A structured report of the differences between two editions of a program.
+///
+/// A change is state-compatible if the existing mappings, records, and function signatures remain valid:
+/// additions are always compatible, as are changes to closures, imports, and the bodies of functions.
+/// Removing or changing a mapping, struct, or record, and removing a function or changing its input
+/// or output types, is not.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProgramDiff {
+    /// The changes, ordered by kind, and then by their order in the program.
+    changes: Vec<DefinitionChange>,
+}
+
+impl ProgramDiff {
+    /// Returns the changes.
+    pub fn changes(&self) -> &[DefinitionChange] {
+        &self.changes
+    }
+
+    /// Returns `true` if the programs have the same definitions.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns `true` if every change is state-compatible.
+    pub fn is_state_compatible(&self) -> bool {
+        self.changes.iter().all(|change| change.is_state_compatible)
+    }
+}
+
+impl Display for ProgramDiff {
+    /// Prints one change per line, as `+` (added), `-` (removed), or `~` (changed), followed by the definition.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for change in &self.changes {
+            let symbol = match change.change {
+                ChangeType::Added => '+',
+                ChangeType::Removed => '-',
+                ChangeType::Changed => '~',
+            };
+            write!(f, "{symbol} {} {}", change.kind, change.name)?;
+            if !change.is_state_compatible {
+                write!(f, " (state-incompatible)")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network, Instruction: InstructionTrait<N> + PartialEq, Command: CommandTrait<N> + PartialEq>
+    ProgramCore<N, Instruction, Command>
+{
+    /// Returns the differences from this program to the given program, which must have the same program ID.
+    pub fn diff(&self, other: &Self) -> Result<ProgramDiff> {
+        // Ensure the program IDs match.
+        ensure!(self.id == other.id, "Cannot diff '{}' against a different program '{}'", self.id, other.id);
+
+        let mut changes = Vec::new();
+        diff_definitions(&mut changes, DefinitionKind::Import, &self.imports, &other.imports, true, |_, _| true);
+        diff_definitions(&mut changes, DefinitionKind::Mapping, &self.mappings, &other.mappings, false, |_, _| false);
+        diff_definitions(&mut changes, DefinitionKind::Struct, &self.structs, &other.structs, false, |_, _| false);
+        diff_definitions(&mut changes, DefinitionKind::Record, &self.records, &other.records, false, |_, _| false);
+        diff_definitions(&mut changes, DefinitionKind::Closure, &self.closures, &other.closures, true, |_, _| true);
+        diff_definitions(
+            &mut changes,
+            DefinitionKind::Function,
+            &self.functions,
+            &other.functions,
+            false,
+            |old, new| old.input_types() == new.input_types() && old.output_types() == new.output_types(),
+        );
+        Ok(ProgramDiff { changes })
+    }
+}
+
+/// Appends the changes between the given definitions of one kind.
+/// A removal is state-compatible if `is_removal_compatible` is set, and a change is state-compatible if `is_change_compatible` holds.
+fn diff_definitions<K: Display + Eq + Hash, V: PartialEq>(
+    changes: &mut Vec<DefinitionChange>,
+    kind: DefinitionKind,
+    old: &IndexMap<K, V>,
+    new: &IndexMap<K, V>,
+    is_removal_compatible: bool,
+    is_change_compatible: impl Fn(&V, &V) -> bool,
+) {
+    for (name, old_definition) in old {
+        match new.get(name) {
+            None => changes.push(DefinitionChange {
+                kind,
+                name: name.to_string(),
+                change: ChangeType::Removed,
+                is_state_compatible: is_removal_compatible,
+            }),
+            Some(new_definition) if old_definition != new_definition => changes.push(DefinitionChange {
+                kind,
+                name: name.to_string(),
+                change: ChangeType::Changed,
+                is_state_compatible: is_change_compatible(old_definition, new_definition),
+            }),
+            Some(_) => (),
+        }
+    }
+    for name in new.keys().filter(|name| !old.contains_key(*name)) {
+        changes.push(DefinitionChange {
+            kind,
+            name: name.to_string(),
+            change: ChangeType::Added,
+            is_state_compatible: true,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Program;
+    use console::network::{prelude::*, Testnet3};
+
+    type CurrentNetwork = Testnet3;
+
+    const OLD: &str = r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+mapping balances:
+    key owner as address.public;
+    value amount as u64.public;
+
+closure twice:
+    input r0 as u64;
+    add r0 r0 into r1;
+    output r1 as u64;
+
+function mint:
+    input r0 as u64.private;
+    output r0 as u64.private;
+
+function burn:
+    input r0 as u64.private;
+    output r0 as u64.private;";
+
+    #[test]
+    fn test_diff() -> Result<()> {
+        let old = Program::<CurrentNetwork>::from_str(OLD)?;
+
+        // Ensure a program has no differences from itself.
+        let diff = old.diff(&old)?;
+        assert!(diff.is_empty());
+        assert!(diff.is_state_compatible());
+
+        // Change the body of a function and a closure, and add a function.
+        let new = Program::<CurrentNetwork>::from_str(
+            &OLD.replace("add r0 r0 into r1;", "mul r0 2u64 into r1;")
+                .replace(
+                    "function burn:\n    input r0 as u64.private;\n    output r0 as u64.private;",
+                    "function burn:\n    input r0 as u64.private;\n    add r0 1u64 into r1;\n    output r1 as u64.private;\n\nfunction transfer:\n    input r0 as u64.private;\n    output r0 as u64.private;",
+                ),
+        )?;
+        let diff = old.diff(&new)?;
+        assert!(diff.is_state_compatible());
+        assert_eq!(diff.to_string(), "~ closure twice\n~ function burn\n+ function transfer\n");
+
+        // Change a mapping and the signature of a function, and remove a function.
+        let new = Program::<CurrentNetwork>::from_str(
+            &OLD.replace("value amount as u64.public;", "value amount as u128.public;")
+                .replace(
+                    "function mint:\n    input r0 as u64.private;\n    output r0 as u64.private;",
+                    "function mint:\n    input r0 as u64.public;\n    output r0 as u64.private;",
+                )
+                .replace("\n\nfunction burn:\n    input r0 as u64.private;\n    output r0 as u64.private;", ""),
+        )?;
+        let diff = old.diff(&new)?;
+        assert!(!diff.is_state_compatible());
+        assert_eq!(
+            diff.to_string(),
+            "~ mapping balances (state-incompatible)\n~ function mint (state-incompatible)\n- function burn (state-incompatible)\n"
+        );
+
+        // Ensure a different program cannot be diffed.
+        let other = Program::<CurrentNetwork>::from_str(&OLD.replace("token.aleo", "other.aleo"))?;
+        assert!(old.diff(&other).is_err());
+        Ok(())
+    }
+}
@@ -24,6 +24,7 @@ mod parse;
 use crate::{
     finalize::FinalizeCore,
     traits::{CommandTrait, FinalizeCommandTrait, InstructionTrait},
+    Operand,
 };
 use console::{
     network::prelude::*,
@@ -207,6 +208,59 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Fun
         self.finalize = Some((command, finalize));
         Ok(())
     }
+
+    /// Replaces the instructions, the output operands, and the finalize command of the function.
+    /// Note: This method is used by the program optimizer, which ensures the new body is equivalent.
+    ///
+    /// # Errors
+    /// This method will halt if the maximum number of instructions is exceeded.
+    /// This method will halt if the number of output operands does not match the number of outputs.
+    /// This method will halt if the finalize command is added, removed, or changes its number of operands.
+    #[inline]
+    pub(crate) fn replace_body(
+        &mut self,
+        instructions: Vec<Instruction>,
+        output_operands: Vec<Operand<N>>,
+        finalize_command: Option<Command::FinalizeCommand>,
+    ) -> Result<()> {
+        // Ensure the maximum number of instructions has not been exceeded.
+        ensure!(instructions.len() <= N::MAX_INSTRUCTIONS, "Cannot add more than {} instructions", N::MAX_INSTRUCTIONS);
+        // Ensure there is an operand for each output.
+        ensure!(
+            output_operands.len() == self.outputs.len(),
+            "Expected {} output operands, found {}",
+            self.outputs.len(),
+            output_operands.len()
+        );
+        // Ensure the destination registers are locators.
+        for register in instructions.iter().flat_map(|instruction| instruction.destinations()) {
+            ensure!(matches!(register, Register::Locator(..)), "Destination register must be a locator");
+        }
+        // Ensure the finalize command takes the same number of operands.
+        match (self.finalize_command(), &finalize_command) {
+            (Some(command), Some(finalize_command)) => ensure!(
+                command.num_operands() == finalize_command.num_operands(),
+                "The 'finalize' command of function '{}' must keep {} operands",
+                self.name,
+                command.num_operands()
+            ),
+            (None, None) => (),
+            _ => bail!("Cannot add or remove the 'finalize' command of function '{}'", self.name),
+        }
+
+        // Replace the instructions, output statements, and finalize command.
+        self.instructions = instructions;
+        self.outputs = self
+            .outputs
+            .iter()
+            .zip_eq(output_operands)
+            .map(|(output, operand)| Output::new(operand, output.value_type().clone()))
+            .collect();
+        if let (Some((command, _)), Some(finalize_command)) = (&mut self.finalize, finalize_command) {
+            *command = finalize_command;
+        }
+        Ok(())
+    }
 }
 
 impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> TypeName
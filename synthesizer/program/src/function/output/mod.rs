@@ -30,6 +30,12 @@ pub struct Output<N: Network> {
 }
 
 impl<N: Network> Output<N> {
+    /// Initializes a new output statement.
+    #[inline]
+    pub(crate) const fn new(operand: Operand<N>, value_type: ValueType<N>) -> Self {
+        Self { operand, value_type }
+    }
+
     /// Returns the output operand.
     #[inline]
     pub const fn operand(&self) -> &Operand<N> {
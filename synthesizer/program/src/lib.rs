@@ -24,6 +24,9 @@ pub type Closure<N> = crate::ClosureCore<N, Instruction<N>>;
 mod closure;
 pub use closure::*;
 
+mod diff;
+pub use diff::*;
+
 pub mod finalize;
 pub use finalize::*;
 
@@ -36,13 +39,20 @@ pub use import::*;
 pub mod logic;
 pub use logic::*;
 
+pub mod manifest;
+pub use manifest::*;
+
 mod mapping;
 pub use mapping::*;
 
+mod optimizer;
+pub use optimizer::*;
+
 pub mod traits;
 pub use traits::*;
 
 mod bytes;
+mod canonical;
 mod parse;
 mod serialize;
 
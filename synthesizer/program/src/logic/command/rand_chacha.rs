@@ -25,10 +25,33 @@ use console::{
 };
 
 use rand::SeedableRng;
+#[cfg(any(test, feature = "test"))]
+use std::{cell::RefCell, collections::VecDeque};
 
 /// The maximum number of additional seeds that can be provided.
 pub const MAX_ADDITIONAL_SEEDS: usize = 2;
 
+#[cfg(any(test, feature = "test"))]
+thread_local! {
+    /// The mocked outputs of `rand.chacha` on this thread, which are used in order in place of sampled values.
+    static MOCKED_OUTPUTS: RefCell<VecDeque<String>> = Default::default();
+}
+
+/// Mocks the outputs of the next `rand.chacha` commands that are finalized on the current thread.
+/// Each command uses the next mocked output in place of its sampled value, until none remain.
+///
+/// This is only available for testing programs against known randomness, and is never set by the VM.
+#[cfg(any(test, feature = "test"))]
+pub fn mock_rand_chacha<N: Network>(outputs: impl IntoIterator<Item = Literal<N>>) {
+    MOCKED_OUTPUTS.with(|mocked| mocked.borrow_mut().extend(outputs.into_iter().map(|output| output.to_string())));
+}
+
+/// Clears the mocked outputs of `rand.chacha` on the current thread, and returns the number that were unused.
+#[cfg(any(test, feature = "test"))]
+pub fn clear_mock_rand_chacha() -> usize {
+    MOCKED_OUTPUTS.with(|mocked| core::mem::take(&mut *mocked.borrow_mut()).len())
+}
+
 /// A random-number generator command, e.g. `rand.chacha into r1 as field;` or
 /// `rand.chacha r0 into r1 as field;`, with the latter including an optional additional seed(s).
 ///
@@ -87,6 +110,18 @@ impl<N: Network> RandChaCha<N> {
         // Load the operands values.
         let seeds: Vec<_> = self.operands.iter().map(|operand| registers.load(stack, operand)).try_collect()?;
 
+        // If an output is mocked, use it in place of a sampled value.
+        #[cfg(any(test, feature = "test"))]
+        if let Some(output) = MOCKED_OUTPUTS.with(|mocked| mocked.borrow_mut().pop_front()) {
+            let output = Literal::<N>::from_str(&output)?;
+            ensure!(
+                output.to_type() == self.destination_type,
+                "The mocked output '{output}' for 'rand.chacha' is not a '{}'",
+                self.destination_type
+            );
+            return registers.store(stack, &self.destination, Value::Plaintext(Plaintext::from(output)));
+        }
+
         // Construct the random seed.
         let preimage = to_bits_le![
             registers.state().random_seed(),
@@ -121,6 +121,16 @@ pub struct Call<N: Network> {
 }
 
 impl<N: Network> Call<N> {
+    /// Initializes a new `call` instruction.
+    #[inline]
+    pub fn new(operator: CallOperator<N>, operands: Vec<Operand<N>>, destinations: Vec<Register<N>>) -> Result<Self> {
+        // Sanity check the number of operands and destinations.
+        ensure!(operands.len() <= N::MAX_OPERANDS, "Instruction 'call' has too many operands");
+        ensure!(destinations.len() <= N::MAX_OPERANDS, "Instruction 'call' has too many destinations");
+        // Return the instruction.
+        Ok(Self { operator, operands, destinations })
+    }
+
     /// Returns the opcode.
     #[inline]
     pub const fn opcode() -> Opcode {
@@ -140,6 +140,19 @@ pub struct Cast<N: Network> {
 }
 
 impl<N: Network> Cast<N> {
+    /// Initializes a new `cast` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>, cast_type: CastType<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        let max_operands = max_operands::<N>(&cast_type);
+        ensure!(
+            !operands.is_empty() && operands.len() <= max_operands,
+            "Instruction 'cast' must have between 1 and {max_operands} operands"
+        );
+        // Return the instruction.
+        Ok(Self { operands, destination, cast_type })
+    }
+
     /// Returns the opcode.
     #[inline]
     pub const fn opcode() -> Opcode {
@@ -859,6 +872,20 @@ impl<N: Network> Cast<N> {
     }
 }
 
+/// Returns the maximum number of operands for a cast to the given type.
+fn max_operands<N: Network>(cast_type: &CastType<N>) -> usize {
+    match cast_type {
+        CastType::GroupXCoordinate
+        | CastType::GroupYCoordinate
+        | CastType::RegisterType(RegisterType::Plaintext(PlaintextType::Literal(_))) => 1,
+        CastType::RegisterType(RegisterType::Plaintext(PlaintextType::Struct(_))) => N::MAX_STRUCT_ENTRIES,
+        CastType::RegisterType(RegisterType::Plaintext(PlaintextType::Array(_))) => N::MAX_ARRAY_ELEMENTS,
+        CastType::RegisterType(RegisterType::Record(_)) | CastType::RegisterType(RegisterType::ExternalRecord(_)) => {
+            N::MAX_RECORD_ENTRIES
+        }
+    }
+}
+
 impl<N: Network> Parser for Cast<N> {
     /// Parses a string into an operation.
     #[inline]
@@ -892,15 +919,7 @@ impl<N: Network> Parser for Cast<N> {
         // Parse the cast type from the string.
         let (string, cast_type) = CastType::parse(string)?;
         // Check that the number of operands does not exceed the maximum number of data entries.
-        let max_operands = match cast_type {
-            CastType::GroupXCoordinate
-            | CastType::GroupYCoordinate
-            | CastType::RegisterType(RegisterType::Plaintext(PlaintextType::Literal(_))) => 1,
-            CastType::RegisterType(RegisterType::Plaintext(PlaintextType::Struct(_))) => N::MAX_STRUCT_ENTRIES,
-            CastType::RegisterType(RegisterType::Plaintext(PlaintextType::Array(_))) => N::MAX_ARRAY_ELEMENTS,
-            CastType::RegisterType(RegisterType::Record(_))
-            | CastType::RegisterType(RegisterType::ExternalRecord(_)) => N::MAX_RECORD_ENTRIES,
-        };
+        let max_operands = max_operands::<N>(&cast_type);
         match !operands.is_empty() && (operands.len() <= max_operands) {
             true => Ok((string, Self { operands, destination, cast_type })),
             false => {
@@ -41,6 +41,15 @@ impl<N: Network, const VARIANT: u8> FinalizeCommandTrait for FinalizeInstruction
 }
 
 impl<N: Network, const VARIANT: u8> FinalizeInstruction<N, VARIANT> {
+    /// Initializes a new `finalize` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() <= N::MAX_INPUTS, "Instruction 'finalize' must have at most {} operands", N::MAX_INPUTS);
+        // Return the instruction.
+        Ok(Self { operands })
+    }
+
     /// Returns the opcode.
     #[inline]
     pub const fn opcode() -> Opcode {
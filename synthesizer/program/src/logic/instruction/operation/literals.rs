@@ -45,6 +45,15 @@ pub struct Literals<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPE
 impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const NUM_OPERANDS: usize>
     Literals<N, O, NUM_OPERANDS>
 {
+    /// Initializes a new literal instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == NUM_OPERANDS, "Instruction '{}' must have {NUM_OPERANDS} operands", O::OPCODE);
+        // Return the instruction.
+        Ok(Self { operands, destination, _phantom: PhantomData })
+    }
+
     /// Returns the opcode.
     #[inline]
     pub const fn opcode() -> Opcode {
@@ -0,0 +1,312 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+mod serialize;
+mod string;
+
+use crate::{CommandTrait, FunctionCore, InstructionTrait, Mapping, ProgramCore};
+use console::{
+    network::prelude::*,
+    program::{Identifier, PlaintextType, ProgramID, RecordType, StructType, ValueType},
+};
+
+use indexmap::IndexMap;
+
+/// The interface of a program: its imports, types, mappings, and how to call each of its functions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProgramManifest<N: Network> {
+    /// The ID of the program.
+    program_id: ProgramID<N>,
+    /// The IDs of the imported programs.
+    imports: Vec<ProgramID<N>>,
+    /// The structs of the program.
+    structs: IndexMap<Identifier<N>, StructType<N>>,
+    /// The records of the program.
+    records: IndexMap<Identifier<N>, RecordType<N>>,
+    /// The mappings of the program.
+    mappings: IndexMap<Identifier<N>, MappingManifest<N>>,
+    /// The interfaces of the functions of the program.
+    functions: IndexMap<Identifier<N>, FunctionManifest<N>>,
+}
+
+impl<N: Network> ProgramManifest<N> {
+    /// Initializes the manifest of the given program.
+    pub fn from_program<Instruction: InstructionTrait<N>, Command: CommandTrait<N>>(
+        program: &ProgramCore<N, Instruction, Command>,
+    ) -> Self {
+        let mappings =
+            program.mappings().iter().map(|(name, mapping)| (*name, MappingManifest::from_mapping(mapping))).collect();
+        let functions = program
+            .functions()
+            .iter()
+            .map(|(name, function)| (*name, FunctionManifest::from_function(function)))
+            .collect();
+        Self {
+            program_id: *program.id(),
+            imports: program.imports().keys().copied().collect(),
+            structs: program.structs().clone(),
+            records: program.records().clone(),
+            mappings,
+            functions,
+        }
+    }
+
+    /// Returns the ID of the program.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the IDs of the imported programs.
+    pub fn imports(&self) -> &[ProgramID<N>] {
+        &self.imports
+    }
+
+    /// Returns the structs of the program.
+    pub const fn structs(&self) -> &IndexMap<Identifier<N>, StructType<N>> {
+        &self.structs
+    }
+
+    /// Returns the records of the program.
+    pub const fn records(&self) -> &IndexMap<Identifier<N>, RecordType<N>> {
+        &self.records
+    }
+
+    /// Returns the mappings of the program.
+    pub const fn mappings(&self) -> &IndexMap<Identifier<N>, MappingManifest<N>> {
+        &self.mappings
+    }
+
+    /// Returns the interfaces of the functions of the program.
+    pub const fn functions(&self) -> &IndexMap<Identifier<N>, FunctionManifest<N>> {
+        &self.functions
+    }
+
+    /// Returns the interface of the function with the given name.
+    pub fn get_function(&self, name: &Identifier<N>) -> Result<&FunctionManifest<N>> {
+        self.functions.get(name).ok_or_else(|| anyhow!("Function '{}/{name}' is not defined", self.program_id))
+    }
+}
+
+impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> ProgramCore<N, Instruction, Command> {
+    /// Returns the manifest of the program.
+    pub fn manifest(&self) -> ProgramManifest<N> {
+        ProgramManifest::from_program(self)
+    }
+}
+
+/// The interface of a mapping, describing its key and value types.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MappingManifest<N: Network> {
+    /// The name of the mapping.
+    name: Identifier<N>,
+    /// The key type of the mapping.
+    key: PlaintextType<N>,
+    /// The value type of the mapping.
+    value: PlaintextType<N>,
+}
+
+impl<N: Network> MappingManifest<N> {
+    /// Initializes the manifest of the given mapping.
+    pub fn from_mapping(mapping: &Mapping<N>) -> Self {
+        Self {
+            name: *mapping.name(),
+            key: mapping.key().plaintext_type().clone(),
+            value: mapping.value().plaintext_type().clone(),
+        }
+    }
+
+    /// Returns the name of the mapping.
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the key type of the mapping.
+    pub const fn key(&self) -> &PlaintextType<N> {
+        &self.key
+    }
+
+    /// Returns the value type of the mapping.
+    pub const fn value(&self) -> &PlaintextType<N> {
+        &self.value
+    }
+}
+
+/// The interface of a function, describing its inputs, outputs, and finalize inputs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FunctionManifest<N: Network> {
+    /// The name of the function.
+    name: Identifier<N>,
+    /// The inputs of the function.
+    inputs: Vec<ParameterManifest<N>>,
+    /// The outputs of the function.
+    outputs: Vec<ParameterManifest<N>>,
+    /// The inputs of the finalize scope, if the function has one.
+    finalize: Option<Vec<ParameterManifest<N>>>,
+}
+
+impl<N: Network> FunctionManifest<N> {
+    /// Initializes the manifest of the given function.
+    pub fn from_function<Instruction: InstructionTrait<N>, Command: CommandTrait<N>>(
+        function: &FunctionCore<N, Instruction, Command>,
+    ) -> Self {
+        let inputs = function
+            .inputs()
+            .iter()
+            .map(|input| ParameterManifest::new(input.register().to_string(), input.value_type().clone()))
+            .collect();
+        let outputs = function
+            .outputs()
+            .iter()
+            .map(|output| ParameterManifest::new(output.operand().to_string(), output.value_type().clone()))
+            .collect();
+        // Finalize inputs are always public.
+        let finalize = function.finalize_logic().map(|finalize| {
+            finalize
+                .inputs()
+                .iter()
+                .map(|input| {
+                    ParameterManifest::new(
+                        input.register().to_string(),
+                        ValueType::Public(input.plaintext_type().clone()),
+                    )
+                })
+                .collect()
+        });
+        Self { name: *function.name(), inputs, outputs, finalize }
+    }
+
+    /// Returns the name of the function.
+    pub const fn name(&self) -> &Identifier<N> {
+        &self.name
+    }
+
+    /// Returns the inputs of the function.
+    pub fn inputs(&self) -> &[ParameterManifest<N>] {
+        &self.inputs
+    }
+
+    /// Returns the outputs of the function.
+    pub fn outputs(&self) -> &[ParameterManifest<N>] {
+        &self.outputs
+    }
+
+    /// Returns the inputs of the finalize scope, if the function has one.
+    pub fn finalize(&self) -> Option<&[ParameterManifest<N>]> {
+        self.finalize.as_deref()
+    }
+}
+
+/// The interface of a function input or output.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParameterManifest<N: Network> {
+    /// The name of the parameter, as its register (for inputs) or operand (for outputs).
+    name: String,
+    /// The value type of the parameter.
+    value_type: ValueType<N>,
+}
+
+impl<N: Network> ParameterManifest<N> {
+    /// Initializes a new parameter manifest.
+    pub const fn new(name: String, value_type: ValueType<N>) -> Self {
+        Self { name, value_type }
+    }
+
+    /// Returns the name of the parameter.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the value type of the parameter.
+    pub const fn value_type(&self) -> &ValueType<N> {
+        &self.value_type
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Program;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_manifest() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+import credits.aleo;
+
+program token.aleo;
+
+struct metadata:
+    supply as u64;
+    decimals as u8;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+mapping balances:
+    key left as address.public;
+    value right as u64.public;
+
+function transfer:
+    input r0 as token.record;
+    input r1 as address.private;
+    input r2 as u64.private;
+    sub r0.amount r2 into r3;
+    cast r1 r2 into r4 as token.record;
+    cast r0.owner r3 into r5 as token.record;
+    output r4 as token.record;
+    output r5 as token.record;
+
+function mint_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize mint_public:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use balances[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into balances[r0];",
+        )?;
+        let manifest = program.manifest();
+        assert_eq!(manifest, ProgramManifest::from_program(&program));
+        assert_eq!(manifest.program_id().to_string(), "token.aleo");
+
+        let function = manifest.get_function(&Identifier::from_str("transfer")?)?;
+        assert_eq!(function.inputs().iter().map(|input| input.name()).collect::<Vec<_>>(), ["r0", "r1", "r2"]);
+        assert_eq!(function.inputs()[1].value_type(), &ValueType::from_str("address.private")?);
+        assert_eq!(function.outputs().iter().map(|output| output.name()).collect::<Vec<_>>(), ["r4", "r5"]);
+        assert_eq!(function.outputs()[0].value_type(), &ValueType::from_str("token.record")?);
+        assert!(function.finalize().is_none());
+        assert!(manifest.get_function(&Identifier::from_str("mint")?).is_err());
+
+        // Check the imports, types, and mappings.
+        assert_eq!(manifest.imports(), [ProgramID::from_str("credits.aleo")?]);
+        assert_eq!(manifest.structs().keys().map(ToString::to_string).collect::<Vec<_>>(), ["metadata"]);
+        assert_eq!(manifest.records().keys().map(ToString::to_string).collect::<Vec<_>>(), ["token"]);
+        let mapping = &manifest.mappings()[&Identifier::from_str("balances")?];
+        assert_eq!(mapping.key(), &PlaintextType::from_str("address")?);
+        assert_eq!(mapping.value(), &PlaintextType::from_str("u64")?);
+
+        // Check the finalize inputs.
+        let function = manifest.get_function(&Identifier::from_str("mint_public")?)?;
+        let finalize = function.finalize().unwrap();
+        assert_eq!(finalize.iter().map(|input| input.name()).collect::<Vec<_>>(), ["r0", "r1"]);
+        assert_eq!(finalize[1].value_type(), &ValueType::from_str("u64.public")?);
+        Ok(())
+    }
+}
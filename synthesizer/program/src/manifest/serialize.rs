@@ -0,0 +1,230 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+use serde_json::{json, Map, Value as Json};
+
+impl<N: Network> ProgramManifest<N> {
+    /// Returns the manifest as JSON, with the members of each object in a stable order.
+    pub fn to_json(&self) -> Json {
+        json!({
+            "program": self.program_id.to_string(),
+            "imports": self.imports.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            "structs": self.structs.values().map(|struct_| json!({
+                "name": struct_.name().to_string(),
+                "members": to_json_map(struct_.members()),
+            })).collect::<Vec<_>>(),
+            "records": self.records.values().map(|record| json!({
+                "name": record.name().to_string(),
+                "owner": format!("address.{}", record.owner()),
+                "entries": to_json_map(record.entries()),
+            })).collect::<Vec<_>>(),
+            "mappings": self.mappings.values().map(|mapping| json!({
+                "name": mapping.name.to_string(),
+                "key": mapping.key.to_string(),
+                "value": mapping.value.to_string(),
+            })).collect::<Vec<_>>(),
+            "functions": self.functions.values().map(|function| json!({
+                "name": function.name.to_string(),
+                "inputs": to_json_parameters(&function.inputs),
+                "outputs": to_json_parameters(&function.outputs),
+                "finalize": function.finalize.as_ref().map(|finalize| to_json_parameters(finalize)),
+            })).collect::<Vec<_>>(),
+        })
+    }
+
+    /// Initializes the manifest from its JSON representation.
+    pub fn from_json(manifest: &Json) -> Result<Self> {
+        let program_id = ProgramID::from_str(get_str(manifest, "program")?)?;
+        let imports = get_array(manifest, "imports")?
+            .iter()
+            .map(|import| ProgramID::from_str(as_str(import, "import")?))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Recover the structs and records by parsing their declarations.
+        let structs = get_array(manifest, "structs")?
+            .iter()
+            .map(|struct_| {
+                let mut declaration = format!("struct {}:", get_str(struct_, "name")?);
+                for (name, member_type) in get_object(struct_, "members")? {
+                    declaration.push_str(&format!("\n    {name} as {};", as_str(member_type, name)?));
+                }
+                let struct_ = StructType::<N>::from_str(&declaration)?;
+                Ok((*struct_.name(), struct_))
+            })
+            .collect::<Result<IndexMap<_, _>>>()?;
+        let records = get_array(manifest, "records")?
+            .iter()
+            .map(|record| {
+                let mut declaration = format!("record {}:", get_str(record, "name")?);
+                declaration.push_str(&format!("\n    owner as {};", get_str(record, "owner")?));
+                for (name, entry_type) in get_object(record, "entries")? {
+                    declaration.push_str(&format!("\n    {name} as {};", as_str(entry_type, name)?));
+                }
+                let record = RecordType::<N>::from_str(&declaration)?;
+                Ok((*record.name(), record))
+            })
+            .collect::<Result<IndexMap<_, _>>>()?;
+
+        let mappings = get_array(manifest, "mappings")?
+            .iter()
+            .map(|mapping| {
+                let name = Identifier::from_str(get_str(mapping, "name")?)?;
+                let key = PlaintextType::from_str(get_str(mapping, "key")?)?;
+                let value = PlaintextType::from_str(get_str(mapping, "value")?)?;
+                Ok((name, MappingManifest { name, key, value }))
+            })
+            .collect::<Result<IndexMap<_, _>>>()?;
+        let functions = get_array(manifest, "functions")?
+            .iter()
+            .map(|function| {
+                let name = Identifier::from_str(get_str(function, "name")?)?;
+                let inputs = from_json_parameters(get_array(function, "inputs")?)?;
+                let outputs = from_json_parameters(get_array(function, "outputs")?)?;
+                let finalize = match function.get("finalize") {
+                    Some(Json::Null) | None => None,
+                    Some(finalize) => Some(from_json_parameters(as_array(finalize, "finalize")?)?),
+                };
+                Ok((name, FunctionManifest { name, inputs, outputs, finalize }))
+            })
+            .collect::<Result<IndexMap<_, _>>>()?;
+
+        Ok(Self { program_id, imports, structs, records, mappings, functions })
+    }
+}
+
+impl<N: Network> Serialize for ProgramManifest<N> {
+    /// Serializes the manifest into JSON.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_json().serialize(serializer)
+    }
+}
+
+impl<'de, N: Network> Deserialize<'de> for ProgramManifest<N> {
+    /// Deserializes the manifest from JSON.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::from_json(&Json::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+/// Returns the given named types as a JSON object.
+fn to_json_map<T: Display>(types: &IndexMap<Identifier<impl Network>, T>) -> Json {
+    Json::Object(types.iter().map(|(name, type_)| (name.to_string(), Json::String(type_.to_string()))).collect())
+}
+
+/// Returns the given parameters as a JSON array.
+fn to_json_parameters<N: Network>(parameters: &[ParameterManifest<N>]) -> Json {
+    Json::Array(
+        parameters
+            .iter()
+            .map(|parameter| json!({ "name": parameter.name, "type": parameter.value_type.to_string() }))
+            .collect(),
+    )
+}
+
+/// Returns the parameters from the given JSON array.
+fn from_json_parameters<N: Network>(parameters: &[Json]) -> Result<Vec<ParameterManifest<N>>> {
+    parameters
+        .iter()
+        .map(|parameter| {
+            Ok(ParameterManifest::new(
+                get_str(parameter, "name")?.to_string(),
+                ValueType::from_str(get_str(parameter, "type")?)?,
+            ))
+        })
+        .collect()
+}
+
+/// Returns the string member with the given key.
+fn get_str<'a>(value: &'a Json, key: &str) -> Result<&'a str> {
+    as_str(value.get(key).ok_or_else(|| anyhow!("Missing '{key}' in the manifest"))?, key)
+}
+
+/// Returns the array member with the given key.
+fn get_array<'a>(value: &'a Json, key: &str) -> Result<&'a [Json]> {
+    as_array(value.get(key).ok_or_else(|| anyhow!("Missing '{key}' in the manifest"))?, key)
+}
+
+/// Returns the object member with the given key.
+fn get_object<'a>(value: &'a Json, key: &str) -> Result<&'a Map<String, Json>> {
+    value.get(key).and_then(Json::as_object).ok_or_else(|| anyhow!("Expected an object for '{key}' in the manifest"))
+}
+
+/// Returns the given value as a string.
+fn as_str<'a>(value: &'a Json, key: &str) -> Result<&'a str> {
+    value.as_str().ok_or_else(|| anyhow!("Expected a string for '{key}' in the manifest"))
+}
+
+/// Returns the given value as an array.
+fn as_array<'a>(value: &'a Json, key: &str) -> Result<&'a [Json]> {
+    value.as_array().map(Vec::as_slice).ok_or_else(|| anyhow!("Expected an array for '{key}' in the manifest"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Program;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_serde_json() -> Result<()> {
+        let expected = Program::<CurrentNetwork>::credits()?.manifest();
+
+        // Serialize
+        let expected_string = expected.to_string();
+        let candidate_string = serde_json::to_string_pretty(&expected)?;
+        assert_eq!(expected_string, candidate_string);
+
+        // Deserialize
+        assert_eq!(expected, ProgramManifest::from_str(&expected_string)?);
+        assert_eq!(expected, serde_json::from_str(&candidate_string)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_stable_json() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as address.private;
+    input r1 as u64.private;
+    cast r0 r1 into r2 as token.record;
+    output r2 as token.record;",
+        )?;
+
+        let expected = json!({
+            "program": "token.aleo",
+            "imports": [],
+            "structs": [],
+            "records": [{ "name": "token", "owner": "address.private", "entries": { "amount": "u64.private" } }],
+            "mappings": [],
+            "functions": [{
+                "name": "mint",
+                "inputs": [{ "name": "r0", "type": "address.private" }, { "name": "r1", "type": "u64.private" }],
+                "outputs": [{ "name": "r2", "type": "token.record" }],
+                "finalize": null,
+            }],
+        });
+        assert_eq!(expected, program.manifest().to_json());
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+
+impl<N: Network> FromStr for ProgramManifest<N> {
+    type Err = Error;
+
+    /// Initializes the manifest from a JSON string.
+    fn from_str(manifest: &str) -> Result<Self, Self::Err> {
+        Ok(serde_json::from_str(manifest)?)
+    }
+}
+
+impl<N: Network> Display for ProgramManifest<N> {
+    /// Displays the manifest as a JSON string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string_pretty(self).map_err::<fmt::Error, _>(ser::Error::custom)?)
+    }
+}
@@ -0,0 +1,299 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    instruction,
+    AssertInstruction,
+    Call,
+    Cast,
+    CastType,
+    CommitInstruction,
+    HashInstruction,
+    Instruction,
+    IsInstruction,
+    Literals,
+    Operand,
+    Operation,
+    SignVerify,
+};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, PlaintextType, Register, RegisterType},
+    types::Boolean,
+};
+
+/// The operations the optimizer performs on each kind of instruction.
+trait OptimizeOperation<N: Network>: Sized {
+    /// Returns the instruction with its operands and destinations replaced by the given functions.
+    fn map_registers(
+        &self,
+        operand: &impl Fn(&Operand<N>) -> Operand<N>,
+        destination: &impl Fn(&Register<N>) -> Register<N>,
+    ) -> Result<Self>;
+
+    /// Returns the output of the instruction, if every operand is a literal and the instruction does not halt.
+    fn fold(&self) -> Option<Literal<N>> {
+        None
+    }
+
+    /// Returns the literal type of the output, if the instruction outputs a literal of a known type.
+    fn output_literal_type(&self, _operand_types: &[Option<LiteralType>]) -> Option<LiteralType> {
+        None
+    }
+}
+
+/// Returns the literals of the given operands, if every operand is a literal.
+fn literals<N: Network, const NUM_OPERANDS: usize>(operands: &[Operand<N>]) -> Option<[Literal<N>; NUM_OPERANDS]> {
+    operands
+        .iter()
+        .map(|operand| match operand {
+            Operand::Literal(literal) => Some(literal.clone()),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?
+        .try_into()
+        .ok()
+}
+
+impl<N: Network, O: Operation<N, Literal<N>, LiteralType, NUM_OPERANDS>, const NUM_OPERANDS: usize> OptimizeOperation<N>
+    for Literals<N, O, NUM_OPERANDS>
+{
+    fn map_registers(
+        &self,
+        operand: &impl Fn(&Operand<N>) -> Operand<N>,
+        destination: &impl Fn(&Register<N>) -> Register<N>,
+    ) -> Result<Self> {
+        Self::new(self.operands().iter().map(operand).collect(), destination(&self.destinations()[0]))
+    }
+
+    fn fold(&self) -> Option<Literal<N>> {
+        // Note: If the operation halts on the literals, it is not folded, so that the program still halts.
+        // The operation halts either with an error, or with a panic that is caught in `Instruction::fold`.
+        O::evaluate(&literals(self.operands())?).ok()
+    }
+
+    fn output_literal_type(&self, operand_types: &[Option<LiteralType>]) -> Option<LiteralType> {
+        let operand_types: [LiteralType; NUM_OPERANDS] =
+            operand_types.iter().copied().collect::<Option<Vec<_>>>()?.try_into().ok()?;
+        O::output_type(&operand_types).ok()
+    }
+}
+
+impl<N: Network, const VARIANT: u8> OptimizeOperation<N> for AssertInstruction<N, VARIANT> {
+    fn map_registers(
+        &self,
+        operand: &impl Fn(&Operand<N>) -> Operand<N>,
+        _destination: &impl Fn(&Register<N>) -> Register<N>,
+    ) -> Result<Self> {
+        Self::new(self.operands().iter().map(operand).collect())
+    }
+}
+
+impl<N: Network> OptimizeOperation<N> for Call<N> {
+    fn map_registers(
+        &self,
+        operand: &impl Fn(&Operand<N>) -> Operand<N>,
+        destination: &impl Fn(&Register<N>) -> Register<N>,
+    ) -> Result<Self> {
+        Self::new(
+            self.operator().clone(),
+            self.operands().iter().map(operand).collect(),
+            self.destinations().iter().map(destination).collect(),
+        )
+    }
+}
+
+impl<N: Network> OptimizeOperation<N> for Cast<N> {
+    fn map_registers(
+        &self,
+        operand: &impl Fn(&Operand<N>) -> Operand<N>,
+        destination: &impl Fn(&Register<N>) -> Register<N>,
+    ) -> Result<Self> {
+        Self::new(
+            self.operands().iter().map(operand).collect(),
+            destination(&self.destinations()[0]),
+            self.cast_type().clone(),
+        )
+    }
+
+    fn fold(&self) -> Option<Literal<N>> {
+        let [literal] = literals::<N, 1>(self.operands())?;
+        match (self.cast_type(), literal) {
+            (CastType::GroupXCoordinate, Literal::Group(group)) => Some(Literal::Field(group.to_x_coordinate())),
+            (CastType::GroupYCoordinate, Literal::Group(group)) => Some(Literal::Field(group.to_y_coordinate())),
+            // Note: If the cast halts on the literal, it is not folded, so that the program still halts.
+            (CastType::RegisterType(RegisterType::Plaintext(PlaintextType::Literal(literal_type))), literal) => {
+                literal.cast(*literal_type).ok()
+            }
+            _ => None,
+        }
+    }
+
+    fn output_literal_type(&self, _operand_types: &[Option<LiteralType>]) -> Option<LiteralType> {
+        match self.cast_type() {
+            CastType::GroupXCoordinate | CastType::GroupYCoordinate => Some(LiteralType::Field),
+            CastType::RegisterType(RegisterType::Plaintext(PlaintextType::Literal(literal_type))) => {
+                Some(*literal_type)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> OptimizeOperation<N> for CommitInstruction<N, VARIANT> {
+    fn map_registers(
+        &self,
+        operand: &impl Fn(&Operand<N>) -> Operand<N>,
+        destination: &impl Fn(&Register<N>) -> Register<N>,
+    ) -> Result<Self> {
+        Self::new(
+            self.operands().iter().map(operand).collect(),
+            destination(&self.destinations()[0]),
+            self.destination_type(),
+        )
+    }
+
+    fn output_literal_type(&self, _operand_types: &[Option<LiteralType>]) -> Option<LiteralType> {
+        Some(self.destination_type())
+    }
+}
+
+impl<N: Network, const VARIANT: u8> OptimizeOperation<N> for HashInstruction<N, VARIANT> {
+    fn map_registers(
+        &self,
+        operand: &impl Fn(&Operand<N>) -> Operand<N>,
+        destination: &impl Fn(&Register<N>) -> Register<N>,
+    ) -> Result<Self> {
+        Self::new(
+            self.operands().iter().map(operand).collect(),
+            destination(&self.destinations()[0]),
+            self.destination_type().clone(),
+        )
+    }
+
+    fn output_literal_type(&self, _operand_types: &[Option<LiteralType>]) -> Option<LiteralType> {
+        match self.destination_type() {
+            PlaintextType::Literal(literal_type) => Some(*literal_type),
+            _ => None,
+        }
+    }
+}
+
+impl<N: Network, const VARIANT: u8> OptimizeOperation<N> for IsInstruction<N, VARIANT> {
+    fn map_registers(
+        &self,
+        operand: &impl Fn(&Operand<N>) -> Operand<N>,
+        destination: &impl Fn(&Register<N>) -> Register<N>,
+    ) -> Result<Self> {
+        Self::new(self.operands().iter().map(operand).collect(), destination(&self.destinations()[0]))
+    }
+
+    fn fold(&self) -> Option<Literal<N>> {
+        let [first, second] = literals::<N, 2>(self.operands())?;
+        // Ensure the literals are of the same type, as otherwise the instruction is ill-typed.
+        if first.to_type() != second.to_type() {
+            return None;
+        }
+        match VARIANT {
+            0 => Some(Literal::Boolean(Boolean::new(first == second))),
+            1 => Some(Literal::Boolean(Boolean::new(first != second))),
+            _ => None,
+        }
+    }
+
+    fn output_literal_type(&self, _operand_types: &[Option<LiteralType>]) -> Option<LiteralType> {
+        Some(LiteralType::Boolean)
+    }
+}
+
+impl<N: Network> OptimizeOperation<N> for SignVerify<N> {
+    fn map_registers(
+        &self,
+        operand: &impl Fn(&Operand<N>) -> Operand<N>,
+        destination: &impl Fn(&Register<N>) -> Register<N>,
+    ) -> Result<Self> {
+        Self::new(self.operands().iter().map(operand).collect(), destination(&self.destinations()[0]))
+    }
+
+    fn output_literal_type(&self, _operand_types: &[Option<LiteralType>]) -> Option<LiteralType> {
+        Some(LiteralType::Boolean)
+    }
+}
+
+impl<N: Network> Instruction<N> {
+    /// Returns the instruction with its operands and destinations replaced by the given functions.
+    pub(crate) fn map_registers(
+        &self,
+        operand: impl Fn(&Operand<N>) -> Operand<N>,
+        destination: impl Fn(&Register<N>) -> Register<N>,
+    ) -> Result<Self> {
+        instruction!(self, |instruction| Ok(Self::from(instruction.map_registers(&operand, &destination)?)))
+    }
+
+    /// Returns the output of the instruction, if every operand is a literal and the instruction does not halt.
+    pub(crate) fn fold(&self) -> Option<Literal<N>> {
+        // Note: Without unwinding, a halt cannot be caught, so only the instructions that never halt are folded.
+        if cfg!(panic = "abort") && !self.is_infallible() {
+            return None;
+        }
+        // Note: The console operations halt by panicking, so a halt is caught, and the instruction is not folded.
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| instruction!(self, |instruction| instruction.fold())))
+            .ok()
+            .flatten()
+    }
+
+    /// Returns the literal type of the output, given the literal types of the operands (if known).
+    pub(crate) fn output_literal_type(&self, operand_types: &[Option<LiteralType>]) -> Option<LiteralType> {
+        instruction!(self, |instruction| instruction.output_literal_type(operand_types))
+    }
+
+    /// Returns `true` if the instruction computes its output from its operands alone.
+    /// Note: Calls are not pure, and neither are casts to records, as the record nonce depends on the destination register.
+    pub(crate) fn is_pure(&self) -> bool {
+        match self {
+            Self::Call(..) => false,
+            Self::Cast(cast) => !matches!(
+                cast.cast_type(),
+                CastType::RegisterType(RegisterType::Record(..) | RegisterType::ExternalRecord(..))
+            ),
+            _ => true,
+        }
+    }
+
+    /// Returns `true` if the instruction never halts on well-typed operands,
+    /// and so may be removed when its destination is unused.
+    pub(crate) fn is_infallible(&self) -> bool {
+        match self {
+            Self::AbsWrapped(..)
+            | Self::AddWrapped(..)
+            | Self::And(..)
+            | Self::GreaterThan(..)
+            | Self::GreaterThanOrEqual(..)
+            | Self::IsEq(..)
+            | Self::IsNeq(..)
+            | Self::LessThan(..)
+            | Self::LessThanOrEqual(..)
+            | Self::MulWrapped(..)
+            | Self::Nand(..)
+            | Self::Nor(..)
+            | Self::Not(..)
+            | Self::Or(..)
+            | Self::SubWrapped(..)
+            | Self::Ternary(..)
+            | Self::Xor(..) => true,
+            Self::Cast(cast) => matches!(cast.cast_type(), CastType::GroupXCoordinate | CastType::GroupYCoordinate),
+            _ => false,
+        }
+    }
+}
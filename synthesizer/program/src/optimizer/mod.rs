@@ -0,0 +1,472 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod instruction;
+
+use crate::{CastType, Closure, Command, FinalizeCommand, Function, Instruction, InstructionTrait, Operand};
+use console::{
+    network::prelude::*,
+    program::{LiteralType, PlaintextType, Register, RegisterType, ValueType},
+};
+
+use std::collections::{HashMap, HashSet};
+
+/// The passes of the program optimizer. Every pass is enabled by default, and may be disabled to opt out of it,
+/// except for common subexpression elimination, which is disabled by default, and must be enabled to opt in to it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OptimizerConfig {
+    /// Folds instructions on literal operands into a literal, and propagates the literal into later instructions.
+    pub fold_constants: bool,
+    /// Replaces the uses of a cast of a register into its own literal type with the register.
+    pub eliminate_redundant_casts: bool,
+    /// Removes the instructions whose destination registers are unused, if they cannot halt.
+    pub eliminate_dead_registers: bool,
+    /// Replaces the uses of a pure instruction that repeats an earlier instruction on identical operands
+    /// with the destination register of the earlier instruction.
+    pub eliminate_common_subexpressions: bool,
+}
+
+impl Default for OptimizerConfig {
+    /// Returns a configuration with every pass enabled, except for common subexpression elimination.
+    fn default() -> Self {
+        Self {
+            fold_constants: true,
+            eliminate_redundant_casts: true,
+            eliminate_dead_registers: true,
+            eliminate_common_subexpressions: false,
+        }
+    }
+}
+
+impl OptimizerConfig {
+    /// Returns a configuration with every pass disabled.
+    pub const fn disabled() -> Self {
+        Self {
+            fold_constants: false,
+            eliminate_redundant_casts: false,
+            eliminate_dead_registers: false,
+            eliminate_common_subexpressions: false,
+        }
+    }
+}
+
+impl<N: Network> crate::ProgramCore<N, Instruction<N>, Command<N>> {
+    /// Returns the program with the default optimizer passes applied to its closures and functions.
+    pub fn optimize(&self) -> Result<Self> {
+        self.optimize_with(OptimizerConfig::default())
+    }
+
+    /// Returns the program with the given optimizer passes applied to its closures and functions.
+    ///
+    /// The optimizer runs before synthesis, to reduce the number of constraints of mechanically generated programs.
+    /// It preserves the inputs, outputs, and finalize scope of each closure and function, and renumbers
+    /// the registers of the remaining instructions in order. Instructions that halt are never removed.
+    /// Note: The optimized program is a different program, and must be deployed in place of the original one.
+    pub fn optimize_with(&self, config: OptimizerConfig) -> Result<Self> {
+        let mut program = self.clone();
+        for closure in program.closures.values_mut() {
+            optimize_closure(closure, &config)?;
+        }
+        for function in program.functions.values_mut() {
+            optimize_function(function, &config)?;
+        }
+        Ok(program)
+    }
+}
+
+/// Optimizes the instructions of the given closure.
+fn optimize_closure<N: Network>(closure: &mut Closure<N>, config: &OptimizerConfig) -> Result<()> {
+    // Retrieve the literal types of the inputs.
+    let input_types = closure
+        .inputs()
+        .iter()
+        .map(|input| match input.register_type() {
+            RegisterType::Plaintext(PlaintextType::Literal(literal_type)) => Some(*literal_type),
+            _ => None,
+        })
+        .collect();
+    let outputs = closure.outputs().iter().map(|output| output.operand().clone()).collect();
+
+    // Optimize the body.
+    let body = optimize_body(config, input_types, closure.instructions(), outputs, None)?;
+    // Note: A closure must have at least one instruction, so a closure without any live instruction is left as is.
+    match body.instructions.is_empty() {
+        true => Ok(()),
+        false => closure.replace_body(body.instructions, body.outputs),
+    }
+}
+
+/// Optimizes the instructions of the given function.
+fn optimize_function<N: Network>(function: &mut Function<N>, config: &OptimizerConfig) -> Result<()> {
+    // Retrieve the literal types of the inputs.
+    let input_types = function
+        .inputs()
+        .iter()
+        .map(|input| match input.value_type() {
+            ValueType::Constant(PlaintextType::Literal(literal_type))
+            | ValueType::Public(PlaintextType::Literal(literal_type))
+            | ValueType::Private(PlaintextType::Literal(literal_type)) => Some(*literal_type),
+            _ => None,
+        })
+        .collect();
+    let outputs = function.outputs().iter().map(|output| output.operand().clone()).collect();
+    let finalize = function.finalize_command().map(|command| command.operands().to_vec());
+
+    // Optimize the body.
+    let body = optimize_body(config, input_types, function.instructions(), outputs, finalize)?;
+    let finalize_command = body.finalize.map(FinalizeCommand::new).transpose()?;
+    function.replace_body(body.instructions, body.outputs, finalize_command)
+}
+
+/// The instructions, output operands, and finalize operands of a closure or function.
+struct Body<N: Network> {
+    /// The instructions.
+    instructions: Vec<Instruction<N>>,
+    /// The output operands.
+    outputs: Vec<Operand<N>>,
+    /// The finalize operands, if the function has a finalize command.
+    finalize: Option<Vec<Operand<N>>>,
+}
+
+/// Optimizes the given body, where the inputs have the given literal types (if known).
+fn optimize_body<N: Network>(
+    config: &OptimizerConfig,
+    input_types: Vec<Option<LiteralType>>,
+    instructions: &[Instruction<N>],
+    outputs: Vec<Operand<N>>,
+    finalize: Option<Vec<Operand<N>>>,
+) -> Result<Body<N>> {
+    let num_inputs = input_types.len() as u64;
+
+    // Initialize the literal types of the registers, if known.
+    let mut register_types: HashMap<u64, LiteralType> = input_types
+        .into_iter()
+        .enumerate()
+        .filter_map(|(locator, literal_type)| Some((locator as u64, literal_type?)))
+        .collect();
+    // Initialize the operands that replace the uses of a register.
+    let mut substitutions: HashMap<u64, Operand<N>> = HashMap::new();
+    // Initialize the destination registers of the pure instructions, with the destination replaced by a placeholder.
+    let mut subexpressions: HashMap<Instruction<N>, u64> = HashMap::new();
+
+    // Fold the constants, and eliminate the redundant casts and common subexpressions, in order of execution.
+    let mut is_replaced = Vec::with_capacity(instructions.len());
+    let instructions = instructions
+        .iter()
+        .map(|instruction| {
+            // Replace the operands with their substitutions.
+            let instruction = instruction.map_registers(|operand| substitute(&substitutions, operand), Clone::clone)?;
+
+            // Determine if the destination can be replaced in every later instruction.
+            let mut replacement = None;
+            if let [Register::Locator(destination)] = instruction.destinations().as_slice() {
+                let operand_types: Vec<_> =
+                    instruction.operands().iter().map(|operand| literal_type(&register_types, operand)).collect();
+                if config.fold_constants {
+                    replacement = instruction.fold().map(Operand::Literal);
+                }
+                if replacement.is_none() && config.eliminate_redundant_casts {
+                    replacement = redundant_cast(&instruction, &operand_types);
+                }
+                if replacement.is_none() && config.eliminate_common_subexpressions && instruction.is_pure() {
+                    // Note: Registers are assigned exactly once, so identical operands hold identical values.
+                    let subexpression = instruction.map_registers(Clone::clone, |_| Register::Locator(u64::MAX))?;
+                    match subexpressions.get(&subexpression) {
+                        Some(source) => replacement = Some(Operand::Register(Register::Locator(*source))),
+                        None => {
+                            subexpressions.insert(subexpression, *destination);
+                        }
+                    }
+                }
+                // Track the literal type of the destination.
+                let destination_type = match &replacement {
+                    Some(operand) => literal_type(&register_types, operand),
+                    None => instruction.output_literal_type(&operand_types),
+                };
+                if let Some(destination_type) = destination_type {
+                    register_types.insert(*destination, destination_type);
+                }
+                if let Some(replacement) = &replacement {
+                    substitutions.insert(*destination, replacement.clone());
+                }
+            }
+            is_replaced.push(replacement.is_some());
+            Ok(instruction)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Replace the registers in the outputs and finalize operands with the registers they alias.
+    // Note: Literals are not substituted here, and an output is kept if its substitution duplicates another output.
+    let mut output_operands: HashSet<_> = outputs.iter().cloned().collect();
+    let outputs: Vec<_> = outputs
+        .iter()
+        .map(|operand| match substitute(&substitutions, operand) {
+            alias @ Operand::Register(..) if output_operands.insert(alias.clone()) => alias,
+            _ => operand.clone(),
+        })
+        .collect();
+    let finalize = finalize.map(|operands| {
+        operands
+            .iter()
+            .map(|operand| match substitute(&substitutions, operand) {
+                alias @ Operand::Register(..) => alias,
+                _ => operand.clone(),
+            })
+            .collect::<Vec<_>>()
+    });
+
+    // Eliminate the dead registers, in reverse order of execution.
+    let mut is_kept = vec![true; instructions.len()];
+    if config.eliminate_dead_registers {
+        // Initialize the live registers with the registers used by the outputs and finalize operands.
+        let mut live: HashSet<u64> = outputs.iter().chain(finalize.iter().flatten()).filter_map(locator).collect();
+        for (index, instruction) in instructions.iter().enumerate().rev() {
+            let destinations = instruction.destinations();
+            let is_dead =
+                !destinations.is_empty() && destinations.iter().all(|register| !live.contains(&register.locator()));
+            match is_dead && (is_replaced[index] || instruction.is_infallible()) {
+                true => is_kept[index] = false,
+                false => live.extend(instruction.operands().iter().filter_map(locator)),
+            }
+        }
+    }
+
+    // Renumber the destination registers of the remaining instructions in order.
+    let mut locators: HashMap<u64, u64> = (0..num_inputs).map(|locator| (locator, locator)).collect();
+    for (instruction, _) in instructions.iter().zip_eq(&is_kept).filter(|(_, is_kept)| **is_kept) {
+        for register in instruction.destinations() {
+            locators.insert(register.locator(), locators.len() as u64);
+        }
+    }
+    let rename = |register: &Register<N>| {
+        let locator = locators.get(&register.locator()).copied().unwrap_or(register.locator());
+        match register {
+            Register::Locator(..) => Register::Locator(locator),
+            Register::Access(_, accesses) => Register::Access(locator, accesses.clone()),
+        }
+    };
+    let rename_operand = |operand: &Operand<N>| match operand {
+        Operand::Register(register) => Operand::Register(rename(register)),
+        _ => operand.clone(),
+    };
+
+    Ok(Body {
+        instructions: instructions
+            .iter()
+            .zip_eq(is_kept)
+            .filter(|(_, is_kept)| *is_kept)
+            .map(|(instruction, _)| instruction.map_registers(rename_operand, rename))
+            .collect::<Result<_>>()?,
+        outputs: outputs.iter().map(rename_operand).collect(),
+        finalize: finalize.map(|operands| operands.iter().map(rename_operand).collect()),
+    })
+}
+
+/// Returns the given operand, with a register replaced by its substitution (if any).
+fn substitute<N: Network>(substitutions: &HashMap<u64, Operand<N>>, operand: &Operand<N>) -> Operand<N> {
+    match operand {
+        Operand::Register(Register::Locator(locator)) => {
+            substitutions.get(locator).cloned().unwrap_or_else(|| operand.clone())
+        }
+        _ => operand.clone(),
+    }
+}
+
+/// Returns the literal type of the given operand, if known.
+fn literal_type<N: Network>(register_types: &HashMap<u64, LiteralType>, operand: &Operand<N>) -> Option<LiteralType> {
+    match operand {
+        Operand::Literal(literal) => Some(literal.to_type()),
+        Operand::Register(Register::Locator(locator)) => register_types.get(locator).copied(),
+        Operand::Register(Register::Access(..)) => None,
+        Operand::ProgramID(..) | Operand::Caller => Some(LiteralType::Address),
+        Operand::BlockHeight => Some(LiteralType::U32),
+    }
+}
+
+/// Returns the locator of the given operand, if it is a register.
+fn locator<N: Network>(operand: &Operand<N>) -> Option<u64> {
+    match operand {
+        Operand::Register(register) => Some(register.locator()),
+        _ => None,
+    }
+}
+
+/// Returns the operand of the given instruction, if it casts the operand into its own literal type.
+fn redundant_cast<N: Network>(
+    instruction: &Instruction<N>,
+    operand_types: &[Option<LiteralType>],
+) -> Option<Operand<N>> {
+    let Instruction::Cast(cast) = instruction else { return None };
+    match (cast.cast_type(), cast.operands(), operand_types) {
+        (
+            CastType::RegisterType(RegisterType::Plaintext(PlaintextType::Literal(literal_type))),
+            [operand],
+            [Some(operand_type)],
+        ) if literal_type == operand_type => Some(operand.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Program;
+    use console::{network::Testnet3, program::Identifier};
+
+    type CurrentNetwork = Testnet3;
+
+    const PROGRAM: &str = r"
+program optimize.aleo;
+
+mapping counts:
+    key owner as address.public;
+    value count as u8.public;
+
+closure scale:
+    input r0 as u64;
+    mul 2u64 5u64 into r1;
+    mul r0 r1 into r2;
+    output r2 as u64;
+
+function compute:
+    input r0 as u8.public;
+    add 1u8 2u8 into r1;
+    mul r1 3u8 into r2;
+    add r0 r2 into r3;
+    cast r3 into r4 as u8;
+    is.eq r0 r0 into r5;
+    add 255u8 1u8 into r6;
+    output r4 as u8.private;
+    finalize self.caller r4;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u8.public;
+    set r1 into counts[r0];";
+
+    #[test]
+    fn test_optimize() -> Result<()> {
+        let expected = Program::<CurrentNetwork>::from_str(
+            r"
+program optimize.aleo;
+
+mapping counts:
+    key owner as address.public;
+    value count as u8.public;
+
+closure scale:
+    input r0 as u64;
+    mul r0 10u64 into r1;
+    output r1 as u64;
+
+function compute:
+    input r0 as u8.public;
+    add r0 9u8 into r1;
+    add 255u8 1u8 into r2;
+    output r1 as u8.private;
+    finalize self.caller r1;
+
+finalize compute:
+    input r0 as address.public;
+    input r1 as u8.public;
+    set r1 into counts[r0];",
+        )?;
+
+        // Ensure the constants are folded, and the redundant cast and dead registers are eliminated.
+        // Note: The overflowing 'add' halts, so it is neither folded nor eliminated.
+        let program = Program::<CurrentNetwork>::from_str(PROGRAM)?;
+        let optimized = program.optimize()?;
+        assert_eq!(optimized, expected, "\n{optimized}");
+
+        // Ensure the optimizer is idempotent.
+        assert_eq!(optimized.optimize()?, optimized);
+        Ok(())
+    }
+
+    #[test]
+    fn test_optimize_halting_instructions() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program halts.aleo;
+
+function compute:
+    input r0 as u32.public;
+    add 255u8 1u8 into r1;
+    div 1u32 0u32 into r2;
+    sub 0u64 1u64 into r3;
+    cast 256u32 into r4 as u8;
+    output r0 as u32.public;",
+        )?;
+
+        // Ensure the instructions that halt are neither folded nor eliminated.
+        assert_eq!(program.optimize()?, program);
+        Ok(())
+    }
+
+    #[test]
+    fn test_optimize_common_subexpressions() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program subexpressions.aleo;
+
+function compute:
+    input r0 as field.private;
+    hash.bhp256 r0 into r1 as field;
+    hash.bhp256 r0 into r2 as field;
+    mul r1 r2 into r3;
+    mul r1 r2 into r4;
+    add r3 r4 into r5;
+    output r5 as field.private;
+    output r2 as field.private;",
+        )?;
+        let expected = Program::<CurrentNetwork>::from_str(
+            r"
+program subexpressions.aleo;
+
+function compute:
+    input r0 as field.private;
+    hash.bhp256 r0 into r1 as field;
+    mul r1 r1 into r2;
+    add r2 r2 into r3;
+    output r3 as field.private;
+    output r1 as field.private;",
+        )?;
+
+        // Ensure the common subexpressions are kept by default.
+        assert_eq!(program.optimize()?, program);
+
+        // Ensure the common subexpressions are eliminated, if the pass is enabled.
+        let config = OptimizerConfig { eliminate_common_subexpressions: true, ..Default::default() };
+        let optimized = program.optimize_with(config)?;
+        assert_eq!(optimized, expected, "\n{optimized}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_optimize_opt_out() -> Result<()> {
+        let program = Program::<CurrentNetwork>::from_str(PROGRAM)?;
+
+        // Ensure the program is unchanged if every pass is disabled.
+        assert_eq!(program.optimize_with(OptimizerConfig::disabled())?, program);
+
+        // Ensure the folded instructions are kept if dead registers are not eliminated.
+        let config = OptimizerConfig { eliminate_dead_registers: false, ..Default::default() };
+        let optimized = program.optimize_with(config)?;
+        let function = optimized.get_function(&Identifier::from_str("compute")?)?;
+        assert_eq!(function.instructions().len(), 6);
+        assert_eq!(function.instructions()[2].to_string(), "add r0 9u8 into r3;");
+        assert_eq!(function.outputs()[0].operand(), &Operand::Register(Register::Locator(3)));
+        Ok(())
+    }
+}
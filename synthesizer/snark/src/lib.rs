@@ -26,17 +26,25 @@ use std::sync::Arc;
 #[cfg(feature = "aleo-cli")]
 use colored::Colorize;
 
+pub use snarkvm_algorithms::instrument;
+pub use varuna::ProvingMode;
+
 type Varuna<N> = varuna::VarunaSNARK<<N as Environment>::PairingCurve, FiatShamir<N>, varuna::VarunaHidingMode>;
 
 mod certificate;
 pub use certificate::Certificate;
 
+mod prepared_verifying_key;
+pub use prepared_verifying_key::{PreparedVerifyingKey, PreparedVerifyingKeyCache};
+
 mod proof;
 pub use proof::Proof;
 
 mod proving_key;
 pub use proving_key::ProvingKey;
 
+pub mod sharding;
+
 mod universal_srs;
 pub use universal_srs::UniversalSRS;
 
@@ -160,4 +168,34 @@ mod test {
         println!("\nShould not verify (i.e. verifier messages should print below):");
         assert!(!verifying_key.verify("test", &[one, one + one], &proof));
     }
+
+    #[test]
+    fn test_prove_from_assignment() {
+        let assignment = crate::test_helpers::sample_assignment();
+
+        // Export the assignment, as an edge device would.
+        let bytes = assignment.to_bytes_le().unwrap();
+        let digest = assignment.to_digest().unwrap();
+
+        // Prove the exported assignment, and verify the proof.
+        let srs = UniversalSRS::<CurrentNetwork>::load().unwrap();
+        let (proving_key, verifying_key) = srs.to_circuit_key("test", &assignment).unwrap();
+        let proof = proving_key.prove_from_assignment("test", &bytes, &digest, &mut TestRng::default()).unwrap();
+        let one = <Circuit as Environment>::BaseField::one();
+        assert!(verifying_key.verify("test", &[one, one], &proof));
+
+        // Ensure a mismatched digest is rejected.
+        let mut digest = digest;
+        digest[0] ^= 1;
+        assert!(proving_key.prove_from_assignment("test", &bytes, &digest, &mut TestRng::default()).is_err());
+    }
+
+    #[test]
+    fn test_preload_powers() {
+        let srs = UniversalSRS::<CurrentNetwork>::load().unwrap();
+
+        // Ensure preloading the embedded powers does not load any additional degrees.
+        srs.preload_powers_for(0..1 << 15).unwrap();
+        assert_eq!(srs.num_loaded_powers(), 1 << 15);
+    }
 }
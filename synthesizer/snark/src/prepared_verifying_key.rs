@@ -0,0 +1,298 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+};
+
+#[derive(Clone)]
+pub struct PreparedVerifyingKey<N: Network> {
+    /// The verifying key for the function.
+    verifying_key: VerifyingKey<N>,
+    /// The verifying key, with the verifier precomputations for its circuit cached.
+    prepared_verifying_key: Arc<varuna::PreparedCircuitVerifyingKey<N::PairingCurve>>,
+}
+
+impl<N: Network> PreparedVerifyingKey<N> {
+    /// Prepares the given verifying key for verification.
+    pub fn new(verifying_key: VerifyingKey<N>) -> Result<Self> {
+        let prepared_verifying_key = varuna::PreparedCircuitVerifyingKey::new(verifying_key.deref().clone())?;
+        Ok(Self { verifying_key, prepared_verifying_key: Arc::new(prepared_verifying_key) })
+    }
+
+    /// Returns the verifying key.
+    pub const fn verifying_key(&self) -> &VerifyingKey<N> {
+        &self.verifying_key
+    }
+
+    /// Returns `true` if the proof is valid for the given public inputs.
+    pub fn verify(&self, function_name: &str, inputs: &[N::Field], proof: &Proof<N>) -> bool {
+        Self::verify_batch(function_name, vec![(self.clone(), vec![inputs.to_vec()])], proof)
+    }
+
+    /// Returns `true` if the batch proof is valid for the given public inputs.
+    #[allow(clippy::type_complexity)]
+    pub fn verify_batch(
+        locator: &str,
+        inputs: Vec<(PreparedVerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        proof: &Proof<N>,
+    ) -> bool {
+        #[cfg(feature = "aleo-cli")]
+        let timer = std::time::Instant::now();
+
+        // Convert the instances.
+        let keys_to_inputs: BTreeMap<_, _> = inputs
+            .iter()
+            .map(|(verifying_key, inputs)| (verifying_key.prepared_verifying_key.deref(), inputs.as_slice()))
+            .collect();
+
+        // Retrieve the verification parameters.
+        let universal_verifier = N::varuna_universal_verifier();
+        let fiat_shamir = N::varuna_fs_parameters();
+
+        // Verify the batch proof.
+        match Varuna::<N>::verify_batch_prepared(universal_verifier, fiat_shamir, &keys_to_inputs, proof) {
+            Ok(is_valid) => {
+                #[cfg(feature = "aleo-cli")]
+                println!("{}", format!(" • Verified '{locator}' (in {} ms)", timer.elapsed().as_millis()).dimmed());
+                is_valid
+            }
+            Err(error) => {
+                #[cfg(feature = "aleo-cli")]
+                println!("{}", format!(" • Verifier failed: {error}").dimmed());
+                false
+            }
+        }
+    }
+
+    /// Returns `true` if every batch proof is valid for its prepared verifying keys and public inputs.
+    /// The final pairing checks of the proofs are combined with random coefficients from the given RNG,
+    /// and checked at once, while the precomputations of a key are shared by every proof that uses it.
+    #[allow(clippy::type_complexity)]
+    pub fn verify_batches<R: Rng + CryptoRng>(
+        batches: Vec<(&str, Vec<(PreparedVerifyingKey<N>, Vec<Vec<N::Field>>)>, &Proof<N>)>,
+        rng: &mut R,
+    ) -> bool {
+        #[cfg(feature = "aleo-cli")]
+        let timer = std::time::Instant::now();
+
+        // Convert the instances.
+        let keys_to_inputs = batches
+            .iter()
+            .map(|(_, inputs, proof)| {
+                let keys_to_inputs: BTreeMap<_, _> = inputs
+                    .iter()
+                    .map(|(verifying_key, inputs)| (verifying_key.prepared_verifying_key.deref(), inputs.as_slice()))
+                    .collect();
+                (keys_to_inputs, Deref::deref(*proof))
+            })
+            .collect::<Vec<_>>();
+
+        // Retrieve the verification parameters.
+        let universal_verifier = N::varuna_universal_verifier();
+        let fiat_shamir = N::varuna_fs_parameters();
+
+        // Verify the batch proofs.
+        match Varuna::<N>::verify_batches_prepared(universal_verifier, fiat_shamir, keys_to_inputs.as_slice(), rng) {
+            Ok(is_valid) => {
+                #[cfg(feature = "aleo-cli")]
+                println!(
+                    "{}",
+                    format!(" • Verified {} proofs (in {} ms)", batches.len(), timer.elapsed().as_millis()).dimmed()
+                );
+                is_valid
+            }
+            Err(error) => {
+                #[cfg(feature = "aleo-cli")]
+                println!("{}", format!(" • Verifier failed: {error}").dimmed());
+                false
+            }
+        }
+    }
+}
+
+impl<N: Network> Deref for PreparedVerifyingKey<N> {
+    type Target = VerifyingKey<N>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.verifying_key
+    }
+}
+
+/// A cache of prepared verifying keys, keyed by the circuit of the verifying key.
+/// The cache holds at most `capacity` keys, and evicts the least recently used key when it is full.
+pub struct PreparedVerifyingKeyCache<N: Network> {
+    /// The maximum number of prepared verifying keys in the cache.
+    capacity: usize,
+    /// The map of circuit IDs to prepared verifying keys, with the tick of their last use.
+    keys: Mutex<BTreeMap<[u8; 32], (PreparedVerifyingKey<N>, u64)>>,
+    /// The tick of the most recent use of the cache.
+    tick: AtomicU64,
+}
+
+impl<N: Network> Default for PreparedVerifyingKeyCache<N> {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CAPACITY)
+    }
+}
+
+impl<N: Network> PreparedVerifyingKeyCache<N> {
+    /// The default maximum number of prepared verifying keys in the cache.
+    pub const DEFAULT_CAPACITY: usize = 1024;
+
+    /// Initializes a new, empty cache, which holds at most `capacity` keys.
+    pub const fn new(capacity: usize) -> Self {
+        Self { capacity, keys: Mutex::new(BTreeMap::new()), tick: AtomicU64::new(0) }
+    }
+
+    /// Returns the maximum number of prepared verifying keys in the cache.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of prepared verifying keys in the cache.
+    pub fn len(&self) -> usize {
+        self.keys.lock().map_or(0, |keys| keys.len())
+    }
+
+    /// Returns `true` if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the prepared verifying key for the given verifying key, preparing and caching it if needed.
+    pub fn get_or_prepare(&self, verifying_key: &VerifyingKey<N>) -> Result<PreparedVerifyingKey<N>> {
+        // Return the cached key, if it exists, and mark it as the most recently used.
+        {
+            let mut keys = self.keys.lock().map_err(|_| anyhow!("The verifying key cache is poisoned"))?;
+            if let Some((prepared, last_used)) = keys.get_mut(&verifying_key.id.0) {
+                if prepared.verifying_key() == verifying_key {
+                    *last_used = self.tick.fetch_add(1, Ordering::Relaxed);
+                    return Ok(prepared.clone());
+                }
+            }
+        }
+        // Otherwise, prepare the key, without holding the lock.
+        let prepared = PreparedVerifyingKey::new(verifying_key.clone())?;
+        // Cache the key, evicting the least recently used key if the cache is full.
+        if self.capacity > 0 {
+            let mut keys = self.keys.lock().map_err(|_| anyhow!("The verifying key cache is poisoned"))?;
+            if !keys.contains_key(&verifying_key.id.0) && keys.len() >= self.capacity {
+                let least_recently_used = keys.iter().min_by_key(|(_, (_, last_used))| *last_used).map(|(id, _)| *id);
+                if let Some(id) = least_recently_used {
+                    keys.remove(&id);
+                }
+            }
+            keys.insert(verifying_key.id.0, (prepared.clone(), self.tick.fetch_add(1, Ordering::Relaxed)));
+        }
+        Ok(prepared)
+    }
+
+    /// Removes every prepared verifying key from the cache.
+    pub fn clear(&self) {
+        if let Ok(mut keys) = self.keys.lock() {
+            keys.clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use circuit::{
+        environment::{Circuit, Environment, Inject, Mode},
+        types::Field,
+    };
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_prepared_verify() {
+        let (_, verifying_key) = crate::test_helpers::sample_keys();
+        let proof = crate::test_helpers::sample_proof();
+        let rng = &mut TestRng::default();
+
+        // Prepare the verifying key, through the cache.
+        let cache = PreparedVerifyingKeyCache::<CurrentNetwork>::default();
+        assert!(cache.is_empty());
+        let prepared = cache.get_or_prepare(&verifying_key).unwrap();
+        let _ = cache.get_or_prepare(&verifying_key).unwrap();
+        assert_eq!(cache.len(), 1);
+        assert_eq!(*prepared, verifying_key);
+
+        // Ensure the prepared key agrees with the verifying key.
+        let one = <Circuit as Environment>::BaseField::one();
+        assert!(verifying_key.verify("test", &[one, one], &proof));
+        assert!(prepared.verify("test", &[one, one], &proof));
+        assert!(!prepared.verify("test", &[one, one + one], &proof));
+
+        // Ensure many proofs verify against the prepared key, with one combined pairing check.
+        let batches = (0..3).map(|_| ("test", vec![(prepared.clone(), vec![vec![one, one]])], &proof)).collect();
+        assert!(PreparedVerifyingKey::verify_batches(batches, rng));
+        // Ensure one invalid proof invalidates the combined pairing check.
+        let batches = vec![
+            ("test", vec![(prepared.clone(), vec![vec![one, one]])], &proof),
+            ("test", vec![(prepared.clone(), vec![vec![one, one + one]])], &proof),
+        ];
+        assert!(!PreparedVerifyingKey::verify_batches(batches, rng));
+
+        // Ensure the cache can be cleared.
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    /// Returns the verifying key of a circuit with the given number of multiplications.
+    fn sample_verifying_key(num_multiplications: usize) -> VerifyingKey<CurrentNetwork> {
+        let one = console::types::Field::<CurrentNetwork>::one();
+        let mut output = Field::<Circuit>::new(Mode::Public, one);
+        for _ in 0..num_multiplications {
+            output *= Field::new(Mode::Private, one);
+        }
+        let assignment = Circuit::eject_assignment_and_reset();
+        let srs = UniversalSRS::<CurrentNetwork>::load().unwrap();
+        srs.to_circuit_key("test", &assignment).unwrap().1
+    }
+
+    #[test]
+    fn test_prepared_verifying_key_cache_eviction() {
+        let keys = (1..=3).map(sample_verifying_key).collect::<Vec<_>>();
+        let contains = |cache: &PreparedVerifyingKeyCache<CurrentNetwork>, key: &VerifyingKey<CurrentNetwork>| {
+            cache.keys.lock().unwrap().contains_key(&key.id.0)
+        };
+
+        // Ensure the cache evicts the least recently used key when it is full.
+        let cache = PreparedVerifyingKeyCache::<CurrentNetwork>::new(2);
+        assert_eq!(cache.capacity(), 2);
+        cache.get_or_prepare(&keys[0]).unwrap();
+        cache.get_or_prepare(&keys[1]).unwrap();
+        cache.get_or_prepare(&keys[0]).unwrap();
+        cache.get_or_prepare(&keys[2]).unwrap();
+        assert_eq!(cache.len(), 2);
+        assert!(contains(&cache, &keys[0]));
+        assert!(!contains(&cache, &keys[1]));
+        assert!(contains(&cache, &keys[2]));
+
+        // Ensure a cache without capacity does not retain keys.
+        let cache = PreparedVerifyingKeyCache::<CurrentNetwork>::new(0);
+        assert_eq!(*cache.get_or_prepare(&keys[0]).unwrap(), keys[0]);
+        assert!(cache.is_empty());
+    }
+}
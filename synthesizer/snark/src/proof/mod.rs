@@ -29,6 +29,15 @@ impl<N: Network> Proof<N> {
     pub(super) const fn new(proof: varuna::Proof<N::PairingCurve>) -> Self {
         Self { proof }
     }
+
+    /// Returns the size in bytes of a proof for the given batch sizes, without computing the proof.
+    pub fn size_in_bytes_for(batch_sizes: &[usize]) -> usize {
+        // Account for the version byte.
+        1 + varuna::Proof::<N::PairingCurve>::compressed_size_for(
+            batch_sizes,
+            <varuna::VarunaHidingMode as varuna::SNARKMode>::ZK,
+        )
+    }
 }
 
 impl<N: Network> Deref for Proof<N> {
@@ -32,6 +32,18 @@ impl<N: Network> ProvingKey<N> {
         Self { proving_key }
     }
 
+    /// Returns the proving key with precomputed window tables for the powers of beta in its committer key,
+    /// which speeds up subsequent proofs at the cost of additional memory.
+    pub fn precompute(&self) -> Self {
+        // If the window tables are already precomputed, return the proving key as is.
+        if self.proving_key.precomputed_powers_of_beta_g.is_some() {
+            return self.clone();
+        }
+        let mut proving_key = (*self.proving_key).clone();
+        proving_key.precompute_powers_of_beta_g();
+        Self::new(Arc::new(proving_key))
+    }
+
     /// Returns a proof for the given assignment on the circuit.
     pub fn prove<R: Rng + CryptoRng>(
         &self,
@@ -39,6 +51,7 @@ impl<N: Network> ProvingKey<N> {
         assignment: &circuit::Assignment<N::Field>,
         rng: &mut R,
     ) -> Result<Proof<N>> {
+        let _span = instrument!("snark_prove", locator = function_name);
         #[cfg(feature = "aleo-cli")]
         let timer = std::time::Instant::now();
 
@@ -54,13 +67,31 @@ impl<N: Network> ProvingKey<N> {
         Ok(proof)
     }
 
-    /// Returns a proof for the given batch of proving keys and assignments.
+    /// Returns a proof for the given exported assignment on the circuit,
+    /// after ensuring the assignment matches the given digest.
+    pub fn prove_from_assignment<R: Rng + CryptoRng>(
+        &self,
+        function_name: &str,
+        assignment_bytes: &[u8],
+        digest: &[u8; 32],
+        rng: &mut R,
+    ) -> Result<Proof<N>> {
+        // Read the assignment, and ensure it matches the digest.
+        let assignment = circuit::Assignment::<N::Field>::from_bytes_with_digest(assignment_bytes, digest)
+            .map_err(|e| anyhow!("Failed to import the assignment for '{function_name}': {e}"))?;
+        // Compute the proof.
+        self.prove(function_name, &assignment, rng)
+    }
+
+    /// Returns a proof for the given batch of proving keys and assignments, with the given proving mode.
     #[allow(clippy::type_complexity)]
     pub fn prove_batch<R: Rng + CryptoRng>(
         locator: &str,
         assignments: &[(ProvingKey<N>, Vec<circuit::Assignment<N::Field>>)],
+        mode: ProvingMode,
         rng: &mut R,
     ) -> Result<Proof<N>> {
+        let _span = instrument!("snark_prove", locator, batch_size = assignments.len());
         #[cfg(feature = "aleo-cli")]
         let timer = std::time::Instant::now();
 
@@ -75,7 +106,8 @@ impl<N: Network> ProvingKey<N> {
         let fiat_shamir = N::varuna_fs_parameters();
 
         // Compute the proof.
-        let batch_proof = Proof::new(Varuna::<N>::prove_batch(universal_prover, fiat_shamir, &instances, rng)?);
+        let batch_proof =
+            Proof::new(Varuna::<N>::prove_batch_with_mode(universal_prover, fiat_shamir, &instances, mode, rng)?);
 
         #[cfg(feature = "aleo-cli")]
         println!("{}", format!(" • Executed '{locator}' (in {} ms)", timer.elapsed().as_millis()).dimmed());
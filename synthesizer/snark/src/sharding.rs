@@ -0,0 +1,365 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sharded proving distributes the MSMs of a proof, which dominate the commitment phase,
+//! across multiple worker processes or machines.
+//!
+//! The prover splits each MSM into `MsmShard`s, which are serialized and sent to workers by a
+//! `ShardExecutor`. Each worker computes its shard into a `PartialMsm`, which is serialized and sent back,
+//! and the prover merges the partial MSMs into the result. The remainder of the proof is computed locally.
+//!
+//! To enable sharded proving, call `enable_sharded_proving` with an executor, and run `MsmShard::compute`
+//! on the workers.
+
+use super::*;
+use snarkvm_algorithms::{
+    accelerator::{self, AcceleratorProvider, NTTDirection, NTTType},
+    msm::variable_base::batched,
+};
+use snarkvm_curves::bls12_377::{Fr, G1Affine, G1Projective};
+use snarkvm_fields::PrimeField;
+
+/// The big integer representation of a scalar.
+type Scalar = <Fr as PrimeField>::BigInteger;
+
+/// A shard of an MSM, to be computed by a worker.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MsmShard {
+    /// The index of the shard.
+    index: u32,
+    /// The number of shards in the MSM.
+    num_shards: u32,
+    /// The bases of the shard.
+    bases: Vec<G1Affine>,
+    /// The scalars of the shard.
+    scalars: Vec<Scalar>,
+}
+
+impl MsmShard {
+    /// Splits the MSM of the given bases and scalars into (at most) the given number of shards of equal size.
+    pub fn split(bases: &[G1Affine], scalars: &[Scalar], num_shards: usize) -> Result<Vec<Self>> {
+        ensure!(bases.len() == scalars.len(), "Mismatched number of bases and scalars in the MSM");
+        ensure!(num_shards > 0, "The number of shards must be nonzero");
+        // Compute the size of each shard.
+        let shard_size = ((bases.len() + num_shards - 1) / num_shards).max(1);
+        let num_shards = ((bases.len() + shard_size - 1) / shard_size).max(1);
+        let num_shards = u32::try_from(num_shards)?;
+        // Split the MSM.
+        let mut shards = bases
+            .chunks(shard_size)
+            .zip_eq(scalars.chunks(shard_size))
+            .enumerate()
+            .map(|(index, (bases, scalars))| Self {
+                index: index as u32,
+                num_shards,
+                bases: bases.to_vec(),
+                scalars: scalars.to_vec(),
+            })
+            .collect::<Vec<_>>();
+        // Ensure an empty MSM has one (empty) shard.
+        if shards.is_empty() {
+            shards.push(Self { index: 0, num_shards, bases: vec![], scalars: vec![] });
+        }
+        Ok(shards)
+    }
+
+    /// Returns the index of the shard.
+    pub const fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns the number of shards in the MSM.
+    pub const fn num_shards(&self) -> u32 {
+        self.num_shards
+    }
+
+    /// Returns the number of bases in the shard.
+    pub fn len(&self) -> usize {
+        self.bases.len()
+    }
+
+    /// Returns `true` if the shard has no bases.
+    pub fn is_empty(&self) -> bool {
+        self.bases.is_empty()
+    }
+
+    /// Computes the shard on the CPU, and returns its partial MSM.
+    pub fn compute(&self) -> PartialMsm {
+        // Note: This calls the CPU implementation directly, so that a worker in the same process
+        // as the prover does not dispatch the shard back to the sharded provider.
+        let result = batched::msm(&self.bases, &self.scalars);
+        PartialMsm { index: self.index, num_shards: self.num_shards, result: result.to_affine() }
+    }
+}
+
+/// The result of computing a shard of an MSM.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PartialMsm {
+    /// The index of the shard.
+    index: u32,
+    /// The number of shards in the MSM.
+    num_shards: u32,
+    /// The MSM of the shard.
+    result: G1Affine,
+}
+
+impl PartialMsm {
+    /// Returns the index of the shard.
+    pub const fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Merges the partial MSMs of every shard of an MSM, given in any order, into the result of the MSM.
+    pub fn merge(partials: &[Self]) -> Result<G1Projective> {
+        let Some(first) = partials.first() else { bail!("Cannot merge an MSM without shards") };
+        ensure!(
+            partials.len() == first.num_shards as usize,
+            "Expected {} shards, found {}",
+            first.num_shards,
+            partials.len()
+        );
+        // Ensure every shard is present exactly once.
+        let mut is_present = vec![false; partials.len()];
+        for partial in partials {
+            ensure!(partial.num_shards == first.num_shards, "Mismatched number of shards in the partial MSMs");
+            let Some(is_present) = is_present.get_mut(partial.index as usize) else {
+                bail!("Invalid shard index {} of {}", partial.index, partial.num_shards)
+            };
+            ensure!(!*is_present, "Duplicate shard index {}", partial.index);
+            *is_present = true;
+        }
+        Ok(partials.iter().map(|partial| partial.result.to_projective()).sum())
+    }
+}
+
+impl FromBytes for MsmShard {
+    /// Reads the MSM shard from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid MSM shard version"));
+        }
+        // Read the index and number of shards.
+        let index = u32::read_le(&mut reader)?;
+        let num_shards = u32::read_le(&mut reader)?;
+        if index >= num_shards {
+            return Err(error("Invalid MSM shard index"));
+        }
+        // Read the bases and scalars.
+        let length = u32::read_le(&mut reader)?;
+        let bases = (0..length).map(|_| G1Affine::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        let scalars = (0..length).map(|_| Scalar::read_le(&mut reader)).collect::<IoResult<Vec<_>>>()?;
+        // Ensure the bases are valid.
+        if !bases.iter().all(|base| base.is_on_curve() && base.is_in_correct_subgroup_assuming_on_curve()) {
+            return Err(error("Invalid base in the MSM shard"));
+        }
+        Ok(Self { index, num_shards, bases, scalars })
+    }
+}
+
+impl ToBytes for MsmShard {
+    /// Writes the MSM shard to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the index and number of shards.
+        self.index.write_le(&mut writer)?;
+        self.num_shards.write_le(&mut writer)?;
+        // Write the bases and scalars.
+        u32::try_from(self.bases.len()).map_err(|_| error("Too many bases in the MSM shard"))?.write_le(&mut writer)?;
+        self.bases.iter().try_for_each(|base| base.write_le(&mut writer))?;
+        self.scalars.iter().try_for_each(|scalar| scalar.write_le(&mut writer))
+    }
+}
+
+impl FromBytes for PartialMsm {
+    /// Reads the partial MSM from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid partial MSM version"));
+        }
+        // Read the index and number of shards.
+        let index = u32::read_le(&mut reader)?;
+        let num_shards = u32::read_le(&mut reader)?;
+        if index >= num_shards {
+            return Err(error("Invalid partial MSM index"));
+        }
+        // Read the result.
+        let result = G1Affine::read_le(&mut reader)?;
+        if !result.is_on_curve() || !result.is_in_correct_subgroup_assuming_on_curve() {
+            return Err(error("Invalid partial MSM result"));
+        }
+        Ok(Self { index, num_shards, result })
+    }
+}
+
+impl ToBytes for PartialMsm {
+    /// Writes the partial MSM to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the index and number of shards.
+        self.index.write_le(&mut writer)?;
+        self.num_shards.write_le(&mut writer)?;
+        // Write the result.
+        self.result.write_le(&mut writer)
+    }
+}
+
+/// An executor that computes MSM shards, e.g. by sending them to worker processes or machines.
+pub trait ShardExecutor: Send + Sync {
+    /// Computes the given shards with `MsmShard::compute`, and returns their partial MSMs in any order.
+    fn execute(&self, shards: Vec<MsmShard>) -> Result<Vec<PartialMsm>>;
+}
+
+/// An accelerator provider that distributes large MSMs across the workers of a `ShardExecutor`.
+///
+/// If the executor fails, the MSM falls back to the CPU of the prover, so a proof never fails
+/// because of an unavailable worker.
+pub struct ShardedProvider<E: ShardExecutor> {
+    /// The executor of the shards.
+    executor: E,
+    /// The number of shards to split each MSM into.
+    num_shards: usize,
+    /// The minimum number of bases for an MSM to be sharded.
+    min_size: usize,
+}
+
+impl<E: ShardExecutor> ShardedProvider<E> {
+    /// Initializes a new sharded provider, which splits each MSM of at least `min_size` bases into `num_shards` shards.
+    pub fn new(executor: E, num_shards: usize, min_size: usize) -> Result<Self> {
+        ensure!(num_shards > 0, "The number of shards must be nonzero");
+        Ok(Self { executor, num_shards, min_size })
+    }
+}
+
+impl<E: ShardExecutor> AcceleratorProvider for ShardedProvider<E> {
+    fn name(&self) -> &'static str {
+        "sharded"
+    }
+
+    fn msm(&self, bases: &[G1Affine], scalars: &[Scalar]) -> Option<G1Projective> {
+        // Compute small MSMs locally, as they are cheaper than the round trip to the workers.
+        if bases.len() < self.min_size {
+            return None;
+        }
+        let shards = MsmShard::split(bases, scalars, self.num_shards).ok()?;
+        let partials = self.executor.execute(shards).ok()?;
+        PartialMsm::merge(&partials).ok()
+    }
+
+    fn ntt(&self, _values: &mut [Fr], _direction: NTTDirection, _ntt_type: NTTType) -> bool {
+        false
+    }
+}
+
+/// Enables sharded proving in this process, by distributing each MSM of at least `min_size` bases
+/// into `num_shards` shards across the workers of the given executor.
+pub fn enable_sharded_proving<E: ShardExecutor + 'static>(
+    executor: E,
+    num_shards: usize,
+    min_size: usize,
+) -> Result<()> {
+    accelerator::set_provider(Arc::new(ShardedProvider::new(executor, num_shards, min_size)?));
+    Ok(())
+}
+
+/// Disables sharded proving in this process, by restoring the default accelerator provider.
+pub fn disable_sharded_proving() {
+    accelerator::reset_provider();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::msm::VariableBase;
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// An executor that round-trips each shard through its byte encoding, as a worker process would.
+    #[derive(Default)]
+    struct LocalExecutor {
+        num_shards: AtomicUsize,
+    }
+
+    impl ShardExecutor for LocalExecutor {
+        fn execute(&self, shards: Vec<MsmShard>) -> Result<Vec<PartialMsm>> {
+            self.num_shards.fetch_add(shards.len(), Ordering::SeqCst);
+            shards
+                .into_iter()
+                .rev()
+                .map(|shard| {
+                    let shard = MsmShard::from_bytes_le(&shard.to_bytes_le()?)?;
+                    Ok(PartialMsm::from_bytes_le(&shard.compute().to_bytes_le()?)?)
+                })
+                .collect()
+        }
+    }
+
+    fn sample_msm(rng: &mut TestRng, size: usize) -> (Vec<G1Affine>, Vec<Scalar>) {
+        let bases = (0..size).map(|_| G1Affine::rand(rng)).collect();
+        let scalars = (0..size).map(|_| Fr::rand(rng).to_bigint()).collect();
+        (bases, scalars)
+    }
+
+    #[test]
+    fn test_split_and_merge() {
+        let rng = &mut TestRng::default();
+
+        for (size, num_shards) in [(0, 3), (1, 3), (10, 3), (64, 4), (100, 7), (5, 10)] {
+            let (bases, scalars) = sample_msm(rng, size);
+            let expected = batched::msm(&bases, &scalars);
+
+            let shards = MsmShard::split(&bases, &scalars, num_shards).unwrap();
+            assert!(shards.len() <= num_shards);
+            assert_eq!(shards.iter().map(MsmShard::len).sum::<usize>(), size);
+            let partials = LocalExecutor::default().execute(shards).unwrap();
+            assert_eq!(PartialMsm::merge(&partials).unwrap().to_affine(), expected.to_affine());
+
+            // Ensure a missing or duplicate shard is rejected.
+            if partials.len() > 1 {
+                assert!(PartialMsm::merge(&partials[1..]).is_err());
+                let mut duplicated = partials.clone();
+                duplicated[0] = duplicated[1];
+                assert!(PartialMsm::merge(&duplicated).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn test_sharded_provider() {
+        let rng = &mut TestRng::default();
+        let (bases, scalars) = sample_msm(rng, 128);
+        let expected = batched::msm(&bases, &scalars);
+
+        let provider = ShardedProvider::new(LocalExecutor::default(), 4, 64).unwrap();
+        // Ensure a small MSM is computed locally.
+        assert!(provider.msm(&bases[..32], &scalars[..32]).is_none());
+        assert_eq!(provider.executor.num_shards.load(Ordering::SeqCst), 0);
+        // Ensure a large MSM is sharded.
+        assert_eq!(provider.msm(&bases, &scalars).unwrap().to_affine(), expected.to_affine());
+        assert_eq!(provider.executor.num_shards.load(Ordering::SeqCst), 4);
+
+        // Ensure the MSMs of the prover are routed to the executor.
+        enable_sharded_proving(LocalExecutor::default(), 4, 64).unwrap();
+        assert_eq!(accelerator::provider().name(), "sharded");
+        assert_eq!(VariableBase::msm(&bases, &scalars).to_affine(), expected.to_affine());
+        disable_sharded_proving();
+    }
+}
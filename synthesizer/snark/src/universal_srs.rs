@@ -42,6 +42,17 @@ impl<N: Network> UniversalSRS<N> {
 
         Ok((ProvingKey::new(Arc::new(proving_key)), VerifyingKey::new(Arc::new(verifying_key))))
     }
+
+    /// Loads the powers of the universal SRS within the given range, ahead of their first use.
+    /// Powers are otherwise loaded lazily, up to the degree needed by each circuit as it is synthesized.
+    pub fn preload_powers_for(&self, range: std::ops::Range<usize>) -> Result<()> {
+        self.deref().download_powers_for(range)
+    }
+
+    /// Returns the number of contiguous powers of the universal SRS that are currently loaded.
+    pub fn num_loaded_powers(&self) -> usize {
+        self.deref().num_loaded_powers()
+    }
 }
 
 impl<N: Network> FromBytes for UniversalSRS<N> {
@@ -34,6 +34,7 @@ impl<N: Network> VerifyingKey<N> {
 
     /// Returns `true` if the proof is valid for the given public inputs.
     pub fn verify(&self, function_name: &str, inputs: &[N::Field], proof: &Proof<N>) -> bool {
+        let _span = instrument!("snark_verify", locator = function_name);
         #[cfg(feature = "aleo-cli")]
         let timer = std::time::Instant::now();
 
@@ -62,6 +63,7 @@ impl<N: Network> VerifyingKey<N> {
     /// Returns `true` if the batch proof is valid for the given public inputs.
     #[allow(clippy::type_complexity)]
     pub fn verify_batch(locator: &str, inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>, proof: &Proof<N>) -> bool {
+        let _span = instrument!("snark_verify", locator, batch_size = inputs.len());
         #[cfg(feature = "aleo-cli")]
         let timer = std::time::Instant::now();
 
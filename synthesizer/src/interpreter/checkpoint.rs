@@ -0,0 +1,160 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A snapshot of the interpreter state, as saved by [`Interpreter::save`].
+#[derive(Clone)]
+pub(super) struct Checkpoint<N: Network> {
+    /// The block height.
+    height: u32,
+    /// The serial numbers of the spent records.
+    serial_numbers: IndexSet<Field<N>>,
+    /// The entries of each mapping.
+    mappings: Vec<(ProgramID<N>, Identifier<N>, Vec<(Plaintext<N>, Value<N>)>)>,
+}
+
+impl<N: Network> Interpreter<N> {
+    /// Saves a checkpoint of the mapping state, the spent records, and the block height, and returns its ID.
+    pub fn save(&mut self) -> Result<usize> {
+        let mut mappings = Vec::new();
+        for (program_id, mapping_name) in self.mapping_names()? {
+            let entries = self.get_mapping(&program_id, &mapping_name)?;
+            mappings.push((program_id, mapping_name, entries));
+        }
+        self.checkpoints.push(Checkpoint {
+            height: self.height,
+            serial_numbers: self.serial_numbers.clone(),
+            mappings,
+        });
+        Ok(self.checkpoints.len() - 1)
+    }
+
+    /// Restores the mapping state, the spent records, and the block height to the checkpoint with the given ID.
+    /// The checkpoint is kept, so it may be restored again. Programs added after the checkpoint remain,
+    /// with their mappings emptied.
+    pub fn restore(&mut self, id: usize) -> Result<()> {
+        // Retrieve the checkpoint.
+        let checkpoint = match self.checkpoints.get(id) {
+            Some(checkpoint) => checkpoint.clone(),
+            None => bail!("Checkpoint {id} does not exist"),
+        };
+
+        // Empty the mappings that did not exist at the checkpoint.
+        for (program_id, mapping_name) in self.mapping_names()? {
+            if !checkpoint.mappings.iter().any(|(p, m, _)| *p == program_id && *m == mapping_name) {
+                self.store.replace_mapping(&program_id, &mapping_name, vec![])?;
+            }
+        }
+        // Restore the mappings.
+        for (program_id, mapping_name, entries) in checkpoint.mappings {
+            self.store.replace_mapping(&program_id, &mapping_name, entries)?;
+        }
+        // Restore the spent records and the block height.
+        self.serial_numbers = checkpoint.serial_numbers;
+        self.height = checkpoint.height;
+        Ok(())
+    }
+
+    /// Sets the block height that the next call is finalized at.
+    pub fn set_height(&mut self, height: u32) {
+        self.height = height;
+    }
+
+    /// Returns the program ID and name of every mapping in the store.
+    fn mapping_names(&self) -> Result<Vec<(ProgramID<N>, Identifier<N>)>> {
+        let mut mapping_names = Vec::new();
+        for program_id in self.store.program_ids().map(|id| id.into_owned()).collect::<Vec<_>>() {
+            for mapping_name in self.store.get_mapping_names_confirmed(&program_id)?.unwrap_or_default() {
+                mapping_names.push((program_id, mapping_name));
+            }
+        }
+        Ok(mapping_names)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::Address, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_save_restore_and_set_height() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the program, which only accepts claims before block 10.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program claim.aleo;
+
+mapping claims:
+    key owner as address.public;
+    value height as u32.public;
+
+function claim:
+    input r0 as address.public;
+    finalize r0;
+
+finalize claim:
+    input r0 as address.public;
+    lt block.height 10u32 into r1;
+    assert.eq r1 true;
+    add block.height 0u32 into r2;
+    set r2 into claims[r0];",
+        )
+        .unwrap();
+
+        // Initialize the interpreter.
+        let mut interpreter = Interpreter::<CurrentNetwork>::new().unwrap();
+        interpreter.add_program(&program).unwrap();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let program_id = ProgramID::from_str("claim.aleo").unwrap();
+        let mapping_name = Identifier::from_str("claims").unwrap();
+        let key = Plaintext::from_str(&address.to_string()).unwrap();
+        let inputs = [address.to_string()];
+
+        // Save the initial state.
+        let initial = interpreter.save().unwrap();
+        assert!(interpreter.restore(initial + 1).is_err());
+
+        // Claim at block 9.
+        interpreter.set_height(9);
+        interpreter.call::<CurrentAleo, _>(&private_key, "claim.aleo", "claim", inputs.iter(), rng).unwrap();
+        assert_eq!(interpreter.height(), 10);
+        assert_eq!(
+            interpreter.get_value(&program_id, &mapping_name, &key).unwrap(),
+            Some(Value::from_str("9u32").unwrap())
+        );
+
+        // Ensure a claim at block 10 fails.
+        assert!(interpreter.call::<CurrentAleo, _>(&private_key, "claim.aleo", "claim", inputs.iter(), rng).is_err());
+
+        // Restore the initial state, twice.
+        for _ in 0..2 {
+            interpreter.restore(initial).unwrap();
+            assert_eq!(interpreter.height(), 1);
+            assert_eq!(interpreter.get_value(&program_id, &mapping_name, &key).unwrap(), None);
+            interpreter.call::<CurrentAleo, _>(&private_key, "claim.aleo", "claim", inputs.iter(), rng).unwrap();
+            assert_eq!(
+                interpreter.get_value(&program_id, &mapping_name, &key).unwrap(),
+                Some(Value::from_str("1u32").unwrap())
+            );
+        }
+    }
+}
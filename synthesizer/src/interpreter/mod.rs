@@ -0,0 +1,393 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An interpreter for local development.
+//!
+//! The [`Interpreter`] runs program functions at the console level, with no circuits and no proofs,
+//! and finalizes them against an in-memory finalize store. Each call is finalized as if it were
+//! the only transaction in its own block, so state carries over between calls and can be inspected
+//! at any point, which makes it a fast way to unit test programs with mappings.
+//!
+//! The [`TestRunner`] builds on the interpreter to script tests: it declares the initial state and
+//! the signers, runs a sequence of calls, and compares the resulting state against a snapshot.
+
+mod checkpoint;
+use checkpoint::*;
+
+mod runner;
+pub use runner::*;
+
+#[cfg(any(test, feature = "test"))]
+use console::program::Literal;
+use console::{
+    account::PrivateKey,
+    network::prelude::*,
+    program::{Identifier, Plaintext, ProgramID, Response, Value},
+    types::Field,
+};
+use ledger_block::Execution;
+use ledger_store::{helpers::memory::FinalizeMemory, FinalizeStore};
+use synthesizer_process::Process;
+#[cfg(any(test, feature = "test"))]
+use synthesizer_program::{clear_mock_rand_chacha, mock_rand_chacha};
+use synthesizer_program::{FinalizeGlobalState, FinalizeStoreTrait, Program};
+
+use indexmap::IndexSet;
+
+/// An interpreter that evaluates program functions and finalizes them against an in-memory store.
+pub struct Interpreter<N: Network> {
+    /// The process.
+    process: Process<N>,
+    /// The finalize store.
+    store: FinalizeStore<N, FinalizeMemory<N>>,
+    /// The block height to finalize the next call at.
+    height: u32,
+    /// The serial numbers of the records spent by previous calls.
+    serial_numbers: IndexSet<Field<N>>,
+    /// The saved checkpoints.
+    checkpoints: Vec<Checkpoint<N>>,
+    /// The mocked outputs of `rand.chacha` for the next call.
+    #[cfg(any(test, feature = "test"))]
+    randomness: Vec<Literal<N>>,
+}
+
+impl<N: Network> Interpreter<N> {
+    /// Initializes a new interpreter, with `credits.aleo` loaded and its mappings initialized.
+    pub fn new() -> Result<Self> {
+        // Initialize the process.
+        let process = Process::load()?;
+        // Initialize the finalize store.
+        let store = FinalizeStore::<N, FinalizeMemory<N>>::open(None)?;
+
+        // Initialize the mappings for 'credits.aleo'.
+        let credits = Program::<N>::credits()?;
+        for mapping in credits.mappings().values() {
+            store.initialize_mapping(credits.id(), mapping.name())?;
+        }
+
+        Ok(Self {
+            process,
+            store,
+            height: 1,
+            serial_numbers: IndexSet::new(),
+            checkpoints: Vec::new(),
+            #[cfg(any(test, feature = "test"))]
+            randomness: Vec::new(),
+        })
+    }
+
+    /// Adds the given program to the interpreter, and initializes its mappings.
+    /// Note: The imports of the program must be added first.
+    pub fn add_program(&mut self, program: &Program<N>) -> Result<()> {
+        // Ensure the program does not already exist.
+        ensure!(!self.process.contains_program(program.id()), "Program '{}' already exists", program.id());
+        // Add the program to the process.
+        self.process.add_program(program)?;
+        // Initialize the mappings for the program.
+        for mapping in program.mappings().values() {
+            self.store.initialize_mapping(program.id(), mapping.name())?;
+        }
+        Ok(())
+    }
+
+    /// Calls the given function on the given inputs, and finalizes the resulting transitions.
+    /// If any transition fails to finalize, or spends a record that was already spent,
+    /// none of the state changes of the call are applied. On success, the block height advances by one.
+    ///
+    /// Note: A call that invokes external functions is authorized by synthesizing its circuit,
+    /// in order to sign the requests of the external calls. No proofs are generated.
+    pub fn call<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &mut self,
+        private_key: &PrivateKey<N>,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        rng: &mut R,
+    ) -> Result<Response<N>> {
+        // Retrieve the mocked randomness, which only applies to this call.
+        #[cfg(any(test, feature = "test"))]
+        let randomness = core::mem::take(&mut self.randomness);
+
+        // Authorize the call.
+        let authorization = self.process.authorize::<A, R>(private_key, program_id, function_name, inputs, rng)?;
+        // Evaluate the call, which records its transitions in the authorization.
+        let response = self.process.evaluate::<A>(authorization.clone())?;
+
+        // Construct the execution from the evaluated transitions.
+        // Note: The execution contains no proof, as the interpreter trusts its own evaluation.
+        let execution = Execution::from(authorization.transitions().into_iter(), N::StateRoot::default(), None)?;
+
+        // Ensure the records spent by the call have not been spent, by this or a previous call.
+        let mut serial_numbers = IndexSet::new();
+        for serial_number in execution.transitions().flat_map(|transition| transition.serial_numbers()) {
+            ensure!(
+                !self.serial_numbers.contains(serial_number) && serial_numbers.insert(*serial_number),
+                "The record with serial number '{serial_number}' was already spent"
+            );
+        }
+
+        // Finalize the execution.
+        let state = FinalizeGlobalState::new::<N>(0, self.height, 0, 0, N::BlockHash::default())?;
+        #[cfg(any(test, feature = "test"))]
+        mock_rand_chacha(randomness);
+        let result = self.process.finalize_execution(state, &self.store, &execution, None);
+        #[cfg(any(test, feature = "test"))]
+        clear_mock_rand_chacha();
+        result?;
+
+        // Mark the records as spent, and advance the block height.
+        self.serial_numbers.extend(serial_numbers);
+        self.height = self.height.saturating_add(1);
+
+        Ok(response)
+    }
+}
+
+impl<N: Network> Interpreter<N> {
+    /// Returns the process.
+    pub const fn process(&self) -> &Process<N> {
+        &self.process
+    }
+
+    /// Returns the finalize store.
+    pub const fn store(&self) -> &FinalizeStore<N, FinalizeMemory<N>> {
+        &self.store
+    }
+
+    /// Returns the block height that the next call is finalized at.
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns `true` if the record with the given serial number was spent by a previous call.
+    pub fn contains_serial_number(&self, serial_number: &Field<N>) -> bool {
+        self.serial_numbers.contains(serial_number)
+    }
+
+    /// Returns the value for the given `program ID`, `mapping name`, and `key`, if it exists.
+    pub fn get_value(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<Option<Value<N>>> {
+        self.store.get_value_confirmed(program_id, mapping_name, key)
+    }
+
+    /// Mocks the outputs of the `rand.chacha` commands in the next call, in the order they are finalized.
+    /// Any outputs that the call does not use are discarded.
+    #[cfg(any(test, feature = "test"))]
+    pub fn mock_randomness(&mut self, outputs: Vec<Literal<N>>) {
+        self.randomness = outputs;
+    }
+
+    /// Sets the value for the given `program ID`, `mapping name`, and `key`, overwriting any existing value.
+    /// This is intended to declare the initial state of a mapping, and bypasses the program logic.
+    pub fn set_value(
+        &mut self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+        key: Plaintext<N>,
+        value: Value<N>,
+    ) -> Result<()> {
+        self.store.update_key_value(program_id, mapping_name, key, value)?;
+        Ok(())
+    }
+
+    /// Returns the entries of the mapping for the given `program ID` and `mapping name`.
+    pub fn get_mapping(
+        &self,
+        program_id: &ProgramID<N>,
+        mapping_name: &Identifier<N>,
+    ) -> Result<Vec<(Plaintext<N>, Value<N>)>> {
+        self.store.get_mapping_confirmed(program_id, mapping_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::Address, network::Testnet3};
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_interpreter_finalizes_calls() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program counter.aleo;
+
+mapping counts:
+    key owner as address.public;
+    value count as u64.public;
+
+function increment:
+    input r0 as address.public;
+    input r1 as u64.public;
+    finalize r0 r1;
+
+finalize increment:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use counts[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    lte r3 10u64 into r4;
+    assert.eq r4 true;
+    set r3 into counts[r0];",
+        )
+        .unwrap();
+
+        // Initialize the interpreter.
+        let mut interpreter = Interpreter::<CurrentNetwork>::new().unwrap();
+        interpreter.add_program(&program).unwrap();
+        assert!(interpreter.add_program(&program).is_err());
+
+        // Sample the caller.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+
+        let program_id = ProgramID::from_str("counter.aleo").unwrap();
+        let mapping_name = Identifier::from_str("counts").unwrap();
+        let key = Plaintext::from_str(&address.to_string()).unwrap();
+        assert_eq!(interpreter.get_value(&program_id, &mapping_name, &key).unwrap(), None);
+
+        // Increment the counter twice.
+        for amount in ["3u64", "4u64"] {
+            let inputs = [address.to_string(), amount.to_string()];
+            interpreter.call::<CurrentAleo, _>(&private_key, "counter.aleo", "increment", inputs.iter(), rng).unwrap();
+        }
+        assert_eq!(interpreter.height(), 3);
+        assert_eq!(
+            interpreter.get_value(&program_id, &mapping_name, &key).unwrap(),
+            Some(Value::from_str("7u64").unwrap())
+        );
+
+        // Ensure a failing finalize leaves the state unchanged.
+        let inputs = [address.to_string(), "4u64".to_string()];
+        assert!(interpreter
+            .call::<CurrentAleo, _>(&private_key, "counter.aleo", "increment", inputs.iter(), rng)
+            .is_err());
+        assert_eq!(interpreter.height(), 3);
+        assert_eq!(interpreter.get_mapping(&program_id, &mapping_name).unwrap(), vec![(
+            key,
+            Value::from_str("7u64").unwrap()
+        )]);
+    }
+
+    #[test]
+    fn test_interpreter_rejects_double_spend() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function burn:
+    input r0 as token.record;
+    output r0.amount as u64.private;",
+        )
+        .unwrap();
+
+        // Initialize the interpreter.
+        let mut interpreter = Interpreter::<CurrentNetwork>::new().unwrap();
+        interpreter.add_program(&program).unwrap();
+
+        // Sample a record owned by the caller.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let record = Value::<CurrentNetwork>::from_str(&format!(
+            "{{ owner: {address}.private, amount: 5u64.private, _nonce: {}.public }}",
+            console::types::Group::<CurrentNetwork>::rand(rng)
+        ))
+        .unwrap();
+
+        // Spend the record.
+        let response = interpreter
+            .call::<CurrentAleo, _>(&private_key, "token.aleo", "burn", [record.clone()].into_iter(), rng)
+            .unwrap();
+        assert_eq!(response.outputs(), [Value::from_str("5u64").unwrap()]);
+        assert_eq!(interpreter.height(), 2);
+
+        // Ensure the record cannot be spent again, and the block height does not advance.
+        let error = interpreter
+            .call::<CurrentAleo, _>(&private_key, "token.aleo", "burn", [record.clone()].into_iter(), rng)
+            .unwrap_err();
+        assert!(error.to_string().contains("already spent"), "{error}");
+        assert_eq!(interpreter.height(), 2);
+    }
+
+    #[test]
+    fn test_interpreter_mocks_randomness() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program lottery.aleo;
+
+mapping draws:
+    key owner as address.public;
+    value number as u8.public;
+
+function draw:
+    input r0 as address.public;
+    finalize r0;
+
+finalize draw:
+    input r0 as address.public;
+    rand.chacha into r1 as u8;
+    set r1 into draws[r0];",
+        )
+        .unwrap();
+
+        // Initialize the interpreter.
+        let mut interpreter = Interpreter::<CurrentNetwork>::new().unwrap();
+        interpreter.add_program(&program).unwrap();
+
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        let program_id = ProgramID::from_str("lottery.aleo").unwrap();
+        let mapping_name = Identifier::from_str("draws").unwrap();
+        let key = Plaintext::from_str(&address.to_string()).unwrap();
+        let inputs = [address.to_string()];
+
+        // Draw with mocked randomness.
+        interpreter.mock_randomness(vec![Literal::from_str("42u8").unwrap(), Literal::from_str("7u8").unwrap()]);
+        interpreter.call::<CurrentAleo, _>(&private_key, "lottery.aleo", "draw", inputs.iter(), rng).unwrap();
+        assert_eq!(
+            interpreter.get_value(&program_id, &mapping_name, &key).unwrap(),
+            Some(Value::from_str("42u8").unwrap())
+        );
+
+        // Ensure the unused mocked output does not carry over to the next call.
+        assert_eq!(clear_mock_rand_chacha(), 0);
+
+        // Ensure a mocked output of the wrong type is rejected.
+        interpreter.mock_randomness(vec![Literal::from_str("42u16").unwrap()]);
+        assert!(interpreter.call::<CurrentAleo, _>(&private_key, "lottery.aleo", "draw", inputs.iter(), rng).is_err());
+        assert_eq!(
+            interpreter.get_value(&program_id, &mapping_name, &key).unwrap(),
+            Some(Value::from_str("42u8").unwrap())
+        );
+    }
+}
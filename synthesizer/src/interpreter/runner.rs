@@ -0,0 +1,337 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::{
+    account::Address,
+    program::{Locator, Record},
+    types::Group,
+};
+
+use indexmap::IndexMap;
+
+/// A runner for program unit tests, backed by an [`Interpreter`].
+///
+/// Signers are referred to by name, and the state is compared against a snapshot that lists
+/// every non-empty mapping, as `program_id/mapping_name` followed by one `key => value` line per entry.
+pub struct TestRunner<N: Network> {
+    /// The interpreter.
+    interpreter: Interpreter<N>,
+    /// The signers, by name.
+    signers: IndexMap<String, PrivateKey<N>>,
+}
+
+impl<N: Network> TestRunner<N> {
+    /// Initializes a new test runner with the given programs, which are added in order.
+    pub fn new(programs: &[Program<N>]) -> Result<Self> {
+        // Initialize the interpreter.
+        let mut interpreter = Interpreter::new()?;
+        // Add the programs.
+        for program in programs {
+            interpreter.add_program(program)?;
+        }
+        Ok(Self { interpreter, signers: Default::default() })
+    }
+
+    /// Returns the interpreter.
+    pub const fn interpreter(&self) -> &Interpreter<N> {
+        &self.interpreter
+    }
+
+    /// Saves a checkpoint of the state, as in [`Interpreter::save`], and returns its ID.
+    pub fn save(&mut self) -> Result<usize> {
+        self.interpreter.save()
+    }
+
+    /// Restores the state to the checkpoint with the given ID, as in [`Interpreter::restore`].
+    pub fn restore(&mut self, id: usize) -> Result<()> {
+        self.interpreter.restore(id)
+    }
+
+    /// Sets the block height that the next call is finalized at.
+    pub fn set_height(&mut self, height: u32) {
+        self.interpreter.set_height(height)
+    }
+
+    /// Mocks the outputs of the `rand.chacha` commands in the next call, as in [`Interpreter::mock_randomness`].
+    #[cfg(any(test, feature = "test"))]
+    pub fn mock_randomness(&mut self, outputs: &[&str]) -> Result<()> {
+        let outputs = outputs.iter().map(|output| Literal::from_str(output)).collect::<Result<Vec<_>>>()?;
+        self.interpreter.mock_randomness(outputs);
+        Ok(())
+    }
+
+    /// Samples a new signer with the given name, and returns its address.
+    pub fn add_signer<R: Rng + CryptoRng>(&mut self, name: &str, rng: &mut R) -> Result<Address<N>> {
+        // Ensure the signer does not already exist.
+        ensure!(!self.signers.contains_key(name), "Signer '{name}' already exists");
+        // Sample the private key.
+        let private_key = PrivateKey::new(rng)?;
+        self.signers.insert(name.to_string(), private_key);
+        Address::try_from(&private_key)
+    }
+
+    /// Returns the address of the given signer.
+    pub fn address(&self, name: &str) -> Result<Address<N>> {
+        Address::try_from(self.private_key(name)?)
+    }
+
+    /// Returns the private key of the given signer.
+    fn private_key(&self, name: &str) -> Result<&PrivateKey<N>> {
+        self.signers.get(name).ok_or_else(|| anyhow!("Signer '{name}' does not exist"))
+    }
+
+    /// Sets the initial value of the given key in the mapping, given as `program_id/mapping_name`.
+    pub fn set_value(&mut self, mapping: &str, key: &str, value: &str) -> Result<()> {
+        let locator = Locator::<N>::from_str(mapping)?;
+        self.interpreter.set_value(
+            locator.program_id(),
+            locator.resource(),
+            Plaintext::from_str(key)?,
+            Value::from_str(value)?,
+        )
+    }
+
+    /// Returns a new record owned by the given signer, with the given entries and a random nonce.
+    /// The entries are given as they are written in a record, e.g. `amount: 5u64.private`.
+    pub fn record<R: Rng + CryptoRng>(
+        &self,
+        owner: &str,
+        entries: &str,
+        rng: &mut R,
+    ) -> Result<Record<N, Plaintext<N>>> {
+        // Sample the nonce.
+        let nonce = Group::<N>::rand(rng);
+        // Construct the record.
+        let entries = entries.trim().trim_end_matches(',');
+        match entries.is_empty() {
+            true => Record::from_str(&format!("{{ owner: {}.private, _nonce: {nonce}.public }}", self.address(owner)?)),
+            false => Record::from_str(&format!(
+                "{{ owner: {}.private, {entries}, _nonce: {nonce}.public }}",
+                self.address(owner)?
+            )),
+        }
+    }
+
+    /// Calls the function at the given locator (as `program_id/function_name`) as the given signer,
+    /// and returns the outputs.
+    pub fn call<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &mut self,
+        signer: &str,
+        function: &str,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        rng: &mut R,
+    ) -> Result<Vec<Value<N>>> {
+        let locator = Locator::<N>::from_str(function)?;
+        let private_key = *self.private_key(signer)?;
+        let response = self
+            .interpreter
+            .call::<A, R>(&private_key, *locator.program_id(), *locator.resource(), inputs, rng)
+            .map_err(|error| anyhow!("'{signer}' failed to call '{locator}' - {error}"))?;
+        Ok(response.outputs().to_vec())
+    }
+
+    /// Calls the function as in [`Self::call`], and ensures the outputs match the expected outputs.
+    pub fn call_and_expect<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &mut self,
+        signer: &str,
+        function: &str,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        expected: &[&str],
+        rng: &mut R,
+    ) -> Result<()> {
+        let outputs = self.call::<A, R>(signer, function, inputs, rng)?;
+        // Compare the outputs, one per line.
+        let actual = outputs.iter().map(|output| output.to_string()).join("\n");
+        let expected = expected
+            .iter()
+            .map(|output| Value::<N>::from_str(output).map(|output| output.to_string()))
+            .collect::<Result<Vec<_>>>()?;
+        if let Some(diff) = diff_lines(&expected.join("\n"), &actual) {
+            bail!("The outputs of '{function}' do not match (- expected, + actual):\n{diff}")
+        }
+        Ok(())
+    }
+
+    /// Calls the function as in [`Self::call`], and ensures the call fails, leaving the state unchanged.
+    pub fn call_and_expect_failure<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &mut self,
+        signer: &str,
+        function: &str,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        rng: &mut R,
+    ) -> Result<()> {
+        let snapshot = self.snapshot()?;
+        ensure!(self.call::<A, R>(signer, function, inputs, rng).is_err(), "Expected '{function}' to fail");
+        ensure!(snapshot == self.snapshot()?, "The failed call to '{function}' changed the state");
+        Ok(())
+    }
+
+    /// Returns the snapshot of the current state.
+    /// Each non-empty mapping is listed with its entries sorted by key.
+    pub fn snapshot(&self) -> Result<String> {
+        let mut snapshot = String::new();
+        let program_ids =
+            self.interpreter.store().program_ids().map(|id| id.into_owned()).sorted_by_key(|id| id.to_string());
+        for program_id in program_ids {
+            let mapping_names = self.interpreter.store().get_mapping_names_confirmed(&program_id)?.unwrap_or_default();
+            for mapping_name in mapping_names {
+                // Retrieve the entries, sorted by key.
+                let entries = self
+                    .interpreter
+                    .get_mapping(&program_id, &mapping_name)?
+                    .into_iter()
+                    .map(|(key, value)| (key.to_string(), value.to_string()))
+                    .sorted();
+                let mut entries = entries.peekable();
+                if entries.peek().is_some() {
+                    snapshot.push_str(&format!("{program_id}/{mapping_name}\n"));
+                    for (key, value) in entries {
+                        snapshot.push_str(&format!("    {key} => {value}\n"));
+                    }
+                }
+            }
+        }
+        Ok(snapshot)
+    }
+
+    /// Ensures the current state matches the given snapshot.
+    /// Leading and trailing whitespace on each line is ignored.
+    pub fn assert_snapshot(&self, expected: &str) -> Result<()> {
+        if let Some(diff) = diff_lines(expected, &self.snapshot()?) {
+            bail!("The state does not match the snapshot (- expected, + actual):\n{diff}")
+        }
+        Ok(())
+    }
+}
+
+/// Returns a line-by-line diff of the given strings, or `None` if they match.
+/// Leading and trailing whitespace, and blank lines, are ignored.
+fn diff_lines(expected: &str, actual: &str) -> Option<String> {
+    let expected = expected.lines().map(str::trim).filter(|line| !line.is_empty()).collect::<Vec<_>>();
+    let actual = actual.lines().map(str::trim).filter(|line| !line.is_empty()).collect::<Vec<_>>();
+    if expected == actual {
+        return None;
+    }
+
+    // Compute the longest common subsequence of the lines, from the end.
+    let mut lengths = vec![vec![0usize; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            lengths[i][j] = match expected[i] == actual[j] {
+                true => lengths[i + 1][j + 1] + 1,
+                false => lengths[i + 1][j].max(lengths[i][j + 1]),
+            };
+        }
+    }
+
+    // Walk the subsequence, marking the removed and added lines.
+    let (mut i, mut j) = (0, 0);
+    let mut diff = Vec::new();
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            diff.push(format!("  {}", expected[i]));
+            i += 1;
+            j += 1;
+        } else if j == actual.len() || (i < expected.len() && lengths[i + 1][j] >= lengths[i][j + 1]) {
+            diff.push(format!("- {}", expected[i]));
+            i += 1;
+        } else {
+            diff.push(format!("+ {}", actual[j]));
+            j += 1;
+        }
+    }
+    Some(diff.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_diff_lines() {
+        assert_eq!(diff_lines("a\n  b\n", "a\nb"), None);
+        assert_eq!(diff_lines("a\nb\nc", "a\nx\nc").unwrap(), "  a\n- b\n+ x\n  c");
+        assert_eq!(diff_lines("a", "a\nb").unwrap(), "  a\n+ b");
+    }
+
+    #[test]
+    fn test_runner() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the program.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program token.aleo;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+mapping balances:
+    key owner as address.public;
+    value amount as u64.public;
+
+function deposit:
+    input r0 as token.record;
+    output r0.amount as u64.private;
+    finalize r0.owner r0.amount;
+
+finalize deposit:
+    input r0 as address.public;
+    input r1 as u64.public;
+    get.or_use balances[r0] 0u64 into r2;
+    add r2 r1 into r3;
+    set r3 into balances[r0];",
+        )
+        .unwrap();
+
+        // Initialize the runner.
+        let mut runner = TestRunner::new(&[program]).unwrap();
+        let alice = runner.add_signer("alice", rng).unwrap();
+        let bob = runner.add_signer("bob", rng).unwrap();
+        assert!(runner.add_signer("alice", rng).is_err());
+
+        // Declare the initial state.
+        runner.set_value("token.aleo/balances", &bob.to_string(), "10u64").unwrap();
+        runner.assert_snapshot(&format!("token.aleo/balances\n    {bob} => 10u64")).unwrap();
+
+        // Deposit a record from each signer.
+        for (signer, amount) in [("alice", "3u64"), ("bob", "4u64")] {
+            let record = runner.record(signer, &format!("amount: {amount}.private"), rng).unwrap();
+            let inputs = [Value::Record(record)];
+            runner
+                .call_and_expect::<CurrentAleo, _>(signer, "token.aleo/deposit", inputs.into_iter(), &[amount], rng)
+                .unwrap();
+        }
+
+        // Ensure the state matches, regardless of the order of the keys.
+        let mut lines = [format!("{alice} => 3u64"), format!("{bob} => 14u64")];
+        lines.sort();
+        runner.assert_snapshot(&format!("token.aleo/balances\n    {}\n    {}", lines[0], lines[1])).unwrap();
+
+        // Ensure a mismatch reports the differing line.
+        let error = runner.assert_snapshot(&format!("token.aleo/balances\n    {bob} => 14u64")).unwrap_err();
+        assert!(error.to_string().contains(&format!("+ {alice} => 3u64")));
+
+        // Ensure a signer cannot spend a record they do not own.
+        let record = runner.record("alice", "amount: 1u64.private", rng).unwrap();
+        let inputs = [Value::Record(record)];
+        runner.call_and_expect_failure::<CurrentAleo, _>("bob", "token.aleo/deposit", inputs.into_iter(), rng).unwrap();
+    }
+}
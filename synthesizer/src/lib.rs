@@ -32,6 +32,12 @@ pub use crate::process::{Authorization, CallMetrics, Process, Stack, Trace};
 #[cfg(feature = "program")]
 pub use crate::program::{Closure, Command, Finalize, Function, Instruction, Program};
 
+#[cfg(all(feature = "process", feature = "program"))]
+pub mod interpreter;
+
+#[cfg(all(feature = "process", feature = "program"))]
+pub mod testing;
+
 #[cfg(all(feature = "process", feature = "program", feature = "snark"))]
 pub mod vm;
 #[cfg(all(feature = "process", feature = "program", feature = "snark"))]
@@ -0,0 +1,334 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::program::Request;
+use synthesizer_process::{Assignments, CallStack, StackExecute};
+
+use std::{env, fmt, fs, path::Path};
+
+/// The size of the circuit of a function.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConstraintCount {
+    /// The number of public variables.
+    pub num_public: u64,
+    /// The number of private variables.
+    pub num_private: u64,
+    /// The number of constraints.
+    pub num_constraints: u64,
+}
+
+impl ConstraintCount {
+    /// Returns `true` if any measure of `self` exceeds that of `baseline`.
+    pub const fn exceeds(&self, baseline: &Self) -> bool {
+        self.num_public > baseline.num_public
+            || self.num_private > baseline.num_private
+            || self.num_constraints > baseline.num_constraints
+    }
+}
+
+impl fmt::Display for ConstraintCount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Public: {}, Private: {}, Constraints: {}", self.num_public, self.num_private, self.num_constraints)
+    }
+}
+
+impl FromStr for ConstraintCount {
+    type Err = Error;
+
+    fn from_str(string: &str) -> Result<Self> {
+        let mut counts = string.split(", ").map(|count| count.split_once(": "));
+        let mut next = |label: &str| match counts.next() {
+            Some(Some((name, value))) if name == label => Ok(value.parse::<u64>()?),
+            _ => bail!("Expected '{label}: <count>' in '{string}'"),
+        };
+        let count =
+            Self { num_public: next("Public")?, num_private: next("Private")?, num_constraints: next("Constraints")? };
+        ensure!(counts.next().is_none(), "Found trailing counts in '{string}'");
+        Ok(count)
+    }
+}
+
+/// The constraint counts of every function in a program.
+///
+/// The baseline is stored as text, with one `<locator>: Public: <n>, Private: <n>, Constraints: <n>` line
+/// per function. Lines starting with `//` are comments.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConstraintBaseline<N: Network> {
+    /// The ID of the program.
+    program_id: ProgramID<N>,
+    /// The constraint counts of each function.
+    counts: IndexMap<Identifier<N>, ConstraintCount>,
+}
+
+impl<N: Network> ConstraintBaseline<N> {
+    /// Measures the constraint counts of every function in the given program.
+    pub fn measure<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        process: &Process<N>,
+        program_id: &ProgramID<N>,
+        rng: &mut R,
+    ) -> Result<Self> {
+        let stack = process.get_stack(program_id)?;
+        let counts = stack
+            .program()
+            .functions()
+            .keys()
+            .map(|function_name| Ok((*function_name, measure_function::<N, A, R>(stack, function_name, rng)?)))
+            .collect::<Result<_>>()?;
+        Ok(Self { program_id: *program_id, counts })
+    }
+
+    /// Returns the ID of the program.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the constraint counts of each function.
+    pub const fn counts(&self) -> &IndexMap<Identifier<N>, ConstraintCount> {
+        &self.counts
+    }
+
+    /// Returns the differences between `self` and the given baseline that count as regressions:
+    /// functions that grew in size, and functions that are missing from the baseline.
+    pub fn regressions(&self, baseline: &Self) -> Vec<String> {
+        self.counts
+            .iter()
+            .filter_map(|(function_name, count)| {
+                let locator = Locator::new(self.program_id, *function_name);
+                match baseline.counts.get(function_name) {
+                    Some(expected) if count.exceeds(expected) => {
+                        Some(format!("'{locator}' grew from ({expected}) to ({count})"))
+                    }
+                    Some(_) => None,
+                    None => Some(format!("'{locator}' is missing from the baseline ({count})")),
+                }
+            })
+            .collect()
+    }
+
+    /// Loads the baseline from the given path.
+    pub fn load(path: &Path) -> Result<Self> {
+        Self::from_str(&fs::read_to_string(path)?)
+    }
+
+    /// Saves the baseline to the given path.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        Ok(fs::write(path, self.to_string())?)
+    }
+}
+
+impl<N: Network> FromStr for ConstraintBaseline<N> {
+    type Err = Error;
+
+    fn from_str(string: &str) -> Result<Self> {
+        let mut program_id = None;
+        let mut counts = IndexMap::new();
+        for line in string.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with("//")) {
+            let (locator, count) =
+                line.split_once(": ").ok_or_else(|| anyhow!("Expected '<locator>: <counts>' in '{line}'"))?;
+            let locator = Locator::<N>::from_str(locator)?;
+            // Ensure every line belongs to the same program.
+            match program_id {
+                None => program_id = Some(*locator.program_id()),
+                Some(program_id) => {
+                    ensure!(&program_id == locator.program_id(), "Found '{locator}' in the baseline of '{program_id}'")
+                }
+            }
+            ensure!(
+                counts.insert(*locator.resource(), ConstraintCount::from_str(count)?).is_none(),
+                "Found a duplicate entry for '{locator}'"
+            );
+        }
+        let program_id = program_id.ok_or_else(|| anyhow!("The constraint baseline is empty"))?;
+        Ok(Self { program_id, counts })
+    }
+}
+
+impl<N: Network> fmt::Display for ConstraintBaseline<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "// Constraint counts for '{}'.", self.program_id)?;
+        writeln!(f, "// To update, rerun with `UPDATE_COUNT={}`.", self.program_id)?;
+        for (function_name, count) in &self.counts {
+            writeln!(f, "{}: {count}", Locator::new(self.program_id, *function_name))?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks the constraint counts of the given program against the baseline file at `path`.
+///
+/// If the baseline file does not exist, or the `UPDATE_COUNT` environment variable is set to a string
+/// contained in the program ID (as with `count_is!`), the baseline file is (re)written instead.
+/// Otherwise, this returns an error listing every function that grew past its baseline.
+pub fn check_constraint_counts<N: Network, A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+    process: &Process<N>,
+    program_id: &ProgramID<N>,
+    path: &Path,
+    rng: &mut R,
+) -> Result<ConstraintBaseline<N>> {
+    let measured = ConstraintBaseline::measure::<A, R>(process, program_id, rng)?;
+
+    let is_update = matches!(env::var("UPDATE_COUNT"), Ok(query) if program_id.to_string().contains(&query));
+    if is_update || !path.exists() {
+        measured.save(path)?;
+        return Ok(measured);
+    }
+
+    let baseline = ConstraintBaseline::load(path)?;
+    ensure!(
+        baseline.program_id() == program_id,
+        "The baseline at '{}' is for '{}', not '{program_id}'",
+        path.display(),
+        baseline.program_id()
+    );
+    let regressions = measured.regressions(&baseline);
+    ensure!(
+        regressions.is_empty(),
+        "Constraint counts regressed against '{}':\n{}\nTo update the baseline, rerun with `UPDATE_COUNT={program_id}`.",
+        path.display(),
+        regressions.join("\n")
+    );
+    Ok(measured)
+}
+
+/// Returns the constraint count of the given function, synthesized on sampled inputs.
+fn measure_function<N: Network, A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+    stack: &Stack<N>,
+    function_name: &Identifier<N>,
+    rng: &mut R,
+) -> Result<ConstraintCount> {
+    // Initialize a burner private key.
+    let burner_private_key = PrivateKey::new(rng)?;
+    let burner_address = Address::try_from(&burner_private_key)?;
+    // Sample the inputs.
+    let input_types = stack.get_function(function_name)?.input_types();
+    let inputs = input_types
+        .iter()
+        .map(|input_type| match input_type {
+            ValueType::ExternalRecord(locator) => stack.get_external_stack(locator.program_id())?.sample_value(
+                &burner_address,
+                &ValueType::Record(*locator.resource()),
+                rng,
+            ),
+            _ => stack.sample_value(&burner_address, input_type, rng),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    // Compute the request, with the burner private key.
+    let request =
+        Request::sign(&burner_private_key, *stack.program_id(), *function_name, inputs.into_iter(), &input_types, rng)?;
+
+    // Synthesize the circuit, without checking that it is satisfied.
+    let assignments = Assignments::<N>::default();
+    let call_stack = CallStack::CheckDeployment(vec![request], burner_private_key, assignments.clone());
+    stack.execute_function::<A>(call_stack)?;
+
+    // The last assignment is the one of the function itself.
+    let assignments = assignments.read();
+    let (assignment, _) = assignments
+        .last()
+        .ok_or_else(|| anyhow!("Missing the assignment for '{}/{function_name}'", stack.program_id()))?;
+    Ok(ConstraintCount {
+        num_public: assignment.num_public(),
+        num_private: assignment.num_private(),
+        num_constraints: assignment.num_constraints(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    const PROGRAM: &str = r"
+program counts.aleo;
+
+function add:
+    input r0 as u8.public;
+    input r1 as u8.private;
+    add r0 r1 into r2;
+    output r2 as u8.private;
+
+function mul:
+    input r0 as u64.private;
+    input r1 as u64.private;
+    mul r0 r1 into r2;
+    output r2 as u64.private;
+";
+
+    fn sample_process() -> (Process<CurrentNetwork>, ProgramID<CurrentNetwork>) {
+        let program = Program::<CurrentNetwork>::from_str(PROGRAM).unwrap();
+        let mut process = Process::load().unwrap();
+        process.add_program(&program).unwrap();
+        (process, *program.id())
+    }
+
+    #[test]
+    fn test_constraint_count_string() {
+        let count = ConstraintCount { num_public: 1, num_private: 2, num_constraints: 3 };
+        assert_eq!(count.to_string(), "Public: 1, Private: 2, Constraints: 3");
+        assert_eq!(ConstraintCount::from_str(&count.to_string()).unwrap(), count);
+        assert!(ConstraintCount::from_str("Public: 1, Private: 2").is_err());
+        assert!(ConstraintCount::from_str("Private: 2, Public: 1, Constraints: 3").is_err());
+    }
+
+    #[test]
+    fn test_constraint_baseline() {
+        let rng = &mut TestRng::default();
+        let (process, program_id) = sample_process();
+
+        let measured = ConstraintBaseline::measure::<CurrentAleo, _>(&process, &program_id, rng).unwrap();
+        assert_eq!(measured.counts().len(), 2);
+        assert!(measured.counts().values().all(|count| count.num_constraints > 0));
+        // Ensure the baseline round-trips through its string representation.
+        assert_eq!(ConstraintBaseline::from_str(&measured.to_string()).unwrap(), measured);
+        // Ensure the counts are stable across samples.
+        let remeasured = ConstraintBaseline::measure::<CurrentAleo, _>(&process, &program_id, rng).unwrap();
+        assert_eq!(remeasured, measured);
+        assert!(measured.regressions(&measured).is_empty());
+
+        // Shrink one function in the baseline, and ensure it is reported as a regression.
+        let mut baseline = measured.clone();
+        baseline.counts[0].num_constraints -= 1;
+        assert_eq!(measured.regressions(&baseline).len(), 1);
+        // Ensure a smaller function is not a regression.
+        assert!(baseline.regressions(&measured).is_empty());
+        // Ensure a missing function is a regression.
+        baseline.counts.pop();
+        assert_eq!(measured.regressions(&baseline).len(), 1);
+    }
+
+    #[test]
+    fn test_check_constraint_counts() {
+        let rng = &mut TestRng::default();
+        let (process, program_id) = sample_process();
+
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("counts.txt");
+
+        // The first check writes the baseline.
+        let measured = check_constraint_counts::<_, CurrentAleo, _>(&process, &program_id, &path, rng).unwrap();
+        assert_eq!(ConstraintBaseline::load(&path).unwrap(), measured);
+        // The second check passes against it.
+        check_constraint_counts::<_, CurrentAleo, _>(&process, &program_id, &path, rng).unwrap();
+
+        // Tighten the baseline, and ensure the check fails.
+        let mut baseline = measured;
+        baseline.counts[0].num_constraints -= 1;
+        baseline.save(&path).unwrap();
+        assert!(check_constraint_counts::<_, CurrentAleo, _>(&process, &program_id, &path, rng).is_err());
+    }
+}
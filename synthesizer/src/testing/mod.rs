@@ -0,0 +1,220 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Testing helpers for program developers.
+//!
+//! For property-based testing, for each case, [`check_function`] samples a fresh caller and random well-typed inputs, then runs the
+//! function through both console evaluation and circuit execution. The two must agree: either both reject
+//! the inputs, or both accept them with equal outputs and a satisfied circuit.
+//!
+//! For circuit size, [`ConstraintBaseline`] records the constraint counts of each function into a
+//! checked-in baseline file, and [`check_constraint_counts`] fails when a function grows past it.
+
+mod constraint_counts;
+pub use constraint_counts::*;
+
+use console::{
+    account::{Address, PrivateKey},
+    network::prelude::*,
+    program::{Identifier, Locator, ProgramID, Response, Value, ValueType},
+};
+use synthesizer_process::{Process, Stack};
+use synthesizer_program::{Program, StackProgram};
+
+use indexmap::IndexMap;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// The outcome of checking a function on random inputs.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PropertyReport {
+    /// The number of cases where both evaluation and execution accepted the inputs.
+    num_accepted: usize,
+    /// The number of cases where both evaluation and execution rejected the inputs.
+    num_rejected: usize,
+}
+
+impl PropertyReport {
+    /// Returns the number of cases where both evaluation and execution accepted the inputs.
+    pub const fn num_accepted(&self) -> usize {
+        self.num_accepted
+    }
+
+    /// Returns the number of cases where both evaluation and execution rejected the inputs.
+    pub const fn num_rejected(&self) -> usize {
+        self.num_rejected
+    }
+
+    /// Returns the total number of cases checked.
+    pub const fn num_cases(&self) -> usize {
+        self.num_accepted + self.num_rejected
+    }
+}
+
+/// Checks every function of the given program on `num_cases` random inputs each.
+pub fn check_program<N: Network, A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+    program: &Program<N>,
+    num_cases: usize,
+    rng: &mut R,
+) -> Result<IndexMap<Identifier<N>, PropertyReport>> {
+    // Initialize the process.
+    let mut process = Process::<N>::load()?;
+    // Add the program, unless it is already loaded (i.e. 'credits.aleo').
+    if !process.contains_program(program.id()) {
+        process.add_program(program)?;
+    }
+    // Check each function of the program.
+    program
+        .functions()
+        .keys()
+        .map(|function_name| {
+            Ok((*function_name, check_function::<N, A, R>(&process, program.id(), function_name, num_cases, rng)?))
+        })
+        .collect()
+}
+
+/// Checks that console evaluation and circuit execution of the given function agree on `num_cases` random inputs.
+pub fn check_function<N: Network, A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+    process: &Process<N>,
+    program_id: &ProgramID<N>,
+    function_name: &Identifier<N>,
+    num_cases: usize,
+    rng: &mut R,
+) -> Result<PropertyReport> {
+    let locator = Locator::new(*program_id, *function_name);
+    // Retrieve the input types.
+    let stack = process.get_stack(program_id)?;
+    let input_types = stack.get_function(function_name)?.input_types();
+
+    let mut report = PropertyReport::default();
+    for _ in 0..num_cases {
+        // Sample a caller and the inputs.
+        let private_key = PrivateKey::<N>::new(rng)?;
+        let caller = Address::try_from(&private_key)?;
+        let inputs = sample_inputs(stack, &caller, &input_types, rng)?;
+
+        // Authorize the call. If this fails, the inputs are rejected before either mode runs.
+        let authorization =
+            match catch(|| process.authorize::<A, R>(&private_key, program_id, function_name, inputs.iter(), rng)) {
+                Ok(authorization) => authorization,
+                Err(_) => {
+                    A::reset();
+                    report.num_rejected += 1;
+                    continue;
+                }
+            };
+
+        // Evaluate and execute the call on the same authorization.
+        let evaluated = catch(|| process.evaluate::<A>(authorization.replicate()));
+        let executed = catch(|| process.execute::<A>(authorization).map(|(response, _)| response));
+        A::reset();
+
+        match (evaluated, executed) {
+            (Ok(evaluated), Ok(executed)) => {
+                ensure!(
+                    evaluated.outputs() == executed.outputs(),
+                    "'{locator}' evaluates to [{}] but executes to [{}] on inputs [{}]",
+                    display(&evaluated),
+                    display(&executed),
+                    inputs.iter().join(", ")
+                );
+                report.num_accepted += 1;
+            }
+            (Err(_), Err(_)) => report.num_rejected += 1,
+            (Ok(_), Err(error)) => {
+                bail!("'{locator}' evaluates but fails to execute on inputs [{}]: {error}", inputs.iter().join(", "))
+            }
+            (Err(error), Ok(_)) => {
+                bail!("'{locator}' executes but fails to evaluate on inputs [{}]: {error}", inputs.iter().join(", "))
+            }
+        }
+    }
+    Ok(report)
+}
+
+/// Returns random inputs for the given input types, with any records owned by the given caller.
+fn sample_inputs<N: Network, R: Rng + CryptoRng>(
+    stack: &Stack<N>,
+    caller: &Address<N>,
+    input_types: &[ValueType<N>],
+    rng: &mut R,
+) -> Result<Vec<Value<N>>> {
+    input_types.iter().map(|input_type| stack.sample_value(caller, input_type, rng)).collect()
+}
+
+/// Runs the given closure, converting a panic (e.g. a halted circuit) into an error.
+fn catch<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|_| Err(anyhow!("Panicked while running the function")))
+}
+
+/// Returns the outputs of the given response, separated by commas.
+fn display<N: Network>(response: &Response<N>) -> String {
+    response.outputs().iter().join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+    type CurrentAleo = circuit::network::AleoV0;
+
+    #[test]
+    fn test_check_program() {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program property.aleo;
+
+struct point:
+    x as u8;
+    y as u8;
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function add:
+    input r0 as u8.public;
+    input r1 as u8.private;
+    add r0 r1 into r2;
+    output r2 as u8.private;
+
+function shift:
+    input r0 as point.private;
+    add.w r0.x r0.y into r1;
+    cast r1 r0.y into r2 as point;
+    output r2 as point.private;
+
+function split:
+    input r0 as token.record;
+    input r1 as u64.private;
+    sub r0.amount r1 into r2;
+    cast r0.owner r1 into r3 as token.record;
+    cast r0.owner r2 into r4 as token.record;
+    output r3 as token.record;
+    output r4 as token.record;
+",
+        )
+        .unwrap();
+
+        let rng = &mut TestRng::default();
+        let reports = check_program::<CurrentNetwork, CurrentAleo, _>(&program, 4, rng).unwrap();
+        assert_eq!(reports.len(), 3);
+        for report in reports.values() {
+            assert_eq!(report.num_cases(), 4);
+        }
+        // Wrapping addition accepts every input.
+        assert_eq!(reports[&Identifier::from_str("shift").unwrap()].num_accepted(), 4);
+    }
+}
@@ -40,7 +40,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let owner = ProgramOwner::new(private_key, deployment_id, rng)?;
 
         // Compute the minimum deployment cost.
-        let (minimum_deployment_cost, (_, _)) = deployment_cost(&deployment)?;
+        let (minimum_deployment_cost, (_, _)) = deployment_cost(&deployment, self.next_block_height())?;
         // Determine the fee.
         let Some(fee_amount) = minimum_deployment_cost.checked_add(priority_fee_in_microcredits) else {
             bail!("Fee overflowed for a deployment transaction")
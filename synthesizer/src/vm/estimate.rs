@@ -0,0 +1,226 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use ledger_block::ConfirmedTransaction;
+
+/// The number of recent blocks used to suggest a priority fee.
+pub const PRIORITY_FEE_BLOCKS: u32 = 10;
+
+/// The priority fees paid in recent blocks, in microcredits, as the fee paid above the minimum cost.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PriorityFeeSuggestion {
+    /// The 25th percentile of the priority fees.
+    pub low: u64,
+    /// The 50th percentile of the priority fees.
+    pub median: u64,
+    /// The 75th percentile of the priority fees.
+    pub high: u64,
+}
+
+/// The estimated fee for an execution, in microcredits.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FeeEstimate {
+    /// The storage cost, which is the size of the execution times the fee multiplier in the cost table.
+    pub storage_cost: u64,
+    /// The finalize cost, which is the cost of the `finalize` commands in the cost table.
+    pub finalize_cost: u64,
+    /// The priority fees paid in recent blocks.
+    pub priority_fee: PriorityFeeSuggestion,
+}
+
+impl FeeEstimate {
+    /// Returns the minimum fee, which is the sum of the storage cost and the finalize cost.
+    pub fn minimum_fee(&self) -> u64 {
+        self.storage_cost.saturating_add(self.finalize_cost)
+    }
+
+    /// Returns the minimum fee, plus the median priority fee.
+    pub fn suggested_fee(&self) -> u64 {
+        self.minimum_fee().saturating_add(self.priority_fee.median)
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Returns the estimated fee to execute the given program function with the given inputs, at the next block height.
+    ///
+    /// The circuits of the execution are synthesized to determine its transitions, but the proof is not computed,
+    /// as its size only depends on the number of circuits. The minimum fee is computed with the same cost table
+    /// as `check_transaction`.
+    pub fn estimate_fee<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        (program_id, function_name): (impl TryInto<ProgramID<N>>, impl TryInto<Identifier<N>>),
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        rng: &mut R,
+    ) -> Result<FeeEstimate> {
+        let timer = timer!("VM::estimate_fee");
+
+        // Authorize the call.
+        let authorization = self.authorize(private_key, program_id, function_name, inputs, rng)?;
+        lap!(timer, "Authorize the call");
+
+        // Prepare the query.
+        let query = Query::VM(self.block_store().clone());
+
+        macro_rules! logic {
+            ($process:expr, $network:path, $aleo:path) => {{
+                // Prepare the authorization.
+                let authorization = cast_ref!(authorization as Authorization<$network>);
+                // Execute the call, which synthesizes the circuits without computing the proof.
+                let (_, mut trace) = $process.execute::<$aleo>(authorization.clone())?;
+                lap!(timer, "Execute the call");
+
+                // Prepare the assignments.
+                cast_mut_ref!(trace as Trace<N>).prepare(query)?;
+                lap!(timer, "Prepare the assignments");
+
+                // Compute the size of the execution, and construct the execution without a proof.
+                let size_in_bytes = trace.execution_size_in_bytes()?;
+                let execution = Execution::from(trace.transitions().iter().cloned(), Default::default(), None)?;
+
+                // Return the execution and its size.
+                (cast_ref!(execution as Execution<N>).clone(), size_in_bytes)
+            }};
+        }
+
+        // Execute the authorization.
+        let (execution, size_in_bytes) = process!(self, logic);
+
+        // Compute the minimum cost.
+        let (_, (storage_cost, finalize_cost)) =
+            execution_cost_with_size(&self.process().read(), &execution, size_in_bytes, self.next_block_height())?;
+        // Suggest a priority fee.
+        let priority_fee = self.suggest_priority_fee()?;
+        finish!(timer, "Compute the fees");
+
+        Ok(FeeEstimate { storage_cost, finalize_cost, priority_fee })
+    }
+
+    /// Returns the priority fees paid in the last `PRIORITY_FEE_BLOCKS` blocks,
+    /// where the priority fee of a transaction is its fee amount above its minimum cost.
+    pub fn suggest_priority_fee(&self) -> Result<PriorityFeeSuggestion> {
+        // Determine the range of recent blocks.
+        let latest_height = self.block_store().current_block_height().unwrap_or(0);
+        let start_height = latest_height.saturating_sub(PRIORITY_FEE_BLOCKS - 1);
+
+        // Collect the priority fees.
+        let mut priority_fees = Vec::new();
+        for height in start_height..=latest_height {
+            let Some(block_hash) = self.block_store().get_block_hash(height)? else {
+                continue;
+            };
+            let Some(transactions) = self.block_store().get_block_transactions(&block_hash)? else {
+                continue;
+            };
+            for confirmed in transactions.iter() {
+                // Skip the transactions without a fee.
+                let Some(fee) = confirmed.fee_transition() else {
+                    continue;
+                };
+                // Compute the minimum cost of the transaction.
+                // Note: The minimum cost is computed at the block height the transaction was included in.
+                let minimum_cost = match confirmed {
+                    ConfirmedTransaction::AcceptedDeploy(_, Transaction::Deploy(_, _, deployment, _), _) => {
+                        deployment_cost(deployment, height)?.0
+                    }
+                    ConfirmedTransaction::AcceptedExecute(_, Transaction::Execute(_, execution, _), _) => {
+                        execution_cost(self, execution, height)?.0
+                    }
+                    ConfirmedTransaction::RejectedDeploy(_, _, rejected) => match rejected.deployment() {
+                        Some(deployment) => deployment_cost(deployment, height)?.0,
+                        None => continue,
+                    },
+                    ConfirmedTransaction::RejectedExecute(_, _, rejected) => match rejected.execution() {
+                        Some(execution) => execution_cost(self, execution, height)?.0,
+                        None => continue,
+                    },
+                    _ => continue,
+                };
+                priority_fees.push((*fee.amount()?).saturating_sub(minimum_cost));
+            }
+        }
+
+        // Return the percentiles of the priority fees.
+        Ok(PriorityFeeSuggestion::from_priority_fees(priority_fees))
+    }
+}
+
+impl PriorityFeeSuggestion {
+    /// Returns the 25th, 50th, and 75th percentiles of the given priority fees, using the nearest lower rank.
+    /// If there are no priority fees, then all percentiles are zero.
+    fn from_priority_fees(mut priority_fees: Vec<u64>) -> Self {
+        if priority_fees.is_empty() {
+            return Self::default();
+        }
+        priority_fees.sort_unstable();
+        let percentile = |percent: usize| priority_fees[(priority_fees.len() - 1) * percent / 100];
+        Self { low: percentile(25), median: percentile(50), high: percentile(75) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::test_helpers::CurrentNetwork;
+
+    #[test]
+    fn test_priority_fee_percentiles() {
+        // Ensure there is no priority fee without transactions.
+        assert_eq!(PriorityFeeSuggestion::from_priority_fees(vec![]), PriorityFeeSuggestion::default());
+
+        // Ensure a single priority fee is every percentile.
+        let suggestion = PriorityFeeSuggestion::from_priority_fees(vec![7]);
+        assert_eq!(suggestion, PriorityFeeSuggestion { low: 7, median: 7, high: 7 });
+
+        // Ensure the priority fees are sorted before the percentiles are taken.
+        let suggestion = PriorityFeeSuggestion::from_priority_fees(vec![40, 0, 30, 10, 20]);
+        assert_eq!(suggestion, PriorityFeeSuggestion { low: 10, median: 20, high: 30 });
+
+        // Ensure the percentiles use the nearest lower rank.
+        let suggestion = PriorityFeeSuggestion::from_priority_fees(vec![4, 3, 2, 1]);
+        assert_eq!(suggestion, PriorityFeeSuggestion { low: 1, median: 2, high: 3 });
+        let suggestion = PriorityFeeSuggestion::from_priority_fees((1..=100).collect());
+        assert_eq!(suggestion, PriorityFeeSuggestion { low: 25, median: 50, high: 75 });
+    }
+
+    #[test]
+    fn test_estimate_fee() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+        // Retrieve the private key of the genesis block.
+        let caller_private_key = crate::vm::test_helpers::sample_genesis_private_key(rng);
+        let address = Address::try_from(&caller_private_key).unwrap();
+
+        // Estimate the fee for a public transfer.
+        let inputs =
+            [Value::<CurrentNetwork>::from_str(&address.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+        let estimate = vm
+            .estimate_fee(&caller_private_key, ("credits.aleo", "transfer_public"), inputs.clone().into_iter(), rng)
+            .unwrap();
+        assert!(estimate.storage_cost > 0);
+        assert!(estimate.finalize_cost > 0);
+        assert_eq!(estimate.minimum_fee(), estimate.storage_cost + estimate.finalize_cost);
+
+        // Ensure the estimate matches the minimum cost of the execution.
+        let transaction = vm
+            .execute(&caller_private_key, ("credits.aleo", "transfer_public"), inputs.into_iter(), None, 0, None, rng)
+            .unwrap();
+        let (minimum_cost, _) = execution_cost(&vm, transaction.execution().unwrap(), vm.next_block_height()).unwrap();
+        assert_eq!(estimate.minimum_fee(), minimum_cost);
+    }
+}
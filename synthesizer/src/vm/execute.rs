@@ -45,7 +45,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let fee = match is_fee_required || is_priority_fee_declared {
             true => {
                 // Compute the minimum execution cost.
-                let (minimum_execution_cost, (_, _)) = execution_cost(self, &execution)?;
+                let (minimum_execution_cost, (_, _)) = execution_cost(self, &execution, self.next_block_height())?;
                 // Determine the fee.
                 let Some(fee_amount) = minimum_execution_cost.checked_add(priority_fee_in_microcredits) else {
                     bail!("Fee overflowed for an execution transaction")
@@ -120,6 +120,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             None => Query::VM(self.block_store().clone()),
         };
         lap!(timer, "Prepare the query");
+        // Retrieve the thread pools.
+        let thread_pools = self.process.read().thread_pools().clone();
 
         macro_rules! logic {
             ($process:expr, $network:path, $aleo:path) => {{
@@ -134,7 +136,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 lap!(timer, "Prepare the assignments");
 
                 // Compute the proof and construct the execution.
-                let execution = trace.prove_execution::<$aleo, _>(&locator, rng)?;
+                let execution = install_with_rng!(
+                    thread_pools,
+                    ThreadPoolKind::Proving,
+                    rng,
+                    trace.prove_execution::<$aleo, _>(&locator, rng)
+                )?;
                 lap!(timer, "Compute the proof");
 
                 // Return the execution.
@@ -165,6 +172,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             None => Query::VM(self.block_store().clone()),
         };
         lap!(timer, "Prepare the query");
+        // Retrieve the thread pools.
+        let thread_pools = self.process.read().thread_pools().clone();
 
         macro_rules! logic {
             ($process:expr, $network:path, $aleo:path) => {{
@@ -179,7 +188,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 lap!(timer, "Prepare the assignments");
 
                 // Compute the proof and construct the fee.
-                let fee = trace.prove_fee::<$aleo, _>(rng)?;
+                let fee =
+                    install_with_rng!(thread_pools, ThreadPoolKind::Proving, rng, trace.prove_fee::<$aleo, _>(rng))?;
                 lap!(timer, "Compute the proof");
 
                 // Return the fee.
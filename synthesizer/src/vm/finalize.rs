@@ -13,12 +13,16 @@
 // limitations under the License.
 
 use super::*;
-use ledger_block::{ConfirmedTransaction, Rejected, Transactions};
+use ledger_block::{ConfirmedTransaction, RatifyStage, Rejected, RejectionReason, Transactions};
 use ledger_coinbase::CoinbaseSolution;
 
+/// The maximum number of rejection reasons held between speculation and finalization.
+const MAX_SPECULATED_REJECTION_REASONS: usize = 4096;
+
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Speculates on the given list of transactions in the VM,
     /// returning the confirmed and aborted transactions.
+    /// The rejection reasons of the rejected transactions are held until the transactions are finalized.
     #[inline]
     pub fn speculate<'a>(
         &self,
@@ -30,16 +34,32 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let timer = timer!("VM::speculate");
 
         // Performs a **dry-run** over the list of ratifications, solutions, and transactions.
-        let (confirmed_transactions, aborted_transactions) =
+        let (confirmed_transactions, aborted_transactions, rejection_reasons) =
             self.atomic_speculate(state, ratifications, solutions, transactions)?;
 
+        // Hold the rejection reasons, until the rejected transactions are finalized.
+        {
+            let mut speculated_reasons = self.rejection_reasons.write();
+            for (unconfirmed_id, reason) in rejection_reasons {
+                // If the cache is full, evict the oldest rejection reason.
+                if speculated_reasons.len() >= MAX_SPECULATED_REJECTION_REASONS
+                    && !speculated_reasons.contains_key(&unconfirmed_id)
+                {
+                    speculated_reasons.shift_remove_index(0);
+                }
+                speculated_reasons.insert(unconfirmed_id, reason);
+            }
+        }
+
         finish!(timer, "Finished dry-run of the transactions");
 
         // Return the transactions.
         Ok((confirmed_transactions.into_iter().collect(), aborted_transactions))
     }
 
-    /// Finalizes the given transactions into the VM.
+    /// Finalizes the given transactions into the VM,
+    /// returning the rejection reasons of the rejected transactions, keyed by their unconfirmed transaction ID.
+    /// Note: A rejection reason is only returned if the VM speculated on the rejected transaction beforehand.
     #[inline]
     pub fn finalize(
         &self,
@@ -47,27 +67,35 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         ratifications: &[Ratify<N>],
         solutions: Option<&CoinbaseSolution<N>>,
         transactions: &Transactions<N>,
-    ) -> Result<()> {
+    ) -> Result<Vec<(N::TransactionID, RejectionReason<N>)>> {
         let timer = timer!("VM::finalize");
 
         // Performs a **real-run** of finalize over the list of ratifications, solutions, and transactions.
-        self.atomic_finalize(state, ratifications, solutions, transactions)?;
+        let rejection_reasons = self.atomic_finalize(state, ratifications, solutions, transactions)?;
+
+        // Release the rejection reasons of the finalized transactions.
+        {
+            let mut speculated_reasons = self.rejection_reasons.write();
+            for (unconfirmed_id, _) in &rejection_reasons {
+                speculated_reasons.shift_remove(unconfirmed_id);
+            }
+        }
 
         finish!(timer, "Finished real-run of finalize");
-        Ok(())
+        Ok(rejection_reasons)
     }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Performs atomic speculation over a list of transactions,
-    /// and returns the confirmed and aborted transactions.
+    /// and returns the confirmed and aborted transactions, and the rejection reasons of the rejected transactions.
     fn atomic_speculate<'a>(
         &self,
         state: FinalizeGlobalState,
         ratifications: &[Ratify<N>],
         solutions: Option<&CoinbaseSolution<N>>,
         transactions: impl ExactSizeIterator<Item = &'a Transaction<N>>,
-    ) -> Result<(Vec<ConfirmedTransaction<N>>, Vec<Transaction<N>>)> {
+    ) -> Result<(Vec<ConfirmedTransaction<N>>, Vec<Transaction<N>>, Vec<(N::TransactionID, RejectionReason<N>)>)> {
         let timer = timer!("VM::atomic_speculate");
 
         // Retrieve the number of transactions.
@@ -75,23 +103,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
         // Perform the finalize operation on the preset finalize mode.
         atomic_finalize!(self.finalize_store(), FinalizeMode::DryRun, {
-            // Initialize an iterator for ratifications before finalize.
-            let pre_ratifications = ratifications.iter().filter(|r| match r {
-                Ratify::Genesis(_, _) => true,
-                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => false,
-            });
-            // Initialize an iterator for ratifications after finalize.
-            let post_ratifications = ratifications.iter().filter(|r| match r {
-                Ratify::Genesis(_, _) => false,
-                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => true,
-            });
-
             // Retrieve the finalize store.
             let store = self.finalize_store();
 
             /* Perform the ratifications before finalize. */
 
-            if let Err(e) = Self::atomic_pre_ratify(store, state, pre_ratifications) {
+            if let Err(e) = Self::atomic_ratify(store, state, RatifyStage::PreFinalize, ratifications, solutions) {
                 // Note: This will abort the entire atomic batch.
                 return Err(format!("Failed to pre-ratify - {e}"));
             }
@@ -107,6 +124,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             let mut confirmed = Vec::with_capacity(num_transactions);
             // Initialize a list of the aborted transactions.
             let mut aborted = Vec::new();
+            // Initialize a list for the rejection reasons.
+            let mut rejection_reasons = Vec::new();
 
             // Finalize the transactions.
             'outer: for (index, transaction) in transactions.enumerate() {
@@ -128,7 +147,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                                     .map_err(|e| e.to_string())
                             }
                             // Construct the rejected deploy transaction.
-                            Err(_error) => {
+                            Err(error) => {
                                 // Finalize the fee, to ensure it is valid.
                                 if let Err(error) = process.finalize_fee(state, store, fee) {
                                     // Note: On failure, skip this transaction, and continue speculation.
@@ -141,6 +160,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                                 // Construct the fee transaction.
                                 // Note: On failure, this will abort the entire atomic batch.
                                 let fee_tx = Transaction::from_fee(fee.clone()).map_err(|e| e.to_string())?;
+                                // Record the rejection reason.
+                                let reason = RejectionReason::new_deployment(*deployment.program_id(), error);
+                                rejection_reasons.push((transaction.id(), reason));
                                 // Construct the rejected deployment.
                                 let rejected = Rejected::new_deployment(*program_owner, *deployment.clone());
                                 // Construct the rejected deploy transaction.
@@ -159,7 +181,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                                     .map_err(|e| e.to_string())
                             }
                             // Construct the rejected execute transaction.
-                            Err(_error) => match fee {
+                            Err(error) => match fee {
                                 Some(fee) => {
                                     // Finalize the fee, to ensure it is valid.
                                     if let Err(error) = process.finalize_fee(state, store, fee) {
@@ -173,6 +195,9 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                                     // Construct the fee transaction.
                                     // Note: On failure, this will abort the entire atomic batch.
                                     let fee_tx = Transaction::from_fee(fee.clone()).map_err(|e| e.to_string())?;
+                                    // Record the rejection reason.
+                                    let reason = Self::execution_rejection_reason(execution, error)?;
+                                    rejection_reasons.push((transaction.id(), reason));
                                     // Construct the rejected execution.
                                     let rejected = Rejected::new_execution(execution.clone());
                                     // Construct the rejected execute transaction.
@@ -211,7 +236,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
             /* Perform the ratifications after finalize. */
 
-            if let Err(e) = Self::atomic_post_ratify(store, state, post_ratifications, solutions) {
+            if let Err(e) = Self::atomic_ratify(store, state, RatifyStage::PostFinalize, ratifications, solutions) {
                 // Note: This will abort the entire atomic batch.
                 return Err(format!("Failed to post-ratify - {e}"));
             }
@@ -219,11 +244,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             finish!(timer);
 
             // On return, 'atomic_finalize!' will abort the batch, and return the confirmed & aborted transactions.
-            Ok((confirmed, aborted))
+            Ok((confirmed, aborted, rejection_reasons))
         })
     }
 
-    /// Performs atomic finalization over a list of transactions.
+    /// Performs atomic finalization over a list of transactions,
+    /// and returns the rejection reasons of the rejected transactions.
     #[inline]
     fn atomic_finalize(
         &self,
@@ -231,28 +257,17 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         ratifications: &[Ratify<N>],
         solutions: Option<&CoinbaseSolution<N>>,
         transactions: &Transactions<N>,
-    ) -> Result<()> {
+    ) -> Result<Vec<(N::TransactionID, RejectionReason<N>)>> {
         let timer = timer!("VM::atomic_finalize");
 
         // Perform the finalize operation on the preset finalize mode.
         atomic_finalize!(self.finalize_store(), FinalizeMode::RealRun, {
-            // Initialize an iterator for ratifications before finalize.
-            let pre_ratifications = ratifications.iter().filter(|r| match r {
-                Ratify::Genesis(_, _) => true,
-                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => false,
-            });
-            // Initialize an iterator for ratifications after finalize.
-            let post_ratifications = ratifications.iter().filter(|r| match r {
-                Ratify::Genesis(_, _) => false,
-                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => true,
-            });
-
             // Retrieve the finalize store.
             let store = self.finalize_store();
 
             /* Perform the ratifications before finalize. */
 
-            if let Err(e) = Self::atomic_pre_ratify(store, state, pre_ratifications) {
+            if let Err(e) = Self::atomic_ratify(store, state, RatifyStage::PreFinalize, ratifications, solutions) {
                 // Note: This will abort the entire atomic batch.
                 return Err(format!("Failed to pre-ratify - {e}"));
             }
@@ -266,6 +281,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
             // Initialize a list for the deployed stacks.
             let mut stacks = Vec::new();
+            // Initialize a list for the rejection reasons.
+            let mut rejection_reasons = Vec::new();
 
             // Finalize the transactions.
             for (index, transaction) in transactions.iter().enumerate() {
@@ -355,11 +372,13 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                             // Note: This will abort the entire atomic batch.
                             return Err("Mismatch in fee for a rejected deploy transaction".to_string());
                         }
-                        // Attempt to finalize the deployment, which should fail.
-                        #[cfg(debug_assertions)]
-                        if process.finalize_deployment(state, store, deployment, fee).is_ok() {
+                        // Record the rejection reason, if the deployment was rejected during speculation.
+                        let Ok(unconfirmed_id) = transaction.unconfirmed_id() else {
                             // Note: This will abort the entire atomic batch.
-                            return Err("Failed to reject a rejected deploy transaction".to_string());
+                            return Err("Failed to compute the ID of a rejected deploy transaction".to_string());
+                        };
+                        if let Some(reason) = self.rejection_reasons.read().get(&unconfirmed_id) {
+                            rejection_reasons.push((unconfirmed_id, reason.clone()));
                         }
                         // Lastly, finalize the fee.
                         if let Err(_error) = process.finalize_fee(state, store, fee) {
@@ -389,11 +408,13 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                             // Note: This will abort the entire atomic batch.
                             return Err("Mismatch in fee for a rejected execute transaction".to_string());
                         }
-                        // Attempt to finalize the execution, which should fail.
-                        #[cfg(debug_assertions)]
-                        if process.finalize_execution(state, store, execution, Some(fee)).is_ok() {
+                        // Record the rejection reason, if the execution was rejected during speculation.
+                        let Ok(unconfirmed_id) = transaction.unconfirmed_id() else {
                             // Note: This will abort the entire atomic batch.
-                            return Err("Failed to reject a rejected execute transaction".to_string());
+                            return Err("Failed to compute the ID of a rejected execute transaction".to_string());
+                        };
+                        if let Some(reason) = self.rejection_reasons.read().get(&unconfirmed_id) {
+                            rejection_reasons.push((unconfirmed_id, reason.clone()));
                         }
                         // Lastly, finalize the fee.
                         if let Err(_error) = process.finalize_fee(state, store, fee) {
@@ -421,7 +442,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
             /* Perform the ratifications after finalize. */
 
-            if let Err(e) = Self::atomic_post_ratify(store, state, post_ratifications, solutions) {
+            if let Err(e) = Self::atomic_ratify(store, state, RatifyStage::PostFinalize, ratifications, solutions) {
                 // Note: This will abort the entire atomic batch.
                 return Err(format!("Failed to post-ratify - {e}"));
             }
@@ -435,170 +456,24 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
             finish!(timer); // <- Note: This timer does **not** include the time to write batch to DB.
 
-            Ok(())
+            Ok(rejection_reasons)
         })
     }
 
-    /// Performs the pre-ratifications before finalizing transactions.
-    #[inline]
-    fn atomic_pre_ratify<'a>(
-        store: &FinalizeStore<N, C::FinalizeStorage>,
-        state: FinalizeGlobalState,
-        pre_ratifications: impl Iterator<Item = &'a Ratify<N>>,
-    ) -> Result<()> {
-        // Construct the program ID.
-        let program_id = ProgramID::from_str("credits.aleo")?;
-        // Construct the committee mapping name.
-        let committee_mapping = Identifier::from_str("committee")?;
-        // Construct the bonded mapping name.
-        let bonded_mapping = Identifier::from_str("bonded")?;
-        // Construct the account mapping name.
-        let account_mapping = Identifier::from_str("account")?;
-
-        // Iterate over the ratifications.
-        for ratify in pre_ratifications {
-            match ratify {
-                Ratify::Genesis(committee, public_balances) => {
-                    // Ensure this is the genesis block.
-                    ensure!(state.block_height() == 0, "Ratify::Genesis(..) expected a genesis block");
-                    // Ensure the genesis committee round is 0.
-                    ensure!(
-                        committee.starting_round() == 0,
-                        "Ratify::Genesis(..) expected a genesis committee round of 0"
-                    );
-
-                    // Initialize the stakers.
-                    let mut stakers = IndexMap::with_capacity(committee.members().len());
-                    // Iterate over the committee members.
-                    for (validator, (microcredits, _)) in committee.members() {
-                        // Insert the validator into the stakers.
-                        stakers.insert(*validator, (*validator, *microcredits));
-                    }
-
-                    // Construct the next committee map and next bonded map.
-                    let (next_committee_map, next_bonded_map) =
-                        to_next_commitee_map_and_bonded_map(committee, &stakers);
-
-                    // Insert the next committee into storage.
-                    store.committee_store().insert(state.block_height(), committee.clone())?;
-                    // Replace the committee mapping in storage.
-                    store.replace_mapping(&program_id, &committee_mapping, next_committee_map)?;
-                    // Replace the bonded mapping in storage.
-                    store.replace_mapping(&program_id, &bonded_mapping, next_bonded_map)?;
-
-                    // Iterate over the public balances.
-                    for (address, amount) in public_balances {
-                        // Construct the key.
-                        let key = Plaintext::from(Literal::Address(*address));
-                        // Retrieve the current public balance.
-                        let value = store.get_value_speculative(&program_id, &account_mapping, &key)?;
-                        // Compute the next public balance.
-                        let next_value = Value::from(Literal::U64(U64::new(match value {
-                            Some(Value::Plaintext(Plaintext::Literal(Literal::U64(value), _))) => {
-                                (*value).saturating_add(*amount)
-                            }
-                            None => *amount,
-                            v => bail!("Critical bug in pre-ratify - Invalid public balance type ({v:?})"),
-                        })));
-                        // Update the public balance in finalize storage.
-                        store.update_key_value(&program_id, &account_mapping, key, next_value)?;
-                    }
-                }
-                Ratify::BlockReward(..) | Ratify::PuzzleReward(..) => continue,
-            }
-        }
-        Ok(())
-    }
-
-    /// Performs the post-ratifications after finalizing transactions.
-    #[inline]
-    fn atomic_post_ratify<'a>(
-        store: &FinalizeStore<N, C::FinalizeStorage>,
-        state: FinalizeGlobalState,
-        post_ratifications: impl Iterator<Item = &'a Ratify<N>>,
-        solutions: Option<&CoinbaseSolution<N>>,
-    ) -> Result<()> {
-        // Construct the program ID.
-        let program_id = ProgramID::from_str("credits.aleo")?;
-        // Construct the committee mapping name.
-        let committee_mapping = Identifier::from_str("committee")?;
-        // Construct the bonded mapping name.
-        let bonded_mapping = Identifier::from_str("bonded")?;
-        // Construct the account mapping name.
-        let account_mapping = Identifier::from_str("account")?;
-
-        // Iterate over the ratifications.
-        for ratify in post_ratifications {
-            match ratify {
-                Ratify::Genesis(..) => continue,
-                Ratify::BlockReward(block_reward) => {
-                    // Retrieve the committee from storage.
-                    let current_committee = store.committee_store().current_committee()?;
-                    // Retrieve the committee mapping from storage.
-                    let current_committee_map = store.get_mapping_speculative(&program_id, &committee_mapping)?;
-                    // Retrieve the bonded mapping from storage.
-                    let current_bonded_map = store.get_mapping_speculative(&program_id, &bonded_mapping)?;
-                    // Convert the bonded map into stakers.
-                    let current_stakers = bonded_map_into_stakers(current_bonded_map)?;
-
-                    // Ensure the committee matches the committee mapping.
-                    ensure_committee_matches(&current_committee, &current_committee_map)?;
-                    // Ensure the committee matches the bonded mapping.
-                    ensure_stakers_matches(&current_committee, &current_stakers)?;
-
-                    // Compute the updated stakers, using the committee and block reward.
-                    let next_stakers = staking_rewards(&current_stakers, &current_committee, *block_reward);
-                    // Compute the updated committee, using the stakers.
-                    let next_committee = to_next_committee(&current_committee, state.block_round(), &next_stakers)?;
-                    // Construct the next committee map and next bonded map.
-                    let (next_committee_map, next_bonded_map) =
-                        to_next_commitee_map_and_bonded_map(&next_committee, &next_stakers);
-
-                    // Insert the next committee into storage.
-                    store.committee_store().insert(state.block_height(), next_committee)?;
-                    // Replace the committee mapping in storage.
-                    store.replace_mapping(&program_id, &committee_mapping, next_committee_map)?;
-                    // Replace the bonded mapping in storage.
-                    store.replace_mapping(&program_id, &bonded_mapping, next_bonded_map)?;
+    /// Returns the rejection reason for the given execution, which failed to finalize with the given error.
+    fn execution_rejection_reason(execution: &Execution<N>, error: Error) -> Result<RejectionReason<N>, String> {
+        match error.downcast::<RejectionReason<N>>() {
+            // If a finalize command failed, use its rejection reason.
+            Ok(reason) => Ok(reason),
+            // Otherwise, attribute the rejection to the top-level transition.
+            Err(error) => match execution.peek() {
+                Ok(transition) => {
+                    Ok(RejectionReason::new_execution(*transition.program_id(), *transition.function_name(), error))
                 }
-                Ratify::PuzzleReward(puzzle_reward) => {
-                    // If the puzzle reward is zero, skip.
-                    if *puzzle_reward == 0 {
-                        continue;
-                    }
-                    // Retrieve the solutions.
-                    let Some(solutions) = solutions else {
-                        continue;
-                    };
-                    // Compute the proof targets, with the corresponding addresses.
-                    let proof_targets = solutions
-                        .partial_solutions()
-                        .iter()
-                        .map(|s| Ok((s.address(), s.to_target()?)))
-                        .collect::<Result<Vec<_>>>()?;
-                    // Calculate the proving rewards.
-                    let proving_rewards = proving_rewards(proof_targets, *puzzle_reward);
-                    // Iterate over the proving rewards.
-                    for (address, amount) in proving_rewards {
-                        // Construct the key.
-                        let key = Plaintext::from(Literal::Address(address));
-                        // Retrieve the current public balance.
-                        let value = store.get_value_speculative(&program_id, &account_mapping, &key)?;
-                        // Compute the next public balance.
-                        let next_value = Value::from(Literal::U64(U64::new(match value {
-                            Some(Value::Plaintext(Plaintext::Literal(Literal::U64(value), _))) => {
-                                (*value).saturating_add(amount)
-                            }
-                            None => amount,
-                            v => bail!("Critical bug in post-ratify puzzle reward- Invalid amount ({v:?})"),
-                        })));
-                        // Update the public balance in finalize storage.
-                        store.update_key_value(&program_id, &account_mapping, key, next_value)?;
-                    }
-                }
-            }
+                // Note: This will abort the entire atomic batch.
+                Err(_) => Err("Expected a non-empty rejected execution".to_string()),
+            },
         }
-        Ok(())
     }
 }
 
@@ -881,7 +756,7 @@ finalize transfer_public:
         assert!(vm.contains_program(&program_id));
 
         // Ensure the dry run of the redeployment will cause a reject transaction to be created.
-        let (candidate_transactions, _) =
+        let (candidate_transactions, _, _) =
             vm.atomic_speculate(sample_finalize_state(1), &[], None, [deployment_transaction].iter()).unwrap();
         assert_eq!(candidate_transactions.len(), 1);
         assert!(matches!(candidate_transactions[0], ConfirmedTransaction::RejectedDeploy(..)));
@@ -982,7 +857,7 @@ finalize transfer_public:
         // Transfer_20 -> Balance = 20 - 20 = 0
         {
             let transactions = [mint_10.clone(), transfer_10.clone(), transfer_20.clone()];
-            let (confirmed_transactions, _) =
+            let (confirmed_transactions, _, _) =
                 vm.atomic_speculate(sample_finalize_state(1), &[], None, transactions.iter()).unwrap();
 
             // Assert that all the transactions are accepted.
@@ -1001,7 +876,7 @@ finalize transfer_public:
         // Transfer_30 -> Balance = 30 - 30 = 0
         {
             let transactions = [transfer_20.clone(), mint_10.clone(), mint_20.clone(), transfer_30.clone()];
-            let (confirmed_transactions, _) =
+            let (confirmed_transactions, _, _) =
                 vm.atomic_speculate(sample_finalize_state(1), &[], None, transactions.iter()).unwrap();
 
             // Assert that all the transactions are accepted.
@@ -1020,7 +895,7 @@ finalize transfer_public:
         // Transfer_10 -> Balance = 0 - 10 = -10 (should be rejected)
         {
             let transactions = [transfer_20.clone(), transfer_10.clone()];
-            let (confirmed_transactions, _) =
+            let (confirmed_transactions, _, _) =
                 vm.atomic_speculate(sample_finalize_state(1), &[], None, transactions.iter()).unwrap();
 
             // Assert that the accepted and rejected transactions are correct.
@@ -1040,7 +915,7 @@ finalize transfer_public:
         // Transfer_10 -> Balance = 10 - 10 = 0
         {
             let transactions = [mint_20.clone(), transfer_30.clone(), transfer_20.clone(), transfer_10.clone()];
-            let (confirmed_transactions, _) =
+            let (confirmed_transactions, _, _) =
                 vm.atomic_speculate(sample_finalize_state(1), &[], None, transactions.iter()).unwrap();
 
             // Assert that the accepted and rejected transactions are correct.
@@ -21,7 +21,7 @@ use console::{
     program::{Identifier, Literal, Plaintext, Value},
     types::{Boolean, U64},
 };
-use ledger_committee::Committee;
+use ledger_committee::{Committee, CommitteeSelector, StakeWeightedSelector};
 
 use anyhow::{bail, ensure, Result};
 use indexmap::{indexmap, IndexMap};
@@ -169,6 +169,19 @@ pub fn to_next_committee<N: Network>(
     current_committee: &Committee<N>,
     next_round: u64,
     next_stakers: &IndexMap<Address<N>, (Address<N>, u64)>,
+) -> Result<Committee<N>> {
+    to_next_committee_with_selector(current_committee, next_round, next_stakers, &StakeWeightedSelector)
+}
+
+/// Returns the next committee, given the current committee and stakers, as selected by the given selector.
+///
+/// Note: Consensus selects the next committee with the `StakeWeightedSelector`, as the committee mapping
+/// and the consistency checks against the stakers assume each member's weight is its stake.
+pub fn to_next_committee_with_selector<N: Network, S: CommitteeSelector<N> + ?Sized>(
+    current_committee: &Committee<N>,
+    next_round: u64,
+    next_stakers: &IndexMap<Address<N>, (Address<N>, u64)>,
+    selector: &S,
 ) -> Result<Committee<N>> {
     // Construct the validator map.
     let validator_map: IndexMap<_, _> = cfg_reduce!(
@@ -183,14 +196,8 @@ pub fn to_next_committee<N: Network>(
         }
     );
 
-    // Initialize the members.
-    let mut members = IndexMap::with_capacity(validator_map.len());
-    // Iterate over the validators.
-    for (validator, microcredits) in validator_map {
-        members.insert(validator, (microcredits, current_committee.is_committee_member_open(validator)));
-    }
     // Return the next committee.
-    Committee::new(next_round, members)
+    current_committee.select_next(next_round, &validator_map, selector)
 }
 
 /// Returns the committee map and bonded map, given the committee and stakers.
@@ -12,19 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::VM;
-use console::{
-    prelude::*,
-    program::{LiteralType, PlaintextType},
-};
-use ledger_block::{Deployment, Execution};
+use crate::{CostTable, Process, VM};
+use console::prelude::*;
+use ledger_block::{Deployment, Execution, Transaction, TransactionWeight};
 use ledger_store::ConsensusStorage;
-use synthesizer_program::{Command, Finalize, Instruction};
+use synthesizer_program::Finalize;
 
 use std::collections::HashMap;
 
-/// Returns the *minimum* cost in microcredits to publish the given deployment (total cost, (storage cost, namespace cost)).
-pub fn deployment_cost<N: Network>(deployment: &Deployment<N>) -> Result<(u64, (u64, u64))> {
+/// Returns the *minimum* cost in microcredits to publish the given deployment at the given block height (total cost, (storage cost, namespace cost)).
+pub fn deployment_cost<N: Network>(deployment: &Deployment<N>, block_height: u32) -> Result<(u64, (u64, u64))> {
+    // Retrieve the cost table.
+    let cost_table = CostTable::at_height::<N>(block_height);
     // Determine the number of bytes in the deployment.
     let size_in_bytes = deployment.size_in_bytes()?;
     // Retrieve the program ID.
@@ -34,12 +33,12 @@ pub fn deployment_cost<N: Network>(deployment: &Deployment<N>) -> Result<(u64, (
 
     // Compute the storage cost in microcredits.
     let storage_cost = size_in_bytes
-        .checked_mul(N::DEPLOYMENT_FEE_MULTIPLIER)
+        .checked_mul(cost_table.deployment_fee_multiplier)
         .ok_or(anyhow!("The storage cost computation overflowed for a deployment"))?;
 
-    // Compute the namespace cost in credits: 10^(10 - num_characters).
+    // Compute the namespace cost in credits: 10^(NAMESPACE_COST_CHARACTERS - num_characters).
     let namespace_cost = 10u64
-        .checked_pow(10u32.saturating_sub(num_characters))
+        .checked_pow(cost_table.namespace_cost_characters.saturating_sub(num_characters))
         .ok_or(anyhow!("The namespace cost computation overflowed for a deployment"))?
         .saturating_mul(1_000_000); // 1 microcredit = 1e-6 credits.
 
@@ -51,20 +50,48 @@ pub fn deployment_cost<N: Network>(deployment: &Deployment<N>) -> Result<(u64, (
     Ok((total_cost, (storage_cost, namespace_cost)))
 }
 
-/// Returns the *minimum* cost in microcredits to publish the given execution (total cost, (storage cost, namespace cost)).
+/// Returns the *minimum* cost in microcredits to publish the given execution at the given block height (total cost, (storage cost, finalize cost)).
 pub fn execution_cost<N: Network, C: ConsensusStorage<N>>(
     vm: &VM<N, C>,
     execution: &Execution<N>,
+    block_height: u32,
 ) -> Result<(u64, (u64, u64))> {
+    execution_cost_with_process(&vm.process().read(), execution, block_height)
+}
+
+/// Returns the *minimum* cost in microcredits to publish the given execution at the given block height (total cost, (storage cost, finalize cost)),
+/// using the programs in the given process.
+pub fn execution_cost_with_process<N: Network>(
+    process: &Process<N>,
+    execution: &Execution<N>,
+    block_height: u32,
+) -> Result<(u64, (u64, u64))> {
+    execution_cost_with_size(process, execution, execution.size_in_bytes()?, block_height)
+}
+
+/// Returns the *minimum* cost in microcredits to publish the given execution with the given size in bytes,
+/// at the given block height (total cost, (storage cost, finalize cost)), using the programs in the given process.
+/// This computes the cost of an execution before its proof is computed.
+pub fn execution_cost_with_size<N: Network>(
+    process: &Process<N>,
+    execution: &Execution<N>,
+    size_in_bytes: u64,
+    block_height: u32,
+) -> Result<(u64, (u64, u64))> {
+    // Retrieve the cost table.
+    let cost_table = CostTable::at_height::<N>(block_height);
+
     // Compute the storage cost in microcredits.
-    let storage_cost = execution.size_in_bytes()?;
+    let storage_cost = size_in_bytes
+        .checked_mul(cost_table.execution_fee_multiplier)
+        .ok_or(anyhow!("The storage cost computation overflowed for an execution"))?;
 
     // Prepare the program lookup.
     let lookup = execution
         .transitions()
         .map(|transition| {
             let program_id = transition.program_id();
-            Ok((*program_id, vm.process().read().get_program(program_id)?.clone()))
+            Ok((*program_id, process.get_program(program_id)?))
         })
         .collect::<Result<HashMap<_, _>>>()?;
 
@@ -80,7 +107,7 @@ pub fn execution_cost<N: Network, C: ConsensusStorage<N>>(
         let program = lookup.get(program_id).ok_or(anyhow!("Program '{program_id}' is missing"))?;
         // Retrieve the finalize cost.
         let cost = match program.get_function(function_name)?.finalize() {
-            Some((_, finalize)) => cost_in_microcredits(finalize)?,
+            Some((_, finalize)) => cost_table.finalize_cost(finalize)?,
             None => continue,
         };
         // Accumulate the finalize cost.
@@ -89,6 +116,13 @@ pub fn execution_cost<N: Network, C: ConsensusStorage<N>>(
             .ok_or(anyhow!("The finalize cost computation overflowed for an execution"))?;
     }
 
+    // Ensure the finalize cost is within the limit.
+    ensure!(
+        finalize_cost <= N::MAX_FINALIZE_COST,
+        "The finalize cost ({finalize_cost} microcredits) exceeds the limit ({} microcredits)",
+        N::MAX_FINALIZE_COST
+    );
+
     // Compute the total cost in microcredits.
     let total_cost = storage_cost
         .checked_add(finalize_cost)
@@ -97,104 +131,28 @@ pub fn execution_cost<N: Network, C: ConsensusStorage<N>>(
     Ok((total_cost, (storage_cost, finalize_cost)))
 }
 
-/// Returns the minimum number of microcredits required to run the finalize.
-pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
-    // Defines the cost of each command.
-    let cost = |command: &Command<N>| match command {
-        Command::Instruction(Instruction::Abs(_)) => Ok(2_000),
-        Command::Instruction(Instruction::AbsWrapped(_)) => Ok(2_000),
-        Command::Instruction(Instruction::Add(_)) => Ok(2_000),
-        Command::Instruction(Instruction::AddWrapped(_)) => Ok(2_000),
-        Command::Instruction(Instruction::And(_)) => Ok(2_000),
-        Command::Instruction(Instruction::AssertEq(_)) => Ok(2_000),
-        Command::Instruction(Instruction::AssertNeq(_)) => Ok(2_000),
-        Command::Instruction(Instruction::Call(_)) => bail!("`call` is not supported in finalize."),
-        Command::Instruction(Instruction::Cast(_)) => Ok(2_000),
-        Command::Instruction(Instruction::CommitBHP256(_)) => Ok(200_000),
-        Command::Instruction(Instruction::CommitBHP512(_)) => Ok(200_000),
-        Command::Instruction(Instruction::CommitBHP768(_)) => Ok(200_000),
-        Command::Instruction(Instruction::CommitBHP1024(_)) => Ok(200_000),
-        Command::Instruction(Instruction::CommitPED64(_)) => Ok(100_000),
-        Command::Instruction(Instruction::CommitPED128(_)) => Ok(100_000),
-        Command::Instruction(Instruction::Div(_)) => Ok(10_000),
-        Command::Instruction(Instruction::DivWrapped(_)) => Ok(2_000),
-        Command::Instruction(Instruction::Double(_)) => Ok(2_000),
-        Command::Instruction(Instruction::GreaterThan(_)) => Ok(2_000),
-        Command::Instruction(Instruction::GreaterThanOrEqual(_)) => Ok(2_000),
-        Command::Instruction(Instruction::HashBHP256(_)) => Ok(100_000),
-        Command::Instruction(Instruction::HashBHP512(_)) => Ok(100_000),
-        Command::Instruction(Instruction::HashBHP768(_)) => Ok(100_000),
-        Command::Instruction(Instruction::HashBHP1024(_)) => Ok(100_000),
-        Command::Instruction(Instruction::HashKeccak256(_)) => Ok(100_000),
-        Command::Instruction(Instruction::HashKeccak384(_)) => Ok(100_000),
-        Command::Instruction(Instruction::HashKeccak512(_)) => Ok(100_000),
-        Command::Instruction(Instruction::HashPED64(_)) => Ok(20_000),
-        Command::Instruction(Instruction::HashPED128(_)) => Ok(30_000),
-        Command::Instruction(Instruction::HashPSD2(hash)) => match hash.destination_type() {
-            PlaintextType::Literal(LiteralType::Address) | PlaintextType::Literal(LiteralType::Group) => Ok(600_000),
-            PlaintextType::Literal(..) => Ok(60_000),
-            plaintext_type => bail!("`hash.psd2` is not supported for plaintext type '{plaintext_type}'"),
-        },
-        Command::Instruction(Instruction::HashPSD4(hash)) => match hash.destination_type() {
-            PlaintextType::Literal(LiteralType::Address) | PlaintextType::Literal(LiteralType::Group) => Ok(700_000),
-            PlaintextType::Literal(..) => Ok(100_000),
-            plaintext_type => bail!("`hash.psd4` is not supported for plaintext type '{plaintext_type}'"),
-        },
-        Command::Instruction(Instruction::HashPSD8(hash)) => match hash.destination_type() {
-            PlaintextType::Literal(LiteralType::Address) | PlaintextType::Literal(LiteralType::Group) => Ok(800_000),
-            PlaintextType::Literal(..) => Ok(200_000),
-            plaintext_type => bail!("`hash.psd8` is not supported for plaintext type '{plaintext_type}'"),
-        },
-        Command::Instruction(Instruction::HashSha3_256(_)) => Ok(100_000),
-        Command::Instruction(Instruction::HashSha3_384(_)) => Ok(100_000),
-        Command::Instruction(Instruction::HashSha3_512(_)) => Ok(100_000),
-        Command::Instruction(Instruction::HashManyPSD2(_)) => {
-            bail!("`hash_many.psd2` is not supported in finalize.")
-        }
-        Command::Instruction(Instruction::HashManyPSD4(_)) => {
-            bail!("`hash_many.psd4` is not supported in finalize.")
-        }
-        Command::Instruction(Instruction::HashManyPSD8(_)) => {
-            bail!("`hash_many.psd8` is not supported in finalize.")
-        }
-        Command::Instruction(Instruction::Inv(_)) => Ok(10_000),
-        Command::Instruction(Instruction::IsEq(_)) => Ok(2_000),
-        Command::Instruction(Instruction::IsNeq(_)) => Ok(2_000),
-        Command::Instruction(Instruction::LessThan(_)) => Ok(2_000),
-        Command::Instruction(Instruction::LessThanOrEqual(_)) => Ok(2_000),
-        Command::Instruction(Instruction::Modulo(_)) => Ok(2_000),
-        Command::Instruction(Instruction::Mul(_)) => Ok(150_000),
-        Command::Instruction(Instruction::MulWrapped(_)) => Ok(2_000),
-        Command::Instruction(Instruction::Nand(_)) => Ok(2_000),
-        Command::Instruction(Instruction::Neg(_)) => Ok(2_000),
-        Command::Instruction(Instruction::Nor(_)) => Ok(2_000),
-        Command::Instruction(Instruction::Not(_)) => Ok(2_000),
-        Command::Instruction(Instruction::Or(_)) => Ok(2_000),
-        Command::Instruction(Instruction::Pow(_)) => Ok(20_000),
-        Command::Instruction(Instruction::PowWrapped(_)) => Ok(2_000),
-        Command::Instruction(Instruction::Rem(_)) => Ok(2_000),
-        Command::Instruction(Instruction::RemWrapped(_)) => Ok(2_000),
-        Command::Instruction(Instruction::SignVerify(_)) => Ok(1_000_000),
-        Command::Instruction(Instruction::Shl(_)) => Ok(2_000),
-        Command::Instruction(Instruction::ShlWrapped(_)) => Ok(2_000),
-        Command::Instruction(Instruction::Shr(_)) => Ok(2_000),
-        Command::Instruction(Instruction::ShrWrapped(_)) => Ok(2_000),
-        Command::Instruction(Instruction::Square(_)) => Ok(2_000),
-        Command::Instruction(Instruction::SquareRoot(_)) => Ok(120_000),
-        Command::Instruction(Instruction::Sub(_)) => Ok(10_000),
-        Command::Instruction(Instruction::SubWrapped(_)) => Ok(2_000),
-        Command::Instruction(Instruction::Ternary(_)) => Ok(2_000),
-        Command::Instruction(Instruction::Xor(_)) => Ok(2_000),
-        // TODO: The following 'finalize' commands are currently priced higher than expected.
-        //  Expect these numbers to change as their usage is stabilized.
-        Command::Contains(_) => Ok(250_000),
-        Command::Get(_) => Ok(500_000),
-        Command::GetOrUse(_) => Ok(500_000),
-        Command::RandChaCha(_) => Ok(500_000),
-        Command::Remove(_) => Ok(10_000),
-        Command::Set(_) => Ok(1_000_000),
-        Command::BranchEq(_) | Command::BranchNeq(_) => Ok(5_000),
-        Command::Position(_) => Ok(1_000),
+/// Returns the weight of the given transaction, including its *minimum* cost in microcredits at the given block height.
+pub fn transaction_weight<N: Network, C: ConsensusStorage<N>>(
+    vm: &VM<N, C>,
+    transaction: &Transaction<N>,
+    block_height: u32,
+) -> Result<TransactionWeight> {
+    // Retrieve the minimum cost of the transaction.
+    let (_, (storage_cost, finalize_cost)) = match transaction {
+        // Compute the deployment cost.
+        Transaction::Deploy(_, _, deployment, _) => deployment_cost(deployment, block_height)?,
+        // Compute the execution cost.
+        Transaction::Execute(_, execution, _) => execution_cost(vm, execution, block_height)?,
+        // Note: A fee transaction has no deployment or execution to pay for.
+        Transaction::Fee(_, _) => (0, (0, 0)),
     };
-    finalize.commands().iter().map(|command| cost(command)).sum()
+    // Ensure the transaction is within the consensus limits, and the fee covers its minimum cost.
+    let weight = transaction.weight()?.with_minimum_cost(storage_cost, finalize_cost);
+    weight.check()?;
+    Ok(weight)
+}
+
+/// Returns the minimum number of microcredits required to run the finalize at the given block height.
+pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>, block_height: u32) -> Result<u64> {
+    CostTable::at_height::<N>(block_height).finalize_cost(finalize)
 }
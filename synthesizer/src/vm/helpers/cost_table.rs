@@ -0,0 +1,267 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    prelude::*,
+    program::{LiteralType, PlaintextType},
+};
+use synthesizer_program::{Command, Finalize, Instruction};
+
+/// The cost of each finalize opcode in version 0, in microcredits.
+/// Note: `call` and `hash_many.*` are not supported in finalize, and are therefore absent.
+const V0_OPCODE_COSTS: &[(&str, u64)] = &[
+    ("abs", 2_000),
+    ("abs.w", 2_000),
+    ("add", 2_000),
+    ("add.w", 2_000),
+    ("and", 2_000),
+    ("assert.eq", 2_000),
+    ("assert.neq", 2_000),
+    ("cast", 2_000),
+    ("commit.bhp256", 200_000),
+    ("commit.bhp512", 200_000),
+    ("commit.bhp768", 200_000),
+    ("commit.bhp1024", 200_000),
+    ("commit.ped64", 100_000),
+    ("commit.ped128", 100_000),
+    ("div", 10_000),
+    ("div.w", 2_000),
+    ("double", 2_000),
+    ("gt", 2_000),
+    ("gte", 2_000),
+    ("hash.bhp256", 100_000),
+    ("hash.bhp512", 100_000),
+    ("hash.bhp768", 100_000),
+    ("hash.bhp1024", 100_000),
+    ("hash.keccak256", 100_000),
+    ("hash.keccak384", 100_000),
+    ("hash.keccak512", 100_000),
+    ("hash.ped64", 20_000),
+    ("hash.ped128", 30_000),
+    ("hash.psd2", 60_000),
+    ("hash.psd4", 100_000),
+    ("hash.psd8", 200_000),
+    ("hash.sha3_256", 100_000),
+    ("hash.sha3_384", 100_000),
+    ("hash.sha3_512", 100_000),
+    ("inv", 10_000),
+    ("is.eq", 2_000),
+    ("is.neq", 2_000),
+    ("lt", 2_000),
+    ("lte", 2_000),
+    ("mod", 2_000),
+    ("mul", 150_000),
+    ("mul.w", 2_000),
+    ("nand", 2_000),
+    ("neg", 2_000),
+    ("nor", 2_000),
+    ("not", 2_000),
+    ("or", 2_000),
+    ("pow", 20_000),
+    ("pow.w", 2_000),
+    ("rem", 2_000),
+    ("rem.w", 2_000),
+    ("sign.verify", 1_000_000),
+    ("shl", 2_000),
+    ("shl.w", 2_000),
+    ("shr", 2_000),
+    ("shr.w", 2_000),
+    ("square", 2_000),
+    ("sqrt", 120_000),
+    ("sub", 10_000),
+    ("sub.w", 2_000),
+    ("ternary", 2_000),
+    ("xor", 2_000),
+    // TODO: The following 'finalize' commands are currently priced higher than expected.
+    //  Expect these numbers to change as their usage is stabilized.
+    ("contains", 250_000),
+    ("get", 500_000),
+    ("get.or_use", 500_000),
+    ("rand.chacha", 500_000),
+    ("remove", 10_000),
+    ("set", 1_000_000),
+    ("branch.eq", 5_000),
+    ("branch.neq", 5_000),
+    ("position", 1_000),
+];
+
+/// The cost of each Poseidon hash opcode with an `address` or `group` destination in version 0, in microcredits.
+const V0_HASH_TO_GROUP_COSTS: &[(&str, u64)] =
+    &[("hash.psd2", 600_000), ("hash.psd4", 700_000), ("hash.psd8", 800_000)];
+
+/// A versioned fee schedule, which applies to all blocks from its starting height,
+/// until the starting height of the next version.
+///
+/// Note: The cost tables are append-only. To change a fee, add a new version with a future
+/// starting height to `CostTable::history`, so the fees of past blocks remain reproducible.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CostTable {
+    /// The version of the cost table.
+    pub version: u16,
+    /// The block height from which the cost table applies.
+    pub starting_height: u32,
+    /// The cost in microcredits per byte of a deployment.
+    pub deployment_fee_multiplier: u64,
+    /// The cost in microcredits per byte of an execution.
+    pub execution_fee_multiplier: u64,
+    /// The multiplier applied to the cost of a finalize.
+    pub finalize_fee_multiplier: u64,
+    /// The number of characters in a program name, at or above which the namespace cost is 1 credit.
+    pub namespace_cost_characters: u32,
+    /// The cost of each finalize opcode, in microcredits.
+    pub opcode_costs: &'static [(&'static str, u64)],
+    /// The cost of each Poseidon hash opcode with an `address` or `group` destination, in microcredits.
+    pub hash_to_group_costs: &'static [(&'static str, u64)],
+}
+
+impl CostTable {
+    /// Returns version 0 of the cost table, which applies from genesis.
+    pub const fn v0<N: Network>() -> Self {
+        Self {
+            version: 0,
+            starting_height: 0,
+            deployment_fee_multiplier: N::DEPLOYMENT_FEE_MULTIPLIER,
+            execution_fee_multiplier: N::EXECUTION_FEE_MULTIPLIER,
+            finalize_fee_multiplier: N::FINALIZE_FEE_MULTIPLIER,
+            namespace_cost_characters: N::NAMESPACE_COST_CHARACTERS,
+            opcode_costs: V0_OPCODE_COSTS,
+            hash_to_group_costs: V0_HASH_TO_GROUP_COSTS,
+        }
+    }
+
+    /// Returns all cost tables, in order of their starting heights.
+    pub fn history<N: Network>() -> Vec<Self> {
+        vec![Self::v0::<N>()]
+    }
+
+    /// Returns the cost table for the given version.
+    pub fn get<N: Network>(version: u16) -> Result<Self> {
+        match Self::history::<N>().into_iter().find(|table| table.version == version) {
+            Some(table) => Ok(table),
+            None => bail!("Cost table version {version} does not exist"),
+        }
+    }
+
+    /// Returns the cost table that applies to the block at the given height.
+    pub fn at_height<N: Network>(block_height: u32) -> Self {
+        Self::history::<N>()
+            .into_iter()
+            .take_while(|table| table.starting_height <= block_height)
+            .last()
+            .unwrap_or_else(|| Self::v0::<N>())
+    }
+
+    /// Returns the latest cost table.
+    pub fn latest<N: Network>() -> Self {
+        Self::history::<N>().pop().unwrap_or_else(|| Self::v0::<N>())
+    }
+
+    /// Returns the cost of the given opcode in microcredits, if it is supported in finalize.
+    pub fn opcode_cost(&self, opcode: &str) -> Option<u64> {
+        self.opcode_costs.iter().find(|(candidate, _)| *candidate == opcode).map(|(_, cost)| *cost)
+    }
+
+    /// Returns the cost of the given command in microcredits.
+    pub fn command_cost<N: Network>(&self, command: &Command<N>) -> Result<u64> {
+        // Retrieve the opcode.
+        let opcode = *command.opcode();
+        // Retrieve the destination type, if the command is a Poseidon hash.
+        let destination_type = match command {
+            Command::Instruction(Instruction::HashPSD2(hash)) => Some(hash.destination_type()),
+            Command::Instruction(Instruction::HashPSD4(hash)) => Some(hash.destination_type()),
+            Command::Instruction(Instruction::HashPSD8(hash)) => Some(hash.destination_type()),
+            _ => None,
+        };
+        // Retrieve the cost.
+        let cost = match destination_type {
+            // A Poseidon hash to an `address` or `group` is priced separately.
+            Some(PlaintextType::Literal(LiteralType::Address) | PlaintextType::Literal(LiteralType::Group)) => {
+                self.hash_to_group_costs.iter().find(|(candidate, _)| *candidate == opcode).map(|(_, cost)| *cost)
+            }
+            Some(PlaintextType::Literal(..)) | None => self.opcode_cost(opcode),
+            Some(plaintext_type) => bail!("`{opcode}` is not supported for plaintext type '{plaintext_type}'"),
+        };
+        cost.ok_or_else(|| anyhow!("`{opcode}` is not supported in finalize."))
+    }
+
+    /// Returns the minimum number of microcredits required to run the given finalize.
+    pub fn finalize_cost<N: Network>(&self, finalize: &Finalize<N>) -> Result<u64> {
+        // Compute the cost of the commands, and apply the fee multiplier.
+        finalize
+            .commands()
+            .iter()
+            .map(|command| self.command_cost(command))
+            .sum::<Result<u64>>()?
+            .checked_mul(self.finalize_fee_multiplier)
+            .ok_or(anyhow!("The finalize cost computation overflowed"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::program::Identifier;
+    use synthesizer_program::Program;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_history() {
+        let history = CostTable::history::<CurrentNetwork>();
+        // Ensure the first cost table applies from genesis.
+        assert_eq!(history[0].starting_height, 0);
+        // Ensure the versions are sequential, and the starting heights are strictly increasing.
+        for (index, (previous, next)) in history.iter().zip(history.iter().skip(1)).enumerate() {
+            assert_eq!(usize::from(next.version), index + 1);
+            assert!(previous.starting_height < next.starting_height);
+        }
+        // Ensure each cost table is selected from its starting height.
+        for table in &history {
+            assert_eq!(CostTable::at_height::<CurrentNetwork>(table.starting_height), *table);
+            assert_eq!(CostTable::get::<CurrentNetwork>(table.version).unwrap(), *table);
+        }
+        assert_eq!(CostTable::at_height::<CurrentNetwork>(u32::MAX), CostTable::latest::<CurrentNetwork>());
+        assert!(CostTable::get::<CurrentNetwork>(u16::MAX).is_err());
+    }
+
+    #[test]
+    fn test_finalize_cost_v0() {
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program cost_table.aleo;
+
+mapping values:
+    key as field.public;
+    value as field.public;
+
+function store:
+    input r0 as field.public;
+    finalize r0;
+
+finalize store:
+    input r0 as field.public;
+    hash.psd2 r0 into r1 as field;
+    hash.psd2 r0 into r2 as group;
+    add r0 r1 into r3;
+    set r3 into values[r0];",
+        )
+        .unwrap();
+        let function = program.get_function(&Identifier::from_str("store").unwrap()).unwrap();
+        let (_, finalize) = function.finalize().unwrap();
+
+        // Ensure the cost is the sum of the opcode costs.
+        let table = CostTable::v0::<CurrentNetwork>();
+        assert_eq!(table.finalize_cost(finalize).unwrap(), 60_000 + 600_000 + 2_000 + 1_000_000);
+    }
+}
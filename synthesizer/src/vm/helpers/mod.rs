@@ -18,7 +18,16 @@ pub use committee::*;
 mod cost;
 pub use cost::*;
 
+mod cost_table;
+pub use cost_table::*;
+
 mod macros;
 
+mod proposal;
+pub use proposal::*;
+
 mod rewards;
 pub use rewards::*;
+
+mod staking;
+pub use staking::*;
@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{network::prelude::*, program::TRANSACTIONS_DEPTH};
+use ledger_block::{Ratify, Transaction, Transactions};
+
+/// The limits on the candidate transactions that are selected for a proposed block.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BlockLimits {
+    /// The maximum number of transactions.
+    pub max_transactions: usize,
+    /// The maximum total size of the transactions, in bytes.
+    pub max_size_in_bytes: usize,
+}
+
+impl Default for BlockLimits {
+    /// Returns the protocol limits for the transactions in a block.
+    /// Note: The number of transactions is further capped by the network, when proposing a block.
+    fn default() -> Self {
+        Self { max_transactions: usize::pow(2, TRANSACTIONS_DEPTH as u32), max_size_in_bytes: usize::MAX }
+    }
+}
+
+/// The outcome of speculatively finalizing the candidate transactions for a block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProposedBlock<N: Network> {
+    /// The ratifications.
+    ratifications: Vec<Ratify<N>>,
+    /// The confirmed transactions, in block order.
+    transactions: Transactions<N>,
+    /// The transactions that were aborted during speculation.
+    aborted: Vec<Transaction<N>>,
+    /// The IDs of the candidate transactions that were dropped before speculation.
+    dropped: Vec<N::TransactionID>,
+}
+
+impl<N: Network> ProposedBlock<N> {
+    /// Initializes a new proposed block.
+    pub const fn new(
+        ratifications: Vec<Ratify<N>>,
+        transactions: Transactions<N>,
+        aborted: Vec<Transaction<N>>,
+        dropped: Vec<N::TransactionID>,
+    ) -> Self {
+        Self { ratifications, transactions, aborted, dropped }
+    }
+
+    /// Returns the ratifications.
+    pub fn ratifications(&self) -> &[Ratify<N>] {
+        &self.ratifications
+    }
+
+    /// Returns the confirmed transactions, in block order.
+    pub const fn transactions(&self) -> &Transactions<N> {
+        &self.transactions
+    }
+
+    /// Returns the transactions that were aborted during speculation.
+    pub fn aborted(&self) -> &[Transaction<N>] {
+        &self.aborted
+    }
+
+    /// Returns the IDs of the candidate transactions that were dropped before speculation.
+    pub fn dropped(&self) -> &[N::TransactionID] {
+        &self.dropped
+    }
+
+    /// Returns the ratifications and the confirmed transactions.
+    pub fn into_parts(self) -> (Vec<Ratify<N>>, Transactions<N>) {
+        (self.ratifications, self.transactions)
+    }
+}
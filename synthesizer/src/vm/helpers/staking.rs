@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    account::Address,
+    network::Network,
+    program::{Identifier, Literal, Plaintext, Value},
+};
+use ledger_committee::Committee;
+
+use anyhow::{bail, Result};
+use indexmap::IndexMap;
+use std::str::FromStr;
+
+/// The staking state of `credits.aleo`, as read from its `committee`, `bonded`, and `unbonding` mappings.
+///
+/// Note: The staking state is a snapshot of finalize storage. To preview the effect of a staking transaction,
+/// execute the transaction against the VM, as the staking logic is only defined by `credits.aleo`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StakingState<N: Network> {
+    /// The committee, as a map of `validator` to `(microcredits, is_open)`.
+    pub committee: IndexMap<Address<N>, (u64, bool)>,
+    /// The stakers, as a map of `staker` to `(validator, microcredits)`.
+    pub bonded: IndexMap<Address<N>, (Address<N>, u64)>,
+    /// The unbonding queue, as a map of `staker` to `(microcredits, claimable height)`.
+    pub unbonding: IndexMap<Address<N>, (u64, u32)>,
+    /// The public balances of the requested accounts.
+    pub balances: IndexMap<Address<N>, u64>,
+}
+
+impl<N: Network> StakingState<N> {
+    /// Initializes the staking state from the `committee`, `bonded`, and `unbonding` mappings in finalize storage,
+    /// and the given public balances.
+    pub fn from_mappings(
+        committee_map: Vec<(Plaintext<N>, Value<N>)>,
+        bonded_map: Vec<(Plaintext<N>, Value<N>)>,
+        unbonding_map: Vec<(Plaintext<N>, Value<N>)>,
+        balances: IndexMap<Address<N>, u64>,
+    ) -> Result<Self> {
+        // Prepare the identifiers.
+        let microcredits = Identifier::from_str("microcredits")?;
+        let is_open = Identifier::from_str("is_open")?;
+        let height = Identifier::from_str("height")?;
+
+        let committee = committee_map
+            .into_iter()
+            .map(|(key, value)| {
+                let validator = key_to_address(&key)?;
+                let state = match &value {
+                    Value::Plaintext(Plaintext::Struct(state, _)) => state,
+                    _ => bail!("Invalid committee state - {value}"),
+                };
+                match (state.get(&microcredits), state.get(&is_open)) {
+                    (
+                        Some(Plaintext::Literal(Literal::U64(microcredits), _)),
+                        Some(Plaintext::Literal(Literal::Boolean(is_open), _)),
+                    ) => Ok((validator, (**microcredits, **is_open))),
+                    _ => bail!("Invalid committee state - {value}"),
+                }
+            })
+            .collect::<Result<_>>()?;
+        let unbonding = unbonding_map
+            .into_iter()
+            .map(|(key, value)| {
+                let staker = key_to_address(&key)?;
+                let state = match &value {
+                    Value::Plaintext(Plaintext::Struct(state, _)) => state,
+                    _ => bail!("Invalid unbond state - {value}"),
+                };
+                match (state.get(&microcredits), state.get(&height)) {
+                    (
+                        Some(Plaintext::Literal(Literal::U64(microcredits), _)),
+                        Some(Plaintext::Literal(Literal::U32(height), _)),
+                    ) => Ok((staker, (**microcredits, **height))),
+                    _ => bail!("Invalid unbond state - {value}"),
+                }
+            })
+            .collect::<Result<_>>()?;
+        let bonded = crate::vm::bonded_map_into_stakers(bonded_map)?;
+
+        Ok(Self { committee, bonded, unbonding, balances })
+    }
+
+    /// Returns the committee for the given starting round, given the staking state.
+    pub fn to_committee(&self, starting_round: u64) -> Result<Committee<N>> {
+        Committee::new(starting_round, self.committee.clone())
+    }
+}
+
+/// Returns the address in the given mapping key.
+fn key_to_address<N: Network>(key: &Plaintext<N>) -> Result<Address<N>> {
+    match key {
+        Plaintext::Literal(Literal::Address(address), _) => Ok(*address),
+        _ => bail!("Invalid staking key (missing address) - {key}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::{account::PrivateKey, network::Testnet3, prelude::TestRng};
+
+    type CurrentNetwork = Testnet3;
+
+    fn sample_address(rng: &mut TestRng) -> Address<CurrentNetwork> {
+        Address::try_from(PrivateKey::new(rng).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_from_mappings() {
+        let rng = &mut TestRng::default();
+
+        let validator = sample_address(rng);
+        let delegator = sample_address(rng);
+        let key = |address: Address<CurrentNetwork>| Plaintext::from(Literal::Address(address));
+
+        // Construct the mappings of a validator with one delegator, who is unbonding.
+        let committee_map = vec![(key(validator), Value::from_str("{ microcredits: 30u64, is_open: false }").unwrap())];
+        let bonded_map = vec![
+            (key(validator), Value::from_str(&format!("{{ validator: {validator}, microcredits: 20u64 }}")).unwrap()),
+            (key(delegator), Value::from_str(&format!("{{ validator: {validator}, microcredits: 10u64 }}")).unwrap()),
+        ];
+        let unbonding_map = vec![(key(delegator), Value::from_str("{ microcredits: 5u64, height: 100u32 }").unwrap())];
+        let balances = [(delegator, 1u64)].into_iter().collect::<IndexMap<_, _>>();
+
+        let state = StakingState::from_mappings(committee_map, bonded_map, unbonding_map, balances.clone()).unwrap();
+        assert_eq!(state.committee[&validator], (30, false));
+        assert_eq!(state.bonded[&validator], (validator, 20));
+        assert_eq!(state.bonded[&delegator], (validator, 10));
+        assert_eq!(state.unbonding[&delegator], (5, 100));
+        assert_eq!(state.balances, balances);
+        // Note: A committee requires at least 4 members.
+        assert!(state.to_committee(1).is_err());
+
+        // Ensure malformed mappings are rejected.
+        let committee_map = vec![(key(validator), Value::from_str("{ microcredits: 30u64 }").unwrap())];
+        assert!(StakingState::from_mappings(committee_map, vec![], vec![], IndexMap::new()).is_err());
+        let unbonding_map =
+            vec![(Plaintext::from_str("1u64").unwrap(), Value::<CurrentNetwork>::from_str("5u64").unwrap())];
+        assert!(StakingState::from_mappings(vec![], vec![], unbonding_map, IndexMap::new()).is_err());
+    }
+}
@@ -15,10 +15,18 @@
 mod helpers;
 pub use helpers::*;
 
+mod estimate;
+pub use estimate::*;
+
+mod preflight;
+pub use preflight::*;
+
 mod authorize;
 mod deploy;
 mod execute;
 mod finalize;
+mod propose;
+mod ratify;
 mod verify;
 
 use crate::{cast_mut_ref, cast_ref, process};
@@ -28,10 +36,11 @@ use console::{
     program::{Identifier, Literal, Locator, Plaintext, ProgramID, ProgramOwner, Record, Value},
     types::{Field, U64},
 };
-use ledger_block::{Block, Deployment, Execution, Fee, Header, Ratify, Transaction};
+use ledger_block::{Block, Deployment, Execution, Fee, Header, Ratify, RejectionReason, Transaction};
 use ledger_committee::Committee;
 use ledger_query::Query;
 use ledger_store::{
+    atomic_batch_scope,
     atomic_finalize,
     BlockStore,
     ConsensusStorage,
@@ -42,7 +51,16 @@ use ledger_store::{
     TransactionStore,
     TransitionStore,
 };
-use synthesizer_process::{Authorization, Process, Trace};
+use synthesizer_process::{
+    install_with_rng,
+    Authorization,
+    Process,
+    ProofBatch,
+    ThreadPoolConfig,
+    ThreadPoolKind,
+    ThreadPools,
+    Trace,
+};
 use synthesizer_program::{FinalizeGlobalState, FinalizeStoreTrait, Program};
 
 use aleo_std::prelude::{finish, lap, timer};
@@ -56,15 +74,14 @@ pub struct VM<N: Network, C: ConsensusStorage<N>> {
     process: Arc<RwLock<Process<N>>>,
     /// The VM store.
     store: ConsensusStore<N, C>,
+    /// The rejection reasons of the transactions rejected during speculation, keyed by their unconfirmed transaction ID.
+    rejection_reasons: Arc<RwLock<IndexMap<N::TransactionID, RejectionReason<N>>>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Initializes the VM from storage.
     #[inline]
     pub fn from(store: ConsensusStore<N, C>) -> Result<Self> {
-        // Initialize a new process.
-        let mut process = Process::load()?;
-
         // Initialize the store for 'credits.aleo'.
         let credits = Program::<N>::credits()?;
         for mapping in credits.mappings().values() {
@@ -75,6 +92,18 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             }
         }
 
+        // Initialize a new process, with the programs deployed in storage.
+        let process = Self::load_process(&store)?;
+
+        // Return the new VM.
+        Ok(Self { process: Arc::new(RwLock::new(process)), store, rejection_reasons: Default::default() })
+    }
+
+    /// Initializes a new process, and loads the programs deployed in the given store.
+    fn load_process(store: &ConsensusStore<N, C>) -> Result<Process<N>> {
+        // Initialize a new process.
+        let mut process = Process::load()?;
+
         // A helper function to load the program into the process, and recursively load all imports.
         fn load_deployment_and_imports<N: Network, T: TransactionStorage<N>>(
             process: &mut Process<N>,
@@ -128,8 +157,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             load_deployment_and_imports(&mut process, transaction_store, *transaction_id)?;
         }
 
-        // Return the new VM.
-        Ok(Self { process: Arc::new(RwLock::new(process)), store })
+        Ok(process)
     }
 
     /// Returns `true` if a program with the given program ID exists.
@@ -143,6 +171,20 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     pub fn process(&self) -> Arc<RwLock<Process<N>>> {
         self.process.clone()
     }
+
+    /// Sets the thread pools for synthesis, proving, and verification, for the given configuration.
+    #[inline]
+    pub fn set_thread_pools(&self, config: ThreadPoolConfig) -> Result<()> {
+        let thread_pools = ThreadPools::new(config)?;
+        self.process.write().set_thread_pools(thread_pools);
+        Ok(())
+    }
+
+    /// Sets the limit on the estimated memory usage (in bytes) of each circuit during synthesis, or removes it if `None`.
+    #[inline]
+    pub fn set_memory_limit(&self, memory_limit: Option<u64>) {
+        self.process.write().set_memory_limit(memory_limit);
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -169,6 +211,12 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     pub fn transition_store(&self) -> &TransitionStore<N, C::TransitionStorage> {
         self.store.transition_store()
     }
+
+    /// Returns the height of the next block, which determines the cost table for new transactions.
+    #[inline]
+    pub fn next_block_height(&self) -> u32 {
+        self.block_store().current_block_height().map_or(0, |height| height.saturating_add(1))
+    }
 }
 
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
@@ -258,24 +306,70 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             block.previous_hash(),
         )?;
 
-        // Attention: The following order is crucial because if 'finalize' fails, we can rollback the block.
-        // If one first calls 'finalize', then calls 'insert(block)' and it fails, there is no way to rollback 'finalize'.
-
-        // First, insert the block.
-        self.block_store().insert(block)?;
-        // Next, finalize the transactions.
-        match self.finalize(state, block.ratifications(), block.coinbase(), block.transactions()) {
-            Ok(_) => {
-                // TODO (howardwu): Check the accepted, rejected, and finalize operations match the block.
-                Ok(())
+        // Insert the block, and finalize the transactions, in a single atomic write batch.
+        // This ensures the block store, finalize store, and committee store are never partially written,
+        // as the block and its finalize operations are either committed together, or not at all.
+        self.block_store().insert_with(block, || {
+            // Record the undo log of the finalize operations, to be able to revert the block.
+            self.finalize_store().start_undo_log();
+            // TODO (howardwu): Check the accepted, rejected, and finalize operations match the block.
+            let rejection_reasons =
+                match self.finalize(state, block.ratifications(), block.coinbase(), block.transactions()) {
+                    Ok(rejection_reasons) => rejection_reasons,
+                    Err(error) => {
+                        self.finalize_store().abort_undo_log();
+                        return Err(error);
+                    }
+                };
+            // Store the undo log of the block.
+            self.finalize_store().finish_undo_log(block.height())?;
+            // Store the rejection reasons of the rejected transactions.
+            for (unconfirmed_id, reason) in rejection_reasons {
+                self.transaction_store().insert_rejection_reason(unconfirmed_id, reason)?;
             }
-            Err(error) => {
-                // Rollback the block.
-                self.block_store().remove_last_n(1)?;
-                // Return the error.
-                Err(error)
+            Ok(())
+        })
+    }
+
+    /// Reverts the VM to the given block height, removing all blocks above the given height.
+    ///
+    /// The finalize state is restored by applying the undo logs of the removed blocks, from the latest block
+    /// down to the given height. The finalize state, committees, and blocks are reverted in a single atomic batch.
+    pub fn revert_to(&self, height: u32) -> Result<()> {
+        // Retrieve the latest height.
+        let latest_height = match self.block_store().heights().max() {
+            Some(height) => *height,
+            None => bail!("Failed to revert to block {height}: no blocks in storage"),
+        };
+        // Ensure the given height is less than the latest height.
+        ensure!(height < latest_height, "Failed to revert to block {height}: the latest block is {latest_height}");
+        // Ensure the undo logs of the removed blocks exist.
+        for block_height in height + 1..=latest_height {
+            if !self.finalize_store().contains_undo_log(block_height)? {
+                bail!("Failed to revert to block {height}: missing the undo log for block {block_height}")
             }
         }
+
+        // Acquire the write lock on the process.
+        // Note: This ensures no finalize is performed on the process while the VM is reverted.
+        let mut process = self.process.write();
+
+        atomic_batch_scope!(self.store, {
+            // Revert the finalize operations of the removed blocks, starting from the latest block.
+            for block_height in (height + 1..=latest_height).rev() {
+                self.finalize_store().revert_block(block_height)?;
+            }
+            // Remove the committees above the given height.
+            self.finalize_store().committee_store().remove_after(height)?;
+            // Remove the blocks above the given height.
+            // Note: This is performed last, as it updates the block tree upon success.
+            self.block_store().remove_last_n(latest_height - height)?;
+            Ok(())
+        })?;
+
+        // Reload the process, which excludes the programs deployed after the given height.
+        *process = Self::load_process(&self.store)?;
+        Ok(())
     }
 }
 
@@ -0,0 +1,281 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use console::program::PlaintextType;
+use ledger_block::{TransactionWeight, Transition};
+use synthesizer_program::StackProgram;
+
+/// An issue found by a preflight check, which causes the transaction to be rejected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PreflightIssue<N: Network> {
+    /// The transaction ID does not match the transaction.
+    InvalidTransactionID,
+    /// The transaction contains a duplicate element, as the name of the element.
+    DuplicateElement(&'static str),
+    /// The transaction exceeds a consensus limit, as the reason.
+    ExceedsLimit(String),
+    /// The transaction is missing a required fee.
+    MissingFee,
+    /// The fee does not cover the minimum cost, as (fee amount, minimum cost) in microcredits.
+    InsufficientFee(u64, u64),
+    /// The minimum cost could not be computed, as the reason.
+    UnknownCost(String),
+    /// The transaction calls a program that is not deployed in the VM.
+    UnknownProgram(ProgramID<N>),
+    /// The finalize inputs of a transition do not match its function, as (transition ID, reason).
+    MalformedFinalize(N::TransitionID, String),
+    /// The deployment failed verification, as the reason.
+    InvalidDeployment(String),
+    /// The execution failed verification, as the reason.
+    InvalidExecution(String),
+    /// The fee failed verification, as the reason.
+    InvalidFee(String),
+}
+
+/// The report of the preflight checks for a transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PreflightReport<N: Network> {
+    /// The transaction ID.
+    pub transaction_id: N::TransactionID,
+    /// The weight of the transaction, including its minimum cost if it is known.
+    pub weight: Option<TransactionWeight>,
+    /// The issues found in the transaction.
+    pub issues: Vec<PreflightIssue<N>>,
+}
+
+impl<N: Network> PreflightReport<N> {
+    /// Returns `true` if no issues were found, in which case the transaction is ready to broadcast.
+    pub fn is_ready(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Runs the checks on the given transaction that do not depend on the ledger state,
+    /// and returns a report of the issues that would cause the transaction to be rejected.
+    ///
+    /// Unlike `check_transaction`, this does not stop at the first issue, and does not check
+    /// whether the transaction elements already exist in the ledger, whether the global state roots
+    /// exist in the ledger, or whether a public fee payer has a sufficient balance.
+    pub fn preflight(&self, transaction: &Transaction<N>) -> PreflightReport<N> {
+        let timer = timer!("VM::preflight");
+
+        let mut issues = Vec::new();
+
+        /* Transaction */
+
+        // Ensure the transaction ID is correct.
+        if !matches!(transaction.to_root(), Ok(root) if root == *transaction.id()) {
+            issues.push(PreflightIssue::InvalidTransactionID);
+        }
+
+        /* Duplicates */
+
+        // Ensure the transaction elements are unique.
+        for (name, has_duplicates) in [
+            ("transition ID", has_duplicates(transaction.transition_ids())),
+            ("input ID", has_duplicates(transaction.input_ids())),
+            ("serial number", has_duplicates(transaction.serial_numbers())),
+            ("tag", has_duplicates(transaction.tags())),
+            ("output ID", has_duplicates(transaction.output_ids())),
+            ("commitment", has_duplicates(transaction.commitments())),
+            ("nonce", has_duplicates(transaction.nonces())),
+            ("transition public key", has_duplicates(transaction.transition_public_keys())),
+            ("transition commitment", has_duplicates(transaction.transition_commitments())),
+        ] {
+            if has_duplicates {
+                issues.push(PreflightIssue::DuplicateElement(name));
+            }
+        }
+        lap!(timer, "Check for duplicate elements");
+
+        /* Programs */
+
+        // Ensure the programs of the transitions are deployed, and the finalize inputs are well-formed.
+        let deployed_program_id = transaction.deployment().map(|deployment| *deployment.program_id());
+        for transition in transaction.transitions() {
+            // Skip the transitions of the program being deployed.
+            if Some(*transition.program_id()) == deployed_program_id {
+                continue;
+            }
+            match self.process.read().get_stack(transition.program_id()) {
+                Ok(stack) => {
+                    if let Err(error) = check_finalize_inputs(stack.program(), transition) {
+                        issues.push(PreflightIssue::MalformedFinalize(*transition.id(), error.to_string()));
+                    }
+                }
+                Err(_) => issues.push(PreflightIssue::UnknownProgram(*transition.program_id())),
+            }
+        }
+        lap!(timer, "Check the programs");
+
+        /* Weight */
+
+        // Compute the weight of the transaction.
+        let weight = match transaction.weight() {
+            Ok(weight) => Some(weight),
+            Err(error) => {
+                issues.push(PreflightIssue::ExceedsLimit(error.to_string()));
+                None
+            }
+        };
+        // Compute the minimum cost of the transaction.
+        let minimum_cost = match transaction {
+            Transaction::Deploy(_, _, deployment, _) => deployment_cost(deployment, self.next_block_height()),
+            Transaction::Execute(_, execution, _) => execution_cost(self, execution, self.next_block_height()),
+            Transaction::Fee(..) => Ok((0, (0, 0))),
+        };
+        let weight = match (weight, minimum_cost) {
+            (Some(weight), Ok((_, (storage_cost, finalize_cost)))) => {
+                Some(weight.with_minimum_cost(storage_cost, finalize_cost))
+            }
+            (weight, Err(error)) => {
+                issues.push(PreflightIssue::UnknownCost(error.to_string()));
+                weight
+            }
+            (None, Ok(_)) => None,
+        };
+        if let Some(weight) = &weight {
+            // Ensure the transaction is within the consensus limits.
+            let weight_without_cost = TransactionWeight { minimum_cost: None, ..*weight };
+            if let Err(error) = weight_without_cost.check() {
+                issues.push(PreflightIssue::ExceedsLimit(error.to_string()));
+            }
+            // Ensure the fee is present and covers the minimum cost, if it is required.
+            if weight.is_fee_required {
+                match (transaction.fee_transition(), weight.total_minimum_cost()) {
+                    (None, _) => issues.push(PreflightIssue::MissingFee),
+                    (Some(_), Ok(Some(total_cost))) if weight.fee_amount < total_cost => {
+                        issues.push(PreflightIssue::InsufficientFee(weight.fee_amount, total_cost))
+                    }
+                    (Some(_), Err(error)) => issues.push(PreflightIssue::UnknownCost(error.to_string())),
+                    (Some(_), _) => (),
+                }
+            }
+        }
+        lap!(timer, "Check the weight");
+
+        /* Proofs */
+
+        // Verify the deployment or execution.
+        match transaction {
+            Transaction::Deploy(_, _, deployment, _) => {
+                if let Err(error) = self.check_deployment(deployment) {
+                    issues.push(PreflightIssue::InvalidDeployment(error.to_string()));
+                }
+            }
+            Transaction::Execute(_, execution, _) => {
+                if let Err(error) = self.process.read().verify_execution(execution) {
+                    issues.push(PreflightIssue::InvalidExecution(error.to_string()));
+                }
+            }
+            Transaction::Fee(..) => (),
+        }
+        // Verify the fee, for a deployment or execution.
+        let deployment_or_execution_id = match transaction {
+            Transaction::Deploy(_, _, deployment, _) => deployment.to_deployment_id().ok(),
+            Transaction::Execute(_, execution, _) => execution.to_execution_id().ok(),
+            Transaction::Fee(..) => None,
+        };
+        if let (Some(fee), Some(id)) = (transaction.fee_transition(), deployment_or_execution_id) {
+            if let Err(error) = self.process.read().verify_fee(&fee, id) {
+                issues.push(PreflightIssue::InvalidFee(error.to_string()));
+            }
+        }
+        finish!(timer, "Verify the proofs");
+
+        PreflightReport { transaction_id: transaction.id(), weight, issues }
+    }
+}
+
+/// Ensures the finalize inputs of the given transition match the finalize scope of its function in the given program.
+fn check_finalize_inputs<N: Network>(program: &Program<N>, transition: &Transition<N>) -> Result<()> {
+    // Retrieve the function.
+    let function = program.get_function(transition.function_name())?;
+    match (function.finalize(), transition.finalize()) {
+        (Some((_, finalize)), Some(inputs)) => {
+            // Retrieve the finalize input types.
+            let input_types = finalize.input_types();
+            // Ensure the number of finalize inputs is correct.
+            ensure!(
+                inputs.len() == input_types.len(),
+                "Expected {} inputs for finalize, found {}",
+                input_types.len(),
+                inputs.len()
+            );
+            // Ensure the finalize inputs are plaintexts, and the literals are of the expected type.
+            for (index, (input, input_type)) in inputs.iter().zip(&input_types).enumerate() {
+                match (input, input_type) {
+                    (Value::Plaintext(Plaintext::Literal(literal, _)), PlaintextType::Literal(literal_type)) => {
+                        ensure!(
+                            literal.to_type() == *literal_type,
+                            "Expected finalize input {index} to be a '{literal_type}', found a '{}'",
+                            literal.to_type()
+                        )
+                    }
+                    (Value::Plaintext(_), _) => (),
+                    (Value::Record(_), _) => bail!("Expected finalize input {index} to be a plaintext, found a record"),
+                }
+            }
+            Ok(())
+        }
+        (Some(_), None) => bail!("The transition is missing inputs for 'finalize'"),
+        (None, Some(_)) => bail!("The transition contains inputs for 'finalize', but the function has no 'finalize'"),
+        (None, None) => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::test_helpers::CurrentNetwork;
+
+    #[test]
+    fn test_preflight() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Ensure the sample transactions are ready to broadcast.
+        for transaction in [
+            crate::vm::test_helpers::sample_deployment_transaction(rng),
+            crate::vm::test_helpers::sample_execution_transaction_with_private_fee(rng),
+            crate::vm::test_helpers::sample_execution_transaction_with_public_fee(rng),
+        ] {
+            let report = vm.preflight(&transaction);
+            assert!(report.is_ready(), "{:?}", report.issues);
+            assert_eq!(report.transaction_id, transaction.id());
+            assert!(report.weight.unwrap().minimum_cost.is_some());
+        }
+    }
+
+    #[test]
+    fn test_preflight_issues() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Ensure an execution without a required fee is not ready to broadcast.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction_with_private_fee(rng);
+        let execution = transaction.execution().unwrap().clone();
+        let transaction = Transaction::from_execution(execution.clone(), None).unwrap();
+        assert_eq!(vm.preflight(&transaction).issues, vec![PreflightIssue::MissingFee]);
+
+        // Ensure a transaction with an incorrect ID is not ready to broadcast.
+        let transaction_id = <CurrentNetwork as Network>::TransactionID::from(Field::rand(rng));
+        let transaction = Transaction::Execute(transaction_id, execution, None);
+        let report = vm.preflight(&transaction);
+        assert_eq!(report.issues, vec![PreflightIssue::InvalidTransactionID, PreflightIssue::MissingFee]);
+    }
+}
@@ -0,0 +1,164 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use ledger_block::Transactions;
+use ledger_coinbase::CoinbaseSolution;
+
+use std::collections::HashSet;
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Proposes the contents of the next block, by selecting from the given candidate transactions,
+    /// and speculatively finalizing them (alongside the ratifications and solutions) against the current state.
+    ///
+    /// The candidate transactions are considered in the given order. A candidate transaction is dropped if:
+    ///  1. it is a fee transaction, or
+    ///  2. its transaction ID already exists in the ledger or in an earlier candidate, or
+    ///  3. one of its input IDs already exists in the ledger or in an earlier candidate, or
+    ///  4. it does not fit within the given block limits.
+    /// The remaining transactions are speculatively finalized, and those that fail to finalize are aborted.
+    pub fn propose_block<'a>(
+        &self,
+        state: FinalizeGlobalState,
+        ratifications: Vec<Ratify<N>>,
+        solutions: Option<&CoinbaseSolution<N>>,
+        candidate_transactions: impl IntoIterator<Item = &'a Transaction<N>>,
+        limits: BlockLimits,
+    ) -> Result<ProposedBlock<N>> {
+        let timer = timer!("VM::propose_block");
+
+        // Initialize a list of the selected transactions.
+        let mut selected = Vec::new();
+        // Initialize a list of the dropped transaction IDs.
+        let mut dropped = Vec::new();
+        // Initialize the sets of the selected transaction IDs and input IDs.
+        let mut transaction_ids = HashSet::new();
+        let mut input_ids = HashSet::new();
+        // Initialize the total size of the selected transactions.
+        let mut size_in_bytes = 0usize;
+
+        // Select the candidate transactions.
+        for transaction in candidate_transactions {
+            let transaction_id = transaction.id();
+
+            // Ensure the transaction is not a fee transaction, and is unique.
+            let mut is_valid = !transaction.is_fee()
+                && !transaction_ids.contains(&transaction_id)
+                && !self.transaction_store().contains_transaction_id(&transaction_id)?;
+            // Ensure the input IDs are unique, and do not exist in the ledger.
+            if is_valid {
+                let mut transaction_input_ids = HashSet::new();
+                for input_id in transaction.input_ids() {
+                    if !transaction_input_ids.insert(input_id)
+                        || input_ids.contains(input_id)
+                        || self.transition_store().contains_input_id(input_id)?
+                    {
+                        is_valid = false;
+                        break;
+                    }
+                }
+            }
+            if !is_valid {
+                dropped.push(transaction_id);
+                continue;
+            }
+
+            // Determine if the transaction fits within the block limits.
+            let transaction_size = transaction.to_bytes_le()?.len();
+            let next_size_in_bytes = size_in_bytes.saturating_add(transaction_size);
+            if selected.len() >= limits.max_transactions.min(Transactions::<N>::MAX_TRANSACTIONS)
+                || next_size_in_bytes > limits.max_size_in_bytes
+            {
+                dropped.push(transaction_id);
+                continue;
+            }
+
+            // Select the transaction.
+            transaction_ids.insert(transaction_id);
+            input_ids.extend(transaction.input_ids().copied());
+            size_in_bytes = next_size_in_bytes;
+            selected.push(transaction);
+        }
+        lap!(timer, "Selected {} of {} candidate transactions", selected.len(), selected.len() + dropped.len());
+
+        // Speculate over the selected transactions.
+        let (transactions, aborted) = self.speculate(state, &ratifications, solutions, selected.into_iter())?;
+
+        finish!(timer, "Speculated on the selected transactions");
+        Ok(ProposedBlock::new(ratifications, transactions, aborted, dropped))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::test_helpers::{self, sample_finalize_state};
+
+    #[test]
+    fn test_propose_block() {
+        let rng = &mut TestRng::default();
+
+        // Initialize the VM.
+        let vm = test_helpers::sample_vm_with_genesis_block(rng);
+        let genesis = test_helpers::sample_genesis_block(rng);
+
+        // Sample the candidate transactions.
+        // Note: The deployment and the execution with a private fee spend the same record.
+        let deployment = test_helpers::sample_deployment_transaction(rng);
+        let execution_private_fee = test_helpers::sample_execution_transaction_with_private_fee(rng);
+        let execution_public_fee = test_helpers::sample_execution_transaction_with_public_fee(rng);
+        let fee = Transaction::from_fee(execution_public_fee.fee_transition().unwrap()).unwrap();
+        let existing = genesis.transactions().iter().next().unwrap().transaction().clone();
+
+        let candidates = [
+            deployment.clone(),
+            deployment.clone(),
+            existing.clone(),
+            execution_private_fee.clone(),
+            fee.clone(),
+            execution_public_fee.clone(),
+        ];
+
+        // Propose a block with the default limits.
+        let proposal = vm
+            .propose_block(sample_finalize_state(1), vec![], None, candidates.iter(), BlockLimits::default())
+            .unwrap();
+        assert!(proposal.ratifications().is_empty());
+        assert_eq!(proposal.transactions().transaction_ids().copied().collect::<Vec<_>>(), vec![
+            deployment.id(),
+            execution_public_fee.id()
+        ]);
+        assert!(proposal.aborted().is_empty());
+        assert_eq!(proposal.dropped(), &[deployment.id(), existing.id(), execution_private_fee.id(), fee.id()]);
+
+        // Propose a block with room for one transaction.
+        let limits = BlockLimits { max_transactions: 1, ..Default::default() };
+        let proposal = vm.propose_block(sample_finalize_state(1), vec![], None, candidates.iter(), limits).unwrap();
+        assert_eq!(proposal.transactions().transaction_ids().copied().collect::<Vec<_>>(), vec![deployment.id()]);
+        assert_eq!(proposal.dropped().len(), 5);
+
+        // Propose a block with room for one execution, and no room for the deployment.
+        let max_size_in_bytes =
+            execution_private_fee.to_bytes_le().unwrap().len().max(execution_public_fee.to_bytes_le().unwrap().len());
+        let limits = BlockLimits { max_size_in_bytes, ..Default::default() };
+        let proposal = vm.propose_block(sample_finalize_state(1), vec![], None, candidates.iter(), limits).unwrap();
+        assert_eq!(proposal.transactions().transaction_ids().copied().collect::<Vec<_>>(), vec![
+            execution_private_fee.id()
+        ]);
+
+        // Ensure the proposal matches the speculation over the selected transactions.
+        let (expected, _) = vm.speculate(sample_finalize_state(1), &[], None, [execution_private_fee].iter()).unwrap();
+        assert_eq!(proposal.transactions(), &expected);
+    }
+}
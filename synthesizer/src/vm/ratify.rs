@@ -0,0 +1,333 @@
+use super::*;
+use ledger_block::RatifyStage;
+use ledger_coinbase::CoinbaseSolution;
+
+/// A handler that validates and applies one kind of ratification to the finalize store.
+///
+/// To add a kind of ratification, add its variant to `Ratify`, implement a handler for it,
+/// and register the handler in `RatifyRegistry::default`.
+pub(crate) trait RatifyHandler<N: Network, C: ConsensusStorage<N>>: Send + Sync {
+    /// Returns the kind of ratification that this handler applies.
+    fn kind(&self) -> &'static str;
+
+    /// Ensures the ratification is valid in the given block, before it is applied.
+    fn validate(&self, _state: FinalizeGlobalState, _ratify: &Ratify<N>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Applies the ratification to the finalize store.
+    fn apply(
+        &self,
+        store: &FinalizeStore<N, C::FinalizeStorage>,
+        state: FinalizeGlobalState,
+        ratify: &Ratify<N>,
+        solutions: Option<&CoinbaseSolution<N>>,
+    ) -> Result<()>;
+}
+
+/// The registry of ratification handlers, by kind of ratification.
+pub(crate) struct RatifyRegistry<N: Network, C: ConsensusStorage<N>> {
+    /// The handlers, by the kind of ratification they apply.
+    handlers: IndexMap<&'static str, Box<dyn RatifyHandler<N, C>>>,
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Default for RatifyRegistry<N, C> {
+    /// Returns a registry with a handler for every kind of ratification.
+    fn default() -> Self {
+        let mut registry = Self::new();
+        registry.register(GenesisHandler).expect("Failed to register the genesis handler");
+        registry.register(BlockRewardHandler).expect("Failed to register the block reward handler");
+        registry.register(PuzzleRewardHandler).expect("Failed to register the puzzle reward handler");
+        registry
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> RatifyRegistry<N, C> {
+    /// Returns a registry without any handlers.
+    pub(crate) fn new() -> Self {
+        Self { handlers: IndexMap::new() }
+    }
+
+    /// Registers the given handler, if no handler is registered for its kind of ratification.
+    pub(crate) fn register(&mut self, handler: impl RatifyHandler<N, C> + 'static) -> Result<()> {
+        let kind = handler.kind();
+        ensure!(!self.handlers.contains_key(kind), "A handler for Ratify::{kind}(..) is already registered");
+        self.handlers.insert(kind, Box::new(handler));
+        Ok(())
+    }
+
+    /// Applies the ratifications for the given stage of finalize to the finalize store.
+    ///
+    /// Each ratification is validated against the consensus version of the block,
+    /// and then validated and applied by the handler registered for its kind.
+    pub(crate) fn apply(
+        &self,
+        store: &FinalizeStore<N, C::FinalizeStorage>,
+        state: FinalizeGlobalState,
+        stage: RatifyStage,
+        ratifications: &[Ratify<N>],
+        solutions: Option<&CoinbaseSolution<N>>,
+    ) -> Result<()> {
+        // Retrieve the consensus version of the block.
+        let consensus_version = N::consensus_version(state.block_height())?;
+
+        // Iterate over the ratifications for the given stage.
+        for ratify in ratifications.iter().filter(|ratify| ratify.stage() == stage) {
+            // Ensure the ratification is enabled in the consensus version.
+            ensure!(
+                ratify.is_enabled(consensus_version),
+                "Ratify::{}(..) is not enabled in consensus {consensus_version}",
+                ratify.kind()
+            );
+            // Retrieve the handler for the kind of ratification.
+            let Some(handler) = self.handlers.get(ratify.kind()) else {
+                bail!("Ratify::{}(..) has no registered handler", ratify.kind())
+            };
+            // Validate and apply the ratification.
+            handler.validate(state, ratify)?;
+            handler.apply(store, state, ratify, solutions)?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
+    /// Applies the ratifications for the given stage of finalize to the finalize store.
+    #[inline]
+    pub(super) fn atomic_ratify(
+        store: &FinalizeStore<N, C::FinalizeStorage>,
+        state: FinalizeGlobalState,
+        stage: RatifyStage,
+        ratifications: &[Ratify<N>],
+        solutions: Option<&CoinbaseSolution<N>>,
+    ) -> Result<()> {
+        RatifyRegistry::<N, C>::default().apply(store, state, stage, ratifications, solutions)
+    }
+}
+
+/// The handler for `Ratify::Genesis`, which initializes the genesis committee, bonded stakers, and public balances.
+struct GenesisHandler;
+
+impl<N: Network, C: ConsensusStorage<N>> RatifyHandler<N, C> for GenesisHandler {
+    fn kind(&self) -> &'static str {
+        "Genesis"
+    }
+
+    fn validate(&self, state: FinalizeGlobalState, ratify: &Ratify<N>) -> Result<()> {
+        let Ratify::Genesis(committee, _) = ratify else { bail!("Expected Ratify::Genesis(..)") };
+        // Ensure this is the genesis block.
+        ensure!(state.block_height() == 0, "Ratify::Genesis(..) expected a genesis block");
+        // Ensure the genesis committee round is 0.
+        ensure!(committee.starting_round() == 0, "Ratify::Genesis(..) expected a genesis committee round of 0");
+        Ok(())
+    }
+
+    fn apply(
+        &self,
+        store: &FinalizeStore<N, C::FinalizeStorage>,
+        state: FinalizeGlobalState,
+        ratify: &Ratify<N>,
+        _solutions: Option<&CoinbaseSolution<N>>,
+    ) -> Result<()> {
+        let Ratify::Genesis(committee, public_balances) = ratify else { bail!("Expected Ratify::Genesis(..)") };
+
+        // Construct the program ID.
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        // Construct the committee mapping name.
+        let committee_mapping = Identifier::from_str("committee")?;
+        // Construct the bonded mapping name.
+        let bonded_mapping = Identifier::from_str("bonded")?;
+        // Construct the account mapping name.
+        let account_mapping = Identifier::from_str("account")?;
+
+        // Initialize the stakers.
+        let mut stakers = IndexMap::with_capacity(committee.members().len());
+        // Iterate over the committee members.
+        for (validator, (microcredits, _)) in committee.members() {
+            // Insert the validator into the stakers.
+            stakers.insert(*validator, (*validator, *microcredits));
+        }
+
+        // Construct the next committee map and next bonded map.
+        let (next_committee_map, next_bonded_map) = to_next_commitee_map_and_bonded_map(committee, &stakers);
+
+        // Insert the next committee into storage.
+        store.committee_store().insert(state.block_height(), committee.clone())?;
+        // Replace the committee mapping in storage.
+        store.replace_mapping(&program_id, &committee_mapping, next_committee_map)?;
+        // Replace the bonded mapping in storage.
+        store.replace_mapping(&program_id, &bonded_mapping, next_bonded_map)?;
+
+        // Iterate over the public balances.
+        for (address, amount) in public_balances {
+            // Construct the key.
+            let key = Plaintext::from(Literal::Address(*address));
+            // Retrieve the current public balance.
+            let value = store.get_value_speculative(&program_id, &account_mapping, &key)?;
+            // Compute the next public balance.
+            let next_value = Value::from(Literal::U64(U64::new(match value {
+                Some(Value::Plaintext(Plaintext::Literal(Literal::U64(value), _))) => (*value).saturating_add(*amount),
+                None => *amount,
+                v => bail!("Critical bug in pre-ratify - Invalid public balance type ({v:?})"),
+            })));
+            // Update the public balance in finalize storage.
+            store.update_key_value(&program_id, &account_mapping, key, next_value)?;
+        }
+        Ok(())
+    }
+}
+
+/// The handler for `Ratify::BlockReward`, which distributes the block reward to the stakers, and updates the committee.
+struct BlockRewardHandler;
+
+impl<N: Network, C: ConsensusStorage<N>> RatifyHandler<N, C> for BlockRewardHandler {
+    fn kind(&self) -> &'static str {
+        "BlockReward"
+    }
+
+    fn apply(
+        &self,
+        store: &FinalizeStore<N, C::FinalizeStorage>,
+        state: FinalizeGlobalState,
+        ratify: &Ratify<N>,
+        _solutions: Option<&CoinbaseSolution<N>>,
+    ) -> Result<()> {
+        let Ratify::BlockReward(block_reward) = ratify else { bail!("Expected Ratify::BlockReward(..)") };
+
+        // Construct the program ID.
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        // Construct the committee mapping name.
+        let committee_mapping = Identifier::from_str("committee")?;
+        // Construct the bonded mapping name.
+        let bonded_mapping = Identifier::from_str("bonded")?;
+
+        // Retrieve the committee from storage.
+        let current_committee = store.committee_store().current_committee()?;
+        // Retrieve the committee mapping from storage.
+        let current_committee_map = store.get_mapping_speculative(&program_id, &committee_mapping)?;
+        // Retrieve the bonded mapping from storage.
+        let current_bonded_map = store.get_mapping_speculative(&program_id, &bonded_mapping)?;
+        // Convert the bonded map into stakers.
+        let current_stakers = bonded_map_into_stakers(current_bonded_map)?;
+
+        // Ensure the committee matches the committee mapping.
+        ensure_committee_matches(&current_committee, &current_committee_map)?;
+        // Ensure the committee matches the bonded mapping.
+        ensure_stakers_matches(&current_committee, &current_stakers)?;
+
+        // Compute the updated stakers, using the committee and block reward.
+        let next_stakers = staking_rewards(&current_stakers, &current_committee, *block_reward);
+        // Compute the updated committee, using the stakers.
+        let next_committee = to_next_committee(&current_committee, state.block_round(), &next_stakers)?;
+        // Construct the next committee map and next bonded map.
+        let (next_committee_map, next_bonded_map) = to_next_commitee_map_and_bonded_map(&next_committee, &next_stakers);
+
+        // Insert the next committee into storage.
+        store.committee_store().insert(state.block_height(), next_committee)?;
+        // Replace the committee mapping in storage.
+        store.replace_mapping(&program_id, &committee_mapping, next_committee_map)?;
+        // Replace the bonded mapping in storage.
+        store.replace_mapping(&program_id, &bonded_mapping, next_bonded_map)?;
+        Ok(())
+    }
+}
+
+/// The handler for `Ratify::PuzzleReward`, which distributes the puzzle reward to the provers of the solutions.
+struct PuzzleRewardHandler;
+
+impl<N: Network, C: ConsensusStorage<N>> RatifyHandler<N, C> for PuzzleRewardHandler {
+    fn kind(&self) -> &'static str {
+        "PuzzleReward"
+    }
+
+    fn apply(
+        &self,
+        store: &FinalizeStore<N, C::FinalizeStorage>,
+        _state: FinalizeGlobalState,
+        ratify: &Ratify<N>,
+        solutions: Option<&CoinbaseSolution<N>>,
+    ) -> Result<()> {
+        let Ratify::PuzzleReward(puzzle_reward) = ratify else { bail!("Expected Ratify::PuzzleReward(..)") };
+
+        // If the puzzle reward is zero, skip.
+        if *puzzle_reward == 0 {
+            return Ok(());
+        }
+        // Retrieve the solutions.
+        let Some(solutions) = solutions else {
+            return Ok(());
+        };
+
+        // Construct the program ID.
+        let program_id = ProgramID::from_str("credits.aleo")?;
+        // Construct the account mapping name.
+        let account_mapping = Identifier::from_str("account")?;
+
+        // Compute the proof targets, with the corresponding addresses.
+        let proof_targets = solutions
+            .partial_solutions()
+            .iter()
+            .map(|s| Ok((s.address(), s.to_target()?)))
+            .collect::<Result<Vec<_>>>()?;
+        // Calculate the proving rewards.
+        let proving_rewards = proving_rewards(proof_targets, *puzzle_reward);
+        // Iterate over the proving rewards.
+        for (address, amount) in proving_rewards {
+            // Construct the key.
+            let key = Plaintext::from(Literal::Address(address));
+            // Retrieve the current public balance.
+            let value = store.get_value_speculative(&program_id, &account_mapping, &key)?;
+            // Compute the next public balance.
+            let next_value = Value::from(Literal::U64(U64::new(match value {
+                Some(Value::Plaintext(Plaintext::Literal(Literal::U64(value), _))) => (*value).saturating_add(amount),
+                None => amount,
+                v => bail!("Critical bug in post-ratify puzzle reward- Invalid amount ({v:?})"),
+            })));
+            // Update the public balance in finalize storage.
+            store.update_key_value(&program_id, &account_mapping, key, next_value)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vm::test_helpers::{sample_finalize_state, sample_vm, CurrentNetwork};
+    use ledger_store::helpers::memory::ConsensusMemory;
+
+    type CurrentRegistry = RatifyRegistry<CurrentNetwork, ConsensusMemory<CurrentNetwork>>;
+
+    #[test]
+    fn test_ratify_registry() {
+        let vm = sample_vm();
+
+        // Ensure a kind of ratification cannot be registered twice.
+        let mut registry = CurrentRegistry::default();
+        let error = registry.register(PuzzleRewardHandler).unwrap_err();
+        assert!(error.to_string().contains("Ratify::PuzzleReward(..) is already registered"), "{error}");
+
+        // Ensure a ratification is rejected if no handler is registered for its kind.
+        let ratifications = [Ratify::PuzzleReward(0)];
+        let state = sample_finalize_state(1);
+        let error = CurrentRegistry::new()
+            .apply(vm.finalize_store(), state, RatifyStage::PostFinalize, &ratifications, None)
+            .unwrap_err();
+        assert!(error.to_string().contains("Ratify::PuzzleReward(..) has no registered handler"), "{error}");
+        // Ensure the ratification is applied once its handler is registered.
+        registry.apply(vm.finalize_store(), state, RatifyStage::PostFinalize, &ratifications, None).unwrap();
+    }
+
+    #[test]
+    fn test_ratify_validates_genesis() {
+        let rng = &mut TestRng::default();
+
+        let vm = sample_vm();
+
+        // Ensure a genesis ratification is rejected outside of the genesis block.
+        let committee = ledger_committee::test_helpers::sample_committee(rng);
+        let ratifications = [Ratify::Genesis(committee, IndexMap::new())];
+        let error = vm.speculate(sample_finalize_state(1), &ratifications, None, [].iter()).unwrap_err();
+        assert!(error.to_string().contains("Ratify::Genesis(..) expected a genesis block"), "{error}");
+    }
+}
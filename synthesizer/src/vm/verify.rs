@@ -14,6 +14,9 @@
 
 use super::*;
 
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 /// Ensures the given iterator has no duplicate elements, and that the ledger
 /// does not already contain a given item.
 macro_rules! ensure_is_unique {
@@ -44,17 +47,55 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
     /// Returns `true` if the execution is valid.
     pub fn verify_execution(&self, execution: &Execution<N>) -> bool {
-        self.check_execution(execution).map_err(|error| warn!("{error}")).is_ok()
+        self.check_execution(execution, None).map_err(|error| warn!("{error}")).is_ok()
     }
 
     /// Returns `true` if the fee is valid.
     pub fn verify_fee(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> bool {
-        self.check_fee(fee, deployment_or_execution_id).map_err(|error| warn!("{error}")).is_ok()
+        self.check_fee(fee, deployment_or_execution_id, None).map_err(|error| warn!("{error}")).is_ok()
     }
 
     /// Verifies the transaction in the VM. On failure, returns an error.
     #[inline]
     pub fn check_transaction(&self, transaction: &Transaction<N>, rejected_id: Option<Field<N>>) -> Result<()> {
+        self.check_transaction_with_batch(transaction, rejected_id, None)
+    }
+
+    /// Verifies the given transactions in the VM, each with its rejected ID (if any),
+    /// verifying their signatures and proofs together. On failure, returns an error.
+    ///
+    /// Each transaction is checked as in `check_transaction`, except that the deployment owner signatures
+    /// are verified together, with one batch check, and the execution and fee proofs are collected across
+    /// all of the transactions, each distinct verifying key is prepared once, and the proofs for the same
+    /// circuits are verified together, with one batch check.
+    /// Note: This does *not* check for duplicate elements across the given transactions.
+    pub fn check_transactions_batch(&self, transactions: &[(&Transaction<N>, Option<Field<N>>)]) -> Result<()> {
+        let timer = timer!("VM::check_transactions_batch");
+
+        // Check the transactions, and collect their signatures and proofs.
+        let batch = ProofBatch::new();
+        cfg_iter!(transactions).try_for_each(|(transaction, rejected_id)| {
+            self.check_transaction_with_batch(transaction, *rejected_id, Some(&batch))
+                .map_err(|error| anyhow!("Transaction '{}' is invalid - {error}", transaction.id()))
+        })?;
+        lap!(timer, "Check the transactions");
+
+        // Verify the signatures and proofs.
+        let num_proofs = batch.len();
+        self.process.read().verify_proof_batch(batch)?;
+        finish!(timer, "Verify {num_proofs} proofs");
+        Ok(())
+    }
+
+    /// Verifies the transaction in the VM. On failure, returns an error.
+    /// If a batch is given, the owner signature, and the execution and fee proofs, are added to the batch
+    /// instead of being verified.
+    fn check_transaction_with_batch(
+        &self,
+        transaction: &Transaction<N>,
+        rejected_id: Option<Field<N>>,
+        batch: Option<&ProofBatch<N>>,
+    ) -> Result<()> {
         let timer = timer!("VM::check_transaction");
 
         /* Transaction */
@@ -116,7 +157,18 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     bail!("Failed to compute the Merkle root for deployment transaction '{id}'")
                 };
                 // Verify the signature corresponds to the transaction ID.
-                ensure!(owner.verify(deployment_id), "Invalid owner signature for deployment transaction '{id}'");
+                match batch {
+                    Some(batch) => batch.push_signature(
+                        format!("owner signature for deployment transaction '{id}'"),
+                        *owner.signature(),
+                        owner.address(),
+                        vec![deployment_id],
+                    ),
+                    None => ensure!(
+                        owner.verify(deployment_id),
+                        "Invalid owner signature for deployment transaction '{id}'"
+                    ),
+                }
                 // Ensure the edition is correct.
                 ensure!(deployment.edition() == N::EDITION, "Invalid deployment: expected edition {}", N::EDITION);
                 // Ensure the program ID does not already exist..
@@ -124,7 +176,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     bail!("Program ID '{}' is already deployed", deployment.program_id())
                 }
                 // Verify the fee.
-                self.check_fee(fee, deployment_id)?;
+                self.check_fee(fee, deployment_id, batch)?;
                 // Verify the deployment.
                 self.check_deployment(deployment)?;
             }
@@ -137,21 +189,21 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 };
                 // Verify the fee.
                 if let Some(fee) = fee {
-                    self.check_fee(fee, execution_id)?;
+                    self.check_fee(fee, execution_id, batch)?;
                 } else {
                     // If the transaction contains only 1 transition, and the transition is a split, then the fee can be skipped.
                     let can_skip_fee = execution.len() == 1 && transaction.contains_split();
                     ensure!(can_skip_fee, "Transaction is missing a fee (execution)");
                 }
                 // Verify the execution.
-                self.check_execution(execution)?;
+                self.check_execution(execution, batch)?;
             }
             Transaction::Fee(_, fee) => {
                 // Ensure the fee is nonzero.
                 ensure!(!fee.is_zero()?, "Invalid fee (zero)");
                 // Verify the fee.
                 match rejected_id {
-                    Some(rejected_id) => self.check_fee(fee, rejected_id)?,
+                    Some(rejected_id) => self.check_fee(fee, rejected_id, batch)?,
                     None => bail!("Transaction is missing a rejected ID (fee)"),
                 }
             }
@@ -163,13 +215,13 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
     /// Verifies the given deployment. On failure, returns an error.
     #[inline]
-    fn check_deployment(&self, deployment: &Deployment<N>) -> Result<()> {
+    pub(super) fn check_deployment(&self, deployment: &Deployment<N>) -> Result<()> {
         macro_rules! logic {
             ($process:expr, $network:path, $aleo:path) => {{
                 // Prepare the deployment.
                 let deployment = cast_ref!(&deployment as Deployment<$network>);
                 // Verify the deployment.
-                $process.verify_deployment::<$aleo, _>(&deployment, &mut rand::thread_rng())
+                $process.verify_deployment::<$aleo, _>(&deployment, &mut rand::thread_rng()).map(|_| ())
             }};
         }
 
@@ -181,18 +233,22 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     }
 
     /// Verifies the given execution. On failure, returns an error.
+    /// If a batch is given, the execution proof is added to the batch instead of being verified.
     #[inline]
-    fn check_execution(&self, execution: &Execution<N>) -> Result<()> {
+    fn check_execution(&self, execution: &Execution<N>, batch: Option<&ProofBatch<N>>) -> Result<()> {
         let timer = timer!("VM::check_execution");
 
         // Verify the execution.
-        let verification = self.process.read().verify_execution(execution);
+        let verification = match batch {
+            Some(batch) => self.process.read().verify_execution_deferred(execution, batch),
+            None => self.process.read().verify_execution(execution),
+        };
         lap!(timer, "Verify the execution");
 
         // Ensure the global state root exists in the block store.
         let result = match verification {
             // Ensure the global state root exists in the block store.
-            Ok(()) => match self.block_store().contains_state_root(&execution.global_state_root()) {
+            Ok(_) => match self.block_store().contains_state_root(&execution.global_state_root()) {
                 Ok(true) => Ok(()),
                 Ok(false) => bail!("Execution verification failed: global state root not found"),
                 Err(error) => bail!("Execution verification failed: {error}"),
@@ -204,8 +260,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     }
 
     /// Verifies the given fee. On failure, returns an error.
+    /// If a batch is given, the fee proof is added to the batch instead of being verified.
     #[inline]
-    fn check_fee(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> Result<()> {
+    fn check_fee(
+        &self,
+        fee: &Fee<N>,
+        deployment_or_execution_id: Field<N>,
+        batch: Option<&ProofBatch<N>>,
+    ) -> Result<()> {
         let timer = timer!("VM::check_fee");
 
         // Ensure the fee does not exceed the limit.
@@ -213,7 +275,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         ensure!(*fee_amount < N::MAX_FEE, "Fee verification failed: fee exceeds the maximum limit");
 
         // Verify the fee.
-        let verification = self.process.read().verify_fee(fee, deployment_or_execution_id);
+        let verification = match batch {
+            Some(batch) => self.process.read().verify_fee_deferred(fee, deployment_or_execution_id, batch),
+            None => self.process.read().verify_fee(fee, deployment_or_execution_id),
+        };
         lap!(timer, "Verify the fee");
 
         // TODO (howardwu): This check is technically insufficient. Consider moving this upstream
@@ -262,6 +327,8 @@ mod tests {
         types::Field,
     };
     use ledger_block::{Block, Header, Metadata, Transaction};
+    use synthesizer_process::TransitionVerifierInputs;
+    use synthesizer_snark::VerifyingKey;
 
     type CurrentNetwork = test_helpers::CurrentNetwork;
 
@@ -328,14 +395,14 @@ mod tests {
                     // Ensure the proof exists.
                     assert!(execution.proof().is_some());
                     // Verify the execution.
-                    assert!(vm.check_execution(&execution).is_ok());
+                    assert!(vm.check_execution(&execution, None).is_ok());
                     assert!(vm.verify_execution(&execution));
 
                     // Ensure that deserialization doesn't break the transaction verification.
                     let serialized_execution = execution.to_string();
                     let recovered_execution: Execution<CurrentNetwork> =
                         serde_json::from_str(&serialized_execution).unwrap();
-                    assert!(vm.check_execution(&recovered_execution).is_ok());
+                    assert!(vm.check_execution(&recovered_execution, None).is_ok());
                     assert!(vm.verify_execution(&recovered_execution));
                 }
                 _ => panic!("Expected an execution transaction"),
@@ -343,6 +410,52 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_transition_verifier_inputs() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Fetch an execution that spends a record, which is batched with its inclusion proof.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction_without_fee(rng);
+        let Transaction::Execute(_, execution, _) = transaction else { panic!("Expected an execution transaction") };
+        let process = vm.process();
+        let process = process.read();
+        // Ensure the execution verifies.
+        process.verify_execution(&execution).unwrap();
+
+        // Retrieve the verifier inputs of the transition.
+        let transition = execution.peek().unwrap();
+        let verifying_key = process.get_verifying_key("credits.aleo", "split").unwrap();
+        let verifier_inputs =
+            process.get_transition_verifier_inputs(&execution, transition.id(), &verifying_key).unwrap();
+        // Ensure the batch contains the transition and the inclusion circuit.
+        assert_eq!(verifier_inputs.batch.len(), 2);
+        let (circuit_index, instance_index) = (verifier_inputs.circuit_index, verifier_inputs.instance_index);
+        assert_eq!(verifier_inputs.batch[circuit_index].0, verifying_key);
+        assert_eq!(verifier_inputs.batch[circuit_index].1[instance_index], verifier_inputs.inputs);
+
+        // Ensure the execution proof verifies with the batch, as in `Process::verify_execution`.
+        let proof = execution.proof().unwrap();
+        assert!(VerifyingKey::verify_batch("credits.aleo/split", verifier_inputs.batch.clone(), proof));
+        // Ensure the serialized verifier inputs are sufficient to verify the execution proof.
+        let bytes = process.get_transition_verifier_inputs_bytes(&execution, transition.id(), &verifying_key).unwrap();
+        let candidate = TransitionVerifierInputs::<CurrentNetwork>::from_bytes_le(&bytes).unwrap();
+        assert_eq!(candidate, verifier_inputs);
+        assert!(VerifyingKey::verify_batch("credits.aleo/split", candidate.batch, proof));
+
+        // Ensure the execution proof does not verify without the inclusion circuit.
+        let mut batch = verifier_inputs.batch.clone();
+        batch.retain(|(key, _)| *key == verifying_key);
+        assert!(!VerifyingKey::verify_batch("credits.aleo/split", batch, proof));
+        // Ensure the execution proof does not verify with a modified input of the transition.
+        let mut batch = verifier_inputs.batch;
+        batch[circuit_index].1[instance_index].swap(1, 2);
+        assert!(!VerifyingKey::verify_batch("credits.aleo/split", batch, proof));
+        // Ensure the verifying key of another function is rejected.
+        let other_verifying_key = process.get_verifying_key("credits.aleo", "transfer_public").unwrap();
+        assert!(process.get_transition_verifier_inputs(&execution, transition.id(), &other_verifying_key).is_err());
+    }
+
     #[test]
     fn test_verify_fee() {
         let rng = &mut TestRng::default();
@@ -362,13 +475,13 @@ mod tests {
                     // Ensure the proof exists.
                     assert!(fee.proof().is_some());
                     // Verify the fee.
-                    assert!(vm.check_fee(&fee, execution_id).is_ok());
+                    assert!(vm.check_fee(&fee, execution_id, None).is_ok());
                     assert!(vm.verify_fee(&fee, execution_id));
 
                     // Ensure that deserialization doesn't break the transaction verification.
                     let serialized_fee = fee.to_string();
                     let recovered_fee: Fee<CurrentNetwork> = serde_json::from_str(&serialized_fee).unwrap();
-                    assert!(vm.check_fee(&recovered_fee, execution_id).is_ok());
+                    assert!(vm.check_fee(&recovered_fee, execution_id, None).is_ok());
                     assert!(vm.verify_fee(&recovered_fee, execution_id));
                 }
                 _ => panic!("Expected an execution with a fee"),
@@ -403,6 +516,47 @@ mod tests {
         assert!(vm.verify_transaction(&valid_transaction, None));
     }
 
+    #[test]
+    fn test_check_transactions_batch() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Fetch the execution transactions.
+        let transactions = vec![
+            crate::vm::test_helpers::sample_execution_transaction_with_private_fee(rng),
+            crate::vm::test_helpers::sample_execution_transaction_with_public_fee(rng),
+            crate::vm::test_helpers::sample_execution_transaction_without_fee(rng),
+        ];
+        // Ensure the transactions verify together.
+        let batch = transactions.iter().map(|transaction| (transaction, None)).collect::<Vec<_>>();
+        assert!(vm.check_transactions_batch(&batch).is_ok());
+        assert!(vm.check_transactions_batch(&[]).is_ok());
+
+        // Construct an execution with the proof of another execution.
+        let execution = transactions[2].execution().unwrap();
+        let invalid_execution = Execution::from(
+            execution.transitions().cloned(),
+            execution.global_state_root(),
+            transactions[0].execution().unwrap().proof().cloned(),
+        )
+        .unwrap();
+        let invalid_transaction = Transaction::from_execution(invalid_execution, None).unwrap();
+        // Ensure the batch fails, for the invalid transaction alone or with the valid transactions.
+        assert!(vm.check_transaction(&invalid_transaction, None).is_err());
+        assert!(vm.check_transactions_batch(&[(&invalid_transaction, None)]).is_err());
+        assert!(vm.check_transactions_batch(&[(&transactions[0], None), (&invalid_transaction, None)]).is_err());
+
+        // Fetch a deployment transaction, and construct it with an owner signature for another deployment.
+        let transaction = crate::vm::test_helpers::sample_deployment_transaction(rng);
+        let Transaction::Deploy(id, _, deployment, fee) = &transaction else { unreachable!() };
+        let owner = ProgramOwner::new(&PrivateKey::new(rng).unwrap(), Field::rand(rng), rng).unwrap();
+        let invalid_transaction = Transaction::Deploy(*id, owner, deployment.clone(), fee.clone());
+        // Ensure the owner signatures are verified in the batch.
+        assert!(vm.check_transactions_batch(&[(&transaction, None), (&transactions[0], None)]).is_ok());
+        let error = vm.check_transactions_batch(&[(&transactions[0], None), (&invalid_transaction, None)]).unwrap_err();
+        assert!(error.to_string().contains("Invalid owner signature"), "{error}");
+    }
+
     #[test]
     fn test_verify_deploy_and_execute() {
         // Initialize the RNG.
@@ -20,6 +20,7 @@ use crate::{
     FromBytes,
     ToBits,
     ToBytes,
+    Vec,
 };
 
 use anyhow::Result;
@@ -108,7 +109,7 @@ impl crate::biginteger::BigInteger for BigInteger256 {
         while n >= 64 {
             let mut t = 0;
             for i in &mut self.0 {
-                std::mem::swap(&mut t, i);
+                core::mem::swap(&mut t, i);
             }
             n -= 64;
         }
@@ -143,7 +144,7 @@ impl crate::biginteger::BigInteger for BigInteger256 {
         while n >= 64 {
             let mut t = 0;
             for i in self.0.iter_mut().rev() {
-                std::mem::swap(&mut t, i);
+                core::mem::swap(&mut t, i);
             }
             n -= 64;
         }
@@ -281,7 +282,7 @@ impl FromBytes for BigInteger256 {
 }
 
 impl Debug for BigInteger256 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         for i in self.0.iter().rev() {
             write!(f, "{:016X}", *i)?;
         }
@@ -290,7 +291,7 @@ impl Debug for BigInteger256 {
 }
 
 impl Display for BigInteger256 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.to_biguint())
     }
 }
@@ -298,21 +299,21 @@ impl Display for BigInteger256 {
 impl Ord for BigInteger256 {
     #[inline]
     #[allow(clippy::comparison_chain)]
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         for (a, b) in self.0.iter().rev().zip(other.0.iter().rev()) {
             if a < b {
-                return std::cmp::Ordering::Less;
+                return core::cmp::Ordering::Less;
             } else if a > b {
-                return std::cmp::Ordering::Greater;
+                return core::cmp::Ordering::Greater;
             }
         }
-        std::cmp::Ordering::Equal
+        core::cmp::Ordering::Equal
     }
 }
 
 impl PartialOrd for BigInteger256 {
     #[inline]
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
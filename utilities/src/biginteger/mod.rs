@@ -12,10 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{rand::Uniform, FromBits, FromBytes, ToBits, ToBytes};
+use crate::{rand::Uniform, FromBits, FromBytes, ToBits, ToBytes, Vec};
 
+use core::fmt::{Debug, Display};
 use num_bigint::BigUint;
-use std::fmt::{Debug, Display};
 
 mod bigint_256;
 pub use bigint_256::*;
@@ -95,52 +95,116 @@ pub trait BigInteger:
     fn find_wnaf(&self) -> Vec<i64>;
 }
 
+/// The limb arithmetic of the big integers and prime fields.
+///
+/// By default, this is portable Rust over `u128`. With the `adx` feature, on an `x86_64` target
+/// compiled with the `adx` and `bmi2` target features (e.g. with `-C target-cpu=native`),
+/// the multiply-accumulate operations use the `MULX` and `ADCX` instructions instead.
+/// The backend is selected at compile time, and both backends return the same results.
 pub mod arithmetic {
-    /// set a = a + b + carry, and return the new carry value.
-    #[inline(always)]
-    pub fn adc(a: &mut u64, b: u64, carry: u64) -> u64 {
-        let tmp = u128::from(*a) + u128::from(b) + u128::from(carry);
-        *a = tmp as u64;
-        (tmp >> 64) as u64
+    pub use portable::{adc, sbb};
+
+    #[cfg(not(all(feature = "adx", target_arch = "x86_64", target_feature = "adx", target_feature = "bmi2")))]
+    pub use portable::{mac, mac_discard, mac_with_carry};
+
+    #[cfg(all(feature = "adx", target_arch = "x86_64", target_feature = "adx", target_feature = "bmi2"))]
+    pub use intrinsics::{mac, mac_discard, mac_with_carry};
+
+    /// The portable limb arithmetic.
+    pub mod portable {
+        /// set a = a + b + carry, and return the new carry value.
+        #[inline(always)]
+        pub fn adc(a: &mut u64, b: u64, carry: u64) -> u64 {
+            let tmp = u128::from(*a) + u128::from(b) + u128::from(carry);
+            *a = tmp as u64;
+            (tmp >> 64) as u64
+        }
+
+        /// set a = a - b - borrow, and return the new borrow value.
+        #[inline(always)]
+        pub fn sbb(a: &mut u64, b: u64, borrow: u64) -> u64 {
+            let tmp = (1u128 << 64) + u128::from(*a) - u128::from(b) - u128::from(borrow);
+            let carry = u64::from(tmp >> 64 == 0);
+            *a = tmp as u64;
+            carry
+        }
+
+        /// Calculate a + (b * c) + carry, returning the least significant digit
+        /// and setting carry to the most significant digit.
+        #[inline(always)]
+        pub fn mac_with_carry(a: u64, b: u64, c: u64, carry: &mut u64) -> u64 {
+            let tmp = (u128::from(a)) + u128::from(b) * u128::from(c) + u128::from(*carry);
+
+            *carry = (tmp >> 64) as u64;
+
+            tmp as u64
+        }
+
+        /// Calculate a + b * c, returning the lower 64 bits of the result and setting
+        /// `carry` to the upper 64 bits.
+        #[inline(always)]
+        pub fn mac(a: u64, b: u64, c: u64, carry: &mut u64) -> u64 {
+            let tmp = (u128::from(a)) + u128::from(b) * u128::from(c);
+
+            *carry = (tmp >> 64) as u64;
+
+            tmp as u64
+        }
+
+        /// Calculate a + b * c, discarding the lower 64 bits of the result and setting
+        /// `carry` to the upper 64 bits.
+        #[inline(always)]
+        pub fn mac_discard(a: u64, b: u64, c: u64, carry: &mut u64) {
+            let tmp = (u128::from(a)) + u128::from(b) * u128::from(c);
+
+            *carry = (tmp >> 64) as u64;
+        }
     }
 
-    /// set a = a - b - borrow, and return the new borrow value.
-    #[inline(always)]
-    pub fn sbb(a: &mut u64, b: u64, borrow: u64) -> u64 {
-        let tmp = (1u128 << 64) + u128::from(*a) - u128::from(b) - u128::from(borrow);
-        let carry = u64::from(tmp >> 64 == 0);
-        *a = tmp as u64;
-        carry
-    }
-
-    /// Calculate a + (b * c) + carry, returning the least significant digit
-    /// and setting carry to the most significant digit.
-    #[inline(always)]
-    pub fn mac_with_carry(a: u64, b: u64, c: u64, carry: &mut u64) -> u64 {
-        let tmp = (u128::from(a)) + u128::from(b) * u128::from(c) + u128::from(*carry);
-
-        *carry = (tmp >> 64) as u64;
-
-        tmp as u64
-    }
-
-    /// Calculate a + b * c, returning the lower 64 bits of the result and setting
-    /// `carry` to the upper 64 bits.
-    #[inline(always)]
-    pub fn mac(a: u64, b: u64, c: u64, carry: &mut u64) -> u64 {
-        let tmp = (u128::from(a)) + u128::from(b) * u128::from(c);
-
-        *carry = (tmp >> 64) as u64;
-
-        tmp as u64
-    }
-
-    /// Calculate a + b * c, discarding the lower 64 bits of the result and setting
-    /// `carry` to the upper 64 bits.
-    #[inline(always)]
-    pub fn mac_discard(a: u64, b: u64, c: u64, carry: &mut u64) {
-        let tmp = (u128::from(a)) + u128::from(b) * u128::from(c);
-
-        *carry = (tmp >> 64) as u64;
+    /// The limb arithmetic using the `x86_64` `MULX` and `ADCX` instructions.
+    #[cfg(all(feature = "adx", target_arch = "x86_64", target_feature = "adx", target_feature = "bmi2"))]
+    pub mod intrinsics {
+        use core::arch::x86_64::{_addcarryx_u64, _mulx_u64};
+
+        /// Calculate a + (b * c) + carry, returning the least significant digit
+        /// and setting carry to the most significant digit.
+        #[inline(always)]
+        pub fn mac_with_carry(a: u64, b: u64, c: u64, carry: &mut u64) -> u64 {
+            let mut hi = 0u64;
+            let mut lo = 0u64;
+            // SAFETY: The `adx` and `bmi2` target features are enabled at compile time.
+            let (c1, c2) = unsafe {
+                let product = _mulx_u64(b, c, &mut hi);
+                let c1 = _addcarryx_u64(0, product, a, &mut lo);
+                let c2 = _addcarryx_u64(0, lo, *carry, &mut lo);
+                (c1, c2)
+            };
+            // This cannot overflow, as a + (b * c) + carry < 2^128.
+            *carry = hi + u64::from(c1) + u64::from(c2);
+            lo
+        }
+
+        /// Calculate a + b * c, returning the lower 64 bits of the result and setting
+        /// `carry` to the upper 64 bits.
+        #[inline(always)]
+        pub fn mac(a: u64, b: u64, c: u64, carry: &mut u64) -> u64 {
+            let mut hi = 0u64;
+            let mut lo = 0u64;
+            // SAFETY: The `adx` and `bmi2` target features are enabled at compile time.
+            let c1 = unsafe {
+                let product = _mulx_u64(b, c, &mut hi);
+                _addcarryx_u64(0, product, a, &mut lo)
+            };
+            // This cannot overflow, as a + (b * c) < 2^128.
+            *carry = hi + u64::from(c1);
+            lo
+        }
+
+        /// Calculate a + b * c, discarding the lower 64 bits of the result and setting
+        /// `carry` to the upper 64 bits.
+        #[inline(always)]
+        pub fn mac_discard(a: u64, b: u64, c: u64, carry: &mut u64) {
+            let _ = mac(a, b, c, carry);
+        }
     }
 }
@@ -124,3 +124,34 @@ fn test_biginteger256() {
 fn test_biginteger384() {
     test_biginteger(BigInteger384::new([0u64; 6]));
 }
+
+#[test]
+fn test_arithmetic_backend() {
+    let mut rng = TestRng::default();
+
+    // Sample random limbs, and limbs at the edges of the range.
+    let edge_cases = [0u64, 1, u64::MAX - 1, u64::MAX];
+    let samples = edge_cases.into_iter().chain((0..64).map(|_| u64::rand(&mut rng))).collect::<Vec<_>>();
+
+    // Ensure the selected backend matches the portable backend.
+    for &a in &samples {
+        for &b in &samples {
+            for &c in edge_cases.iter().chain(&samples[..8]) {
+                let (mut expected_carry, mut candidate_carry) = (c, c);
+                let expected = arithmetic::portable::mac_with_carry(a, b, c, &mut expected_carry);
+                let candidate = arithmetic::mac_with_carry(a, b, c, &mut candidate_carry);
+                assert_eq!((expected, expected_carry), (candidate, candidate_carry));
+
+                let (mut expected_carry, mut candidate_carry) = (0, 0);
+                let expected = arithmetic::portable::mac(a, b, c, &mut expected_carry);
+                let candidate = arithmetic::mac(a, b, c, &mut candidate_carry);
+                assert_eq!((expected, expected_carry), (candidate, candidate_carry));
+
+                let (mut expected_carry, mut candidate_carry) = (0, 0);
+                arithmetic::portable::mac_discard(a, b, c, &mut expected_carry);
+                arithmetic::mac_discard(a, b, c, &mut candidate_carry);
+                assert_eq!(expected_carry, candidate_carry);
+            }
+        }
+    }
+}
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::iter::ExactSizeIterator;
+use core::iter::ExactSizeIterator;
 
 /// Iterates over a slice of `u64` in *big-endian* order.
 #[derive(Debug)]
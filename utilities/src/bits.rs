@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::Vec;
+use crate::{string::String, Vec};
 
 use anyhow::{ensure, Result};
 
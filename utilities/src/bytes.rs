@@ -17,6 +17,7 @@ use crate::{
     fmt,
     io::{Read, Result as IoResult, Write},
     marker::PhantomData,
+    string::{String, ToString},
     Vec,
 };
 use serde::{
@@ -76,6 +77,17 @@ pub trait FromBytes {
     {
         Ok(Self::read_le(bytes)?)
     }
+
+    /// Returns `Self` from the front of a byte array in little-endian order,
+    /// along with the remaining bytes, which are borrowed without copying.
+    fn from_bytes_le_prefix(bytes: &[u8]) -> anyhow::Result<(Self, &[u8])>
+    where
+        Self: Sized,
+    {
+        let mut remaining = bytes;
+        let value = Self::read_le(&mut remaining)?;
+        Ok((value, remaining))
+    }
 }
 
 pub struct ToBytesSerializer<T: ToBytes>(String, Option<usize>, PhantomData<T>);
@@ -19,6 +19,12 @@ use core::{cmp, mem};
 #[derive(Debug)]
 pub struct Error;
 
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "I/O error")
+    }
+}
+
 pub type Result<T> = core::result::Result<T, Error>;
 
 pub trait Read {
@@ -12,14 +12,28 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::{collections::HashSet, hash::Hash};
+use core::hash::Hash;
 
 /// Returns true if the given iterator has duplicate elements.
+#[cfg(feature = "std")]
 pub fn has_duplicates<T>(iter: T) -> bool
 where
     T: IntoIterator,
     T::Item: Eq + Hash,
 {
-    let mut uniq = HashSet::new();
+    let mut uniq = std::collections::HashSet::new();
     !iter.into_iter().all(move |x| uniq.insert(x))
 }
+
+/// Returns true if the given iterator has duplicate elements.
+///
+/// Without `std` there is no hash set, so each element is compared against the ones before it.
+#[cfg(not(feature = "std"))]
+pub fn has_duplicates<T>(iter: T) -> bool
+where
+    T: IntoIterator,
+    T::Item: Eq + Hash,
+{
+    let items = iter.into_iter().collect::<crate::Vec<_>>();
+    items.iter().enumerate().any(|(index, item)| items[..index].contains(item))
+}
@@ -18,12 +18,21 @@
 #[macro_use]
 extern crate std;
 
+#[cfg(all(not(feature = "std"), not(feature = "serial")))]
+compile_error!("Building without the `std` feature requires the `serial` feature");
+
 #[cfg(not(feature = "std"))]
-pub use alloc::*;
+#[allow(hidden_glob_reexports)]
+#[macro_use]
+extern crate alloc;
 
 #[cfg(not(feature = "std"))]
 pub use core::*;
 
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+pub use alloc::{borrow, boxed, collections, fmt, format, rc, slice, str, string, sync, vec};
+
 #[cfg(feature = "std")]
 #[doc(hidden)]
 pub use std::*;
@@ -38,9 +47,6 @@ pub use alloc::{boxed::Box, vec::Vec};
 #[doc(hidden)]
 pub use std::{boxed::Box, vec::Vec};
 
-#[macro_use]
-extern crate thiserror;
-
 pub mod biginteger;
 pub use biginteger::*;
 
@@ -75,7 +81,7 @@ pub use serialize::*;
 pub mod io;
 
 #[cfg(not(feature = "std"))]
-pub fn error(_msg: &'static str) -> io::Error {
+pub fn error<S: string::ToString>(_msg: S) -> io::Error {
     io::Error
 }
 
@@ -45,6 +45,24 @@ impl<'a, T> ExecutionPool<'a, T> {
             self.jobs.into_iter().map(|f| f()).collect()
         }
     }
+
+    /// Executes the jobs in order, running at most `chunk_size` of them concurrently.
+    /// This bounds the memory held by in-flight jobs, at the cost of parallelism.
+    pub fn execute_in_chunks(self, chunk_size: usize) -> Vec<T>
+    where
+        T: Send + Sync,
+    {
+        let mut results = Vec::with_capacity(self.jobs.len());
+        let mut jobs = self.jobs.into_iter();
+        loop {
+            let chunk = jobs.by_ref().take(chunk_size.max(1)).collect::<Vec<_>>();
+            if chunk.is_empty() {
+                break;
+            }
+            results.extend(Self { jobs: chunk }.execute_all());
+        }
+        results
+    }
 }
 
 impl<'a, T> Default for ExecutionPool<'a, T> {
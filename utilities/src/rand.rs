@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::string::String;
+
+#[cfg(feature = "std")]
+use rand::rngs::StdRng;
 use rand::{
     distributions::{Distribution, Standard},
-    rngs::StdRng,
     Rng,
     SeedableRng,
 };
@@ -39,10 +42,13 @@ where
 /// A fast RNG used **solely** for testing and benchmarking, **not** for any real world purposes.
 pub struct TestRng(XorShiftRng);
 
+#[cfg(feature = "std")]
 impl Default for TestRng {
     fn default() -> Self {
         // Obtain the initial seed using entropy provided by the OS.
         let seed = StdRng::from_entropy().gen();
+        // Print the seed, so it's displayed if any of the tests using `test_rng` fails.
+        println!("\nInitializing 'TestRng' with seed '{seed}'\n");
 
         // Use it as the basis for the underlying Rng.
         Self::fixed(seed)
@@ -51,9 +57,6 @@ impl Default for TestRng {
 
 impl TestRng {
     pub fn fixed(seed: u64) -> Self {
-        // Print the seed, so it's displayed if any of the tests using `test_rng` fails.
-        println!("\nInitializing 'TestRng' with seed '{seed}'\n");
-
         // Use the seed to initialize a fast, non-cryptographic Rng.
         Self(XorShiftRng::seed_from_u64(seed))
     }
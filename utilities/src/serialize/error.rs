@@ -12,36 +12,74 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-#[derive(Error, Debug)]
+#[derive(Debug)]
 pub enum SerializationError {
-    #[error("{}", _0)]
-    AnyhowError(#[from] anyhow::Error),
+    AnyhowError(anyhow::Error),
     /// During serialization with bincode, we encountered a serialization issue
-    #[error(transparent)]
-    BincodeError(#[from] bincode::Error),
+    #[cfg(feature = "std")]
+    BincodeError(bincode::Error),
     /// During serialization we could not serialize to the right sized int
-    #[error(transparent)]
-    IntError(#[from] std::num::TryFromIntError),
+    IntError(core::num::TryFromIntError),
     /// During serialization, the data was invalid.
-    #[error("the input buffer contained invalid data")]
     InvalidData,
     /// During serialization, we countered an I/O error.
-    #[error("IoError: {0}")]
-    IoError(#[from] crate::io::Error),
+    IoError(crate::io::Error),
     /// During serialization, we didn't have enough space to write extra info.
-    #[error("the last byte does not have enough space to encode the extra info bits")]
     NotEnoughSpace,
     /// During serialization, non-empty flags were given where none were
     /// expected.
-    #[error("the call expects empty flags")]
     UnexpectedFlags,
     /// During serialization, the target was found to be incompatible
-    #[error("the value was serialized on a target that is incompatible with the current target")]
     IncompatibleTarget,
 }
 
+impl core::fmt::Display for SerializationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::AnyhowError(error) => write!(f, "{error}"),
+            #[cfg(feature = "std")]
+            Self::BincodeError(error) => write!(f, "{error}"),
+            Self::IntError(error) => write!(f, "{error}"),
+            Self::InvalidData => write!(f, "the input buffer contained invalid data"),
+            Self::IoError(error) => write!(f, "IoError: {error}"),
+            Self::NotEnoughSpace => write!(f, "the last byte does not have enough space to encode the extra info bits"),
+            Self::UnexpectedFlags => write!(f, "the call expects empty flags"),
+            Self::IncompatibleTarget => {
+                write!(f, "the value was serialized on a target that is incompatible with the current target")
+            }
+        }
+    }
+}
+
+impl crate::error::Error for SerializationError {}
+
+impl From<anyhow::Error> for SerializationError {
+    fn from(error: anyhow::Error) -> Self {
+        Self::AnyhowError(error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<bincode::Error> for SerializationError {
+    fn from(error: bincode::Error) -> Self {
+        Self::BincodeError(error)
+    }
+}
+
+impl From<core::num::TryFromIntError> for SerializationError {
+    fn from(error: core::num::TryFromIntError) -> Self {
+        Self::IntError(error)
+    }
+}
+
+impl From<crate::io::Error> for SerializationError {
+    fn from(error: crate::io::Error) -> Self {
+        Self::IoError(error)
+    }
+}
+
 impl From<SerializationError> for crate::io::Error {
     fn from(error: SerializationError) -> Self {
-        crate::io::Error::new(crate::io::ErrorKind::Other, format!("{error}"))
+        crate::error(format!("{error}"))
     }
 }
@@ -12,18 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::{
+    borrow::{Cow, ToOwned},
+    collections::BTreeMap,
+    marker::PhantomData,
+    rc::Rc,
+    serialize::traits::*,
+    sync::Arc,
+    SerializationError,
+};
 pub use crate::{
     io::{self, Read, Write},
     FromBytes,
     ToBytes,
     Vec,
 };
-use crate::{serialize::traits::*, SerializationError};
 
+#[cfg(feature = "std")]
 use bincode::Options;
 
-use std::{borrow::Cow, collections::BTreeMap, marker::PhantomData, rc::Rc, sync::Arc};
-
 impl Valid for bool {
     fn check(&self) -> Result<(), SerializationError> {
         Ok(())
@@ -53,6 +60,7 @@ impl CanonicalDeserialize for bool {
     }
 }
 
+#[cfg(feature = "std")]
 impl CanonicalSerialize for String {
     #[inline]
     fn serialize_with_mode<W: Write>(&self, mut writer: W, _compress: Compress) -> Result<(), SerializationError> {
@@ -65,6 +73,7 @@ impl CanonicalSerialize for String {
     }
 }
 
+#[cfg(feature = "std")]
 impl Valid for String {
     #[inline]
     fn check(&self) -> Result<(), SerializationError> {
@@ -80,6 +89,7 @@ impl Valid for String {
     }
 }
 
+#[cfg(feature = "std")]
 impl CanonicalDeserialize for String {
     #[inline]
     fn deserialize_with_mode<R: Read>(
@@ -109,7 +119,7 @@ macro_rules! impl_canonical_serialization_uint {
 
             #[inline]
             fn serialized_size(&self, _compress: Compress) -> usize {
-                std::mem::size_of::<$type>()
+                core::mem::size_of::<$type>()
             }
         }
         impl Valid for $type {
@@ -134,7 +144,7 @@ macro_rules! impl_canonical_serialization_uint {
                 _compress: Compress,
                 _validate: Validate,
             ) -> Result<Self, SerializationError> {
-                let mut bytes = [0u8; std::mem::size_of::<$type>()];
+                let mut bytes = [0u8; core::mem::size_of::<$type>()];
                 reader.read_exact(&mut bytes)?;
                 Ok(<$type>::from_le_bytes(bytes))
             }
@@ -237,7 +247,7 @@ impl<T: CanonicalDeserialize> CanonicalDeserialize for Option<T> {
 }
 
 // No-op
-impl<T> CanonicalSerialize for std::marker::PhantomData<T> {
+impl<T> CanonicalSerialize for PhantomData<T> {
     #[inline]
     fn serialize_with_mode<W: Write>(&self, _writer: W, _compress: Compress) -> Result<(), SerializationError> {
         Ok(())
@@ -256,14 +266,14 @@ impl<T: Sync> Valid for PhantomData<T> {
     }
 }
 
-impl<T: Send + Sync> CanonicalDeserialize for std::marker::PhantomData<T> {
+impl<T: Send + Sync> CanonicalDeserialize for PhantomData<T> {
     #[inline]
     fn deserialize_with_mode<R: Read>(
         _reader: R,
         _compress: Compress,
         _validate: Validate,
     ) -> Result<Self, SerializationError> {
-        Ok(std::marker::PhantomData)
+        Ok(PhantomData)
     }
 }
 
@@ -325,7 +335,6 @@ impl<T: Valid + Sync + Send> Valid for Arc<T> {
     }
 
     #[inline]
-
     fn batch_check<'a>(batch: impl Iterator<Item = &'a Self> + Send) -> Result<(), SerializationError>
     where
         Self: 'a,
@@ -435,7 +444,7 @@ impl<T: CanonicalDeserialize> CanonicalDeserialize for Vec<T> {
     }
 }
 
-impl<T: CanonicalDeserialize + std::fmt::Debug> CanonicalDeserialize for [T; 32] {
+impl<T: CanonicalDeserialize + core::fmt::Debug> CanonicalDeserialize for [T; 32] {
     #[inline]
     fn deserialize_with_mode<R: Read>(
         mut reader: R,
@@ -632,7 +641,7 @@ mod test {
     use super::*;
     use crate::{deserialize_vec_without_len, serialize_vec_without_len, serialized_vec_size_without_len};
 
-    fn test_serialize<T: PartialEq + std::fmt::Debug + CanonicalSerialize + CanonicalDeserialize>(data: T) {
+    fn test_serialize<T: PartialEq + core::fmt::Debug + CanonicalSerialize + CanonicalDeserialize>(data: T) {
         let combinations = [
             (Compress::No, Validate::No),
             (Compress::Yes, Validate::No),
@@ -647,7 +656,7 @@ mod test {
         }
     }
 
-    fn test_serialize_without_len<T: PartialEq + std::fmt::Debug + CanonicalSerialize + CanonicalDeserialize>(
+    fn test_serialize_without_len<T: PartialEq + core::fmt::Debug + CanonicalSerialize + CanonicalDeserialize>(
         data: Vec<T>,
     ) {
         let combinations = [
@@ -716,6 +725,6 @@ mod test {
 
     #[test]
     fn test_phantomdata() {
-        test_serialize(std::marker::PhantomData::<u64>);
+        test_serialize(PhantomData::<u64>);
     }
 }
@@ -0,0 +1,128 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The stable API of snarkVM, for wallets and nodes.
+//!
+//! The items in this module are re-exported by name from the internal crates, and follow semantic versioning:
+//! an item is not removed, renamed, or moved out of this module, and its signature does not change,
+//! without a major version bump (or a minor version bump, while the major version is `0`).
+//! Deprecated items remain available for at least one such release.
+//!
+//! The internal crates (e.g. `snarkvm::console`, `snarkvm::ledger`, `snarkvm::synthesizer`) and the
+//! `snarkvm::prelude` are reorganized more frequently, and carry no such guarantees.
+//!
+//! ```ignore
+//! use snarkvm::api::prelude::*;
+//! ```
+
+/// The account types, to derive keys and addresses, and to sign and verify messages.
+#[cfg(feature = "console")]
+pub mod account {
+    pub use crate::console::account::{Address, ComputeKey, GraphKey, PrivateKey, Signature, ViewKey};
+}
+
+/// The network types.
+#[cfg(feature = "console")]
+pub mod network {
+    pub use crate::console::network::{ConsensusVersion, Network, Testnet3};
+}
+
+/// The program types, to parse programs and to construct their inputs and outputs.
+#[cfg(feature = "console")]
+pub mod program {
+    pub use crate::console::program::{
+        Ciphertext,
+        Identifier,
+        Literal,
+        Locator,
+        Plaintext,
+        ProgramID,
+        Record,
+        Request,
+        Response,
+        StatePath,
+        Value,
+    };
+    #[cfg(feature = "synthesizer")]
+    pub use crate::synthesizer::Program;
+}
+
+/// The VM, to authorize, execute, and verify transactions.
+#[cfg(feature = "synthesizer")]
+pub mod vm {
+    pub use crate::synthesizer::{Authorization, Process, VM};
+}
+
+/// The ledger types, to query blocks and transactions, and to construct and store the ledger.
+#[cfg(feature = "ledger")]
+pub mod ledger {
+    #[cfg(feature = "rocks")]
+    pub use crate::ledger::store::helpers::rocksdb::ConsensusDB;
+    pub use crate::ledger::{
+        block::{
+            Block,
+            ConfirmedTransaction,
+            Deployment,
+            Execution,
+            Fee,
+            Header,
+            Receipt,
+            RejectionReason,
+            Transaction,
+            Transactions,
+            Transition,
+        },
+        query::{Query, QueryTrait, StaticQuery},
+        store::{helpers::memory::ConsensusMemory, ConsensusStorage, ConsensusStore},
+        Ledger,
+    };
+}
+
+/// The stable API, in one import.
+pub mod prelude {
+    #[cfg(feature = "ledger")]
+    pub use super::ledger::*;
+    #[cfg(feature = "synthesizer")]
+    pub use super::vm::*;
+    #[cfg(feature = "console")]
+    pub use super::{account::*, network::*, program::*};
+}
+
+#[cfg(all(test, feature = "console"))]
+mod tests {
+    use super::prelude::*;
+    use crate::console::prelude::TestRng;
+
+    use core::str::FromStr;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_account() {
+        let rng = &mut TestRng::default();
+
+        // Ensure an account can be derived and used to sign through the stable API.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let address = Address::try_from(&private_key).unwrap();
+        assert_eq!(view_key.to_address(), address);
+
+        let signature = Signature::sign_bytes(&private_key, b"message", rng).unwrap();
+        assert!(signature.verify_bytes(&address, b"message"));
+
+        // Ensure a program can be parsed through the stable API.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("credits.aleo").unwrap();
+        assert_eq!(program_id.to_string(), "credits.aleo");
+    }
+}
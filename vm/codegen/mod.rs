@@ -0,0 +1,464 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rust client code generation for Aleo programs.
+//!
+//! [`generate`] turns a program into a Rust module with one strongly typed struct per program struct,
+//! and one call builder per function. The call builders return a [`Call`], which executes the function
+//! through [`VM::execute`]. The generated module is meant to be written from a build script and
+//! included with `include!`, e.g. `mod token { include!(concat!(env!("OUT_DIR"), "/token.rs")); }`.
+
+use crate::{
+    console::{
+        account::{PrivateKey, Signature},
+        network::Network,
+        prelude::*,
+        program::{
+            Identifier,
+            Literal,
+            LiteralType,
+            Plaintext,
+            PlaintextType,
+            ProgramID,
+            Record,
+            StructType,
+            Value,
+            ValueType,
+        },
+        types::{Address, Boolean, Field, Group, Scalar, StringType, I128, I16, I32, I64, I8, U128, U16, U32, U64, U8},
+    },
+    ledger::{block::Transaction, query::Query, store::ConsensusStorage},
+    synthesizer::{Program, VM},
+};
+
+use indexmap::IndexMap;
+use std::{collections::HashSet, fmt::Write as _};
+
+/// The Rust names the generated module uses, which a generated struct must not shadow.
+const RESERVED_TYPE_NAMES: &[&str] = &[
+    "Self",
+    "N",
+    "Address",
+    "Boolean",
+    "Call",
+    "Field",
+    "Group",
+    "I8",
+    "I16",
+    "I32",
+    "I64",
+    "I128",
+    "IntoPlaintext",
+    "Network",
+    "Plaintext",
+    "Record",
+    "Result",
+    "Scalar",
+    "Signature",
+    "StringType",
+    "U8",
+    "U16",
+    "U32",
+    "U64",
+    "U128",
+    "Value",
+];
+/// The Rust names the generated module imports, which a generated function must not shadow.
+const RESERVED_FUNCTION_NAMES: &[&str] = &["to_struct"];
+
+/// A call to a program function, with its inputs already converted into values.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Call<N: Network> {
+    /// The ID of the program.
+    program_id: ProgramID<N>,
+    /// The name of the function.
+    function_name: Identifier<N>,
+    /// The inputs of the function.
+    inputs: Vec<Value<N>>,
+}
+
+impl<N: Network> Call<N> {
+    /// Initializes a new call to `program_id/function_name` with the given inputs.
+    pub fn new(program_id: &str, function_name: &str, inputs: Vec<Value<N>>) -> Result<Self> {
+        Ok(Self {
+            program_id: ProgramID::from_str(program_id)?,
+            function_name: Identifier::from_str(function_name)?,
+            inputs,
+        })
+    }
+
+    /// Returns the ID of the program.
+    pub const fn program_id(&self) -> &ProgramID<N> {
+        &self.program_id
+    }
+
+    /// Returns the name of the function.
+    pub const fn function_name(&self) -> &Identifier<N> {
+        &self.function_name
+    }
+
+    /// Returns the inputs of the function.
+    pub fn inputs(&self) -> &[Value<N>] {
+        &self.inputs
+    }
+
+    /// Returns a new execute transaction for this call, as in [`VM::execute`].
+    pub fn execute<C: ConsensusStorage<N>, R: Rng + CryptoRng>(
+        &self,
+        vm: &VM<N, C>,
+        private_key: &PrivateKey<N>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        vm.execute(
+            private_key,
+            (self.program_id, self.function_name),
+            self.inputs.iter(),
+            fee_record,
+            priority_fee_in_microcredits,
+            query,
+            rng,
+        )
+    }
+}
+
+/// A Rust value that converts into a plaintext of the program.
+pub trait IntoPlaintext<N: Network> {
+    /// Returns the plaintext of this value.
+    fn into_plaintext(self) -> Result<Plaintext<N>>;
+}
+
+impl<N: Network> IntoPlaintext<N> for Plaintext<N> {
+    fn into_plaintext(self) -> Result<Plaintext<N>> {
+        Ok(self)
+    }
+}
+
+impl<N: Network, T: IntoPlaintext<N>, const LENGTH: usize> IntoPlaintext<N> for [T; LENGTH] {
+    fn into_plaintext(self) -> Result<Plaintext<N>> {
+        let elements = self.into_iter().map(IntoPlaintext::into_plaintext).collect::<Result<Vec<_>>>()?;
+        Ok(Plaintext::Array(elements, Default::default()))
+    }
+}
+
+macro_rules! impl_into_plaintext {
+    ($($type_:ident => $variant:ident),*) => {
+        $(impl<N: Network> IntoPlaintext<N> for $type_<N> {
+            fn into_plaintext(self) -> Result<Plaintext<N>> {
+                Ok(Plaintext::from(Literal::$variant(self.into())))
+            }
+        })*
+    };
+}
+
+impl_into_plaintext!(
+    Address => Address, Boolean => Boolean, Field => Field, Group => Group,
+    I8 => I8, I16 => I16, I32 => I32, I64 => I64, I128 => I128,
+    U8 => U8, U16 => U16, U32 => U32, U64 => U64, U128 => U128,
+    Scalar => Scalar, Signature => Signature, StringType => String
+);
+
+/// Returns a struct plaintext with the given members, in declaration order.
+pub fn to_struct<N: Network>(members: Vec<(&str, Plaintext<N>)>) -> Result<Plaintext<N>> {
+    let members = members
+        .into_iter()
+        .map(|(name, plaintext)| Ok((Identifier::from_str(name)?, plaintext)))
+        .collect::<Result<IndexMap<_, _>>>()?;
+    Ok(Plaintext::Struct(members, Default::default()))
+}
+
+/// Returns the Rust client module for the given program source.
+pub fn generate_from_str<N: Network>(source: &str) -> Result<String> {
+    generate(&Program::<N>::from_str(source)?)
+}
+
+/// Returns the Rust client module for the given program.
+pub fn generate<N: Network>(program: &Program<N>) -> Result<String> {
+    let manifest = program.manifest();
+    let mut output = String::new();
+
+    writeln!(output, "// Generated by `snarkvm::codegen` from `{}`. Do not edit.", manifest.program_id())?;
+    writeln!(output)?;
+    writeln!(output, "#[allow(unused_imports)]")?;
+    writeln!(output, "use snarkvm::codegen::{{to_struct, Call, IntoPlaintext}};")?;
+    writeln!(output, "#[allow(unused_imports)]")?;
+    writeln!(
+        output,
+        "use snarkvm::console::{{account::Signature, network::Network, prelude::Result, program::{{Plaintext, Record, Value}}, types::*}};"
+    )?;
+    writeln!(output)?;
+    writeln!(output, "/// The ID of the program.")?;
+    writeln!(output, "pub const PROGRAM_ID: &str = \"{}\";", manifest.program_id())?;
+
+    // Ensure the struct names are unique once converted, as e.g. `token_info` and `tokenInfo` both become `TokenInfo`.
+    let mut type_names = HashSet::new();
+    for name in manifest.structs().keys() {
+        let type_name = to_rust_type_name(&name.to_string())?;
+        ensure!(type_names.insert(type_name.clone()), "Struct '{name}' collides with another struct as '{type_name}'");
+    }
+
+    for (name, struct_) in manifest.structs() {
+        writeln!(output)?;
+        write_struct(&mut output, name, struct_)?;
+    }
+
+    for (name, function) in manifest.functions() {
+        let function_name = to_rust_ident(&name.to_string());
+        ensure!(
+            !RESERVED_FUNCTION_NAMES.contains(&function_name.as_str()),
+            "Function '{name}' collides with a name used by the generated code"
+        );
+        let mut parameters = Vec::with_capacity(function.inputs().len());
+        let mut inputs = Vec::with_capacity(function.inputs().len());
+        for input in function.inputs() {
+            let parameter = to_rust_ident(input.name());
+            match input.value_type() {
+                ValueType::Constant(plaintext_type)
+                | ValueType::Public(plaintext_type)
+                | ValueType::Private(plaintext_type) => {
+                    parameters.push(format!("{parameter}: {}", to_rust_type(plaintext_type)?));
+                    inputs.push(format!("Value::Plaintext({parameter}.into_plaintext()?)"));
+                }
+                ValueType::Record(..) | ValueType::ExternalRecord(..) => {
+                    parameters.push(format!("{parameter}: Record<N, Plaintext<N>>"));
+                    inputs.push(format!("Value::Record({parameter})"));
+                }
+            }
+        }
+
+        writeln!(output)?;
+        writeln!(output, "/// Returns a call to `{}/{name}`.", manifest.program_id())?;
+        writeln!(output, "///")?;
+        for input in function.inputs() {
+            writeln!(output, "/// - `{}`: `{}`", to_rust_ident(input.name()), input.value_type())?;
+        }
+        writeln!(output, "pub fn {function_name}<N: Network>({}) -> Result<Call<N>> {{", parameters.join(", "))?;
+        writeln!(output, "    Call::new(PROGRAM_ID, \"{name}\", vec![{}])", inputs.join(", "))?;
+        writeln!(output, "}}")?;
+    }
+    Ok(output)
+}
+
+/// Writes the Rust struct for the given program struct.
+fn write_struct<N: Network>(output: &mut String, name: &Identifier<N>, struct_: &StructType<N>) -> Result<()> {
+    let type_name = to_rust_type_name(&name.to_string())?;
+    writeln!(output, "/// The `{name}` struct.")?;
+    writeln!(output, "#[derive(Clone, Debug, PartialEq, Eq)]")?;
+    writeln!(output, "pub struct {type_name}<N: Network> {{")?;
+    for (member, plaintext_type) in struct_.members() {
+        writeln!(output, "    pub {}: {},", to_rust_ident(&member.to_string()), to_rust_type(plaintext_type)?)?;
+    }
+    writeln!(output, "}}")?;
+    writeln!(output)?;
+    writeln!(output, "impl<N: Network> IntoPlaintext<N> for {type_name}<N> {{")?;
+    writeln!(output, "    fn into_plaintext(self) -> Result<Plaintext<N>> {{")?;
+    let members = struct_
+        .members()
+        .keys()
+        .map(|member| format!("(\"{member}\", self.{}.into_plaintext()?)", to_rust_ident(&member.to_string())))
+        .collect::<Vec<_>>();
+    writeln!(output, "        to_struct(vec![{}])", members.join(", "))?;
+    writeln!(output, "    }}")?;
+    writeln!(output, "}}")?;
+    Ok(())
+}
+
+/// Returns the Rust type for the given plaintext type.
+fn to_rust_type<N: Network>(plaintext_type: &PlaintextType<N>) -> Result<String> {
+    Ok(match plaintext_type {
+        PlaintextType::Literal(literal_type) => {
+            let type_name = match literal_type {
+                LiteralType::Address => "Address",
+                LiteralType::Boolean => "Boolean",
+                LiteralType::Field => "Field",
+                LiteralType::Group => "Group",
+                LiteralType::I8 => "I8",
+                LiteralType::I16 => "I16",
+                LiteralType::I32 => "I32",
+                LiteralType::I64 => "I64",
+                LiteralType::I128 => "I128",
+                LiteralType::U8 => "U8",
+                LiteralType::U16 => "U16",
+                LiteralType::U32 => "U32",
+                LiteralType::U64 => "U64",
+                LiteralType::U128 => "U128",
+                LiteralType::Scalar => "Scalar",
+                LiteralType::Signature => "Signature",
+                LiteralType::String => "StringType",
+            };
+            format!("{type_name}<N>")
+        }
+        PlaintextType::Struct(name) => format!("{}<N>", to_rust_type_name(&name.to_string())?),
+        PlaintextType::Array(array_type) => {
+            format!("[{}; {}]", to_rust_type(array_type.next_element_type())?, **array_type.length())
+        }
+    })
+}
+
+/// Returns the Rust type name for the given program identifier, in upper camel case.
+///
+/// Names that would be a keyword, or shadow a name used by the generated code, are rejected.
+fn to_rust_type_name(name: &str) -> Result<String> {
+    let mut type_name = String::with_capacity(name.len());
+    for part in name.split('_').filter(|part| !part.is_empty()) {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            type_name.extend(first.to_uppercase());
+            type_name.push_str(chars.as_str());
+        }
+    }
+    if type_name.is_empty() {
+        type_name.push_str("Struct");
+    }
+    ensure!(
+        !RESERVED_TYPE_NAMES.contains(&type_name.as_str()),
+        "Struct '{name}' would generate the reserved Rust name '{type_name}'"
+    );
+    Ok(type_name)
+}
+
+/// Returns the Rust identifier for the given program identifier, escaping Rust keywords.
+fn to_rust_ident(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn", "else", "enum",
+        "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let", "loop", "macro", "match", "mod",
+        "move", "mut", "override", "priv", "pub", "ref", "return", "static", "struct", "trait", "true", "try", "type",
+        "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+    ];
+    match name {
+        // These keywords cannot be raw identifiers.
+        "self" | "super" | "crate" | "_" => format!("{name}_"),
+        _ if KEYWORDS.contains(&name) => format!("r#{name}"),
+        _ => name.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const PROGRAM: &str = r"
+program token.aleo;
+
+struct token_info:
+    supply as u64;
+    decimals as u8;
+    loop as [field; 2u32];
+
+record token:
+    owner as address.private;
+    amount as u64.private;
+
+function mint:
+    input r0 as address.private;
+    input r1 as token_info.public;
+    cast r0 r1.supply into r2 as token.record;
+    output r2 as token.record;
+
+function burn:
+    input r0 as token.record;
+    input r1 as boolean.public;
+    output r1 as boolean.public;
+";
+
+    #[test]
+    fn test_generate() {
+        let output = generate_from_str::<CurrentNetwork>(PROGRAM).unwrap();
+        assert!(output.contains("pub const PROGRAM_ID: &str = \"token.aleo\";"));
+        assert!(output.contains("pub struct TokenInfo<N: Network> {"));
+        assert!(output.contains("    pub supply: U64<N>,"));
+        assert!(output.contains("    pub r#loop: [Field<N>; 2],"));
+        assert!(output.contains("impl<N: Network> IntoPlaintext<N> for TokenInfo<N> {"));
+        assert!(output.contains(
+            "to_struct(vec![(\"supply\", self.supply.into_plaintext()?), (\"decimals\", self.decimals.into_plaintext()?), (\"loop\", self.r#loop.into_plaintext()?)])"
+        ));
+        assert!(output.contains("pub fn mint<N: Network>(r0: Address<N>, r1: TokenInfo<N>) -> Result<Call<N>> {"));
+        assert!(output
+            .contains("pub fn burn<N: Network>(r0: Record<N, Plaintext<N>>, r1: Boolean<N>) -> Result<Call<N>> {"));
+        assert!(output.contains(
+            "Call::new(PROGRAM_ID, \"burn\", vec![Value::Record(r0), Value::Plaintext(r1.into_plaintext()?)])"
+        ));
+    }
+
+    #[test]
+    fn test_generate_rejects_reserved_names() {
+        let programs = [
+            // A struct that shadows a name used by the generated code.
+            "program token.aleo;\n\nstruct plaintext:\n    amount as u64;\n\nfunction main:\n    input r0 as u64.public;\n",
+            // Two structs that convert to the same name.
+            "program token.aleo;\n\nstruct token_info:\n    amount as u64;\n\nstruct tokenInfo:\n    amount as u64;\n\nfunction main:\n    input r0 as u64.public;\n",
+            // A function that shadows an imported name.
+            "program token.aleo;\n\nfunction to_struct:\n    input r0 as u64.public;\n",
+        ];
+        for program in programs {
+            // Ensure the program is valid, and the generator rejects it.
+            let program = Program::<CurrentNetwork>::from_str(program).unwrap();
+            assert!(generate(&program).is_err());
+        }
+    }
+
+    #[test]
+    fn test_generate_credits() {
+        let output = generate::<CurrentNetwork>(&Program::credits().unwrap()).unwrap();
+        assert!(output.contains("pub const PROGRAM_ID: &str = \"credits.aleo\";"));
+        assert!(output.contains("pub fn transfer_public<N: Network>(r0: Address<N>, r1: U64<N>) -> Result<Call<N>> {"));
+    }
+
+    #[test]
+    fn test_call() {
+        // Mirror what the generated code builds for `token.aleo/mint`.
+        let address =
+            Address::<CurrentNetwork>::from_str("aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah")
+                .unwrap();
+        let info = to_struct(vec![
+            ("supply", U64::<CurrentNetwork>::new(5).into_plaintext().unwrap()),
+            ("decimals", U8::new(6).into_plaintext().unwrap()),
+            ("loop", [Field::from_u8(1), Field::from_u8(2)].into_plaintext().unwrap()),
+        ])
+        .unwrap();
+        let call = Call::new("token.aleo", "mint", vec![
+            Value::Plaintext(address.into_plaintext().unwrap()),
+            Value::Plaintext(info),
+        ])
+        .unwrap();
+
+        assert_eq!(call.program_id().to_string(), "token.aleo");
+        assert_eq!(call.function_name().to_string(), "mint");
+        let expected = vec![
+            Value::from_str("aleo1d5hg2z3ma00382pngntdp68e74zv54jdxy249qhaujhks9c72yrs33ddah").unwrap(),
+            Value::from_str("{ supply: 5u64, decimals: 6u8, loop: [1field, 2field] }").unwrap(),
+        ];
+        assert_eq!(call.inputs(), expected.as_slice());
+
+        // Ensure invalid identifiers are rejected.
+        assert!(Call::<CurrentNetwork>::new("token", "mint", vec![]).is_err());
+        assert!(Call::<CurrentNetwork>::new("token.aleo", "1mint", vec![]).is_err());
+    }
+
+    #[test]
+    fn test_to_rust_names() {
+        assert_eq!(to_rust_type_name("token_info").unwrap(), "TokenInfo");
+        assert_eq!(to_rust_type_name("Point").unwrap(), "Point");
+        assert!(to_rust_type_name("self_").is_err());
+        assert!(to_rust_type_name("value").is_err());
+        assert_eq!(to_rust_ident("amount"), "amount");
+        assert_eq!(to_rust_ident("match"), "r#match");
+        assert_eq!(to_rust_ident("self"), "self_");
+    }
+}
@@ -20,10 +20,17 @@
 #[macro_use]
 extern crate thiserror;
 
+pub mod api;
 #[cfg(feature = "cli")]
 pub mod cli;
+#[cfg(feature = "codegen")]
+pub mod codegen;
+#[cfg(feature = "cli")]
 pub mod file;
+#[cfg(feature = "cli")]
 pub mod package;
+#[cfg(feature = "test_vectors")]
+pub mod test_vectors;
 
 #[cfg(feature = "algorithms")]
 pub use snarkvm_algorithms as algorithms;
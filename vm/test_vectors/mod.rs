@@ -0,0 +1,354 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Deterministic test vectors for third-party implementations of the Aleo primitives.
+//!
+//! The vectors are generated from a seeded RNG, so the same seed always yields byte-for-byte identical JSON.
+//! Each section pairs the inputs of an operation with its expected output, using the canonical string
+//! representation of each type (e.g. `5field`, `aleo1...`) and lowercase hex for raw bytes and bits.
+
+use crate::{
+    console::{
+        account::{Address, ComputeKey, GraphKey, PrivateKey, Signature, ViewKey},
+        network::Network,
+        prelude::*,
+        program::{Identifier, Plaintext, ProgramID, Record, Register, Request, Response, Value, ValueType},
+        types::{Field, Scalar, U16},
+    },
+    ledger::block::Transition,
+};
+
+use serde_json::{json, Value as Json};
+
+/// The version of the test vectors format.
+pub const TEST_VECTORS_VERSION: u8 = 1;
+
+/// Returns the test vectors for the given network as a JSON string, with `num_cases` cases per section.
+pub fn generate_json<N: Network>(seed: u64, num_cases: usize) -> Result<String> {
+    Ok(serde_json::to_string_pretty(&generate::<N>(seed, num_cases)?)?)
+}
+
+/// Returns the test vectors for the given network, with `num_cases` cases per section.
+pub fn generate<N: Network>(seed: u64, num_cases: usize) -> Result<Json> {
+    let rng = &mut TestRng::fixed(seed);
+    Ok(json!({
+        "version": TEST_VECTORS_VERSION,
+        "network_id": N::ID,
+        "seed": seed,
+        "accounts": accounts::<N>(num_cases, rng)?,
+        "signatures": signatures::<N>(num_cases, rng)?,
+        "records": records::<N>(num_cases, rng)?,
+        "requests": requests::<N>(num_cases, rng)?,
+        "transitions": transitions::<N>(num_cases, rng)?,
+        "hashes": hashes::<N>(num_cases, rng)?,
+        "commitments": commitments::<N>(num_cases, rng)?,
+    }))
+}
+
+/// Returns the account key derivation vectors.
+fn accounts<N: Network>(num_cases: usize, rng: &mut TestRng) -> Result<Vec<Json>> {
+    (0..num_cases)
+        .map(|_| {
+            let private_key = PrivateKey::<N>::new(rng)?;
+            let compute_key = ComputeKey::try_from(&private_key)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            Ok(json!({
+                "seed": private_key.seed().to_string(),
+                "private_key": private_key.to_string(),
+                "sk_sig": private_key.sk_sig().to_string(),
+                "r_sig": private_key.r_sig().to_string(),
+                "pk_sig": compute_key.pk_sig().to_string(),
+                "pr_sig": compute_key.pr_sig().to_string(),
+                "sk_prf": compute_key.sk_prf().to_string(),
+                "view_key": view_key.to_string(),
+                "graph_key": GraphKey::try_from(&view_key)?.to_string(),
+                "address": Address::try_from(&private_key)?.to_string(),
+            }))
+        })
+        .collect()
+}
+
+/// Returns the signature vectors.
+fn signatures<N: Network>(num_cases: usize, rng: &mut TestRng) -> Result<Vec<Json>> {
+    (0..num_cases)
+        .map(|i| {
+            let private_key = PrivateKey::<N>::new(rng)?;
+            let message = (0..=i).map(|_| Field::<N>::rand(rng)).collect::<Vec<_>>();
+            let signature = Signature::sign(&private_key, &message, rng)?;
+            Ok(json!({
+                "private_key": private_key.to_string(),
+                "address": Address::try_from(&private_key)?.to_string(),
+                "message": message.iter().map(ToString::to_string).collect::<Vec<_>>(),
+                "signature": signature.to_string(),
+                "challenge": signature.challenge().to_string(),
+                "response": signature.response().to_string(),
+            }))
+        })
+        .collect()
+}
+
+/// Returns the record encryption, commitment, serial number, and tag vectors.
+fn records<N: Network>(num_cases: usize, rng: &mut TestRng) -> Result<Vec<Json>> {
+    let program_id = ProgramID::<N>::from_str("token.aleo")?;
+    let record_name = Identifier::<N>::from_str("token")?;
+    (0..num_cases)
+        .map(|_| {
+            let private_key = PrivateKey::<N>::new(rng)?;
+            let view_key = ViewKey::try_from(&private_key)?;
+            let address = Address::try_from(&private_key)?;
+
+            // Construct and encrypt the record.
+            let randomizer = Scalar::<N>::rand(rng);
+            let nonce = N::g_scalar_multiply(&randomizer);
+            let amount = u64::rand(rng);
+            let record = Record::<N, Plaintext<N>>::from_str(&format!(
+                "{{ owner: {address}.private, amount: {amount}u64.private, _nonce: {nonce}.public }}"
+            ))?;
+            let ciphertext = record.encrypt(randomizer)?;
+
+            // Compute the commitment, serial number, and tag.
+            let commitment = record.to_commitment(&program_id, &record_name)?;
+            let serial_number = Record::<N, Plaintext<N>>::serial_number(private_key, commitment)?;
+            let tag = Record::<N, Plaintext<N>>::tag(GraphKey::try_from(&view_key)?.sk_tag(), commitment)?;
+            Ok(json!({
+                "private_key": private_key.to_string(),
+                "view_key": view_key.to_string(),
+                "program_id": program_id.to_string(),
+                "record_name": record_name.to_string(),
+                "randomizer": randomizer.to_string(),
+                "record_view_key": (nonce * *view_key).to_x_coordinate().to_string(),
+                "plaintext": record.to_string(),
+                "ciphertext": ciphertext.to_string(),
+                "commitment": commitment.to_string(),
+                "serial_number": serial_number.to_string(),
+                "tag": tag.to_string(),
+            }))
+        })
+        .collect()
+}
+
+/// Returns the request vectors, covering the transition view key, transition commitment, and input IDs.
+fn requests<N: Network>(num_cases: usize, rng: &mut TestRng) -> Result<Vec<Json>> {
+    let program_id = ProgramID::<N>::from_str("token.aleo")?;
+    let function_name = Identifier::<N>::from_str("transfer")?;
+    let input_types = [
+        ValueType::from_str("address.private")?,
+        ValueType::from_str("u64.public")?,
+        ValueType::from_str("field.constant")?,
+    ];
+    (0..num_cases)
+        .map(|_| {
+            let private_key = PrivateKey::<N>::new(rng)?;
+            let inputs = [
+                Value::from_str(&Address::try_from(&PrivateKey::<N>::new(rng)?)?.to_string())?,
+                Value::from_str(&format!("{}u64", u64::rand(rng)))?,
+                Value::from_str(&Field::<N>::rand(rng).to_string())?,
+            ];
+            let request =
+                Request::sign(&private_key, program_id, function_name, inputs.into_iter(), &input_types, rng)?;
+            Ok(json!({
+                "private_key": private_key.to_string(),
+                "program_id": program_id.to_string(),
+                "function_name": function_name.to_string(),
+                "input_types": input_types.iter().map(ToString::to_string).collect::<Vec<_>>(),
+                "tvk": request.tvk().to_string(),
+                "tcm": request.tcm().to_string(),
+                "tpk": request.to_tpk().to_string(),
+                "request": serde_json::to_value(&request)?,
+            }))
+        })
+        .collect()
+}
+
+/// Returns the transition vectors, covering the transition commitment, input and output IDs, and transition ID.
+fn transitions<N: Network>(num_cases: usize, rng: &mut TestRng) -> Result<Vec<Json>> {
+    let program_id = ProgramID::<N>::from_str("token.aleo")?;
+    let function_name = Identifier::<N>::from_str("mint")?;
+    let input_types = [ValueType::from_str("address.private")?, ValueType::from_str("u64.public")?];
+    let output_types = [ValueType::from_str("token.record")?, ValueType::from_str("u64.public")?];
+    // The record is output from `r2`, and the amount from `r1`.
+    let output_registers = [Some(Register::Locator(2)), None];
+    (0..num_cases)
+        .map(|_| {
+            let private_key = PrivateKey::<N>::new(rng)?;
+            let recipient = Address::try_from(&PrivateKey::<N>::new(rng)?)?;
+            let amount = u64::rand(rng);
+            let inputs = [Value::from_str(&recipient.to_string())?, Value::from_str(&format!("{amount}u64"))?];
+            let request =
+                Request::sign(&private_key, program_id, function_name, inputs.into_iter(), &input_types, rng)?;
+
+            // Construct the outputs, where the record nonce is derived from `tvk` and the output register.
+            let randomizer = N::hash_to_scalar_psd2(&[*request.tvk(), Field::from_u64(2)])?;
+            let nonce = N::g_scalar_multiply(&randomizer);
+            let outputs = vec![
+                Value::from_str(&format!(
+                    "{{ owner: {recipient}.private, amount: {amount}u64.private, _nonce: {nonce}.public }}"
+                ))?,
+                Value::from_str(&format!("{amount}u64"))?,
+            ];
+            let response = Response::new(
+                &U16::new(N::ID),
+                &program_id,
+                &function_name,
+                input_types.len(),
+                request.tvk(),
+                request.tcm(),
+                outputs,
+                &output_types,
+                &output_registers,
+            )?;
+            let transition = Transition::from(&request, &response, None, &output_types, &output_registers)?;
+            Ok(json!({
+                "private_key": private_key.to_string(),
+                "tvk": request.tvk().to_string(),
+                "tcm": transition.tcm().to_string(),
+                "tpk": transition.tpk().to_string(),
+                "input_ids": transition.input_ids().map(ToString::to_string).collect::<Vec<_>>(),
+                "output_ids": transition.output_ids().map(ToString::to_string).collect::<Vec<_>>(),
+                "transition_id": transition.id().to_string(),
+                "transition": serde_json::to_value(&transition)?,
+            }))
+        })
+        .collect()
+}
+
+/// Returns the hash vectors for each hash function of the network.
+fn hashes<N: Network>(num_cases: usize, rng: &mut TestRng) -> Result<Vec<Json>> {
+    (0..num_cases)
+        .map(|i| {
+            // Sample the inputs, with the number of fields and bytes growing with each case.
+            let fields = (0..=i).map(|_| Field::<N>::rand(rng)).collect::<Vec<_>>();
+            let bytes = (0..(i + 1) * 8).map(|_| u8::rand(rng)).collect::<Vec<_>>();
+            let bits = bytes.to_bits_le();
+            // The Pedersen hashes have a fixed maximum input size.
+            let bits_64 = &bits[..bits.len().min(64)];
+            let bits_128 = &bits[..bits.len().min(128)];
+            Ok(json!({
+                "fields": fields.iter().map(ToString::to_string).collect::<Vec<_>>(),
+                "bytes": to_hex(&bytes),
+                "bhp256": N::hash_bhp256(&bits)?.to_string(),
+                "bhp512": N::hash_bhp512(&bits)?.to_string(),
+                "bhp768": N::hash_bhp768(&bits)?.to_string(),
+                "bhp1024": N::hash_bhp1024(&bits)?.to_string(),
+                "ped64": N::hash_ped64(bits_64)?.to_string(),
+                "ped128": N::hash_ped128(bits_128)?.to_string(),
+                "psd2": N::hash_psd2(&fields)?.to_string(),
+                "psd4": N::hash_psd4(&fields)?.to_string(),
+                "psd8": N::hash_psd8(&fields)?.to_string(),
+                "psd2_to_group": N::hash_to_group_psd2(&fields)?.to_string(),
+                "psd2_to_scalar": N::hash_to_scalar_psd2(&fields)?.to_string(),
+                "keccak256": to_hex(&from_bits(&N::hash_keccak256(&bits)?)),
+                "keccak384": to_hex(&from_bits(&N::hash_keccak384(&bits)?)),
+                "keccak512": to_hex(&from_bits(&N::hash_keccak512(&bits)?)),
+                "sha3_256": to_hex(&from_bits(&N::hash_sha3_256(&bits)?)),
+                "sha3_384": to_hex(&from_bits(&N::hash_sha3_384(&bits)?)),
+                "sha3_512": to_hex(&from_bits(&N::hash_sha3_512(&bits)?)),
+            }))
+        })
+        .collect()
+}
+
+/// Returns the commitment vectors for each commitment scheme of the network.
+fn commitments<N: Network>(num_cases: usize, rng: &mut TestRng) -> Result<Vec<Json>> {
+    (0..num_cases)
+        .map(|i| {
+            let bytes = (0..(i + 1) * 8).map(|_| u8::rand(rng)).collect::<Vec<_>>();
+            let bits = bytes.to_bits_le();
+            let bits_64 = &bits[..bits.len().min(64)];
+            let bits_128 = &bits[..bits.len().min(128)];
+            let randomizer = Scalar::<N>::rand(rng);
+            Ok(json!({
+                "bytes": to_hex(&bytes),
+                "randomizer": randomizer.to_string(),
+                "bhp256": N::commit_bhp256(&bits, &randomizer)?.to_string(),
+                "bhp512": N::commit_bhp512(&bits, &randomizer)?.to_string(),
+                "bhp768": N::commit_bhp768(&bits, &randomizer)?.to_string(),
+                "bhp1024": N::commit_bhp1024(&bits, &randomizer)?.to_string(),
+                "ped64": N::commit_ped64(bits_64, &randomizer)?.to_string(),
+                "ped128": N::commit_ped128(bits_128, &randomizer)?.to_string(),
+            }))
+        })
+        .collect()
+}
+
+/// Returns the given bits (little-endian) as bytes.
+fn from_bits(bits: &[bool]) -> Vec<u8> {
+    bits.chunks(8).map(|byte| byte.iter().rev().fold(0u8, |acc, bit| (acc << 1) | (*bit as u8))).collect()
+}
+
+/// Returns the given bytes as lowercase hex.
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_generate_is_deterministic() {
+        let expected = generate_json::<CurrentNetwork>(0, 2).unwrap();
+        assert_eq!(expected, generate_json::<CurrentNetwork>(0, 2).unwrap());
+        assert_ne!(expected, generate_json::<CurrentNetwork>(1, 2).unwrap());
+    }
+
+    #[test]
+    fn test_generate_is_consistent() {
+        let vectors = generate::<CurrentNetwork>(0, 2).unwrap();
+
+        // Ensure the account vectors rederive.
+        for account in vectors["accounts"].as_array().unwrap() {
+            let private_key = PrivateKey::<CurrentNetwork>::from_str(account["private_key"].as_str().unwrap()).unwrap();
+            assert_eq!(account["view_key"], ViewKey::try_from(&private_key).unwrap().to_string());
+            assert_eq!(account["address"], Address::try_from(&private_key).unwrap().to_string());
+        }
+        // Ensure the signature vectors verify.
+        for case in vectors["signatures"].as_array().unwrap() {
+            let address = Address::<CurrentNetwork>::from_str(case["address"].as_str().unwrap()).unwrap();
+            let signature = Signature::<CurrentNetwork>::from_str(case["signature"].as_str().unwrap()).unwrap();
+            let message = case["message"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|field| Field::from_str(field.as_str().unwrap()).unwrap())
+                .collect::<Vec<_>>();
+            assert!(signature.verify(&address, &message));
+        }
+        // Ensure the record vectors decrypt.
+        for case in vectors["records"].as_array().unwrap() {
+            let view_key = ViewKey::<CurrentNetwork>::from_str(case["view_key"].as_str().unwrap()).unwrap();
+            let ciphertext = Record::<CurrentNetwork, crate::console::program::Ciphertext<CurrentNetwork>>::from_str(
+                case["ciphertext"].as_str().unwrap(),
+            )
+            .unwrap();
+            assert_eq!(case["plaintext"], ciphertext.decrypt(&view_key).unwrap().to_string());
+        }
+        // Ensure the transition vectors rederive their commitment and ID.
+        for case in vectors["transitions"].as_array().unwrap() {
+            let transition = serde_json::from_value::<Transition<CurrentNetwork>>(case["transition"].clone()).unwrap();
+            assert_eq!(case["transition_id"], transition.id().to_string());
+            assert_eq!(case["tcm"], transition.tcm().to_string());
+            let tvk = Field::<CurrentNetwork>::from_str(case["tvk"].as_str().unwrap()).unwrap();
+            assert_eq!(*transition.tcm(), CurrentNetwork::hash_psd2(&[tvk]).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_from_bits() {
+        assert_eq!(from_bits(&[0x01u8, 0xa5, 0xff].to_bits_le()), vec![0x01, 0xa5, 0xff]);
+        assert_eq!(to_hex(&[0x01, 0xa5, 0xff]), "01a5ff");
+    }
+}